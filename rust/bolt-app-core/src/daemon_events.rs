@@ -0,0 +1,249 @@
+//! Typed daemon event stream.
+//!
+//! The daemon reports everything a shell needs to render — endpoint
+//! readiness, WebTransport certificate, HELLO mode, SAS, session and
+//! transfer lifecycle — as NDJSON `event` messages on the IPC socket.
+//! [`DaemonEvent::from_message`] turns one message into a typed event, so
+//! shells derive state from the stream instead of scraping daemon stderr.
+//!
+//! After the version handshake the daemon replays current state (the
+//! `endpoint.*` events already emitted and, with a peer connected,
+//! `session.connected` / `session.sas`), so a shell that connects late
+//! sees the same stream as one that connected first.
+
+use serde::de::DeserializeOwned;
+
+use crate::ipc_types::{
+    DaemonStatusPayload, DecisionAckPayload, EndpointListeningPayload, IpcKind, IpcMessage,
+    PairingRequestPayload, SessionConnectedPayload, SessionEndedPayload, SessionErrorPayload,
+    SessionSasPayload, TransferCompletePayload, TransferErrorPayload,
+    TransferIncomingRequestPayload, TransferProgressPayload, TransferStartedPayload, WtCertPayload,
+};
+
+/// One event from the daemon.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DaemonEvent {
+    Status(DaemonStatusPayload),
+    EndpointListening(EndpointListeningPayload),
+    WtCert(WtCertPayload),
+    SessionConnected(SessionConnectedPayload),
+    SessionSas(SessionSasPayload),
+    SessionEnded(SessionEndedPayload),
+    SessionError(SessionErrorPayload),
+    PairingRequest(PairingRequestPayload),
+    TransferIncomingRequest(TransferIncomingRequestPayload),
+    TransferStarted(TransferStartedPayload),
+    TransferProgress(TransferProgressPayload),
+    TransferComplete(TransferCompletePayload),
+    TransferError(TransferErrorPayload),
+    DecisionAck(DecisionAckPayload),
+}
+
+fn payload<T: DeserializeOwned>(msg: &IpcMessage) -> Result<T, String> {
+    serde_json::from_value(msg.payload.clone())
+        .map_err(|e| format!("malformed {} payload: {e}", msg.msg_type))
+}
+
+impl DaemonEvent {
+    /// Parse an IPC message. `Ok(None)` for decisions and for event types
+    /// this version does not know (the stream is forward compatible);
+    /// `Err` when a known event carries a malformed payload.
+    pub fn from_message(msg: &IpcMessage) -> Result<Option<Self>, String> {
+        if msg.kind != IpcKind::Event {
+            return Ok(None);
+        }
+        let event = match msg.msg_type.as_str() {
+            "daemon.status" => Self::Status(payload(msg)?),
+            "endpoint.listening" => Self::EndpointListening(payload(msg)?),
+            "endpoint.wt_cert" => Self::WtCert(payload(msg)?),
+            "session.connected" => Self::SessionConnected(payload(msg)?),
+            "session.sas" => Self::SessionSas(payload(msg)?),
+            "session.ended" => Self::SessionEnded(payload(msg)?),
+            "session.error" => Self::SessionError(payload(msg)?),
+            "pairing.request" => Self::PairingRequest(payload(msg)?),
+            "transfer.incoming.request" => Self::TransferIncomingRequest(payload(msg)?),
+            "transfer.started" => Self::TransferStarted(payload(msg)?),
+            "transfer.progress" => Self::TransferProgress(payload(msg)?),
+            "transfer.complete" => Self::TransferComplete(payload(msg)?),
+            "transfer.error" => Self::TransferError(payload(msg)?),
+            "decision.ack" => Self::DecisionAck(payload(msg)?),
+            _ => return Ok(None),
+        };
+        Ok(Some(event))
+    }
+
+    /// Wire `type` of the event.
+    pub fn msg_type(&self) -> &'static str {
+        match self {
+            Self::Status(_) => "daemon.status",
+            Self::EndpointListening(_) => "endpoint.listening",
+            Self::WtCert(_) => "endpoint.wt_cert",
+            Self::SessionConnected(_) => "session.connected",
+            Self::SessionSas(_) => "session.sas",
+            Self::SessionEnded(_) => "session.ended",
+            Self::SessionError(_) => "session.error",
+            Self::PairingRequest(_) => "pairing.request",
+            Self::TransferIncomingRequest(_) => "transfer.incoming.request",
+            Self::TransferStarted(_) => "transfer.started",
+            Self::TransferProgress(_) => "transfer.progress",
+            Self::TransferComplete(_) => "transfer.complete",
+            Self::TransferError(_) => "transfer.error",
+            Self::DecisionAck(_) => "decision.ack",
+        }
+    }
+
+    /// Shell event name used by [`IpcBridgeCore`](crate::ipc_bridge_core::IpcBridgeCore)
+    /// callbacks.
+    pub fn shell_event_name(&self) -> &'static str {
+        match self {
+            Self::Status(_) => "daemon://status-update",
+            Self::EndpointListening(_) => "daemon://endpoint-listening",
+            Self::WtCert(_) => "daemon://endpoint-wt-cert",
+            Self::SessionConnected(_) => "daemon://session-connected",
+            Self::SessionSas(_) => "daemon://session-sas",
+            Self::SessionEnded(_) => "daemon://session-ended",
+            Self::SessionError(_) => "daemon://session-error",
+            Self::PairingRequest(_) => "daemon://pairing-request",
+            Self::TransferIncomingRequest(_) => "daemon://transfer-request",
+            Self::TransferStarted(_) => "daemon://transfer-started",
+            Self::TransferProgress(_) => "daemon://transfer-progress",
+            Self::TransferComplete(_) => "daemon://transfer-complete",
+            Self::TransferError(_) => "daemon://transfer-error",
+            Self::DecisionAck(_) => "daemon://decision-ack",
+        }
+    }
+
+    /// Serialize as an event message (what the daemon writes).
+    pub fn to_message(&self) -> IpcMessage {
+        let payload = match self {
+            Self::Status(p) => serde_json::to_value(p),
+            Self::EndpointListening(p) => serde_json::to_value(p),
+            Self::WtCert(p) => serde_json::to_value(p),
+            Self::SessionConnected(p) => serde_json::to_value(p),
+            Self::SessionSas(p) => serde_json::to_value(p),
+            Self::SessionEnded(p) => serde_json::to_value(p),
+            Self::SessionError(p) => serde_json::to_value(p),
+            Self::PairingRequest(p) => serde_json::to_value(p),
+            Self::TransferIncomingRequest(p) => serde_json::to_value(p),
+            Self::TransferStarted(p) => serde_json::to_value(p),
+            Self::TransferProgress(p) => serde_json::to_value(p),
+            Self::TransferComplete(p) => serde_json::to_value(p),
+            Self::TransferError(p) => serde_json::to_value(p),
+            Self::DecisionAck(p) => serde_json::to_value(p),
+        };
+        IpcMessage::new_event(self.msg_type(), payload.unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ipc_types::{Endpoint, HelloMode, TransferDirection};
+
+    fn event(msg_type: &str, payload: serde_json::Value) -> IpcMessage {
+        IpcMessage::new_event(msg_type, payload)
+    }
+
+    #[test]
+    fn endpoint_events_parse() {
+        let msg = event(
+            "endpoint.listening",
+            serde_json::json!({"endpoint": "wt", "port": 9101}),
+        );
+        assert_eq!(
+            DaemonEvent::from_message(&msg).unwrap(),
+            Some(DaemonEvent::EndpointListening(EndpointListeningPayload {
+                endpoint: Endpoint::Wt,
+                port: Some(9101),
+            }))
+        );
+        let hash = "ab".repeat(32);
+        let msg = event(
+            "endpoint.wt_cert",
+            serde_json::json!({ "cert_hash_hex": hash }),
+        );
+        match DaemonEvent::from_message(&msg).unwrap() {
+            Some(DaemonEvent::WtCert(p)) => assert_eq!(p.cert_hash_hex, hash),
+            other => panic!("expected WtCert, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn session_connected_defaults_to_identity_hello() {
+        let msg = event(
+            "session.connected",
+            serde_json::json!({"negotiated_capabilities": ["bolt.file-hash"]}),
+        );
+        let Some(DaemonEvent::SessionConnected(p)) = DaemonEvent::from_message(&msg).unwrap()
+        else {
+            panic!("expected SessionConnected");
+        };
+        assert_eq!(p.hello_mode, HelloMode::Identity);
+
+        let msg = event(
+            "session.connected",
+            serde_json::json!({"hello_mode": "legacy", "transport": "ws"}),
+        );
+        let Some(DaemonEvent::SessionConnected(p)) = DaemonEvent::from_message(&msg).unwrap()
+        else {
+            panic!("expected SessionConnected");
+        };
+        assert_eq!(p.hello_mode, HelloMode::Legacy);
+        assert_eq!(p.transport, Some(Endpoint::Ws));
+    }
+
+    #[test]
+    fn unknown_events_and_decisions_are_skipped() {
+        let msg = event("daemon.future_thing", serde_json::json!({}));
+        assert_eq!(DaemonEvent::from_message(&msg).unwrap(), None);
+        let decision = IpcMessage::new_decision("session.sas", serde_json::json!({"sas": "x"}));
+        assert_eq!(DaemonEvent::from_message(&decision).unwrap(), None);
+    }
+
+    #[test]
+    fn malformed_known_event_is_an_error() {
+        let msg = event("transfer.started", serde_json::json!({"file_name": 3}));
+        let err = DaemonEvent::from_message(&msg).unwrap_err();
+        assert!(err.contains("transfer.started"), "{err}");
+    }
+
+    #[test]
+    fn events_round_trip_through_messages() {
+        let events = [
+            DaemonEvent::SessionSas(SessionSasPayload {
+                sas: "A1B2C3".into(),
+                remote_identity_pk_b64: None,
+            }),
+            DaemonEvent::SessionEnded(SessionEndedPayload { reason: None }),
+            DaemonEvent::TransferStarted(TransferStartedPayload {
+                transfer_id: Some("t-1".into()),
+                file_name: "a.txt".into(),
+                direction: TransferDirection::Receive,
+                file_size_bytes: Some(12),
+            }),
+            DaemonEvent::TransferProgress(TransferProgressPayload {
+                transfer_id: Some("t-1".into()),
+                progress: 0.5,
+            }),
+            DaemonEvent::TransferComplete(TransferCompletePayload {
+                transfer_id: Some("t-1".into()),
+                file_name: "a.txt".into(),
+                save_path: Some("/tmp/a.txt".into()),
+                file_size_bytes: Some(12),
+                file_hash_hex: None,
+            }),
+            DaemonEvent::TransferError(TransferErrorPayload {
+                transfer_id: None,
+                file_name: None,
+                reason: "integrity".into(),
+            }),
+        ];
+        for e in events {
+            let line = e.to_message().to_ndjson().unwrap();
+            let msg: IpcMessage = serde_json::from_str(line.trim()).unwrap();
+            assert_eq!(msg.kind, IpcKind::Event);
+            assert_eq!(DaemonEvent::from_message(&msg).unwrap(), Some(e.clone()));
+            assert!(e.shell_event_name().starts_with("daemon://"));
+        }
+    }
+}
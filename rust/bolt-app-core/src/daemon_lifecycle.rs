@@ -11,7 +11,7 @@ use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::{Arc, Mutex};
 
-use crate::daemon_log::{self, StderrBuffer};
+use crate::daemon_log::{self, CrashLogPolicy, CrashReport, StderrBuffer};
 use crate::ipc_bridge_core::IpcBridgeCore;
 use crate::ipc_client::{self, ReadinessResult};
 use crate::ipc_transport::IpcStream;
@@ -35,6 +35,10 @@ pub struct DaemonLifecycle {
     pub stderr_buffer: StderrBuffer,
     pub bridge: Arc<IpcBridgeCore>,
     child_pid: Arc<Mutex<Option<u32>>>,
+    /// When the current daemon was spawned (for crash report uptime).
+    spawned_at: Arc<Mutex<Option<std::time::Instant>>>,
+    /// Set by the stderr reader once it has reaped the child.
+    exit_status: Arc<Mutex<Option<std::process::ExitStatus>>>,
     shutdown_flag: Arc<AtomicBool>,
     on_watchdog: Arc<Mutex<Option<WatchdogCallback>>>,
     // N6-B3: platform-aware paths
@@ -46,6 +50,7 @@ pub struct DaemonLifecycle {
     app_version: String,
     /// Additional daemon binary search paths (shell-provided).
     extra_binary_paths: Vec<PathBuf>,
+    crash_log_policy: CrashLogPolicy,
 }
 
 impl DaemonLifecycle {
@@ -55,6 +60,8 @@ impl DaemonLifecycle {
             stderr_buffer: StderrBuffer::with_default_capacity(),
             bridge: Arc::new(IpcBridgeCore::new()),
             child_pid: Arc::new(Mutex::new(None)),
+            spawned_at: Arc::new(Mutex::new(None)),
+            exit_status: Arc::new(Mutex::new(None)),
             shutdown_flag: Arc::new(AtomicBool::new(false)),
             on_watchdog: Arc::new(Mutex::new(None)),
             socket_path: platform::default_ipc_path(),
@@ -64,6 +71,7 @@ impl DaemonLifecycle {
             spawn_count: Arc::new(AtomicU32::new(0)),
             app_version: app_version.to_string(),
             extra_binary_paths: Vec::new(),
+            crash_log_policy: CrashLogPolicy::default(),
         }
     }
 
@@ -85,6 +93,12 @@ impl DaemonLifecycle {
         self.extra_binary_paths.extend(paths);
     }
 
+    /// Replace the crash snapshot retention policy (applied at start and
+    /// after each crash).
+    pub fn set_crash_log_policy(&mut self, policy: CrashLogPolicy) {
+        self.crash_log_policy = policy;
+    }
+
     // ── Accessors ─────────────────────────────────────────────
 
     pub fn socket_path(&self) -> &str {
@@ -127,6 +141,7 @@ impl DaemonLifecycle {
 
     /// Start the lifecycle loop on a background thread.
     pub fn start(self: &Arc<Self>) {
+        let _ = daemon_log::prune_crash_logs(&platform::crash_log_dir(), &self.crash_log_policy);
         let mgr = Arc::clone(self);
         std::thread::spawn(move || {
             mgr.lifecycle_loop();
@@ -165,6 +180,7 @@ impl DaemonLifecycle {
             Ok(pid) => {
                 tracing::info!("[WATCHDOG] daemon spawned (pid={pid})");
                 *self.child_pid.lock().unwrap() = Some(pid);
+                *self.spawned_at.lock().unwrap() = Some(std::time::Instant::now());
                 self.write_pid_file(pid);
                 self.spawn_count.fetch_add(1, Ordering::Relaxed);
 
@@ -258,9 +274,13 @@ impl DaemonLifecycle {
         let pid = child.id();
 
         let buffer = self.stderr_buffer.clone();
+        let exit_status = Arc::clone(&self.exit_status);
+        *exit_status.lock().unwrap() = None;
         let mut child = child;
         let stderr = child.stderr.take();
         if let Some(stderr) = stderr {
+            // The reader owns the child: once stderr closes the daemon is
+            // exiting, so reap it and keep the status for the crash report.
             std::thread::spawn(move || {
                 let reader = BufReader::new(stderr);
                 for line in reader.lines() {
@@ -272,12 +292,15 @@ impl DaemonLifecycle {
                         Err(_) => break,
                     }
                 }
+                if let Ok(status) = child.wait() {
+                    *exit_status.lock().unwrap() = Some(status);
+                }
             });
+        } else {
+            // Detach child — track via PID + platform signals.
+            std::mem::forget(child);
         }
 
-        // Detach child — track via PID + platform signals.
-        std::mem::forget(child);
-
         Ok(pid)
     }
 
@@ -347,18 +370,35 @@ impl DaemonLifecycle {
 
                 self.bridge.shutdown();
 
-                let delay = self.watchdog.lock().unwrap().on_daemon_exit(None);
+                let mut report = CrashReport::capture(&self.stderr_buffer);
+                report.pid = Some(pid);
+                report.uptime_ms = self
+                    .spawned_at
+                    .lock()
+                    .unwrap()
+                    .take()
+                    .map(|t| t.elapsed().as_millis() as u64);
+                if let Some(status) = self.wait_exit_status() {
+                    report.exit_code = status.code();
+                    #[cfg(unix)]
+                    {
+                        use std::os::unix::process::ExitStatusExt;
+                        report.signal = status.signal();
+                    }
+                }
+
+                let delay = self.watchdog.lock().unwrap().on_daemon_exit(report.exit_code);
                 self.emit_watchdog_state();
-                let retry_count = self.watchdog.lock().unwrap().retry_count();
-                let log_dir = platform::crash_log_dir();
+                {
+                    let watchdog = self.watchdog.lock().unwrap();
+                    report.retry_count = watchdog.retry_count();
+                    report.watchdog_transitions = watchdog.history().entries();
+                }
 
-                let _ = daemon_log::write_crash_snapshot(
-                    &self.stderr_buffer,
-                    &log_dir,
-                    None,
-                    Some(pid),
-                    retry_count,
-                );
+                let log_dir = platform::crash_log_dir();
+                if daemon_log::write_crash_report(&report, &log_dir).is_ok() {
+                    let _ = daemon_log::prune_crash_logs(&log_dir, &self.crash_log_policy);
+                }
 
                 if let Some(d) = delay {
                     std::thread::sleep(d);
@@ -371,6 +411,18 @@ impl DaemonLifecycle {
         }
     }
 
+    /// Exit status from the stderr reader, which may still be draining
+    /// output when the PID disappears.
+    fn wait_exit_status(&self) -> Option<std::process::ExitStatus> {
+        for _ in 0..10 {
+            if let Some(status) = self.exit_status.lock().unwrap().take() {
+                return Some(status);
+            }
+            std::thread::sleep(std::time::Duration::from_millis(50));
+        }
+        None
+    }
+
     // ── Cleanup ────────────────────────────────────────────────
 
     pub fn run_cleanup(&self) {
@@ -1,16 +1,34 @@
 //! Daemon stderr capture and crash snapshot persistence.
 //!
 //! Maintains an in-memory ring buffer of daemon stderr lines and writes
-//! crash snapshots to disk when the daemon enters degraded state.
+//! crash snapshots to disk when the daemon exits unexpectedly.
+//!
+//! Snapshots are `daemon-crash-<unix secs>.json` (a [`CrashReport`], see
+//! [`write_crash_report`]), with a `-<n>` suffix when several land in the
+//! same second. Plain-text `daemon-crash-<unix secs>.log` snapshots from
+//! earlier versions are still listed and read. [`prune_crash_logs`]
+//! applies a [`CrashLogPolicy`]; [`list_crash_logs`] and [`read_crash_log`]
+//! let a shell show past crashes.
 
 use std::collections::VecDeque;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::state_history::StateChange;
+use crate::watchdog::{WatchdogState, MAX_RETRIES};
 
 /// Default ring buffer capacity (lines).
 const DEFAULT_CAPACITY: usize = 1000;
 /// Lines to include in a crash snapshot.
 const CRASH_SNAPSHOT_LINES: usize = 200;
+/// Crash snapshot file name prefix.
+const CRASH_FILE_PREFIX: &str = "daemon-crash-";
+/// Reports kept per second; more than this in one second are dropped.
+const MAX_REPORTS_PER_SECOND: u32 = 100;
 
 /// Thread-safe ring buffer for daemon stderr lines.
 #[derive(Clone)]
@@ -65,46 +83,196 @@ impl StderrBuffer {
     }
 }
 
-/// Write a crash snapshot to disk.
-///
-/// Returns the path written, or an error.
-pub fn write_crash_snapshot(
-    buffer: &StderrBuffer,
-    log_dir: &std::path::Path,
-    exit_code: Option<i32>,
-    pid: Option<u32>,
-    retry_count: u32,
-) -> std::io::Result<PathBuf> {
-    std::fs::create_dir_all(log_dir)?;
-
-    let timestamp = std::time::SystemTime::now()
+fn now() -> Duration {
+    std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap_or_default()
-        .as_secs();
+}
+
+// ── Structured snapshots ─────────────────────────────────────
+
+/// Structured crash snapshot, written as `daemon-crash-<secs>[-<n>].json`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CrashReport {
+    /// Milliseconds since the Unix epoch.
+    pub at_ms: u64,
+    pub exit_code: Option<i32>,
+    /// Terminating signal (Unix only).
+    pub signal: Option<i32>,
+    pub pid: Option<u32>,
+    /// Time from spawn to exit.
+    pub uptime_ms: Option<u64>,
+    pub retry_count: u32,
+    pub max_retries: u32,
+    /// Watchdog transitions leading up to the crash, oldest first.
+    #[serde(default)]
+    pub watchdog_transitions: Vec<StateChange<WatchdogState>>,
+    /// Last stderr lines, oldest first.
+    #[serde(default)]
+    pub stderr: Vec<String>,
+}
+
+impl CrashReport {
+    /// Report stamped now with the tail of `buffer`; the caller fills in
+    /// what it knows about the exit.
+    pub fn capture(buffer: &StderrBuffer) -> Self {
+        Self {
+            at_ms: now().as_millis() as u64,
+            max_retries: MAX_RETRIES,
+            stderr: buffer.last_n(CRASH_SNAPSHOT_LINES),
+            ..Self::default()
+        }
+    }
+}
+
+/// Write `report` as JSON. Returns the path written. A report never
+/// replaces an earlier one from the same second; it takes the next free
+/// `-<n>` suffix instead.
+pub fn write_crash_report(report: &CrashReport, log_dir: &Path) -> std::io::Result<PathBuf> {
+    std::fs::create_dir_all(log_dir)?;
+    let json = serde_json::to_string_pretty(report).map_err(std::io::Error::other)?;
+    let secs = report.at_ms / 1000;
+    for seq in 0..MAX_REPORTS_PER_SECOND {
+        let name = match seq {
+            0 => format!("{CRASH_FILE_PREFIX}{secs}.json"),
+            n => format!("{CRASH_FILE_PREFIX}{secs}-{n}.json"),
+        };
+        let path = log_dir.join(name);
+        let mut file = match std::fs::File::options()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+        {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        };
+        file.write_all(json.as_bytes())?;
+
+        tracing::warn!("[WATCHDOG] crash report written: {}", path.display());
+
+        return Ok(path);
+    }
+    Err(std::io::Error::new(
+        std::io::ErrorKind::AlreadyExists,
+        format!("more than {MAX_REPORTS_PER_SECOND} crash reports at {secs}"),
+    ))
+}
+
+// ── Retention ────────────────────────────────────────────────
+
+/// How many crash snapshots to keep, and for how long.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrashLogPolicy {
+    /// Newest snapshots kept; older ones are deleted.
+    pub max_count: usize,
+    /// Snapshots older than this are deleted regardless of count.
+    pub max_age: Duration,
+}
+
+impl Default for CrashLogPolicy {
+    fn default() -> Self {
+        Self {
+            max_count: 20,
+            max_age: Duration::from_secs(30 * 24 * 60 * 60),
+        }
+    }
+}
+
+/// Delete snapshots in `log_dir` outside `policy`. Returns how many were
+/// removed. Files not named like a snapshot are left alone.
+pub fn prune_crash_logs(log_dir: &Path, policy: &CrashLogPolicy) -> std::io::Result<usize> {
+    prune_crash_logs_at(log_dir, policy, now().as_secs())
+}
 
-    let filename = format!("daemon-crash-{timestamp}.log");
-    let path = log_dir.join(&filename);
+fn prune_crash_logs_at(
+    log_dir: &Path,
+    policy: &CrashLogPolicy,
+    now_secs: u64,
+) -> std::io::Result<usize> {
+    let cutoff = now_secs.saturating_sub(policy.max_age.as_secs());
+    let mut removed = 0;
+    for (i, entry) in list_crash_logs(log_dir)?.into_iter().enumerate() {
+        if i < policy.max_count && entry.at_secs >= cutoff {
+            continue;
+        }
+        match std::fs::remove_file(&entry.path) {
+            Ok(()) => removed += 1,
+            Err(e) => tracing::warn!("[WATCHDOG] failed to prune {}: {e}", entry.path.display()),
+        }
+    }
+    if removed > 0 {
+        tracing::info!("[WATCHDOG] pruned {removed} crash snapshot(s)");
+    }
+    Ok(removed)
+}
 
-    let lines = buffer.last_n(CRASH_SNAPSHOT_LINES);
-    let code_str = exit_code
-        .map(|c| c.to_string())
-        .unwrap_or_else(|| "unknown".to_string());
-    let pid_str = pid
-        .map(|p| p.to_string())
-        .unwrap_or_else(|| "unknown".to_string());
+// ── Reading ──────────────────────────────────────────────────
 
-    let header = format!(
-        "[DAEMON_CRASH] exit_code={code_str} pid={pid_str} retry={retry_count}/3\n\
-         --- stderr snapshot ({} lines) ---\n",
-        lines.len()
-    );
+/// A snapshot file found by [`list_crash_logs`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrashLogEntry {
+    pub path: PathBuf,
+    /// Seconds since the Unix epoch, from the file name.
+    pub at_secs: u64,
+    /// Order among snapshots from the same second (the `-<n>` suffix).
+    pub seq: u32,
+    /// `.json` ([`CrashReport`]) rather than plain text.
+    pub structured: bool,
+}
 
-    let content = format!("{}{}", header, lines.join("\n"));
-    std::fs::write(&path, content)?;
+/// Contents of a snapshot.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CrashLog {
+    Structured(CrashReport),
+    Text(String),
+}
 
-    tracing::warn!("[WATCHDOG] crash snapshot written: {}", path.display());
+/// Snapshots in `log_dir`, newest first. A missing directory is empty.
+pub fn list_crash_logs(log_dir: &Path) -> std::io::Result<Vec<CrashLogEntry>> {
+    let dir = match std::fs::read_dir(log_dir) {
+        Ok(dir) => dir,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    let mut entries: Vec<CrashLogEntry> = dir
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path
+                .file_name()?
+                .to_str()?
+                .strip_prefix(CRASH_FILE_PREFIX)?;
+            let (secs, structured) = match name.strip_suffix(".json") {
+                Some(secs) => (secs, true),
+                None => (name.strip_suffix(".log")?, false),
+            };
+            let (secs, seq) = match secs.split_once('-') {
+                Some((secs, seq)) => (secs, seq.parse().ok()?),
+                None => (secs, 0),
+            };
+            let at_secs = secs.parse().ok()?;
+            Some(CrashLogEntry {
+                path,
+                at_secs,
+                seq,
+                structured,
+            })
+        })
+        .collect();
+    // Same second: structured first, it carries more.
+    entries.sort_by_key(|e| std::cmp::Reverse((e.at_secs, e.structured, e.seq)));
+    Ok(entries)
+}
 
-    Ok(path)
+pub fn read_crash_log(entry: &CrashLogEntry) -> std::io::Result<CrashLog> {
+    let content = std::fs::read_to_string(&entry.path)?;
+    if entry.structured {
+        serde_json::from_str(&content)
+            .map(CrashLog::Structured)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    } else {
+        Ok(CrashLog::Text(content))
+    }
 }
 
 #[cfg(test)]
@@ -149,22 +317,104 @@ mod tests {
     }
 
     #[test]
-    fn crash_snapshot_writes_file() {
-        let dir = std::env::temp_dir().join("bolt-test-crash-snapshot");
+    fn crash_report_round_trips() {
+        let dir =
+            std::env::temp_dir().join(format!("bolt-test-crash-report-{}", std::process::id()));
         let _ = std::fs::remove_dir_all(&dir);
         let buf = StderrBuffer::new(10);
-        buf.push("error line 1".into());
-        buf.push("error line 2".into());
-
-        let path = write_crash_snapshot(&buf, &dir, Some(1), Some(1234), 2).unwrap();
-        assert!(path.exists());
-
-        let content = std::fs::read_to_string(&path).unwrap();
-        assert!(content.contains("[DAEMON_CRASH]"));
-        assert!(content.contains("exit_code=1"));
-        assert!(content.contains("pid=1234"));
-        assert!(content.contains("retry=2/3"));
-        assert!(content.contains("error line 1"));
+        buf.push("panicked at transport".into());
+
+        let mut report = CrashReport::capture(&buf);
+        report.exit_code = Some(101);
+        report.signal = Some(11);
+        report.uptime_ms = Some(4_200);
+        report.retry_count = 1;
+        report.watchdog_transitions = vec![StateChange {
+            at_ms: report.at_ms,
+            from: WatchdogState::Ready,
+            to: WatchdogState::Restarting,
+            detail: Some("retry 1".into()),
+        }];
+        write_crash_report(&report, &dir).unwrap();
+        // Plain-text snapshot as written by earlier versions.
+        std::fs::write(
+            dir.join(format!("daemon-crash-{}.log", report.at_ms / 1000)),
+            "[DAEMON_CRASH] exit_code=101",
+        )
+        .unwrap();
+
+        let entries = list_crash_logs(&dir).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert!(entries[0].structured);
+        assert_eq!(
+            read_crash_log(&entries[0]).unwrap(),
+            CrashLog::Structured(report)
+        );
+        match read_crash_log(&entries[1]).unwrap() {
+            CrashLog::Text(text) => assert!(text.contains("exit_code=101")),
+            other => panic!("expected text snapshot, got {other:?}"),
+        }
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn same_second_reports_are_all_kept() {
+        let dir = std::env::temp_dir().join(format!("bolt-test-crash-same-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let reports: Vec<CrashReport> = (0..3)
+            .map(|i| CrashReport {
+                at_ms: 1_700_000_000_000 + i,
+                exit_code: Some(i as i32),
+                ..CrashReport::default()
+            })
+            .collect();
+        let paths: Vec<PathBuf> = reports
+            .iter()
+            .map(|r| write_crash_report(r, &dir).unwrap())
+            .collect();
+        assert!(paths[2].ends_with("daemon-crash-1700000000-2.json"));
+
+        let entries = list_crash_logs(&dir).unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(
+            entries.iter().map(|e| e.seq).collect::<Vec<_>>(),
+            vec![2, 1, 0]
+        );
+        for (entry, report) in entries.iter().zip(reports.iter().rev()) {
+            assert_eq!(
+                read_crash_log(entry).unwrap(),
+                CrashLog::Structured(report.clone())
+            );
+        }
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn prune_enforces_count_and_age() {
+        let dir =
+            std::env::temp_dir().join(format!("bolt-test-crash-prune-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let now = 1_000_000;
+        for secs in [now - 10, now - 20, now - 30, now - 40, now - 5_000] {
+            std::fs::write(dir.join(format!("daemon-crash-{secs}.log")), "x").unwrap();
+        }
+        std::fs::write(dir.join("notes.txt"), "keep").unwrap();
+
+        let policy = CrashLogPolicy {
+            max_count: 3,
+            max_age: Duration::from_secs(25),
+        };
+        assert_eq!(prune_crash_logs_at(&dir, &policy, now).unwrap(), 3);
+        let left: Vec<u64> = list_crash_logs(&dir)
+            .unwrap()
+            .iter()
+            .map(|e| e.at_secs)
+            .collect();
+        assert_eq!(left, vec![now - 10, now - 20]);
+        assert!(dir.join("notes.txt").exists());
 
         let _ = std::fs::remove_dir_all(&dir);
     }
@@ -0,0 +1,211 @@
+//! Decision acknowledgements.
+//!
+//! Every decision the app sends carries a unique message `id`; the daemon
+//! answers with a `decision.ack` event whose `decision_id` names it.
+//! [`PendingAcks`] holds one channel per in-flight decision and resolves it
+//! when the ack arrives, so a shell can poll ([`AckHandle::try_result`]) or
+//! block ([`AckHandle::wait`]) for a definite outcome. Losing the
+//! connection fails every pending decision instead of leaving it hanging.
+
+use std::collections::HashMap;
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::ipc_types::{DecisionAckPayload, IpcKind, IpcMessage};
+
+/// Wire `type` of the daemon's acknowledgement event.
+pub const DECISION_ACK: &str = "decision.ack";
+
+/// Outcome of a decision: the ack on success, the failure reason otherwise
+/// (negative ack, malformed ack, or lost connection).
+pub type AckResult = Result<DecisionAckPayload, String>;
+
+/// In-flight decisions, keyed by decision message `id`. Cheap to clone;
+/// clones share the same table.
+#[derive(Clone, Default)]
+pub struct PendingAcks {
+    inner: Arc<Mutex<HashMap<String, mpsc::Sender<AckResult>>>>,
+}
+
+impl PendingAcks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register decision `id` before it is written.
+    pub fn register(&self, id: &str) -> AckHandle {
+        let (tx, rx) = mpsc::channel();
+        self.inner.lock().unwrap().insert(id.to_string(), tx);
+        AckHandle {
+            id: id.to_string(),
+            rx,
+        }
+    }
+
+    /// Drop a registration whose decision was never sent.
+    pub fn forget(&self, id: &str) {
+        self.inner.lock().unwrap().remove(id);
+    }
+
+    /// Resolve the pending decision named by a `decision.ack` event.
+    /// Returns `true` when `msg` is an ack (known or not), `false` for any
+    /// other message.
+    pub fn resolve(&self, msg: &IpcMessage) -> bool {
+        if msg.kind != IpcKind::Event || msg.msg_type != DECISION_ACK {
+            return false;
+        }
+        let ack: DecisionAckPayload = match serde_json::from_value(msg.payload.clone()) {
+            Ok(ack) => ack,
+            Err(e) => {
+                tracing::debug!("[IPC_ACK] malformed ack: {e}");
+                return true;
+            }
+        };
+        let Some(tx) = self.inner.lock().unwrap().remove(&ack.decision_id) else {
+            tracing::debug!("[IPC_ACK] ack for unknown decision {}", ack.decision_id);
+            return true;
+        };
+        let result = if ack.ok {
+            Ok(ack)
+        } else {
+            Err(ack
+                .error
+                .unwrap_or_else(|| "rejected by daemon".to_string()))
+        };
+        let _ = tx.send(result);
+        true
+    }
+
+    /// Fail every pending decision with `reason`.
+    pub fn fail_all(&self, reason: &str) {
+        for (_, tx) in self.inner.lock().unwrap().drain() {
+            let _ = tx.send(Err(reason.to_string()));
+        }
+    }
+
+    /// Number of decisions still awaiting an ack.
+    pub fn len(&self) -> usize {
+        self.inner.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Receiving end for one decision's outcome. The outcome is delivered
+/// once; later calls report the channel as closed.
+pub struct AckHandle {
+    id: String,
+    rx: mpsc::Receiver<AckResult>,
+}
+
+impl AckHandle {
+    /// The decision message `id`.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Non-blocking poll: `None` while the decision is still pending.
+    pub fn try_result(&self) -> Option<AckResult> {
+        match self.rx.try_recv() {
+            Ok(result) => Some(result),
+            Err(mpsc::TryRecvError::Empty) => None,
+            Err(mpsc::TryRecvError::Disconnected) => Some(Err(closed())),
+        }
+    }
+
+    /// Block until the outcome arrives or `timeout` elapses.
+    pub fn wait(&self, timeout: Duration) -> AckResult {
+        match self.rx.recv_timeout(timeout) {
+            Ok(result) => result,
+            Err(mpsc::RecvTimeoutError::Timeout) => Err(format!(
+                "no acknowledgement within {}ms",
+                timeout.as_millis()
+            )),
+            Err(mpsc::RecvTimeoutError::Disconnected) => Err(closed()),
+        }
+    }
+}
+
+fn closed() -> String {
+    "acknowledgement channel closed".to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ack(payload: serde_json::Value) -> IpcMessage {
+        IpcMessage {
+            id: "evt-9".into(),
+            kind: IpcKind::Event,
+            msg_type: DECISION_ACK.into(),
+            ts_ms: 0,
+            payload,
+        }
+    }
+
+    #[test]
+    fn positive_ack_resolves_matching_handle() {
+        let acks = PendingAcks::new();
+        let a = acks.register("app-1");
+        let b = acks.register("app-2");
+        assert!(a.try_result().is_none());
+
+        assert!(acks.resolve(&ack(serde_json::json!({
+            "decision_id": "app-1", "ok": true, "transfer_id": "t-7"
+        }))));
+        let result = a.try_result().unwrap().unwrap();
+        assert_eq!(result.transfer_id.as_deref(), Some("t-7"));
+        assert!(b.try_result().is_none());
+        assert_eq!(acks.len(), 1);
+    }
+
+    #[test]
+    fn negative_ack_is_an_error() {
+        let acks = PendingAcks::new();
+        let h = acks.register("app-1");
+        acks.resolve(&ack(serde_json::json!({
+            "decision_id": "app-1", "ok": false, "error": "file not found"
+        })));
+        assert_eq!(
+            h.wait(Duration::from_secs(1)).unwrap_err(),
+            "file not found"
+        );
+    }
+
+    #[test]
+    fn other_messages_are_not_acks() {
+        let acks = PendingAcks::new();
+        let _h = acks.register("app-1");
+        let mut msg = ack(serde_json::json!({"decision_id": "app-1", "ok": true}));
+        msg.msg_type = "transfer.progress".into();
+        assert!(!acks.resolve(&msg));
+        assert_eq!(acks.len(), 1);
+    }
+
+    #[test]
+    fn fail_all_resolves_every_pending_decision() {
+        let acks = PendingAcks::new();
+        let a = acks.register("app-1");
+        let b = acks.register("app-2");
+        acks.fail_all("bridge disconnected");
+        assert_eq!(a.try_result().unwrap().unwrap_err(), "bridge disconnected");
+        assert_eq!(b.try_result().unwrap().unwrap_err(), "bridge disconnected");
+        assert!(acks.is_empty());
+    }
+
+    #[test]
+    fn wait_times_out_and_forget_closes() {
+        let acks = PendingAcks::new();
+        let h = acks.register("app-1");
+        assert!(h
+            .wait(Duration::from_millis(10))
+            .unwrap_err()
+            .contains("no acknowledgement"));
+        acks.forget("app-1");
+        assert!(h.try_result().unwrap().is_err());
+    }
+}
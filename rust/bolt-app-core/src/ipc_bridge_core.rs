@@ -1,20 +1,24 @@
 //! Shell-agnostic IPC bridge for daemon event forwarding.
 //!
-//! Persistent connection to daemon: version handshake, event dispatch
-//! via callback, decision relay. No Tauri dependency.
+//! Persistent connection to daemon: version handshake, typed event
+//! dispatch ([`DaemonEvent`]) via callback or channel, decision relay. Transfer commands return an
+//! [`AckHandle`] resolved by the daemon's `decision.ack`. No Tauri
+//! dependency.
 //!
 //! Extracted from localbolt-app ipc_bridge.rs (NATIVE-APP-CORE-1).
 
 use std::io::{BufRead, BufReader, Write};
 use std::path::Path;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, Mutex};
+use std::sync::{mpsc, Arc, Mutex};
 use std::time::Duration;
 
+use crate::daemon_events::DaemonEvent;
+use crate::ipc_ack::{AckHandle, PendingAcks};
 use crate::ipc_transport::IpcStream;
 use crate::ipc_types::{
-    DaemonStatusPayload, IpcKind, IpcMessage, PairingRequestPayload,
-    TransferIncomingRequestPayload, VersionHandshakePayload, VersionStatusPayload,
+    DaemonStatusPayload, IpcKind, IpcMessage, TransferCommand, VersionHandshakePayload,
+    VersionStatusPayload,
 };
 
 /// Read timeout during handshake phase.
@@ -27,11 +31,16 @@ const EVENT_LOOP_TIMEOUT: Duration = Duration::from_secs(5);
 /// Arguments: (event_name, payload_json).
 pub type EventCallback = Box<dyn Fn(&str, serde_json::Value) + Send + Sync + 'static>;
 
+/// Channel end receiving parsed events (see [`IpcBridgeCore::subscribe`]).
+type EventSender = Arc<Mutex<Option<mpsc::Sender<DaemonEvent>>>>;
+
 /// Shell-agnostic IPC bridge.
 pub struct IpcBridgeCore {
     writer: Arc<Mutex<Option<IpcStream>>>,
     shutdown: Arc<AtomicBool>,
     event_callback: Arc<Mutex<Option<EventCallback>>>,
+    event_tx: EventSender,
+    acks: PendingAcks,
 }
 
 impl IpcBridgeCore {
@@ -40,6 +49,8 @@ impl IpcBridgeCore {
             writer: Arc::new(Mutex::new(None)),
             shutdown: Arc::new(AtomicBool::new(false)),
             event_callback: Arc::new(Mutex::new(None)),
+            event_tx: Arc::new(Mutex::new(None)),
+            acks: PendingAcks::new(),
         }
     }
 
@@ -49,6 +60,15 @@ impl IpcBridgeCore {
         *self.event_callback.lock().unwrap() = Some(cb);
     }
 
+    /// Receive typed events on a channel, for shells that render from
+    /// [`DaemonEvent`]s rather than shell event names. Runs alongside the
+    /// callback; a later call replaces the earlier receiver.
+    pub fn subscribe(&self) -> mpsc::Receiver<DaemonEvent> {
+        let (tx, rx) = mpsc::channel();
+        *self.event_tx.lock().unwrap() = Some(tx);
+        rx
+    }
+
     /// Establish persistent connection and start event forwarding.
     pub fn start(&self, socket_path: &Path, app_version: &str) -> Result<(), String> {
         let stream =
@@ -119,6 +139,9 @@ impl IpcBridgeCore {
             .map_err(|e| format!("parse daemon.status: {e}"))?;
         if ds_msg.msg_type == "daemon.status" {
             if let Ok(payload) = serde_json::from_value::<DaemonStatusPayload>(ds_msg.payload) {
+                if let Some(ref tx) = *self.event_tx.lock().unwrap() {
+                    let _ = tx.send(DaemonEvent::Status(payload.clone()));
+                }
                 self.emit_event("daemon://status-update", serde_json::to_value(&payload).unwrap_or_default());
                 tracing::info!(
                     "[IPC_BRIDGE] initial status: peers={}",
@@ -133,8 +156,11 @@ impl IpcBridgeCore {
 
         let shutdown = Arc::clone(&self.shutdown);
         let event_cb = Arc::clone(&self.event_callback);
+        let event_tx = Arc::clone(&self.event_tx);
+        let acks = self.acks.clone();
         std::thread::spawn(move || {
-            Self::event_loop(reader, &event_cb, &shutdown);
+            Self::event_loop(reader, &event_cb, &event_tx, &acks, &shutdown);
+            acks.fail_all("bridge disconnected");
             tracing::info!("[IPC_BRIDGE] reader thread exiting");
         });
 
@@ -144,6 +170,8 @@ impl IpcBridgeCore {
     fn event_loop(
         mut reader: BufReader<IpcStream>,
         event_cb: &Arc<Mutex<Option<EventCallback>>>,
+        event_tx: &EventSender,
+        acks: &PendingAcks,
         shutdown: &AtomicBool,
     ) {
         let mut buf = String::new();
@@ -161,7 +189,7 @@ impl IpcBridgeCore {
                     break;
                 }
                 Ok(_) => {
-                    Self::dispatch_event(event_cb, buf.trim());
+                    Self::dispatch_event(event_cb, event_tx, acks, buf.trim());
                 }
                 Err(ref e)
                     if e.kind() == std::io::ErrorKind::WouldBlock
@@ -180,7 +208,12 @@ impl IpcBridgeCore {
         }
     }
 
-    fn dispatch_event(event_cb: &Arc<Mutex<Option<EventCallback>>>, line: &str) {
+    fn dispatch_event(
+        event_cb: &Arc<Mutex<Option<EventCallback>>>,
+        event_tx: &EventSender,
+        acks: &PendingAcks,
+        line: &str,
+    ) {
         let msg: IpcMessage = match serde_json::from_str(line) {
             Ok(m) => m,
             Err(e) => {
@@ -194,52 +227,44 @@ impl IpcBridgeCore {
             return;
         }
 
-        let cb_guard = event_cb.lock().unwrap();
-        let Some(ref cb) = *cb_guard else { return };
+        // Resolve before the callback check: acks matter without a shell
+        // listener too.
+        acks.resolve(&msg);
 
-        match msg.msg_type.as_str() {
-            "daemon.status" => {
-                if let Ok(payload) = serde_json::from_value::<DaemonStatusPayload>(msg.payload) {
-                    cb("daemon://status-update", serde_json::to_value(&payload).unwrap_or_default());
-                }
-            }
-            "pairing.request" => {
-                if let Ok(payload) = serde_json::from_value::<PairingRequestPayload>(msg.payload) {
-                    tracing::info!(
-                        "[IPC_BRIDGE] pairing request from {}",
-                        payload.remote_device_name
-                    );
-                    cb("daemon://pairing-request", serde_json::to_value(&payload).unwrap_or_default());
-                }
-            }
-            "transfer.incoming.request" => {
-                if let Ok(payload) =
-                    serde_json::from_value::<TransferIncomingRequestPayload>(msg.payload)
-                {
-                    tracing::info!(
-                        "[IPC_BRIDGE] transfer request: {} ({} bytes)",
-                        payload.file_name,
-                        payload.file_size_bytes
-                    );
-                    cb("daemon://transfer-request", serde_json::to_value(&payload).unwrap_or_default());
-                }
+        let event = match DaemonEvent::from_message(&msg) {
+            Ok(Some(event)) => event,
+            Ok(None) => {
+                tracing::debug!("[IPC_BRIDGE] unhandled event type: {}", msg.msg_type);
+                return;
             }
-            // Session lifecycle events
-            "session.connected" | "session.sas" | "session.ended" | "session.error" => {
-                let event_name = format!("daemon://{}", msg.msg_type.replace('.', "-"));
-                tracing::info!("[IPC_BRIDGE] session event: {}", msg.msg_type);
-                cb(&event_name, msg.payload);
+            Err(e) => {
+                tracing::debug!("[IPC_BRIDGE] {e}");
+                return;
             }
-            // Transfer lifecycle events
-            "transfer.started" | "transfer.progress" | "transfer.complete" | "transfer.error" => {
-                let event_name = format!("daemon://{}", msg.msg_type.replace('.', "-"));
-                tracing::info!("[IPC_BRIDGE] transfer event: {}", msg.msg_type);
-                cb(&event_name, msg.payload);
+        };
+        match &event {
+            DaemonEvent::PairingRequest(p) => {
+                tracing::info!("[IPC_BRIDGE] pairing request from {}", p.remote_device_name);
             }
-            other => {
-                tracing::debug!("[IPC_BRIDGE] unhandled event type: {other}");
+            DaemonEvent::TransferIncomingRequest(p) => {
+                tracing::info!(
+                    "[IPC_BRIDGE] transfer request: {} ({} bytes)",
+                    p.file_name,
+                    p.file_size_bytes
+                );
             }
+            DaemonEvent::Status(_)
+            | DaemonEvent::TransferProgress(_)
+            | DaemonEvent::DecisionAck(_) => {}
+            other => tracing::info!("[IPC_BRIDGE] event: {}", other.msg_type()),
+        }
+
+        if let Some(ref tx) = *event_tx.lock().unwrap() {
+            let _ = tx.send(event.clone());
         }
+        let cb_guard = event_cb.lock().unwrap();
+        let Some(ref cb) = *cb_guard else { return };
+        cb(event.shell_event_name(), msg.payload);
     }
 
     fn emit_event(&self, name: &str, payload: serde_json::Value) {
@@ -262,6 +287,30 @@ impl IpcBridgeCore {
         Ok(())
     }
 
+    /// Send a transfer command. The returned handle resolves with the
+    /// daemon's `decision.ack`, or fails if the bridge disconnects first.
+    pub fn send_command(&self, command: &TransferCommand) -> Result<AckHandle, String> {
+        let msg = command.to_message();
+        let id = msg.id.clone();
+        let handle = self.acks.register(&id);
+        if let Err(e) = self.send_decision(msg) {
+            self.acks.forget(&id);
+            return Err(e);
+        }
+        Ok(handle)
+    }
+
+    /// Stop waiting for a command's ack, e.g. once the shell has given up
+    /// on it. A late ack is then ignored.
+    pub fn forget_command(&self, id: &str) {
+        self.acks.forget(id);
+    }
+
+    /// Number of commands still awaiting an ack.
+    pub fn pending_acks(&self) -> usize {
+        self.acks.len()
+    }
+
     pub fn is_connected(&self) -> bool {
         self.writer.lock().unwrap().is_some()
     }
@@ -269,6 +318,7 @@ impl IpcBridgeCore {
     pub fn shutdown(&self) {
         self.shutdown.store(true, Ordering::Relaxed);
         *self.writer.lock().unwrap() = None;
+        self.acks.fail_all("bridge shut down");
     }
 }
 
@@ -301,4 +351,144 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().contains("not connected"));
     }
+
+    #[test]
+    fn send_command_fails_when_not_connected() {
+        let bridge = IpcBridgeCore::new();
+        let cmd = TransferCommand::Cancel(crate::ipc_types::TransferControlPayload {
+            transfer_id: "t-1".into(),
+        });
+        assert!(bridge.send_command(&cmd).is_err());
+        assert_eq!(bridge.pending_acks(), 0);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn transfer_commands_resolve_with_daemon_acks() {
+        use crate::ipc_types::{DecisionAckPayload, TransferControlPayload, TransferSendPayload};
+        use std::os::unix::net::UnixListener;
+
+        let id = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path = std::path::PathBuf::from(format!("/tmp/bolt-test-bridge-{id}.sock"));
+        let listener = UnixListener::bind(&path).unwrap();
+
+        let event = |msg_type: &str, payload: serde_json::Value| {
+            IpcMessage {
+                id: "evt".into(),
+                kind: IpcKind::Event,
+                msg_type: msg_type.into(),
+                ts_ms: 0,
+                payload,
+            }
+            .to_ndjson()
+            .unwrap()
+        };
+        let daemon = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut writer = stream;
+            let mut read = || {
+                let mut buf = String::new();
+                reader.read_line(&mut buf).unwrap();
+                serde_json::from_str::<IpcMessage>(buf.trim()).unwrap()
+            };
+            assert_eq!(read().msg_type, "version.handshake");
+            let status = serde_json::json!({"daemon_version": "0.0.1", "compatible": true});
+            writer
+                .write_all(event("version.status", status).as_bytes())
+                .unwrap();
+            let ds =
+                serde_json::json!({"connected_peers": 1, "ui_connected": true, "version": "0.0.1"});
+            writer
+                .write_all(event("daemon.status", ds).as_bytes())
+                .unwrap();
+
+            let send = read();
+            assert_eq!(send.msg_type, "transfer.send");
+            assert_eq!(send.payload["path"], "/tmp/a.bin");
+            let ok = DecisionAckPayload {
+                decision_id: send.id,
+                ok: true,
+                transfer_id: Some("t-1".into()),
+                error: None,
+            };
+            let ok = serde_json::to_value(ok).unwrap();
+            writer
+                .write_all(event("decision.ack", ok).as_bytes())
+                .unwrap();
+
+            let cancel = read();
+            assert_eq!(cancel.msg_type, "transfer.cancel");
+            let rejected = serde_json::json!({
+                "decision_id": cancel.id, "ok": false, "error": "unknown transfer"
+            });
+            writer
+                .write_all(event("decision.ack", rejected).as_bytes())
+                .unwrap();
+
+            // Third command is never answered: the hangup must fail it.
+            assert_eq!(read().msg_type, "transfer.pause");
+        });
+
+        let bridge = IpcBridgeCore::new();
+        let (tx, rx) = std::sync::mpsc::channel();
+        let tx = Mutex::new(tx);
+        bridge.set_event_callback(Box::new(move |name, _| {
+            let _ = tx.lock().unwrap().send(name.to_string());
+        }));
+        let typed = bridge.subscribe();
+        bridge.start(&path, "1.0.0").unwrap();
+
+        let send = bridge
+            .send_command(&TransferCommand::Send(TransferSendPayload {
+                path: "/tmp/a.bin".into(),
+                file_name: None,
+            }))
+            .unwrap();
+        let ack = send.wait(Duration::from_secs(5)).unwrap();
+        assert_eq!(ack.transfer_id.as_deref(), Some("t-1"));
+
+        let control = TransferControlPayload {
+            transfer_id: "t-9".into(),
+        };
+        let cancel = bridge
+            .send_command(&TransferCommand::Cancel(control.clone()))
+            .unwrap();
+        assert_eq!(
+            cancel.wait(Duration::from_secs(5)).unwrap_err(),
+            "unknown transfer"
+        );
+
+        let pause = bridge
+            .send_command(&TransferCommand::Pause(control))
+            .unwrap();
+        daemon.join().unwrap();
+        assert_eq!(
+            pause.wait(Duration::from_secs(15)).unwrap_err(),
+            "bridge disconnected"
+        );
+        assert_eq!(bridge.pending_acks(), 0);
+
+        let events: Vec<String> = rx.try_iter().collect();
+        assert_eq!(
+            events
+                .iter()
+                .filter(|e| *e == "daemon://decision-ack")
+                .count(),
+            2
+        );
+        let typed: Vec<DaemonEvent> = typed.try_iter().collect();
+        assert!(matches!(typed.first(), Some(DaemonEvent::Status(_))));
+        assert_eq!(
+            typed
+                .iter()
+                .filter(|e| matches!(e, DaemonEvent::DecisionAck(_)))
+                .count(),
+            2
+        );
+        let _ = std::fs::remove_file(&path);
+    }
 }
@@ -1,6 +1,8 @@
 //! IPC message types for daemon communication (app-side).
 //!
-//! Mirrors the subset of bolt-daemon's IPC contract needed for readiness probing.
+//! Mirrors the subset of bolt-daemon's IPC contract needed for readiness
+//! probing, the daemon event stream, pairing/transfer prompts and transfer
+//! control.
 //! Wire format: NDJSON (one JSON object per `\n`-terminated line).
 
 use serde::{Deserialize, Serialize};
@@ -92,6 +94,194 @@ pub struct TransferIncomingDecisionPayload {
     pub note: Option<String>,
 }
 
+// ── Payloads for the daemon event stream (daemon -> app) ──
+
+/// A daemon transport endpoint.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Endpoint {
+    /// WebSocket endpoint (`--ws-listen`).
+    Ws,
+    /// WebTransport endpoint.
+    Wt,
+}
+
+/// `endpoint.listening`: an endpoint accepts connections.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct EndpointListeningPayload {
+    pub endpoint: Endpoint,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+}
+
+/// `endpoint.wt_cert`: SHA-256 of the WebTransport self-signed certificate
+/// (64 lowercase hex chars), advertised to browsers as `certHash`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct WtCertPayload {
+    pub cert_hash_hex: String,
+}
+
+/// How the peer's HELLO authenticated.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum HelloMode {
+    /// No identity key: transfer is allowed without SAS verification.
+    Legacy,
+    /// Identity HELLO: transfer waits for SAS verification. Assumed when
+    /// the daemon omits the field.
+    #[default]
+    Identity,
+}
+
+/// `session.connected`: HELLO completed with a peer.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SessionConnectedPayload {
+    #[serde(default)]
+    pub negotiated_capabilities: Vec<String>,
+    #[serde(default)]
+    pub hello_mode: HelloMode,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transport: Option<Endpoint>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote_identity_pk_b64: Option<String>,
+}
+
+/// `session.sas`: short authentication string for an identity session.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SessionSasPayload {
+    pub sas: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote_identity_pk_b64: Option<String>,
+}
+
+/// `session.ended`: the peer disconnected or the session was closed.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SessionEndedPayload {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// `session.error`: the session failed.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SessionErrorPayload {
+    pub reason: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TransferDirection {
+    Send,
+    Receive,
+}
+
+/// `transfer.started`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TransferStartedPayload {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transfer_id: Option<String>,
+    pub file_name: String,
+    pub direction: TransferDirection,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_size_bytes: Option<u64>,
+}
+
+/// `transfer.progress`: `progress` is the completed fraction, 0.0..=1.0.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TransferProgressPayload {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transfer_id: Option<String>,
+    pub progress: f64,
+}
+
+/// `transfer.complete`: `save_path` is set for received files;
+/// `file_hash_hex` is the SHA-256 of the file when `bolt.file-hash` was
+/// negotiated.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TransferCompletePayload {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transfer_id: Option<String>,
+    pub file_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub save_path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_size_bytes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_hash_hex: Option<String>,
+}
+
+/// `transfer.error`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TransferErrorPayload {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transfer_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+    pub reason: String,
+}
+
+// ── Payloads for transfer control (app -> daemon) ─────────
+
+/// `transfer.send`: send a local file to the connected peer.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TransferSendPayload {
+    /// Absolute path of the file to send.
+    pub path: String,
+    /// Display name; the daemon uses the path's file name when absent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+}
+
+/// `transfer.cancel` / `transfer.pause` / `transfer.resume`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TransferControlPayload {
+    pub transfer_id: String,
+}
+
+/// A transfer decision, one per user action.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransferCommand {
+    Send(TransferSendPayload),
+    Cancel(TransferControlPayload),
+    Pause(TransferControlPayload),
+    Resume(TransferControlPayload),
+}
+
+impl TransferCommand {
+    /// Wire `type` of the decision message.
+    pub fn msg_type(&self) -> &'static str {
+        match self {
+            Self::Send(_) => "transfer.send",
+            Self::Cancel(_) => "transfer.cancel",
+            Self::Pause(_) => "transfer.pause",
+            Self::Resume(_) => "transfer.resume",
+        }
+    }
+
+    /// Build the decision message; its `id` correlates the daemon's ack.
+    pub fn to_message(&self) -> IpcMessage {
+        let payload = match self {
+            Self::Send(p) => serde_json::to_value(p),
+            Self::Cancel(p) | Self::Pause(p) | Self::Resume(p) => serde_json::to_value(p),
+        };
+        IpcMessage::new_decision(self.msg_type(), payload.unwrap_or_default())
+    }
+}
+
+/// `decision.ack` event: the daemon's answer to a decision, matched to it
+/// by `decision_id` (the decision message's `id`).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DecisionAckPayload {
+    pub decision_id: String,
+    pub ok: bool,
+    /// Transfer the decision created or acted on (`transfer.send` always
+    /// returns the new id on success).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transfer_id: Option<String>,
+    /// Failure reason when `ok` is false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
 // ── Helpers ────────────────────────────────────────────────
 
 fn now_ms() -> u64 {
@@ -109,6 +299,17 @@ fn generate_id() -> String {
 }
 
 impl IpcMessage {
+    /// Create an event message (daemon -> app). Used by mock daemons.
+    pub fn new_event(msg_type: &str, payload: serde_json::Value) -> Self {
+        Self {
+            id: generate_id(),
+            kind: IpcKind::Event,
+            msg_type: msg_type.to_string(),
+            ts_ms: now_ms(),
+            payload,
+        }
+    }
+
     /// Create a decision message (app -> daemon).
     pub fn new_decision(msg_type: &str, payload: serde_json::Value) -> Self {
         Self {
@@ -229,6 +430,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn transfer_commands_use_typed_payloads() {
+        let send = TransferCommand::Send(TransferSendPayload {
+            path: "/home/a/doc.pdf".into(),
+            file_name: None,
+        });
+        let msg = send.to_message();
+        assert_eq!(msg.kind, IpcKind::Decision);
+        assert_eq!(msg.msg_type, "transfer.send");
+        assert_eq!(msg.payload, serde_json::json!({"path": "/home/a/doc.pdf"}));
+
+        let control = TransferControlPayload {
+            transfer_id: "t-1".into(),
+        };
+        for (cmd, ty) in [
+            (TransferCommand::Cancel(control.clone()), "transfer.cancel"),
+            (TransferCommand::Pause(control.clone()), "transfer.pause"),
+            (TransferCommand::Resume(control), "transfer.resume"),
+        ] {
+            let msg = cmd.to_message();
+            assert_eq!(msg.msg_type, ty);
+            assert_eq!(msg.payload["transfer_id"], "t-1");
+        }
+    }
+
+    #[test]
+    fn transfer_command_ids_are_unique() {
+        let cmd = TransferCommand::Cancel(TransferControlPayload {
+            transfer_id: "t-1".into(),
+        });
+        assert_ne!(cmd.to_message().id, cmd.to_message().id);
+    }
+
+    #[test]
+    fn decision_ack_deserialize() {
+        let json = r#"{"decision_id":"app-3","ok":false,"error":"no connected peer"}"#;
+        let ack: DecisionAckPayload = serde_json::from_str(json).unwrap();
+        assert!(!ack.ok);
+        assert_eq!(ack.decision_id, "app-3");
+        assert!(ack.transfer_id.is_none());
+        assert_eq!(ack.error.as_deref(), Some("no connected peer"));
+    }
+
     #[test]
     fn extra_fields_preserved() {
         let json =
@@ -2,27 +2,46 @@
 //!
 //! Extracted from localbolt-app Tauri backend (NATIVE-APP-CORE-1).
 //! Zero Tauri dependency. Consumable by any Rust-based shell:
-//! egui desktop (bolt-ui), SwiftUI/Kotlin mobile (via UniFFI), or Tauri (transitional).
+//! egui desktop (bolt-ui), SwiftUI/Kotlin mobile (via UniFFI — protocol
+//! authority bindings live in bolt-ffi), or Tauri (transitional).
 //!
 //! # Modules
 //!
 //! - [`watchdog`] — Daemon process supervision state machine (N3 spec)
 //! - [`ipc_types`] — IPC message contract (NDJSON wire format)
+//! - [`ipc_ack`] — Decision acknowledgement correlation (`decision.ack`)
+//! - [`daemon_events`] — Typed daemon event stream (endpoints, session, transfer)
 //! - [`ipc_transport`] — Cross-platform IPC stream (Unix socket / Windows named pipe)
 //! - [`ipc_client`] — Daemon readiness probe (version handshake + status check)
 //! - [`platform`] — Platform-aware path defaults and process management
 //! - [`daemon_log`] — Stderr ring buffer and crash snapshot persistence
 //! - [`signal_monitor`] — Signal server health probe state machine (N8 spec)
 //! - [`contracts`] — Session/transfer state contract validators (v1)
+//! - [`transfer_queue`] — Outgoing transfer queue (ordering, one active transfer)
+//! - [`transfer_history`] — Persisted transfer history log
+//! - [`trusted_devices`] — Identity pin store (trusted devices, SAS verification record)
+//! - [`settings`] — Versioned user settings (validation, migration, change notifications)
+//! - [`state_history`] — Bounded state transition history (watchdog, signal monitor)
+//! - [`support_bundle`] — Redacted diagnostics archive (crash logs, stderr, state history)
+//! - [`peer_sessions`] — Concurrent peer sessions (per-peer verification, queue, daemon resources)
 
 pub mod contracts;
+pub mod daemon_events;
 pub mod daemon_lifecycle;
 pub mod daemon_log;
+pub mod ipc_ack;
 pub mod ipc_bridge_core;
 pub mod ipc_client;
 pub mod ipc_transport;
 pub mod ipc_types;
+pub mod peer_sessions;
 pub mod platform;
+pub mod settings;
 pub mod signal_monitor;
 pub mod signaling_client;
+pub mod state_history;
+pub mod support_bundle;
+pub mod transfer_history;
+pub mod transfer_queue;
+pub mod trusted_devices;
 pub mod watchdog;
@@ -0,0 +1,388 @@
+//! Concurrent peer sessions.
+//!
+//! A shell may be connected to several peers at once. [`PeerSessions`]
+//! keeps one [`PeerSession`] per peer code, each with its own contract
+//! phase and verification state (see [`crate::contracts::session_contract`]),
+//! its own [`TransferQueue`], and its own [`SessionResources`] so that
+//! per-session daemons never share a socket, data directory or listen port.
+//!
+//! The transport behind a session is shell-owned and opaque here: `T` is
+//! whatever the shell drives the session with — a spawned daemon plus its
+//! IPC client, or an in-process session.
+
+use std::path::PathBuf;
+
+use crate::contracts::session_contract::{
+    is_transfer_allowed, is_valid_session_transition, SessionPhase, VerificationState,
+};
+use crate::transfer_queue::TransferQueue;
+
+/// Shell-local session identifier, never reused within a [`PeerSessions`].
+pub type SessionId = u64;
+
+/// Default cap on concurrent sessions.
+pub const DEFAULT_MAX_SESSIONS: usize = 4;
+
+/// Listen ports reserved per session: WebSocket, then WebTransport.
+const PORTS_PER_SESSION: u16 = 2;
+
+/// Per-session daemon resources.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionResources {
+    /// Slot index; freed slots are reused by later sessions.
+    pub slot: usize,
+    pub socket_path: PathBuf,
+    pub data_dir: PathBuf,
+    /// WebSocket listen port. WebTransport listens on `ws_port + 1`.
+    pub ws_port: u16,
+}
+
+impl SessionResources {
+    pub fn wt_port(&self) -> u16 {
+        self.ws_port + 1
+    }
+}
+
+/// One peer session.
+#[derive(Debug)]
+pub struct PeerSession<T> {
+    pub id: SessionId,
+    pub peer_code: String,
+    pub peer_name: String,
+    phase: SessionPhase,
+    verification: VerificationState,
+    sas: Option<String>,
+    identity_pk_b64: Option<String>,
+    /// Files waiting for this peer and the transfer in progress.
+    pub queue: TransferQueue,
+    pub resources: SessionResources,
+    pub transport: T,
+}
+
+impl<T> PeerSession<T> {
+    pub fn phase(&self) -> SessionPhase {
+        self.phase
+    }
+
+    /// Move to `to`. Staying in the same phase is a no-op; transitions the
+    /// contract forbids are refused and leave the phase unchanged.
+    pub fn set_phase(&mut self, to: SessionPhase) -> Result<(), String> {
+        if self.phase == to {
+            return Ok(());
+        }
+        if !is_valid_session_transition(self.phase, to) {
+            return Err(format!(
+                "illegal session transition {:?} -> {to:?} (peer {})",
+                self.phase, self.peer_code
+            ));
+        }
+        tracing::info!("[SESSIONS] {} {:?} -> {to:?}", self.peer_code, self.phase);
+        if to == SessionPhase::Idle {
+            self.verification = VerificationState::Unverified;
+            self.sas = None;
+        }
+        self.phase = to;
+        Ok(())
+    }
+
+    pub fn verification(&self) -> VerificationState {
+        self.verification
+    }
+
+    /// SAS to compare while unverified, once the daemon has sent it.
+    pub fn sas(&self) -> Option<&str> {
+        self.sas.as_deref()
+    }
+
+    /// Identity key the peer presented, if any.
+    pub fn identity_pk_b64(&self) -> Option<&str> {
+        self.identity_pk_b64.as_deref()
+    }
+
+    /// The session came up without identity (legacy HELLO).
+    pub fn on_legacy(&mut self) {
+        self.verification = VerificationState::Legacy;
+        self.sas = None;
+    }
+
+    /// The peer presented `identity_pk_b64`; `trusted` if it was
+    /// SAS-verified in an earlier session.
+    pub fn on_identity(&mut self, identity_pk_b64: Option<String>, trusted: bool) {
+        if identity_pk_b64.is_some() {
+            self.identity_pk_b64 = identity_pk_b64;
+        }
+        self.verification = if trusted {
+            VerificationState::Verified
+        } else {
+            VerificationState::Unverified
+        };
+    }
+
+    /// The daemon derived the short authentication string.
+    pub fn on_sas(&mut self, sas: String, identity_pk_b64: Option<String>) {
+        if identity_pk_b64.is_some() {
+            self.identity_pk_b64 = identity_pk_b64;
+        }
+        if self.verification != VerificationState::Verified {
+            self.sas = Some(sas);
+        }
+    }
+
+    /// The user confirmed the SAS.
+    pub fn mark_verified(&mut self) {
+        self.verification = VerificationState::Verified;
+        self.sas = None;
+    }
+
+    /// Connected and verified (or legacy), per contract policy P1.
+    pub fn transfer_allowed(&self) -> bool {
+        is_transfer_allowed(self.phase == SessionPhase::Connected, self.verification)
+    }
+}
+
+/// All sessions of one shell, plus which one the user is looking at.
+#[derive(Debug)]
+pub struct PeerSessions<T> {
+    sessions: Vec<PeerSession<T>>,
+    selected: Option<SessionId>,
+    next_id: SessionId,
+    runtime_dir: PathBuf,
+    base_port: u16,
+    max_sessions: usize,
+}
+
+impl<T> PeerSessions<T> {
+    /// Sessions get `runtime_dir/session-<slot>.sock`, the data directory
+    /// `runtime_dir/session-<slot>` and ports from `base_port` upward.
+    pub fn new(runtime_dir: impl Into<PathBuf>, base_port: u16, max_sessions: usize) -> Self {
+        Self {
+            sessions: Vec::new(),
+            selected: None,
+            next_id: 1,
+            runtime_dir: runtime_dir.into(),
+            base_port,
+            max_sessions,
+        }
+    }
+
+    pub fn runtime_dir(&self) -> &std::path::Path {
+        &self.runtime_dir
+    }
+
+    /// Open a session with `peer_code` in `phase` (one of the contract's
+    /// phases leaving idle). Fails if the peer already has a session or
+    /// the cap is reached. The first session opened is selected.
+    pub fn open(
+        &mut self,
+        peer_code: &str,
+        peer_name: &str,
+        phase: SessionPhase,
+        transport: T,
+    ) -> Result<SessionId, String> {
+        if self.find_peer(peer_code).is_some() {
+            return Err(format!("already in a session with {peer_code}"));
+        }
+        if self.is_full() {
+            return Err(format!("at most {} sessions at a time", self.max_sessions));
+        }
+        if !is_valid_session_transition(SessionPhase::Idle, phase) {
+            return Err(format!("a session cannot open in {phase:?}"));
+        }
+
+        let slot = (0..)
+            .find(|slot| !self.sessions.iter().any(|s| s.resources.slot == *slot))
+            .expect("unbounded range");
+        let resources = SessionResources {
+            slot,
+            socket_path: self.runtime_dir.join(format!("session-{slot}.sock")),
+            data_dir: self.runtime_dir.join(format!("session-{slot}")),
+            ws_port: self.base_port + slot as u16 * PORTS_PER_SESSION,
+        };
+        let id = self.next_id;
+        self.next_id += 1;
+        tracing::info!("[SESSIONS] open {id} with {peer_code} ({phase:?}, slot {slot})");
+        self.sessions.push(PeerSession {
+            id,
+            peer_code: peer_code.to_string(),
+            peer_name: peer_name.to_string(),
+            phase,
+            verification: VerificationState::Unverified,
+            sas: None,
+            identity_pk_b64: None,
+            queue: TransferQueue::new(),
+            resources,
+            transport,
+        });
+        if self.selected.is_none() {
+            self.selected = Some(id);
+        }
+        Ok(id)
+    }
+
+    /// Remove a session and hand it back so the shell can stop its
+    /// transport. Selection moves to the most recent remaining session.
+    pub fn close(&mut self, id: SessionId) -> Option<PeerSession<T>> {
+        let index = self.sessions.iter().position(|s| s.id == id)?;
+        let session = self.sessions.remove(index);
+        tracing::info!("[SESSIONS] close {id} ({})", session.peer_code);
+        if self.selected == Some(id) {
+            self.selected = self.sessions.last().map(|s| s.id);
+        }
+        Some(session)
+    }
+
+    pub fn get(&self, id: SessionId) -> Option<&PeerSession<T>> {
+        self.sessions.iter().find(|s| s.id == id)
+    }
+
+    pub fn get_mut(&mut self, id: SessionId) -> Option<&mut PeerSession<T>> {
+        self.sessions.iter_mut().find(|s| s.id == id)
+    }
+
+    /// Session with `peer_code`, if any.
+    pub fn find_peer(&self, peer_code: &str) -> Option<SessionId> {
+        self.sessions
+            .iter()
+            .find(|s| s.peer_code == peer_code)
+            .map(|s| s.id)
+    }
+
+    /// Sessions in the order they were opened.
+    pub fn iter(&self) -> impl Iterator<Item = &PeerSession<T>> {
+        self.sessions.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut PeerSession<T>> {
+        self.sessions.iter_mut()
+    }
+
+    pub fn ids(&self) -> Vec<SessionId> {
+        self.sessions.iter().map(|s| s.id).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.sessions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sessions.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.sessions.len() >= self.max_sessions
+    }
+
+    /// Show session `id`, or no session with `None`. Returns false for an
+    /// unknown id.
+    pub fn select(&mut self, id: Option<SessionId>) -> bool {
+        if id.is_some_and(|id| self.get(id).is_none()) {
+            return false;
+        }
+        self.selected = id;
+        true
+    }
+
+    pub fn selected_id(&self) -> Option<SessionId> {
+        self.selected
+    }
+
+    pub fn selected(&self) -> Option<&PeerSession<T>> {
+        self.get(self.selected?)
+    }
+
+    pub fn selected_mut(&mut self) -> Option<&mut PeerSession<T>> {
+        self.get_mut(self.selected?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sessions(max: usize) -> PeerSessions<()> {
+        PeerSessions::new("/tmp/bolt-test-sessions", 9100, max)
+    }
+
+    #[test]
+    fn sessions_get_distinct_resources_and_reuse_slots() {
+        let mut s = sessions(3);
+        let a = s.open("AAAAAA", "a", SessionPhase::Requesting, ()).unwrap();
+        let b = s
+            .open("BBBBBB", "b", SessionPhase::IncomingRequest, ())
+            .unwrap();
+        let (ra, rb) = (
+            s.get(a).unwrap().resources.clone(),
+            s.get(b).unwrap().resources.clone(),
+        );
+        assert_ne!(ra.socket_path, rb.socket_path);
+        assert_ne!(ra.data_dir, rb.data_dir);
+        assert_eq!((ra.ws_port, ra.wt_port()), (9100, 9101));
+        assert_eq!(rb.ws_port, 9102);
+
+        s.close(a).unwrap();
+        let c = s.open("CCCCCC", "c", SessionPhase::Requesting, ()).unwrap();
+        assert_ne!(c, a);
+        assert_eq!(s.get(c).unwrap().resources, ra);
+    }
+
+    #[test]
+    fn open_refuses_duplicates_capacity_and_bad_phase() {
+        let mut s = sessions(2);
+        s.open("AAAAAA", "a", SessionPhase::Requesting, ()).unwrap();
+        assert!(s.open("AAAAAA", "a", SessionPhase::Requesting, ()).is_err());
+        assert!(s.open("BBBBBB", "b", SessionPhase::Connected, ()).is_err());
+        s.open("BBBBBB", "b", SessionPhase::Requesting, ()).unwrap();
+        assert!(s.is_full());
+        assert!(s.open("CCCCCC", "c", SessionPhase::Requesting, ()).is_err());
+        assert_eq!(s.len(), 2);
+    }
+
+    #[test]
+    fn selection_follows_open_and_close() {
+        let mut s = sessions(4);
+        let a = s.open("AAAAAA", "a", SessionPhase::Requesting, ()).unwrap();
+        let b = s.open("BBBBBB", "b", SessionPhase::Requesting, ()).unwrap();
+        assert_eq!(s.selected_id(), Some(a));
+        assert!(s.select(Some(b)));
+        assert!(!s.select(Some(99)));
+        s.close(b);
+        assert_eq!(s.selected_id(), Some(a));
+        assert!(s.select(None));
+        assert!(s.selected().is_none());
+        s.close(a);
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn verification_is_per_session_and_gates_transfer() {
+        let mut s = sessions(4);
+        let a = s.open("AAAAAA", "a", SessionPhase::Requesting, ()).unwrap();
+        let b = s
+            .open("BBBBBB", "b", SessionPhase::IncomingRequest, ())
+            .unwrap();
+        for id in [a, b] {
+            let session = s.get_mut(id).unwrap();
+            session.set_phase(SessionPhase::Connecting).unwrap();
+            session.set_phase(SessionPhase::Connected).unwrap();
+        }
+
+        let sa = s.get_mut(a).unwrap();
+        sa.on_identity(Some("key-a".into()), false);
+        sa.on_sas("A1B2C3".into(), None);
+        assert!(!sa.transfer_allowed());
+        assert_eq!(sa.sas(), Some("A1B2C3"));
+        sa.mark_verified();
+        assert!(sa.transfer_allowed());
+
+        let sb = s.get_mut(b).unwrap();
+        assert!(!sb.transfer_allowed());
+        sb.on_legacy();
+        assert!(sb.transfer_allowed());
+
+        let sa = s.get_mut(a).unwrap();
+        assert!(sa.set_phase(SessionPhase::Requesting).is_err());
+        sa.set_phase(SessionPhase::Idle).unwrap();
+        assert_eq!(sa.verification(), VerificationState::Unverified);
+        assert_eq!(sa.identity_pk_b64(), Some("key-a"));
+    }
+}
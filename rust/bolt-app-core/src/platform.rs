@@ -70,6 +70,30 @@ pub fn default_data_dir() -> String {
     "/tmp/localbolt-daemon-data".to_string()
 }
 
+/// App data directory: the parent of [`default_data_dir`].
+pub fn app_data_dir() -> PathBuf {
+    let daemon_dir = PathBuf::from(default_data_dir());
+    match daemon_dir.parent() {
+        Some(parent) if daemon_dir.ends_with("daemon") => parent.to_path_buf(),
+        _ => daemon_dir,
+    }
+}
+
+/// Transfer history log (NDJSON), see [`crate::transfer_history`].
+pub fn transfer_history_path() -> PathBuf {
+    app_data_dir().join("transfer-history.jsonl")
+}
+
+/// Trusted devices pin store, see [`crate::trusted_devices`].
+pub fn trusted_devices_path() -> PathBuf {
+    app_data_dir().join("trusted-devices.json")
+}
+
+/// Settings file, see [`crate::settings`].
+pub fn settings_path() -> PathBuf {
+    PathBuf::from(default_data_dir()).join(crate::settings::SETTINGS_FILE)
+}
+
 /// Crash log directory for daemon crash snapshots.
 pub fn crash_log_dir() -> PathBuf {
     #[cfg(target_os = "macos")]
@@ -168,6 +192,19 @@ mod tests {
         assert!(!default_data_dir().is_empty());
     }
 
+    #[test]
+    fn transfer_history_path_nonempty() {
+        assert!(transfer_history_path().ends_with("transfer-history.jsonl"));
+    }
+
+    #[test]
+    fn settings_path_is_in_data_dir() {
+        assert_eq!(
+            settings_path().parent().unwrap(),
+            std::path::Path::new(&default_data_dir())
+        );
+    }
+
     #[test]
     fn crash_log_dir_nonempty() {
         assert!(!crash_log_dir().as_os_str().is_empty());
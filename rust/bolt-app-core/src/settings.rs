@@ -0,0 +1,590 @@
+//! Persistent app settings shared by all shells.
+//!
+//! Stored as JSON at [`crate::platform::settings_path`]. The file carries a
+//! schema `version`; [`SettingsStore::load`] refuses files written by a
+//! newer app rather than silently dropping fields it does not know. Every
+//! write goes through [`SettingsStore::update`], which validates, saves
+//! atomically and then notifies subscribers with the previous and new
+//! values. A store whose file failed to load is read-only, so the file is
+//! never overwritten with defaults.
+//!
+//! `BOLT_RENDEZVOUS_URL` and `BOLT_CLOUD_SIGNAL_URL` still override the
+//! stored addresses at runtime (see [`Settings::effective_rendezvous_addr`]);
+//! overrides are never written back to the file.
+
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+
+use serde::{Deserialize, Serialize};
+
+/// Current settings schema version.
+pub const SETTINGS_VERSION: u32 = 1;
+
+/// Settings file name inside [`crate::platform::default_data_dir`].
+pub const SETTINGS_FILE: &str = "settings.json";
+
+/// Longest accepted device name, in characters.
+pub const MAX_DEVICE_NAME_CHARS: usize = 64;
+
+pub const DEFAULT_RENDEZVOUS_ADDR: &str = "127.0.0.1:3001";
+pub const DEFAULT_CLOUD_SIGNAL_URL: &str = "wss://bolt-rendezvous.fly.dev";
+
+/// Environment override for [`Settings::rendezvous_addr`].
+pub const RENDEZVOUS_ENV: &str = "BOLT_RENDEZVOUS_URL";
+/// Environment override for [`Settings::cloud_signal_url`].
+pub const CLOUD_SIGNAL_ENV: &str = "BOLT_CLOUD_SIGNAL_URL";
+
+/// How the daemon treats pairing requests from unknown peers
+/// (`--pairing-policy`).
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PairingPolicy {
+    /// Surface a `pairing.request` event and wait for the user.
+    Ask,
+    /// Accept without prompting (SAS verification still applies).
+    #[default]
+    Allow,
+    /// Reject every unknown peer.
+    Deny,
+}
+
+impl PairingPolicy {
+    pub const ALL: [PairingPolicy; 3] = [Self::Ask, Self::Allow, Self::Deny];
+
+    /// Value passed to the daemon's `--pairing-policy` flag.
+    pub fn as_arg(self) -> &'static str {
+        match self {
+            Self::Ask => "ask",
+            Self::Allow => "allow",
+            Self::Deny => "deny",
+        }
+    }
+}
+
+/// User-editable settings (schema [`SETTINGS_VERSION`]).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct Settings {
+    pub version: u32,
+    /// Name shown to peers. `None` uses the host name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_name: Option<String>,
+    /// Local rendezvous server, `host:port` or a `ws://`/`wss://` URL.
+    pub rendezvous_addr: String,
+    /// Also register with the cloud signaling server.
+    pub cloud_signaling: bool,
+    pub cloud_signal_url: String,
+    /// Where received files should be saved. Stored only: the daemon has
+    /// no download folder flag yet, so this is not part of
+    /// [`Settings::daemon_args`] and the daemon default applies.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub download_dir: Option<PathBuf>,
+    pub pairing_policy: PairingPolicy,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            version: SETTINGS_VERSION,
+            device_name: None,
+            rendezvous_addr: DEFAULT_RENDEZVOUS_ADDR.to_string(),
+            cloud_signaling: true,
+            cloud_signal_url: DEFAULT_CLOUD_SIGNAL_URL.to_string(),
+            download_dir: None,
+            pairing_policy: PairingPolicy::default(),
+        }
+    }
+}
+
+/// Settings field that failed validation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SettingsField {
+    DeviceName,
+    RendezvousAddr,
+    CloudSignalUrl,
+    DownloadDir,
+}
+
+/// One validation failure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    pub field: SettingsField,
+    pub message: String,
+}
+
+impl FieldError {
+    fn new(field: SettingsField, message: impl Into<String>) -> Self {
+        Self {
+            field,
+            message: message.into(),
+        }
+    }
+}
+
+impl Settings {
+    /// Check every field. Returns all failures, not just the first.
+    pub fn validate(&self) -> Result<(), Vec<FieldError>> {
+        let mut errors = Vec::new();
+
+        if let Some(name) = &self.device_name {
+            if name.trim().is_empty() {
+                errors.push(FieldError::new(
+                    SettingsField::DeviceName,
+                    "device name is empty",
+                ));
+            } else if name.chars().count() > MAX_DEVICE_NAME_CHARS {
+                errors.push(FieldError::new(
+                    SettingsField::DeviceName,
+                    format!("device name is longer than {MAX_DEVICE_NAME_CHARS} characters"),
+                ));
+            } else if name.chars().any(char::is_control) {
+                errors.push(FieldError::new(
+                    SettingsField::DeviceName,
+                    "device name contains control characters",
+                ));
+            }
+        }
+
+        if let Err(e) = check_host_port(&self.rendezvous_addr, &["ws://", "wss://"], false) {
+            errors.push(FieldError::new(SettingsField::RendezvousAddr, e));
+        }
+
+        if self.cloud_signaling {
+            if let Err(e) = check_host_port(&self.cloud_signal_url, &["ws://", "wss://"], true) {
+                errors.push(FieldError::new(SettingsField::CloudSignalUrl, e));
+            }
+        }
+
+        if let Some(dir) = &self.download_dir {
+            if !dir.is_absolute() {
+                errors.push(FieldError::new(
+                    SettingsField::DownloadDir,
+                    "download folder must be an absolute path",
+                ));
+            } else if dir.exists() && !dir.is_dir() {
+                errors.push(FieldError::new(
+                    SettingsField::DownloadDir,
+                    "download folder is not a directory",
+                ));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Device name to advertise: the setting, else `hostname`.
+    pub fn device_name_or(&self, hostname: impl FnOnce() -> String) -> String {
+        match &self.device_name {
+            Some(name) => name.clone(),
+            None => hostname(),
+        }
+    }
+
+    /// Rendezvous address after the `BOLT_RENDEZVOUS_URL` override.
+    pub fn effective_rendezvous_addr(&self) -> String {
+        std::env::var(RENDEZVOUS_ENV).unwrap_or_else(|_| self.rendezvous_addr.clone())
+    }
+
+    /// Cloud signaling URL after the `BOLT_CLOUD_SIGNAL_URL` override;
+    /// `None` when cloud signaling is off.
+    pub fn effective_cloud_signal_url(&self) -> Option<String> {
+        if !self.cloud_signaling {
+            return None;
+        }
+        Some(std::env::var(CLOUD_SIGNAL_ENV).unwrap_or_else(|_| self.cloud_signal_url.clone()))
+    }
+
+    /// Daemon flags derived from these settings, appended to every spawn.
+    pub fn daemon_args(&self) -> Vec<String> {
+        vec![
+            "--pairing-policy".to_string(),
+            self.pairing_policy.as_arg().to_string(),
+        ]
+    }
+}
+
+/// Validate `host:port`, optionally behind one of `schemes`.
+fn check_host_port(value: &str, schemes: &[&str], require_scheme: bool) -> Result<(), String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err("address is empty".into());
+    }
+    let rest = match schemes.iter().find_map(|s| value.strip_prefix(s)) {
+        Some(rest) => rest,
+        None if require_scheme => {
+            return Err(format!("URL must start with {}", schemes.join(" or ")));
+        }
+        None if value.contains("://") => {
+            return Err(format!("unsupported scheme (use {})", schemes.join(" or ")));
+        }
+        None => value,
+    };
+    let authority = rest.split('/').next().unwrap_or_default();
+    // `[v6]:port` keeps the brackets in the host.
+    let split_at = match authority.rfind(']') {
+        Some(end) => authority[end..].find(':').map(|i| end + i),
+        None => authority.rfind(':'),
+    };
+    let (host, port) = match split_at {
+        Some(i) => (&authority[..i], Some(&authority[i + 1..])),
+        None => (authority, None),
+    };
+    if host.is_empty() || host.chars().any(|c| c.is_whitespace()) {
+        return Err("missing or invalid host".into());
+    }
+    match port {
+        Some(port) => match port.parse::<u16>() {
+            Ok(p) if p > 0 => Ok(()),
+            _ => Err(format!("invalid port '{port}'")),
+        },
+        // A URL may rely on the scheme's default port.
+        None if rest.len() != value.len() => Ok(()),
+        None => Err("missing port (expected host:port)".into()),
+    }
+}
+
+// ── Parsing ──────────────────────────────────────────────────
+
+/// Parse a settings document.
+pub fn parse_settings(content: &str) -> Result<Settings, String> {
+    let doc: serde_json::Value =
+        serde_json::from_str(content).map_err(|e| format!("settings file is not JSON: {e}"))?;
+    if !doc.is_object() {
+        return Err("settings file is not a JSON object".into());
+    }
+    let version = match doc.get("version") {
+        None => return Err("settings file has no version".into()),
+        Some(v) => v
+            .as_u64()
+            .and_then(|v| u32::try_from(v).ok())
+            .ok_or_else(|| format!("invalid settings version {v}"))?,
+    };
+    if version > SETTINGS_VERSION {
+        return Err(format!(
+            "settings version {version} is newer than supported version {SETTINGS_VERSION}"
+        ));
+    }
+    if version < SETTINGS_VERSION {
+        return Err(format!("unsupported settings version {version}"));
+    }
+    serde_json::from_value(doc).map_err(|e| format!("invalid settings: {e}"))
+}
+
+// ── Store ────────────────────────────────────────────────────
+
+/// Sent to subscribers after a successful [`SettingsStore::update`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SettingsChange {
+    pub previous: Settings,
+    pub current: Settings,
+}
+
+impl SettingsChange {
+    /// Signaling registration (name or servers) changed.
+    pub fn signaling_changed(&self) -> bool {
+        self.previous.device_name != self.current.device_name
+            || self.previous.rendezvous_addr != self.current.rendezvous_addr
+            || self.previous.effective_cloud_signal_url()
+                != self.current.effective_cloud_signal_url()
+    }
+
+    /// Daemon flags changed; running daemons keep their old flags.
+    pub fn daemon_args_changed(&self) -> bool {
+        self.previous.daemon_args() != self.current.daemon_args()
+    }
+}
+
+/// Settings file plus change subscribers.
+#[derive(Debug)]
+pub struct SettingsStore {
+    path: PathBuf,
+    current: Settings,
+    subscribers: Vec<mpsc::Sender<SettingsChange>>,
+    /// Why the file could not be loaded; updates are refused while set.
+    read_only: Option<String>,
+}
+
+impl SettingsStore {
+    /// Defaults that will persist to `path`.
+    pub fn with_defaults(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            current: Settings::default(),
+            subscribers: Vec::new(),
+            read_only: None,
+        }
+    }
+
+    /// Defaults for a `path` that failed to [`load`](Self::load). Updates
+    /// are refused with `reason`, so the file on disk (perhaps written by a
+    /// newer app) is left as it is.
+    pub fn read_only(path: impl Into<PathBuf>, reason: impl Into<String>) -> Self {
+        Self {
+            read_only: Some(reason.into()),
+            ..Self::with_defaults(path)
+        }
+    }
+
+    /// Load `path`. A missing file yields defaults. Invalid values are an
+    /// error so the caller can report them instead of using them.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, String> {
+        let mut store = Self::with_defaults(path);
+        let content = match std::fs::read_to_string(&store.path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(store),
+            Err(e) => return Err(format!("cannot read {}: {e}", store.path.display())),
+        };
+        let settings = parse_settings(&content)?;
+        if let Err(errors) = settings.validate() {
+            return Err(describe_errors(&errors));
+        }
+        store.current = settings;
+        Ok(store)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn current(&self) -> &Settings {
+        &self.current
+    }
+
+    /// The reason updates are refused, for a store built by
+    /// [`read_only`](Self::read_only).
+    pub fn read_only_reason(&self) -> Option<&str> {
+        self.read_only.as_deref()
+    }
+
+    /// Receive a [`SettingsChange`] after each successful update.
+    pub fn subscribe(&mut self) -> mpsc::Receiver<SettingsChange> {
+        let (tx, rx) = mpsc::channel();
+        self.subscribers.push(tx);
+        rx
+    }
+
+    /// Validate, persist and publish `settings`. Nothing changes when
+    /// validation or the write fails, or when the store is read-only.
+    pub fn update(&mut self, mut settings: Settings) -> Result<(), SettingsError> {
+        if let Some(reason) = &self.read_only {
+            return Err(SettingsError::ReadOnly(reason.clone()));
+        }
+        settings.version = SETTINGS_VERSION;
+        settings.validate().map_err(SettingsError::Invalid)?;
+        if settings == self.current {
+            return Ok(());
+        }
+        let previous = std::mem::replace(&mut self.current, settings);
+        if let Err(e) = self.save() {
+            self.current = previous;
+            return Err(SettingsError::Io(e.to_string()));
+        }
+        let change = SettingsChange {
+            previous,
+            current: self.current.clone(),
+        };
+        self.subscribers
+            .retain(|tx| tx.send(change.clone()).is_ok());
+        Ok(())
+    }
+
+    fn save(&self) -> std::io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let json = serde_json::to_string_pretty(&self.current).map_err(std::io::Error::other)?;
+        let tmp = self.path.with_extension("json.tmp");
+        std::fs::write(&tmp, json)?;
+        std::fs::rename(&tmp, &self.path)
+    }
+}
+
+/// Why [`SettingsStore::update`] was refused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SettingsError {
+    Invalid(Vec<FieldError>),
+    Io(String),
+    /// The settings file failed to load; see [`SettingsStore::read_only`].
+    ReadOnly(String),
+}
+
+impl std::fmt::Display for SettingsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Invalid(errors) => write!(f, "{}", describe_errors(errors)),
+            Self::Io(e) => write!(f, "settings not saved: {e}"),
+            Self::ReadOnly(e) => write!(f, "settings not saved: file could not be loaded ({e})"),
+        }
+    }
+}
+
+fn describe_errors(errors: &[FieldError]) -> String {
+    errors
+        .iter()
+        .map(|e| format!("{:?}: {}", e.field, e.message))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("bolt-test-settings-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn defaults_are_valid_and_match_previous_behavior() {
+        let s = Settings::default();
+        assert!(s.validate().is_ok());
+        assert_eq!(s.rendezvous_addr, "127.0.0.1:3001");
+        assert_eq!(s.pairing_policy.as_arg(), "allow");
+        assert_eq!(s.daemon_args(), ["--pairing-policy", "allow"]);
+        assert_eq!(s.device_name_or(|| "host".into()), "host");
+    }
+
+    #[test]
+    fn download_dir_is_not_a_daemon_flag() {
+        let s = Settings {
+            download_dir: Some(PathBuf::from("/srv/incoming")),
+            ..Settings::default()
+        };
+        assert_eq!(s.daemon_args(), Settings::default().daemon_args());
+    }
+
+    #[test]
+    fn validation_reports_every_bad_field() {
+        let s = Settings {
+            device_name: Some("  ".into()),
+            rendezvous_addr: "localhost".into(),
+            cloud_signal_url: "https://example.com".into(),
+            download_dir: Some(PathBuf::from("relative/dir")),
+            ..Settings::default()
+        };
+        let fields: Vec<_> = s.validate().unwrap_err().iter().map(|e| e.field).collect();
+        assert_eq!(
+            fields,
+            [
+                SettingsField::DeviceName,
+                SettingsField::RendezvousAddr,
+                SettingsField::CloudSignalUrl,
+                SettingsField::DownloadDir,
+            ]
+        );
+
+        let mut s = Settings {
+            device_name: Some("x".repeat(MAX_DEVICE_NAME_CHARS + 1)),
+            ..Settings::default()
+        };
+        assert!(s.validate().is_err());
+        s.device_name = Some("Desk\nTop".into());
+        assert!(s.validate().is_err());
+        s.device_name = Some("Büro-Mac".into());
+        assert!(s.validate().is_ok());
+    }
+
+    #[test]
+    fn address_forms() {
+        for ok in [
+            "127.0.0.1:3001",
+            "ws://10.0.0.2:3001",
+            "wss://signal.example.com",
+            "[::1]:3001",
+        ] {
+            assert!(
+                check_host_port(ok, &["ws://", "wss://"], false).is_ok(),
+                "{ok}"
+            );
+        }
+        for bad in ["", "host", "host:0", "host:99999", "http://host:1", ":3001"] {
+            assert!(
+                check_host_port(bad, &["ws://", "wss://"], false).is_err(),
+                "{bad}"
+            );
+        }
+        // Cloud URL is ignored while cloud signaling is off.
+        let s = Settings {
+            cloud_signaling: false,
+            cloud_signal_url: String::new(),
+            ..Settings::default()
+        };
+        assert!(s.validate().is_ok());
+        assert_eq!(s.effective_cloud_signal_url(), None);
+    }
+
+    #[test]
+    fn newer_or_invalid_files_are_refused() {
+        assert!(parse_settings(r#"{"version":99}"#)
+            .unwrap_err()
+            .contains("newer"));
+        assert!(parse_settings("[]").is_err());
+        assert!(parse_settings(r#"{"rendezvous_addr":"10.0.0.5:3001"}"#).is_err());
+        assert!(parse_settings(r#"{"version":1,"pairing_policy":"maybe"}"#).is_err());
+
+        let path = temp_path("invalid.json");
+        std::fs::write(&path, r#"{"version":1,"rendezvous_addr":"nope"}"#).unwrap();
+        assert!(SettingsStore::load(&path).is_err());
+        assert!(SettingsStore::load(temp_path("missing.json")).is_ok());
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn read_only_store_never_overwrites_the_file() {
+        let path = temp_path("newer.json");
+        let newer = r#"{"version":99,"future_field":true}"#;
+        std::fs::write(&path, newer).unwrap();
+        let err = SettingsStore::load(&path).unwrap_err();
+        let mut store = SettingsStore::read_only(&path, err);
+        let rx = store.subscribe();
+        assert!(store.read_only_reason().unwrap().contains("newer"));
+
+        let next = Settings {
+            device_name: Some("Studio".into()),
+            ..Settings::default()
+        };
+        assert!(matches!(
+            store.update(next),
+            Err(SettingsError::ReadOnly(_))
+        ));
+        assert_eq!(store.current(), &Settings::default());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), newer);
+        assert!(rx.try_recv().is_err());
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn update_persists_and_notifies() {
+        let path = temp_path("update.json");
+        let mut store = SettingsStore::load(&path).unwrap();
+        let rx = store.subscribe();
+
+        let mut next = store.current().clone();
+        next.device_name = Some("Studio".into());
+        next.pairing_policy = PairingPolicy::Ask;
+        store.update(next.clone()).unwrap();
+
+        let change = rx.try_recv().unwrap();
+        assert_eq!(change.current, next);
+        assert!(change.signaling_changed());
+        assert!(change.daemon_args_changed());
+        assert_eq!(SettingsStore::load(&path).unwrap().current(), &next);
+
+        // No-op and rejected updates publish nothing.
+        store.update(next.clone()).unwrap();
+        let mut bad = next.clone();
+        bad.rendezvous_addr = String::new();
+        assert!(matches!(store.update(bad), Err(SettingsError::Invalid(_))));
+        assert_eq!(store.current(), &next);
+        assert!(rx.try_recv().is_err());
+        let _ = std::fs::remove_file(&path);
+    }
+}
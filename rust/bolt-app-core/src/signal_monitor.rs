@@ -10,6 +10,8 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
+use crate::state_history::StateHistory;
+
 /// Signal health states.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 #[serde(rename_all = "snake_case")]
@@ -55,6 +57,7 @@ pub struct SignalMonitor {
     status: SignalStatus,
     consecutive_failures: u32,
     shutdown_flag: Arc<AtomicBool>,
+    history: StateHistory<SignalStatus>,
 }
 
 impl SignalMonitor {
@@ -63,6 +66,7 @@ impl SignalMonitor {
             status: SignalStatus::Unknown,
             consecutive_failures: 0,
             shutdown_flag,
+            history: StateHistory::with_default_capacity(),
         }
     }
 
@@ -75,12 +79,24 @@ impl SignalMonitor {
         self.consecutive_failures
     }
 
+    /// Transition history handle (for support bundles).
+    pub fn history(&self) -> StateHistory<SignalStatus> {
+        self.history.clone()
+    }
+
+    fn set_status(&mut self, status: SignalStatus) {
+        let detail = (self.consecutive_failures > 0)
+            .then(|| format!("{} consecutive failures", self.consecutive_failures));
+        self.history.record(self.status, status, detail);
+        self.status = status;
+    }
+
     /// Process a probe result. Returns Some(new_status) if a transition occurred.
     pub fn on_probe_result(&mut self, success: bool) -> Option<SignalStatus> {
         if self.shutdown_flag.load(Ordering::Relaxed) {
             if self.status != SignalStatus::Unknown {
-                self.status = SignalStatus::Unknown;
                 self.consecutive_failures = 0;
+                self.set_status(SignalStatus::Unknown);
                 tracing::info!("[SIGNAL_PROBE_SUPPRESSED] shutdown in progress");
                 return Some(SignalStatus::Unknown);
             }
@@ -91,7 +107,7 @@ impl SignalMonitor {
             self.consecutive_failures = 0;
             let old = self.status;
             if old != SignalStatus::Active {
-                self.status = SignalStatus::Active;
+                self.set_status(SignalStatus::Active);
                 match old {
                     SignalStatus::Offline => {
                         tracing::info!("[SIGNAL_RECOVERED] offline -> active");
@@ -109,7 +125,7 @@ impl SignalMonitor {
 
             if self.consecutive_failures >= OFFLINE_THRESHOLD {
                 if old != SignalStatus::Offline {
-                    self.status = SignalStatus::Offline;
+                    self.set_status(SignalStatus::Offline);
                     tracing::warn!(
                         "[SIGNAL_OFFLINE] {} consecutive failures, {old} -> offline",
                         self.consecutive_failures
@@ -118,7 +134,7 @@ impl SignalMonitor {
                 }
                 None
             } else if old == SignalStatus::Active || old == SignalStatus::Unknown {
-                self.status = SignalStatus::Degraded;
+                self.set_status(SignalStatus::Degraded);
                 tracing::warn!("[SIGNAL_PROBE_FAIL] {old} -> degraded");
                 Some(SignalStatus::Degraded)
             } else {
@@ -141,13 +157,14 @@ pub type SignalStatusCallback = Box<dyn Fn(SignalStatusEvent) + Send + 'static>;
 ///
 /// Probes every 5 seconds, calling `on_transition` on status changes.
 /// Shell implementations wire this to their event system (Tauri emit, egui state, etc.).
+/// Returns the monitor's transition history.
 pub fn start_signal_monitor(
     shutdown_flag: Arc<AtomicBool>,
     on_transition: SignalStatusCallback,
-) {
+) -> StateHistory<SignalStatus> {
+    let mut monitor = SignalMonitor::new(shutdown_flag.clone());
+    let history = monitor.history();
     std::thread::spawn(move || {
-        let mut monitor = SignalMonitor::new(shutdown_flag.clone());
-
         // Brief initial delay to let the signal server bind.
         std::thread::sleep(Duration::from_secs(2));
 
@@ -174,6 +191,7 @@ pub fn start_signal_monitor(
             std::thread::sleep(PROBE_INTERVAL);
         }
     });
+    history
 }
 
 #[cfg(test)]
@@ -298,6 +316,25 @@ mod tests {
         assert_eq!(m.status(), SignalStatus::Degraded);
     }
 
+    #[test]
+    fn transitions_are_recorded_in_history() {
+        let mut m = make_monitor();
+        m.on_probe_result(true);
+        m.on_probe_result(true); // unchanged, not recorded
+        for _ in 0..3 {
+            m.on_probe_result(false);
+        }
+        let steps: Vec<_> = m.history().entries().iter().map(|c| (c.from, c.to)).collect();
+        assert_eq!(
+            steps,
+            [
+                (SignalStatus::Unknown, SignalStatus::Active),
+                (SignalStatus::Active, SignalStatus::Degraded),
+                (SignalStatus::Degraded, SignalStatus::Offline),
+            ]
+        );
+    }
+
     #[test]
     fn display_impl() {
         assert_eq!(SignalStatus::Unknown.to_string(), "unknown");
@@ -0,0 +1,88 @@
+//! Bounded state transition history for diagnostics.
+//!
+//! [`crate::watchdog::Watchdog`] and [`crate::signal_monitor::SignalMonitor`]
+//! record every transition here so a support bundle can show how the app
+//! got into its current state. Like [`crate::daemon_log::StderrBuffer`],
+//! the history is a cloneable handle to a shared ring buffer.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+
+/// Default number of transitions kept.
+const DEFAULT_CAPACITY: usize = 100;
+
+/// One recorded transition.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateChange<S> {
+    /// Milliseconds since the Unix epoch.
+    pub at_ms: u64,
+    pub from: S,
+    pub to: S,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+/// Thread-safe ring buffer of [`StateChange`]s.
+#[derive(Debug, Clone)]
+pub struct StateHistory<S> {
+    inner: Arc<Mutex<VecDeque<StateChange<S>>>>,
+    capacity: usize,
+}
+
+impl<S: Clone> StateHistory<S> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+            capacity,
+        }
+    }
+
+    pub fn with_default_capacity() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+
+    /// Record `from -> to`, evicting the oldest entry if at capacity.
+    pub fn record(&self, from: S, to: S, detail: Option<String>) {
+        let at_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+        let mut buf = self.inner.lock().unwrap();
+        if buf.len() >= self.capacity {
+            buf.pop_front();
+        }
+        buf.push_back(StateChange {
+            at_ms,
+            from,
+            to,
+            detail,
+        });
+    }
+
+    /// All recorded transitions, oldest first.
+    pub fn entries(&self) -> Vec<StateChange<S>> {
+        self.inner.lock().unwrap().iter().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn history_is_bounded_and_shared() {
+        let history = StateHistory::new(2);
+        let handle = history.clone();
+        history.record(1, 2, None);
+        history.record(2, 3, Some("retry".into()));
+        handle.record(3, 4, None);
+
+        let entries = history.entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!((entries[0].from, entries[0].to), (2, 3));
+        assert_eq!(entries[0].detail.as_deref(), Some("retry"));
+        assert_eq!((entries[1].from, entries[1].to), (3, 4));
+    }
+}
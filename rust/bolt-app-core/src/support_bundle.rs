@@ -0,0 +1,589 @@
+//! Support bundle: one archive with what is needed to diagnose a report.
+//!
+//! [`create`] writes `localbolt-support-<unix secs>.tar` to
+//! [`BundleInput::output_dir`] containing:
+//!
+//! - `manifest.json` — app/core/daemon versions, OS and architecture,
+//!   whether the bundle is redacted, and the file list
+//! - `daemon-stderr.log` — the [`StderrBuffer`] contents
+//! - `crash/` — the newest [`MAX_CRASH_SNAPSHOTS`] crash snapshots
+//! - `watchdog-history.json`, `signal-history.json` — state transitions
+//! - `settings.json` — settings with the device name and download folder
+//!   replaced
+//!
+//! With [`BundleInput::redact`] set (the default) every text file is passed
+//! through [`Redactor`], which replaces identity keys, peer codes and file
+//! names/paths with placeholders. Plain tar is used so the bundle opens
+//! anywhere without extra tooling.
+
+use std::io::Write;
+use std::path::PathBuf;
+
+use serde::Serialize;
+
+use bolt_core::constants::{PEER_CODE_ALPHABET, PEER_CODE_LENGTH};
+
+use crate::daemon_log::{self, StderrBuffer};
+use crate::platform;
+use crate::settings::Settings;
+use crate::signal_monitor::SignalStatus;
+use crate::state_history::StateHistory;
+use crate::watchdog::WatchdogState;
+
+/// Bundle layout version, recorded in `manifest.json`.
+pub const BUNDLE_VERSION: u32 = 1;
+
+/// Crash snapshots included, newest first.
+pub const MAX_CRASH_SNAPSHOTS: usize = 5;
+
+const IDENTITY_KEY_PLACEHOLDER: &str = "[identity-key]";
+const PEER_CODE_PLACEHOLDER: &str = "[peer-code]";
+const FILE_PLACEHOLDER: &str = "[file]";
+const PATH_PLACEHOLDER: &str = "[path]";
+
+/// Log keys whose values are file names or paths.
+const FILE_KEYS: [&str; 5] = ["file_name", "file", "save_path", "path", "download_dir"];
+/// Log keys whose values are peer codes.
+const PEER_KEYS: [&str; 7] = [
+    "peer_code",
+    "peer",
+    "peer_id",
+    "peer-id",
+    "expect_peer",
+    "expect-peer",
+    "to",
+];
+
+/// What to put in the bundle. Sources left `None` are omitted.
+pub struct BundleInput<'a> {
+    pub app_name: &'a str,
+    pub app_version: &'a str,
+    pub daemon_version: Option<&'a str>,
+    pub stderr: Option<&'a StderrBuffer>,
+    pub watchdog_history: Option<&'a StateHistory<WatchdogState>>,
+    pub signal_history: Option<&'a StateHistory<SignalStatus>>,
+    pub settings: Option<&'a Settings>,
+    /// Where crash snapshots are read from.
+    pub crash_log_dir: PathBuf,
+    /// Where the archive is written.
+    pub output_dir: PathBuf,
+    /// Scrub identity keys, peer codes and file names.
+    pub redact: bool,
+    /// Extra values to scrub wherever they appear (e.g. the shell's own
+    /// peer code, names from the transfer history).
+    pub redactor: Redactor,
+}
+
+impl<'a> BundleInput<'a> {
+    /// Platform default directories, redaction on, no sources.
+    pub fn new(app_name: &'a str, app_version: &'a str) -> Self {
+        Self {
+            app_name,
+            app_version,
+            daemon_version: None,
+            stderr: None,
+            watchdog_history: None,
+            signal_history: None,
+            settings: None,
+            crash_log_dir: platform::crash_log_dir(),
+            output_dir: platform::support_bundle_dir(),
+            redact: true,
+            redactor: Redactor::new(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Manifest<'a> {
+    bundle_version: u32,
+    created_at_ms: u64,
+    app_name: &'a str,
+    app_version: &'a str,
+    core_version: &'a str,
+    daemon_version: Option<&'a str>,
+    os: &'a str,
+    os_family: &'a str,
+    arch: &'a str,
+    redacted: bool,
+    files: Vec<String>,
+}
+
+/// Assemble the bundle and return the archive path.
+pub fn create(input: &BundleInput<'_>) -> std::io::Result<PathBuf> {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default();
+    let root = format!("localbolt-support-{}", now.as_secs());
+    let scrub = |text: &str| {
+        if input.redact {
+            input.redactor.scrub(text)
+        } else {
+            text.to_string()
+        }
+    };
+
+    let mut files: Vec<(String, Vec<u8>)> = Vec::new();
+
+    if let Some(stderr) = input.stderr {
+        let text = stderr.last_n(usize::MAX).join("\n");
+        files.push(("daemon-stderr.log".into(), scrub(&text).into_bytes()));
+    }
+
+    let snapshots = daemon_log::list_crash_logs(&input.crash_log_dir)?;
+    for snapshot in snapshots.iter().take(MAX_CRASH_SNAPSHOTS) {
+        let path = &snapshot.path;
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        match std::fs::read(path) {
+            Ok(bytes) => {
+                let text = String::from_utf8_lossy(&bytes);
+                files.push((format!("crash/{name}"), scrub(&text).into_bytes()));
+            }
+            Err(e) => tracing::warn!("[SUPPORT] skipping {}: {e}", path.display()),
+        }
+    }
+
+    if let Some(history) = input.watchdog_history {
+        let json = to_json(&history.entries())?;
+        files.push(("watchdog-history.json".into(), scrub(&json).into_bytes()));
+    }
+    if let Some(history) = input.signal_history {
+        let json = to_json(&history.entries())?;
+        files.push(("signal-history.json".into(), scrub(&json).into_bytes()));
+    }
+
+    if let Some(settings) = input.settings {
+        let mut settings = settings.clone();
+        if input.redact {
+            if settings.device_name.is_some() {
+                settings.device_name = Some("[device-name]".into());
+            }
+            if settings.download_dir.is_some() {
+                settings.download_dir = Some(PathBuf::from(PATH_PLACEHOLDER));
+            }
+        }
+        let json = to_json(&settings)?;
+        files.push(("settings.json".into(), json.into_bytes()));
+    }
+
+    let manifest = Manifest {
+        bundle_version: BUNDLE_VERSION,
+        created_at_ms: now.as_millis() as u64,
+        app_name: input.app_name,
+        app_version: input.app_version,
+        core_version: env!("CARGO_PKG_VERSION"),
+        daemon_version: input.daemon_version,
+        os: std::env::consts::OS,
+        os_family: std::env::consts::FAMILY,
+        arch: std::env::consts::ARCH,
+        redacted: input.redact,
+        files: files.iter().map(|(name, _)| name.clone()).collect(),
+    };
+    files.insert(
+        0,
+        ("manifest.json".into(), to_json(&manifest)?.into_bytes()),
+    );
+
+    std::fs::create_dir_all(&input.output_dir)?;
+    let path = input.output_dir.join(format!("{root}.tar"));
+    let tmp = path.with_extension("tar.tmp");
+    {
+        let mut out = std::io::BufWriter::new(std::fs::File::create(&tmp)?);
+        for (name, data) in &files {
+            write_tar_entry(&mut out, &format!("{root}/{name}"), data, now.as_secs())?;
+        }
+        out.write_all(&[0u8; 1024])?;
+        out.flush()?;
+    }
+    std::fs::rename(&tmp, &path)?;
+
+    tracing::info!(
+        "[SUPPORT] bundle written: {} ({} files, redacted={})",
+        path.display(),
+        files.len(),
+        input.redact
+    );
+    Ok(path)
+}
+
+fn to_json<T: Serialize>(value: &T) -> std::io::Result<String> {
+    serde_json::to_string_pretty(value).map_err(std::io::Error::other)
+}
+
+/// One regular-file entry in ustar format. `name` must fit in 100 bytes.
+fn write_tar_entry(
+    out: &mut impl Write,
+    name: &str,
+    data: &[u8],
+    mtime: u64,
+) -> std::io::Result<()> {
+    if name.len() > 100 {
+        return Err(std::io::Error::other(format!("tar name too long: {name}")));
+    }
+    let mut header = [0u8; 512];
+    header[..name.len()].copy_from_slice(name.as_bytes());
+    let mut field = |offset: usize, width: usize, value: u64| {
+        let text = format!("{value:0w$o}\0", w = width - 1);
+        header[offset..offset + width].copy_from_slice(text.as_bytes());
+    };
+    field(100, 8, 0o644); // mode
+    field(108, 8, 0); // uid
+    field(116, 8, 0); // gid
+    field(124, 12, data.len() as u64);
+    field(136, 12, mtime);
+    header[156] = b'0'; // regular file
+    header[257..263].copy_from_slice(b"ustar\0");
+    header[263..265].copy_from_slice(b"00");
+    // Checksum is computed with its own field set to spaces.
+    header[148..156].copy_from_slice(b"        ");
+    let sum: u32 = header.iter().map(|&b| u32::from(b)).sum();
+    header[148..156].copy_from_slice(format!("{sum:06o}\0 ").as_bytes());
+
+    out.write_all(&header)?;
+    out.write_all(data)?;
+    let pad = (512 - data.len() % 512) % 512;
+    out.write_all(&vec![0u8; pad])
+}
+
+// ── Redaction ────────────────────────────────────────────────
+
+/// Scrubs identifying values from diagnostic text.
+///
+/// Always replaced: base64 X25519 identity keys, `XXXX-XXXX` peer codes,
+/// six-character peer codes containing a digit, absolute paths, and the
+/// values of `file_name=`, `peer=` style keys (and their JSON forms).
+/// Six-letter codes without a digit read like ordinary words, so those
+/// are only replaced when registered with [`Redactor::peer_code`].
+#[derive(Debug, Clone, Default)]
+pub struct Redactor {
+    known: Vec<(String, &'static str)>,
+}
+
+impl Redactor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Always replace `code`.
+    pub fn peer_code(&mut self, code: &str) -> &mut Self {
+        self.add(code, PEER_CODE_PLACEHOLDER)
+    }
+
+    /// Always replace `name` (a file name seen in transfers).
+    pub fn file_name(&mut self, name: &str) -> &mut Self {
+        self.add(name, FILE_PLACEHOLDER)
+    }
+
+    fn add(&mut self, value: &str, placeholder: &'static str) -> &mut Self {
+        let value = value.trim();
+        if value.len() >= 3 && !self.known.iter().any(|(v, _)| v == value) {
+            self.known.push((value.to_string(), placeholder));
+            // Longest first so a value containing another is replaced whole.
+            self.known.sort_by_key(|(v, _)| std::cmp::Reverse(v.len()));
+        }
+        self
+    }
+
+    pub fn scrub(&self, text: &str) -> String {
+        let mut text = text.to_string();
+        for (value, placeholder) in &self.known {
+            text = text.replace(value.as_str(), placeholder);
+        }
+        for key in FILE_KEYS {
+            text = scrub_key_values(&text, key, FILE_PLACEHOLDER);
+        }
+        for key in PEER_KEYS {
+            text = scrub_key_values(&text, key, PEER_CODE_PLACEHOLDER);
+        }
+        scrub_tokens(&text)
+    }
+}
+
+/// Replace the value in `key=value`, `key: value` and `"key":"value"`.
+/// `key` must start at a word boundary.
+fn scrub_key_values(text: &str, key: &str, placeholder: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(pos) = rest.find(key) {
+        let boundary = rest[..pos]
+            .chars()
+            .next_back()
+            .is_none_or(|c| !(c.is_alphanumeric() || c == '_' || c == '-'));
+        let after_key = &rest[pos + key.len()..];
+        let value_start = if boundary {
+            value_offset(after_key)
+        } else {
+            None
+        };
+        out.push_str(&rest[..pos + key.len()]);
+        match value_start {
+            Some((offset, quoted)) => {
+                let value = &after_key[offset..];
+                let end = if quoted {
+                    value.find('"').unwrap_or(value.len())
+                } else {
+                    value
+                        .find(|c: char| c.is_whitespace() || c == ',' || c == ')' || c == '}')
+                        .unwrap_or(value.len())
+                };
+                out.push_str(&after_key[..offset]);
+                if end > 0 {
+                    out.push_str(placeholder);
+                }
+                rest = &value[end..];
+            }
+            None => rest = after_key,
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Offset of the value after a key and whether it is a JSON string.
+fn value_offset(after_key: &str) -> Option<(usize, bool)> {
+    let json = after_key.strip_prefix('"').map(|s| (1, s));
+    let (skip, s) = json.unwrap_or((0, after_key));
+    let s_trim = s.trim_start();
+    let sep = s_trim.chars().next()?;
+    if sep != '=' && sep != ':' {
+        return None;
+    }
+    let mut offset = skip + (s.len() - s_trim.len()) + 1;
+    let value = &after_key[offset..];
+    let value_trim = value.trim_start();
+    offset += value.len() - value_trim.len();
+    if value_trim.starts_with('"') {
+        return Some((offset + 1, true));
+    }
+    // `key: value` only counts in JSON; plain text uses `key=value`.
+    if json.is_none() && sep == ':' {
+        return None;
+    }
+    Some((offset, false))
+}
+
+/// Replace identity keys, peer codes and absolute paths token by token.
+fn scrub_tokens(text: &str) -> String {
+    let is_token_char = |c: char| {
+        c.is_ascii_alphanumeric() || matches!(c, '+' | '/' | '=' | '-' | '_' | '.' | '~' | '\\')
+    };
+    let mut out = String::with_capacity(text.len());
+    let mut token = String::new();
+    let mut prev = None;
+    for c in text.chars().chain(std::iter::once('\n')) {
+        if is_token_char(c) {
+            token.push(c);
+            continue;
+        }
+        if !token.is_empty() {
+            // `scheme://host/...` is a URL, not a path.
+            let url = prev == Some(':') && token.starts_with("//");
+            out.push_str(&scrub_token(&token, url));
+            token.clear();
+        }
+        out.push(c);
+        prev = Some(c);
+    }
+    out.pop();
+    out
+}
+
+fn scrub_token(token: &str, url: bool) -> String {
+    // `key=value`: scrub the value on its own (base64 padding aside).
+    if let Some((key, value)) = token.split_once('=') {
+        if !value.is_empty() && !value.chars().all(|c| c == '=') {
+            return format!("{key}={}", scrub_token(value, false));
+        }
+    }
+    if is_identity_key(token) {
+        return IDENTITY_KEY_PLACEHOLDER.into();
+    }
+    let trimmed = token.trim_end_matches(['.', '-', '_']);
+    if is_peer_code_like(trimmed) {
+        return format!("{PEER_CODE_PLACEHOLDER}{}", &token[trimmed.len()..]);
+    }
+    if !url && is_absolute_path(token) {
+        return PATH_PLACEHOLDER.into();
+    }
+    token.to_string()
+}
+
+/// Standard base64 of 32 bytes: 43 characters plus one `=`.
+fn is_identity_key(token: &str) -> bool {
+    token.len() == 44
+        && token.ends_with('=')
+        && token[..43]
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '/')
+}
+
+fn is_peer_code_like(token: &str) -> bool {
+    let in_alphabet = |s: &str| s.chars().all(|c| PEER_CODE_ALPHABET.contains(c));
+    if let Some((a, b)) = token.split_once('-') {
+        return a.len() == 4 && b.len() == 4 && in_alphabet(a) && in_alphabet(b);
+    }
+    token.len() == PEER_CODE_LENGTH
+        && in_alphabet(token)
+        && token.chars().any(|c| c.is_ascii_digit())
+}
+
+fn is_absolute_path(token: &str) -> bool {
+    let unix = token.starts_with('/') && token.len() > 1 && token[1..].contains('/');
+    let home = token.starts_with("~/");
+    let bytes = token.as_bytes();
+    let windows = bytes.len() > 3
+        && bytes[0].is_ascii_alphabetic()
+        && bytes[1] == b':'
+        && (bytes[2] == b'\\' || bytes[2] == b'/');
+    unix || home || windows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    const KEY: &str = "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=";
+
+    fn temp_dir(tag: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("bolt-test-support-{tag}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// (name, contents) of every entry, read back with the `tar` crate.
+    fn read_tar(bytes: &[u8]) -> Vec<(String, String)> {
+        let mut archive = tar::Archive::new(bytes);
+        archive
+            .entries()
+            .unwrap()
+            .map(|entry| {
+                let mut entry = entry.unwrap();
+                assert_eq!(entry.header().entry_type(), tar::EntryType::Regular);
+                let name = entry.path().unwrap().to_string_lossy().into_owned();
+                let mut data = String::new();
+                entry.read_to_string(&mut data).unwrap();
+                (name, data)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn tar_entries_round_trip_through_the_tar_crate() {
+        let mut bytes = Vec::new();
+        let sizes = [0, 1, 511, 512, 513, 2000];
+        for (i, size) in sizes.iter().enumerate() {
+            let data = "x".repeat(*size);
+            write_tar_entry(
+                &mut bytes,
+                &format!("root/file-{i}.txt"),
+                data.as_bytes(),
+                1_700_000_000,
+            )
+            .unwrap();
+        }
+        bytes.extend_from_slice(&[0u8; 1024]);
+        assert_eq!(bytes.len() % 512, 0);
+
+        let mut archive = tar::Archive::new(bytes.as_slice());
+        for (entry, size) in archive.entries().unwrap().zip(sizes) {
+            let mut entry = entry.unwrap();
+            let header = entry.header();
+            assert!(header.as_ustar().is_some());
+            assert_eq!(header.mode().unwrap(), 0o644);
+            assert_eq!(header.mtime().unwrap(), 1_700_000_000);
+            assert_eq!(header.size().unwrap(), size as u64);
+            let mut data = Vec::new();
+            entry.read_to_end(&mut data).unwrap();
+            assert_eq!(data.len(), size);
+        }
+
+        let long = "n".repeat(101);
+        assert!(write_tar_entry(&mut Vec::new(), &long, b"", 0).is_err());
+    }
+
+    #[test]
+    fn redactor_scrubs_keys_codes_and_files() {
+        let mut r = Redactor::new();
+        r.peer_code("QWERTY").file_name("holiday photos.zip");
+        let text = format!(
+            "[UI] connect peer=QWERTY to=AB3DEF key {KEY}\n\
+             {{\"file_name\":\"report.pdf\",\"remote_identity_pk_b64\":\"{KEY}\"}}\n\
+             sent holiday photos.zip from /Users/ana/Desktop/x.bin code ABCD-EF23\n\
+             signal ws://127.0.0.1:3001 STATUS ok file_name=notes.txt,"
+        );
+        let scrubbed = r.scrub(&text);
+        for secret in [
+            "QWERTY",
+            "AB3DEF",
+            KEY,
+            "report.pdf",
+            "holiday",
+            "/Users/ana",
+            "ABCD-EF23",
+            "notes.txt",
+        ] {
+            assert!(!scrubbed.contains(secret), "{secret} leaked: {scrubbed}");
+        }
+        // Ordinary words, URLs and log tags survive.
+        for kept in ["[UI]", "STATUS", "ws://127.0.0.1:3001", "\"file_name\":\""] {
+            assert!(scrubbed.contains(kept), "{kept} lost: {scrubbed}");
+        }
+    }
+
+    #[test]
+    fn bundle_contains_redacted_sources() {
+        let crash_dir = temp_dir("crash");
+        let out_dir = temp_dir("out");
+        for secs in 1..=(MAX_CRASH_SNAPSHOTS as u64 + 2) {
+            std::fs::write(
+                crash_dir.join(format!("daemon-crash-{secs}.log")),
+                format!("crash {secs} peer=XY7ZAB"),
+            )
+            .unwrap();
+        }
+        let stderr = StderrBuffer::new(10);
+        stderr.push(format!("[HELLO] identity {KEY}"));
+        let watchdog = StateHistory::with_default_capacity();
+        watchdog.record(WatchdogState::Starting, WatchdogState::Ready, None);
+        let settings = Settings {
+            device_name: Some("Ana's laptop".into()),
+            ..Settings::default()
+        };
+
+        let mut input = BundleInput::new("bolt-test", "9.9.9");
+        input.crash_log_dir = crash_dir.clone();
+        input.output_dir = out_dir.clone();
+        input.stderr = Some(&stderr);
+        input.watchdog_history = Some(&watchdog);
+        input.settings = Some(&settings);
+        let path = create(&input).unwrap();
+
+        let entries = read_tar(&std::fs::read(&path).unwrap());
+        let names: Vec<_> = entries
+            .iter()
+            .map(|(n, _)| n.split_once('/').unwrap().1)
+            .collect();
+        assert_eq!(names[0], "manifest.json");
+        assert!(names.contains(&"daemon-stderr.log"));
+        assert!(names.contains(&"watchdog-history.json"));
+        assert!(!names.contains(&"signal-history.json"));
+        assert!(names.contains(&"crash/daemon-crash-7.log"));
+        assert!(!names.contains(&"crash/daemon-crash-2.log"));
+        assert_eq!(
+            names.iter().filter(|n| n.starts_with("crash/")).count(),
+            MAX_CRASH_SNAPSHOTS
+        );
+
+        let all: String = entries.iter().map(|(_, c)| c.as_str()).collect();
+        assert!(!all.contains(KEY));
+        assert!(!all.contains("XY7ZAB"));
+        assert!(!all.contains("Ana's laptop"));
+        assert!(all.contains("\"redacted\": true"));
+        assert!(all.contains("\"app_version\": \"9.9.9\""));
+
+        let _ = std::fs::remove_dir_all(&crash_dir);
+        let _ = std::fs::remove_dir_all(&out_dir);
+    }
+}
@@ -0,0 +1,235 @@
+//! Persisted transfer history.
+//!
+//! One NDJSON line per finished transfer (peer, file name, size, hash,
+//! duration, result), appended as transfers end so a crash loses at most
+//! the line being written. [`TransferHistory::load`] skips lines it cannot
+//! parse and keeps the newest [`MAX_HISTORY_ENTRIES`]; the file is
+//! rewritten only when it grows past that bound or is cleared.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::ipc_types::TransferDirection;
+
+/// Entries kept in memory and on disk.
+pub const MAX_HISTORY_ENTRIES: usize = 500;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TransferResult {
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+/// One finished transfer.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct HistoryEntry {
+    /// Peer display name.
+    pub peer: String,
+    pub file_name: String,
+    pub direction: TransferDirection,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size_bytes: Option<u64>,
+    /// SHA-256 of the file, when the daemon reported one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_hash_hex: Option<String>,
+    /// Wall-clock start, milliseconds since the Unix epoch.
+    pub started_at_ms: u64,
+    pub duration_ms: u64,
+    pub result: TransferResult,
+    /// Failure or cancellation reason.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Source path of a send, save path of a receive.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+impl HistoryEntry {
+    /// Source file to re-send: set for sends that did not complete and
+    /// whose file still exists.
+    pub fn retry_path(&self) -> Option<PathBuf> {
+        if self.direction != TransferDirection::Send || self.result == TransferResult::Completed {
+            return None;
+        }
+        let path = PathBuf::from(self.path.as_ref()?);
+        path.is_file().then_some(path)
+    }
+}
+
+/// History log backed by an NDJSON file.
+#[derive(Debug)]
+pub struct TransferHistory {
+    path: PathBuf,
+    entries: Vec<HistoryEntry>,
+}
+
+impl TransferHistory {
+    /// Empty history that will persist to `path`.
+    pub fn empty(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Load `path`. A missing file is an empty history.
+    pub fn load(path: impl Into<PathBuf>) -> std::io::Result<Self> {
+        let mut history = Self::empty(path);
+        let content = match std::fs::read_to_string(&history.path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(history),
+            Err(e) => return Err(e),
+        };
+        for (n, line) in content.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str(line) {
+                Ok(entry) => history.entries.push(entry),
+                Err(e) => tracing::warn!("[HISTORY] skipping line {}: {e}", n + 1),
+            }
+        }
+        let excess = history.entries.len().saturating_sub(MAX_HISTORY_ENTRIES);
+        history.entries.drain(..excess);
+        Ok(history)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Entries, oldest first.
+    pub fn entries(&self) -> &[HistoryEntry] {
+        &self.entries
+    }
+
+    /// Add `entry` and persist it. The in-memory history is updated even
+    /// when the write fails.
+    pub fn record(&mut self, entry: HistoryEntry) -> std::io::Result<()> {
+        let line = serde_json::to_string(&entry).map_err(std::io::Error::other)?;
+        self.entries.push(entry);
+        if self.entries.len() > MAX_HISTORY_ENTRIES {
+            let excess = self.entries.len() - MAX_HISTORY_ENTRIES;
+            self.entries.drain(..excess);
+            return self.rewrite();
+        }
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{line}")
+    }
+
+    /// Forget every entry and delete the file.
+    pub fn clear(&mut self) -> std::io::Result<()> {
+        self.entries.clear();
+        match std::fs::remove_file(&self.path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+
+    fn rewrite(&self) -> std::io::Result<()> {
+        let mut out = String::new();
+        for entry in &self.entries {
+            out.push_str(&serde_json::to_string(entry).map_err(std::io::Error::other)?);
+            out.push('\n');
+        }
+        let tmp = self.path.with_extension("jsonl.tmp");
+        std::fs::write(&tmp, out)?;
+        std::fs::rename(&tmp, &self.path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("bolt-test-history-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    fn entry(file_name: &str, result: TransferResult) -> HistoryEntry {
+        HistoryEntry {
+            peer: "Laptop".into(),
+            file_name: file_name.into(),
+            direction: TransferDirection::Send,
+            size_bytes: Some(10),
+            file_hash_hex: Some("ab".repeat(32)),
+            started_at_ms: 1_700_000_000_000,
+            duration_ms: 1200,
+            result,
+            error: None,
+            path: Some(format!("/nope/{file_name}")),
+        }
+    }
+
+    #[test]
+    fn record_then_load_round_trips() {
+        let path = temp_path("round-trip.jsonl");
+        let mut h = TransferHistory::load(&path).unwrap();
+        assert!(h.entries().is_empty());
+        h.record(entry("a.txt", TransferResult::Completed)).unwrap();
+        h.record(entry("b.txt", TransferResult::Failed)).unwrap();
+
+        let loaded = TransferHistory::load(&path).unwrap();
+        assert_eq!(loaded.entries(), h.entries());
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn malformed_lines_are_skipped() {
+        let path = temp_path("malformed.jsonl");
+        let good = serde_json::to_string(&entry("a.txt", TransferResult::Cancelled)).unwrap();
+        std::fs::write(&path, format!("{good}\n{{not json\n\n{good}\n")).unwrap();
+        assert_eq!(TransferHistory::load(&path).unwrap().entries().len(), 2);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn history_is_bounded_and_clearable() {
+        let path = temp_path("bounded.jsonl");
+        let mut h = TransferHistory::empty(&path);
+        for i in 0..MAX_HISTORY_ENTRIES + 3 {
+            h.record(entry(&format!("{i}.txt"), TransferResult::Completed))
+                .unwrap();
+        }
+        assert_eq!(h.entries().len(), MAX_HISTORY_ENTRIES);
+        assert_eq!(h.entries()[0].file_name, "3.txt");
+        let loaded = TransferHistory::load(&path).unwrap();
+        assert_eq!(loaded.entries(), h.entries());
+
+        h.clear().unwrap();
+        assert!(h.entries().is_empty());
+        assert!(!path.exists());
+        h.clear().unwrap();
+    }
+
+    #[test]
+    fn retry_only_for_unfinished_sends_of_existing_files() {
+        let path = temp_path("source.bin");
+        std::fs::write(&path, b"x").unwrap();
+        let mut e = entry("source.bin", TransferResult::Failed);
+        e.path = Some(path.display().to_string());
+        assert_eq!(e.retry_path(), Some(path.clone()));
+
+        e.result = TransferResult::Completed;
+        assert_eq!(e.retry_path(), None);
+        e.result = TransferResult::Cancelled;
+        e.direction = TransferDirection::Receive;
+        assert_eq!(e.retry_path(), None);
+        assert_eq!(entry("gone", TransferResult::Failed).retry_path(), None);
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,541 @@
+//! Outgoing transfer queue.
+//!
+//! The daemon moves one file at a time. [`TransferQueue`] holds the files
+//! waiting to be sent, in user order, plus the single [`ActiveTransfer`]
+//! (a send taken from the queue, or a receive the peer started). Shells
+//! enqueue, reorder and remove pending items freely; only the active
+//! transfer needs a daemon `transfer.cancel`. Finished transfers become
+//! [`HistoryEntry`] values for [`crate::transfer_history`].
+//!
+//! A multi-file selection or dropped folder is enqueued as one [`Batch`]
+//! ([`TransferQueue::enqueue_batch`]); folders expand to the regular files
+//! beneath them, and the batch tracks combined progress across its files.
+
+use std::path::{Path, PathBuf};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use crate::ipc_types::TransferDirection;
+use crate::transfer_history::{HistoryEntry, TransferResult};
+
+/// Queue-local identifier of a pending file.
+pub type QueueItemId = u64;
+
+/// Queue-local identifier of a batch.
+pub type BatchId = u64;
+
+/// Files enqueued together, with combined progress.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Batch {
+    pub id: BatchId,
+    /// Display label: the single selected item's name, or "N files".
+    pub label: String,
+    pub total_files: usize,
+    /// Sum of known file sizes.
+    pub total_bytes: u64,
+    pub completed_files: usize,
+    pub failed_files: usize,
+    /// Bytes of finished files, failed ones included.
+    finished_bytes: u64,
+}
+
+impl Batch {
+    /// Files that finished, successfully or not.
+    pub fn finished_files(&self) -> usize {
+        self.completed_files + self.failed_files
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished_files() >= self.total_files
+    }
+
+    /// Combined progress, 0.0..=1.0, given the active file's progress.
+    /// Weighted by bytes when sizes are known, by file count otherwise.
+    pub fn progress(&self, active: Option<(&ActiveTransfer, f64)>) -> f64 {
+        let active = active.filter(|(a, _)| a.batch == Some(self.id));
+        let fraction = if self.total_bytes > 0 {
+            let in_flight = active
+                .and_then(|(a, p)| a.size_bytes.map(|size| size as f64 * p))
+                .unwrap_or(0.0);
+            (self.finished_bytes as f64 + in_flight) / self.total_bytes as f64
+        } else if self.total_files > 0 {
+            let in_flight = active.map(|(_, p)| p).unwrap_or(0.0);
+            (self.finished_files() as f64 + in_flight) / self.total_files as f64
+        } else {
+            1.0
+        };
+        fraction.clamp(0.0, 1.0)
+    }
+}
+
+/// Expand `paths` to the regular files they name: files as given,
+/// directories recursively in name order. Symlinked directories are not
+/// followed; unreadable entries are skipped.
+pub fn expand_paths(paths: &[PathBuf]) -> Vec<PathBuf> {
+    fn walk(path: &Path, out: &mut Vec<PathBuf>) {
+        let Ok(meta) = std::fs::symlink_metadata(path) else {
+            return;
+        };
+        if meta.is_dir() {
+            let Ok(entries) = std::fs::read_dir(path) else {
+                return;
+            };
+            let mut children: Vec<PathBuf> = entries.flatten().map(|e| e.path()).collect();
+            children.sort();
+            for child in children {
+                walk(&child, out);
+            }
+        } else if path.is_file() {
+            out.push(path.to_path_buf());
+        }
+    }
+    let mut out = Vec::new();
+    for path in paths {
+        walk(path, &mut out);
+    }
+    out
+}
+
+/// A file waiting to be sent.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueuedFile {
+    pub id: QueueItemId,
+    pub path: PathBuf,
+    pub file_name: String,
+    /// Size at enqueue time; `None` if the file could not be read.
+    pub size_bytes: Option<u64>,
+    pub batch: Option<BatchId>,
+}
+
+/// The transfer currently in progress.
+#[derive(Debug, Clone)]
+pub struct ActiveTransfer {
+    pub direction: TransferDirection,
+    pub file_name: String,
+    /// Source path of a send.
+    pub path: Option<PathBuf>,
+    pub size_bytes: Option<u64>,
+    /// Daemon transfer id, once known (ack or `transfer.started`).
+    pub transfer_id: Option<String>,
+    pub batch: Option<BatchId>,
+    started_at: Instant,
+    started_at_ms: u64,
+}
+
+impl ActiveTransfer {
+    fn new(direction: TransferDirection, file_name: String) -> Self {
+        Self {
+            direction,
+            file_name,
+            path: None,
+            size_bytes: None,
+            transfer_id: None,
+            batch: None,
+            started_at: Instant::now(),
+            started_at_ms: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64,
+        }
+    }
+
+    /// History record for this transfer, ended now.
+    pub fn into_history(
+        self,
+        peer: &str,
+        result: TransferResult,
+        error: Option<String>,
+    ) -> HistoryEntry {
+        HistoryEntry {
+            peer: peer.to_string(),
+            file_name: self.file_name,
+            direction: self.direction,
+            size_bytes: self.size_bytes,
+            file_hash_hex: None,
+            started_at_ms: self.started_at_ms,
+            duration_ms: self.started_at.elapsed().as_millis() as u64,
+            result,
+            error,
+            path: self.path.map(|p| p.display().to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct TransferQueue {
+    pending: Vec<QueuedFile>,
+    active: Option<ActiveTransfer>,
+    batches: Vec<Batch>,
+    next_id: QueueItemId,
+    next_batch: BatchId,
+}
+
+impl TransferQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append `path` to the queue. The display name is the path's file
+    /// name; the size is read from the filesystem when possible.
+    pub fn enqueue(&mut self, path: &Path) -> QueueItemId {
+        self.push(path, None)
+    }
+
+    /// Enqueue a selection as one batch, expanding folders
+    /// ([`expand_paths`]). `None` if the selection holds no files.
+    /// Batches that already finished are dropped.
+    pub fn enqueue_batch(&mut self, paths: &[PathBuf]) -> Option<BatchId> {
+        let files = expand_paths(paths);
+        if files.is_empty() {
+            return None;
+        }
+        self.batches.retain(|b| !b.is_finished());
+        let id = self.next_batch;
+        self.next_batch += 1;
+        let label = match paths {
+            [single] => single.file_name().map(|n| n.to_string_lossy().to_string()),
+            _ => None,
+        }
+        .unwrap_or_else(|| format!("{} files", files.len()));
+        for file in &files {
+            self.push(file, Some(id));
+        }
+        let total_bytes = self
+            .pending
+            .iter()
+            .filter(|f| f.batch == Some(id))
+            .filter_map(|f| f.size_bytes)
+            .sum();
+        self.batches.push(Batch {
+            id,
+            label,
+            total_files: files.len(),
+            total_bytes,
+            completed_files: 0,
+            failed_files: 0,
+            finished_bytes: 0,
+        });
+        Some(id)
+    }
+
+    fn push(&mut self, path: &Path, batch: Option<BatchId>) -> QueueItemId {
+        let id = self.next_id;
+        self.next_id += 1;
+        let file_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| "file".into());
+        self.pending.push(QueuedFile {
+            id,
+            path: path.to_path_buf(),
+            file_name,
+            size_bytes: std::fs::metadata(path).ok().map(|m| m.len()),
+            batch,
+        });
+        id
+    }
+
+    pub fn batch(&self, id: BatchId) -> Option<&Batch> {
+        self.batches.iter().find(|b| b.id == id)
+    }
+
+    /// The batch in flight: the active transfer's, else the next pending
+    /// file's, else the most recent one (possibly finished).
+    pub fn current_batch(&self) -> Option<&Batch> {
+        let id = self
+            .active
+            .as_ref()
+            .and_then(|a| a.batch)
+            .or_else(|| self.pending.first().and_then(|f| f.batch));
+        match id {
+            Some(id) => self.batch(id),
+            None => self.batches.last(),
+        }
+    }
+
+    fn batch_mut(&mut self, id: Option<BatchId>) -> Option<&mut Batch> {
+        let id = id?;
+        self.batches.iter_mut().find(|b| b.id == id)
+    }
+
+    /// Files waiting to be sent, next first.
+    pub fn pending(&self) -> &[QueuedFile] {
+        &self.pending
+    }
+
+    pub fn active(&self) -> Option<&ActiveTransfer> {
+        self.active.as_ref()
+    }
+
+    pub fn active_mut(&mut self) -> Option<&mut ActiveTransfer> {
+        self.active.as_mut()
+    }
+
+    /// Nothing active and nothing pending.
+    pub fn is_empty(&self) -> bool {
+        self.active.is_none() && self.pending.is_empty()
+    }
+
+    fn position(&self, id: QueueItemId) -> Option<usize> {
+        self.pending.iter().position(|f| f.id == id)
+    }
+
+    /// Move pending item `id` to `index` (clamped). `false` if unknown.
+    pub fn move_to(&mut self, id: QueueItemId, index: usize) -> bool {
+        let Some(from) = self.position(id) else {
+            return false;
+        };
+        let item = self.pending.remove(from);
+        let index = index.min(self.pending.len());
+        self.pending.insert(index, item);
+        true
+    }
+
+    /// Move pending item `id` one place towards the front.
+    pub fn move_up(&mut self, id: QueueItemId) -> bool {
+        match self.position(id) {
+            Some(i) => self.move_to(id, i.saturating_sub(1)),
+            None => false,
+        }
+    }
+
+    /// Move pending item `id` one place towards the back.
+    pub fn move_down(&mut self, id: QueueItemId) -> bool {
+        match self.position(id) {
+            Some(i) => self.move_to(id, i + 1),
+            None => false,
+        }
+    }
+
+    /// Remove a pending item (cancel before it starts).
+    pub fn remove(&mut self, id: QueueItemId) -> Option<QueuedFile> {
+        let i = self.position(id)?;
+        let item = self.pending.remove(i);
+        self.shrink_batch(&item);
+        Some(item)
+    }
+
+    /// Drop every pending item; the active transfer is unaffected.
+    pub fn clear_pending(&mut self) {
+        for item in std::mem::take(&mut self.pending) {
+            self.shrink_batch(&item);
+        }
+    }
+
+    /// Take a never-started item out of its batch's totals.
+    fn shrink_batch(&mut self, item: &QueuedFile) {
+        if let Some(batch) = self.batch_mut(item.batch) {
+            batch.total_files -= 1;
+            batch.total_bytes -= item.size_bytes.unwrap_or(0);
+        }
+    }
+
+    /// Make the next pending file the active send. `None` while another
+    /// transfer is active or the queue is empty.
+    pub fn start_next_send(&mut self) -> Option<&ActiveTransfer> {
+        if self.active.is_some() || self.pending.is_empty() {
+            return None;
+        }
+        let next = self.pending.remove(0);
+        let mut active = ActiveTransfer::new(TransferDirection::Send, next.file_name);
+        active.path = Some(next.path);
+        active.size_bytes = next.size_bytes;
+        active.batch = next.batch;
+        self.active = Some(active);
+        self.active.as_ref()
+    }
+
+    /// Apply `transfer.started`. A matching active send just picks up the
+    /// daemon's id and size; anything else becomes the active transfer.
+    /// Returns the transfer it displaced, if any, so it can be recorded.
+    pub fn on_started(
+        &mut self,
+        direction: TransferDirection,
+        file_name: &str,
+        size_bytes: Option<u64>,
+        transfer_id: Option<String>,
+    ) -> Option<ActiveTransfer> {
+        if let Some(active) = &mut self.active {
+            if active.direction == direction && active.file_name == file_name {
+                active.transfer_id = transfer_id.or(active.transfer_id.take());
+                active.size_bytes = size_bytes.or(active.size_bytes);
+                return None;
+            }
+        }
+        let displaced = self.finish(TransferResult::Failed);
+        let mut started = ActiveTransfer::new(direction, file_name.to_string());
+        started.size_bytes = size_bytes;
+        started.transfer_id = transfer_id;
+        self.active = Some(started);
+        displaced
+    }
+
+    /// End the active transfer with `result`, counting it in its batch,
+    /// and hand it back for history.
+    pub fn finish(&mut self, result: TransferResult) -> Option<ActiveTransfer> {
+        let active = self.active.take()?;
+        if let Some(batch) = self.batch_mut(active.batch) {
+            if result == TransferResult::Completed {
+                batch.completed_files += 1;
+            } else {
+                batch.failed_files += 1;
+            }
+            batch.finished_bytes += active.size_bytes.unwrap_or(0);
+        }
+        Some(active)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn queue_of(names: &[&str]) -> (TransferQueue, Vec<QueueItemId>) {
+        let mut q = TransferQueue::new();
+        let ids = names.iter().map(|n| q.enqueue(Path::new(n))).collect();
+        (q, ids)
+    }
+
+    fn names(q: &TransferQueue) -> Vec<&str> {
+        q.pending().iter().map(|f| f.file_name.as_str()).collect()
+    }
+
+    #[test]
+    fn enqueue_keeps_order_and_reads_size() {
+        let dir = std::env::temp_dir().join(format!("bolt-test-queue-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("real.bin");
+        std::fs::write(&path, [0u8; 42]).unwrap();
+
+        let (mut q, _) = queue_of(&["/nope/a.txt", "/nope/b.txt"]);
+        q.enqueue(&path);
+        assert_eq!(names(&q), ["a.txt", "b.txt", "real.bin"]);
+        assert_eq!(q.pending()[0].size_bytes, None);
+        assert_eq!(q.pending()[2].size_bytes, Some(42));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn reorder_and_remove() {
+        let (mut q, ids) = queue_of(&["a", "b", "c"]);
+        assert!(q.move_down(ids[0]));
+        assert_eq!(names(&q), ["b", "a", "c"]);
+        assert!(q.move_up(ids[2]));
+        assert_eq!(names(&q), ["b", "c", "a"]);
+        assert!(q.move_up(ids[1])); // already first
+        assert!(q.move_to(ids[1], 99));
+        assert_eq!(names(&q), ["c", "a", "b"]);
+        assert_eq!(q.remove(ids[0]).unwrap().file_name, "a");
+        assert_eq!(names(&q), ["c", "b"]);
+        assert!(q.remove(ids[0]).is_none());
+        assert!(!q.move_up(ids[0]));
+    }
+
+    #[test]
+    fn one_active_transfer_at_a_time() {
+        let (mut q, _) = queue_of(&["/x/a", "/x/b"]);
+        let active = q.start_next_send().unwrap();
+        assert_eq!(active.file_name, "a");
+        assert_eq!(active.path.as_deref(), Some(Path::new("/x/a")));
+        assert!(q.start_next_send().is_none());
+        assert_eq!(names(&q), ["b"]);
+
+        let done = q.finish(TransferResult::Completed).unwrap();
+        let entry = done.into_history("peer", TransferResult::Completed, None);
+        assert_eq!(entry.path.as_deref(), Some("/x/a"));
+        assert_eq!(q.start_next_send().unwrap().file_name, "b");
+        q.finish(TransferResult::Completed);
+        assert!(q.is_empty());
+    }
+
+    #[test]
+    fn started_event_attaches_to_matching_send() {
+        let (mut q, _) = queue_of(&["/x/a"]);
+        q.start_next_send();
+        let displaced = q.on_started(TransferDirection::Send, "a", Some(9), Some("t-1".into()));
+        assert!(displaced.is_none());
+        let active = q.active().unwrap();
+        assert_eq!(active.transfer_id.as_deref(), Some("t-1"));
+        assert_eq!(active.size_bytes, Some(9));
+
+        let displaced = q.on_started(TransferDirection::Receive, "in.txt", None, None);
+        assert_eq!(displaced.unwrap().file_name, "a");
+        assert_eq!(q.active().unwrap().direction, TransferDirection::Receive);
+    }
+
+    fn temp_tree(tag: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("bolt-test-{tag}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("photos/raw")).unwrap();
+        std::fs::write(root.join("photos/b.jpg"), [0u8; 30]).unwrap();
+        std::fs::write(root.join("photos/a.jpg"), [0u8; 10]).unwrap();
+        std::fs::write(root.join("photos/raw/c.raw"), [0u8; 60]).unwrap();
+        std::fs::write(root.join("notes.txt"), [0u8; 100]).unwrap();
+        root
+    }
+
+    #[test]
+    fn expand_paths_walks_folders_in_name_order() {
+        let root = temp_tree("expand");
+        let files = expand_paths(&[
+            root.join("photos"),
+            root.join("missing"),
+            root.join("notes.txt"),
+        ]);
+        let rel: Vec<_> = files
+            .iter()
+            .map(|f| f.strip_prefix(&root).unwrap().to_owned())
+            .collect();
+        assert_eq!(
+            rel,
+            [
+                PathBuf::from("photos/a.jpg"),
+                PathBuf::from("photos/b.jpg"),
+                PathBuf::from("photos/raw/c.raw"),
+                PathBuf::from("notes.txt"),
+            ]
+        );
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn batch_tracks_combined_progress() {
+        let root = temp_tree("batch");
+        let mut q = TransferQueue::new();
+        assert!(q.enqueue_batch(&[root.join("missing")]).is_none());
+
+        let id = q.enqueue_batch(&[root.join("photos")]).unwrap();
+        let batch = q.batch(id).unwrap();
+        assert_eq!(
+            (batch.label.as_str(), batch.total_files, batch.total_bytes),
+            ("photos", 3, 100)
+        );
+        assert_eq!(batch.progress(None), 0.0);
+
+        q.start_next_send(); // a.jpg, 10 bytes
+        let active = q.active().unwrap().clone();
+        assert_eq!(active.batch, Some(id));
+        assert_eq!(q.batch(id).unwrap().progress(Some((&active, 0.5))), 0.05);
+        q.finish(TransferResult::Completed);
+
+        q.start_next_send(); // b.jpg, 30 bytes
+        q.finish(TransferResult::Failed);
+        let batch = q.current_batch().unwrap();
+        assert_eq!((batch.completed_files, batch.failed_files), (1, 1));
+        assert_eq!(batch.progress(None), 0.4);
+
+        // Removing the last pending item shrinks the batch, finishing it.
+        let last = q.pending()[0].id;
+        q.remove(last);
+        let batch = q.batch(id).unwrap();
+        assert!(batch.is_finished());
+        assert_eq!(batch.progress(None), 1.0);
+
+        let next = q
+            .enqueue_batch(&[root.join("notes.txt"), root.join("photos/a.jpg")])
+            .unwrap();
+        assert!(q.batch(id).is_none(), "finished batches are dropped");
+        assert_eq!(q.batch(next).unwrap().label, "2 files");
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
@@ -0,0 +1,391 @@
+//! Trusted devices: the app's identity pin store.
+//!
+//! Each record pins a peer's long-lived identity key (from the
+//! `remote_identity_pk_b64` field of `session.connected` / `session.sas`)
+//! with when it was first and last seen and how it was trusted. Peer codes
+//! change every launch, so records are keyed by identity key, never by
+//! peer code.
+//!
+//! A device pinned on first contact is [`VerificationMethod::Tofu`]; once
+//! the user compares the SAS it becomes [`VerificationMethod::Sas`] and
+//! later sessions with that key skip the SAS prompt. Revoking deletes the
+//! pin; re-verifying drops it back to TOFU so the next session asks again.
+//!
+//! Stored as one JSON document at [`crate::platform::trusted_devices_path`],
+//! rewritten atomically on every change. A store whose file failed to load
+//! is read-only, so the unreadable file is never replaced.
+//!
+//! This is the app's own store: the daemon keeps its TOFU pins separately,
+//! and revoking a device here does not change what the daemon accepts.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// Current pin store schema version.
+pub const PIN_STORE_VERSION: u32 = 1;
+
+/// Longest accepted device alias, in characters.
+pub const MAX_ALIAS_CHARS: usize = 64;
+
+/// How a device came to be trusted.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationMethod {
+    /// Pinned on first use; the SAS was not confirmed.
+    Tofu,
+    /// The user confirmed the SAS code matched.
+    Sas,
+}
+
+/// One pinned identity.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TrustedDevice {
+    /// Base64 X25519 identity public key, as sent by the daemon.
+    pub identity_pk_b64: String,
+    /// Name the device last announced.
+    pub device_name: String,
+    /// User-chosen name, shown instead of `device_name`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    /// Milliseconds since the Unix epoch.
+    pub first_seen_ms: u64,
+    pub last_seen_ms: u64,
+    pub method: VerificationMethod,
+    /// When the SAS was last confirmed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verified_at_ms: Option<u64>,
+}
+
+impl TrustedDevice {
+    pub fn display_name(&self) -> &str {
+        self.alias.as_deref().unwrap_or(&self.device_name)
+    }
+
+    pub fn is_verified(&self) -> bool {
+        self.method == VerificationMethod::Sas
+    }
+
+    /// Short key fingerprint for comparing out of band, see [`fingerprint`].
+    pub fn fingerprint(&self) -> String {
+        fingerprint(&self.identity_pk_b64)
+    }
+}
+
+/// First 16 bytes of SHA-256 over the raw identity key, as upper-case hex
+/// in groups of four (`3F2A 91C0 …`). Keys that are not valid base64 are
+/// hashed as text so a malformed record still gets a stable label.
+pub fn fingerprint(identity_pk_b64: &str) -> String {
+    let raw = bolt_core::encoding::from_base64(identity_pk_b64)
+        .unwrap_or_else(|_| identity_pk_b64.as_bytes().to_vec());
+    let hex = bolt_core::hash::sha256_hex(&raw).to_uppercase();
+    hex.as_bytes()[..32]
+        .chunks(4)
+        .map(|c| std::str::from_utf8(c).unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[derive(Serialize, Deserialize)]
+struct PinFile {
+    version: u32,
+    devices: Vec<TrustedDevice>,
+}
+
+/// Pin store backed by a JSON file.
+#[derive(Debug)]
+pub struct PinStore {
+    path: PathBuf,
+    devices: Vec<TrustedDevice>,
+    /// Why the file could not be loaded; every edit is refused while set.
+    read_only: Option<String>,
+}
+
+impl PinStore {
+    /// Empty store that will persist to `path`.
+    pub fn empty(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            devices: Vec::new(),
+            read_only: None,
+        }
+    }
+
+    /// Empty store for a `path` that failed to [`load`](Self::load). Edits
+    /// are refused with `reason`, so a corrupt or newer file is kept.
+    pub fn read_only(path: impl Into<PathBuf>, reason: impl Into<String>) -> Self {
+        Self {
+            read_only: Some(reason.into()),
+            ..Self::empty(path)
+        }
+    }
+
+    /// Load `path`. A missing file is an empty store; a newer schema
+    /// version is an error so it is never overwritten.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, String> {
+        let mut store = Self::empty(path);
+        let content = match std::fs::read_to_string(&store.path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(store),
+            Err(e) => return Err(format!("cannot read {}: {e}", store.path.display())),
+        };
+        let file: PinFile =
+            serde_json::from_str(&content).map_err(|e| format!("invalid pin store: {e}"))?;
+        if file.version > PIN_STORE_VERSION {
+            return Err(format!(
+                "pin store version {} is newer than supported version {PIN_STORE_VERSION}",
+                file.version
+            ));
+        }
+        store.devices = file.devices;
+        Ok(store)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The reason edits are refused, for a store built by
+    /// [`read_only`](Self::read_only).
+    pub fn read_only_reason(&self) -> Option<&str> {
+        self.read_only.as_deref()
+    }
+
+    /// Pinned devices, most recently seen first.
+    pub fn list(&self) -> Vec<&TrustedDevice> {
+        let mut devices: Vec<_> = self.devices.iter().collect();
+        devices.sort_by_key(|d| std::cmp::Reverse(d.last_seen_ms));
+        devices
+    }
+
+    pub fn get(&self, identity_pk_b64: &str) -> Option<&TrustedDevice> {
+        self.devices
+            .iter()
+            .find(|d| d.identity_pk_b64 == identity_pk_b64)
+    }
+
+    /// Record a session with `identity_pk_b64`: pin it on first contact,
+    /// otherwise refresh its name and last-seen time.
+    pub fn observe(
+        &mut self,
+        identity_pk_b64: &str,
+        device_name: &str,
+        now_ms: u64,
+    ) -> Result<&TrustedDevice, String> {
+        self.check_writable()?;
+        let index = match self.position(identity_pk_b64) {
+            Some(i) => {
+                let device = &mut self.devices[i];
+                device.device_name = device_name.to_string();
+                device.last_seen_ms = now_ms;
+                i
+            }
+            None => {
+                self.devices.push(TrustedDevice {
+                    identity_pk_b64: identity_pk_b64.to_string(),
+                    device_name: device_name.to_string(),
+                    alias: None,
+                    first_seen_ms: now_ms,
+                    last_seen_ms: now_ms,
+                    method: VerificationMethod::Tofu,
+                    verified_at_ms: None,
+                });
+                self.devices.len() - 1
+            }
+        };
+        self.save()?;
+        Ok(&self.devices[index])
+    }
+
+    /// The user confirmed the SAS for this key.
+    pub fn mark_verified(&mut self, identity_pk_b64: &str, now_ms: u64) -> Result<(), String> {
+        self.check_writable()?;
+        let device = self.device_mut(identity_pk_b64)?;
+        device.method = VerificationMethod::Sas;
+        device.verified_at_ms = Some(now_ms);
+        self.save()
+    }
+
+    /// Set or clear (`None` or blank) the user's name for a device.
+    pub fn rename(&mut self, identity_pk_b64: &str, alias: Option<&str>) -> Result<(), String> {
+        self.check_writable()?;
+        let alias = alias.map(str::trim).filter(|a| !a.is_empty());
+        if let Some(alias) = alias {
+            if alias.chars().count() > MAX_ALIAS_CHARS {
+                return Err(format!("name is longer than {MAX_ALIAS_CHARS} characters"));
+            }
+            if alias.chars().any(char::is_control) {
+                return Err("name contains control characters".into());
+            }
+        }
+        self.device_mut(identity_pk_b64)?.alias = alias.map(String::from);
+        self.save()
+    }
+
+    /// Forget the device. Its next session is a first contact again.
+    pub fn revoke(&mut self, identity_pk_b64: &str) -> Result<(), String> {
+        self.check_writable()?;
+        let index = self
+            .position(identity_pk_b64)
+            .ok_or_else(|| unknown(identity_pk_b64))?;
+        self.devices.remove(index);
+        self.save()
+    }
+
+    /// Keep the pin but require SAS comparison on the next session.
+    pub fn require_reverification(&mut self, identity_pk_b64: &str) -> Result<(), String> {
+        self.check_writable()?;
+        let device = self.device_mut(identity_pk_b64)?;
+        device.method = VerificationMethod::Tofu;
+        device.verified_at_ms = None;
+        self.save()
+    }
+
+    fn check_writable(&self) -> Result<(), String> {
+        match &self.read_only {
+            Some(reason) => Err(format!("pin store is read-only: {reason}")),
+            None => Ok(()),
+        }
+    }
+
+    fn position(&self, identity_pk_b64: &str) -> Option<usize> {
+        self.devices
+            .iter()
+            .position(|d| d.identity_pk_b64 == identity_pk_b64)
+    }
+
+    fn device_mut(&mut self, identity_pk_b64: &str) -> Result<&mut TrustedDevice, String> {
+        self.devices
+            .iter_mut()
+            .find(|d| d.identity_pk_b64 == identity_pk_b64)
+            .ok_or_else(|| unknown(identity_pk_b64))
+    }
+
+    fn save(&self) -> Result<(), String> {
+        let file = PinFile {
+            version: PIN_STORE_VERSION,
+            devices: self.devices.clone(),
+        };
+        let write = || -> std::io::Result<()> {
+            if let Some(dir) = self.path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            let json = serde_json::to_string_pretty(&file).map_err(std::io::Error::other)?;
+            let tmp = self.path.with_extension("json.tmp");
+            std::fs::write(&tmp, json)?;
+            std::fs::rename(&tmp, &self.path)
+        };
+        write().map_err(|e| format!("pin store not saved: {e}"))
+    }
+}
+
+fn unknown(identity_pk_b64: &str) -> String {
+    format!(
+        "no trusted device with key {}",
+        fingerprint(identity_pk_b64)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY_A: &str = "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=";
+    const KEY_B: &str = "Hx4dHBsaGRgXFhUUExIREA8ODQwLCgkIBwYFBAMCAQA=";
+
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("bolt-test-pins-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn first_contact_pins_as_tofu_then_refreshes() {
+        let path = temp_path("observe.json");
+        let mut store = PinStore::load(&path).unwrap();
+        let d = store.observe(KEY_A, "Laptop", 1_000).unwrap().clone();
+        assert_eq!(d.method, VerificationMethod::Tofu);
+        assert!(!d.is_verified());
+
+        let d = store.observe(KEY_A, "Laptop 2", 5_000).unwrap();
+        assert_eq!(
+            (d.first_seen_ms, d.last_seen_ms, d.device_name.as_str()),
+            (1_000, 5_000, "Laptop 2")
+        );
+        store.observe(KEY_B, "Phone", 3_000).unwrap();
+
+        let loaded = PinStore::load(&path).unwrap();
+        let names: Vec<_> = loaded.list().iter().map(|d| d.display_name()).collect();
+        assert_eq!(names, ["Laptop 2", "Phone"]);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn verify_rename_reverify_revoke() {
+        let path = temp_path("manage.json");
+        let mut store = PinStore::empty(&path);
+        store.observe(KEY_A, "Laptop", 1_000).unwrap();
+
+        store.mark_verified(KEY_A, 2_000).unwrap();
+        assert!(store.get(KEY_A).unwrap().is_verified());
+        assert_eq!(store.get(KEY_A).unwrap().verified_at_ms, Some(2_000));
+
+        store.rename(KEY_A, Some("  Work laptop ")).unwrap();
+        assert_eq!(store.get(KEY_A).unwrap().display_name(), "Work laptop");
+        assert!(store.rename(KEY_A, Some("bad\u{7}")).is_err());
+        store.rename(KEY_A, Some("")).unwrap();
+        assert_eq!(store.get(KEY_A).unwrap().display_name(), "Laptop");
+
+        store.require_reverification(KEY_A).unwrap();
+        let reloaded = PinStore::load(&path).unwrap();
+        assert_eq!(
+            reloaded.get(KEY_A).unwrap().method,
+            VerificationMethod::Tofu
+        );
+
+        store.revoke(KEY_A).unwrap();
+        assert!(store.get(KEY_A).is_none());
+        assert!(store.revoke(KEY_A).is_err());
+        assert!(store.mark_verified(KEY_B, 0).is_err());
+        assert!(PinStore::load(&path).unwrap().list().is_empty());
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn newer_schema_is_refused() {
+        let path = temp_path("newer.json");
+        std::fs::write(&path, r#"{"version":9,"devices":[]}"#).unwrap();
+        assert!(PinStore::load(&path).unwrap_err().contains("newer"));
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn read_only_store_never_overwrites_the_file() {
+        let path = temp_path("corrupt.json");
+        std::fs::write(&path, "{not json").unwrap();
+        let err = PinStore::load(&path).unwrap_err();
+        let mut store = PinStore::read_only(&path, err);
+        assert!(store.read_only_reason().is_some());
+
+        assert!(store
+            .observe(KEY_A, "Laptop", 1_000)
+            .unwrap_err()
+            .contains("read-only"));
+        assert!(store.get(KEY_A).is_none());
+        assert!(store.revoke(KEY_A).is_err());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "{not json");
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn fingerprint_is_stable_and_grouped() {
+        let fp = fingerprint(KEY_A);
+        assert_eq!(fp.len(), 39);
+        assert_eq!(fp.split(' ').count(), 8);
+        assert_eq!(fp, fingerprint(KEY_A));
+        assert_ne!(fp, fingerprint(KEY_B));
+        assert_eq!(fingerprint("not base64!").len(), 39);
+    }
+}
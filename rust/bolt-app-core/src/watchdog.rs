@@ -3,9 +3,11 @@
 //! Implements N3 supervision spec: 5-state machine with retry/backoff,
 //! degraded mode, and version incompatibility detection.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::time::{Duration, Instant};
 
+use crate::state_history::StateHistory;
+
 /// Backoff delays for retries 0, 1, 2.
 const BACKOFF_DELAYS: [Duration; 3] = [
     Duration::from_secs(1),
@@ -14,7 +16,7 @@ const BACKOFF_DELAYS: [Duration; 3] = [
 ];
 
 /// Max retries before entering degraded state.
-const MAX_RETRIES: u32 = 3;
+pub const MAX_RETRIES: u32 = 3;
 
 /// Duration of stable `ready` before retry counter resets.
 const RETRY_RESET_WINDOW: Duration = Duration::from_secs(60);
@@ -23,7 +25,7 @@ const RETRY_RESET_WINDOW: Duration = Duration::from_secs(60);
 pub const STARTUP_TIMEOUT: Duration = Duration::from_secs(10);
 
 /// Watchdog states per N3 spec.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum WatchdogState {
     Starting,
@@ -50,6 +52,7 @@ pub struct Watchdog {
     state: WatchdogState,
     retry_count: u32,
     ready_since: Option<Instant>,
+    history: StateHistory<WatchdogState>,
 }
 
 /// Result of a state transition.
@@ -68,6 +71,7 @@ impl Watchdog {
             state: WatchdogState::Starting,
             retry_count: 0,
             ready_since: None,
+            history: StateHistory::with_default_capacity(),
         }
     }
 
@@ -79,6 +83,11 @@ impl Watchdog {
         self.retry_count
     }
 
+    /// Transition history handle (for support bundles).
+    pub fn history(&self) -> StateHistory<WatchdogState> {
+        self.history.clone()
+    }
+
     /// Transition to `ready` upon receiving daemon.status.
     pub fn on_daemon_ready(&mut self) -> Transition {
         match self.state {
@@ -86,6 +95,7 @@ impl Watchdog {
                 let old = self.state;
                 self.state = WatchdogState::Ready;
                 self.ready_since = Some(Instant::now());
+                self.history.record(old, WatchdogState::Ready, None);
                 tracing::info!("[WATCHDOG] {old} -> ready");
                 Transition::Changed(WatchdogState::Ready)
             }
@@ -101,6 +111,11 @@ impl Watchdog {
         let old = self.state;
         self.state = WatchdogState::Incompatible;
         self.ready_since = None;
+        self.history.record(
+            old,
+            WatchdogState::Incompatible,
+            Some("version mismatch".into()),
+        );
         tracing::warn!("[WATCHDOG] {old} -> incompatible (version mismatch)");
         Transition::Changed(WatchdogState::Incompatible)
     }
@@ -126,6 +141,11 @@ impl Watchdog {
             let old = self.state;
             self.state = WatchdogState::Degraded;
             self.ready_since = None;
+            self.history.record(
+                old,
+                WatchdogState::Degraded,
+                Some(format!("retries exhausted, exit_code={exit_code:?}")),
+            );
             tracing::error!(
                 "[WATCHDOG] {old} -> degraded (retries exhausted after {} attempts, last exit_code={:?})",
                 self.retry_count,
@@ -139,6 +159,14 @@ impl Watchdog {
         let old = self.state;
         self.state = WatchdogState::Restarting;
         self.ready_since = None;
+        self.history.record(
+            old,
+            WatchdogState::Restarting,
+            Some(format!(
+                "retry {}, exit_code={exit_code:?}",
+                self.retry_count
+            )),
+        );
         tracing::warn!(
             "[WATCHDOG] {old} -> restarting (retry {}/{MAX_RETRIES}, delay {}s, exit_code={:?})",
             self.retry_count,
@@ -157,6 +185,11 @@ impl Watchdog {
         let old = self.state;
         self.state = WatchdogState::Degraded;
         self.ready_since = None;
+        self.history.record(
+            old,
+            WatchdogState::Degraded,
+            Some(format!("spawn failure: {reason}")),
+        );
         tracing::error!("[WATCHDOG] {old} -> degraded (spawn failure: {reason})");
         Transition::Changed(WatchdogState::Degraded)
     }
@@ -199,6 +232,11 @@ impl Watchdog {
                 self.state = WatchdogState::Starting;
                 self.retry_count = 0;
                 self.ready_since = None;
+                self.history.record(
+                    WatchdogState::Degraded,
+                    WatchdogState::Starting,
+                    Some("manual restart".into()),
+                );
                 tracing::info!("[WATCHDOG] degraded -> starting (manual restart)");
                 Transition::Changed(WatchdogState::Starting)
             }
@@ -318,6 +356,27 @@ mod tests {
         assert_eq!(t, Transition::Unchanged);
     }
 
+    #[test]
+    fn transitions_are_recorded_in_history() {
+        let mut w = Watchdog::new();
+        w.on_daemon_ready();
+        w.on_daemon_ready(); // unchanged, not recorded
+        w.on_daemon_exit(Some(2));
+        w.on_spawn_failure("missing");
+
+        let history = w.history().entries();
+        let steps: Vec<_> = history.iter().map(|c| (c.from, c.to)).collect();
+        assert_eq!(
+            steps,
+            [
+                (WatchdogState::Starting, WatchdogState::Ready),
+                (WatchdogState::Ready, WatchdogState::Restarting),
+                (WatchdogState::Restarting, WatchdogState::Degraded),
+            ]
+        );
+        assert_eq!(history[2].detail.as_deref(), Some("spawn failure: missing"));
+    }
+
     #[test]
     fn manual_restart_from_degraded() {
         let mut w = Watchdog::new();
@@ -17,6 +17,27 @@ pub const BTR_CHAIN_ADVANCE_INFO: &[u8] = b"bolt-btr-chain-advance-v1";
 /// HKDF info string for DH ratchet step (§16.3).
 pub const BTR_DH_RATCHET_INFO: &[u8] = b"bolt-btr-dh-ratchet-v1";
 
+/// HKDF info string for the X25519 + ML-KEM-768 hybrid combiner (§16.8).
+pub const BTR_HYBRID_COMBINE_INFO: &[u8] = b"bolt-btr-hybrid-combine-v1";
+
+/// Domain label for the PQ hybrid HELLO transcript (§16.8).
+pub const PQ_HELLO_TRANSCRIPT_LABEL: &[u8] = b"bolt-pq-hello-transcript-v1";
+
+/// Capability string advertising the Bolt Transfer Ratchet (§16).
+pub const TRANSFER_RATCHET_CAPABILITY: &str = "bolt.transfer-ratchet-v1";
+
+/// Capability string advertising the X25519 + ML-KEM-768 hybrid handshake (§16.8).
+pub const PQ_HYBRID_CAPABILITY: &str = "bolt.pq-hybrid-mlkem768-v1";
+
+/// ML-KEM-768 encapsulation key length in bytes (FIPS 203).
+pub const MLKEM768_ENCAPSULATION_KEY_LENGTH: usize = 1184;
+
+/// ML-KEM-768 ciphertext length in bytes (FIPS 203).
+pub const MLKEM768_CIPHERTEXT_LENGTH: usize = 1088;
+
+/// ML-KEM shared secret length in bytes (FIPS 203).
+pub const MLKEM_SHARED_SECRET_LENGTH: usize = 32;
+
 /// BTR key length in bytes (all derived keys).
 pub const BTR_KEY_LENGTH: usize = 32;
 
@@ -28,6 +49,9 @@ pub const BTR_WIRE_ERROR_CODES: [&str; 4] = [
     "RATCHET_DOWNGRADE_REJECTED",
 ];
 
+/// PQ hybrid wire error codes (§16.8, `bolt.pq-hybrid-mlkem768-v1`).
+pub const PQ_WIRE_ERROR_CODES: [&str; 2] = ["PQ_HANDSHAKE_FAILED", "PQ_DOWNGRADE_REJECTED"];
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -39,6 +63,21 @@ mod tests {
         assert_eq!(BTR_MESSAGE_KEY_INFO, b"bolt-btr-message-key-v1");
         assert_eq!(BTR_CHAIN_ADVANCE_INFO, b"bolt-btr-chain-advance-v1");
         assert_eq!(BTR_DH_RATCHET_INFO, b"bolt-btr-dh-ratchet-v1");
+        assert_eq!(BTR_HYBRID_COMBINE_INFO, b"bolt-btr-hybrid-combine-v1");
+        assert_eq!(PQ_HELLO_TRANSCRIPT_LABEL, b"bolt-pq-hello-transcript-v1");
+    }
+
+    #[test]
+    fn capabilities_are_namespaced() {
+        assert!(PQ_HYBRID_CAPABILITY.starts_with(bolt_core::constants::CAPABILITY_NAMESPACE));
+        assert!(TRANSFER_RATCHET_CAPABILITY.starts_with(bolt_core::constants::CAPABILITY_NAMESPACE));
+    }
+
+    #[test]
+    fn mlkem768_sizes_match_fips203() {
+        assert_eq!(MLKEM768_ENCAPSULATION_KEY_LENGTH, 1184);
+        assert_eq!(MLKEM768_CIPHERTEXT_LENGTH, 1088);
+        assert_eq!(MLKEM_SHARED_SECRET_LENGTH, 32);
     }
 
     #[test]
@@ -54,7 +93,7 @@ mod tests {
     #[test]
     fn btr_wire_codes_present_in_core_registry() {
         // BTR codes were added to bolt-core's canonical registry (22 → 26).
-        for code in &BTR_WIRE_ERROR_CODES {
+        for code in BTR_WIRE_ERROR_CODES.iter().chain(&PQ_WIRE_ERROR_CODES) {
             assert!(
                 bolt_core::errors::is_valid_wire_error_code(code),
                 "BTR code {code} missing from bolt-core wire error registry"
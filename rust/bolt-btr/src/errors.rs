@@ -5,6 +5,10 @@
 //! - `RatchetChainError` → cancel transfer
 //! - `RatchetDecryptFail` → cancel transfer
 //! - `RatchetDowngradeRejected` → disconnect
+//!
+//! The PQ hybrid handshake (§16.8) adds two more, both disconnect:
+//! - `PqHandshakeFailed`
+//! - `PqDowngradeRejected`
 
 /// BTR-specific error type.
 #[derive(Debug, thiserror::Error)]
@@ -29,6 +33,17 @@ pub enum BtrError {
     /// Triggering semantics deferred to BTR-4.
     #[error("RATCHET_DOWNGRADE_REJECTED: {0}")]
     RatchetDowngradeRejected(String),
+
+    /// Missing or malformed ML-KEM encapsulation key or ciphertext, or
+    /// a KEM operation failed. Required action: send error, disconnect.
+    #[error("PQ_HANDSHAKE_FAILED: {0}")]
+    PqHandshakeFailed(String),
+
+    /// The peers' views of the HELLO capability lists differ, or the PQ
+    /// capability was negotiated without both peers advertising it.
+    /// Required action: send error inside envelope, disconnect.
+    #[error("PQ_DOWNGRADE_REJECTED: {0}")]
+    PqDowngradeRejected(String),
 }
 
 impl BtrError {
@@ -39,6 +54,8 @@ impl BtrError {
             Self::RatchetChainError(_) => "RATCHET_CHAIN_ERROR",
             Self::RatchetDecryptFail(_) => "RATCHET_DECRYPT_FAIL",
             Self::RatchetDowngradeRejected(_) => "RATCHET_DOWNGRADE_REJECTED",
+            Self::PqHandshakeFailed(_) => "PQ_HANDSHAKE_FAILED",
+            Self::PqDowngradeRejected(_) => "PQ_DOWNGRADE_REJECTED",
         }
     }
 
@@ -46,7 +63,10 @@ impl BtrError {
     pub fn requires_disconnect(&self) -> bool {
         matches!(
             self,
-            Self::RatchetStateError(_) | Self::RatchetDowngradeRejected(_)
+            Self::RatchetStateError(_)
+                | Self::RatchetDowngradeRejected(_)
+                | Self::PqHandshakeFailed(_)
+                | Self::PqDowngradeRejected(_)
         )
     }
 }
@@ -68,6 +88,12 @@ mod tests {
 
         let err = BtrError::RatchetDowngradeRejected("test".into());
         assert_eq!(err.wire_code(), "RATCHET_DOWNGRADE_REJECTED");
+
+        let err = BtrError::PqHandshakeFailed("test".into());
+        assert_eq!(err.wire_code(), "PQ_HANDSHAKE_FAILED");
+
+        let err = BtrError::PqDowngradeRejected("test".into());
+        assert_eq!(err.wire_code(), "PQ_DOWNGRADE_REJECTED");
     }
 
     #[test]
@@ -76,6 +102,29 @@ mod tests {
         assert!(!BtrError::RatchetChainError("".into()).requires_disconnect());
         assert!(!BtrError::RatchetDecryptFail("".into()).requires_disconnect());
         assert!(BtrError::RatchetDowngradeRejected("".into()).requires_disconnect());
+        assert!(BtrError::PqHandshakeFailed("".into()).requires_disconnect());
+        assert!(BtrError::PqDowngradeRejected("".into()).requires_disconnect());
+    }
+
+    #[test]
+    fn disconnect_semantics_match_core_registry() {
+        use bolt_core::errors::{wire_error_spec, WireErrorAction};
+        for err in [
+            BtrError::RatchetStateError(String::new()),
+            BtrError::RatchetChainError(String::new()),
+            BtrError::RatchetDecryptFail(String::new()),
+            BtrError::RatchetDowngradeRejected(String::new()),
+            BtrError::PqHandshakeFailed(String::new()),
+            BtrError::PqDowngradeRejected(String::new()),
+        ] {
+            let action = wire_error_spec(err.wire_code()).unwrap().action;
+            assert_eq!(
+                action == WireErrorAction::Disconnect,
+                err.requires_disconnect(),
+                "{}",
+                err.wire_code()
+            );
+        }
     }
 
     #[test]
@@ -0,0 +1,486 @@
+//! Post-quantum hybrid key agreement — X25519 + ML-KEM-768 (§16.8).
+//!
+//! When both peers negotiate `bolt.pq-hybrid-mlkem768-v1`, the handshake
+//! runs an ML-KEM-768 encapsulation alongside the X25519 ephemeral exchange.
+//! Both shared secrets are combined into a single secret that replaces the
+//! X25519-only `ephemeral_shared_secret` input of [`derive_session_root`]:
+//!
+//! ```text
+//! transcript      = SHA-256( for cap in sort(dedup(negotiated_caps)):
+//!                              u32_be(len(cap)) || cap )
+//! combined_secret = HKDF-SHA256(
+//!   salt  = transcript,
+//!   ikm   = x25519_shared_secret || mlkem_shared_secret,
+//!   info  = "bolt-btr-hybrid-combine-v1",
+//!   len   = 32
+//! )
+//! session_root_key = derive_session_root(combined_secret)
+//! ```
+//!
+//! The transcript is order-independent because each peer computes the
+//! intersection in its own local order.
+//!
+//! ## Handshake
+//!
+//! §16.8 is not in PROTOCOL.md yet; `docs/PROTOCOL_CONTRACT.md` §10
+//! carries its text. In short:
+//!
+//! 1. The peer whose X25519 ephemeral key sorts first is the KEM
+//!    initiator ([`is_kem_initiator`]). Its inner HELLO carries
+//!    `pqEncapsulationKey`.
+//! 2. Once HELLO completes with the capability negotiated, the responder
+//!    encapsulates and sends `pq-ciphertext` with the
+//!    [`hello_transcript`].
+//! 3. The initiator decapsulates, checks the transcript and answers
+//!    `pq-confirm` with its own. The responder checks that one too.
+//!
+//! A transcript mismatch means the two peers saw different HELLO
+//! capability lists and is `PQ_DOWNGRADE_REJECTED`. Stripping the
+//! capability from *both* HELLOs leaves both peers in classical mode with
+//! nothing to compare, but HELLO is sealed to the ephemeral keys, so doing
+//! that means substituting them, which the SAS exposes.
+//!
+//! The ML-KEM primitive itself requires the `pq-hybrid` feature. The
+//! combiner, transcript and negotiation check are always available so that
+//! vectors and non-PQ peers can verify derivations without pulling in the
+//! KEM.
+
+use hkdf::Hkdf;
+use sha2::{Digest, Sha256};
+use zeroize::Zeroize;
+
+use crate::constants::{
+    BTR_HYBRID_COMBINE_INFO, BTR_KEY_LENGTH, PQ_HELLO_TRANSCRIPT_LABEL, PQ_HYBRID_CAPABILITY,
+};
+use crate::errors::BtrError;
+use crate::key_schedule::derive_session_root;
+
+/// Hybrid negotiation result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HybridMode {
+    /// Both peers negotiated the hybrid capability. X25519 + ML-KEM-768.
+    Hybrid,
+    /// At least one peer does not advertise the hybrid capability.
+    /// X25519 only, via [`derive_session_root`].
+    Classical,
+}
+
+/// Hash the negotiated capability list into a canonical transcript (§16.8).
+///
+/// Entries are deduplicated and sorted bytewise before hashing, and each
+/// entry is length-prefixed (u32 big-endian) so that no two distinct
+/// lists share an encoding.
+pub fn capability_transcript(negotiated_capabilities: &[String]) -> [u8; 32] {
+    let mut caps: Vec<&str> = negotiated_capabilities.iter().map(String::as_str).collect();
+    caps.sort_unstable();
+    caps.dedup();
+
+    let mut hasher = Sha256::new();
+    for cap in caps {
+        hasher.update((cap.len() as u32).to_be_bytes());
+        hasher.update(cap.as_bytes());
+    }
+    hasher.finalize().into()
+}
+
+/// Combine the X25519 and ML-KEM shared secrets into one secret (§16.8).
+///
+/// The result is the `ephemeral_shared_secret` input for
+/// [`derive_session_root`]. See the module docs for the exact construction.
+pub fn combine_hybrid_secret(
+    x25519_shared_secret: &[u8; 32],
+    mlkem_shared_secret: &[u8; 32],
+    negotiated_capabilities: &[String],
+) -> [u8; BTR_KEY_LENGTH] {
+    let transcript = capability_transcript(negotiated_capabilities);
+
+    let mut ikm = [0u8; 64];
+    ikm[..32].copy_from_slice(x25519_shared_secret);
+    ikm[32..].copy_from_slice(mlkem_shared_secret);
+
+    let hk = Hkdf::<Sha256>::new(Some(&transcript), &ikm);
+    ikm.zeroize();
+
+    let mut okm = [0u8; BTR_KEY_LENGTH];
+    hk.expand(BTR_HYBRID_COMBINE_INFO, &mut okm)
+        .expect("HKDF expand with 32-byte output must not fail");
+    okm
+}
+
+/// Derive the session root key from a hybrid handshake (§16.8).
+///
+/// Equivalent to `derive_session_root(combine_hybrid_secret(..))`.
+pub fn derive_hybrid_session_root(
+    x25519_shared_secret: &[u8; 32],
+    mlkem_shared_secret: &[u8; 32],
+    negotiated_capabilities: &[String],
+) -> [u8; BTR_KEY_LENGTH] {
+    let mut combined = combine_hybrid_secret(
+        x25519_shared_secret,
+        mlkem_shared_secret,
+        negotiated_capabilities,
+    );
+    let srk = derive_session_root(&combined);
+    combined.zeroize();
+    srk
+}
+
+/// Decide the hybrid mode and check the negotiated set for downgrade (§16.8).
+///
+/// `local` and `remote` are the capability lists each peer advertised in
+/// HELLO; `negotiated` is the intersection the caller intends to use.
+///
+/// | Local | Remote | Negotiated | Result |
+/// |-------|--------|------------|--------|
+/// | YES   | YES    | YES        | Hybrid |
+/// | YES   | YES    | NO         | `PQ_DOWNGRADE_REJECTED` |
+/// | YES   | NO     | -          | Classical |
+/// | NO    | YES    | -          | Classical |
+/// | NO    | NO     | -          | Classical |
+///
+/// A negotiated set that contains the capability while either advertisement
+/// lacks it is also rejected — the intersection cannot produce it honestly.
+pub fn negotiate_hybrid(
+    local: &[String],
+    remote: &[String],
+    negotiated: &[String],
+) -> Result<HybridMode, BtrError> {
+    let has = |caps: &[String]| caps.iter().any(|c| c == PQ_HYBRID_CAPABILITY);
+    match (has(local), has(remote), has(negotiated)) {
+        (true, true, true) => Ok(HybridMode::Hybrid),
+        (true, true, false) => Err(BtrError::PqDowngradeRejected(
+            "both peers advertise PQ hybrid but it was not negotiated".into(),
+        )),
+        (_, _, true) => Err(BtrError::PqDowngradeRejected(
+            "PQ hybrid negotiated without mutual advertisement".into(),
+        )),
+        (_, _, false) => Ok(HybridMode::Classical),
+    }
+}
+
+/// Whether the local peer is the KEM initiator (§16.8).
+///
+/// The initiator is the peer whose X25519 ephemeral public key sorts first
+/// bytewise. Both peers know both ephemeral keys before HELLO, so they
+/// agree on the roles without another round trip.
+///
+/// # Errors
+/// `PqHandshakeFailed` if the two keys are identical.
+pub fn is_kem_initiator(
+    local_ephemeral: &[u8; 32],
+    remote_ephemeral: &[u8; 32],
+) -> Result<bool, BtrError> {
+    match local_ephemeral.cmp(remote_ephemeral) {
+        std::cmp::Ordering::Less => Ok(true),
+        std::cmp::Ordering::Greater => Ok(false),
+        std::cmp::Ordering::Equal => Err(BtrError::PqHandshakeFailed(
+            "identical ephemeral keys".into(),
+        )),
+    }
+}
+
+/// Hash both peers' advertised HELLO capabilities and the ML-KEM
+/// encapsulation key into the transcript exchanged in `pq-ciphertext` and
+/// `pq-confirm` (§16.8).
+///
+/// ```text
+/// SHA-256( "bolt-pq-hello-transcript-v1"
+///          || capability_transcript(initiator_capabilities)
+///          || capability_transcript(responder_capabilities)
+///          || SHA-256(encapsulation_key) )
+/// ```
+///
+/// The lists are what each peer advertised, not the intersection, so a
+/// capability stripped from either HELLO changes the hash.
+pub fn hello_transcript(
+    initiator_capabilities: &[String],
+    responder_capabilities: &[String],
+    encapsulation_key: &[u8],
+) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(PQ_HELLO_TRANSCRIPT_LABEL);
+    hasher.update(capability_transcript(initiator_capabilities));
+    hasher.update(capability_transcript(responder_capabilities));
+    hasher.update(Sha256::digest(encapsulation_key));
+    hasher.finalize().into()
+}
+
+/// Compare the peer's transcript with ours (§16.8).
+///
+/// # Errors
+/// `PqDowngradeRejected` if they differ.
+pub fn check_hello_transcript(expected: &[u8; 32], received: &[u8]) -> Result<(), BtrError> {
+    if received == expected {
+        Ok(())
+    } else {
+        Err(BtrError::PqDowngradeRejected(
+            "peers saw different HELLO capability lists".into(),
+        ))
+    }
+}
+
+/// Returns the log token for a given hybrid mode.
+pub fn hybrid_log_token(mode: HybridMode) -> Option<&'static str> {
+    match mode {
+        HybridMode::Hybrid => Some("[PQ_HYBRID]"),
+        HybridMode::Classical => None,
+    }
+}
+
+// ── ML-KEM-768 (feature `pq-hybrid`) ─────────────────────────
+
+#[cfg(feature = "pq-hybrid")]
+pub use kem::{mlkem_encapsulate, MlKemKeypair};
+
+#[cfg(feature = "pq-hybrid")]
+mod kem {
+    use ml_kem::kem::{Decapsulate, Encapsulate};
+    use ml_kem::{Ciphertext, EncodedSizeUser, KemCore, MlKem768};
+    use rand_core::OsRng;
+
+    use crate::constants::{MLKEM768_CIPHERTEXT_LENGTH, MLKEM768_ENCAPSULATION_KEY_LENGTH};
+    use crate::errors::BtrError;
+
+    type DecapsulationKey = <MlKem768 as KemCore>::DecapsulationKey;
+    type EncapsulationKey = <MlKem768 as KemCore>::EncapsulationKey;
+
+    /// ML-KEM-768 keypair held by the HELLO initiator.
+    ///
+    /// The encapsulation key is sent to the peer; the decapsulation key
+    /// never leaves this struct and is zeroized on drop by `ml-kem`.
+    pub struct MlKemKeypair {
+        decapsulation_key: DecapsulationKey,
+        encapsulation_key: Vec<u8>,
+    }
+
+    impl MlKemKeypair {
+        /// Generate a fresh ML-KEM-768 keypair. Uses OS CSPRNG.
+        pub fn generate() -> Self {
+            let (dk, ek) = MlKem768::generate(&mut OsRng);
+            Self {
+                decapsulation_key: dk,
+                encapsulation_key: ek.as_bytes().to_vec(),
+            }
+        }
+
+        /// Encoded encapsulation key (1184 bytes) to send to the peer.
+        pub fn encapsulation_key(&self) -> &[u8] {
+            &self.encapsulation_key
+        }
+
+        /// Recover the shared secret from the peer's ciphertext.
+        ///
+        /// # Errors
+        /// Returns `PqHandshakeFailed` if the ciphertext has the wrong length.
+        pub fn decapsulate(&self, ciphertext: &[u8]) -> Result<[u8; 32], BtrError> {
+            if ciphertext.len() != MLKEM768_CIPHERTEXT_LENGTH {
+                return Err(BtrError::PqHandshakeFailed(format!(
+                    "ML-KEM-768 ciphertext must be {MLKEM768_CIPHERTEXT_LENGTH} bytes, got {}",
+                    ciphertext.len()
+                )));
+            }
+            let ct = Ciphertext::<MlKem768>::try_from(ciphertext).map_err(|_| {
+                BtrError::PqHandshakeFailed("malformed ML-KEM-768 ciphertext".into())
+            })?;
+            let shared = self
+                .decapsulation_key
+                .decapsulate(&ct)
+                .map_err(|_| BtrError::PqHandshakeFailed("ML-KEM decapsulation failed".into()))?;
+            Ok(shared.into())
+        }
+    }
+
+    /// Encapsulate to the peer's encapsulation key.
+    ///
+    /// Returns `(ciphertext, shared_secret)`. The ciphertext (1088 bytes) is
+    /// sent back to the keypair holder.
+    ///
+    /// # Errors
+    /// Returns `PqHandshakeFailed` if the encapsulation key has the wrong length.
+    pub fn mlkem_encapsulate(encapsulation_key: &[u8]) -> Result<(Vec<u8>, [u8; 32]), BtrError> {
+        if encapsulation_key.len() != MLKEM768_ENCAPSULATION_KEY_LENGTH {
+            return Err(BtrError::PqHandshakeFailed(format!(
+                "ML-KEM-768 encapsulation key must be {MLKEM768_ENCAPSULATION_KEY_LENGTH} bytes, got {}",
+                encapsulation_key.len()
+            )));
+        }
+        let encoded = encapsulation_key.try_into().map_err(|_| {
+            BtrError::PqHandshakeFailed("malformed ML-KEM-768 encapsulation key".into())
+        })?;
+        let ek = EncapsulationKey::from_bytes(encoded);
+        let (ct, shared) = ek
+            .encapsulate(&mut OsRng)
+            .map_err(|_| BtrError::PqHandshakeFailed("ML-KEM encapsulation failed".into()))?;
+        Ok((ct.to_vec(), shared.into()))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn encapsulate_decapsulate_roundtrip() {
+            let kp = MlKemKeypair::generate();
+            assert_eq!(
+                kp.encapsulation_key().len(),
+                MLKEM768_ENCAPSULATION_KEY_LENGTH
+            );
+            let (ct, ss_sender) = mlkem_encapsulate(kp.encapsulation_key()).unwrap();
+            assert_eq!(ct.len(), MLKEM768_CIPHERTEXT_LENGTH);
+            let ss_receiver = kp.decapsulate(&ct).unwrap();
+            assert_eq!(ss_sender, ss_receiver);
+        }
+
+        #[test]
+        fn wrong_length_inputs_rejected() {
+            let kp = MlKemKeypair::generate();
+            assert!(mlkem_encapsulate(&[0u8; 32]).is_err());
+            assert!(kp.decapsulate(&[0u8; 32]).is_err());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn caps(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn transcript_order_independent() {
+        let a = capability_transcript(&caps(&["bolt.file-hash", PQ_HYBRID_CAPABILITY]));
+        let b = capability_transcript(&caps(&[PQ_HYBRID_CAPABILITY, "bolt.file-hash"]));
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn transcript_ignores_duplicates() {
+        let a = capability_transcript(&caps(&["bolt.file-hash"]));
+        let b = capability_transcript(&caps(&["bolt.file-hash", "bolt.file-hash"]));
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn transcript_length_prefix_prevents_ambiguity() {
+        let a = capability_transcript(&caps(&["bolt.ab", "bolt.c"]));
+        let b = capability_transcript(&caps(&["bolt.a", "bolt.bc"]));
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn combined_secret_deterministic() {
+        let list = caps(&[PQ_HYBRID_CAPABILITY]);
+        let a = combine_hybrid_secret(&[0x01; 32], &[0x02; 32], &list);
+        let b = combine_hybrid_secret(&[0x01; 32], &[0x02; 32], &list);
+        assert_eq!(a, b);
+        assert_ne!(a, [0u8; 32]);
+    }
+
+    #[test]
+    fn combined_secret_binds_both_inputs() {
+        let list = caps(&[PQ_HYBRID_CAPABILITY]);
+        let base = combine_hybrid_secret(&[0x01; 32], &[0x02; 32], &list);
+        assert_ne!(base, combine_hybrid_secret(&[0x03; 32], &[0x02; 32], &list));
+        assert_ne!(base, combine_hybrid_secret(&[0x01; 32], &[0x03; 32], &list));
+    }
+
+    #[test]
+    fn stripped_capability_changes_session_root() {
+        let full = caps(&["bolt.file-hash", PQ_HYBRID_CAPABILITY]);
+        let stripped = caps(&["bolt.file-hash"]);
+        let a = derive_hybrid_session_root(&[0x01; 32], &[0x02; 32], &full);
+        let b = derive_hybrid_session_root(&[0x01; 32], &[0x02; 32], &stripped);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn hybrid_root_differs_from_classical_root() {
+        let list = caps(&[PQ_HYBRID_CAPABILITY]);
+        let hybrid = derive_hybrid_session_root(&[0x01; 32], &[0x02; 32], &list);
+        assert_ne!(hybrid, derive_session_root(&[0x01; 32]));
+    }
+
+    #[test]
+    fn negotiate_both_advertise() {
+        let list = caps(&[PQ_HYBRID_CAPABILITY]);
+        assert_eq!(
+            negotiate_hybrid(&list, &list, &list).unwrap(),
+            HybridMode::Hybrid
+        );
+    }
+
+    #[test]
+    fn negotiate_one_sided_is_classical() {
+        let pq = caps(&[PQ_HYBRID_CAPABILITY]);
+        let none = caps(&["bolt.file-hash"]);
+        assert_eq!(
+            negotiate_hybrid(&pq, &none, &[]).unwrap(),
+            HybridMode::Classical
+        );
+        assert_eq!(
+            negotiate_hybrid(&none, &pq, &[]).unwrap(),
+            HybridMode::Classical
+        );
+        assert_eq!(
+            negotiate_hybrid(&none, &none, &none).unwrap(),
+            HybridMode::Classical
+        );
+    }
+
+    #[test]
+    fn negotiate_stripped_capability_rejected() {
+        let pq = caps(&[PQ_HYBRID_CAPABILITY, "bolt.file-hash"]);
+        let err = negotiate_hybrid(&pq, &pq, &caps(&["bolt.file-hash"])).unwrap_err();
+        assert_eq!(err.wire_code(), "PQ_DOWNGRADE_REJECTED");
+        assert!(err.requires_disconnect());
+    }
+
+    #[test]
+    fn negotiate_injected_capability_rejected() {
+        let pq = caps(&[PQ_HYBRID_CAPABILITY]);
+        let err = negotiate_hybrid(&pq, &[], &pq).unwrap_err();
+        assert_eq!(err.wire_code(), "PQ_DOWNGRADE_REJECTED");
+    }
+
+    #[test]
+    fn kem_initiator_is_lower_ephemeral_key() {
+        assert!(is_kem_initiator(&[0x01; 32], &[0x02; 32]).unwrap());
+        assert!(!is_kem_initiator(&[0x02; 32], &[0x01; 32]).unwrap());
+        let err = is_kem_initiator(&[0x01; 32], &[0x01; 32]).unwrap_err();
+        assert_eq!(err.wire_code(), "PQ_HANDSHAKE_FAILED");
+    }
+
+    #[test]
+    fn hello_transcript_detects_one_sided_strip() {
+        let full = caps(&["bolt.file-hash", PQ_HYBRID_CAPABILITY]);
+        let stripped = caps(&["bolt.file-hash"]);
+        let ek = [0x05; 16];
+        let honest = hello_transcript(&full, &full, &ek);
+        check_hello_transcript(&honest, &hello_transcript(&full, &full, &ek)).unwrap();
+
+        // The responder saw a stripped initiator HELLO.
+        let err =
+            check_hello_transcript(&honest, &hello_transcript(&stripped, &full, &ek)).unwrap_err();
+        assert_eq!(err.wire_code(), "PQ_DOWNGRADE_REJECTED");
+        assert!(err.requires_disconnect());
+        assert_ne!(honest, hello_transcript(&full, &stripped, &ek));
+    }
+
+    #[test]
+    fn hello_transcript_binds_roles_and_key() {
+        let a = caps(&["bolt.file-hash", PQ_HYBRID_CAPABILITY]);
+        let b = caps(&[PQ_HYBRID_CAPABILITY]);
+        let base = hello_transcript(&a, &b, &[0x05; 16]);
+        assert_ne!(base, hello_transcript(&b, &a, &[0x05; 16]));
+        assert_ne!(base, hello_transcript(&a, &b, &[0x06; 16]));
+        assert!(check_hello_transcript(&base, &base[..31]).is_err());
+    }
+
+    #[test]
+    fn log_tokens() {
+        assert_eq!(hybrid_log_token(HybridMode::Hybrid), Some("[PQ_HYBRID]"));
+        assert_eq!(hybrid_log_token(HybridMode::Classical), None);
+    }
+}
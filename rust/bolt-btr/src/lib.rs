@@ -22,6 +22,7 @@
 //! | [`state`] | Session/transfer engine and lifecycle (§16.5) |
 //! | [`replay`] | Replay rejection guard (§11) |
 //! | [`negotiate`] | Capability negotiation matrix (§4) |
+//! | [`hybrid`] | X25519 + ML-KEM-768 hybrid key agreement (§16.8) |
 //! | [`vectors`] | Golden vector generator (test-only, feature-gated) |
 //!
 //! # Security Properties
@@ -57,6 +58,10 @@ pub mod replay;
 /// Capability negotiation matrix (§4).
 pub mod negotiate;
 
+/// X25519 + ML-KEM-768 hybrid key agreement (§16.8).
+/// The ML-KEM primitive requires the `pq-hybrid` feature.
+pub mod hybrid;
+
 /// BTR golden vector generator (test-only).
 /// Requires the `vectors` feature: `cargo test --features vectors`.
 #[cfg(feature = "vectors")]
@@ -64,5 +69,6 @@ pub mod vectors;
 
 // Re-exports for convenience.
 pub use errors::BtrError;
+pub use hybrid::{check_hello_transcript, hello_transcript, negotiate_hybrid, HybridMode};
 pub use negotiate::{negotiate_btr, BtrMode};
 pub use state::{BtrEngine, BtrTransferContext};
@@ -8,6 +8,7 @@ use zeroize::{Zeroize, ZeroizeOnDrop};
 use crate::constants::BTR_KEY_LENGTH;
 use crate::encrypt::{btr_open, btr_seal};
 use crate::errors::BtrError;
+use crate::hybrid::derive_hybrid_session_root;
 use crate::key_schedule::{chain_advance, derive_session_root, derive_transfer_root};
 use crate::ratchet::{derive_ratcheted_session_root, RatchetKeypair};
 use crate::replay::ReplayGuard;
@@ -54,6 +55,30 @@ impl BtrEngine {
         }
     }
 
+    /// Create a new BTR engine from a hybrid X25519 + ML-KEM-768 handshake (§16.8).
+    ///
+    /// Called instead of [`BtrEngine::new`] when both peers negotiated
+    /// `bolt.pq-hybrid-mlkem768-v1`. `negotiated_capabilities` is mixed
+    /// into the session root so that a tampered capability set yields
+    /// mismatched keys.
+    pub fn new_hybrid(
+        x25519_shared_secret: &[u8; 32],
+        mlkem_shared_secret: &[u8; 32],
+        negotiated_capabilities: &[String],
+    ) -> Self {
+        let session_root_key = derive_hybrid_session_root(
+            x25519_shared_secret,
+            mlkem_shared_secret,
+            negotiated_capabilities,
+        );
+        Self {
+            session_root_key: SecretKey32::new(session_root_key),
+            ratchet_generation: 0,
+            local_ratchet_keypair: None,
+            replay_guard: ReplayGuard::new(),
+        }
+    }
+
     /// Current ratchet generation (monotonically increasing per session).
     pub fn ratchet_generation(&self) -> u32 {
         self.ratchet_generation
@@ -352,6 +377,15 @@ mod tests {
         assert_eq!(a.session_root_key(), b.session_root_key());
     }
 
+    #[test]
+    fn hybrid_engine_differs_from_classical() {
+        let caps = vec![crate::constants::PQ_HYBRID_CAPABILITY.to_string()];
+        let classical = BtrEngine::new(&make_shared_secret());
+        let hybrid = BtrEngine::new_hybrid(&make_shared_secret(), &[0xCD; 32], &caps);
+        assert_eq!(hybrid.ratchet_generation(), 0);
+        assert_ne!(classical.session_root_key(), hybrid.session_root_key());
+    }
+
     #[test]
     fn transfer_roundtrip() {
         let shared_secret = make_shared_secret();
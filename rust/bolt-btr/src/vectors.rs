@@ -893,3 +893,110 @@ pub fn generate_adversarial_json() -> String {
     };
     serde_json::to_string_pretty(&data).unwrap() + "\n"
 }
+
+// ── btr-hybrid-combine (X25519 + ML-KEM-768) ────────────────────────
+
+#[derive(Serialize)]
+struct HybridCombineVectors {
+    #[serde(rename = "_WARNING")]
+    warning: String,
+    description: String,
+    vectors: Vec<HybridCombineVector>,
+}
+
+#[derive(Serialize)]
+struct HybridCombineVector {
+    id: String,
+    description: String,
+    x25519_shared_secret_hex: String,
+    mlkem_shared_secret_hex: String,
+    negotiated_capabilities: Vec<String>,
+    expected_capability_transcript_hex: String,
+    expected_combined_secret_hex: String,
+    expected_session_root_key_hex: String,
+}
+
+/// Generate hybrid combiner vectors.
+///
+/// Covers distinct secrets, capability-order invariance (same expected
+/// outputs for a permuted list), and a stripped capability set (different
+/// session root for otherwise identical secrets).
+pub fn generate_hybrid_combine_json() -> String {
+    use crate::constants::PQ_HYBRID_CAPABILITY;
+    use crate::hybrid::{capability_transcript, combine_hybrid_secret, derive_hybrid_session_root};
+
+    let full = vec![
+        "bolt.file-hash".to_string(),
+        "bolt.profile-envelope-v1".to_string(),
+        PQ_HYBRID_CAPABILITY.to_string(),
+        "bolt.transfer-ratchet-v1".to_string(),
+    ];
+    let mut permuted = full.clone();
+    permuted.reverse();
+    let stripped: Vec<String> = full
+        .iter()
+        .filter(|c| c.as_str() != PQ_HYBRID_CAPABILITY)
+        .cloned()
+        .collect();
+
+    let cases = [
+        (
+            "hybrid-0",
+            "Baseline hybrid combine.",
+            0x10,
+            0x90,
+            full.clone(),
+        ),
+        (
+            "hybrid-1",
+            "Different secrets, same capabilities.",
+            0x20,
+            0xA0,
+            full.clone(),
+        ),
+        (
+            "hybrid-permuted",
+            "Same as hybrid-0 with capability order reversed — outputs MUST equal hybrid-0.",
+            0x10,
+            0x90,
+            permuted,
+        ),
+        (
+            "hybrid-stripped",
+            "Same secrets as hybrid-0 with the PQ capability stripped — session root MUST differ.",
+            0x10,
+            0x90,
+            stripped,
+        ),
+    ];
+
+    let vectors: Vec<HybridCombineVector> = cases
+        .into_iter()
+        .map(|(id, desc, x_seed, k_seed, caps)| {
+            let x25519 = make_key(x_seed);
+            let mlkem = make_key(k_seed);
+            HybridCombineVector {
+                id: id.into(),
+                description: desc.into(),
+                x25519_shared_secret_hex: to_hex(&x25519),
+                mlkem_shared_secret_hex: to_hex(&mlkem),
+                expected_capability_transcript_hex: to_hex(&capability_transcript(&caps)),
+                expected_combined_secret_hex: to_hex(&combine_hybrid_secret(
+                    &x25519, &mlkem, &caps,
+                )),
+                expected_session_root_key_hex: to_hex(&derive_hybrid_session_root(
+                    &x25519, &mlkem, &caps,
+                )),
+                negotiated_capabilities: caps,
+            }
+        })
+        .collect();
+
+    let data = HybridCombineVectors {
+        warning: "TEST FIXTURES ONLY — deterministic keys, not for production.".into(),
+        description:
+            "X25519 + ML-KEM-768 hybrid combiner with capability transcript binding (§16.8).".into(),
+        vectors,
+    };
+    serde_json::to_string_pretty(&data).unwrap() + "\n"
+}
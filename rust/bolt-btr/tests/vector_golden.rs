@@ -120,11 +120,41 @@ fn generate_adversarial_vectors() {
     );
 }
 
+#[test]
+fn generate_hybrid_combine_vectors() {
+    write_and_verify(
+        "btr-hybrid-combine.vectors.json",
+        vectors::generate_hybrid_combine_json,
+    );
+}
+
+#[test]
+fn hybrid_combine_permuted_matches_baseline() {
+    let json: serde_json::Value =
+        serde_json::from_str(&vectors::generate_hybrid_combine_json()).unwrap();
+    let vecs = json["vectors"].as_array().unwrap();
+    let find = |id: &str| vecs.iter().find(|v| v["id"] == id).unwrap();
+    let base = find("hybrid-0");
+    let permuted = find("hybrid-permuted");
+    let stripped = find("hybrid-stripped");
+    assert_eq!(
+        base["expected_session_root_key_hex"],
+        permuted["expected_session_root_key_hex"]
+    );
+    assert_ne!(
+        base["expected_session_root_key_hex"],
+        stripped["expected_session_root_key_hex"]
+    );
+}
+
+/// A vector file name and the generator that writes it.
+type Generator = (&'static str, fn() -> String);
+
 /// Generates all vector files and then verifies they all exist.
 #[test]
 fn all_vector_files_present() {
     let dir = vector_dir();
-    let generators: Vec<(&str, fn() -> String)> = vec![
+    let generators: Vec<Generator> = vec![
         (
             "btr-key-schedule.vectors.json",
             vectors::generate_key_schedule_json,
@@ -165,6 +195,10 @@ fn all_vector_files_present() {
             "btr-adversarial.vectors.json",
             vectors::generate_adversarial_json,
         ),
+        (
+            "btr-hybrid-combine.vectors.json",
+            vectors::generate_hybrid_combine_json,
+        ),
     ];
     for (filename, gen) in &generators {
         let path = dir.join(filename);
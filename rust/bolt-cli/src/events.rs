@@ -0,0 +1,191 @@
+//! Progress events.
+//!
+//! Every observable step is an [`Event`]. The binary renders them either
+//! as short human-readable lines or, with `--json`, as one JSON object per
+//! line on stdout (`{"event":"progress","transfer_id":...}`).
+
+use std::io::Write;
+
+use serde::Serialize;
+
+/// One observable step of a CLI session.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    /// Registered on the rendezvous under `peer_code`.
+    Registered {
+        peer_code: String,
+    },
+    /// Peer exchanged keys with us.
+    PeerConnected {
+        peer_code: String,
+        device_name: String,
+    },
+    /// SAS to compare with the peer (hex and PGP words).
+    Sas {
+        sas: String,
+        words: String,
+    },
+    /// Both sides confirmed the SAS.
+    Verified,
+    /// A file offer was sent or accepted.
+    Offer {
+        transfer_id: String,
+        filename: String,
+        size: u64,
+    },
+    Progress {
+        transfer_id: String,
+        bytes: u64,
+        total: u64,
+    },
+    FileComplete {
+        transfer_id: String,
+        filename: String,
+        size: u64,
+        hash_verified: bool,
+    },
+    /// Local file not sent (empty, symlink, ...).
+    Skipped {
+        path: String,
+        reason: String,
+    },
+    Done {
+        files: u32,
+        bytes: u64,
+    },
+    Error {
+        code: &'static str,
+        message: String,
+    },
+}
+
+/// Receives events as they happen.
+pub trait EventSink {
+    fn emit(&mut self, event: Event);
+}
+
+/// Collects events in memory (tests, embedding).
+impl EventSink for Vec<Event> {
+    fn emit(&mut self, event: Event) {
+        self.push(event);
+    }
+}
+
+/// Renders events to stdout (errors to stderr in text mode). Progress is
+/// throttled to whole percents in JSON mode and tens in text mode.
+pub struct Printer {
+    json: bool,
+    last_percent: u64,
+}
+
+impl Printer {
+    pub fn new(json: bool) -> Self {
+        Self {
+            json,
+            last_percent: 0,
+        }
+    }
+
+    /// Whether a progress event crosses the next reporting step.
+    fn progress_step(&mut self, bytes: u64, total: u64) -> Option<u64> {
+        let step = if self.json { 1 } else { 10 };
+        let percent = bytes * 100 / total.max(1) / step * step;
+        if percent <= self.last_percent {
+            return None;
+        }
+        self.last_percent = percent;
+        Some(percent)
+    }
+
+    fn text(&mut self, event: &Event) -> Option<String> {
+        Some(match event {
+            Event::Registered { peer_code } => format!("peer code: {peer_code}"),
+            Event::PeerConnected {
+                peer_code,
+                device_name,
+            } => format!("connected to {device_name} ({peer_code})"),
+            Event::Sas { sas, words } => format!("SAS: {sas}  ({words})"),
+            Event::Verified => "SAS confirmed by both peers".to_string(),
+            Event::Offer { filename, size, .. } => format!("{filename} ({size} bytes)"),
+            Event::Progress { bytes, total, .. } => {
+                format!("  {}%", self.progress_step(*bytes, *total)?)
+            }
+            Event::FileComplete {
+                filename,
+                hash_verified,
+                ..
+            } => {
+                let check = if *hash_verified { ", sha-256 ok" } else { "" };
+                format!("  done: {filename}{check}")
+            }
+            Event::Skipped { path, reason } => format!("skipped {path}: {reason}"),
+            Event::Done { files, bytes } => format!("finished: {files} file(s), {bytes} bytes"),
+            Event::Error { .. } => return None,
+        })
+    }
+}
+
+impl EventSink for Printer {
+    fn emit(&mut self, event: Event) {
+        match &event {
+            Event::Offer { .. } => self.last_percent = 0,
+            Event::Progress { bytes, total, .. }
+                if self.json && self.progress_step(*bytes, *total).is_none() =>
+            {
+                return
+            }
+            _ => {}
+        }
+        if self.json {
+            let mut out = std::io::stdout().lock();
+            let _ = serde_json::to_writer(&mut out, &event);
+            let _ = writeln!(out);
+            let _ = out.flush();
+        } else if let Event::Error { code, message } = &event {
+            eprintln!("error [{code}]: {message}");
+        } else if let Some(line) = self.text(&event) {
+            println!("{line}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn events_serialize_as_tagged_json_lines() {
+        let json = serde_json::to_string(&Event::Progress {
+            transfer_id: "t0".into(),
+            bytes: 10,
+            total: 20,
+        })
+        .unwrap();
+        assert_eq!(
+            json,
+            r#"{"event":"progress","transfer_id":"t0","bytes":10,"total":20}"#
+        );
+        let json = serde_json::to_string(&Event::Verified).unwrap();
+        assert_eq!(json, r#"{"event":"verified"}"#);
+    }
+
+    #[test]
+    fn progress_is_throttled() {
+        let mut p = Printer::new(false);
+        let progress = |bytes| Event::Progress {
+            transfer_id: "t0".into(),
+            bytes,
+            total: 100,
+        };
+        assert_eq!(p.text(&progress(5)), None);
+        assert_eq!(p.text(&progress(12)).as_deref(), Some("  10%"));
+        assert_eq!(p.text(&progress(15)), None);
+        assert_eq!(p.text(&progress(100)).as_deref(), Some("  100%"));
+
+        let mut p = Printer::new(true);
+        assert_eq!(p.progress_step(5, 100), Some(5));
+        assert_eq!(p.progress_step(5, 100), None);
+        assert_eq!(p.progress_step(100, 100), Some(100));
+    }
+}
@@ -0,0 +1,97 @@
+//! bolt-cli — headless Bolt send/receive over the local rendezvous.
+//!
+//! Library half of the `bolt` binary. One peer hosts (registers a peer
+//! code and waits), the other joins by code. Both display the SAS; once
+//! each side confirms, one side sends a file or directory and the other
+//! writes it into a chosen directory.
+//!
+//! # Modules
+//!
+//! - [`link`] — Peer signal link (rendezvous relay via `signaling_client`)
+//! - [`ws`] — WS-direct socket carrying the Bolt frames
+//! - [`session`] — Connection signals, Bolt HELLO, SAS confirmation
+//! - [`transfer`] — File/directory send and receive loops
+//! - [`events`] — Progress events, rendered as text or JSON lines
+//!
+//! ## Wire
+//!
+//! Standard Bolt, the same flow as the browser app: ephemeral keys and a
+//! `wsUrl` travel in `connection_request` / `connection_accepted` signals
+//! on the rendezvous, then one peer dials the other's WebSocket. Over it
+//! runs the sealed HELLO and profile envelope of
+//! `docs/PROTOCOL_CONTRACT.md`, driven by [`bolt_peer::PeerLink`]. The
+//! rendezvous never sees file data; blocking socket writes give TCP flow
+//! control as backpressure.
+//!
+//! ## Non-goals
+//!
+//! - No WebRTC — WS-direct only, so both peers need a reachable address
+//!   on the same network.
+//! - No identity persistence or TOFU pinning; identities are per run.
+
+pub mod events;
+pub mod link;
+pub mod session;
+pub mod transfer;
+pub mod ws;
+
+use std::fmt;
+
+/// CLI failure: canonical wire error code + detail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CliError {
+    pub code: &'static str,
+    pub message: String,
+}
+
+impl CliError {
+    pub fn new(code: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+
+    /// Rebuild a [`bolt_peer::LinkEvent::Failed`], ours or the peer's.
+    /// Unknown codes collapse to `TRANSFER_FAILED`.
+    pub fn from_link(code: &str, detail: impl Into<String>) -> Self {
+        let code = bolt_core::errors::WIRE_ERROR_CODES
+            .iter()
+            .find(|c| **c == code)
+            .copied()
+            .unwrap_or("TRANSFER_FAILED");
+        Self::new(code, detail)
+    }
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.code, self.message)
+    }
+}
+
+impl std::error::Error for CliError {}
+
+impl From<bolt_core::errors::BoltError> for CliError {
+    fn from(e: bolt_core::errors::BoltError) -> Self {
+        Self::new(e.code(), e.to_string())
+    }
+}
+
+impl From<bolt_transfer_core::TransferError> for CliError {
+    fn from(e: bolt_transfer_core::TransferError) -> Self {
+        Self::new(e.code(), e.to_string())
+    }
+}
+
+impl From<std::io::Error> for CliError {
+    fn from(e: std::io::Error) -> Self {
+        Self::new("TRANSFER_FAILED", e.to_string())
+    }
+}
+
+impl From<serde_json::Error> for CliError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::new("INVALID_MESSAGE", e.to_string())
+    }
+}
@@ -0,0 +1,166 @@
+//! Peer signal link.
+//!
+//! [`Link`] carries the connection signals that set up a session: address
+//! a signal to a peer code, and wait for inbound signals or presence
+//! changes. File data never crosses it; see [`crate::ws`].
+//! [`RendezvousLink`] implements it over `bolt_app_core::signaling_client`;
+//! tests substitute an in-memory pair.
+
+use std::collections::VecDeque;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+use bolt_app_core::signaling_client::{
+    self, DiscoveryEvent, InboundSignal, SignalingConfig, SignalingHandle,
+};
+
+use crate::CliError;
+
+/// Something observed on the link.
+#[derive(Debug, Clone)]
+pub enum LinkEvent {
+    /// A relayed signal from another peer.
+    Signal(InboundSignal),
+    /// Peer is registered on the rendezvous (peer list or join).
+    PeerPresent(String),
+    PeerLeft(String),
+}
+
+/// Signal transport between two peers.
+pub trait Link {
+    /// Our registered peer code.
+    fn local_code(&self) -> &str;
+
+    fn send(
+        &mut self,
+        to: &str,
+        signal_type: &str,
+        data: serde_json::Value,
+    ) -> Result<(), CliError>;
+
+    /// Next event, or `None` once `deadline` passes.
+    fn recv_until(&mut self, deadline: Instant) -> Result<Option<LinkEvent>, CliError>;
+}
+
+/// Wait until `code` is registered on the rendezvous.
+pub fn wait_for_presence<L: Link>(link: &mut L, code: &str, deadline: Instant) -> Result<(), CliError> {
+    loop {
+        match link.recv_until(deadline)? {
+            Some(LinkEvent::PeerPresent(c)) if c == code => return Ok(()),
+            Some(_) => {}
+            None => {
+                return Err(CliError::new(
+                    "PEER_NOT_FOUND",
+                    format!("{code} is not registered on the rendezvous"),
+                ))
+            }
+        }
+    }
+}
+
+/// The signaling thread flushes outbound signals between 200 ms reads;
+/// give queued frames one flush cycle before shutting it down.
+const SHUTDOWN_LINGER: Duration = Duration::from_millis(500);
+
+/// [`Link`] over the local rendezvous (local plane only, no cloud).
+pub struct RendezvousLink {
+    peer_code: String,
+    handle: SignalingHandle,
+    rx: mpsc::Receiver<DiscoveryEvent>,
+    pending: VecDeque<LinkEvent>,
+}
+
+impl RendezvousLink {
+    /// Register `peer_code` on `server_url` (e.g. `127.0.0.1:3001`).
+    /// The signaling client connects and reconnects in the background.
+    pub fn connect(server_url: &str, peer_code: &str, device_name: &str) -> Self {
+        let (tx, rx) = mpsc::channel();
+        let handle = signaling_client::spawn_signaling_client(
+            SignalingConfig {
+                server_url: server_url.to_string(),
+                cloud_url: None,
+                peer_code: peer_code.to_string(),
+                device_name: device_name.to_string(),
+                device_type: "cli".to_string(),
+            },
+            Box::new(move |event| {
+                let _ = tx.send(event);
+            }),
+        );
+        Self {
+            peer_code: peer_code.to_string(),
+            handle,
+            rx,
+            pending: VecDeque::new(),
+        }
+    }
+
+    fn translate(&mut self, event: DiscoveryEvent) -> Result<(), CliError> {
+        match event {
+            DiscoveryEvent::PeerList(peers, _) => self.pending.extend(
+                peers
+                    .into_iter()
+                    .map(|p| LinkEvent::PeerPresent(p.peer_code)),
+            ),
+            DiscoveryEvent::PeerJoined(peer, _) => self
+                .pending
+                .push_back(LinkEvent::PeerPresent(peer.peer_code)),
+            DiscoveryEvent::PeerLeft(code, _) => self.pending.push_back(LinkEvent::PeerLeft(code)),
+            DiscoveryEvent::Signal(signal, _) => self.pending.push_back(LinkEvent::Signal(signal)),
+            DiscoveryEvent::Connected(_) => tracing::debug!("[CLI] rendezvous connected"),
+            DiscoveryEvent::Disconnected(reason, _) => {
+                tracing::warn!("[CLI] rendezvous disconnected: {reason}")
+            }
+            DiscoveryEvent::Error(message) => {
+                return Err(CliError::new(
+                    "CONNECTION_LOST",
+                    format!("rendezvous: {message}"),
+                ))
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Link for RendezvousLink {
+    fn local_code(&self) -> &str {
+        &self.peer_code
+    }
+
+    fn send(
+        &mut self,
+        to: &str,
+        signal_type: &str,
+        data: serde_json::Value,
+    ) -> Result<(), CliError> {
+        self.handle
+            .send_signal(to, signal_type, data, &self.peer_code);
+        Ok(())
+    }
+
+    fn recv_until(&mut self, deadline: Instant) -> Result<Option<LinkEvent>, CliError> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Ok(Some(event));
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Ok(None);
+            }
+            match self.rx.recv_timeout(deadline - now) {
+                Ok(event) => self.translate(event)?,
+                Err(mpsc::RecvTimeoutError::Timeout) => return Ok(None),
+                Err(mpsc::RecvTimeoutError::Disconnected) => {
+                    return Err(CliError::new("CONNECTION_LOST", "signaling client stopped"))
+                }
+            }
+        }
+    }
+}
+
+impl Drop for RendezvousLink {
+    fn drop(&mut self) {
+        std::thread::sleep(SHUTDOWN_LINGER);
+        self.handle.shutdown();
+    }
+}
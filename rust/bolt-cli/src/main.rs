@@ -0,0 +1,218 @@
+//! `bolt` — headless Bolt file transfer over the local rendezvous.
+//!
+//! ```text
+//! bolt host --receive ./incoming            # prints a peer code, waits
+//! bolt join K7M2QX --send ./build/artifacts # from the other machine
+//! bolt --json --yes join K7M2QX --send report.pdf
+//! ```
+//!
+//! The CLI speaks standard Bolt (HELLO + profile envelope over a
+//! WS-direct socket), so the peer may be another `bolt` or the browser
+//! app. The peers must reach each other on the network; `--listen` picks
+//! the local socket address.
+//!
+//! Exit status is 0 on success, 1 on any failure (the error event carries
+//! the wire error code).
+
+use std::io::{BufRead, Write};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::{Args, Parser, Subcommand};
+use tracing_subscriber::EnvFilter;
+
+use bolt_cli::events::{Event, EventSink, Printer};
+use bolt_cli::link::{Link, RendezvousLink};
+use bolt_cli::session::{self, Role, SessionOptions};
+use bolt_cli::transfer;
+use bolt_cli::CliError;
+use bolt_core::peer_code::{generate_secure_peer_code, is_valid_peer_code, normalize_peer_code};
+
+#[derive(Parser)]
+#[command(
+    name = "bolt",
+    version,
+    about = "Headless Bolt file transfer over the local rendezvous",
+    long_about = "Headless Bolt file transfer over the local rendezvous.\n\n\
+                  Speaks standard Bolt over a WS-direct socket, so the peer may \
+                  be another `bolt` or the browser app."
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Print one JSON event per line on stdout.
+    #[arg(long, global = true)]
+    json: bool,
+
+    /// Rendezvous server (host:port or ws:// URL).
+    #[arg(
+        long,
+        global = true,
+        env = "BOLT_RENDEZVOUS_URL",
+        default_value = "127.0.0.1:3001"
+    )]
+    rendezvous: String,
+
+    /// Device name shown to the peer (default: hostname).
+    #[arg(long, global = true)]
+    device_name: Option<String>,
+
+    /// Accept the SAS without prompting.
+    #[arg(long, global = true, conflicts_with = "expect_sas")]
+    yes: bool,
+
+    /// Accept only if the SAS equals this value (scripted pairing).
+    #[arg(long, global = true, value_name = "SAS")]
+    expect_sas: Option<String>,
+
+    /// Local address for the WS-direct socket the peer dials.
+    #[arg(long, global = true, default_value = "0.0.0.0:0", value_name = "ADDR")]
+    listen: String,
+
+    /// Seconds to wait for the peer at each step.
+    #[arg(long, global = true, default_value_t = 120, value_name = "SECS")]
+    timeout: u64,
+
+    /// Replace existing files when receiving.
+    #[arg(long, global = true)]
+    overwrite: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Register a peer code and wait for a peer to join.
+    Host {
+        /// Peer code to register (default: random).
+        #[arg(long)]
+        code: Option<String>,
+        #[command(flatten)]
+        action: Action,
+    },
+    /// Join the peer hosting under CODE.
+    Join {
+        code: String,
+        #[command(flatten)]
+        action: Action,
+    },
+}
+
+#[derive(Args)]
+#[group(required = true, multiple = false)]
+struct Action {
+    /// Send a file or directory.
+    #[arg(long, value_name = "PATH")]
+    send: Option<PathBuf>,
+    /// Receive into this directory.
+    #[arg(long, value_name = "DIR")]
+    receive: Option<PathBuf>,
+}
+
+fn main() {
+    tracing_subscriber::fmt()
+        .with_writer(std::io::stderr)
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("warn")),
+        )
+        .init();
+
+    let cli = Cli::parse();
+    let mut printer = Printer::new(cli.json);
+    if let Err(e) = run(cli, &mut printer) {
+        printer.emit(Event::Error {
+            code: e.code,
+            message: e.message,
+        });
+        std::process::exit(1);
+    }
+}
+
+fn run(cli: Cli, sink: &mut dyn EventSink) -> Result<(), CliError> {
+    let (role, local_code, action) = match cli.command {
+        Command::Host { code, action } => {
+            let code = match code {
+                Some(c) if is_valid_peer_code(&c) => normalize_peer_code(&c),
+                Some(c) => {
+                    return Err(CliError::new(
+                        "INVALID_MESSAGE",
+                        format!("invalid peer code: {c}"),
+                    ))
+                }
+                None => generate_secure_peer_code(),
+            };
+            (Role::Host, code, action)
+        }
+        Command::Join { code, action } => {
+            if !is_valid_peer_code(&code) {
+                return Err(CliError::new(
+                    "INVALID_MESSAGE",
+                    format!("invalid peer code: {code}"),
+                ));
+            }
+            let host = normalize_peer_code(&code);
+            (Role::Join(host), generate_secure_peer_code(), action)
+        }
+    };
+
+    // Validate local paths before anyone waits on us.
+    let outgoing = match &action.send {
+        Some(path) => Some(transfer::collect_files(path, sink)?),
+        None => None,
+    };
+    if let Some(dir) = &action.receive {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    let device_name = cli.device_name.unwrap_or_else(|| {
+        hostname::get()
+            .map(|h| h.to_string_lossy().to_string())
+            .unwrap_or_else(|_| "bolt-cli".to_string())
+    });
+    let mut link = RendezvousLink::connect(&cli.rendezvous, &local_code, &device_name);
+    sink.emit(Event::Registered {
+        peer_code: link.local_code().to_string(),
+    });
+
+    let (yes, expect_sas, json) = (cli.yes, cli.expect_sas, cli.json);
+    let mut confirm = |sas: &str| match &expect_sas {
+        Some(expected) => expected.eq_ignore_ascii_case(sas),
+        None => yes || prompt_sas(sas, json),
+    };
+    let mut session = session::establish(
+        &mut link,
+        SessionOptions {
+            role,
+            device_name,
+            identity: bolt_core::identity::generate_identity_keypair(),
+            listen: cli.listen,
+            timeout: Duration::from_secs(cli.timeout),
+            confirm: &mut confirm,
+        },
+        sink,
+    )?;
+
+    let result = match (outgoing, &action.receive) {
+        (Some(files), _) => transfer::send_files(&mut session, &files, sink),
+        (None, Some(dir)) => transfer::receive_files(&mut session, dir, cli.overwrite, sink),
+        (None, None) => unreachable!("clap requires --send or --receive"),
+    };
+    result.map(|_| ())
+}
+
+/// Ask on the terminal whether the peer shows the same SAS. In JSON mode
+/// the question goes to stderr so stdout stays machine-readable.
+fn prompt_sas(sas: &str, json: bool) -> bool {
+    let question = format!("Does the peer show SAS {sas}? [y/N] ");
+    if json {
+        eprint!("{question}");
+        let _ = std::io::stderr().flush();
+    } else {
+        print!("{question}");
+        let _ = std::io::stdout().flush();
+    }
+    let mut answer = String::new();
+    if std::io::stdin().lock().read_line(&mut answer).is_err() {
+        return false;
+    }
+    matches!(answer.trim(), "y" | "Y" | "yes" | "YES")
+}
@@ -0,0 +1,364 @@
+//! Session setup: connection signals, WS-direct socket, Bolt HELLO, SAS.
+//!
+//! ```text
+//! join → host   connection_request  {deviceName, deviceType, publicKey, wsUrl}
+//! host → join   connection_accepted {deviceName, deviceType, publicKey[, wsUrl]}
+//! host dials the joiner's wsUrl (or, for a browser joiner with no wsUrl,
+//! the joiner dials the host's)
+//! both          sealed HELLO, then profile-envelope messages
+//! ```
+//!
+//! `publicKey` is the ephemeral key the peer seals HELLO to, exchanged in
+//! plaintext signaling as on every Bolt transport. The HELLO and
+//! everything after it is [`bolt_peer::PeerLink`], the same state machine
+//! the browser app runs, so the CLI pairs with either.
+//!
+//! The SAS covers both identity and both ephemeral keys
+//! ([`bolt_core::sas`]). Each side confirms it locally; a side that
+//! rejects sends `KEY_MISMATCH` and closes, which the other side sees as
+//! soon as it next reads.
+
+use std::time::{Duration, Instant};
+
+use serde_json::json;
+
+use bolt_core::encoding::{from_base64, to_base64};
+use bolt_core::identity::IdentityKeyPair;
+use bolt_core::sas::{compute_sas_encoded, SasEncoding};
+use bolt_peer::{LinkEvent, PeerLink, CAPABILITIES};
+
+use crate::events::{Event, EventSink};
+use crate::link::{self, Link};
+use crate::ws::{self, Inbound, Listener, WsWire};
+use crate::CliError;
+
+/// Ask a peer for a session.
+pub const REQUEST_SIGNAL: &str = "connection_request";
+pub const ACCEPTED_SIGNAL: &str = "connection_accepted";
+pub const DECLINED_SIGNAL: &str = "connection_declined";
+
+/// `deviceType` in our connection signals.
+pub const DEVICE_TYPE: &str = "cli";
+
+/// How long one [`Session::next_event`] read blocks before checking the
+/// step deadline again.
+const READ_SLICE: Duration = Duration::from_millis(200);
+
+/// Which side of the rendezvous we are.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Role {
+    /// Wait for any peer to connect to our code.
+    Host,
+    /// Connect to the host registered under this code.
+    Join(String),
+}
+
+/// Session setup parameters.
+pub struct SessionOptions<'a> {
+    pub role: Role,
+    pub device_name: String,
+    /// Long-lived identity; the CLI generates one per run.
+    pub identity: IdentityKeyPair,
+    /// Local address for the WS-direct listener (e.g. `0.0.0.0:0`).
+    pub listen: String,
+    /// Wait budget for each step (peer presence, each frame).
+    pub timeout: Duration,
+    /// Decide whether the displayed SAS matches the peer's.
+    pub confirm: &'a mut dyn FnMut(&str) -> bool,
+}
+
+/// Established session with one peer.
+pub struct Session {
+    wire: WsWire,
+    link: PeerLink,
+    peer_code: String,
+    timeout: Duration,
+}
+
+/// The parts of a `connection_request` / `connection_accepted` we use.
+struct Connection {
+    device_name: String,
+    public_key: [u8; 32],
+    ws_url: Option<String>,
+}
+
+fn parse_connection(data: &serde_json::Value) -> Result<Connection, CliError> {
+    let public_key = data["publicKey"]
+        .as_str()
+        .ok_or_else(|| CliError::new("INVALID_MESSAGE", "connection signal without publicKey"))?;
+    let public_key = from_base64(public_key)?
+        .try_into()
+        .map_err(|_| CliError::new("INVALID_MESSAGE", "publicKey must be 32 bytes"))?;
+    Ok(Connection {
+        device_name: data["deviceName"].as_str().unwrap_or("Unknown").to_string(),
+        public_key,
+        ws_url: data["wsUrl"].as_str().map(str::to_string),
+    })
+}
+
+/// Exchange connection signals, open the socket, run HELLO and confirm
+/// the SAS locally.
+pub fn establish<L: Link>(
+    link: &mut L,
+    options: SessionOptions<'_>,
+    sink: &mut dyn EventSink,
+) -> Result<Session, CliError> {
+    let mut peer = PeerLink::new(options.identity.public_key, &CAPABILITIES);
+    let mut data = json!({
+        "deviceName": options.device_name,
+        "deviceType": DEVICE_TYPE,
+        "publicKey": to_base64(&peer.local_ephemeral_key()),
+    });
+
+    let deadline = Instant::now() + options.timeout;
+    let (peer_code, remote, wire) = match &options.role {
+        Role::Join(host) => {
+            link::wait_for_presence(link, host, deadline)?;
+            let listener = Listener::bind(&options.listen)?;
+            data["wsUrl"] = json!(listener.url());
+            link.send(host, REQUEST_SIGNAL, data)?;
+            let remote = wait_for_accept(link, host, deadline)?;
+            let wire = match &remote.ws_url {
+                Some(url) => ws::dial(url, options.timeout)?,
+                None => listener.accept(Instant::now() + options.timeout)?,
+            };
+            (host.clone(), remote, wire)
+        }
+        Role::Host => {
+            let (from, remote) = wait_for_request(link, deadline)?;
+            let wire = match &remote.ws_url {
+                Some(url) => {
+                    link.send(&from, ACCEPTED_SIGNAL, data)?;
+                    ws::dial(url, options.timeout)?
+                }
+                // A browser cannot listen; it dials us instead.
+                None => {
+                    let listener = Listener::bind(&options.listen)?;
+                    data["wsUrl"] = json!(listener.url());
+                    link.send(&from, ACCEPTED_SIGNAL, data)?;
+                    listener.accept(Instant::now() + options.timeout)?
+                }
+            };
+            (from, remote, wire)
+        }
+    };
+
+    sink.emit(Event::PeerConnected {
+        peer_code: peer_code.clone(),
+        device_name: remote.device_name.clone(),
+    });
+
+    peer.set_remote_ephemeral(remote.public_key);
+    let mut session = Session {
+        wire,
+        link: peer,
+        peer_code,
+        timeout: options.timeout,
+    };
+    session.link.start(&mut session.wire);
+    let (sas, remote_identity) = match session.next_event()? {
+        Some(LinkEvent::Established {
+            sas,
+            remote_identity,
+        }) => (sas, remote_identity),
+        Some(event) => return Err(session.unexpected(event)),
+        None => return Err(CliError::new("CONNECTION_LOST", "peer closed before HELLO")),
+    };
+
+    session.wait_ready()?;
+
+    let words = compute_sas_encoded(
+        &options.identity.public_key,
+        &remote_identity,
+        &session.link.local_ephemeral_key(),
+        &remote.public_key,
+        SasEncoding::Words,
+    );
+    sink.emit(Event::Sas {
+        sas: sas.clone(),
+        words,
+    });
+
+    if !(options.confirm)(&sas) {
+        session.abort("KEY_MISMATCH", "peer rejected the SAS");
+        let _ = session.finish();
+        return Err(CliError::new("KEY_MISMATCH", "SAS rejected locally"));
+    }
+    sink.emit(Event::Verified);
+    Ok(session)
+}
+
+/// Wait for `connection_request` from anyone.
+fn wait_for_request<L: Link>(
+    link: &mut L,
+    deadline: Instant,
+) -> Result<(String, Connection), CliError> {
+    loop {
+        match link.recv_until(deadline)? {
+            Some(link::LinkEvent::Signal(s)) if s.signal_type == REQUEST_SIGNAL => {
+                return Ok((s.from, parse_connection(&s.data)?));
+            }
+            Some(_) => {}
+            None => {
+                return Err(CliError::new(
+                    "CONNECTION_LOST",
+                    "timed out waiting for peer",
+                ))
+            }
+        }
+    }
+}
+
+/// Wait for the host's answer to our `connection_request`.
+fn wait_for_accept<L: Link>(
+    link: &mut L,
+    host: &str,
+    deadline: Instant,
+) -> Result<Connection, CliError> {
+    loop {
+        match link.recv_until(deadline)? {
+            Some(link::LinkEvent::Signal(s)) if s.from == host => match s.signal_type.as_str() {
+                ACCEPTED_SIGNAL => return parse_connection(&s.data),
+                DECLINED_SIGNAL => {
+                    let reason = s.data["reason"].as_str().unwrap_or("declined");
+                    return Err(CliError::new(
+                        "CONNECTION_LOST",
+                        format!("{host} declined: {reason}"),
+                    ));
+                }
+                _ => {}
+            },
+            Some(link::LinkEvent::PeerLeft(c)) if c == host => {
+                return Err(CliError::new("CONNECTION_LOST", format!("{c} left")))
+            }
+            Some(_) => {}
+            None => {
+                return Err(CliError::new(
+                    "CONNECTION_LOST",
+                    "timed out waiting for peer",
+                ))
+            }
+        }
+    }
+}
+
+impl Session {
+    /// Peer code of the connected peer.
+    pub fn peer_code(&self) -> &str {
+        &self.peer_code
+    }
+
+    /// Next link event. `None` means the peer closed the socket with no
+    /// transfer in flight.
+    pub fn next_event(&mut self) -> Result<Option<LinkEvent>, CliError> {
+        let deadline = Instant::now() + self.timeout;
+        loop {
+            self.link.pump(&mut self.wire);
+            if let Some(event) = self.link.poll_event() {
+                return match event {
+                    LinkEvent::Failed { code, detail } => Err(CliError::from_link(&code, detail)),
+                    event => Ok(Some(event)),
+                };
+            }
+            let busy = self.link.is_busy() || self.link.has_pending_offer();
+            if !self.read(deadline)? {
+                self.link.close();
+                if busy {
+                    return Err(CliError::new("CONNECTION_LOST", "peer closed mid-transfer"));
+                }
+                return Ok(None);
+            }
+        }
+    }
+
+    /// Wait until transfers may start (with `pq-hybrid`, the ML-KEM
+    /// exchange follows HELLO).
+    fn wait_ready(&mut self) -> Result<(), CliError> {
+        let deadline = Instant::now() + self.timeout;
+        while !self.link.is_ready() {
+            match self.link.poll_event() {
+                Some(LinkEvent::Failed { code, detail }) => {
+                    return Err(CliError::from_link(&code, detail))
+                }
+                Some(event) => return Err(self.unexpected(event)),
+                None => {}
+            }
+            if !self.read(deadline)? {
+                return Err(CliError::new("CONNECTION_LOST", "peer closed during key exchange"));
+            }
+        }
+        Ok(())
+    }
+
+    /// Read one slice and feed any frame to the link. `false` once the
+    /// peer has closed the socket.
+    fn read(&mut self, deadline: Instant) -> Result<bool, CliError> {
+        let now = Instant::now();
+        if now >= deadline {
+            return Err(CliError::new(
+                "CONNECTION_LOST",
+                format!("no frame from peer for {}s", self.timeout.as_secs()),
+            ));
+        }
+        match self.wire.recv(READ_SLICE.min(deadline - now))? {
+            Inbound::Frame(text) => self.link.on_frame(&text, &mut self.wire),
+            Inbound::Closed => return Ok(false),
+            Inbound::Idle => {}
+        }
+        Ok(true)
+    }
+
+    /// Offer a file; chunks go out from [`next_event`](Self::next_event)
+    /// once the peer accepts. Returns the transfer id.
+    pub fn send_file(&mut self, file_name: &str, bytes: Vec<u8>) -> Result<String, CliError> {
+        self.link
+            .send_file(file_name, bytes, &mut self.wire)
+            .map_err(|e| CliError::new("INVALID_STATE", e))
+    }
+
+    /// Accept the pending inbound offer.
+    pub fn accept_offer(&mut self) -> Result<(), CliError> {
+        self.link
+            .accept_offer(&mut self.wire)
+            .map_err(|e| CliError::new("INVALID_STATE", e))
+    }
+
+    /// Tell the peer why we are giving up and close the link.
+    pub fn abort(&mut self, code: &str, detail: impl Into<String>) {
+        self.link.abort(code, detail, &mut self.wire);
+        // Drop the Failed event the abort queued; the caller reports it.
+        while self.link.poll_event().is_some() {}
+    }
+
+    /// Close the socket and wait for the peer to close its side, so every
+    /// frame we sent has been read. An `error` the peer sent before it
+    /// closed (say, a hash mismatch on the last file) is returned.
+    pub fn finish(&mut self) -> Result<(), CliError> {
+        self.wire.close();
+        let deadline = Instant::now() + self.timeout;
+        let result = loop {
+            if let Some(LinkEvent::Failed { code, detail }) = self.link.poll_event() {
+                break Err(CliError::from_link(&code, detail));
+            }
+            if Instant::now() >= deadline {
+                break Err(CliError::new(
+                    "CONNECTION_LOST",
+                    "peer did not close the connection",
+                ));
+            }
+            match self.wire.recv(READ_SLICE) {
+                Ok(Inbound::Frame(text)) => self.link.on_frame(&text, &mut self.wire),
+                Ok(Inbound::Idle) => {}
+                Ok(Inbound::Closed) | Err(_) => break Ok(()),
+            }
+        };
+        self.link.close();
+        result
+    }
+
+    fn unexpected(&mut self, event: LinkEvent) -> CliError {
+        let err = CliError::new("PROTOCOL_VIOLATION", format!("unexpected event: {event:?}"));
+        self.abort(err.code, err.message.clone());
+        err
+    }
+}
@@ -0,0 +1,322 @@
+//! File and directory transfer over an established [`Session`].
+//!
+//! One Bolt transfer per file, sequentially, over the session's
+//! [`PeerLink`](bolt_peer::PeerLink):
+//!
+//! ```text
+//! sender → receiver   file-offer {transferId, filename, size, totalChunks, fileHash}
+//! receiver → sender   file-accept {transferId}
+//! sender → receiver   file-chunk × totalChunks, file-finish
+//! ...
+//! sender              closes the socket once every file is out
+//! ```
+//!
+//! The receiver checks each file's SHA-256 (`bolt.file-hash`). Either side
+//! may send `error {code, message}` instead, which ends the run on both
+//! sides. Filenames are `/`-separated relative paths; the receiver refuses
+//! anything that would escape its output directory.
+
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use bolt_peer::LinkEvent;
+use bolt_transfer_core::receive::MAX_TRANSFER_BYTES;
+
+use crate::events::{Event, EventSink};
+use crate::session::Session;
+use crate::CliError;
+
+/// Totals for a finished run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Summary {
+    pub files: u32,
+    pub bytes: u64,
+}
+
+/// Report `err` to the peer and return it.
+fn abort(session: &mut Session, err: CliError) -> CliError {
+    session.abort(err.code, err.message.clone());
+    err
+}
+
+fn unexpected(event: &LinkEvent) -> CliError {
+    let name = match event {
+        LinkEvent::Established { .. } => "established",
+        LinkEvent::Offered { .. } => "offer",
+        LinkEvent::SendProgress { .. } | LinkEvent::Sent { .. } => "send progress",
+        LinkEvent::ReceiveProgress { .. } | LinkEvent::Received { .. } => "receive progress",
+        LinkEvent::Failed { .. } => "failure",
+    };
+    CliError::new("PROTOCOL_VIOLATION", format!("unexpected {name}"))
+}
+
+// ── Send ──────────────────────────────────────────────────────────
+
+/// A local file and the relative name it is offered under.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutgoingFile {
+    pub path: PathBuf,
+    pub name: String,
+}
+
+/// Expand `path` into the files to send. A directory is walked
+/// recursively (sorted) and its own name prefixes every entry.
+/// Empty files and symlinks are reported as skipped.
+pub fn collect_files(path: &Path, sink: &mut dyn EventSink) -> Result<Vec<OutgoingFile>, CliError> {
+    let root_name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or_else(|| {
+            CliError::new(
+                "INVALID_MESSAGE",
+                format!("unusable path: {}", path.display()),
+            )
+        })?
+        .to_string();
+    let mut files = Vec::new();
+    walk(path, root_name, &mut files, sink)?;
+    Ok(files)
+}
+
+fn walk(
+    path: &Path,
+    name: String,
+    files: &mut Vec<OutgoingFile>,
+    sink: &mut dyn EventSink,
+) -> Result<(), CliError> {
+    let meta = fs::symlink_metadata(path)?;
+    let skip = |sink: &mut dyn EventSink, reason: &str| {
+        sink.emit(Event::Skipped {
+            path: path.display().to_string(),
+            reason: reason.to_string(),
+        })
+    };
+    if meta.file_type().is_symlink() {
+        skip(sink, "symlink");
+    } else if meta.is_dir() {
+        let mut entries = fs::read_dir(path)?.collect::<Result<Vec<_>, _>>()?;
+        entries.sort_by_key(|e| e.file_name());
+        for entry in entries {
+            match entry.file_name().to_str() {
+                Some(child) => walk(&entry.path(), format!("{name}/{child}"), files, sink)?,
+                None => skip(sink, "non-UTF-8 name"),
+            }
+        }
+    } else if meta.len() == 0 {
+        skip(sink, "empty file");
+    } else if meta.len() > MAX_TRANSFER_BYTES {
+        return Err(CliError::new(
+            "LIMIT_EXCEEDED",
+            format!("{} exceeds {MAX_TRANSFER_BYTES} bytes", path.display()),
+        ));
+    } else {
+        files.push(OutgoingFile {
+            path: path.to_path_buf(),
+            name,
+        });
+    }
+    Ok(())
+}
+
+/// Send `files` to the peer, then close the session.
+pub fn send_files(
+    session: &mut Session,
+    files: &[OutgoingFile],
+    sink: &mut dyn EventSink,
+) -> Result<Summary, CliError> {
+    let mut summary = Summary::default();
+    for file in files {
+        summary.bytes += send_one(session, file, sink)?;
+        summary.files += 1;
+    }
+    session.finish()?;
+    sink.emit(Event::Done {
+        files: summary.files,
+        bytes: summary.bytes,
+    });
+    Ok(summary)
+}
+
+/// `FileComplete` on this side means every chunk went out; the receiver
+/// verifies the hash and reports a mismatch as an error, which fails the
+/// next read.
+fn send_one(
+    session: &mut Session,
+    file: &OutgoingFile,
+    sink: &mut dyn EventSink,
+) -> Result<u64, CliError> {
+    let payload = fs::read(&file.path)?;
+    let size = payload.len() as u64;
+    let transfer_id = session.send_file(&file.name, payload)?;
+    sink.emit(Event::Offer {
+        transfer_id: transfer_id.clone(),
+        filename: file.name.clone(),
+        size,
+    });
+    loop {
+        match session.next_event()? {
+            Some(LinkEvent::SendProgress { bytes, total, .. }) => sink.emit(Event::Progress {
+                transfer_id: transfer_id.clone(),
+                bytes,
+                total,
+            }),
+            Some(LinkEvent::Sent { .. }) => break,
+            Some(other) => return Err(abort(session, unexpected(&other))),
+            None => return Err(CliError::new("CONNECTION_LOST", "peer closed the session")),
+        }
+    }
+    sink.emit(Event::FileComplete {
+        transfer_id,
+        filename: file.name.clone(),
+        size,
+        hash_verified: false,
+    });
+    Ok(size)
+}
+
+// ── Receive ───────────────────────────────────────────────────────
+
+/// Map an offered `/`-separated name to a path under the output
+/// directory. Rejects absolute paths, `.`/`..`, and separators or drive
+/// prefixes smuggled inside a component.
+pub fn safe_relative_path(name: &str) -> Result<PathBuf, CliError> {
+    let reject = || CliError::new("PROTOCOL_VIOLATION", format!("unsafe filename: {name:?}"));
+    let mut out = PathBuf::new();
+    for part in name.split('/') {
+        if part.is_empty() || part == "." || part == ".." || part.contains(['\\', ':', '\0']) {
+            return Err(reject());
+        }
+        out.push(part);
+    }
+    if out.components().any(|c| !matches!(c, Component::Normal(_))) {
+        return Err(reject());
+    }
+    Ok(out)
+}
+
+/// Receive files into `out_dir` until the peer closes the session.
+/// Existing files are only replaced when `overwrite` is set.
+pub fn receive_files(
+    session: &mut Session,
+    out_dir: &Path,
+    overwrite: bool,
+    sink: &mut dyn EventSink,
+) -> Result<Summary, CliError> {
+    let mut summary = Summary::default();
+    let mut dest = None;
+    loop {
+        match session.next_event()? {
+            Some(LinkEvent::Offered {
+                transfer_id,
+                file_name,
+                size,
+            }) if dest.is_none() => {
+                let path = match offered_path(out_dir, overwrite, &file_name) {
+                    Ok(path) => path,
+                    Err(e) => return Err(abort(session, e)),
+                };
+                dest = Some(path);
+                sink.emit(Event::Offer {
+                    transfer_id,
+                    filename: file_name,
+                    size,
+                });
+                session.accept_offer()?;
+            }
+            Some(LinkEvent::ReceiveProgress {
+                transfer_id,
+                bytes,
+                total,
+                ..
+            }) => sink.emit(Event::Progress {
+                transfer_id,
+                bytes,
+                total,
+            }),
+            Some(LinkEvent::Received {
+                transfer_id,
+                file_name,
+                bytes,
+                hash_verified,
+            }) => {
+                let Some(path) = dest.take() else {
+                    return Err(abort(session, CliError::new("INVALID_STATE", "file without offer")));
+                };
+                if let Err(e) = write_file(&path, &bytes) {
+                    return Err(abort(session, e));
+                }
+                summary.files += 1;
+                summary.bytes += bytes.len() as u64;
+                sink.emit(Event::FileComplete {
+                    transfer_id,
+                    filename: file_name,
+                    size: bytes.len() as u64,
+                    hash_verified,
+                });
+            }
+            Some(other) => return Err(abort(session, unexpected(&other))),
+            None => {
+                sink.emit(Event::Done {
+                    files: summary.files,
+                    bytes: summary.bytes,
+                });
+                return Ok(summary);
+            }
+        }
+    }
+}
+
+/// Where an offered file would land, if we may write it.
+fn offered_path(out_dir: &Path, overwrite: bool, filename: &str) -> Result<PathBuf, CliError> {
+    let dest = out_dir.join(safe_relative_path(filename)?);
+    if dest.exists() && !overwrite {
+        return Err(CliError::new(
+            "TRANSFER_FAILED",
+            format!("{} already exists", dest.display()),
+        ));
+    }
+    Ok(dest)
+}
+
+/// Write beside the destination, then rename: no partial files on failure.
+fn write_file(dest: &Path, bytes: &[u8]) -> Result<(), CliError> {
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut partial = dest.to_path_buf().into_os_string();
+    partial.push(".part");
+    fs::write(&partial, bytes)?;
+    fs::rename(&partial, dest)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn safe_relative_path_accepts_nested_names() {
+        assert_eq!(
+            safe_relative_path("dir/sub/file.txt").unwrap(),
+            Path::new("dir").join("sub").join("file.txt")
+        );
+    }
+
+    #[test]
+    fn safe_relative_path_rejects_escapes() {
+        for name in [
+            "",
+            "/etc/passwd",
+            "../up",
+            "a/../../up",
+            "a/./b",
+            "a//b",
+            "dir\\..\\up",
+            "C:evil",
+            "trailing/",
+        ] {
+            let err = safe_relative_path(name).unwrap_err();
+            assert_eq!(err.code, "PROTOCOL_VIOLATION", "{name:?}");
+        }
+    }
+}
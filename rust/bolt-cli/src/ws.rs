@@ -0,0 +1,193 @@
+//! WS-direct data path.
+//!
+//! One peer listens and puts `ws://<ip>:<port>` in its connection signal
+//! as `wsUrl`; the other dials it. [`WsWire`] then carries the Bolt frames
+//! for [`PeerLink`](bolt_peer::PeerLink). Writes block until the kernel
+//! accepts them, so TCP flow control is the backpressure: a slow receiver
+//! stalls the sender instead of the file queueing up in memory.
+
+use std::io;
+use std::net::{IpAddr, SocketAddr, TcpListener, TcpStream, ToSocketAddrs, UdpSocket};
+use std::time::{Duration, Instant};
+
+use tungstenite::{Message, WebSocket};
+
+use bolt_peer::Wire;
+use bolt_transfer_core::TransportQuery;
+
+use crate::CliError;
+
+/// How often a pending accept checks its deadline.
+const ACCEPT_POLL: Duration = Duration::from_millis(50);
+
+/// Largest frame we accept (tungstenite's default message limit).
+const MAX_MESSAGE_SIZE: usize = 64 << 20;
+
+fn lost(detail: impl std::fmt::Display) -> CliError {
+    CliError::new("CONNECTION_LOST", detail.to_string())
+}
+
+/// Best guess at the LAN address a peer can reach us on. Connecting a UDP
+/// socket sends nothing; it only picks the outbound interface.
+fn local_ip() -> IpAddr {
+    UdpSocket::bind("0.0.0.0:0")
+        .and_then(|s| s.connect("8.8.8.8:80").and(s.local_addr()))
+        .map_or(IpAddr::from([127, 0, 0, 1]), |a| a.ip())
+}
+
+/// A bound listener waiting for the peer to dial its [`url`](Self::url).
+pub struct Listener {
+    listener: TcpListener,
+    url: String,
+}
+
+impl Listener {
+    /// Bind `addr` (e.g. `0.0.0.0:0`). An unspecified address is advertised
+    /// as this host's LAN address.
+    pub fn bind(addr: &str) -> Result<Self, CliError> {
+        let listener = TcpListener::bind(addr)?;
+        let local = listener.local_addr()?;
+        let ip = if local.ip().is_unspecified() {
+            local_ip()
+        } else {
+            local.ip()
+        };
+        let url = format!("ws://{}", SocketAddr::new(ip, local.port()));
+        Ok(Self { listener, url })
+    }
+
+    /// The `wsUrl` to advertise.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Accept one connection and complete the WebSocket handshake.
+    pub fn accept(self, deadline: Instant) -> Result<WsWire, CliError> {
+        self.listener.set_nonblocking(true)?;
+        let stream = loop {
+            match self.listener.accept() {
+                Ok((stream, _)) => break stream,
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                    if Instant::now() >= deadline {
+                        return Err(lost("timed out waiting for the peer to connect"));
+                    }
+                    std::thread::sleep(ACCEPT_POLL);
+                }
+                Err(e) => return Err(e.into()),
+            }
+        };
+        stream.set_nonblocking(false)?;
+        stream.set_read_timeout(Some(deadline.saturating_duration_since(Instant::now())))?;
+        let socket = tungstenite::accept(stream).map_err(lost)?;
+        Ok(WsWire::new(socket))
+    }
+}
+
+/// Dial the peer's `wsUrl`.
+pub fn dial(url: &str, timeout: Duration) -> Result<WsWire, CliError> {
+    let authority = url
+        .strip_prefix("ws://")
+        .map(|rest| rest.split('/').next().unwrap_or(rest))
+        .ok_or_else(|| CliError::new("INVALID_MESSAGE", format!("unsupported wsUrl: {url}")))?;
+    let addr = authority
+        .to_socket_addrs()?
+        .next()
+        .ok_or_else(|| lost(format!("{authority} did not resolve")))?;
+    let stream = TcpStream::connect_timeout(&addr, timeout)?;
+    stream.set_read_timeout(Some(timeout))?;
+    let (socket, _) = tungstenite::client(url, stream).map_err(lost)?;
+    Ok(WsWire::new(socket))
+}
+
+/// What one [`WsWire::recv`] produced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Inbound {
+    Frame(String),
+    /// The peer closed the socket.
+    Closed,
+    /// Nothing arrived before the timeout.
+    Idle,
+}
+
+/// [`Wire`] over a blocking WebSocket.
+pub struct WsWire {
+    socket: WebSocket<TcpStream>,
+    /// First send failure; [`Wire::send_text`] cannot return it.
+    error: Option<CliError>,
+}
+
+impl WsWire {
+    fn new(socket: WebSocket<TcpStream>) -> Self {
+        Self {
+            socket,
+            error: None,
+        }
+    }
+
+    /// Wait up to `timeout` for the next text frame.
+    pub fn recv(&mut self, timeout: Duration) -> Result<Inbound, CliError> {
+        if let Some(e) = self.error.take() {
+            return Err(e);
+        }
+        // A zero timeout means "block forever" to the socket.
+        let timeout = timeout.max(Duration::from_millis(1));
+        self.socket.get_ref().set_read_timeout(Some(timeout))?;
+        loop {
+            match self.socket.read() {
+                Ok(Message::Text(text)) => return Ok(Inbound::Frame(text)),
+                Ok(Message::Close(_)) => {
+                    // Send our half of the close handshake.
+                    let _ = self.socket.flush();
+                    return Ok(Inbound::Closed);
+                }
+                // Bolt frames are JSON text; tungstenite answers pings.
+                Ok(_) => {}
+                Err(tungstenite::Error::Io(e))
+                    if matches!(
+                        e.kind(),
+                        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+                    ) =>
+                {
+                    return Ok(Inbound::Idle)
+                }
+                Err(tungstenite::Error::ConnectionClosed | tungstenite::Error::AlreadyClosed) => {
+                    return Ok(Inbound::Closed)
+                }
+                Err(e) => return Err(lost(e)),
+            }
+        }
+    }
+
+    /// Start the close handshake. Keep calling [`recv`](Self::recv) until
+    /// it reports [`Inbound::Closed`] to know the peer read everything.
+    pub fn close(&mut self) {
+        let _ = self.socket.close(None);
+        let _ = self.socket.flush();
+    }
+}
+
+impl TransportQuery for WsWire {
+    fn is_open(&self) -> bool {
+        self.error.is_none() && self.socket.can_write()
+    }
+
+    /// Sends complete before [`Wire::send_text`] returns.
+    fn buffered_bytes(&self) -> usize {
+        0
+    }
+
+    fn max_message_size(&self) -> usize {
+        MAX_MESSAGE_SIZE
+    }
+}
+
+impl Wire for WsWire {
+    fn send_text(&mut self, frame: &str) {
+        if self.error.is_some() {
+            return;
+        }
+        if let Err(e) = self.socket.send(Message::Text(frame.to_string())) {
+            self.error = Some(lost(e));
+        }
+    }
+}
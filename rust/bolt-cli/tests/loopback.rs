@@ -0,0 +1,307 @@
+//! Host ↔ join sessions over an in-memory signal link (no rendezvous) and
+//! a real WS-direct socket on localhost.
+
+use std::fs;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use bolt_app_core::signaling_client::InboundSignal;
+use bolt_cli::events::Event;
+use bolt_cli::link::{Link, LinkEvent};
+use bolt_cli::session::{establish, Role, Session, SessionOptions};
+use bolt_cli::transfer::{collect_files, receive_files, send_files, Summary};
+use bolt_cli::ws::{dial, Inbound};
+use bolt_cli::CliError;
+use bolt_core::encoding::{from_base64, to_base64};
+use bolt_core::identity::generate_identity_keypair;
+use bolt_peer::{LinkEvent as PeerEvent, PeerLink, CAPABILITIES};
+use serde_json::json;
+
+/// One end of a connected pair; each end starts out seeing the other.
+struct MemoryLink {
+    code: String,
+    peer_tx: mpsc::Sender<LinkEvent>,
+    rx: mpsc::Receiver<LinkEvent>,
+}
+
+fn link_pair(a: &str, b: &str) -> (MemoryLink, MemoryLink) {
+    let (a_tx, a_rx) = mpsc::channel();
+    let (b_tx, b_rx) = mpsc::channel();
+    a_tx.send(LinkEvent::PeerPresent(b.into())).unwrap();
+    b_tx.send(LinkEvent::PeerPresent(a.into())).unwrap();
+    (
+        MemoryLink {
+            code: a.into(),
+            peer_tx: b_tx,
+            rx: a_rx,
+        },
+        MemoryLink {
+            code: b.into(),
+            peer_tx: a_tx,
+            rx: b_rx,
+        },
+    )
+}
+
+impl Link for MemoryLink {
+    fn local_code(&self) -> &str {
+        &self.code
+    }
+
+    fn send(
+        &mut self,
+        _to: &str,
+        signal_type: &str,
+        data: serde_json::Value,
+    ) -> Result<(), CliError> {
+        let _ = self.peer_tx.send(LinkEvent::Signal(InboundSignal {
+            from: self.code.clone(),
+            signal_type: signal_type.into(),
+            data,
+        }));
+        Ok(())
+    }
+
+    fn recv_until(&mut self, deadline: Instant) -> Result<Option<LinkEvent>, CliError> {
+        let wait = deadline.saturating_duration_since(Instant::now());
+        Ok(self.rx.recv_timeout(wait).ok())
+    }
+}
+
+fn connect(
+    mut link: MemoryLink,
+    role: Role,
+    accept: bool,
+    events: &mut Vec<Event>,
+) -> Result<Session, CliError> {
+    let mut confirm = |_: &str| accept;
+    establish(
+        &mut link,
+        SessionOptions {
+            role,
+            device_name: "test".into(),
+            identity: generate_identity_keypair(),
+            listen: "127.0.0.1:0".into(),
+            timeout: Duration::from_secs(5),
+            confirm: &mut confirm,
+        },
+        events,
+    )
+}
+
+fn sas_of(events: &[Event]) -> String {
+    events
+        .iter()
+        .find_map(|e| match e {
+            Event::Sas { sas, .. } => Some(sas.clone()),
+            _ => None,
+        })
+        .expect("SAS event")
+}
+
+#[test]
+fn directory_send_and_receive() {
+    let src = tempfile::tempdir().unwrap();
+    let root = src.path().join("artifacts");
+    fs::create_dir_all(root.join("nested")).unwrap();
+    let big: Vec<u8> = (0..40_000u32).map(|i| (i % 251) as u8).collect();
+    fs::write(root.join("a.txt"), b"hello").unwrap();
+    fs::write(root.join("nested/b.bin"), &big).unwrap();
+    fs::write(root.join("empty.txt"), b"").unwrap();
+    let out = tempfile::tempdir().unwrap();
+    let out_dir = out.path().to_path_buf();
+
+    let (host_link, join_link) = link_pair("HOST01", "JOIN01");
+    let host = thread::spawn(move || {
+        let mut events = Vec::new();
+        let mut session = connect(host_link, Role::Host, true, &mut events)?;
+        let summary = receive_files(&mut session, &out_dir, false, &mut events)?;
+        Ok::<_, CliError>((summary, events))
+    });
+
+    let mut events = Vec::new();
+    let files = collect_files(&root, &mut events).unwrap();
+    assert_eq!(files.len(), 2);
+    let mut session = connect(join_link, Role::Join("HOST01".into()), true, &mut events).unwrap();
+    assert_eq!(session.peer_code(), "HOST01");
+    let sent = send_files(&mut session, &files, &mut events).unwrap();
+
+    let (received, host_events) = host.join().unwrap().unwrap();
+    let expected = Summary {
+        files: 2,
+        bytes: 5 + big.len() as u64,
+    };
+    assert_eq!(sent, expected);
+    assert_eq!(received, expected);
+    assert_eq!(sas_of(&events), sas_of(&host_events));
+    assert!(host_events.contains(&Event::Verified));
+    assert!(events
+        .iter()
+        .any(|e| matches!(e, Event::Skipped { reason, .. } if reason == "empty file")));
+    assert!(host_events.iter().all(|e| !matches!(
+        e,
+        Event::FileComplete {
+            hash_verified: false,
+            ..
+        }
+    )));
+
+    assert_eq!(
+        fs::read(out.path().join("artifacts/a.txt")).unwrap(),
+        b"hello"
+    );
+    assert_eq!(
+        fs::read(out.path().join("artifacts/nested/b.bin")).unwrap(),
+        big
+    );
+    assert!(!out.path().join("artifacts/empty.txt").exists());
+}
+
+#[test]
+fn sas_rejection_fails_both_sides() {
+    let out = tempfile::tempdir().unwrap();
+    let out_dir = out.path().to_path_buf();
+    let (host_link, join_link) = link_pair("HOST02", "JOIN02");
+    // The host confirms; it learns of the rejection on its next read.
+    let host = thread::spawn(move || {
+        let mut events = Vec::new();
+        let mut session = connect(host_link, Role::Host, true, &mut events).unwrap();
+        receive_files(&mut session, &out_dir, false, &mut events).unwrap_err()
+    });
+
+    let err = connect(
+        join_link,
+        Role::Join("HOST02".into()),
+        false,
+        &mut Vec::new(),
+    )
+    .err()
+    .unwrap();
+    assert_eq!(err.code, "KEY_MISMATCH");
+    let host_err = host.join().unwrap();
+    assert_eq!(host_err.code, "KEY_MISMATCH");
+    assert!(host_err.message.contains("peer"));
+}
+
+#[test]
+fn existing_file_aborts_without_overwrite() {
+    let src = tempfile::tempdir().unwrap();
+    let file = src.path().join("report.txt");
+    fs::write(&file, b"new").unwrap();
+    let out = tempfile::tempdir().unwrap();
+    fs::write(out.path().join("report.txt"), b"old").unwrap();
+    let out_dir = out.path().to_path_buf();
+
+    let (host_link, join_link) = link_pair("HOST03", "JOIN03");
+    let host = thread::spawn(move || {
+        let mut events = Vec::new();
+        let mut session = connect(host_link, Role::Host, true, &mut events).unwrap();
+        receive_files(&mut session, &out_dir, false, &mut events).unwrap_err()
+    });
+
+    let mut events = Vec::new();
+    let files = collect_files(&file, &mut events).unwrap();
+    let mut session = connect(join_link, Role::Join("HOST03".into()), true, &mut events).unwrap();
+    let err = send_files(&mut session, &files, &mut events).unwrap_err();
+
+    assert_eq!(err.code, "TRANSFER_FAILED");
+    assert_eq!(host.join().unwrap().code, "TRANSFER_FAILED");
+    assert_eq!(fs::read(out.path().join("report.txt")).unwrap(), b"old");
+}
+
+#[test]
+fn join_fails_when_host_absent() {
+    let (_host_link, mut join_link) = link_pair("HOST04", "JOIN04");
+    // Drain the presence event so the host looks unregistered.
+    join_link.rx.try_recv().unwrap();
+    let mut confirm = |_: &str| true;
+    let err = establish(
+        &mut join_link,
+        SessionOptions {
+            role: Role::Join("HOST04".into()),
+            device_name: "test".into(),
+            identity: generate_identity_keypair(),
+            listen: "127.0.0.1:0".into(),
+            timeout: Duration::from_millis(100),
+            confirm: &mut confirm,
+        },
+        &mut Vec::new(),
+    )
+    .err()
+    .unwrap();
+    assert_eq!(err.code, "PEER_NOT_FOUND");
+}
+
+/// A joiner that cannot listen (the browser app) sends no `wsUrl`: the
+/// host listens instead, and a bare `PeerLink` on the joiner's side
+/// receives the file over standard HELLO and envelope.
+#[test]
+fn host_serves_a_joiner_without_ws_url() {
+    let src = tempfile::tempdir().unwrap();
+    let file = src.path().join("note.txt");
+    fs::write(&file, b"from the cli").unwrap();
+
+    let (host_link, mut join_link) = link_pair("HOST05", "JOIN05");
+    let host = thread::spawn(move || {
+        let mut events = Vec::new();
+        let files = collect_files(&file, &mut events)?;
+        let mut session = connect(host_link, Role::Host, true, &mut events)?;
+        send_files(&mut session, &files, &mut events)
+    });
+
+    let mut peer = PeerLink::new(generate_identity_keypair().public_key, &CAPABILITIES);
+    join_link
+        .send(
+            "HOST05",
+            "connection_request",
+            json!({
+                "deviceName": "browser",
+                "deviceType": "browser",
+                "publicKey": to_base64(&peer.local_ephemeral_key()),
+            }),
+        )
+        .unwrap();
+    let deadline = Instant::now() + Duration::from_secs(5);
+    let accepted = loop {
+        match join_link.recv_until(deadline).unwrap() {
+            Some(LinkEvent::Signal(s)) if s.signal_type == "connection_accepted" => break s.data,
+            Some(_) => {}
+            None => panic!("no connection_accepted"),
+        }
+    };
+    let host_key = from_base64(accepted["publicKey"].as_str().unwrap()).unwrap();
+    peer.set_remote_ephemeral(host_key.try_into().unwrap());
+    let ws_url = accepted["wsUrl"].as_str().expect("host advertises wsUrl");
+    let mut wire = dial(ws_url, Duration::from_secs(5)).unwrap();
+    peer.start(&mut wire);
+
+    let mut received = None;
+    while received.is_none() {
+        match wire.recv(Duration::from_secs(5)).unwrap() {
+            Inbound::Frame(text) => peer.on_frame(&text, &mut wire),
+            other => panic!("socket ended early: {other:?}"),
+        }
+        while let Some(event) = peer.poll_event() {
+            match event {
+                PeerEvent::Offered { .. } => peer.accept_offer(&mut wire).unwrap(),
+                PeerEvent::Received {
+                    bytes,
+                    hash_verified,
+                    ..
+                } => {
+                    assert!(hash_verified);
+                    received = Some(bytes);
+                }
+                PeerEvent::Failed { code, detail } => panic!("{code}: {detail}"),
+                _ => {}
+            }
+        }
+    }
+    assert_eq!(received.unwrap(), b"from the cli");
+
+    // The host closes once the file is out and waits for our close.
+    while wire.recv(Duration::from_secs(5)).unwrap() != Inbound::Closed {}
+    let summary = host.join().unwrap().unwrap();
+    assert_eq!(summary.files, 1);
+}
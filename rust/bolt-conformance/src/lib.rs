@@ -0,0 +1,29 @@
+//! bolt-conformance — black-box conformance runner for Bolt peers.
+//!
+//! Drives a peer under test, written in any language, through scripted
+//! wire scenarios and scores each reply against the expected PROTOCOL.md
+//! §10 wire error code. The peer speaks a small JSON-lines driver
+//! protocol over stdin/stdout or a TCP socket; the runner plays the
+//! remote peer and hands it fresh keys per scenario.
+//!
+//! Full protocol and scenario list: `docs/conformance/CONFORMANCE_KIT.md`.
+//!
+//! # Modules
+//!
+//! - [`protocol`] — Driver protocol commands and replies
+//! - [`scenarios`] — Scripted scenarios and the frames they send
+//! - [`link`] — Child-process and TCP line transports
+//! - [`report`] — Running scenarios, scoring, text/JSON report
+//! - [`reference`] — Reference peer on the canonical crates
+//!
+//! ## Non-goals
+//!
+//! - No outbound traffic is scored: the peer only reports what it would
+//!   reject. Sending-side behavior is covered by the golden vectors.
+//! - No real DH ratchet: BTR scenarios hand the peer a chain key fixture.
+
+pub mod link;
+pub mod protocol;
+pub mod reference;
+pub mod report;
+pub mod scenarios;
@@ -0,0 +1,135 @@
+//! Line transport to the peer under test: a child process (stdin/stdout)
+//! or a TCP socket.
+//!
+//! A reader thread feeds reply lines into a channel so every request can
+//! time out. The peer's stderr is inherited; stdout is reserved for
+//! replies.
+
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+use std::process::{Child, Command as Process, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+use crate::protocol::{Command, Reply};
+
+/// Request failure; the connection is unusable afterwards.
+#[derive(Debug)]
+pub enum LinkError {
+    Io(io::Error),
+    /// Peer closed its output (exited or hung up).
+    Closed,
+    Timeout(Duration),
+    /// Reply line is not a valid [`Reply`].
+    Malformed(String),
+}
+
+impl std::fmt::Display for LinkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LinkError::Io(e) => write!(f, "I/O error: {e}"),
+            LinkError::Closed => write!(f, "peer closed the connection"),
+            LinkError::Timeout(t) => write!(f, "no reply within {} ms", t.as_millis()),
+            LinkError::Malformed(line) => write!(f, "malformed reply: {line}"),
+        }
+    }
+}
+
+impl std::error::Error for LinkError {}
+
+impl From<io::Error> for LinkError {
+    fn from(e: io::Error) -> Self {
+        LinkError::Io(e)
+    }
+}
+
+pub struct PeerLink {
+    writer: Box<dyn Write + Send>,
+    lines: Receiver<io::Result<String>>,
+    child: Option<Child>,
+    timeout: Duration,
+}
+
+impl PeerLink {
+    /// Run `command` through the platform shell and talk over its
+    /// stdin/stdout.
+    pub fn spawn(command: &str, timeout: Duration) -> io::Result<Self> {
+        let mut process = if cfg!(windows) {
+            let mut p = Process::new("cmd");
+            p.args(["/C", command]);
+            p
+        } else {
+            let mut p = Process::new("sh");
+            p.args(["-c", command]);
+            p
+        };
+        let mut child = process
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()?;
+        let stdin = child.stdin.take().expect("piped stdin");
+        let stdout = child.stdout.take().expect("piped stdout");
+        let mut link = Self::from_io(stdout, stdin, timeout);
+        link.child = Some(child);
+        Ok(link)
+    }
+
+    /// Connect to a peer listening on `addr` (e.g. `127.0.0.1:7700`).
+    pub fn connect(addr: &str, timeout: Duration) -> io::Result<Self> {
+        let stream = TcpStream::connect(addr)?;
+        stream.set_nodelay(true)?;
+        let reader = stream.try_clone()?;
+        Ok(Self::from_io(reader, stream, timeout))
+    }
+
+    pub fn from_io(
+        reader: impl Read + Send + 'static,
+        writer: impl Write + Send + 'static,
+        timeout: Duration,
+    ) -> Self {
+        let (tx, lines) = mpsc::channel();
+        thread::spawn(move || {
+            for line in BufReader::new(reader).lines() {
+                let failed = line.is_err();
+                if tx.send(line).is_err() || failed {
+                    break;
+                }
+            }
+        });
+        Self {
+            writer: Box::new(writer),
+            lines,
+            child: None,
+            timeout,
+        }
+    }
+
+    /// Send one command and wait for its reply. Blank lines are skipped.
+    pub fn request(&mut self, command: &Command) -> Result<Reply, LinkError> {
+        let line = serde_json::to_string(command).expect("command serializes");
+        writeln!(self.writer, "{line}")?;
+        self.writer.flush()?;
+        loop {
+            let line = match self.lines.recv_timeout(self.timeout) {
+                Ok(line) => line?,
+                Err(RecvTimeoutError::Timeout) => return Err(LinkError::Timeout(self.timeout)),
+                Err(RecvTimeoutError::Disconnected) => return Err(LinkError::Closed),
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            return serde_json::from_str(&line).map_err(|_| LinkError::Malformed(line));
+        }
+    }
+}
+
+impl Drop for PeerLink {
+    fn drop(&mut self) {
+        if let Some(child) = &mut self.child {
+            let _ = child.kill();
+            let _ = child.wait();
+        }
+    }
+}
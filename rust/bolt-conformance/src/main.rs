@@ -0,0 +1,161 @@
+//! `bolt-conformance` — score a peer implementation against scripted
+//! wire scenarios.
+//!
+//! ```text
+//! bolt-conformance run --exec "./go-peer --conformance"
+//! bolt-conformance --json run --connect 127.0.0.1:7700 --only duplicate-hello
+//! bolt-conformance list
+//! bolt-conformance reference-peer [--listen 127.0.0.1:7700]
+//! ```
+//!
+//! Exit status is 0 when every scenario passes (or is skipped by the
+//! peer), 1 when any scenario fails or errors, 2 on usage or connection
+//! errors.
+
+use std::io::{self, BufReader};
+use std::net::TcpListener;
+use std::time::Duration;
+
+use clap::{Parser, Subcommand};
+
+use bolt_conformance::link::PeerLink;
+use bolt_conformance::{reference, report, scenarios};
+
+#[derive(Parser)]
+#[command(
+    name = "bolt-conformance",
+    version,
+    about = "Score a Bolt peer against scripted wire scenarios"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Print the report as a single JSON document on stdout.
+    #[arg(long, global = true)]
+    json: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run scenarios against a peer under test.
+    Run {
+        /// Spawn CMD (through the shell) and talk over its stdin/stdout.
+        #[arg(long, value_name = "CMD", conflicts_with = "connect")]
+        exec: Option<String>,
+        /// Connect to a peer listening on ADDR.
+        #[arg(long, value_name = "ADDR", required_unless_present = "exec")]
+        connect: Option<String>,
+        /// Only the scenario with this id (repeatable).
+        #[arg(long, value_name = "ID")]
+        only: Vec<String>,
+        /// Per-reply timeout.
+        #[arg(long, value_name = "MS", default_value_t = 5000)]
+        timeout_ms: u64,
+    },
+    /// List scenario ids and their expected codes.
+    List,
+    /// Serve the driver protocol with the reference implementation.
+    ReferencePeer {
+        /// Accept TCP connections on ADDR instead of using stdin/stdout.
+        #[arg(long, value_name = "ADDR")]
+        listen: Option<String>,
+    },
+}
+
+fn main() {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run {
+            exec,
+            connect,
+            only,
+            timeout_ms,
+        } => {
+            let selected = scenarios::select(&only).unwrap_or_else(|e| fail(&e));
+            let timeout = Duration::from_millis(timeout_ms);
+            let link = match (exec, connect) {
+                (Some(cmd), _) => PeerLink::spawn(&cmd, timeout),
+                (None, Some(addr)) => PeerLink::connect(&addr, timeout),
+                (None, None) => unreachable!("clap requires --exec or --connect"),
+            };
+            let mut link = link.unwrap_or_else(|e| fail(&e.to_string()));
+            let report = report::run(&mut link, &selected);
+            if cli.json {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&report).expect("report serializes")
+                );
+            } else {
+                print!("{}", report.to_text());
+            }
+            if !report.ok {
+                std::process::exit(1);
+            }
+        }
+        Command::List => list(cli.json),
+        Command::ReferencePeer { listen } => {
+            if let Err(e) = reference_peer(listen.as_deref()) {
+                fail(&e.to_string());
+            }
+        }
+    }
+}
+
+fn list(json: bool) {
+    let rows: Vec<_> = scenarios::SCENARIOS
+        .iter()
+        .map(|scenario| {
+            let expected: Vec<_> = scenario
+                .build()
+                .steps
+                .iter()
+                .map(|step| step.expect.as_str())
+                .collect();
+            (scenario, expected)
+        })
+        .collect();
+    if json {
+        let value: Vec<_> = rows
+            .iter()
+            .map(|(s, expected)| {
+                serde_json::json!({
+                    "id": s.id,
+                    "description": s.description,
+                    "capabilities": s.capabilities,
+                    "expected": expected,
+                })
+            })
+            .collect();
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&value).expect("serializes")
+        );
+    } else {
+        for (s, expected) in rows {
+            println!("{:<24} {}", s.id, s.description);
+            println!("{:<24} expects {}", "", expected.join(", "));
+        }
+    }
+}
+
+fn reference_peer(listen: Option<&str>) -> io::Result<()> {
+    let Some(addr) = listen else {
+        return reference::serve(io::stdin().lock(), io::stdout().lock());
+    };
+    let listener = TcpListener::bind(addr)?;
+    eprintln!("bolt-conformance: listening on {}", listener.local_addr()?);
+    for stream in listener.incoming() {
+        let stream = stream?;
+        let reader = BufReader::new(stream.try_clone()?);
+        if let Err(e) = reference::serve(reader, stream) {
+            eprintln!("bolt-conformance: connection ended: {e}");
+        }
+    }
+    Ok(())
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("bolt-conformance: {message}");
+    std::process::exit(2);
+}
@@ -0,0 +1,126 @@
+//! Driver protocol: one JSON object per line, strict request/reply.
+//!
+//! The runner writes a [`Command`]; the peer under test answers with
+//! exactly one [`Reply`] before the next command is sent. Keys are
+//! base64, transfer ids and BTR chain keys are lowercase hex.
+//!
+//! ```text
+//! runner → peer  {"op":"start","scenario":"duplicate-hello",...}
+//! peer → runner  {"op":"ok"}
+//! runner → peer  {"op":"frame","frame":{"type":"hello","payload":"..."}}
+//! peer → runner  {"op":"ok"}
+//! runner → peer  {"op":"frame","frame":{"type":"hello","payload":"..."}}
+//! peer → runner  {"op":"error","code":"DUPLICATE_HELLO","detail":"..."}
+//! runner → peer  {"op":"end"}
+//! peer → runner  {"op":"ok"}
+//! ```
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Runner → peer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum Command {
+    /// Reset all state and begin a scenario with fresh keys.
+    Start(Start),
+    /// One inbound wire frame from the remote peer (the runner).
+    Frame { frame: Value },
+    /// Scenario over; the peer may drop its session state.
+    End,
+}
+
+/// Session parameters for one scenario.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Start {
+    pub scenario: String,
+    /// Peer's ephemeral X25519 keypair for this scenario.
+    pub local_public_key: String,
+    pub local_secret_key: String,
+    /// Runner's ephemeral public key. Every sealed payload (HELLO,
+    /// envelopes) is sealed by the runner for `local_public_key`.
+    pub remote_public_key: String,
+    /// Capabilities the peer advertises in this scenario.
+    pub capabilities: Vec<String>,
+    /// BTR fixture; present when `bolt.transfer-ratchet-v1` is among
+    /// `capabilities`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub btr: Option<BtrFixture>,
+}
+
+/// Pre-agreed BTR receive state for the scenario's ratcheted transfer,
+/// standing in for the DH ratchet step (vectors cover that separately).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BtrFixture {
+    /// 16-byte transfer id, hex. The ratcheted FILE_OFFER uses this id.
+    pub transfer_id: String,
+    pub generation: u32,
+    /// Chain key before chunk 0, hex.
+    pub chain_key: String,
+}
+
+/// Peer → runner.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum Reply {
+    /// Command accepted (frame processed without error).
+    Ok,
+    /// Frame rejected with a PROTOCOL.md §10 wire error code.
+    Error {
+        code: String,
+        #[serde(default)]
+        detail: String,
+    },
+    /// Reply to `start` only: the peer does not implement this scenario.
+    Skip {
+        #[serde(default)]
+        detail: String,
+    },
+}
+
+impl Reply {
+    pub fn error(code: &str, detail: impl Into<String>) -> Self {
+        Reply::Error {
+            code: code.to_string(),
+            detail: detail.into(),
+        }
+    }
+
+    /// `ok`, the error code, or `skip` — the value scoring compares.
+    pub fn outcome(&self) -> &str {
+        match self {
+            Reply::Ok => "ok",
+            Reply::Error { code, .. } => code,
+            Reply::Skip { .. } => "skip",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn command_wire_shape() {
+        let end = serde_json::to_value(Command::End).unwrap();
+        assert_eq!(end, json!({"op": "end"}));
+
+        let frame = Command::Frame {
+            frame: json!({"type": "ping"}),
+        };
+        assert_eq!(
+            serde_json::to_value(frame).unwrap(),
+            json!({"op": "frame", "frame": {"type": "ping"}})
+        );
+    }
+
+    #[test]
+    fn reply_detail_is_optional() {
+        let reply: Reply =
+            serde_json::from_str(r#"{"op":"error","code":"DUPLICATE_HELLO"}"#).unwrap();
+        assert_eq!(reply.outcome(), "DUPLICATE_HELLO");
+        let reply: Reply = serde_json::from_str(r#"{"op":"ok"}"#).unwrap();
+        assert_eq!(reply, Reply::Ok);
+    }
+}
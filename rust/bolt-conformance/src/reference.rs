@@ -0,0 +1,336 @@
+//! Reference peer: the driver protocol served by the canonical crates.
+//!
+//! `bolt-conformance reference-peer` runs this over stdin/stdout (or TCP
+//! with `--listen`), so an implementer can check their runner setup and
+//! read the expected behavior in one place. Every scenario passes against
+//! it; CI keeps it that way.
+//!
+//! Inbound frame rules, in order:
+//! 1. `hello` — exactly once ([`HelloState`]); open, validate, negotiate.
+//! 2. `profile-envelope` — after HELLO requires envelope-v1 negotiated;
+//!    open; before HELLO only an inner `error` is accepted.
+//! 3. plaintext `ping`/`pong` before HELLO; any other plaintext message is
+//!    `INVALID_STATE` before HELLO and `ENVELOPE_REQUIRED` after it when
+//!    envelope-v1 was negotiated.
+//!
+//! Inner messages drive a [`ReceiveSession`] (offers are auto-accepted);
+//! with `bolt.transfer-ratchet-v1` negotiated, chunks of the fixture
+//! transfer pass the BTR [`ReplayGuard`] and are opened with the chain.
+
+use std::io::{self, BufRead, Write};
+
+use serde_json::Value;
+
+use bolt_btr::constants::TRANSFER_RATCHET_CAPABILITY;
+use bolt_btr::encrypt::btr_open;
+use bolt_btr::key_schedule::chain_advance;
+use bolt_btr::replay::ReplayGuard;
+use bolt_btr::BtrError;
+use bolt_core::constants::PROFILE_ENVELOPE_V1_CAPABILITY;
+use bolt_core::crypto::open_box_payload;
+use bolt_core::encoding::{from_base64, from_hex};
+use bolt_core::session::{negotiate_capabilities, HelloError, HelloState};
+use bolt_transfer_core::receive::MAX_TRANSFER_BYTES;
+use bolt_transfer_core::{ReceiveSession, TransferError};
+
+use crate::protocol::{Command, Reply, Start};
+
+/// Wire code + detail for a rejected frame.
+type Rejection = (&'static str, String);
+
+fn reject(code: &'static str, detail: impl Into<String>) -> Rejection {
+    (code, detail.into())
+}
+
+fn transfer(e: TransferError) -> Rejection {
+    (e.code(), e.to_string())
+}
+
+fn ratchet(e: BtrError) -> Rejection {
+    (e.wire_code(), e.to_string())
+}
+
+fn key32(b64: &str, name: &str) -> Result<[u8; 32], String> {
+    from_base64(b64)
+        .ok()
+        .and_then(|k| k.try_into().ok())
+        .ok_or_else(|| format!("{name} must be 32 bytes of base64"))
+}
+
+struct BtrReceive {
+    transfer_id: [u8; 16],
+    generation: u32,
+    chain_key: [u8; 32],
+    guard: ReplayGuard,
+}
+
+struct Session {
+    local_secret_key: [u8; 32],
+    remote_public_key: [u8; 32],
+    capabilities: Vec<String>,
+    hello: HelloState,
+    negotiated: Vec<String>,
+    receive: ReceiveSession,
+    btr: Option<BtrReceive>,
+}
+
+impl Session {
+    fn new(start: &Start) -> Result<Self, String> {
+        let btr = match &start.btr {
+            Some(fixture) => {
+                let transfer_id = from_hex(&fixture.transfer_id)
+                    .ok()
+                    .and_then(|id| id.try_into().ok())
+                    .ok_or("btr.transfer_id must be 16 bytes of hex")?;
+                let chain_key = from_hex(&fixture.chain_key)
+                    .ok()
+                    .and_then(|key| key.try_into().ok())
+                    .ok_or("btr.chain_key must be 32 bytes of hex")?;
+                Some(BtrReceive {
+                    transfer_id,
+                    generation: fixture.generation,
+                    chain_key,
+                    guard: ReplayGuard::new(),
+                })
+            }
+            None => None,
+        };
+        Ok(Self {
+            local_secret_key: key32(&start.local_secret_key, "local_secret_key")?,
+            remote_public_key: key32(&start.remote_public_key, "remote_public_key")?,
+            capabilities: start.capabilities.clone(),
+            hello: HelloState::new(),
+            negotiated: Vec::new(),
+            receive: ReceiveSession::new(),
+            btr,
+        })
+    }
+
+    fn negotiated(&self, capability: &str) -> bool {
+        self.negotiated.iter().any(|c| c == capability)
+    }
+
+    fn on_frame(&mut self, frame: &Value) -> Result<(), Rejection> {
+        let hello_done = self.hello.is_completed();
+        match frame["type"].as_str() {
+            Some("hello") => self.on_hello(frame).map_err(|e| (e.code(), e.to_string())),
+            Some("profile-envelope") => {
+                if hello_done && !self.negotiated(PROFILE_ENVELOPE_V1_CAPABILITY) {
+                    return Err(reject(
+                        "ENVELOPE_UNNEGOTIATED",
+                        "profile-envelope-v1 not negotiated",
+                    ));
+                }
+                let inner = self.open_envelope(frame)?;
+                if !hello_done && inner["type"] != "error" {
+                    return Err(reject("INVALID_STATE", "message before HELLO"));
+                }
+                self.on_message(&inner, frame.get("chain_index"))
+            }
+            Some("ping" | "pong") if !hello_done => Ok(()),
+            Some(_) if !hello_done => Err(reject("INVALID_STATE", "message before HELLO")),
+            Some(_) if self.negotiated(PROFILE_ENVELOPE_V1_CAPABILITY) => Err(reject(
+                "ENVELOPE_REQUIRED",
+                "plaintext message after envelope-v1 negotiation",
+            )),
+            Some(_) => self.on_message(frame, None),
+            None => Err(reject("INVALID_MESSAGE", "frame has no string `type`")),
+        }
+    }
+
+    fn on_hello(&mut self, frame: &Value) -> Result<(), HelloError> {
+        if self.hello.is_completed() {
+            return Err(HelloError::DuplicateHello);
+        }
+        let payload = frame["payload"]
+            .as_str()
+            .ok_or_else(|| HelloError::ParseError("missing `payload`".into()))?;
+        let plain = open_box_payload(payload, &self.remote_public_key, &self.local_secret_key)
+            .map_err(|e| HelloError::DecryptFail(e.to_string()))?;
+        let schema = |detail: &str| HelloError::SchemaError(detail.to_string());
+        let inner: Value =
+            serde_json::from_slice(&plain).map_err(|_| schema("inner payload is not JSON"))?;
+        if inner["type"] != "hello" || inner["version"] != 1 {
+            return Err(schema("expected type `hello`, version 1"));
+        }
+        let identity = inner["identityPublicKey"].as_str().unwrap_or_default();
+        key32(identity, "identityPublicKey").map_err(|e| schema(&e))?;
+        let remote: Vec<String> = inner["capabilities"]
+            .as_array()
+            .and_then(|caps| caps.iter().map(|c| c.as_str().map(String::from)).collect())
+            .ok_or_else(|| schema("`capabilities` must be an array of strings"))?;
+
+        self.hello
+            .mark_completed()
+            .map_err(|_| HelloError::DuplicateHello)?;
+        self.negotiated = negotiate_capabilities(&self.capabilities, &remote);
+        Ok(())
+    }
+
+    fn open_envelope(&self, frame: &Value) -> Result<Value, Rejection> {
+        let payload = match (&frame["version"], &frame["encoding"], &frame["payload"]) {
+            (Value::Number(v), Value::String(enc), Value::String(p))
+                if v.as_u64() == Some(1) && enc == "base64" =>
+            {
+                p
+            }
+            _ => return Err(reject("ENVELOPE_INVALID", "bad envelope header")),
+        };
+        let plain = open_box_payload(payload, &self.remote_public_key, &self.local_secret_key)
+            .map_err(|e| reject("ENVELOPE_DECRYPT_FAIL", e.to_string()))?;
+        serde_json::from_slice(&plain)
+            .map_err(|_| reject("INVALID_MESSAGE", "inner payload is not JSON"))
+    }
+
+    fn on_message(&mut self, msg: &Value, chain_index: Option<&Value>) -> Result<(), Rejection> {
+        let str_field = |name: &str| {
+            msg[name]
+                .as_str()
+                .ok_or_else(|| reject("INVALID_MESSAGE", format!("missing `{name}`")))
+        };
+        let u64_field = |name: &str| {
+            msg[name]
+                .as_u64()
+                .ok_or_else(|| reject("INVALID_MESSAGE", format!("missing `{name}`")))
+        };
+        let ratcheted = self.negotiated(TRANSFER_RATCHET_CAPABILITY);
+
+        match msg["type"].as_str().unwrap_or_default() {
+            "ping" | "pong" | "error" => Ok(()),
+            "file-offer" => {
+                let tid = str_field("transferId")?;
+                str_field("filename")?;
+                let size = u64_field("size")?;
+                let total = u32::try_from(u64_field("totalChunks")?)
+                    .map_err(|_| reject("INVALID_MESSAGE", "`totalChunks` out of range"))?;
+                if size > MAX_TRANSFER_BYTES {
+                    return Err(reject(
+                        "LIMIT_EXCEEDED",
+                        format!("offer of {size} bytes exceeds {MAX_TRANSFER_BYTES}"),
+                    ));
+                }
+                if ratcheted {
+                    let btr = self
+                        .btr
+                        .as_mut()
+                        .ok_or_else(|| reject("RATCHET_STATE_ERROR", "no BTR fixture"))?;
+                    if from_hex(tid).ok().as_deref() != Some(&btr.transfer_id[..]) {
+                        return Err(reject(
+                            "RATCHET_STATE_ERROR",
+                            "offer does not match the BTR fixture transfer",
+                        ));
+                    }
+                    btr.guard.begin_transfer(btr.transfer_id, btr.generation);
+                }
+                self.receive
+                    .on_file_offer(tid, size, total, None)
+                    .map_err(transfer)?;
+                self.receive.accept_current_offer().map_err(transfer)?;
+                Ok(())
+            }
+            "file-chunk" => {
+                let tid = str_field("transferId")?;
+                let index = u32::try_from(u64_field("chunkIndex")?)
+                    .map_err(|_| reject("INVALID_MESSAGE", "`chunkIndex` out of range"))?;
+                let data = from_base64(str_field("chunk")?)
+                    .map_err(|e| reject("INVALID_MESSAGE", e.to_string()))?;
+                let data = if ratcheted {
+                    self.open_ratcheted(tid, chain_index, &data)?
+                } else {
+                    data
+                };
+                self.receive
+                    .on_file_chunk(tid, index, &data)
+                    .map_err(transfer)
+            }
+            "file-finish" => {
+                let tid = str_field("transferId")?;
+                self.receive.on_file_finish(tid, None).map_err(transfer)
+            }
+            other => Err(reject(
+                "UNKNOWN_MESSAGE_TYPE",
+                format!("unknown message type `{other}`"),
+            )),
+        }
+    }
+
+    fn open_ratcheted(
+        &mut self,
+        tid: &str,
+        chain_index: Option<&Value>,
+        sealed: &[u8],
+    ) -> Result<Vec<u8>, Rejection> {
+        let btr = self
+            .btr
+            .as_mut()
+            .ok_or_else(|| reject("RATCHET_STATE_ERROR", "no BTR fixture"))?;
+        let chain_index = chain_index
+            .and_then(Value::as_u64)
+            .and_then(|i| u32::try_from(i).ok())
+            .ok_or_else(|| reject("RATCHET_STATE_ERROR", "missing `chain_index`"))?;
+        let transfer_id: [u8; 16] = from_hex(tid)
+            .ok()
+            .and_then(|id| id.try_into().ok())
+            .ok_or_else(|| reject("RATCHET_STATE_ERROR", "transferId is not 16 bytes"))?;
+        btr.guard
+            .check(&transfer_id, btr.generation, chain_index)
+            .map_err(ratchet)?;
+        let step = chain_advance(&btr.chain_key);
+        let plain = btr_open(&step.message_key, sealed).map_err(ratchet)?;
+        btr.chain_key = step.next_chain_key;
+        Ok(plain)
+    }
+}
+
+/// Driver-protocol state machine; one session per `start`.
+#[derive(Default)]
+pub struct ReferencePeer {
+    session: Option<Session>,
+}
+
+impl ReferencePeer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn handle(&mut self, command: Command) -> Reply {
+        match command {
+            Command::Start(start) => match Session::new(&start) {
+                Ok(session) => {
+                    self.session = Some(session);
+                    Reply::Ok
+                }
+                Err(detail) => Reply::error("INVALID_MESSAGE", detail),
+            },
+            Command::Frame { frame } => match &mut self.session {
+                Some(session) => match session.on_frame(&frame) {
+                    Ok(()) => Reply::Ok,
+                    Err((code, detail)) => Reply::error(code, detail),
+                },
+                None => Reply::error("INVALID_STATE", "frame before start"),
+            },
+            Command::End => {
+                self.session = None;
+                Reply::Ok
+            }
+        }
+    }
+}
+
+/// Serve the driver protocol until `input` ends.
+pub fn serve(input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+    let mut peer = ReferencePeer::new();
+    for line in input.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let reply = match serde_json::from_str(&line) {
+            Ok(command) => peer.handle(command),
+            Err(e) => Reply::error("INVALID_MESSAGE", format!("bad command: {e}")),
+        };
+        writeln!(output, "{}", serde_json::to_string(&reply)?)?;
+        output.flush()?;
+    }
+    Ok(())
+}
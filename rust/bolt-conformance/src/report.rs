@@ -0,0 +1,195 @@
+//! Running scenarios and scoring the replies.
+//!
+//! A scenario passes when every reply matches its expected outcome
+//! (`ok` or the exact wire code). The first mismatch ends the scenario —
+//! the peer's state is no longer the one the script assumes. A transport
+//! failure ends the run; remaining scenarios are reported as `error`.
+
+use serde::Serialize;
+
+use crate::link::PeerLink;
+use crate::protocol::{Command, Reply};
+use crate::scenarios::Scenario;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Pass,
+    /// A reply did not match the expected outcome.
+    Fail,
+    /// The peer declined the scenario at `start`.
+    Skipped,
+    /// Transport failure (timeout, crash, malformed reply) or not run.
+    Error,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StepReport {
+    pub label: String,
+    pub expected: &'static str,
+    pub actual: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub detail: String,
+    pub pass: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ScenarioReport {
+    pub id: &'static str,
+    pub description: &'static str,
+    pub status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    pub steps: Vec<StepReport>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    /// Every scenario passed or was skipped.
+    pub ok: bool,
+    pub passed: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    pub errors: usize,
+    pub scenarios: Vec<ScenarioReport>,
+}
+
+impl Report {
+    fn new(scenarios: Vec<ScenarioReport>) -> Self {
+        let count = |status| scenarios.iter().filter(|s| s.status == status).count();
+        let (passed, failed, skipped, errors) = (
+            count(Status::Pass),
+            count(Status::Fail),
+            count(Status::Skipped),
+            count(Status::Error),
+        );
+        Self {
+            ok: failed == 0 && errors == 0,
+            passed,
+            failed,
+            skipped,
+            errors,
+            scenarios,
+        }
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut out = String::new();
+        for s in &self.scenarios {
+            let status = match s.status {
+                Status::Pass => "PASS",
+                Status::Fail => "FAIL",
+                Status::Skipped => "SKIP",
+                Status::Error => "ERROR",
+            };
+            out.push_str(&format!("{status:<6} {:<24} {}\n", s.id, s.description));
+            for step in s.steps.iter().filter(|step| !step.pass) {
+                out.push_str(&format!(
+                    "       {}: expected {}, got {}",
+                    step.label, step.expected, step.actual
+                ));
+                if !step.detail.is_empty() {
+                    out.push_str(&format!(" ({})", step.detail));
+                }
+                out.push('\n');
+            }
+            if let Some(detail) = &s.detail {
+                out.push_str(&format!("       {detail}\n"));
+            }
+        }
+        out.push_str(&format!(
+            "{}/{} passed, {} failed, {} skipped, {} errors\n",
+            self.passed,
+            self.scenarios.len(),
+            self.failed,
+            self.skipped,
+            self.errors
+        ));
+        out
+    }
+}
+
+/// Run `scenarios` in order against `link`.
+pub fn run(link: &mut PeerLink, scenarios: &[&'static Scenario]) -> Report {
+    let mut reports = Vec::new();
+    let mut broken = false;
+    for scenario in scenarios {
+        let report = if broken {
+            ScenarioReport {
+                id: scenario.id,
+                description: scenario.description,
+                status: Status::Error,
+                detail: Some("not run: peer link failed earlier".to_string()),
+                steps: Vec::new(),
+            }
+        } else {
+            run_one(link, scenario)
+        };
+        broken |= report.status == Status::Error;
+        reports.push(report);
+    }
+    Report::new(reports)
+}
+
+fn run_one(link: &mut PeerLink, scenario: &'static Scenario) -> ScenarioReport {
+    let mut report = ScenarioReport {
+        id: scenario.id,
+        description: scenario.description,
+        status: Status::Pass,
+        detail: None,
+        steps: Vec::new(),
+    };
+    let script = scenario.build();
+
+    match link.request(&script.start()) {
+        Ok(Reply::Ok) => {}
+        Ok(Reply::Skip { detail }) => {
+            report.status = Status::Skipped;
+            report.detail = (!detail.is_empty()).then_some(detail);
+            return report;
+        }
+        Ok(other) => {
+            report.status = Status::Error;
+            report.detail = Some(format!("start rejected: {}", other.outcome()));
+            return report;
+        }
+        Err(e) => {
+            report.status = Status::Error;
+            report.detail = Some(format!("start: {e}"));
+            return report;
+        }
+    }
+
+    for step in script.steps {
+        let command = Command::Frame { frame: step.frame };
+        let reply = match link.request(&command) {
+            Ok(reply) => reply,
+            Err(e) => {
+                report.status = Status::Error;
+                report.detail = Some(format!("{}: {e}", step.label));
+                return report;
+            }
+        };
+        let pass = reply.outcome() == step.expect.as_str();
+        report.steps.push(StepReport {
+            label: step.label,
+            expected: step.expect.as_str(),
+            actual: reply.outcome().to_string(),
+            detail: match reply {
+                Reply::Error { detail, .. } => detail,
+                _ => String::new(),
+            },
+            pass,
+        });
+        if !pass {
+            report.status = Status::Fail;
+            break;
+        }
+    }
+
+    if let Err(e) = link.request(&Command::End) {
+        report.status = Status::Error;
+        report.detail = Some(format!("end: {e}"));
+    }
+    report
+}
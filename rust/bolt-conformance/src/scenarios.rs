@@ -0,0 +1,405 @@
+//! Scripted scenarios and the frames they send.
+//!
+//! The runner plays the remote peer. Each scenario is a fixed list of
+//! inbound frames with the reply expected for each: `ok` or one wire
+//! error code. Frames are built fresh per run ([`Script`]) because they
+//! are sealed under that run's ephemeral keys.
+//!
+//! Wire shapes (LocalBolt profile):
+//!
+//! ```text
+//! HELLO     {"type":"hello","payload":"<sealed inner HELLO>"}
+//! envelope  {"type":"profile-envelope","version":1,"encoding":"base64",
+//!            "payload":"<sealed inner message>"}   (+ "chain_index",
+//!            "ratchet_generation" on BTR chunks)
+//! inner     {"type":"ping"|"pong"|"file-offer"|"file-chunk"|"file-finish",...}
+//! ```
+
+use rand_core::{OsRng, RngCore};
+use serde_json::{json, Value};
+
+use bolt_btr::constants::TRANSFER_RATCHET_CAPABILITY;
+use bolt_btr::encrypt::btr_seal;
+use bolt_btr::key_schedule::chain_advance;
+use bolt_core::constants::{FILE_HASH_CAPABILITY, PROFILE_ENVELOPE_V1_CAPABILITY};
+use bolt_core::crypto::{generate_ephemeral_keypair, seal_box_payload, KeyPair};
+use bolt_core::encoding::{from_base64, to_base64, to_hex};
+use bolt_transfer_core::receive::MAX_TRANSFER_BYTES;
+
+use crate::protocol::{BtrFixture, Command, Start};
+
+/// Capabilities the runner advertises in every HELLO it sends; the
+/// scenario's peer capabilities decide what is negotiated.
+pub const RUNNER_CAPABILITIES: [&str; 3] = [
+    FILE_HASH_CAPABILITY,
+    PROFILE_ENVELOPE_V1_CAPABILITY,
+    TRANSFER_RATCHET_CAPABILITY,
+];
+
+/// Expected reply to one frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expect {
+    Ok,
+    Error(&'static str),
+}
+
+impl Expect {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Expect::Ok => "ok",
+            Expect::Error(code) => code,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Step {
+    pub label: String,
+    pub frame: Value,
+    pub expect: Expect,
+}
+
+/// One scripted scenario.
+pub struct Scenario {
+    pub id: &'static str,
+    pub description: &'static str,
+    /// Capabilities the peer under test advertises.
+    pub capabilities: &'static [&'static str],
+    script: fn(&mut Script),
+}
+
+impl Scenario {
+    /// Fresh keys and the frames for one run.
+    pub fn build(&self) -> Script {
+        let mut script = Script::new(self);
+        (self.script)(&mut script);
+        script
+    }
+}
+
+/// Every scenario, in run order.
+pub const SCENARIOS: &[Scenario] = &[
+    Scenario {
+        id: "baseline-transfer",
+        description: "HELLO, enveloped ping and a two-chunk transfer are all accepted",
+        capabilities: &[PROFILE_ENVELOPE_V1_CAPABILITY],
+        script: baseline_transfer,
+    },
+    Scenario {
+        id: "duplicate-hello",
+        description: "A second HELLO after a completed exchange",
+        capabilities: &[PROFILE_ENVELOPE_V1_CAPABILITY],
+        script: duplicate_hello,
+    },
+    Scenario {
+        id: "pre-hello-offer",
+        description: "FILE_OFFER before the HELLO exchange",
+        capabilities: &[PROFILE_ENVELOPE_V1_CAPABILITY],
+        script: pre_hello_offer,
+    },
+    Scenario {
+        id: "envelope-downgrade",
+        description: "Plaintext FILE_OFFER after profile-envelope-v1 was negotiated",
+        capabilities: &[PROFILE_ENVELOPE_V1_CAPABILITY],
+        script: envelope_downgrade,
+    },
+    Scenario {
+        id: "envelope-unnegotiated",
+        description: "Envelope received when profile-envelope-v1 was not negotiated",
+        capabilities: &[],
+        script: envelope_unnegotiated,
+    },
+    Scenario {
+        id: "envelope-tampered",
+        description: "Envelope whose sealed payload has one bit flipped",
+        capabilities: &[PROFILE_ENVELOPE_V1_CAPABILITY],
+        script: envelope_tampered,
+    },
+    Scenario {
+        id: "oversized-offer",
+        description: "FILE_OFFER one byte over the 256 MiB transfer limit",
+        capabilities: &[PROFILE_ENVELOPE_V1_CAPABILITY],
+        script: oversized_offer,
+    },
+    Scenario {
+        id: "btr-chunk-replay",
+        description: "A BTR chunk delivered twice (same transfer, generation, chain index)",
+        capabilities: &[PROFILE_ENVELOPE_V1_CAPABILITY, TRANSFER_RATCHET_CAPABILITY],
+        script: btr_chunk_replay,
+    },
+];
+
+/// Scenarios whose id is in `ids` (all when empty); `Err` names the
+/// first unknown id.
+pub fn select(ids: &[String]) -> Result<Vec<&'static Scenario>, String> {
+    if ids.is_empty() {
+        return Ok(SCENARIOS.iter().collect());
+    }
+    ids.iter()
+        .map(|id| {
+            SCENARIOS
+                .iter()
+                .find(|s| s.id == id)
+                .ok_or_else(|| format!("unknown scenario: {id}"))
+        })
+        .collect()
+}
+
+/// Keys, BTR chain and frames for one scenario run.
+pub struct Script {
+    scenario: &'static str,
+    capabilities: Vec<String>,
+    peer: KeyPair,
+    runner: KeyPair,
+    runner_identity: [u8; 32],
+    btr: Option<BtrFixture>,
+    btr_transfer_id: [u8; 16],
+    chain_key: [u8; 32],
+    pub steps: Vec<Step>,
+}
+
+impl Script {
+    fn new(scenario: &Scenario) -> Self {
+        let mut btr_transfer_id = [0u8; 16];
+        let mut chain_key = [0u8; 32];
+        OsRng.fill_bytes(&mut btr_transfer_id);
+        OsRng.fill_bytes(&mut chain_key);
+        let btr = scenario
+            .capabilities
+            .contains(&TRANSFER_RATCHET_CAPABILITY)
+            .then(|| BtrFixture {
+                transfer_id: to_hex(&btr_transfer_id),
+                generation: 1,
+                chain_key: to_hex(&chain_key),
+            });
+        Self {
+            scenario: scenario.id,
+            capabilities: scenario
+                .capabilities
+                .iter()
+                .map(|c| c.to_string())
+                .collect(),
+            peer: generate_ephemeral_keypair(),
+            runner: generate_ephemeral_keypair(),
+            runner_identity: generate_ephemeral_keypair().public_key,
+            btr,
+            btr_transfer_id,
+            chain_key,
+            steps: Vec::new(),
+        }
+    }
+
+    /// The `start` command for this run.
+    pub fn start(&self) -> Command {
+        Command::Start(Start {
+            scenario: self.scenario.to_string(),
+            local_public_key: to_base64(&self.peer.public_key),
+            local_secret_key: to_base64(&self.peer.secret_key),
+            remote_public_key: to_base64(&self.runner.public_key),
+            capabilities: self.capabilities.clone(),
+            btr: self.btr.clone(),
+        })
+    }
+
+    fn step(&mut self, label: &str, frame: Value, expect: Expect) {
+        self.steps.push(Step {
+            label: label.to_string(),
+            frame,
+            expect,
+        });
+    }
+
+    fn seal(&self, inner: &Value) -> String {
+        seal_box_payload(
+            inner.to_string().as_bytes(),
+            &self.peer.public_key,
+            &self.runner.secret_key,
+        )
+        .expect("box seal")
+    }
+
+    fn hello(&self) -> Value {
+        let inner = json!({
+            "type": "hello",
+            "version": 1,
+            "identityPublicKey": to_base64(&self.runner_identity),
+            "capabilities": RUNNER_CAPABILITIES,
+        });
+        json!({"type": "hello", "payload": self.seal(&inner)})
+    }
+
+    fn envelope(&self, inner: &Value) -> Value {
+        json!({
+            "type": "profile-envelope",
+            "version": 1,
+            "encoding": "base64",
+            "payload": self.seal(inner),
+        })
+    }
+
+    /// Seal `data` with the next BTR message key and wrap it in an
+    /// envelope carrying the chain index.
+    fn btr_chunk(&mut self, transfer_id: &str, index: u32, total: u32, data: &[u8]) -> Value {
+        let step = chain_advance(&self.chain_key);
+        self.chain_key = step.next_chain_key;
+        let sealed = btr_seal(&step.message_key, data).expect("secretbox seal");
+        let mut frame = self.envelope(&chunk(transfer_id, index, total, &sealed));
+        frame["chain_index"] = json!(index);
+        if index == 0 {
+            frame["ratchet_generation"] = json!(1);
+        }
+        frame
+    }
+}
+
+fn offer(transfer_id: &str, size: u64, total_chunks: u32) -> Value {
+    json!({
+        "type": "file-offer",
+        "transferId": transfer_id,
+        "filename": "conformance.bin",
+        "size": size,
+        "totalChunks": total_chunks,
+    })
+}
+
+fn chunk(transfer_id: &str, index: u32, total_chunks: u32, data: &[u8]) -> Value {
+    json!({
+        "type": "file-chunk",
+        "transferId": transfer_id,
+        "chunkIndex": index,
+        "totalChunks": total_chunks,
+        "chunk": to_base64(data),
+    })
+}
+
+fn random_transfer_id() -> String {
+    let mut id = [0u8; 16];
+    OsRng.fill_bytes(&mut id);
+    to_hex(&id)
+}
+
+// ── Scripts ─────────────────────────────────────────────────────
+
+fn baseline_transfer(s: &mut Script) {
+    let tid = random_transfer_id();
+    s.step("HELLO", s.hello(), Expect::Ok);
+    let ping = s.envelope(&json!({"type": "ping", "ts_ms": 1}));
+    s.step("envelope(ping)", ping, Expect::Ok);
+    s.step(
+        "envelope(file-offer)",
+        s.envelope(&offer(&tid, 6, 2)),
+        Expect::Ok,
+    );
+    let first = s.envelope(&chunk(&tid, 0, 2, b"bol"));
+    s.step("envelope(file-chunk 0)", first, Expect::Ok);
+    let second = s.envelope(&chunk(&tid, 1, 2, b"t!\n"));
+    s.step("envelope(file-chunk 1)", second, Expect::Ok);
+    let finish = s.envelope(&json!({"type": "file-finish", "transferId": tid}));
+    s.step("envelope(file-finish)", finish, Expect::Ok);
+}
+
+fn duplicate_hello(s: &mut Script) {
+    s.step("HELLO", s.hello(), Expect::Ok);
+    s.step("HELLO (again)", s.hello(), Expect::Error("DUPLICATE_HELLO"));
+}
+
+fn pre_hello_offer(s: &mut Script) {
+    let frame = s.envelope(&offer(&random_transfer_id(), 6, 1));
+    s.step(
+        "envelope(file-offer) before HELLO",
+        frame,
+        Expect::Error("INVALID_STATE"),
+    );
+}
+
+fn envelope_downgrade(s: &mut Script) {
+    s.step("HELLO", s.hello(), Expect::Ok);
+    s.step(
+        "plaintext file-offer",
+        offer(&random_transfer_id(), 6, 1),
+        Expect::Error("ENVELOPE_REQUIRED"),
+    );
+}
+
+fn envelope_unnegotiated(s: &mut Script) {
+    s.step("HELLO", s.hello(), Expect::Ok);
+    let ping = s.envelope(&json!({"type": "ping", "ts_ms": 1}));
+    s.step(
+        "envelope(ping)",
+        ping,
+        Expect::Error("ENVELOPE_UNNEGOTIATED"),
+    );
+}
+
+fn envelope_tampered(s: &mut Script) {
+    s.step("HELLO", s.hello(), Expect::Ok);
+    let mut frame = s.envelope(&json!({"type": "ping", "ts_ms": 1}));
+    let mut sealed = from_base64(frame["payload"].as_str().unwrap()).unwrap();
+    let last = sealed.len() - 1;
+    sealed[last] ^= 0x01;
+    frame["payload"] = json!(to_base64(&sealed));
+    s.step(
+        "envelope(ping) with flipped bit",
+        frame,
+        Expect::Error("ENVELOPE_DECRYPT_FAIL"),
+    );
+}
+
+fn oversized_offer(s: &mut Script) {
+    s.step("HELLO", s.hello(), Expect::Ok);
+    let frame = s.envelope(&offer(&random_transfer_id(), MAX_TRANSFER_BYTES + 1, 1));
+    s.step(
+        "envelope(file-offer) size 256 MiB + 1",
+        frame,
+        Expect::Error("LIMIT_EXCEEDED"),
+    );
+}
+
+fn btr_chunk_replay(s: &mut Script) {
+    let tid = to_hex(&s.btr_transfer_id);
+    s.step("HELLO", s.hello(), Expect::Ok);
+    s.step(
+        "envelope(file-offer)",
+        s.envelope(&offer(&tid, 6, 2)),
+        Expect::Ok,
+    );
+    let first = s.btr_chunk(&tid, 0, 2, b"bol");
+    s.step("BTR file-chunk 0", first.clone(), Expect::Ok);
+    s.step(
+        "BTR file-chunk 0 (replayed)",
+        first,
+        Expect::Error("RATCHET_CHAIN_ERROR"),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bolt_core::errors::is_valid_wire_error_code;
+
+    #[test]
+    fn scenario_ids_unique_and_codes_registered() {
+        let mut ids: Vec<_> = SCENARIOS.iter().map(|s| s.id).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), SCENARIOS.len());
+
+        for scenario in SCENARIOS {
+            let script = scenario.build();
+            assert!(!script.steps.is_empty(), "{}", scenario.id);
+            for step in &script.steps {
+                if let Expect::Error(code) = step.expect {
+                    assert!(is_valid_wire_error_code(code), "{}: {code}", scenario.id);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn select_rejects_unknown_ids() {
+        assert_eq!(select(&[]).unwrap().len(), SCENARIOS.len());
+        let picked = select(&["duplicate-hello".to_string()]).unwrap();
+        assert_eq!(picked[0].id, "duplicate-hello");
+        assert!(select(&["nope".to_string()]).is_err());
+    }
+}
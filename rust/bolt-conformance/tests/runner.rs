@@ -0,0 +1,150 @@
+//! Runner against the reference peer and against deliberately broken
+//! peers, over both transports.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::process::Command;
+use std::thread;
+use std::time::Duration;
+
+use bolt_conformance::link::PeerLink;
+use bolt_conformance::reference;
+use bolt_conformance::report::{self, Status};
+use bolt_conformance::scenarios::{self, SCENARIOS};
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Serve one TCP connection with `handler` on a background thread.
+fn tcp_peer(handler: impl FnOnce(TcpStream) + Send + 'static) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap().to_string();
+    thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        handler(stream);
+    });
+    addr
+}
+
+fn all() -> Vec<&'static scenarios::Scenario> {
+    scenarios::select(&[]).unwrap()
+}
+
+#[test]
+fn reference_peer_passes_every_scenario() {
+    let addr = tcp_peer(|stream| {
+        let reader = BufReader::new(stream.try_clone().unwrap());
+        reference::serve(reader, stream).unwrap();
+    });
+    let mut link = PeerLink::connect(&addr, TIMEOUT).unwrap();
+    let report = report::run(&mut link, &all());
+    assert!(report.ok, "{}", report.to_text());
+    assert_eq!(report.passed, SCENARIOS.len());
+}
+
+#[test]
+fn binary_scores_reference_peer_over_stdio() {
+    let exe = env!("CARGO_BIN_EXE_bolt-conformance");
+    let output = Command::new(exe)
+        .args(["--json", "run", "--exec"])
+        .arg(format!("\"{exe}\" reference-peer"))
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["ok"], true);
+    assert_eq!(report["passed"], SCENARIOS.len());
+}
+
+/// A peer that accepts everything passes the baseline and fails each
+/// adversarial scenario at the step that should have been rejected.
+#[test]
+fn permissive_peer_fails_adversarial_scenarios() {
+    let addr = tcp_peer(|stream| {
+        let mut writer = stream.try_clone().unwrap();
+        for _ in BufReader::new(stream).lines() {
+            writeln!(writer, r#"{{"op":"ok"}}"#).unwrap();
+        }
+    });
+    let mut link = PeerLink::connect(&addr, TIMEOUT).unwrap();
+    let report = report::run(&mut link, &all());
+    assert!(!report.ok);
+    assert_eq!(report.passed, 1);
+    assert_eq!(report.failed, SCENARIOS.len() - 1);
+
+    let replay = report
+        .scenarios
+        .iter()
+        .find(|s| s.id == "btr-chunk-replay")
+        .unwrap();
+    assert_eq!(replay.status, Status::Fail);
+    let last = replay.steps.last().unwrap();
+    assert_eq!(
+        (last.expected, last.actual.as_str()),
+        ("RATCHET_CHAIN_ERROR", "ok")
+    );
+    assert!(report
+        .to_text()
+        .contains("expected RATCHET_CHAIN_ERROR, got ok"));
+}
+
+#[test]
+fn skipped_scenarios_do_not_fail_the_run() {
+    let addr = tcp_peer(|stream| {
+        let mut writer = stream.try_clone().unwrap();
+        for line in BufReader::new(stream).lines() {
+            let reply = if line.unwrap().contains(r#""op":"start""#) {
+                r#"{"op":"skip","detail":"not implemented"}"#
+            } else {
+                r#"{"op":"ok"}"#
+            };
+            writeln!(writer, "{reply}").unwrap();
+        }
+    });
+    let mut link = PeerLink::connect(&addr, TIMEOUT).unwrap();
+    let report = report::run(&mut link, &all());
+    assert!(report.ok);
+    assert_eq!(report.skipped, SCENARIOS.len());
+}
+
+#[test]
+fn peer_hangup_errors_remaining_scenarios() {
+    let addr = tcp_peer(|stream| {
+        let mut writer = stream.try_clone().unwrap();
+        let mut lines = BufReader::new(stream).lines();
+        lines.next();
+        writeln!(writer, r#"{{"op":"ok"}}"#).unwrap();
+        // Drop both halves after the first `start`.
+    });
+    let mut link = PeerLink::connect(&addr, TIMEOUT).unwrap();
+    let report = report::run(&mut link, &all());
+    assert!(!report.ok);
+    assert_eq!(report.errors, SCENARIOS.len());
+    assert!(report.scenarios[1]
+        .detail
+        .as_deref()
+        .unwrap()
+        .contains("not run"));
+}
+
+#[test]
+fn silent_peer_times_out() {
+    let addr = tcp_peer(|stream| {
+        thread::sleep(Duration::from_millis(500));
+        drop(stream);
+    });
+    let mut link = PeerLink::connect(&addr, Duration::from_millis(50)).unwrap();
+    let report = report::run(
+        &mut link,
+        &scenarios::select(&["duplicate-hello".into()]).unwrap(),
+    );
+    assert_eq!(report.scenarios[0].status, Status::Error);
+    assert!(report.scenarios[0]
+        .detail
+        .as_deref()
+        .unwrap()
+        .contains("no reply"));
+}
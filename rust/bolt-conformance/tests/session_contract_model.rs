@@ -0,0 +1,257 @@
+//! Conformance: session contract model (docs/SESSION_CONTRACT.md).
+//!
+//! proptest drives random trigger traces through `ProductModel` — session
+//! and transfer phases with the contract's trigger tables, policies P1/P3
+//! and INV-1/INV-2 — and checks bolt-app-core's
+//! `is_valid_session_transition` / `is_valid_transfer_transition` against
+//! it. Failing traces are shrunk to a minimal one. The §9 transfer models
+//! are in bolt-core's conformance suite.
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+
+use bolt_app_core::contracts::session_contract::{
+    is_transfer_allowed, is_valid_session_transition, is_valid_transfer_transition, SessionPhase,
+    TransferPhase, VerificationState, SESSION_PHASES, TRANSFER_PHASES,
+};
+
+/// Triggers from the contract's transition tables, plus SAS confirmation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ProductEvent {
+    SelectPeer,
+    SignalReceived,
+    RemoteAccepted,
+    RemoteDeclined,
+    LocalAccepted,
+    LocalDeclined,
+    HandshakeComplete {
+        legacy: bool,
+    },
+    HandshakeFailed,
+    Disconnect,
+    SasConfirmed,
+    StartSend,
+    RemoteStartsSend,
+    TransferDone,
+    TransferFailed,
+    Dismiss,
+    /// Probe the validators with an arbitrary target pair.
+    Probe {
+        session: usize,
+        transfer: usize,
+    },
+}
+
+fn product_event() -> impl Strategy<Value = ProductEvent> {
+    use ProductEvent::*;
+    prop_oneof![
+        Just(SelectPeer),
+        Just(SignalReceived),
+        Just(RemoteAccepted),
+        Just(RemoteDeclined),
+        Just(LocalAccepted),
+        Just(LocalDeclined),
+        any::<bool>().prop_map(|legacy| HandshakeComplete { legacy }),
+        Just(HandshakeFailed),
+        Just(Disconnect),
+        Just(SasConfirmed),
+        Just(StartSend),
+        Just(RemoteStartsSend),
+        Just(TransferDone),
+        Just(TransferFailed),
+        Just(Dismiss),
+        (0..SESSION_PHASES.len(), 0..TRANSFER_PHASES.len())
+            .prop_map(|(session, transfer)| Probe { session, transfer }),
+    ]
+}
+
+/// Session trigger table: (trigger, from, to).
+fn session_trigger(event: ProductEvent, from: SessionPhase) -> Option<SessionPhase> {
+    use ProductEvent as E;
+    use SessionPhase::*;
+    match (event, from) {
+        (E::SelectPeer, Idle) => Some(Requesting),
+        (E::SignalReceived, Idle) => Some(IncomingRequest),
+        (E::RemoteAccepted, Requesting) => Some(Connecting),
+        (E::RemoteDeclined, Requesting) => Some(Idle),
+        (E::LocalAccepted, IncomingRequest) => Some(Connecting),
+        (E::LocalDeclined, IncomingRequest) => Some(Idle),
+        (E::HandshakeComplete { .. }, Connecting) => Some(Connected),
+        (E::HandshakeFailed, Connecting) => Some(Idle),
+        (E::Disconnect, Connected) => Some(Idle),
+        _ => None,
+    }
+}
+
+/// Transfer trigger table: (trigger, from, to). Gating (P1) is applied
+/// separately.
+fn transfer_trigger(event: ProductEvent, from: TransferPhase) -> Option<TransferPhase> {
+    use ProductEvent as E;
+    use TransferPhase::*;
+    match (event, from) {
+        (E::StartSend, Idle) => Some(Sending),
+        (E::RemoteStartsSend, Idle) => Some(Receiving),
+        (E::TransferDone, Sending | Receiving) => Some(Complete),
+        (E::TransferFailed, Sending | Receiving) => Some(Failed),
+        (E::Dismiss, Complete | Failed) => Some(Idle),
+        _ => None,
+    }
+}
+
+/// Pairs reachable through one trigger of the tables above.
+fn model_allows_session(from: SessionPhase, to: SessionPhase) -> bool {
+    use ProductEvent::*;
+    [
+        SelectPeer,
+        SignalReceived,
+        RemoteAccepted,
+        RemoteDeclined,
+        LocalAccepted,
+        LocalDeclined,
+        HandshakeComplete { legacy: false },
+        HandshakeFailed,
+        Disconnect,
+    ]
+    .into_iter()
+    .any(|e| session_trigger(e, from) == Some(to))
+}
+
+fn model_allows_transfer(from: TransferPhase, to: TransferPhase) -> bool {
+    use ProductEvent::*;
+    let by_trigger = [
+        StartSend,
+        RemoteStartsSend,
+        TransferDone,
+        TransferFailed,
+        Dismiss,
+    ]
+    .into_iter()
+    .any(|e| transfer_trigger(e, from) == Some(to));
+    // INV-1: disconnect resets any non-idle transfer.
+    by_trigger || (to == TransferPhase::Idle && from != TransferPhase::Idle)
+}
+
+struct ProductModel {
+    session: SessionPhase,
+    transfer: TransferPhase,
+    verification: VerificationState,
+}
+
+fn session_contract_property(trace: &[ProductEvent]) -> Result<(), TestCaseError> {
+    let mut m = ProductModel {
+        session: SessionPhase::Idle,
+        transfer: TransferPhase::Idle,
+        verification: VerificationState::Unverified,
+    };
+
+    for (step, &event) in trace.iter().enumerate() {
+        if let ProductEvent::Probe { session, transfer } = event {
+            let (s, t) = (SESSION_PHASES[session], TRANSFER_PHASES[transfer]);
+            if is_valid_session_transition(m.session, s) != model_allows_session(m.session, s) {
+                return Err(TestCaseError::fail(format!(
+                    "step {step}: session validator on {:?} -> {s:?}",
+                    m.session
+                )));
+            }
+            if is_valid_transfer_transition(m.transfer, t) != model_allows_transfer(m.transfer, t) {
+                return Err(TestCaseError::fail(format!(
+                    "step {step}: transfer validator on {:?} -> {t:?}",
+                    m.transfer
+                )));
+            }
+            continue;
+        }
+
+        let (session_before, transfer_before) = (m.session, m.transfer);
+
+        if let Some(next) = session_trigger(event, m.session) {
+            m.session = next;
+            match event {
+                ProductEvent::HandshakeComplete { legacy: true } => {
+                    m.verification = VerificationState::Legacy
+                }
+                _ if next == SessionPhase::Idle => {
+                    // P3 canonical reset: INV-1 transfer reset, fresh SAS.
+                    m.transfer = TransferPhase::Idle;
+                    m.verification = VerificationState::Unverified;
+                }
+                _ => {}
+            }
+        }
+        if event == ProductEvent::SasConfirmed
+            && m.session == SessionPhase::Connected
+            && m.verification == VerificationState::Unverified
+        {
+            m.verification = VerificationState::Verified;
+        }
+        if let Some(next) = transfer_trigger(event, m.transfer) {
+            let starting = matches!(next, TransferPhase::Sending | TransferPhase::Receiving);
+            let connected = m.session == SessionPhase::Connected;
+            // INV-2 / P1: only start when gated in.
+            if !starting || is_transfer_allowed(connected, m.verification) {
+                m.transfer = next;
+            }
+        }
+
+        if m.session != session_before && !is_valid_session_transition(session_before, m.session) {
+            return Err(TestCaseError::fail(format!(
+                "step {step}: {event:?} took session {session_before:?} -> {:?}, validator rejects",
+                m.session
+            )));
+        }
+        if m.transfer != transfer_before
+            && !is_valid_transfer_transition(transfer_before, m.transfer)
+        {
+            return Err(TestCaseError::fail(format!(
+                "step {step}: {event:?} took transfer {transfer_before:?} -> {:?}, validator rejects",
+                m.transfer
+            )));
+        }
+        if m.session != SessionPhase::Connected && m.transfer != TransferPhase::Idle {
+            return Err(TestCaseError::fail(format!(
+                "step {step}: transfer {:?} outside a connected session (INV-1)",
+                m.transfer
+            )));
+        }
+        if matches!(
+            m.transfer,
+            TransferPhase::Sending | TransferPhase::Receiving
+        ) && transfer_before == TransferPhase::Idle
+            && m.verification == VerificationState::Unverified
+        {
+            return Err(TestCaseError::fail(format!(
+                "step {step}: transfer started unverified (INV-2)"
+            )));
+        }
+    }
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(1000))]
+
+    /// Contract validators agree with the trigger-table model, and every
+    /// modelled step passes them with INV-1/INV-2 holding.
+    #[test]
+    fn model_session_contract_matches_reference(trace in vec(product_event(), 0..=60)) {
+        session_contract_property(&trace)?;
+    }
+}
+
+/// Model self-check: the trigger tables produce exactly the validators'
+/// documented pair counts (9 session, 10 transfer).
+#[test]
+fn model_session_contract_tables_match_documented_counts() {
+    let session = SESSION_PHASES
+        .iter()
+        .flat_map(|f| SESSION_PHASES.iter().map(move |t| (*f, *t)))
+        .filter(|(f, t)| model_allows_session(*f, *t))
+        .count();
+    let transfer = TRANSFER_PHASES
+        .iter()
+        .flat_map(|f| TRANSFER_PHASES.iter().map(move |t| (*f, *t)))
+        .filter(|(f, t)| model_allows_transfer(*f, *t))
+        .count();
+    assert_eq!((session, transfer), (9, 10));
+}
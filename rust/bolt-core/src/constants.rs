@@ -25,6 +25,15 @@ pub const PEER_CODE_ALPHABET: &str = "ABCDEFGHJKMNPQRSTUVWXYZ23456789";
 /// SAS (Short Authentication String) length in hex characters.
 pub const SAS_LENGTH: usize = 6;
 
+/// SAS word rendering length in words (32 bits, PGP word list).
+pub const SAS_WORD_COUNT: usize = 4;
+
+/// SAS emoji rendering length in emoji (42 bits, 6 bits each).
+pub const SAS_EMOJI_COUNT: usize = 7;
+
+/// SAS decimal rendering length in 4-digit groups (39 bits, 13 bits each).
+pub const SAS_DECIMAL_GROUPS: usize = 3;
+
 /// NaCl box overhead (Poly1305 MAC).
 pub const BOX_OVERHEAD: usize = 16;
 
@@ -46,6 +55,45 @@ pub const BOLT_VERSION: usize = 1;
 /// Capability namespace prefix (§14). All capability strings start with this.
 pub const CAPABILITY_NAMESPACE: &str = "bolt.";
 
+/// Capability string advertising SHA-256 file hash verification.
+pub const FILE_HASH_CAPABILITY: &str = "bolt.file-hash";
+
+/// Capability string advertising ProfileEnvelopeV1 for post-HELLO messages.
+pub const PROFILE_ENVELOPE_V1_CAPABILITY: &str = "bolt.profile-envelope-v1";
+
+/// Pairing payload version (QR/URI format).
+pub const PAIRING_VERSION: u32 = 1;
+
+/// Pairing URI prefix, up to and including the query separator.
+pub const PAIRING_URI_PREFIX: &str = "bolt://pair?";
+
+/// Pairing identity fingerprint length in bytes (truncated SHA-256).
+pub const PAIRING_FINGERPRINT_LENGTH: usize = 16;
+
+/// Pairing one-time secret length in bytes.
+pub const PAIRING_SECRET_LENGTH: usize = 16;
+
+/// PAKE pairing code length (characters from `PEER_CODE_ALPHABET`, excluding dash).
+pub const PAKE_CODE_LENGTH: usize = 6;
+
+/// Capability string advertising CPace PAKE pairing.
+pub const PAKE_CAPABILITY: &str = "bolt.pake-cpace-v1";
+
+/// Identity rotation statement version (first wire byte).
+pub const ROTATION_VERSION: u8 = 1;
+
+/// Identity rotation statement length in bytes (v1, fixed).
+pub const ROTATION_STATEMENT_LENGTH: usize = 209;
+
+/// Maximum rotation statement validity window (30 days).
+pub const ROTATION_MAX_VALIDITY_MS: u64 = 30 * 24 * 60 * 60 * 1000;
+
+/// Tolerated clock skew for a rotation statement's issue time (5 minutes).
+pub const ROTATION_MAX_CLOCK_SKEW_MS: u64 = 5 * 60 * 1000;
+
+/// Capability string advertising identity rotation statements.
+pub const IDENTITY_ROTATION_CAPABILITY: &str = "bolt.identity-rotation-v1";
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -59,6 +107,9 @@ mod tests {
         assert_eq!(PEER_CODE_LENGTH, 6);
         assert_eq!(PEER_CODE_ALPHABET, "ABCDEFGHJKMNPQRSTUVWXYZ23456789");
         assert_eq!(SAS_LENGTH, 6);
+        assert_eq!(SAS_WORD_COUNT, 4);
+        assert_eq!(SAS_EMOJI_COUNT, 7);
+        assert_eq!(SAS_DECIMAL_GROUPS, 3);
         assert_eq!(BOX_OVERHEAD, 16);
         assert_eq!(TRANSFER_ID_LENGTH, 16);
         assert_eq!(SAS_ENTROPY, 24);
@@ -66,6 +117,19 @@ mod tests {
         assert_eq!(FILE_HASH_LENGTH, 32);
         assert_eq!(BOLT_VERSION, 1);
         assert_eq!(CAPABILITY_NAMESPACE, "bolt.");
+        assert_eq!(FILE_HASH_CAPABILITY, "bolt.file-hash");
+        assert_eq!(PROFILE_ENVELOPE_V1_CAPABILITY, "bolt.profile-envelope-v1");
+        assert_eq!(PAIRING_VERSION, 1);
+        assert_eq!(PAIRING_URI_PREFIX, "bolt://pair?");
+        assert_eq!(PAIRING_FINGERPRINT_LENGTH, 16);
+        assert_eq!(PAIRING_SECRET_LENGTH, 16);
+        assert_eq!(PAKE_CODE_LENGTH, 6);
+        assert_eq!(PAKE_CAPABILITY, "bolt.pake-cpace-v1");
+        assert_eq!(ROTATION_VERSION, 1);
+        assert_eq!(ROTATION_STATEMENT_LENGTH, 209);
+        assert_eq!(ROTATION_MAX_VALIDITY_MS, 2_592_000_000);
+        assert_eq!(ROTATION_MAX_CLOCK_SKEW_MS, 300_000);
+        assert_eq!(IDENTITY_ROTATION_CAPABILITY, "bolt.identity-rotation-v1");
     }
 
     #[test]
@@ -42,7 +42,8 @@ pub fn to_hex(data: &[u8]) -> String {
     data.iter().map(|b| format!("{b:02x}")).collect()
 }
 
-/// Decode hex string to bytes.
+/// Decode hex string to bytes. Accepts upper- and lowercase digits only
+/// (no sign, no whitespace, no `0x` prefix).
 ///
 /// # Errors
 /// Returns `BoltError::Encoding` on invalid hex input.
@@ -50,6 +51,11 @@ pub fn from_hex(encoded: &str) -> Result<Vec<u8>, BoltError> {
     if !encoded.len().is_multiple_of(2) {
         return Err(BoltError::Encoding("odd-length hex string".into()));
     }
+    // Checked up front: byte-offset slicing below would panic on a
+    // multi-byte char, and `from_str_radix` alone accepts a leading `+`.
+    if let Some(c) = encoded.chars().find(|c| !c.is_ascii_hexdigit()) {
+        return Err(BoltError::Encoding(format!("invalid hex digit: {c:?}")));
+    }
     (0..encoded.len())
         .step_by(2)
         .map(|i| {
@@ -130,4 +136,16 @@ mod tests {
     fn hex_invalid_chars_rejected() {
         assert!(from_hex("zzzz").is_err());
     }
+
+    #[test]
+    fn hex_sign_rejected() {
+        // `u8::from_str_radix("+f", 16)` parses; the wire format does not.
+        assert!(from_hex("+f").is_err());
+    }
+
+    #[test]
+    fn hex_multibyte_char_rejected_without_panic() {
+        // Byte length 4, but "é" straddles the 2-byte slice boundary.
+        assert!(from_hex("a\u{e9}a").is_err());
+    }
 }
@@ -32,11 +32,31 @@ pub enum BoltError {
     Encoding(String),
 }
 
+impl BoltError {
+    /// Default wire error code for this error category.
+    ///
+    /// The transport layer MAY emit a more specific code for the same
+    /// failure (e.g. `ENVELOPE_DECRYPT_FAIL` rather than
+    /// `ENCRYPTION_FAILED` when opening a profile envelope).
+    pub fn code(&self) -> &'static str {
+        match self {
+            BoltError::Encryption(_) => "ENCRYPTION_FAILED",
+            BoltError::Connection(_) => "CONNECTION_LOST",
+            BoltError::Transfer(_) => "TRANSFER_FAILED",
+            BoltError::Integrity(_) => "INTEGRITY_FAILED",
+            BoltError::Encoding(_) => "INVALID_MESSAGE",
+        }
+    }
+}
+
 // ── Wire Error Code Registry (PROTOCOL.md §10, v0.1.3-spec) ──────────
 
-/// Canonical wire error code registry — 26 codes (11 PROTOCOL + 11 ENFORCEMENT + 4 BTR).
+/// Canonical wire error code registry — 32 codes
+/// (11 PROTOCOL + 11 ENFORCEMENT + 4 BTR + 2 PAKE + 2 ROTATION + 2 PQ).
 ///
 /// Every error frame sent on the wire MUST use a code from this array.
+/// PAKE, ROTATION and PQ codes are not in §10 yet; they are documented in
+/// docs/PROTOCOL_CONTRACT.md §4 until the spec lists them.
 /// String identifiers match the TS `WIRE_ERROR_CODES` array in `errors.ts`.
 ///
 /// Registry is canonical in both Rust bolt-core (here) and TS bolt-core.
@@ -44,7 +64,7 @@ pub enum BoltError {
 /// at the transport layer (transport-web). The conformance test in
 /// `tests/conformance/error_code_mapping.rs` documents which error
 /// flows/types are represented where.
-pub const WIRE_ERROR_CODES: [&str; 26] = [
+pub const WIRE_ERROR_CODES: [&str; 32] = [
     // PROTOCOL class (11)
     "VERSION_MISMATCH",
     "ENCRYPTION_FAILED",
@@ -74,6 +94,15 @@ pub const WIRE_ERROR_CODES: [&str; 26] = [
     "RATCHET_CHAIN_ERROR",
     "RATCHET_DECRYPT_FAIL",
     "RATCHET_DOWNGRADE_REJECTED",
+    // PAKE class (2) — `bolt.pake-cpace-v1`
+    "PAKE_FAILED",
+    "PAKE_CONFIRM_FAILED",
+    // ROTATION class (2) — `bolt.identity-rotation-v1`
+    "ROTATION_INVALID",
+    "ROTATION_EXPIRED",
+    // PQ class (2) — `bolt.pq-hybrid-mlkem768-v1`
+    "PQ_HANDSHAKE_FAILED",
+    "PQ_DOWNGRADE_REJECTED",
 ];
 
 /// Returns `true` if the given string is a canonical wire error code
@@ -82,6 +111,121 @@ pub fn is_valid_wire_error_code(code: &str) -> bool {
     WIRE_ERROR_CODES.contains(&code)
 }
 
+// ── Code Metadata (class + required action) ──────────────────────────
+
+/// Registry class of a wire error code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WireErrorClass {
+    Protocol,
+    Enforcement,
+    Btr,
+    Pake,
+    Rotation,
+    Pq,
+}
+
+impl WireErrorClass {
+    /// Class name as used in PROTOCOL.md §10 (`PROTOCOL`, `BTR`, ...).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            WireErrorClass::Protocol => "PROTOCOL",
+            WireErrorClass::Enforcement => "ENFORCEMENT",
+            WireErrorClass::Btr => "BTR",
+            WireErrorClass::Pake => "PAKE",
+            WireErrorClass::Rotation => "ROTATION",
+            WireErrorClass::Pq => "PQ",
+        }
+    }
+}
+
+/// What the peer that detects the error MUST do after sending it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WireErrorAction {
+    /// Send the error inside an envelope, then close the session.
+    Disconnect,
+    /// Send the error, cancel the affected transfer; the session survives.
+    CancelTransfer,
+    /// Reject the offending message only (e.g. keep the old identity pin).
+    RejectMessage,
+    /// Local or signaling condition surfaced to the user; no frame follows.
+    Notify,
+}
+
+impl WireErrorAction {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            WireErrorAction::Disconnect => "disconnect",
+            WireErrorAction::CancelTransfer => "cancel_transfer",
+            WireErrorAction::RejectMessage => "reject_message",
+            WireErrorAction::Notify => "notify",
+        }
+    }
+}
+
+/// One registry entry with its class and required action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WireErrorSpec {
+    pub code: &'static str,
+    pub class: WireErrorClass,
+    pub action: WireErrorAction,
+}
+
+const fn spec(code: &'static str, class: WireErrorClass, action: WireErrorAction) -> WireErrorSpec {
+    WireErrorSpec {
+        code,
+        class,
+        action,
+    }
+}
+
+/// [`WIRE_ERROR_CODES`] with class and required action, same order.
+///
+/// ENFORCEMENT codes are fail-closed (error frame + disconnect). BTR
+/// actions follow §16.7 (`BtrError::requires_disconnect` in bolt-btr).
+pub const WIRE_ERROR_REGISTRY: [WireErrorSpec; 32] = {
+    use WireErrorAction::*;
+    use WireErrorClass::*;
+    [
+        spec("VERSION_MISMATCH", Protocol, Disconnect),
+        spec("ENCRYPTION_FAILED", Protocol, Disconnect),
+        spec("INTEGRITY_FAILED", Protocol, CancelTransfer),
+        spec("REPLAY_DETECTED", Protocol, CancelTransfer),
+        spec("TRANSFER_FAILED", Protocol, CancelTransfer),
+        spec("LIMIT_EXCEEDED", Protocol, CancelTransfer),
+        spec("CONNECTION_LOST", Protocol, Notify),
+        spec("PEER_NOT_FOUND", Protocol, Notify),
+        spec("ALREADY_CONNECTED", Protocol, Disconnect),
+        spec("INVALID_STATE", Protocol, Disconnect),
+        spec("KEY_MISMATCH", Protocol, Disconnect),
+        spec("DUPLICATE_HELLO", Enforcement, Disconnect),
+        spec("ENVELOPE_REQUIRED", Enforcement, Disconnect),
+        spec("ENVELOPE_UNNEGOTIATED", Enforcement, Disconnect),
+        spec("ENVELOPE_DECRYPT_FAIL", Enforcement, Disconnect),
+        spec("ENVELOPE_INVALID", Enforcement, Disconnect),
+        spec("HELLO_PARSE_ERROR", Enforcement, Disconnect),
+        spec("HELLO_DECRYPT_FAIL", Enforcement, Disconnect),
+        spec("HELLO_SCHEMA_ERROR", Enforcement, Disconnect),
+        spec("INVALID_MESSAGE", Enforcement, Disconnect),
+        spec("UNKNOWN_MESSAGE_TYPE", Enforcement, Disconnect),
+        spec("PROTOCOL_VIOLATION", Enforcement, Disconnect),
+        spec("RATCHET_STATE_ERROR", Btr, Disconnect),
+        spec("RATCHET_CHAIN_ERROR", Btr, CancelTransfer),
+        spec("RATCHET_DECRYPT_FAIL", Btr, CancelTransfer),
+        spec("RATCHET_DOWNGRADE_REJECTED", Btr, Disconnect),
+        spec("PAKE_FAILED", Pake, Disconnect),
+        spec("PAKE_CONFIRM_FAILED", Pake, Disconnect),
+        spec("ROTATION_INVALID", Rotation, RejectMessage),
+        spec("ROTATION_EXPIRED", Rotation, RejectMessage),
+        spec("PQ_HANDSHAKE_FAILED", Pq, Disconnect),
+        spec("PQ_DOWNGRADE_REJECTED", Pq, Disconnect),
+    ]
+};
+
+/// Class and required action for a canonical code; `None` if unregistered.
+pub fn wire_error_spec(code: &str) -> Option<&'static WireErrorSpec> {
+    WIRE_ERROR_REGISTRY.iter().find(|s| s.code == code)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -105,8 +249,8 @@ mod tests {
     }
 
     #[test]
-    fn wire_error_registry_has_26_codes() {
-        assert_eq!(WIRE_ERROR_CODES.len(), 26);
+    fn wire_error_registry_has_32_codes() {
+        assert_eq!(WIRE_ERROR_CODES.len(), 32);
     }
 
     #[test]
@@ -130,12 +274,52 @@ mod tests {
     #[test]
     fn wire_error_registry_btr_class_count() {
         // Indices 22..26 are BTR class (§16.7)
-        let btr = &WIRE_ERROR_CODES[22..];
+        let btr = &WIRE_ERROR_CODES[22..26];
         assert_eq!(btr.len(), 4);
         assert_eq!(btr[0], "RATCHET_STATE_ERROR");
         assert_eq!(btr[3], "RATCHET_DOWNGRADE_REJECTED");
     }
 
+    #[test]
+    fn wire_error_registry_pake_class_count() {
+        // Indices 26..28 are PAKE class
+        let pake = &WIRE_ERROR_CODES[26..28];
+        assert_eq!(pake.len(), 2);
+        assert_eq!(pake[0], "PAKE_FAILED");
+        assert_eq!(pake[1], "PAKE_CONFIRM_FAILED");
+    }
+
+    #[test]
+    fn wire_error_registry_rotation_class_count() {
+        // Indices 28..30 are ROTATION class
+        let rotation = &WIRE_ERROR_CODES[28..30];
+        assert_eq!(rotation.len(), 2);
+        assert_eq!(rotation[0], "ROTATION_INVALID");
+        assert_eq!(rotation[1], "ROTATION_EXPIRED");
+    }
+
+    #[test]
+    fn wire_error_registry_pq_class_count() {
+        // Indices 30..32 are PQ class
+        let pq = &WIRE_ERROR_CODES[30..];
+        assert_eq!(pq.len(), 2);
+        assert_eq!(pq[0], "PQ_HANDSHAKE_FAILED");
+        assert_eq!(pq[1], "PQ_DOWNGRADE_REJECTED");
+    }
+
+    #[test]
+    fn bolt_error_codes_are_canonical() {
+        for err in [
+            BoltError::Encryption(String::new()),
+            BoltError::Connection(String::new()),
+            BoltError::Transfer(String::new()),
+            BoltError::Integrity(String::new()),
+            BoltError::Encoding(String::new()),
+        ] {
+            assert!(is_valid_wire_error_code(err.code()), "{}", err.code());
+        }
+    }
+
     #[test]
     fn wire_error_registry_accepts_canonical() {
         assert!(is_valid_wire_error_code("KEY_MISMATCH"));
@@ -150,6 +334,33 @@ mod tests {
         assert!(!is_valid_wire_error_code("key_mismatch")); // case-sensitive
     }
 
+    #[test]
+    fn wire_error_metadata_matches_code_registry() {
+        let codes: Vec<_> = WIRE_ERROR_REGISTRY.iter().map(|s| s.code).collect();
+        assert_eq!(codes, WIRE_ERROR_CODES);
+        let class = |code| wire_error_spec(code).unwrap().class;
+        assert!(WIRE_ERROR_CODES[..11]
+            .iter()
+            .all(|c| class(c) == WireErrorClass::Protocol));
+        assert!(WIRE_ERROR_CODES[11..22]
+            .iter()
+            .all(|c| class(c) == WireErrorClass::Enforcement));
+        assert!(WIRE_ERROR_CODES[22..26]
+            .iter()
+            .all(|c| class(c) == WireErrorClass::Btr));
+        assert_eq!(class("PAKE_FAILED"), WireErrorClass::Pake);
+        assert_eq!(class("ROTATION_EXPIRED"), WireErrorClass::Rotation);
+        assert_eq!(class("PQ_DOWNGRADE_REJECTED"), WireErrorClass::Pq);
+        assert!(wire_error_spec("NOT_A_REAL_CODE").is_none());
+    }
+
+    #[test]
+    fn enforcement_codes_fail_closed() {
+        for spec in &WIRE_ERROR_REGISTRY[11..22] {
+            assert_eq!(spec.action, WireErrorAction::Disconnect, "{}", spec.code);
+        }
+    }
+
     #[test]
     fn wire_error_registry_all_unique() {
         let mut seen = std::collections::HashSet::new();
@@ -9,6 +9,9 @@
 //! - Public key is derivable from secret key (X25519 property).
 //! - `KeyMismatchError` carries peer_code, expected, received fields.
 //!
+//! Key replacement without a TOFU violation is handled by
+//! [`crate::rotation`].
+//!
 //! ## Non-goals
 //! - No TOFU pin storage (transport concern).
 //! - No persistence logic.
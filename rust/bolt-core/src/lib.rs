@@ -17,6 +17,9 @@
 //! | [`identity`] | `identity.ts` | Complete |
 //! | [`sas`] | `sas.ts` | Complete |
 //! | [`peer_code`] | `peer-code.ts` | Complete |
+//! | [`pairing`] | N/A | Rust-canonical |
+//! | [`pake`] | N/A | Rust-canonical |
+//! | [`rotation`] | N/A | Rust-canonical |
 //! | [`session`] | WebRTCService (TS-owned) | Rust-canonical (AC-RC-07) |
 //! | [`vectors`] | N/A | Complete (test-only) |
 //!
@@ -51,9 +54,21 @@ pub mod identity;
 /// SAS — Short Authentication String computation.
 pub mod sas;
 
+/// SAS rendering tables (PGP word list, emoji set).
+mod sas_tables;
+
 /// Peer code generation and validation.
 pub mod peer_code;
 
+/// Pairing payload — QR/URI pairing with identity commitment.
+pub mod pairing;
+
+/// PAKE pairing — CPace over ristretto255 with a short spoken code.
+pub mod pake;
+
+/// Identity key rotation — signed continuity statement.
+pub mod rotation;
+
 /// Session authority — transport-agnostic handshake lifecycle primitives.
 pub mod session;
 
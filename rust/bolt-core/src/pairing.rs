@@ -0,0 +1,437 @@
+//! Pairing payload — QR/URI pairing with identity commitment.
+//!
+//! A peer code alone is a routing hint, not an authenticator (PROTOCOL.md
+//! §2). A pairing payload adds two values that the displaying peer shows
+//! as a QR code or link:
+//!
+//! - **Identity fingerprint** — first 16 bytes of `SHA-256(identity_pk)`.
+//!   The scanning peer compares it against the identity key received in
+//!   HELLO, which authenticates the displaying peer without SAS.
+//! - **One-time secret** — 16 random bytes. The scanning peer proves
+//!   possession via [`compute_pairing_proof`], which authenticates the
+//!   scanning peer to the displaying peer without SAS.
+//!
+//! When both checks pass on first contact, the peers MAY pin each other
+//! (TOFU) and skip SAS comparison. The displaying peer MUST discard the
+//! secret after one successful verification or when the QR is dismissed.
+//! [`PairingPayload::prove`] and [`PairingPayload::verify`] consume the
+//! payload, so each side uses its copy of the secret exactly once.
+//!
+//! ## URI format (v1)
+//! ```text
+//! bolt://pair?v=1&code=<PEER_CODE>&fp=<base64url(fingerprint)>&s=<base64url(secret)>
+//! ```
+//! base64url is unpadded (RFC 4648 §5). Parameter order is fixed on
+//! output; on input, unknown parameters are ignored and duplicates are
+//! rejected.
+//!
+//! ## Proof
+//! ```text
+//! proof = HMAC-SHA256(
+//!   key = secret,
+//!   msg = "bolt-pairing-proof-v1" || sort32(idA, idB) || sort32(ephA, ephB)
+//! )
+//! ```
+//! The proof binds the secret to this session's keys, so a proof observed
+//! in one session cannot be replayed into another.
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use rand_core::{OsRng, RngCore};
+use sha2::Sha256;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::constants::{
+    PAIRING_FINGERPRINT_LENGTH, PAIRING_SECRET_LENGTH, PAIRING_URI_PREFIX, PAIRING_VERSION,
+    PUBLIC_KEY_LENGTH,
+};
+use crate::errors::BoltError;
+use crate::hash;
+use crate::peer_code::{is_valid_peer_code, normalize_peer_code};
+
+/// Domain separation label for the pairing proof MAC.
+const PAIRING_PROOF_LABEL: &[u8] = b"bolt-pairing-proof-v1";
+
+/// Decoded pairing payload (v1).
+///
+/// The secret is redacted from `Debug` output, zeroized on drop and only
+/// reachable through the consuming [`prove`](Self::prove) and
+/// [`verify`](Self::verify). The payload is neither `Clone` nor
+/// `PartialEq`, so the secret is never copied or compared in variable time.
+#[derive(Zeroize, ZeroizeOnDrop)]
+pub struct PairingPayload {
+    /// Normalized peer code (uppercase, no dashes) used for rendezvous.
+    pub peer_code: String,
+    /// Truncated SHA-256 of the displaying peer's identity public key.
+    pub identity_fingerprint: [u8; PAIRING_FINGERPRINT_LENGTH],
+    /// One-time pairing secret.
+    pub(crate) secret: [u8; PAIRING_SECRET_LENGTH],
+}
+
+impl std::fmt::Debug for PairingPayload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PairingPayload")
+            .field("peer_code", &self.peer_code)
+            .field("identity_fingerprint", &self.identity_fingerprint)
+            .field("secret", &"<redacted>")
+            .finish()
+    }
+}
+
+impl PairingPayload {
+    /// Create a payload for the local peer with a fresh one-time secret.
+    ///
+    /// # Errors
+    /// Returns `BoltError::Encoding` if `peer_code` is not a valid peer code.
+    pub fn generate(
+        peer_code: &str,
+        identity_public_key: &[u8; PUBLIC_KEY_LENGTH],
+    ) -> Result<Self, BoltError> {
+        if !is_valid_peer_code(peer_code) {
+            return Err(BoltError::Encoding(format!(
+                "invalid pairing peer code: {peer_code}"
+            )));
+        }
+        let mut secret = [0u8; PAIRING_SECRET_LENGTH];
+        OsRng.fill_bytes(&mut secret);
+        Ok(Self {
+            peer_code: normalize_peer_code(peer_code),
+            identity_fingerprint: identity_fingerprint(identity_public_key),
+            secret,
+        })
+    }
+
+    /// Encode as a `bolt://pair` URI, suitable for a QR code.
+    pub fn to_uri(&self) -> String {
+        format!(
+            "{PAIRING_URI_PREFIX}v={PAIRING_VERSION}&code={}&fp={}&s={}",
+            self.peer_code,
+            URL_SAFE_NO_PAD.encode(self.identity_fingerprint),
+            URL_SAFE_NO_PAD.encode(self.secret),
+        )
+    }
+
+    /// Parse and validate a `bolt://pair` URI.
+    ///
+    /// # Errors
+    /// Returns `BoltError::Encoding` if the scheme, version, peer code,
+    /// fingerprint, or secret is missing or malformed.
+    pub fn parse(uri: &str) -> Result<Self, BoltError> {
+        let query = uri
+            .strip_prefix(PAIRING_URI_PREFIX)
+            .ok_or_else(|| BoltError::Encoding("not a bolt pairing URI".into()))?;
+
+        let mut version = None;
+        let mut code = None;
+        let mut fp = None;
+        let mut secret = None;
+
+        for param in query.split('&') {
+            let (key, value) = param
+                .split_once('=')
+                .ok_or_else(|| BoltError::Encoding(format!("malformed pairing param: {param}")))?;
+            let slot = match key {
+                "v" => &mut version,
+                "code" => &mut code,
+                "fp" => &mut fp,
+                "s" => &mut secret,
+                _ => continue,
+            };
+            if slot.replace(value).is_some() {
+                return Err(BoltError::Encoding(format!(
+                    "duplicate pairing param: {key}"
+                )));
+            }
+        }
+
+        let version = version.ok_or_else(|| missing("v"))?;
+        if version != PAIRING_VERSION.to_string() {
+            return Err(BoltError::Encoding(format!(
+                "unsupported pairing version: {version}"
+            )));
+        }
+
+        let code = code.ok_or_else(|| missing("code"))?;
+        if !is_valid_peer_code(code) {
+            return Err(BoltError::Encoding(format!(
+                "invalid pairing peer code: {code}"
+            )));
+        }
+
+        Ok(Self {
+            peer_code: normalize_peer_code(code),
+            identity_fingerprint: decode_fixed(fp.ok_or_else(|| missing("fp"))?, "fp")?,
+            secret: decode_fixed(secret.ok_or_else(|| missing("s"))?, "s")?,
+        })
+    }
+
+    /// Whether `identity_public_key` (from HELLO) matches the fingerprint.
+    pub fn matches_identity(&self, identity_public_key: &[u8; PUBLIC_KEY_LENGTH]) -> bool {
+        identity_fingerprint(identity_public_key) == self.identity_fingerprint
+    }
+
+    /// Scanning peer: compute the proof for this session and discard the
+    /// secret. See [`compute_pairing_proof`].
+    pub fn prove(
+        self,
+        identity_a: &[u8; PUBLIC_KEY_LENGTH],
+        identity_b: &[u8; PUBLIC_KEY_LENGTH],
+        ephemeral_a: &[u8; PUBLIC_KEY_LENGTH],
+        ephemeral_b: &[u8; PUBLIC_KEY_LENGTH],
+    ) -> [u8; 32] {
+        compute_pairing_proof(
+            &self.secret,
+            identity_a,
+            identity_b,
+            ephemeral_a,
+            ephemeral_b,
+        )
+    }
+
+    /// Displaying peer: check the scanning peer's proof and discard the
+    /// secret, whether or not it verifies. See [`verify_pairing_proof`].
+    pub fn verify(
+        self,
+        identity_a: &[u8; PUBLIC_KEY_LENGTH],
+        identity_b: &[u8; PUBLIC_KEY_LENGTH],
+        ephemeral_a: &[u8; PUBLIC_KEY_LENGTH],
+        ephemeral_b: &[u8; PUBLIC_KEY_LENGTH],
+        proof: &[u8],
+    ) -> bool {
+        verify_pairing_proof(
+            &self.secret,
+            identity_a,
+            identity_b,
+            ephemeral_a,
+            ephemeral_b,
+            proof,
+        )
+    }
+}
+
+/// Truncated identity fingerprint: first 16 bytes of `SHA-256(identity_pk)`.
+pub fn identity_fingerprint(
+    identity_public_key: &[u8; PUBLIC_KEY_LENGTH],
+) -> [u8; PAIRING_FINGERPRINT_LENGTH] {
+    let digest = hash::sha256(identity_public_key);
+    let mut fp = [0u8; PAIRING_FINGERPRINT_LENGTH];
+    fp.copy_from_slice(&digest[..PAIRING_FINGERPRINT_LENGTH]);
+    fp
+}
+
+/// Compute the scanning peer's proof of secret possession.
+///
+/// Key arguments are commutative (same ordering rule as SAS), so both
+/// peers compute the same value from their own view of the session.
+pub fn compute_pairing_proof(
+    secret: &[u8; PAIRING_SECRET_LENGTH],
+    identity_a: &[u8; PUBLIC_KEY_LENGTH],
+    identity_b: &[u8; PUBLIC_KEY_LENGTH],
+    ephemeral_a: &[u8; PUBLIC_KEY_LENGTH],
+    ephemeral_b: &[u8; PUBLIC_KEY_LENGTH],
+) -> [u8; 32] {
+    proof_mac(secret, identity_a, identity_b, ephemeral_a, ephemeral_b)
+        .finalize()
+        .into_bytes()
+        .into()
+}
+
+/// Verify a pairing proof in constant time.
+pub fn verify_pairing_proof(
+    secret: &[u8; PAIRING_SECRET_LENGTH],
+    identity_a: &[u8; PUBLIC_KEY_LENGTH],
+    identity_b: &[u8; PUBLIC_KEY_LENGTH],
+    ephemeral_a: &[u8; PUBLIC_KEY_LENGTH],
+    ephemeral_b: &[u8; PUBLIC_KEY_LENGTH],
+    proof: &[u8],
+) -> bool {
+    proof_mac(secret, identity_a, identity_b, ephemeral_a, ephemeral_b)
+        .verify_slice(proof)
+        .is_ok()
+}
+
+fn proof_mac(
+    secret: &[u8; PAIRING_SECRET_LENGTH],
+    identity_a: &[u8; PUBLIC_KEY_LENGTH],
+    identity_b: &[u8; PUBLIC_KEY_LENGTH],
+    ephemeral_a: &[u8; PUBLIC_KEY_LENGTH],
+    ephemeral_b: &[u8; PUBLIC_KEY_LENGTH],
+) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret).expect("HMAC accepts keys of any length");
+    mac.update(PAIRING_PROOF_LABEL);
+    mac.update(&crate::sas::sort32(identity_a, identity_b));
+    mac.update(&crate::sas::sort32(ephemeral_a, ephemeral_b));
+    mac
+}
+
+fn missing(key: &str) -> BoltError {
+    BoltError::Encoding(format!("missing pairing param: {key}"))
+}
+
+fn decode_fixed<const N: usize>(value: &str, key: &str) -> Result<[u8; N], BoltError> {
+    let bytes = URL_SAFE_NO_PAD
+        .decode(value)
+        .map_err(|e| BoltError::Encoding(format!("invalid pairing param {key}: {e}")))?;
+    bytes.try_into().map_err(|b: Vec<u8>| {
+        BoltError::Encoding(format!(
+            "pairing param {key} must be {N} bytes, got {}",
+            b.len()
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> PairingPayload {
+        PairingPayload {
+            peer_code: "ABCDEF".into(),
+            identity_fingerprint: [0x11; PAIRING_FINGERPRINT_LENGTH],
+            secret: [0x22; PAIRING_SECRET_LENGTH],
+        }
+    }
+
+    #[test]
+    fn uri_format_is_stable() {
+        assert_eq!(
+            sample().to_uri(),
+            "bolt://pair?v=1&code=ABCDEF&fp=EREREREREREREREREREREQ&s=IiIiIiIiIiIiIiIiIiIiIg"
+        );
+    }
+
+    #[test]
+    fn uri_roundtrip() {
+        let uri = sample().to_uri();
+        let parsed = PairingPayload::parse(&uri).unwrap();
+        assert_eq!(parsed.peer_code, "ABCDEF");
+        assert_eq!(parsed.secret, [0x22; PAIRING_SECRET_LENGTH]);
+        assert_eq!(parsed.to_uri(), uri);
+    }
+
+    #[test]
+    fn generate_roundtrip_and_matches_identity() {
+        let kp = crate::identity::generate_identity_keypair();
+        let payload = PairingPayload::generate("abcd-efgh", &kp.public_key).unwrap();
+        assert_eq!(payload.peer_code, "ABCDEFGH");
+        let parsed = PairingPayload::parse(&payload.to_uri()).unwrap();
+        assert!(parsed.matches_identity(&kp.public_key));
+        let other = crate::identity::generate_identity_keypair();
+        assert!(!parsed.matches_identity(&other.public_key));
+    }
+
+    #[test]
+    fn generate_secrets_are_unique() {
+        let pk = [0x42; 32];
+        let a = PairingPayload::generate("ABCDEF", &pk).unwrap();
+        let b = PairingPayload::generate("ABCDEF", &pk).unwrap();
+        assert_ne!(a.secret, b.secret);
+    }
+
+    #[test]
+    fn generate_rejects_invalid_code() {
+        assert!(PairingPayload::generate("A0CDEF", &[0x42; 32]).is_err());
+    }
+
+    #[test]
+    fn parse_ignores_unknown_params_and_order() {
+        let uri =
+            "bolt://pair?s=IiIiIiIiIiIiIiIiIiIiIg&x=1&fp=EREREREREREREREREREREQ&code=abcdef&v=1";
+        assert_eq!(
+            PairingPayload::parse(uri).unwrap().to_uri(),
+            sample().to_uri()
+        );
+    }
+
+    #[test]
+    fn parse_rejects_malformed() {
+        let good = sample().to_uri();
+        let cases = [
+            good.replace("bolt://pair?", "https://pair?"),
+            good.replace("v=1", "v=2"),
+            good.replace("&code=ABCDEF", ""),
+            good.replace("code=ABCDEF", "code=A0CDEF"),
+            good.replace("fp=EREREREREREREREREREREQ", "fp=ERER"),
+            good.replace("s=IiIiIiIiIiIiIiIiIiIiIg", "s=!!!"),
+            good.clone() + "&v=1",
+            good.clone() + "&novalue",
+        ];
+        for uri in &cases {
+            let err = PairingPayload::parse(uri).unwrap_err();
+            assert!(
+                matches!(err, BoltError::Encoding(_)),
+                "expected Encoding error for {uri}"
+            );
+        }
+    }
+
+    #[test]
+    fn debug_redacts_secret() {
+        let out = format!("{:?}", sample());
+        assert!(out.contains("ABCDEF"));
+        assert!(out.contains("<redacted>"));
+        assert!(
+            !out.contains("34"),
+            "secret bytes (0x22 = 34) leaked: {out}"
+        );
+    }
+
+    #[test]
+    fn zeroize_clears_secret() {
+        let mut payload = PairingPayload::generate("ABCDEF", &[0x42; 32]).unwrap();
+        assert_ne!(payload.secret, [0u8; PAIRING_SECRET_LENGTH]);
+        payload.zeroize();
+        assert_eq!(payload.secret, [0u8; PAIRING_SECRET_LENGTH]);
+    }
+
+    #[test]
+    fn proof_is_commutative_and_verifies() {
+        let secret = [0x33; PAIRING_SECRET_LENGTH];
+        let (ia, ib, ea, eb) = ([1u8; 32], [2u8; 32], [3u8; 32], [4u8; 32]);
+        let proof = compute_pairing_proof(&secret, &ia, &ib, &ea, &eb);
+        assert_eq!(proof, compute_pairing_proof(&secret, &ib, &ia, &eb, &ea));
+        assert!(verify_pairing_proof(&secret, &ib, &ia, &eb, &ea, &proof));
+    }
+
+    #[test]
+    fn proof_rejects_wrong_secret_or_session() {
+        let secret = [0x33; PAIRING_SECRET_LENGTH];
+        let (ia, ib, ea, eb) = ([1u8; 32], [2u8; 32], [3u8; 32], [4u8; 32]);
+        let proof = compute_pairing_proof(&secret, &ia, &ib, &ea, &eb);
+        assert!(!verify_pairing_proof(
+            &[0x34; PAIRING_SECRET_LENGTH],
+            &ia,
+            &ib,
+            &ea,
+            &eb,
+            &proof
+        ));
+        assert!(!verify_pairing_proof(
+            &secret, &ia, &ib, &ea, &[5u8; 32], &proof
+        ));
+        assert!(!verify_pairing_proof(
+            &secret,
+            &ia,
+            &ib,
+            &ea,
+            &eb,
+            &proof[..16]
+        ));
+    }
+
+    #[test]
+    fn payload_proves_and_verifies_once() {
+        let (ia, ib, ea, eb) = ([1u8; 32], [2u8; 32], [3u8; 32], [4u8; 32]);
+        let uri = sample().to_uri();
+        let proof = PairingPayload::parse(&uri)
+            .unwrap()
+            .prove(&ia, &ib, &ea, &eb);
+        assert_eq!(
+            proof,
+            compute_pairing_proof(&[0x22; PAIRING_SECRET_LENGTH], &ia, &ib, &ea, &eb)
+        );
+        assert!(sample().verify(&ib, &ia, &eb, &ea, &proof));
+        assert!(!sample().verify(&ia, &ib, &ea, &[5u8; 32], &proof));
+    }
+}
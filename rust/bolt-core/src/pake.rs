@@ -0,0 +1,474 @@
+//! PAKE pairing — CPace over ristretto255 with a short spoken code.
+//!
+//! Optional pairing mode for users who can only share a short code by
+//! voice. Negotiated via `PAKE_CAPABILITY` (`bolt.pake-cpace-v1`). Both peers enter the same
+//! [`PAKE_CODE_LENGTH`]-character code (drawn from
+//! `PEER_CODE_ALPHABET`); a successful run yields a shared key and
+//! mutually authenticates both identity keys, which the caller then pins
+//! without TOFU or SAS.
+//!
+//! The code is NOT the routing peer code — it never touches the
+//! signaling server. An attacker gets one online guess per run.
+//!
+//! ## Construction (ristretto255, SHA-512)
+//! ```text
+//! gen_str = lv(DSI) || lv(code) || lv(zpad) || lv(CI) || lv(sid)
+//! g       = ristretto_from_uniform(SHA-512(gen_str))
+//! Y_x     = y_x * g                              (y_x random scalar)
+//! K       = y_I * Y_R = y_R * Y_I
+//! ISK     = SHA-512(lv(DSI_ISK) || lv(sid) || lv(K)
+//!                   || lv(Y_I) || lv(id_I) || lv(Y_R) || lv(id_R))
+//! confirm_x = HMAC-SHA256(ISK[..32], "bolt-pake-confirm-" || role_x)
+//! key     = ISK[32..]
+//! ```
+//! `lv(x)` is LEB128 `len(x)` followed by `x`. `DSI = "CPaceRistretto255"`,
+//! `DSI_ISK = "CPaceRistretto255_ISK"`, `CI = "bolt-pake-v1"`, and `zpad`
+//! pads the generator string to the SHA-512 block size. `id_I`/`id_R` are
+//! the identity public keys, so the confirmation messages only verify if
+//! both peers saw the same identity keys and entered the same code.
+//!
+//! `sid` is a per-run session identifier both peers agree on before the
+//! exchange, e.g. `sort32(ephemeral_a, ephemeral_b)`.
+
+use curve25519_dalek::ristretto::{CompressedRistretto, RistrettoPoint};
+use curve25519_dalek::scalar::Scalar;
+use hmac::{Hmac, Mac};
+use rand_core::{OsRng, RngCore};
+use sha2::{Digest, Sha256, Sha512};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::constants::{PAKE_CODE_LENGTH, PEER_CODE_ALPHABET, PUBLIC_KEY_LENGTH};
+use crate::peer_code::normalize_peer_code;
+
+const DSI: &[u8] = b"CPaceRistretto255";
+const DSI_ISK: &[u8] = b"CPaceRistretto255_ISK";
+const CHANNEL_ID: &[u8] = b"bolt-pake-v1";
+const SHA512_BLOCK: usize = 128;
+
+/// Which side of the exchange the local peer is.
+///
+/// The initiator is the peer that sent the first HELLO. Roles fix the
+/// transcript order; both peers MUST agree on them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PakeRole {
+    Initiator,
+    Responder,
+}
+
+impl PakeRole {
+    fn label(self) -> &'static [u8] {
+        match self {
+            PakeRole::Initiator => b"bolt-pake-confirm-initiator",
+            PakeRole::Responder => b"bolt-pake-confirm-responder",
+        }
+    }
+
+    fn peer(self) -> Self {
+        match self {
+            PakeRole::Initiator => PakeRole::Responder,
+            PakeRole::Responder => PakeRole::Initiator,
+        }
+    }
+}
+
+/// PAKE failures.
+///
+/// Wire codes align with the canonical `WIRE_ERROR_CODES` registry in
+/// [`crate::errors`]. Both require disconnect.
+#[derive(Debug, PartialEq, Eq)]
+pub enum PakeError {
+    /// Code is not [`PAKE_CODE_LENGTH`] characters from the alphabet.
+    InvalidCode,
+    /// Remote share is not a valid ristretto255 encoding, or is the identity.
+    InvalidShare,
+    /// Remote confirmation did not verify — wrong code or active attacker.
+    ConfirmFailed,
+}
+
+impl PakeError {
+    /// Wire error code string aligned with the canonical registry.
+    pub fn code(&self) -> &'static str {
+        match self {
+            PakeError::InvalidCode | PakeError::InvalidShare => "PAKE_FAILED",
+            PakeError::ConfirmFailed => "PAKE_CONFIRM_FAILED",
+        }
+    }
+}
+
+impl std::fmt::Display for PakeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PakeError::InvalidCode => write!(f, "PAKE code malformed"),
+            PakeError::InvalidShare => write!(f, "PAKE share invalid"),
+            PakeError::ConfirmFailed => write!(f, "PAKE confirmation failed"),
+        }
+    }
+}
+
+impl std::error::Error for PakeError {}
+
+/// Generate a random PAKE code (`PAKE_CODE_LENGTH` chars, `XXX-XXX`).
+pub fn generate_pake_code() -> String {
+    let alphabet = PEER_CODE_ALPHABET.as_bytes();
+    let mut code = String::with_capacity(PAKE_CODE_LENGTH + 1);
+    while code.len() < PAKE_CODE_LENGTH + 1 {
+        if code.len() == PAKE_CODE_LENGTH / 2 {
+            code.push('-');
+            continue;
+        }
+        let mut byte = [0u8; 1];
+        OsRng.fill_bytes(&mut byte);
+        // Same rejection threshold as peer codes: floor(256 / 31) * 31.
+        if byte[0] < 248 {
+            code.push(alphabet[byte[0] as usize % alphabet.len()] as char);
+        }
+    }
+    code
+}
+
+/// Validate a PAKE code (dashes and case are ignored).
+pub fn is_valid_pake_code(code: &str) -> bool {
+    let normalized = normalize_peer_code(code);
+    normalized.len() == PAKE_CODE_LENGTH
+        && normalized.chars().all(|c| PEER_CODE_ALPHABET.contains(c))
+}
+
+/// One side of a PAKE run. Consumed by [`PakeSession::finish`].
+///
+/// The secret scalar is zeroized on drop.
+#[derive(ZeroizeOnDrop)]
+pub struct PakeSession {
+    #[zeroize(skip)]
+    role: PakeRole,
+    scalar: Scalar,
+    share: [u8; 32],
+    sid: Vec<u8>,
+    local_identity: [u8; PUBLIC_KEY_LENGTH],
+}
+
+impl PakeSession {
+    /// Start a PAKE run with a fresh random scalar.
+    ///
+    /// # Errors
+    /// Returns `PakeError::InvalidCode` if `code` is malformed.
+    pub fn start(
+        role: PakeRole,
+        code: &str,
+        sid: &[u8],
+        local_identity_public_key: &[u8; PUBLIC_KEY_LENGTH],
+    ) -> Result<Self, PakeError> {
+        let mut seed = [0u8; 64];
+        OsRng.fill_bytes(&mut seed);
+        Self::start_seeded(role, code, sid, local_identity_public_key, &seed)
+    }
+
+    /// Deterministic start for golden vectors (test use only).
+    /// `seed` is reduced mod ℓ to form the scalar.
+    #[cfg(feature = "vectors")]
+    pub fn start_with_seed(
+        role: PakeRole,
+        code: &str,
+        sid: &[u8],
+        local_identity_public_key: &[u8; PUBLIC_KEY_LENGTH],
+        seed: &[u8; 64],
+    ) -> Result<Self, PakeError> {
+        Self::start_seeded(role, code, sid, local_identity_public_key, seed)
+    }
+
+    fn start_seeded(
+        role: PakeRole,
+        code: &str,
+        sid: &[u8],
+        local_identity_public_key: &[u8; PUBLIC_KEY_LENGTH],
+        seed: &[u8; 64],
+    ) -> Result<Self, PakeError> {
+        if !is_valid_pake_code(code) {
+            return Err(PakeError::InvalidCode);
+        }
+        let generator = calculate_generator(&normalize_peer_code(code), sid);
+        let scalar = Scalar::from_bytes_mod_order_wide(seed);
+        let share = (scalar * generator).compress().to_bytes();
+        Ok(Self {
+            role,
+            scalar,
+            share,
+            sid: sid.to_vec(),
+            local_identity: *local_identity_public_key,
+        })
+    }
+
+    /// Local share (32 bytes) to send to the peer.
+    pub fn share(&self) -> [u8; 32] {
+        self.share
+    }
+
+    /// Complete the exchange with the peer's share and identity key.
+    ///
+    /// Returns keys whose [`PakeKeys::confirmation`] must be sent to the
+    /// peer. The shared key is released only after the peer's
+    /// confirmation verifies.
+    ///
+    /// # Errors
+    /// Returns `PakeError::InvalidShare` if `remote_share` does not decode
+    /// to a non-identity ristretto255 point.
+    pub fn finish(
+        self,
+        remote_share: &[u8; 32],
+        remote_identity_public_key: &[u8; PUBLIC_KEY_LENGTH],
+    ) -> Result<PakeKeys, PakeError> {
+        let remote_point = CompressedRistretto(*remote_share)
+            .decompress()
+            .ok_or(PakeError::InvalidShare)?;
+        if remote_point == RistrettoPoint::default() {
+            return Err(PakeError::InvalidShare);
+        }
+        let k = (self.scalar * remote_point).compress().to_bytes();
+
+        let (y_i, id_i, y_r, id_r) = match self.role {
+            PakeRole::Initiator => (
+                &self.share,
+                &self.local_identity,
+                remote_share,
+                remote_identity_public_key,
+            ),
+            PakeRole::Responder => (
+                remote_share,
+                remote_identity_public_key,
+                &self.share,
+                &self.local_identity,
+            ),
+        };
+
+        let mut hasher = Sha512::new();
+        for part in [DSI_ISK, &self.sid, &k, y_i, id_i, y_r, id_r] {
+            hasher.update(leb128(part.len()));
+            hasher.update(part);
+        }
+        let isk: [u8; 64] = hasher.finalize().into();
+
+        let mut mac_key = [0u8; 32];
+        let mut key = [0u8; 32];
+        mac_key.copy_from_slice(&isk[..32]);
+        key.copy_from_slice(&isk[32..]);
+
+        Ok(PakeKeys {
+            role: self.role,
+            mac_key,
+            key,
+            remote_identity: *remote_identity_public_key,
+        })
+    }
+}
+
+/// Keys from a completed exchange, pending key confirmation.
+///
+/// Both keys are zeroized on drop.
+#[derive(ZeroizeOnDrop)]
+pub struct PakeKeys {
+    #[zeroize(skip)]
+    role: PakeRole,
+    mac_key: [u8; 32],
+    key: [u8; 32],
+    remote_identity: [u8; PUBLIC_KEY_LENGTH],
+}
+
+/// Outcome of a verified PAKE run.
+///
+/// The key is zeroized on drop; copies taken out of it are not.
+#[derive(Zeroize, ZeroizeOnDrop)]
+pub struct PakeOutcome {
+    /// Mutually authenticated 32-byte shared key.
+    pub key: [u8; 32],
+    /// Peer identity key, authenticated by the code. Pin this.
+    pub remote_identity_public_key: [u8; PUBLIC_KEY_LENGTH],
+}
+
+impl PakeKeys {
+    /// Local confirmation MAC (32 bytes) to send to the peer.
+    pub fn confirmation(&self) -> [u8; 32] {
+        confirm_mac(&self.mac_key, self.role)
+            .finalize()
+            .into_bytes()
+            .into()
+    }
+
+    /// Verify the peer's confirmation (constant time) and release the key.
+    ///
+    /// # Errors
+    /// Returns `PakeError::ConfirmFailed` if the peer used a different
+    /// code, saw different identity keys, or the exchange was tampered with.
+    pub fn verify(self, remote_confirmation: &[u8]) -> Result<PakeOutcome, PakeError> {
+        confirm_mac(&self.mac_key, self.role.peer())
+            .verify_slice(remote_confirmation)
+            .map_err(|_| PakeError::ConfirmFailed)?;
+        Ok(PakeOutcome {
+            key: self.key,
+            remote_identity_public_key: self.remote_identity,
+        })
+    }
+}
+
+fn confirm_mac(mac_key: &[u8; 32], role: PakeRole) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(mac_key).expect("HMAC accepts 32-byte keys");
+    mac.update(role.label());
+    mac
+}
+
+fn calculate_generator(code: &str, sid: &[u8]) -> RistrettoPoint {
+    let prs = code.as_bytes();
+    let len_zpad = SHA512_BLOCK
+        .saturating_sub(1)
+        .saturating_sub(leb128(prs.len()).len() + prs.len())
+        .saturating_sub(leb128(DSI.len()).len() + DSI.len());
+    let zpad = vec![0u8; len_zpad];
+
+    let mut hasher = Sha512::new();
+    for part in [DSI, prs, &zpad, CHANNEL_ID, sid] {
+        hasher.update(leb128(part.len()));
+        hasher.update(part);
+    }
+    let uniform: [u8; 64] = hasher.finalize().into();
+    RistrettoPoint::from_uniform_bytes(&uniform)
+}
+
+fn leb128(mut n: usize) -> Vec<u8> {
+    let mut out = Vec::with_capacity(2);
+    loop {
+        let byte = (n & 0x7F) as u8;
+        n >>= 7;
+        if n == 0 {
+            out.push(byte);
+            return out;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SID: &[u8] = b"test-session-id";
+
+    fn run(
+        code_i: &str,
+        code_r: &str,
+    ) -> (
+        Result<PakeOutcome, PakeError>,
+        Result<PakeOutcome, PakeError>,
+    ) {
+        let id_i = [0x11; 32];
+        let id_r = [0x22; 32];
+        let init = PakeSession::start(PakeRole::Initiator, code_i, SID, &id_i).unwrap();
+        let resp = PakeSession::start(PakeRole::Responder, code_r, SID, &id_r).unwrap();
+        let (share_i, share_r) = (init.share(), resp.share());
+        let keys_i = init.finish(&share_r, &id_r).unwrap();
+        let keys_r = resp.finish(&share_i, &id_i).unwrap();
+        let (conf_i, conf_r) = (keys_i.confirmation(), keys_r.confirmation());
+        (keys_i.verify(&conf_r), keys_r.verify(&conf_i))
+    }
+
+    #[test]
+    fn same_code_agrees() {
+        let (i, r) = run("ABC-DEF", "abcdef");
+        let (i, r) = (i.unwrap(), r.unwrap());
+        assert_eq!(i.key, r.key);
+        assert_eq!(i.remote_identity_public_key, [0x22; 32]);
+        assert_eq!(r.remote_identity_public_key, [0x11; 32]);
+    }
+
+    #[test]
+    fn outcome_zeroize_clears_key() {
+        let mut outcome = run("ABCDEF", "ABCDEF").0.unwrap();
+        assert_ne!(outcome.key, [0u8; 32]);
+        outcome.zeroize();
+        assert_eq!(outcome.key, [0u8; 32]);
+    }
+
+    #[test]
+    fn different_code_fails_confirmation() {
+        let (i, r) = run("ABC-DEF", "ABC-DEG");
+        assert_eq!(i.err(), Some(PakeError::ConfirmFailed));
+        assert_eq!(r.err(), Some(PakeError::ConfirmFailed));
+    }
+
+    #[test]
+    fn substituted_identity_fails_confirmation() {
+        let id_i = [0x11; 32];
+        let id_r = [0x22; 32];
+        let init = PakeSession::start(PakeRole::Initiator, "ABCDEF", SID, &id_i).unwrap();
+        let resp = PakeSession::start(PakeRole::Responder, "ABCDEF", SID, &id_r).unwrap();
+        let (share_i, share_r) = (init.share(), resp.share());
+        // Initiator is told a different responder identity.
+        let keys_i = init.finish(&share_r, &[0x33; 32]).unwrap();
+        let keys_r = resp.finish(&share_i, &id_i).unwrap();
+        assert_eq!(
+            keys_r.verify(&keys_i.confirmation()).err(),
+            Some(PakeError::ConfirmFailed)
+        );
+    }
+
+    #[test]
+    fn invalid_share_rejected() {
+        let init = PakeSession::start(PakeRole::Initiator, "ABCDEF", SID, &[0x11; 32]).unwrap();
+        assert_eq!(
+            init.finish(&[0xFF; 32], &[0x22; 32])
+                .err()
+                .map(|e| e.code()),
+            Some("PAKE_FAILED")
+        );
+        let init = PakeSession::start(PakeRole::Initiator, "ABCDEF", SID, &[0x11; 32]).unwrap();
+        // Compressed identity point.
+        assert_eq!(
+            init.finish(&[0u8; 32], &[0x22; 32]).err(),
+            Some(PakeError::InvalidShare)
+        );
+    }
+
+    #[test]
+    fn invalid_code_rejected() {
+        for code in ["", "ABCDE", "ABCDEFG", "AB0DEF", "ABCDEF-GH"] {
+            assert_eq!(
+                PakeSession::start(PakeRole::Initiator, code, SID, &[0; 32]).err(),
+                Some(PakeError::InvalidCode),
+                "{code:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn generated_code_is_valid() {
+        for _ in 0..32 {
+            let code = generate_pake_code();
+            assert_eq!(code.len(), PAKE_CODE_LENGTH + 1);
+            assert_eq!(code.as_bytes()[PAKE_CODE_LENGTH / 2], b'-');
+            assert!(is_valid_pake_code(&code), "{code}");
+        }
+    }
+
+    #[test]
+    fn error_codes_are_canonical() {
+        use crate::errors::is_valid_wire_error_code;
+        for err in [
+            PakeError::InvalidCode,
+            PakeError::InvalidShare,
+            PakeError::ConfirmFailed,
+        ] {
+            assert!(is_valid_wire_error_code(err.code()));
+        }
+    }
+
+    #[test]
+    fn capability_is_namespaced() {
+        use crate::constants::PAKE_CAPABILITY;
+        assert!(PAKE_CAPABILITY.starts_with(crate::constants::CAPABILITY_NAMESPACE));
+    }
+
+    #[test]
+    fn leb128_encoding() {
+        assert_eq!(leb128(0), vec![0x00]);
+        assert_eq!(leb128(127), vec![0x7F]);
+        assert_eq!(leb128(128), vec![0x80, 0x01]);
+        assert_eq!(leb128(300), vec![0xAC, 0x02]);
+    }
+}
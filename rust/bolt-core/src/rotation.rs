@@ -0,0 +1,464 @@
+//! Identity key rotation — signed continuity statement.
+//!
+//! Replacing an identity key normally looks like a TOFU violation to
+//! every peer that pinned the old key (`KEY_MISMATCH`, fail closed). A
+//! rotation statement lets the old key endorse the new one so a pin
+//! store can accept the transition instead.
+//!
+//! The statement is signed by BOTH keys: the old key endorses the new
+//! one, and the new key proves possession (so nobody can rotate a victim
+//! onto a key they don't hold). Identity keys are X25519, so signatures
+//! use XEdDSA (Signal spec) — no separate signing key is needed.
+//!
+//! ## Wire format (v1, fixed length)
+//! ```text
+//! version(1) || old_pk(32) || new_pk(32) || issued_at_ms(8 BE)
+//!   || expires_at_ms(8 BE) || old_sig(64) || new_sig(64)
+//! ```
+//! Carried base64-encoded in the encrypted `identity-rotation` message
+//! after HELLO, under capability `bolt.identity-rotation-v1`.
+//!
+//! ## Signed message
+//! ```text
+//! "bolt-identity-rotation-v1" || old_pk || new_pk || issued_at_ms || expires_at_ms
+//! ```
+//!
+//! ## Non-goals
+//! - No pin storage (transport concern). Callers replace the pin with
+//!   the key returned by [`verify_rotation`].
+
+use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
+use curve25519_dalek::montgomery::MontgomeryPoint;
+use curve25519_dalek::scalar::{clamp_integer, Scalar};
+use rand_core::{OsRng, RngCore};
+use sha2::{Digest, Sha512};
+
+use crate::constants::{
+    PUBLIC_KEY_LENGTH, ROTATION_MAX_CLOCK_SKEW_MS, ROTATION_MAX_VALIDITY_MS,
+    ROTATION_STATEMENT_LENGTH, ROTATION_VERSION,
+};
+use crate::identity::IdentityKeyPair;
+
+/// Domain separation label for the signed rotation message.
+const ROTATION_LABEL: &[u8] = b"bolt-identity-rotation-v1";
+
+/// XEdDSA signature length.
+const SIGNATURE_LENGTH: usize = 64;
+
+/// Rotation statement failures.
+///
+/// Wire codes align with the canonical `WIRE_ERROR_CODES` registry in
+/// [`crate::errors`]. On either error the caller MUST keep the old pin
+/// and treat a subsequent new key as `KEY_MISMATCH`.
+#[derive(Debug, PartialEq, Eq)]
+pub enum RotationError {
+    /// Malformed, badly signed, or not issued by the pinned key.
+    Invalid(String),
+    /// Outside its validity window.
+    Expired,
+}
+
+impl RotationError {
+    /// Wire error code string aligned with the canonical registry.
+    pub fn code(&self) -> &'static str {
+        match self {
+            RotationError::Invalid(_) => "ROTATION_INVALID",
+            RotationError::Expired => "ROTATION_EXPIRED",
+        }
+    }
+}
+
+impl std::fmt::Display for RotationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RotationError::Invalid(reason) => write!(f, "Rotation statement invalid: {reason}"),
+            RotationError::Expired => write!(f, "Rotation statement expired"),
+        }
+    }
+}
+
+impl std::error::Error for RotationError {}
+
+/// Signed statement that `old_identity` is replaced by `new_identity`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RotationStatement {
+    /// Identity public key being retired.
+    pub old_identity: [u8; PUBLIC_KEY_LENGTH],
+    /// Replacement identity public key.
+    pub new_identity: [u8; PUBLIC_KEY_LENGTH],
+    /// Issue time (Unix milliseconds).
+    pub issued_at_ms: u64,
+    /// Expiry time (Unix milliseconds). Peers offline past this re-pin via TOFU.
+    pub expires_at_ms: u64,
+    /// XEdDSA signature by the old identity key.
+    pub old_signature: [u8; SIGNATURE_LENGTH],
+    /// XEdDSA signature by the new identity key.
+    pub new_signature: [u8; SIGNATURE_LENGTH],
+}
+
+impl RotationStatement {
+    /// Sign a rotation from `old` to `new`, valid for `validity_ms` from `issued_at_ms`.
+    ///
+    /// # Errors
+    /// Returns `RotationError::Invalid` if the keys are identical or
+    /// `validity_ms` is zero or exceeds `ROTATION_MAX_VALIDITY_MS`.
+    pub fn sign(
+        old: &IdentityKeyPair,
+        new: &IdentityKeyPair,
+        issued_at_ms: u64,
+        validity_ms: u64,
+    ) -> Result<Self, RotationError> {
+        let mut nonces = [0u8; 2 * SIGNATURE_LENGTH];
+        OsRng.fill_bytes(&mut nonces);
+        let (old_nonce, new_nonce) = nonces.split_at(SIGNATURE_LENGTH);
+        Self::sign_inner(
+            old,
+            new,
+            issued_at_ms,
+            validity_ms,
+            old_nonce.try_into().unwrap(),
+            new_nonce.try_into().unwrap(),
+        )
+    }
+
+    /// Deterministic signing for golden vectors (test use only).
+    #[cfg(feature = "vectors")]
+    pub fn sign_with_nonces(
+        old: &IdentityKeyPair,
+        new: &IdentityKeyPair,
+        issued_at_ms: u64,
+        validity_ms: u64,
+        old_nonce: &[u8; 64],
+        new_nonce: &[u8; 64],
+    ) -> Result<Self, RotationError> {
+        Self::sign_inner(old, new, issued_at_ms, validity_ms, old_nonce, new_nonce)
+    }
+
+    fn sign_inner(
+        old: &IdentityKeyPair,
+        new: &IdentityKeyPair,
+        issued_at_ms: u64,
+        validity_ms: u64,
+        old_nonce: &[u8; 64],
+        new_nonce: &[u8; 64],
+    ) -> Result<Self, RotationError> {
+        if old.public_key == new.public_key {
+            return Err(RotationError::Invalid("old and new keys identical".into()));
+        }
+        if validity_ms == 0 || validity_ms > ROTATION_MAX_VALIDITY_MS {
+            return Err(RotationError::Invalid(format!(
+                "validity {validity_ms}ms outside (0, {ROTATION_MAX_VALIDITY_MS}]"
+            )));
+        }
+        let expires_at_ms = issued_at_ms
+            .checked_add(validity_ms)
+            .ok_or_else(|| RotationError::Invalid("expiry overflows".into()))?;
+        let msg = signed_message(
+            &old.public_key,
+            &new.public_key,
+            issued_at_ms,
+            expires_at_ms,
+        );
+        Ok(Self {
+            old_identity: old.public_key,
+            new_identity: new.public_key,
+            issued_at_ms,
+            expires_at_ms,
+            old_signature: xeddsa_sign(&old.secret_key, &msg, old_nonce),
+            new_signature: xeddsa_sign(&new.secret_key, &msg, new_nonce),
+        })
+    }
+
+    /// Encode to the fixed-length v1 wire format.
+    pub fn to_bytes(&self) -> [u8; ROTATION_STATEMENT_LENGTH] {
+        let mut out = [0u8; ROTATION_STATEMENT_LENGTH];
+        out[0] = ROTATION_VERSION;
+        out[1..33].copy_from_slice(&self.old_identity);
+        out[33..65].copy_from_slice(&self.new_identity);
+        out[65..73].copy_from_slice(&self.issued_at_ms.to_be_bytes());
+        out[73..81].copy_from_slice(&self.expires_at_ms.to_be_bytes());
+        out[81..145].copy_from_slice(&self.old_signature);
+        out[145..209].copy_from_slice(&self.new_signature);
+        out
+    }
+
+    /// Decode from the v1 wire format. Does NOT verify signatures.
+    ///
+    /// # Errors
+    /// Returns `RotationError::Invalid` on wrong length or unknown version.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, RotationError> {
+        if bytes.len() != ROTATION_STATEMENT_LENGTH {
+            return Err(RotationError::Invalid(format!(
+                "expected {ROTATION_STATEMENT_LENGTH} bytes, got {}",
+                bytes.len()
+            )));
+        }
+        if bytes[0] != ROTATION_VERSION {
+            return Err(RotationError::Invalid(format!(
+                "unsupported version {}",
+                bytes[0]
+            )));
+        }
+        Ok(Self {
+            old_identity: bytes[1..33].try_into().unwrap(),
+            new_identity: bytes[33..65].try_into().unwrap(),
+            issued_at_ms: u64::from_be_bytes(bytes[65..73].try_into().unwrap()),
+            expires_at_ms: u64::from_be_bytes(bytes[73..81].try_into().unwrap()),
+            old_signature: bytes[81..145].try_into().unwrap(),
+            new_signature: bytes[145..209].try_into().unwrap(),
+        })
+    }
+}
+
+/// Verify a rotation statement against the currently pinned key.
+///
+/// Returns the new identity key to pin on success. Checks, in order:
+/// issuer matches `pinned`, keys differ, window is well-formed and no
+/// longer than `ROTATION_MAX_VALIDITY_MS`, both signatures verify, and
+/// `now_ms` is inside the window (issue time tolerates
+/// `ROTATION_MAX_CLOCK_SKEW_MS` of clock skew).
+///
+/// # Errors
+/// Returns `RotationError::Expired` if the window has passed, otherwise
+/// `RotationError::Invalid` for any other failure.
+pub fn verify_rotation(
+    statement: &RotationStatement,
+    pinned: &[u8; PUBLIC_KEY_LENGTH],
+    now_ms: u64,
+) -> Result<[u8; PUBLIC_KEY_LENGTH], RotationError> {
+    if statement.old_identity != *pinned {
+        return Err(RotationError::Invalid(
+            "issuer is not the pinned key".into(),
+        ));
+    }
+    if statement.old_identity == statement.new_identity {
+        return Err(RotationError::Invalid("old and new keys identical".into()));
+    }
+    if statement.expires_at_ms <= statement.issued_at_ms
+        || statement.expires_at_ms - statement.issued_at_ms > ROTATION_MAX_VALIDITY_MS
+    {
+        return Err(RotationError::Invalid("validity window malformed".into()));
+    }
+
+    let msg = signed_message(
+        &statement.old_identity,
+        &statement.new_identity,
+        statement.issued_at_ms,
+        statement.expires_at_ms,
+    );
+    if !xeddsa_verify(&statement.old_identity, &msg, &statement.old_signature) {
+        return Err(RotationError::Invalid("old key signature".into()));
+    }
+    if !xeddsa_verify(&statement.new_identity, &msg, &statement.new_signature) {
+        return Err(RotationError::Invalid("new key signature".into()));
+    }
+
+    if now_ms.saturating_add(ROTATION_MAX_CLOCK_SKEW_MS) < statement.issued_at_ms {
+        return Err(RotationError::Invalid("issued in the future".into()));
+    }
+    if now_ms >= statement.expires_at_ms {
+        return Err(RotationError::Expired);
+    }
+    Ok(statement.new_identity)
+}
+
+fn signed_message(
+    old: &[u8; PUBLIC_KEY_LENGTH],
+    new: &[u8; PUBLIC_KEY_LENGTH],
+    issued_at_ms: u64,
+    expires_at_ms: u64,
+) -> Vec<u8> {
+    let mut msg = Vec::with_capacity(ROTATION_LABEL.len() + 2 * PUBLIC_KEY_LENGTH + 16);
+    msg.extend_from_slice(ROTATION_LABEL);
+    msg.extend_from_slice(old);
+    msg.extend_from_slice(new);
+    msg.extend_from_slice(&issued_at_ms.to_be_bytes());
+    msg.extend_from_slice(&expires_at_ms.to_be_bytes());
+    msg
+}
+
+// ── XEdDSA (Signal XEdDSA spec §2.3, curve25519) ────────────────────
+
+fn sha512_scalar(parts: &[&[u8]]) -> Scalar {
+    let mut hasher = Sha512::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    Scalar::from_bytes_mod_order_wide(&hasher.finalize().into())
+}
+
+fn xeddsa_sign(secret_key: &[u8; 32], msg: &[u8], nonce: &[u8; 64]) -> [u8; SIGNATURE_LENGTH] {
+    // calculate_key_pair: force the Edwards public key's sign bit to zero.
+    let k = Scalar::from_bytes_mod_order(clamp_integer(*secret_key));
+    let mut a_bytes = EdwardsPoint::mul_base(&k).compress().to_bytes();
+    let a = if a_bytes[31] & 0x80 != 0 { -k } else { k };
+    a_bytes[31] &= 0x7F;
+
+    // hash_1 prefix: 0xFE || 0xFF * 31.
+    let mut prefix = [0xFFu8; 32];
+    prefix[0] = 0xFE;
+    let r = sha512_scalar(&[&prefix, a.as_bytes(), msg, nonce]);
+    let r_bytes = EdwardsPoint::mul_base(&r).compress().to_bytes();
+    let h = sha512_scalar(&[&r_bytes, &a_bytes, msg]);
+    let s = r + h * a;
+
+    let mut sig = [0u8; SIGNATURE_LENGTH];
+    sig[..32].copy_from_slice(&r_bytes);
+    sig[32..].copy_from_slice(s.as_bytes());
+    sig
+}
+
+fn xeddsa_verify(public_key: &[u8; 32], msg: &[u8], sig: &[u8; SIGNATURE_LENGTH]) -> bool {
+    let Some(a) = MontgomeryPoint(*public_key).to_edwards(0) else {
+        return false;
+    };
+    let r_bytes: [u8; 32] = sig[..32].try_into().unwrap();
+    let Some(s) =
+        Option::<Scalar>::from(Scalar::from_canonical_bytes(sig[32..].try_into().unwrap()))
+    else {
+        return false;
+    };
+    if CompressedEdwardsY(r_bytes).decompress().is_none() {
+        return false;
+    }
+    let a_bytes = a.compress().to_bytes();
+    let h = sha512_scalar(&[&r_bytes, &a_bytes, msg]);
+    let check = EdwardsPoint::vartime_double_scalar_mul_basepoint(&h, &(-a), &s);
+    check.compress().to_bytes() == r_bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::identity::generate_identity_keypair;
+
+    const NOW: u64 = 1_700_000_000_000;
+    const DAY_MS: u64 = 86_400_000;
+
+    #[test]
+    fn sign_verify_roundtrip() {
+        let old = generate_identity_keypair();
+        let new = generate_identity_keypair();
+        let stmt = RotationStatement::sign(&old, &new, NOW, DAY_MS).unwrap();
+        assert_eq!(
+            verify_rotation(&stmt, &old.public_key, NOW + 1),
+            Ok(new.public_key)
+        );
+    }
+
+    #[test]
+    fn bytes_roundtrip() {
+        let old = generate_identity_keypair();
+        let new = generate_identity_keypair();
+        let stmt = RotationStatement::sign(&old, &new, NOW, DAY_MS).unwrap();
+        let bytes = stmt.to_bytes();
+        assert_eq!(bytes.len(), ROTATION_STATEMENT_LENGTH);
+        assert_eq!(RotationStatement::from_bytes(&bytes).unwrap(), stmt);
+    }
+
+    #[test]
+    fn from_bytes_rejects_bad_length_and_version() {
+        assert_eq!(
+            RotationStatement::from_bytes(&[1u8; 10])
+                .unwrap_err()
+                .code(),
+            "ROTATION_INVALID"
+        );
+        let mut bytes = [0u8; ROTATION_STATEMENT_LENGTH];
+        bytes[0] = 2;
+        assert!(RotationStatement::from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn wrong_pin_rejected() {
+        let old = generate_identity_keypair();
+        let new = generate_identity_keypair();
+        let stmt = RotationStatement::sign(&old, &new, NOW, DAY_MS).unwrap();
+        let err = verify_rotation(&stmt, &[0x42; 32], NOW).unwrap_err();
+        assert_eq!(err.code(), "ROTATION_INVALID");
+    }
+
+    #[test]
+    fn expired_rejected() {
+        let old = generate_identity_keypair();
+        let new = generate_identity_keypair();
+        let stmt = RotationStatement::sign(&old, &new, NOW, DAY_MS).unwrap();
+        assert_eq!(
+            verify_rotation(&stmt, &old.public_key, NOW + DAY_MS),
+            Err(RotationError::Expired)
+        );
+    }
+
+    #[test]
+    fn future_issue_time_tolerates_skew() {
+        let old = generate_identity_keypair();
+        let new = generate_identity_keypair();
+        let stmt = RotationStatement::sign(&old, &new, NOW, DAY_MS).unwrap();
+        assert!(verify_rotation(&stmt, &old.public_key, NOW - ROTATION_MAX_CLOCK_SKEW_MS).is_ok());
+        assert_eq!(
+            verify_rotation(&stmt, &old.public_key, NOW - ROTATION_MAX_CLOCK_SKEW_MS - 1)
+                .unwrap_err()
+                .code(),
+            "ROTATION_INVALID"
+        );
+    }
+
+    #[test]
+    fn tampered_fields_rejected() {
+        let old = generate_identity_keypair();
+        let new = generate_identity_keypair();
+        let stmt = RotationStatement::sign(&old, &new, NOW, DAY_MS).unwrap();
+
+        let mut extended = stmt.clone();
+        extended.expires_at_ms += 1;
+        assert!(verify_rotation(&extended, &old.public_key, NOW).is_err());
+
+        let mut swapped = stmt.clone();
+        swapped.new_identity = generate_identity_keypair().public_key;
+        assert!(verify_rotation(&swapped, &old.public_key, NOW).is_err());
+
+        let mut bad_sig = stmt;
+        bad_sig.old_signature[0] ^= 0x01;
+        assert!(verify_rotation(&bad_sig, &old.public_key, NOW).is_err());
+    }
+
+    #[test]
+    fn missing_new_key_possession_rejected() {
+        // Attacker holds old key but not the new one: reuses old signature.
+        let old = generate_identity_keypair();
+        let new = generate_identity_keypair();
+        let mut stmt = RotationStatement::sign(&old, &new, NOW, DAY_MS).unwrap();
+        stmt.new_signature = stmt.old_signature;
+        assert!(verify_rotation(&stmt, &old.public_key, NOW).is_err());
+    }
+
+    #[test]
+    fn sign_rejects_bad_parameters() {
+        let old = generate_identity_keypair();
+        let new = generate_identity_keypair();
+        assert!(RotationStatement::sign(&old, &old, NOW, DAY_MS).is_err());
+        assert!(RotationStatement::sign(&old, &new, NOW, 0).is_err());
+        assert!(RotationStatement::sign(&old, &new, NOW, ROTATION_MAX_VALIDITY_MS + 1).is_err());
+        assert!(RotationStatement::sign(&old, &new, u64::MAX, DAY_MS).is_err());
+    }
+
+    #[test]
+    fn xeddsa_public_key_sign_bit_both_ways() {
+        // Enough keys to hit both Edwards sign-bit cases.
+        for _ in 0..16 {
+            let kp = generate_identity_keypair();
+            let sig = xeddsa_sign(&kp.secret_key, b"msg", &[7u8; 64]);
+            assert!(xeddsa_verify(&kp.public_key, b"msg", &sig));
+            assert!(!xeddsa_verify(&kp.public_key, b"msh", &sig));
+        }
+    }
+
+    #[test]
+    fn error_codes_are_canonical() {
+        use crate::errors::is_valid_wire_error_code;
+        assert!(is_valid_wire_error_code(RotationError::Expired.code()));
+        assert!(is_valid_wire_error_code(
+            RotationError::Invalid(String::new()).code()
+        ));
+    }
+}
@@ -10,6 +10,19 @@
 //! Display   = first 6 hex chars, uppercase
 //! ```
 //!
+//! ## Alternative encodings
+//! [`compute_sas_encoded`] renders the same `SAS_input` hash in forms that
+//! survive being read aloud. Every encoding consumes the hash from byte 0:
+//!
+//! | Encoding | Bits | Rendering |
+//! |----------|------|-----------|
+//! | [`SasEncoding::Hex`] | 24 | `compute_sas` output (6 hex chars) |
+//! | [`SasEncoding::Words`] | 32 | 4 PGP words; even list for bytes 0/2, odd list for 1/3 |
+//! | [`SasEncoding::Emoji`] | 42 | 7 emoji, one per 6-bit group (big-endian) |
+//! | [`SasEncoding::Decimal`] | 39 | 3 groups of 13 bits, each + 1000, as 4 digits |
+//!
+//! Multi-token renderings are joined with a single ASCII space.
+//!
 //! ## Parity
 //! - Each SAS golden vector produces expected 6-char string.
 //! - Commutative: `compute_sas(A, B, ...) == compute_sas(B, A, ...)`.
@@ -19,15 +32,18 @@
 //! TS `computeSas` is async (Web Crypto digest is async). Rust SHA-256
 //! via `sha2` is synchronous. The Rust function is sync.
 
-use crate::constants::{PUBLIC_KEY_LENGTH, SAS_LENGTH};
+use crate::constants::{
+    PUBLIC_KEY_LENGTH, SAS_DECIMAL_GROUPS, SAS_EMOJI_COUNT, SAS_LENGTH, SAS_WORD_COUNT,
+};
 use crate::encoding;
 use crate::hash;
+use crate::sas_tables::{PGP_EVEN_WORDS, PGP_ODD_WORDS, SAS_EMOJI};
 
 /// Lexicographically sort two 32-byte values and concatenate them.
 ///
 /// Matches TS `sort32()` exactly: byte-by-byte comparison, smaller first.
 /// If identical, concatenate as-is (a || b).
-fn sort32(a: &[u8; 32], b: &[u8; 32]) -> [u8; 64] {
+pub(crate) fn sort32(a: &[u8; 32], b: &[u8; 32]) -> [u8; 64] {
     let mut result = [0u8; 64];
     for i in 0..32 {
         if a[i] != b[i] {
@@ -65,6 +81,100 @@ pub fn compute_sas(
     ephemeral_a: &[u8; PUBLIC_KEY_LENGTH],
     ephemeral_b: &[u8; PUBLIC_KEY_LENGTH],
 ) -> String {
+    let hash = sas_input_hash(identity_a, identity_b, ephemeral_a, ephemeral_b);
+    render_hex(&hash)
+}
+
+/// SAS display encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SasEncoding {
+    /// 6 uppercase hex characters (24 bits). Same as [`compute_sas`].
+    Hex,
+    /// 4 words from the PGP word list (32 bits).
+    Words,
+    /// 7 emoji from the 64-entry SAS emoji set (42 bits).
+    Emoji,
+    /// 3 groups of 4 decimal digits (39 bits).
+    Decimal,
+}
+
+impl SasEncoding {
+    /// All encodings, in canonical order.
+    pub const ALL: [SasEncoding; 4] = [
+        SasEncoding::Hex,
+        SasEncoding::Words,
+        SasEncoding::Emoji,
+        SasEncoding::Decimal,
+    ];
+
+    /// Stable lowercase name, used across the WASM/TS boundary.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SasEncoding::Hex => "hex",
+            SasEncoding::Words => "words",
+            SasEncoding::Emoji => "emoji",
+            SasEncoding::Decimal => "decimal",
+        }
+    }
+
+    /// Parse a name produced by [`SasEncoding::as_str`].
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|e| e.as_str() == name)
+    }
+}
+
+/// Compute the SAS and render it in the requested encoding.
+///
+/// All encodings derive from the same `SAS_input` hash as [`compute_sas`],
+/// so both peers agree regardless of which rendering each UI shows.
+/// `SasEncoding::Hex` returns exactly the `compute_sas` output.
+///
+/// # Parity
+/// TS equivalent: `computeSas(identityA, identityB, ephemeralA, ephemeralB, encoding)`.
+pub fn compute_sas_encoded(
+    identity_a: &[u8; PUBLIC_KEY_LENGTH],
+    identity_b: &[u8; PUBLIC_KEY_LENGTH],
+    ephemeral_a: &[u8; PUBLIC_KEY_LENGTH],
+    ephemeral_b: &[u8; PUBLIC_KEY_LENGTH],
+    encoding: SasEncoding,
+) -> String {
+    let hash = sas_input_hash(identity_a, identity_b, ephemeral_a, ephemeral_b);
+    match encoding {
+        SasEncoding::Hex => render_hex(&hash),
+        SasEncoding::Words => render_words(&hash),
+        SasEncoding::Emoji => sas_emoji_indices(&hash)
+            .iter()
+            .map(|&i| SAS_EMOJI[i as usize].0)
+            .collect::<Vec<_>>()
+            .join(" "),
+        SasEncoding::Decimal => render_decimal(&hash),
+    }
+}
+
+/// English names for the emoji rendering, in display order.
+///
+/// UIs show these as captions under each emoji so that users can read
+/// the SAS aloud.
+pub fn compute_sas_emoji_names(
+    identity_a: &[u8; PUBLIC_KEY_LENGTH],
+    identity_b: &[u8; PUBLIC_KEY_LENGTH],
+    ephemeral_a: &[u8; PUBLIC_KEY_LENGTH],
+    ephemeral_b: &[u8; PUBLIC_KEY_LENGTH],
+) -> Vec<&'static str> {
+    let hash = sas_input_hash(identity_a, identity_b, ephemeral_a, ephemeral_b);
+    sas_emoji_indices(&hash)
+        .iter()
+        .map(|&i| SAS_EMOJI[i as usize].1)
+        .collect()
+}
+
+/// `SHA-256(sort32(id_a, id_b) || sort32(eph_a, eph_b))`.
+fn sas_input_hash(
+    identity_a: &[u8; PUBLIC_KEY_LENGTH],
+    identity_b: &[u8; PUBLIC_KEY_LENGTH],
+    ephemeral_a: &[u8; PUBLIC_KEY_LENGTH],
+    ephemeral_b: &[u8; PUBLIC_KEY_LENGTH],
+) -> [u8; 32] {
     let sorted_identity = sort32(identity_a, identity_b);
     let sorted_ephemeral = sort32(ephemeral_a, ephemeral_b);
 
@@ -72,11 +182,50 @@ pub fn compute_sas(
     combined[..64].copy_from_slice(&sorted_identity);
     combined[64..].copy_from_slice(&sorted_ephemeral);
 
-    let hash = hash::sha256(&combined);
-    let hex = encoding::to_hex(&hash);
+    hash::sha256(&combined)
+}
+
+fn render_hex(hash: &[u8; 32]) -> String {
+    let hex = encoding::to_hex(hash);
     hex[..SAS_LENGTH].to_uppercase()
 }
 
+fn render_words(hash: &[u8; 32]) -> String {
+    hash[..SAS_WORD_COUNT]
+        .iter()
+        .enumerate()
+        .map(|(i, &b)| {
+            if i % 2 == 0 {
+                PGP_EVEN_WORDS[b as usize]
+            } else {
+                PGP_ODD_WORDS[b as usize]
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Split the first 42 bits of the hash into 7 big-endian 6-bit indices.
+fn sas_emoji_indices(hash: &[u8; 32]) -> [u8; SAS_EMOJI_COUNT] {
+    let bits = u64::from_be_bytes([hash[0], hash[1], hash[2], hash[3], hash[4], hash[5], 0, 0]);
+    let mut out = [0u8; SAS_EMOJI_COUNT];
+    for (i, slot) in out.iter_mut().enumerate() {
+        *slot = ((bits >> (58 - 6 * i)) & 0x3F) as u8;
+    }
+    out
+}
+
+fn render_decimal(hash: &[u8; 32]) -> String {
+    let bits = u64::from_be_bytes([hash[0], hash[1], hash[2], hash[3], hash[4], 0, 0, 0]);
+    (0..SAS_DECIMAL_GROUPS)
+        .map(|i| {
+            let group = (bits >> (51 - 13 * i)) & 0x1FFF;
+            format!("{}", group + 1000)
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,6 +318,106 @@ mod tests {
         assert_eq!(sas, expected_sas);
     }
 
+    fn sparse_keys() -> ([u8; 32], [u8; 32], [u8; 32], [u8; 32]) {
+        let mut a = [0u8; 32];
+        let mut b = [0u8; 32];
+        let mut c = [0u8; 32];
+        let mut d = [0u8; 32];
+        a[0] = 0x01;
+        b[0] = 0x02;
+        c[0] = 0x10;
+        d[0] = 0x20;
+        (a, b, c, d)
+    }
+
+    #[test]
+    fn encoded_hex_matches_compute_sas() {
+        let (a, b, c, d) = sparse_keys();
+        assert_eq!(
+            compute_sas_encoded(&a, &b, &c, &d, SasEncoding::Hex),
+            compute_sas(&a, &b, &c, &d)
+        );
+    }
+
+    #[test]
+    fn encoded_forms_are_commutative() {
+        let (a, b, c, d) = sparse_keys();
+        for enc in SasEncoding::ALL {
+            assert_eq!(
+                compute_sas_encoded(&a, &b, &c, &d, enc),
+                compute_sas_encoded(&b, &a, &d, &c, enc),
+                "{} not commutative",
+                enc.as_str()
+            );
+        }
+    }
+
+    #[test]
+    fn encoding_names_roundtrip() {
+        for enc in SasEncoding::ALL {
+            assert_eq!(SasEncoding::from_name(enc.as_str()), Some(enc));
+        }
+        assert_eq!(SasEncoding::from_name("HEX"), None);
+        assert_eq!(SasEncoding::from_name(""), None);
+    }
+
+    #[test]
+    fn words_alternate_even_and_odd_lists() {
+        let hash = [0u8; 32];
+        assert_eq!(
+            render_words(&hash),
+            "aardvark adroitness aardvark adroitness"
+        );
+        let mut hash = [0u8; 32];
+        hash[..4].copy_from_slice(&[0xFF, 0xFF, 0x01, 0x0A]);
+        assert_eq!(render_words(&hash), "Zulu Yucatan absurd Apollo");
+    }
+
+    #[test]
+    fn emoji_indices_big_endian_6_bit_groups() {
+        // 0b000001_000010_000011_000100_000101_000110_000111 || padding
+        let mut hash = [0u8; 32];
+        hash[..6].copy_from_slice(&[0x04, 0x20, 0xC4, 0x14, 0x61, 0xC0]);
+        assert_eq!(sas_emoji_indices(&hash), [1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(sas_emoji_indices(&[0xFF; 32]), [63; 7]);
+    }
+
+    #[test]
+    fn decimal_groups_in_range() {
+        assert_eq!(render_decimal(&[0u8; 32]), "1000 1000 1000");
+        assert_eq!(render_decimal(&[0xFF; 32]), "9191 9191 9191");
+        let (a, b, c, d) = sparse_keys();
+        let dec = compute_sas_encoded(&a, &b, &c, &d, SasEncoding::Decimal);
+        for group in dec.split(' ') {
+            assert_eq!(group.len(), 4);
+            let n: u32 = group.parse().unwrap();
+            assert!((1000..=9191).contains(&n));
+        }
+    }
+
+    #[test]
+    fn emoji_names_align_with_emoji() {
+        let (a, b, c, d) = sparse_keys();
+        let emoji = compute_sas_encoded(&a, &b, &c, &d, SasEncoding::Emoji);
+        let names = compute_sas_emoji_names(&a, &b, &c, &d);
+        assert_eq!(names.len(), SAS_EMOJI_COUNT);
+        for (sym, name) in emoji.split(' ').zip(&names) {
+            assert!(SAS_EMOJI.contains(&(sym, *name)));
+        }
+    }
+
+    #[test]
+    fn tables_have_unique_entries() {
+        use std::collections::HashSet;
+        let even: HashSet<_> = PGP_EVEN_WORDS.iter().collect();
+        let odd: HashSet<_> = PGP_ODD_WORDS.iter().collect();
+        let emoji: HashSet<_> = SAS_EMOJI.iter().map(|(e, _)| e).collect();
+        assert_eq!(even.len(), 256);
+        assert_eq!(odd.len(), 256);
+        assert!(even.is_disjoint(&odd));
+        assert_eq!(emoji.len(), 64);
+    }
+
     #[test]
     fn sort32_smaller_first() {
         let a = [0x01u8; 32];
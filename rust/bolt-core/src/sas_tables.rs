@@ -0,0 +1,595 @@
+//! SAS rendering tables — word lists and emoji set.
+//!
+//! Index order is normative: changing any entry changes SAS output and
+//! breaks cross-language parity with `sas.vectors.json`.
+
+/// PGP word list, even positions (two-syllable words). Indexed by byte value.
+pub(crate) const PGP_EVEN_WORDS: [&str; 256] = [
+    "aardvark",
+    "absurd",
+    "accrue",
+    "acme",
+    "adrift",
+    "adult",
+    "afflict",
+    "ahead",
+    "aimless",
+    "Algol",
+    "allow",
+    "alone",
+    "ammo",
+    "ancient",
+    "apple",
+    "artist",
+    "assume",
+    "Athens",
+    "atlas",
+    "Aztec",
+    "baboon",
+    "backfield",
+    "backward",
+    "banjo",
+    "beaming",
+    "bedlamp",
+    "beehive",
+    "beeswax",
+    "befriend",
+    "Belfast",
+    "berserk",
+    "billiard",
+    "bison",
+    "blackjack",
+    "blockade",
+    "blowtorch",
+    "bluebird",
+    "bombast",
+    "bookshelf",
+    "brackish",
+    "breadline",
+    "breakup",
+    "brickyard",
+    "briefcase",
+    "Burbank",
+    "button",
+    "buzzard",
+    "cement",
+    "chairlift",
+    "chatter",
+    "checkup",
+    "chisel",
+    "choking",
+    "chopper",
+    "Christmas",
+    "clamshell",
+    "classic",
+    "classroom",
+    "cleanup",
+    "clockwork",
+    "cobra",
+    "commence",
+    "concert",
+    "cowbell",
+    "crackdown",
+    "cranky",
+    "crowfoot",
+    "crucial",
+    "crumpled",
+    "crusade",
+    "cubic",
+    "dashboard",
+    "deadbolt",
+    "deckhand",
+    "dogsled",
+    "dragnet",
+    "drainage",
+    "dreadful",
+    "drifter",
+    "dropper",
+    "drumbeat",
+    "drunken",
+    "Dupont",
+    "dwelling",
+    "eating",
+    "edict",
+    "egghead",
+    "eightball",
+    "endorse",
+    "endow",
+    "enlist",
+    "erase",
+    "escape",
+    "exceed",
+    "eyeglass",
+    "eyetooth",
+    "facial",
+    "fallout",
+    "flagpole",
+    "flatfoot",
+    "flytrap",
+    "fracture",
+    "framework",
+    "freedom",
+    "frighten",
+    "gazelle",
+    "Geiger",
+    "glitter",
+    "glucose",
+    "goggles",
+    "goldfish",
+    "gremlin",
+    "guidance",
+    "hamlet",
+    "highchair",
+    "hockey",
+    "indoors",
+    "indulge",
+    "inverse",
+    "involve",
+    "island",
+    "jawbone",
+    "keyboard",
+    "kickoff",
+    "kiwi",
+    "klaxon",
+    "locale",
+    "lockup",
+    "merit",
+    "minnow",
+    "miser",
+    "Mohawk",
+    "mural",
+    "music",
+    "necklace",
+    "Neptune",
+    "newborn",
+    "nightbird",
+    "Oakland",
+    "obtuse",
+    "offload",
+    "optic",
+    "orca",
+    "payday",
+    "peachy",
+    "pheasant",
+    "physique",
+    "playhouse",
+    "Pluto",
+    "preclude",
+    "prefer",
+    "preshrunk",
+    "printer",
+    "prowler",
+    "pupil",
+    "puppy",
+    "python",
+    "quadrant",
+    "quiver",
+    "quota",
+    "ragtime",
+    "ratchet",
+    "rebirth",
+    "reform",
+    "regain",
+    "reindeer",
+    "rematch",
+    "repay",
+    "retouch",
+    "revenge",
+    "reward",
+    "rhythm",
+    "ribcage",
+    "ringbolt",
+    "robust",
+    "rocker",
+    "ruffled",
+    "sailboat",
+    "sawdust",
+    "scallion",
+    "scenic",
+    "scorecard",
+    "Scotland",
+    "seabird",
+    "select",
+    "sentence",
+    "shadow",
+    "shamrock",
+    "showgirl",
+    "skullcap",
+    "skydive",
+    "slingshot",
+    "slowdown",
+    "snapline",
+    "snapshot",
+    "snowcap",
+    "snowslide",
+    "solo",
+    "southward",
+    "soybean",
+    "spaniel",
+    "spearhead",
+    "spellbind",
+    "spheroid",
+    "spigot",
+    "spindle",
+    "spyglass",
+    "stagehand",
+    "stagnate",
+    "stairway",
+    "standard",
+    "stapler",
+    "steamship",
+    "sterling",
+    "stockman",
+    "stopwatch",
+    "stormy",
+    "sugar",
+    "surmount",
+    "suspense",
+    "sweatband",
+    "swelter",
+    "tactics",
+    "talon",
+    "tapeworm",
+    "tempest",
+    "tiger",
+    "tissue",
+    "tonic",
+    "topmost",
+    "tracker",
+    "transit",
+    "trauma",
+    "treadmill",
+    "Trojan",
+    "trouble",
+    "tumor",
+    "tunnel",
+    "tycoon",
+    "uncut",
+    "unearth",
+    "unwind",
+    "uproot",
+    "upset",
+    "upshot",
+    "vapor",
+    "village",
+    "virus",
+    "Vulcan",
+    "waffle",
+    "wallet",
+    "watchword",
+    "wayside",
+    "willow",
+    "woodlark",
+    "Zulu",
+];
+
+/// PGP word list, odd positions (three-syllable words). Indexed by byte value.
+pub(crate) const PGP_ODD_WORDS: [&str; 256] = [
+    "adroitness",
+    "adviser",
+    "aftermath",
+    "aggregate",
+    "alkali",
+    "almighty",
+    "amulet",
+    "amusement",
+    "antenna",
+    "applicant",
+    "Apollo",
+    "armistice",
+    "article",
+    "asteroid",
+    "Atlantic",
+    "atmosphere",
+    "autopsy",
+    "Babylon",
+    "backwater",
+    "barbecue",
+    "belowground",
+    "bifocals",
+    "bodyguard",
+    "bookseller",
+    "borderline",
+    "bottomless",
+    "Bradbury",
+    "bravado",
+    "Brazilian",
+    "breakaway",
+    "Burlington",
+    "businessman",
+    "butterfat",
+    "Camelot",
+    "candidate",
+    "cannonball",
+    "Capricorn",
+    "caravan",
+    "caretaker",
+    "celebrate",
+    "cellulose",
+    "certify",
+    "chambermaid",
+    "Cherokee",
+    "Chicago",
+    "clergyman",
+    "coherence",
+    "combustion",
+    "commando",
+    "company",
+    "component",
+    "concurrent",
+    "confidence",
+    "conformist",
+    "congregate",
+    "consensus",
+    "consulting",
+    "corporate",
+    "corrosion",
+    "councilman",
+    "crossover",
+    "crucifix",
+    "cumbersome",
+    "customer",
+    "Dakota",
+    "decadence",
+    "December",
+    "decimal",
+    "designing",
+    "detector",
+    "detergent",
+    "determine",
+    "dictator",
+    "dinosaur",
+    "direction",
+    "disable",
+    "disbelief",
+    "disruptive",
+    "distortion",
+    "document",
+    "embezzle",
+    "enchanting",
+    "enrollment",
+    "enterprise",
+    "equation",
+    "equipment",
+    "escapade",
+    "Eskimo",
+    "everyday",
+    "examine",
+    "existence",
+    "exodus",
+    "fascinate",
+    "filament",
+    "finicky",
+    "forever",
+    "fortitude",
+    "frequency",
+    "gadgetry",
+    "Galveston",
+    "getaway",
+    "glossary",
+    "gossamer",
+    "graduate",
+    "gravity",
+    "guitarist",
+    "hamburger",
+    "Hamilton",
+    "handiwork",
+    "hazardous",
+    "headwaters",
+    "hemisphere",
+    "hesitate",
+    "hideaway",
+    "holiness",
+    "hurricane",
+    "hydraulic",
+    "impartial",
+    "impetus",
+    "inception",
+    "indigo",
+    "inertia",
+    "infancy",
+    "inferno",
+    "informant",
+    "insincere",
+    "insurgent",
+    "integrate",
+    "intention",
+    "inventive",
+    "Istanbul",
+    "Jamaica",
+    "Jupiter",
+    "leprosy",
+    "letterhead",
+    "liberty",
+    "maritime",
+    "matchmaker",
+    "maverick",
+    "Medusa",
+    "megaton",
+    "microscope",
+    "microwave",
+    "midsummer",
+    "millionaire",
+    "miracle",
+    "misnomer",
+    "molasses",
+    "molecule",
+    "Montana",
+    "monument",
+    "mosquito",
+    "narrative",
+    "nebula",
+    "newsletter",
+    "Norwegian",
+    "October",
+    "Ohio",
+    "onlooker",
+    "opulent",
+    "Orlando",
+    "outfielder",
+    "Pacific",
+    "pandemic",
+    "Pandora",
+    "paperweight",
+    "paragon",
+    "paragraph",
+    "paramount",
+    "passenger",
+    "pedigree",
+    "Pegasus",
+    "penetrate",
+    "perceptive",
+    "performance",
+    "pharmacy",
+    "phonetic",
+    "photograph",
+    "pioneer",
+    "pocketful",
+    "politeness",
+    "positive",
+    "potato",
+    "processor",
+    "provincial",
+    "proximate",
+    "puberty",
+    "publisher",
+    "pyramid",
+    "quantity",
+    "racketeer",
+    "rebellion",
+    "recipe",
+    "recover",
+    "repellent",
+    "replica",
+    "reproduce",
+    "resistor",
+    "responsive",
+    "retraction",
+    "retrieval",
+    "retrospect",
+    "revenue",
+    "revival",
+    "revolver",
+    "sandalwood",
+    "sardonic",
+    "Saturday",
+    "savagery",
+    "scavenger",
+    "sensation",
+    "sociable",
+    "souvenir",
+    "specialist",
+    "speculate",
+    "stethoscope",
+    "stupendous",
+    "supportive",
+    "surrender",
+    "suspicious",
+    "sympathy",
+    "tambourine",
+    "telephone",
+    "therapist",
+    "tobacco",
+    "tolerance",
+    "tomorrow",
+    "torpedo",
+    "tradition",
+    "travesty",
+    "trombonist",
+    "truncated",
+    "typewriter",
+    "ultimate",
+    "undaunted",
+    "underfoot",
+    "unicorn",
+    "unify",
+    "universe",
+    "unravel",
+    "upcoming",
+    "vacancy",
+    "vagabond",
+    "vertigo",
+    "Virginia",
+    "visitor",
+    "vocalist",
+    "voyager",
+    "warranty",
+    "Waterloo",
+    "whimsical",
+    "Wichita",
+    "Wilmington",
+    "Wyoming",
+    "yesteryear",
+    "Yucatan",
+];
+
+/// SAS emoji set as `(emoji, English name)`. Indexed by 6-bit value.
+///
+/// Same 64 entries and order as the Matrix SAS emoji table, so users
+/// see familiar symbols across messengers.
+pub(crate) const SAS_EMOJI: [(&str, &str); 64] = [
+    ("\u{1f436}", "Dog"),              // 🐶
+    ("\u{1f431}", "Cat"),              // 🐱
+    ("\u{1f981}", "Lion"),             // 🦁
+    ("\u{1f40e}", "Horse"),            // 🐎
+    ("\u{1f984}", "Unicorn"),          // 🦄
+    ("\u{1f437}", "Pig"),              // 🐷
+    ("\u{1f418}", "Elephant"),         // 🐘
+    ("\u{1f430}", "Rabbit"),           // 🐰
+    ("\u{1f43c}", "Panda"),            // 🐼
+    ("\u{1f413}", "Rooster"),          // 🐓
+    ("\u{1f427}", "Penguin"),          // 🐧
+    ("\u{1f422}", "Turtle"),           // 🐢
+    ("\u{1f41f}", "Fish"),             // 🐟
+    ("\u{1f419}", "Octopus"),          // 🐙
+    ("\u{1f98b}", "Butterfly"),        // 🦋
+    ("\u{1f337}", "Flower"),           // 🌷
+    ("\u{1f333}", "Tree"),             // 🌳
+    ("\u{1f335}", "Cactus"),           // 🌵
+    ("\u{1f344}", "Mushroom"),         // 🍄
+    ("\u{1f30f}", "Globe"),            // 🌏
+    ("\u{1f319}", "Moon"),             // 🌙
+    ("\u{2601}\u{fe0f}", "Cloud"),     // ☁️
+    ("\u{1f525}", "Fire"),             // 🔥
+    ("\u{1f34c}", "Banana"),           // 🍌
+    ("\u{1f34e}", "Apple"),            // 🍎
+    ("\u{1f353}", "Strawberry"),       // 🍓
+    ("\u{1f33d}", "Corn"),             // 🌽
+    ("\u{1f355}", "Pizza"),            // 🍕
+    ("\u{1f382}", "Cake"),             // 🎂
+    ("\u{2764}\u{fe0f}", "Heart"),     // ❤️
+    ("\u{1f600}", "Smiley"),           // 😀
+    ("\u{1f916}", "Robot"),            // 🤖
+    ("\u{1f3a9}", "Hat"),              // 🎩
+    ("\u{1f453}", "Glasses"),          // 👓
+    ("\u{1f527}", "Spanner"),          // 🔧
+    ("\u{1f385}", "Santa"),            // 🎅
+    ("\u{1f44d}", "Thumbs Up"),        // 👍
+    ("\u{2602}\u{fe0f}", "Umbrella"),  // ☂️
+    ("\u{231b}", "Hourglass"),         // ⌛
+    ("\u{23f0}", "Clock"),             // ⏰
+    ("\u{1f381}", "Gift"),             // 🎁
+    ("\u{1f4a1}", "Light Bulb"),       // 💡
+    ("\u{1f4d5}", "Book"),             // 📕
+    ("\u{270f}\u{fe0f}", "Pencil"),    // ✏️
+    ("\u{1f4ce}", "Paperclip"),        // 📎
+    ("\u{2702}\u{fe0f}", "Scissors"),  // ✂️
+    ("\u{1f512}", "Lock"),             // 🔒
+    ("\u{1f511}", "Key"),              // 🔑
+    ("\u{1f528}", "Hammer"),           // 🔨
+    ("\u{260e}\u{fe0f}", "Telephone"), // ☎️
+    ("\u{1f3c1}", "Flag"),             // 🏁
+    ("\u{1f682}", "Train"),            // 🚂
+    ("\u{1f6b2}", "Bicycle"),          // 🚲
+    ("\u{2708}\u{fe0f}", "Aeroplane"), // ✈️
+    ("\u{1f680}", "Rocket"),           // 🚀
+    ("\u{1f3c6}", "Trophy"),           // 🏆
+    ("\u{26bd}", "Ball"),              // ⚽
+    ("\u{1f3b8}", "Guitar"),           // 🎸
+    ("\u{1f3ba}", "Trumpet"),          // 🎺
+    ("\u{1f514}", "Bell"),             // 🔔
+    ("\u{2693}", "Anchor"),            // ⚓
+    ("\u{1f3a7}", "Headphones"),       // 🎧
+    ("\u{1f4c1}", "Folder"),           // 📁
+    ("\u{1f4cc}", "Pin"),              // 📌
+];
@@ -158,7 +158,7 @@ impl SessionContext {
 
     /// Shorthand: was `bolt.profile-envelope-v1` negotiated?
     pub fn envelope_v1_negotiated(&self) -> bool {
-        self.has_capability("bolt.profile-envelope-v1")
+        self.has_capability(crate::constants::PROFILE_ENVELOPE_V1_CAPABILITY)
     }
 
     /// Whether the HELLO exchange has completed.
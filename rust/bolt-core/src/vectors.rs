@@ -4,7 +4,8 @@
 //! vectors in the Bolt ecosystem. Generated vectors are consumed by both
 //! the Rust and TypeScript SDKs for cross-implementation verification.
 //!
-//! Covers: box-payload, framing, SAS, HELLO-open, envelope-open.
+//! Covers: box-payload, framing, SAS, HELLO-open, envelope-open, PAKE,
+//! identity rotation, pairing payload.
 //! Output directory: `test-vectors/core/` (parallel to `test-vectors/btr/`).
 //!
 //! Uses fixed keypairs, nonces, and plaintexts for full determinism.
@@ -351,11 +352,43 @@ struct SasCase {
     ephemeral_a_hex: String,
     ephemeral_b_hex: String,
     expected_sas: String,
+    expected_sas_words: String,
+    expected_sas_emoji: String,
+    expected_sas_emoji_names: Vec<String>,
+    expected_sas_decimal: String,
+}
+
+fn sas_case(
+    name: &str,
+    description: &str,
+    id_a: &[u8; 32],
+    id_b: &[u8; 32],
+    eph_a: &[u8; 32],
+    eph_b: &[u8; 32],
+) -> SasCase {
+    use crate::sas::{compute_sas, compute_sas_emoji_names, compute_sas_encoded, SasEncoding};
+    SasCase {
+        name: name.to_string(),
+        description: description.to_string(),
+        identity_a_hex: to_hex(id_a),
+        identity_b_hex: to_hex(id_b),
+        ephemeral_a_hex: to_hex(eph_a),
+        ephemeral_b_hex: to_hex(eph_b),
+        expected_sas: compute_sas(id_a, id_b, eph_a, eph_b),
+        expected_sas_words: compute_sas_encoded(id_a, id_b, eph_a, eph_b, SasEncoding::Words),
+        expected_sas_emoji: compute_sas_encoded(id_a, id_b, eph_a, eph_b, SasEncoding::Emoji),
+        expected_sas_emoji_names: compute_sas_emoji_names(id_a, id_b, eph_a, eph_b)
+            .into_iter()
+            .map(String::from)
+            .collect(),
+        expected_sas_decimal: compute_sas_encoded(id_a, id_b, eph_a, eph_b, SasEncoding::Decimal),
+    }
 }
 
 /// Generate the SAS golden vectors JSON string.
 ///
-/// Uses `crate::sas::compute_sas` to produce expected SAS values.
+/// Uses `crate::sas::compute_sas` and `compute_sas_encoded` to produce
+/// expected SAS values in every encoding.
 /// Same fixed keypairs as box-payload vectors plus sparse test keys.
 pub fn generate_sas_json() -> String {
     let (_, _, sender_pk) = keypair_from_offset(1);
@@ -388,42 +421,38 @@ pub fn generate_sas_json() -> String {
         warning: "TEST FIXTURES ONLY \u{2014} NEVER USE IN PRODUCTION. All keys are deterministic test fixtures.".to_string(),
         description: "SAS (Short Authentication String) golden vectors. Generated by Rust canonical vector generator (bolt-core, vectors feature).".to_string(),
         cases: vec![
-            SasCase {
-                name: "sas_sparse_keys".to_string(),
-                description: "Sparse test keys (mostly zeros with sentinel bytes at positions 0 and 31)".to_string(),
-                identity_a_hex: to_hex(&key_a),
-                identity_b_hex: to_hex(&key_b),
-                ephemeral_a_hex: to_hex(&eph_a),
-                ephemeral_b_hex: to_hex(&eph_b),
-                expected_sas: crate::sas::compute_sas(&key_a, &key_b, &eph_a, &eph_b),
-            },
-            SasCase {
-                name: "sas_box_payload_keys_symmetric".to_string(),
-                description: "Uses sender + receiver public keys from box-payload.vectors.json for both identity and ephemeral".to_string(),
-                identity_a_hex: to_hex(&sender_pk_bytes),
-                identity_b_hex: to_hex(&receiver_pk_bytes),
-                ephemeral_a_hex: to_hex(&sender_pk_bytes),
-                ephemeral_b_hex: to_hex(&receiver_pk_bytes),
-                expected_sas: crate::sas::compute_sas(&sender_pk_bytes, &receiver_pk_bytes, &sender_pk_bytes, &receiver_pk_bytes),
-            },
-            SasCase {
-                name: "sas_mixed_keys".to_string(),
-                description: "Identity: receiver + eve; ephemeral: sender + receiver from box-payload.vectors.json".to_string(),
-                identity_a_hex: to_hex(&receiver_pk_bytes),
-                identity_b_hex: to_hex(&eve_pk_bytes),
-                ephemeral_a_hex: to_hex(&sender_pk_bytes),
-                ephemeral_b_hex: to_hex(&receiver_pk_bytes),
-                expected_sas: crate::sas::compute_sas(&receiver_pk_bytes, &eve_pk_bytes, &sender_pk_bytes, &receiver_pk_bytes),
-            },
-            SasCase {
-                name: "sas_all_different_keys".to_string(),
-                description: "All four keys are distinct: sender_pk, receiver_pk, eve_pk, and sparse keyA".to_string(),
-                identity_a_hex: to_hex(&sender_pk_bytes),
-                identity_b_hex: to_hex(&eve_pk_bytes),
-                ephemeral_a_hex: to_hex(&receiver_pk_bytes),
-                ephemeral_b_hex: to_hex(&key_a),
-                expected_sas: crate::sas::compute_sas(&sender_pk_bytes, &eve_pk_bytes, &receiver_pk_bytes, &key_a),
-            },
+            sas_case(
+                "sas_sparse_keys",
+                "Sparse test keys (mostly zeros with sentinel bytes at positions 0 and 31)",
+                &key_a,
+                &key_b,
+                &eph_a,
+                &eph_b,
+            ),
+            sas_case(
+                "sas_box_payload_keys_symmetric",
+                "Uses sender + receiver public keys from box-payload.vectors.json for both identity and ephemeral",
+                &sender_pk_bytes,
+                &receiver_pk_bytes,
+                &sender_pk_bytes,
+                &receiver_pk_bytes,
+            ),
+            sas_case(
+                "sas_mixed_keys",
+                "Identity: receiver + eve; ephemeral: sender + receiver from box-payload.vectors.json",
+                &receiver_pk_bytes,
+                &eve_pk_bytes,
+                &sender_pk_bytes,
+                &receiver_pk_bytes,
+            ),
+            sas_case(
+                "sas_all_different_keys",
+                "All four keys are distinct: sender_pk, receiver_pk, eve_pk, and sparse keyA",
+                &sender_pk_bytes,
+                &eve_pk_bytes,
+                &receiver_pk_bytes,
+                &key_a,
+            ),
         ],
     };
 
@@ -606,9 +635,12 @@ pub fn generate_envelope_open_json() -> String {
     let plain_app = serde_json::to_string(&app_msg_inner).unwrap();
 
     // Seal inner messages
-    let sealed_ping = seal_with_fixed_nonce(plain_ping.as_bytes(), &nonce_c, &receiver_pk, &sender_sk);
-    let sealed_pong = seal_with_fixed_nonce(plain_pong.as_bytes(), &nonce_d, &sender_pk, &receiver_sk);
-    let sealed_app = seal_with_fixed_nonce(plain_app.as_bytes(), &nonce_f, &receiver_pk, &sender_sk);
+    let sealed_ping =
+        seal_with_fixed_nonce(plain_ping.as_bytes(), &nonce_c, &receiver_pk, &sender_sk);
+    let sealed_pong =
+        seal_with_fixed_nonce(plain_pong.as_bytes(), &nonce_d, &sender_pk, &receiver_sk);
+    let sealed_app =
+        seal_with_fixed_nonce(plain_app.as_bytes(), &nonce_f, &receiver_pk, &sender_sk);
 
     let data = EnvelopeOpenVectors {
         version: 1,
@@ -659,3 +691,529 @@ pub fn generate_envelope_open_json() -> String {
 
     serde_json::to_string_pretty(&data).unwrap() + "\n"
 }
+
+// ── PAKE vector schema ────────────────────────────────────────────
+
+#[derive(Serialize)]
+struct PakeVectors {
+    version: u32,
+    #[serde(rename = "_WARNING")]
+    warning: String,
+    description: String,
+    cases: Vec<PakeCase>,
+}
+
+#[derive(Serialize)]
+struct PakeCase {
+    name: String,
+    description: String,
+    code_initiator: String,
+    code_responder: String,
+    sid_hex: String,
+    identity_initiator_hex: String,
+    identity_responder_hex: String,
+    /// Responder identity as presented to the initiator (differs on substitution).
+    identity_responder_seen_hex: String,
+    seed_initiator_hex: String,
+    seed_responder_hex: String,
+    expected_share_initiator_hex: String,
+    expected_share_responder_hex: String,
+    expected_confirm_initiator_hex: String,
+    expected_confirm_responder_hex: String,
+    /// Agreed key, or `null` when confirmation fails.
+    expected_key_hex: Option<String>,
+    /// Wire error code on failure, or `null` on success.
+    expected_error: Option<String>,
+}
+
+fn make_seed(offset: u8) -> [u8; 64] {
+    let mut s = [0u8; 64];
+    for (i, b) in s.iter_mut().enumerate() {
+        *b = (i as u8).wrapping_mul(3).wrapping_add(offset);
+    }
+    s
+}
+
+#[allow(clippy::too_many_arguments)]
+fn pake_case(
+    name: &str,
+    description: &str,
+    code_initiator: &str,
+    code_responder: &str,
+    sid: &[u8],
+    id_i: &[u8; 32],
+    id_r: &[u8; 32],
+    id_r_seen: &[u8; 32],
+) -> PakeCase {
+    use crate::pake::{PakeRole, PakeSession};
+
+    let seed_i = make_seed(7);
+    let seed_r = make_seed(101);
+    let init =
+        PakeSession::start_with_seed(PakeRole::Initiator, code_initiator, sid, id_i, &seed_i)
+            .expect("fixture code is valid");
+    let resp =
+        PakeSession::start_with_seed(PakeRole::Responder, code_responder, sid, id_r, &seed_r)
+            .expect("fixture code is valid");
+    let (share_i, share_r) = (init.share(), resp.share());
+    let keys_i = init
+        .finish(&share_r, id_r_seen)
+        .expect("fixture share is valid");
+    let keys_r = resp.finish(&share_i, id_i).expect("fixture share is valid");
+    let (conf_i, conf_r) = (keys_i.confirmation(), keys_r.confirmation());
+
+    let (expected_key_hex, expected_error) = match (keys_i.verify(&conf_r), keys_r.verify(&conf_i))
+    {
+        (Ok(a), Ok(b)) => {
+            assert_eq!(a.key, b.key);
+            (Some(to_hex(&a.key)), None)
+        }
+        (Err(e), _) | (_, Err(e)) => (None, Some(e.code().to_string())),
+    };
+
+    PakeCase {
+        name: name.to_string(),
+        description: description.to_string(),
+        code_initiator: code_initiator.to_string(),
+        code_responder: code_responder.to_string(),
+        sid_hex: to_hex(sid),
+        identity_initiator_hex: to_hex(id_i),
+        identity_responder_hex: to_hex(id_r),
+        identity_responder_seen_hex: to_hex(id_r_seen),
+        seed_initiator_hex: to_hex(&seed_i),
+        seed_responder_hex: to_hex(&seed_r),
+        expected_share_initiator_hex: to_hex(&share_i),
+        expected_share_responder_hex: to_hex(&share_r),
+        expected_confirm_initiator_hex: to_hex(&conf_i),
+        expected_confirm_responder_hex: to_hex(&conf_r),
+        expected_key_hex,
+        expected_error,
+    }
+}
+
+/// Generate the PAKE (CPace ristretto255) golden vectors JSON string.
+///
+/// Uses `crate::pake::PakeSession::start_with_seed` with fixed scalar
+/// seeds. Identity keys are the box-payload sender/receiver public keys;
+/// the session id is `sort32` of two sparse ephemerals.
+pub fn generate_pake_json() -> String {
+    let (_, _, sender_pk) = keypair_from_offset(1);
+    let (_, _, receiver_pk) = keypair_from_offset(33);
+    let (_, _, eve_pk) = keypair_from_offset(65);
+    let id_i: [u8; 32] = *sender_pk.as_bytes();
+    let id_r: [u8; 32] = *receiver_pk.as_bytes();
+    let id_eve: [u8; 32] = *eve_pk.as_bytes();
+
+    let mut eph_a = [0u8; 32];
+    eph_a[0] = 0x10;
+    eph_a[31] = 0xCC;
+    let mut eph_b = [0u8; 32];
+    eph_b[0] = 0x20;
+    eph_b[31] = 0xDD;
+    let sid = crate::sas::sort32(&eph_a, &eph_b);
+
+    let data = PakeVectors {
+        version: 1,
+        warning: "TEST FIXTURES ONLY \u{2014} NEVER USE IN PRODUCTION. All keys and scalar seeds are deterministic test fixtures.".to_string(),
+        description: "PAKE (CPace over ristretto255, bolt.pake-cpace-v1) golden vectors. Generated by Rust canonical vector generator (bolt-core, vectors feature).".to_string(),
+        cases: vec![
+            pake_case(
+                "pake_matching_code",
+                "Both peers enter the same code; keys agree and both identities are authenticated",
+                "ABC-DEF",
+                "ABC-DEF",
+                &sid,
+                &id_i,
+                &id_r,
+                &id_r,
+            ),
+            pake_case(
+                "pake_code_normalized",
+                "Responder enters the code lowercase without dash; normalizes to the same code",
+                "K7M-Q2X",
+                "k7mq2x",
+                &sid,
+                &id_i,
+                &id_r,
+                &id_r,
+            ),
+            pake_case(
+                "pake_wrong_code",
+                "Codes differ by one character; confirmation fails",
+                "ABC-DEF",
+                "ABC-DEG",
+                &sid,
+                &id_i,
+                &id_r,
+                &id_r,
+            ),
+            pake_case(
+                "pake_identity_substituted",
+                "Initiator is presented eve_pk as the responder identity; confirmation fails",
+                "ABC-DEF",
+                "ABC-DEF",
+                &sid,
+                &id_i,
+                &id_r,
+                &id_eve,
+            ),
+        ],
+    };
+
+    serde_json::to_string_pretty(&data).unwrap() + "\n"
+}
+
+// ── Identity rotation vector schema ───────────────────────────────
+
+#[derive(Serialize)]
+struct RotationVectors {
+    version: u32,
+    #[serde(rename = "_WARNING")]
+    warning: String,
+    description: String,
+    cases: Vec<RotationCase>,
+}
+
+#[derive(Serialize)]
+struct RotationCase {
+    name: String,
+    description: String,
+    old_secret_hex: String,
+    new_secret_hex: String,
+    old_nonce_hex: String,
+    new_nonce_hex: String,
+    issued_at_ms: u64,
+    validity_ms: u64,
+    /// Statement as signed, before any tampering.
+    signed_statement_hex: String,
+    /// Statement presented to the verifier.
+    statement_hex: String,
+    pinned_hex: String,
+    now_ms: u64,
+    /// New identity to pin, or `null` on failure.
+    expected_new_identity_hex: Option<String>,
+    /// Wire error code on failure, or `null` on success.
+    expected_error: Option<String>,
+}
+
+fn rotation_case(
+    name: &str,
+    description: &str,
+    old_offset: u8,
+    new_offset: u8,
+    pinned: &[u8; 32],
+    now_ms: u64,
+    tamper: fn(&mut [u8]),
+) -> RotationCase {
+    use crate::crypto::KeyPair;
+    use crate::rotation::{verify_rotation, RotationStatement};
+
+    const ISSUED_AT_MS: u64 = 1_700_000_000_000;
+    const VALIDITY_MS: u64 = 7 * 24 * 60 * 60 * 1000;
+
+    let (old_sk, _, old_pk) = keypair_from_offset(old_offset);
+    let (new_sk, _, new_pk) = keypair_from_offset(new_offset);
+    let old = KeyPair {
+        public_key: *old_pk.as_bytes(),
+        secret_key: old_sk,
+    };
+    let new = KeyPair {
+        public_key: *new_pk.as_bytes(),
+        secret_key: new_sk,
+    };
+    let old_nonce = make_seed(old_offset);
+    let new_nonce = make_seed(new_offset);
+
+    let signed = RotationStatement::sign_with_nonces(
+        &old,
+        &new,
+        ISSUED_AT_MS,
+        VALIDITY_MS,
+        &old_nonce,
+        &new_nonce,
+    )
+    .expect("fixture rotation parameters are valid")
+    .to_bytes();
+    let mut presented = signed;
+    tamper(&mut presented);
+
+    let result = RotationStatement::from_bytes(&presented)
+        .and_then(|stmt| verify_rotation(&stmt, pinned, now_ms));
+    let (expected_new_identity_hex, expected_error) = match result {
+        Ok(key) => (Some(to_hex(&key)), None),
+        Err(e) => (None, Some(e.code().to_string())),
+    };
+
+    RotationCase {
+        name: name.to_string(),
+        description: description.to_string(),
+        old_secret_hex: to_hex(&old.secret_key),
+        new_secret_hex: to_hex(&new.secret_key),
+        old_nonce_hex: to_hex(&old_nonce),
+        new_nonce_hex: to_hex(&new_nonce),
+        issued_at_ms: ISSUED_AT_MS,
+        validity_ms: VALIDITY_MS,
+        signed_statement_hex: to_hex(&signed),
+        statement_hex: to_hex(&presented),
+        pinned_hex: to_hex(pinned),
+        now_ms,
+        expected_new_identity_hex,
+        expected_error,
+    }
+}
+
+/// Generate the identity rotation golden vectors JSON string.
+///
+/// Uses `crate::rotation::RotationStatement::sign_with_nonces` with
+/// box-payload keypairs (old = sender, new = receiver) and fixed XEdDSA
+/// nonces, then runs `verify_rotation` against the presented statement.
+pub fn generate_rotation_json() -> String {
+    let (_, _, sender_pk) = keypair_from_offset(1);
+    let (_, _, eve_pk) = keypair_from_offset(65);
+    let old_pin: [u8; 32] = *sender_pk.as_bytes();
+    let eve_pin: [u8; 32] = *eve_pk.as_bytes();
+
+    const ISSUED: u64 = 1_700_000_000_000;
+    const EXPIRES: u64 = ISSUED + 7 * 24 * 60 * 60 * 1000;
+
+    let data = RotationVectors {
+        version: 1,
+        warning: "TEST FIXTURES ONLY \u{2014} NEVER USE IN PRODUCTION. All keypairs and signature nonces are deterministic test fixtures.".to_string(),
+        description: "Identity rotation statement (bolt.identity-rotation-v1, XEdDSA) golden vectors. Generated by Rust canonical vector generator (bolt-core, vectors feature).".to_string(),
+        cases: vec![
+            rotation_case(
+                "rotation_valid",
+                "Sender key rotates to receiver key; verified one hour after issue",
+                1,
+                33,
+                &old_pin,
+                ISSUED + 3_600_000,
+                |_| {},
+            ),
+            rotation_case(
+                "rotation_expired",
+                "Valid statement verified exactly at expiry",
+                1,
+                33,
+                &old_pin,
+                EXPIRES,
+                |_| {},
+            ),
+            rotation_case(
+                "rotation_wrong_pin",
+                "Verifier has eve_pk pinned, not the issuing key",
+                1,
+                33,
+                &eve_pin,
+                ISSUED,
+                |_| {},
+            ),
+            rotation_case(
+                "rotation_tampered_expiry",
+                "Last byte of expires_at_ms incremented after signing",
+                1,
+                33,
+                &old_pin,
+                ISSUED,
+                |b| b[80] = b[80].wrapping_add(1),
+            ),
+            rotation_case(
+                "rotation_bad_version",
+                "Version byte set to 2",
+                1,
+                33,
+                &old_pin,
+                ISSUED,
+                |b| b[0] = 2,
+            ),
+        ],
+    };
+
+    serde_json::to_string_pretty(&data).unwrap() + "\n"
+}
+
+// ── Pairing payload vector schema ─────────────────────────────────
+
+#[derive(Serialize)]
+struct PairingVectors {
+    version: u32,
+    #[serde(rename = "_WARNING")]
+    warning: String,
+    description: String,
+    cases: Vec<PairingCase>,
+    /// URIs every implementation MUST reject.
+    invalid_uris: Vec<PairingInvalidUri>,
+}
+
+#[derive(Serialize)]
+struct PairingCase {
+    name: String,
+    description: String,
+    /// Peer code as entered; the URI carries the normalized form.
+    peer_code: String,
+    identity_public_hex: String,
+    secret_hex: String,
+    expected_fingerprint_hex: String,
+    expected_uri: String,
+    identity_a_hex: String,
+    identity_b_hex: String,
+    ephemeral_a_hex: String,
+    ephemeral_b_hex: String,
+    expected_proof_hex: String,
+}
+
+#[derive(Serialize)]
+struct PairingInvalidUri {
+    name: String,
+    uri: String,
+}
+
+#[allow(clippy::too_many_arguments)]
+fn pairing_case(
+    name: &str,
+    description: &str,
+    peer_code: &str,
+    identity: &[u8; 32],
+    secret_offset: u8,
+    id_a: &[u8; 32],
+    id_b: &[u8; 32],
+    eph_a: &[u8; 32],
+    eph_b: &[u8; 32],
+) -> PairingCase {
+    use crate::pairing::{compute_pairing_proof, identity_fingerprint, PairingPayload};
+
+    let mut secret = [0u8; crate::constants::PAIRING_SECRET_LENGTH];
+    secret.copy_from_slice(&make_secret(secret_offset)[..crate::constants::PAIRING_SECRET_LENGTH]);
+    assert!(crate::peer_code::is_valid_peer_code(peer_code));
+    let payload = PairingPayload {
+        peer_code: crate::peer_code::normalize_peer_code(peer_code),
+        identity_fingerprint: identity_fingerprint(identity),
+        secret,
+    };
+
+    PairingCase {
+        name: name.to_string(),
+        description: description.to_string(),
+        peer_code: peer_code.to_string(),
+        identity_public_hex: to_hex(identity),
+        secret_hex: to_hex(&secret),
+        expected_fingerprint_hex: to_hex(&payload.identity_fingerprint),
+        expected_uri: payload.to_uri(),
+        identity_a_hex: to_hex(id_a),
+        identity_b_hex: to_hex(id_b),
+        ephemeral_a_hex: to_hex(eph_a),
+        ephemeral_b_hex: to_hex(eph_b),
+        expected_proof_hex: to_hex(&compute_pairing_proof(&secret, id_a, id_b, eph_a, eph_b)),
+    }
+}
+
+/// Generate the pairing payload (URI + HMAC proof) golden vectors JSON string.
+///
+/// Uses `crate::pairing::PairingPayload::to_uri` and
+/// `compute_pairing_proof` with fixed secrets, the box-payload public keys
+/// as identities and the sparse SAS ephemerals.
+pub fn generate_pairing_json() -> String {
+    let (_, _, sender_pk) = keypair_from_offset(1);
+    let (_, _, receiver_pk) = keypair_from_offset(33);
+    let (_, _, eve_pk) = keypair_from_offset(65);
+    let sender: [u8; 32] = *sender_pk.as_bytes();
+    let receiver: [u8; 32] = *receiver_pk.as_bytes();
+    let eve: [u8; 32] = *eve_pk.as_bytes();
+
+    let mut eph_a = [0u8; 32];
+    eph_a[0] = 0x10;
+    eph_a[31] = 0xCC;
+    let mut eph_b = [0u8; 32];
+    eph_b[0] = 0x20;
+    eph_b[31] = 0xDD;
+
+    let base = pairing_case(
+        "pairing_box_payload_keys",
+        "Sender displays the QR; receiver scans and proves the secret",
+        "ABCDEF",
+        &sender,
+        200,
+        &sender,
+        &receiver,
+        &eph_a,
+        &eph_b,
+    );
+    let invalid = |name: &str, uri: String| PairingInvalidUri {
+        name: name.to_string(),
+        uri,
+    };
+    let invalid_uris = vec![
+        invalid(
+            "wrong_scheme",
+            base.expected_uri.replace("bolt://pair?", "https://pair?"),
+        ),
+        invalid("wrong_version", base.expected_uri.replace("v=1", "v=2")),
+        invalid(
+            "missing_code",
+            base.expected_uri.replace("&code=ABCDEF", ""),
+        ),
+        invalid(
+            "invalid_code",
+            base.expected_uri.replace("code=ABCDEF", "code=A0CDEF"),
+        ),
+        invalid(
+            "short_fingerprint",
+            base.expected_uri.replace(
+                &format!("fp={}", uri_param(&base.expected_uri, "fp")),
+                "fp=ERER",
+            ),
+        ),
+        invalid(
+            "secret_not_base64url",
+            base.expected_uri.replace(
+                &format!("s={}", uri_param(&base.expected_uri, "s")),
+                "s=!!!",
+            ),
+        ),
+        invalid("duplicate_param", base.expected_uri.clone() + "&v=1"),
+        invalid(
+            "param_without_value",
+            base.expected_uri.clone() + "&novalue",
+        ),
+    ];
+
+    let data = PairingVectors {
+        version: 1,
+        warning: "TEST FIXTURES ONLY \u{2014} NEVER USE IN PRODUCTION. All keys and pairing secrets are deterministic test fixtures.".to_string(),
+        description: "Pairing payload (bolt://pair URI v1) and pairing proof (HMAC-SHA256) golden vectors. Generated by Rust canonical vector generator (bolt-core, vectors feature).".to_string(),
+        cases: vec![
+            base,
+            pairing_case(
+                "pairing_code_normalized",
+                "Peer code entered lowercase with a dash; identity and ephemeral order swapped (proof is commutative)",
+                "abcd-efgh",
+                &receiver,
+                17,
+                &receiver,
+                &sender,
+                &eph_b,
+                &eph_a,
+            ),
+            pairing_case(
+                "pairing_mixed_keys",
+                "Identity: eve; proof binds receiver + eve identities to the box-payload public keys as ephemerals",
+                "K7MQ2X",
+                &eve,
+                99,
+                &receiver,
+                &eve,
+                &sender,
+                &receiver,
+            ),
+        ],
+        invalid_uris,
+    };
+
+    serde_json::to_string_pretty(&data).unwrap() + "\n"
+}
+
+fn uri_param<'a>(uri: &'a str, key: &str) -> &'a str {
+    uri.split(['?', '&'])
+        .find_map(|p| p.strip_prefix(key)?.strip_prefix('='))
+        .expect("generated URI has every param")
+}
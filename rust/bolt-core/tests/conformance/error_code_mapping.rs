@@ -2,7 +2,9 @@
 //!
 //! Tests that Rust-exposed error types are stable and correctly mapped.
 //!
-//! PROTOCOL.md §10 + §16.7 defines 26 wire error codes (11 PROTOCOL + 11 ENFORCEMENT + 4 BTR).
+//! PROTOCOL.md §10 + §16.7 defines 26 wire error codes (11 PROTOCOL + 11 ENFORCEMENT + 4 BTR);
+//! the PAKE pairing, identity rotation and PQ hybrid extensions add 2 each
+//! (32 total). PQ codes come from `BtrError` and are checked in bolt-btr.
 //! The canonical string registry is now in Rust bolt-core (`errors::WIRE_ERROR_CODES`)
 //! and TS bolt-core (`errors.ts::WIRE_ERROR_CODES`). See `wire_error_registry.rs`
 //! for registry conformance tests.
@@ -13,7 +15,11 @@
 //! - BoltError::Integrity   — maps to file integrity failures
 //! - BoltError::Connection  — maps to connection-level errors
 //! - BoltError::Transfer    — maps to transfer-level errors
+//!   (`BoltError::code()` returns the category default wire code)
 //! - KeyMismatchError       — maps to KEY_MISMATCH
+//! - PakeError              — maps to PAKE_FAILED, PAKE_CONFIRM_FAILED
+//! - RotationError          — maps to ROTATION_INVALID, ROTATION_EXPIRED
+//! - TransferError          — maps to INVALID_STATE, INTEGRITY_FAILED
 //!
 //! Wire codes emitted only at transport layer (TS-owned):
 //! - DUPLICATE_HELLO, ENVELOPE_REQUIRED, ENVELOPE_UNNEGOTIATED,
@@ -202,3 +208,67 @@ fn conformance_short_payload_produces_error() {
         "payload shorter than nonce must be rejected"
     );
 }
+
+// ── Conformance: PakeError → Wire Code ──────────────────────────
+
+/// Every PakeError variant MUST map to a canonical PAKE-class wire code.
+#[test]
+fn conformance_pake_error_codes_canonical() {
+    use bolt_core::errors::is_valid_wire_error_code;
+    use bolt_core::pake::PakeError;
+
+    let cases = [
+        (PakeError::InvalidCode, "PAKE_FAILED"),
+        (PakeError::InvalidShare, "PAKE_FAILED"),
+        (PakeError::ConfirmFailed, "PAKE_CONFIRM_FAILED"),
+    ];
+    for (err, expected) in cases {
+        assert_eq!(err.code(), expected, "PakeError wire code drift: {err}");
+        assert!(is_valid_wire_error_code(err.code()));
+    }
+}
+
+// ── Conformance: RotationError → Wire Code ──────────────────────
+
+/// Every RotationError variant MUST map to a canonical ROTATION-class wire code.
+#[test]
+fn conformance_rotation_error_codes_canonical() {
+    use bolt_core::errors::is_valid_wire_error_code;
+    use bolt_core::rotation::RotationError;
+
+    let cases = [
+        (
+            RotationError::Invalid("bad signature".into()),
+            "ROTATION_INVALID",
+        ),
+        (RotationError::Expired, "ROTATION_EXPIRED"),
+    ];
+    for (err, expected) in cases {
+        assert_eq!(err.code(), expected, "RotationError wire code drift: {err}");
+        assert!(is_valid_wire_error_code(err.code()));
+    }
+}
+
+// ── Conformance: TransferError → Wire Code ──────────────────────
+
+/// TransferError variants MUST map to canonical wire codes.
+#[test]
+fn conformance_transfer_error_codes_canonical() {
+    use bolt_core::errors::is_valid_wire_error_code;
+    use bolt_transfer_core::TransferError;
+
+    let cases = [
+        (
+            TransferError::InvalidTransition("no active offer".into()),
+            "INVALID_STATE",
+        ),
+        (
+            TransferError::IntegrityFailed("file hash mismatch".into()),
+            "INTEGRITY_FAILED",
+        ),
+    ];
+    for (err, expected) in cases {
+        assert_eq!(err.code(), expected, "TransferError wire code drift: {err}");
+        assert!(is_valid_wire_error_code(err.code()));
+    }
+}
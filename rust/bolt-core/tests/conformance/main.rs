@@ -10,6 +10,7 @@
 //! - SAS determinism (PROTO-06)
 //! - Error code mapping (Appendix A, Rust-surface only)
 //! - State-machine authority (AC-RC-10: transfer SM, BTR SM, backpressure)
+//! - Model-based state-machine tests (§9 transfer SM, proptest)
 //!
 //! TS-owned invariants (NOT tested here — see AAR):
 //! - Handshake gating (WebRTCService state machine) — AC-RC-07 scope
@@ -25,4 +26,5 @@ mod sas_determinism;
 
 mod error_code_mapping;
 mod state_machine_authority;
+mod state_machine_model;
 mod wire_error_registry;
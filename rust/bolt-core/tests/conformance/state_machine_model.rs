@@ -0,0 +1,494 @@
+//! Conformance: Model-Based State-Machine Tests
+//!
+//! proptest generates random event traces and runs each against both the
+//! implementation and an executable reference model of the PROTOCOL.md §9
+//! transfer state machine (`SendModel` / `RecvModel`, driving
+//! `SendSession` / `ReceiveSession`). Any divergence (result, error code,
+//! state, emitted data) fails the property, and proptest shrinks the trace
+//! to a minimal one. A panic inside the property is a failure too.
+//!
+//! `PROPTEST_CASES` overrides the trace count. The session contract model
+//! lives in bolt-conformance (`tests/session_contract_model.rs`), since it
+//! needs bolt-app-core.
+//!
+//! Complements `state_machine_authority` (hand-picked transitions).
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+
+use bolt_transfer_core::receive::MAX_TRANSFER_BYTES;
+use bolt_transfer_core::state::is_section9_transition;
+use bolt_transfer_core::{
+    CancelReason, IntegrityVerifier, ReceiveSession, SendSession, TransferError, TransferState,
+};
+
+const TRANSFER_IDS: [&str; 2] = ["t0", "t1"];
+const CHUNK_SIZE: usize = 4;
+/// Traces per property.
+const CASES: u32 = 1000;
+/// Longest generated trace.
+const MAX_TRACE: usize = 40;
+
+// ── §9 reference model ──────────────────────────────────────────
+
+#[derive(Debug, Clone, Default, PartialEq)]
+enum Phase {
+    #[default]
+    Idle,
+    Offered,
+    Transferring,
+    Paused,
+    Completed,
+    Cancelled(CancelReason),
+}
+
+fn model_state(phase: &Phase, tid: &str) -> TransferState {
+    let transfer_id = tid.to_string();
+    match phase {
+        Phase::Idle => TransferState::Idle,
+        Phase::Offered => TransferState::Offered { transfer_id },
+        Phase::Transferring => TransferState::Transferring { transfer_id },
+        Phase::Paused => TransferState::Paused { transfer_id },
+        Phase::Completed => TransferState::Completed { transfer_id },
+        Phase::Cancelled(reason) => TransferState::Cancelled {
+            transfer_id,
+            reason: reason.clone(),
+        },
+    }
+}
+
+/// What the model predicts for one event.
+type Expect<T> = Result<T, &'static str>;
+
+const INVALID: &str = "INVALID_STATE";
+
+fn compare<T: PartialEq + std::fmt::Debug>(
+    step: usize,
+    actual: Result<T, TransferError>,
+    expected: Expect<T>,
+) -> Result<(), TestCaseError> {
+    match (actual, expected) {
+        (Ok(a), Ok(e)) if a == e => Ok(()),
+        (Err(a), Err(e)) if a.code() == e => Ok(()),
+        (actual, expected) => Err(TestCaseError::fail(format!(
+            "step {step}: implementation {actual:?}, model {expected:?}"
+        ))),
+    }
+}
+
+// ── Send side ───────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy)]
+enum SendEvent {
+    Begin { tid: usize, len: usize },
+    Accept(usize),
+    Pause(usize),
+    Resume(usize),
+    NextChunk,
+    Finish,
+    Cancel(usize),
+}
+
+fn send_event() -> impl Strategy<Value = SendEvent> {
+    let tid = 0..TRANSFER_IDS.len();
+    prop_oneof![
+        2 => (tid.clone(), 0..=20usize).prop_map(|(tid, len)| SendEvent::Begin { tid, len }),
+        2 => tid.clone().prop_map(SendEvent::Accept),
+        2 => tid.clone().prop_map(SendEvent::Pause),
+        2 => tid.clone().prop_map(SendEvent::Resume),
+        4 => Just(SendEvent::NextChunk),
+        1 => Just(SendEvent::Finish),
+        1 => tid.prop_map(SendEvent::Cancel),
+    ]
+}
+
+fn payload(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i as u8) ^ 0x5A).collect()
+}
+
+#[derive(Default)]
+struct SendModel {
+    phase: Phase,
+    tid: String,
+    len: usize,
+    yielded: usize,
+}
+
+impl SendModel {
+    fn phase(&self) -> Phase {
+        self.phase.clone()
+    }
+
+    fn total(&self) -> usize {
+        self.len.div_ceil(CHUNK_SIZE)
+    }
+
+    /// Offered/Transferring/Paused for `tid`.
+    fn active_for(&self, tid: &str) -> bool {
+        matches!(
+            self.phase(),
+            Phase::Offered | Phase::Transferring | Phase::Paused
+        ) && self.tid == tid
+    }
+
+    fn set(&mut self, next: Phase) {
+        let (from, to) = (
+            model_state(&self.phase, &self.tid),
+            model_state(&next, &self.tid),
+        );
+        assert!(is_section9_transition(&from, &to), "model left §9");
+        self.phase = next;
+    }
+}
+
+fn send_session_property(trace: &[SendEvent]) -> Result<(), TestCaseError> {
+    let mut session = SendSession::with_chunk_size(CHUNK_SIZE);
+    let mut model = SendModel::default();
+
+    for (step, event) in trace.iter().enumerate() {
+        let phase = model.phase();
+        match *event {
+            SendEvent::Begin { tid, len } => {
+                let tid = TRANSFER_IDS[tid];
+                let actual = session
+                    .begin_send(tid, payload(len), "f.bin", None)
+                    .map(|o| (o.transfer_id, o.size, o.total_chunks, o.chunk_size));
+                let expected = if phase == Phase::Idle && len > 0 {
+                    model.tid = tid.to_string();
+                    model.len = len;
+                    model.yielded = 0;
+                    model.set(Phase::Offered);
+                    Ok((
+                        tid.to_string(),
+                        len as u64,
+                        model.total() as u32,
+                        CHUNK_SIZE as u32,
+                    ))
+                } else {
+                    Err(INVALID)
+                };
+                compare(step, actual, expected)?;
+            }
+            SendEvent::Accept(tid) => {
+                let tid = TRANSFER_IDS[tid];
+                let expected = if phase == Phase::Offered && model.tid == tid {
+                    model.yielded = 0;
+                    model.set(Phase::Transferring);
+                    Ok(())
+                } else {
+                    Err(INVALID)
+                };
+                compare(step, session.on_accept(tid), expected)?;
+            }
+            SendEvent::Pause(tid) => {
+                let tid = TRANSFER_IDS[tid];
+                let expected = match phase {
+                    Phase::Transferring if model.tid == tid => {
+                        model.set(Phase::Paused);
+                        Ok(())
+                    }
+                    // Idempotent, whatever the id.
+                    Phase::Paused => Ok(()),
+                    _ => Err(INVALID),
+                };
+                compare(step, session.on_pause(tid), expected)?;
+            }
+            SendEvent::Resume(tid) => {
+                let tid = TRANSFER_IDS[tid];
+                let expected = match phase {
+                    Phase::Paused if model.tid == tid => {
+                        model.set(Phase::Transferring);
+                        Ok(())
+                    }
+                    Phase::Transferring => Ok(()),
+                    _ => Err(INVALID),
+                };
+                compare(step, session.on_resume(tid), expected)?;
+            }
+            SendEvent::NextChunk => {
+                let actual = session
+                    .next_chunk()
+                    .map(|c| c.map(|c| (c.transfer_id, c.chunk_index, c.total_chunks, c.data)));
+                let expected = match phase {
+                    Phase::Transferring if model.yielded < model.total() => {
+                        let start = model.yielded * CHUNK_SIZE;
+                        let end = (start + CHUNK_SIZE).min(model.len);
+                        let data = payload(model.len)[start..end].to_vec();
+                        let chunk = (
+                            model.tid.clone(),
+                            model.yielded as u32,
+                            model.total() as u32,
+                            data,
+                        );
+                        model.yielded += 1;
+                        Ok(Some(chunk))
+                    }
+                    Phase::Transferring => Ok(None),
+                    _ => Err(INVALID),
+                };
+                compare(step, actual, expected)?;
+            }
+            SendEvent::Finish => {
+                let expected = if phase == Phase::Transferring && model.yielded == model.total() {
+                    model.set(Phase::Completed);
+                    Ok(model.tid.clone())
+                } else {
+                    Err(INVALID)
+                };
+                compare(step, session.finish(), expected)?;
+            }
+            SendEvent::Cancel(tid) => {
+                let tid = TRANSFER_IDS[tid];
+                let expected = if model.active_for(tid) {
+                    // `on_cancel` handles the receiver's CANCEL.
+                    model.set(Phase::Cancelled(CancelReason::ByReceiver));
+                    Ok(())
+                } else {
+                    Err(INVALID)
+                };
+                compare(step, session.on_cancel(tid), expected)?;
+            }
+        }
+
+        let expected_state = model_state(&model.phase(), &model.tid);
+        if session.state() != &expected_state {
+            return Err(TestCaseError::fail(format!(
+                "step {step}: state {:?}, model {expected_state:?}",
+                session.state()
+            )));
+        }
+        let active = model.phase() == Phase::Transferring && model.yielded < model.total();
+        if session.is_send_active() != active {
+            return Err(TestCaseError::fail(format!(
+                "step {step}: is_send_active != {active}"
+            )));
+        }
+    }
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(CASES))]
+
+    /// SendSession agrees with the §9 model on every random trace.
+    #[test]
+    fn model_send_session_matches_reference(trace in vec(send_event(), 0..=MAX_TRACE)) {
+        send_session_property(&trace)?;
+    }
+}
+
+// ── Receive side ────────────────────────────────────────────────
+
+const OFFER_SIZES: [u64; 6] = [0, 1, 7, 64, MAX_TRANSFER_BYTES, MAX_TRANSFER_BYTES + 1];
+
+#[derive(Debug, Clone, Copy)]
+enum RecvEvent {
+    Offer {
+        tid: usize,
+        size: u64,
+        chunks: u32,
+        /// Expected length encoded in the offered hash (see `LenVerifier`).
+        hash: Option<usize>,
+    },
+    Accept,
+    Reject,
+    Chunk {
+        tid: usize,
+        index: u32,
+        len: usize,
+    },
+    Finish {
+        tid: usize,
+        verify: bool,
+    },
+    Cancel(usize),
+}
+
+fn recv_event() -> impl Strategy<Value = RecvEvent> {
+    let tid = 0..TRANSFER_IDS.len();
+    prop_oneof![
+        1 => (
+            tid.clone(),
+            proptest::sample::select(&OFFER_SIZES[..]),
+            0..5u32,
+            proptest::option::of(0..13usize),
+        )
+            .prop_map(|(tid, size, chunks, hash)| RecvEvent::Offer {
+                tid,
+                size,
+                chunks,
+                hash,
+            }),
+        1 => Just(RecvEvent::Accept),
+        1 => Just(RecvEvent::Reject),
+        3 => (tid.clone(), 0..5u32, 0..5usize)
+            .prop_map(|(tid, index, len)| RecvEvent::Chunk { tid, index, len }),
+        1 => (tid.clone(), any::<bool>()).prop_map(|(tid, verify)| RecvEvent::Finish { tid, verify }),
+        1 => tid.prop_map(RecvEvent::Cancel),
+    ]
+}
+
+/// Deterministic stand-in for a hash: the offer carries `len:<n>` and
+/// verification passes iff the reassembled buffer is `n` bytes long.
+struct LenVerifier;
+
+impl IntegrityVerifier for LenVerifier {
+    fn verify(&self, data: &[u8], expected_hash: &str) -> bool {
+        expected_hash == format!("len:{}", data.len())
+    }
+}
+
+#[derive(Default)]
+struct RecvModel {
+    phase: Phase,
+    tid: String,
+    total: u32,
+    next: u32,
+    buffer: Vec<u8>,
+    hash: Option<String>,
+}
+
+impl RecvModel {
+    fn phase(&self) -> Phase {
+        self.phase.clone()
+    }
+
+    fn transferring(&self, tid: &str) -> bool {
+        self.phase() == Phase::Transferring && self.tid == tid
+    }
+
+    fn set(&mut self, next: Phase) {
+        let (from, to) = (
+            model_state(&self.phase, &self.tid),
+            model_state(&next, &self.tid),
+        );
+        assert!(is_section9_transition(&from, &to), "model left §9");
+        self.phase = next;
+    }
+}
+
+fn receive_session_property(trace: &[RecvEvent]) -> Result<(), TestCaseError> {
+    let mut session = ReceiveSession::new();
+    let mut model = RecvModel::default();
+
+    for (step, event) in trace.iter().enumerate() {
+        let phase = model.phase();
+        match *event {
+            RecvEvent::Offer {
+                tid,
+                size,
+                chunks,
+                hash,
+            } => {
+                let tid = TRANSFER_IDS[tid];
+                let hash = hash.map(|n| format!("len:{n}"));
+                let actual = session.on_file_offer(tid, size, chunks, hash.as_deref());
+                // Offer fields are validated before the state.
+                let valid = size > 0 && chunks > 0 && size <= MAX_TRANSFER_BYTES;
+                let expected = if valid && phase == Phase::Idle {
+                    model.tid = tid.to_string();
+                    model.total = chunks;
+                    model.hash = hash;
+                    model.set(Phase::Offered);
+                    Ok(())
+                } else {
+                    Err(INVALID)
+                };
+                compare(step, actual, expected)?;
+            }
+            RecvEvent::Accept => {
+                // The receiver passes through Accepted atomically.
+                let expected = if phase == Phase::Offered {
+                    model.next = 0;
+                    model.buffer.clear();
+                    model.set(Phase::Transferring);
+                    Ok(model.tid.clone())
+                } else {
+                    Err(INVALID)
+                };
+                compare(step, session.accept_current_offer(), expected)?;
+            }
+            RecvEvent::Reject => {
+                let expected = if phase == Phase::Offered {
+                    model.set(Phase::Cancelled(CancelReason::Rejected));
+                    Ok(model.tid.clone())
+                } else {
+                    Err(INVALID)
+                };
+                compare(step, session.reject_current_offer(), expected)?;
+            }
+            RecvEvent::Chunk { tid, index, len } => {
+                let tid = TRANSFER_IDS[tid];
+                let data = vec![index as u8; len];
+                let expected =
+                    if model.transferring(tid) && index < model.total && index == model.next {
+                        model.next += 1;
+                        model.buffer.extend_from_slice(&data);
+                        Ok(())
+                    } else {
+                        Err(INVALID)
+                    };
+                compare(step, session.on_file_chunk(tid, index, &data), expected)?;
+            }
+            RecvEvent::Finish { tid, verify } => {
+                let tid = TRANSFER_IDS[tid];
+                let verifier: Option<&dyn IntegrityVerifier> = verify.then_some(&LenVerifier);
+                let mismatch = match (&model.hash, verifier) {
+                    (Some(hash), Some(v)) => !v.verify(&model.buffer, hash),
+                    _ => false,
+                };
+                let expected = if !model.transferring(tid) {
+                    Err(INVALID)
+                } else if mismatch {
+                    Err("INTEGRITY_FAILED")
+                } else {
+                    model.set(Phase::Completed);
+                    Ok(())
+                };
+                compare(step, session.on_file_finish(tid, verifier), expected)?;
+            }
+            RecvEvent::Cancel(tid) => {
+                let tid = TRANSFER_IDS[tid];
+                let expected = if model.transferring(tid) {
+                    model.set(Phase::Cancelled(CancelReason::ByReceiver));
+                    Ok(())
+                } else {
+                    Err(INVALID)
+                };
+                compare(step, session.cancel(tid), expected)?;
+            }
+        }
+
+        let expected_state = model_state(&model.phase(), &model.tid);
+        if session.state() != &expected_state {
+            return Err(TestCaseError::fail(format!(
+                "step {step}: state {:?}, model {expected_state:?}",
+                session.state()
+            )));
+        }
+        let completed = model.phase() == Phase::Completed;
+        let expected_bytes = completed.then_some(model.buffer.as_slice());
+        if session.completed_bytes() != expected_bytes {
+            return Err(TestCaseError::fail(format!(
+                "step {step}: completed_bytes diverged"
+            )));
+        }
+        if session.hash_verified() != (completed && model.hash.is_some()) {
+            return Err(TestCaseError::fail(format!(
+                "step {step}: hash_verified diverged"
+            )));
+        }
+    }
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(CASES))]
+
+    /// ReceiveSession agrees with the §9 model on every random trace.
+    #[test]
+    fn model_receive_session_matches_reference(trace in vec(recv_event(), 0..=MAX_TRACE)) {
+        receive_session_property(&trace)?;
+    }
+}
@@ -1,8 +1,8 @@
 //! Conformance: Wire Error Code Registry (PROTOCOL.md §10 + §16.7)
 //!
-//! Asserts the canonical 26-code registry exists in Rust bolt-core and
+//! Asserts the canonical 32-code registry exists in Rust bolt-core and
 //! matches the expected exact list and order from PROTOCOL.md §10
-//! (11 PROTOCOL + 11 ENFORCEMENT + 4 BTR).
+//! (11 PROTOCOL + 11 ENFORCEMENT + 4 BTR + 2 PAKE + 2 ROTATION + 2 PQ).
 //!
 //! TS parity: `ts/bolt-core/__tests__/wire-error-codes.test.ts` asserts
 //! the same code list in TypeScript.
@@ -12,7 +12,7 @@ use bolt_core::errors::{is_valid_wire_error_code, WIRE_ERROR_CODES};
 /// Exact list and order must match PROTOCOL.md §10 + §16.7.
 #[test]
 fn conformance_wire_error_registry_exact_list() {
-    let expected: [&str; 26] = [
+    let expected: [&str; 32] = [
         // PROTOCOL class (11)
         "VERSION_MISMATCH",
         "ENCRYPTION_FAILED",
@@ -42,6 +42,15 @@ fn conformance_wire_error_registry_exact_list() {
         "RATCHET_CHAIN_ERROR",
         "RATCHET_DECRYPT_FAIL",
         "RATCHET_DOWNGRADE_REJECTED",
+        // PAKE class (2)
+        "PAKE_FAILED",
+        "PAKE_CONFIRM_FAILED",
+        // ROTATION class (2)
+        "ROTATION_INVALID",
+        "ROTATION_EXPIRED",
+        // PQ class (2)
+        "PQ_HANDSHAKE_FAILED",
+        "PQ_DOWNGRADE_REJECTED",
     ];
 
     assert_eq!(
@@ -50,10 +59,10 @@ fn conformance_wire_error_registry_exact_list() {
     );
 }
 
-/// Length must be exactly 26 (11 PROTOCOL + 11 ENFORCEMENT + 4 BTR).
+/// Length must be exactly 32 (11 PROTOCOL + 11 ENFORCEMENT + 4 BTR + 2 PAKE + 2 ROTATION + 2 PQ).
 #[test]
 fn conformance_wire_error_registry_length() {
-    assert_eq!(WIRE_ERROR_CODES.len(), 26);
+    assert_eq!(WIRE_ERROR_CODES.len(), 32);
 }
 
 /// All codes must be unique.
@@ -65,7 +74,7 @@ fn conformance_wire_error_registry_unique() {
     }
 }
 
-/// is_valid_wire_error_code accepts all 26 canonical codes.
+/// is_valid_wire_error_code accepts all 32 canonical codes.
 #[test]
 fn conformance_wire_error_validator_accepts_all() {
     for code in &WIRE_ERROR_CODES {
@@ -0,0 +1,125 @@
+#![cfg(feature = "vectors")]
+//! Pairing payload golden vector tests.
+//!
+//! Loads pairing.vectors.json from `test-vectors/core/` (Rust canonical)
+//! and verifies the fingerprint, URI encoding, URI parsing, pairing proof,
+//! and rejection of every invalid URI.
+
+use bolt_core::encoding::{from_hex, to_hex};
+use bolt_core::pairing::{
+    compute_pairing_proof, identity_fingerprint, verify_pairing_proof, PairingPayload,
+};
+use serde::Deserialize;
+use std::path::PathBuf;
+
+#[derive(Deserialize)]
+struct PairingVectors {
+    version: u32,
+    cases: Vec<PairingCase>,
+    invalid_uris: Vec<PairingInvalidUri>,
+}
+
+#[derive(Deserialize)]
+struct PairingCase {
+    name: String,
+    peer_code: String,
+    identity_public_hex: String,
+    secret_hex: String,
+    expected_fingerprint_hex: String,
+    expected_uri: String,
+    identity_a_hex: String,
+    identity_b_hex: String,
+    ephemeral_a_hex: String,
+    ephemeral_b_hex: String,
+    expected_proof_hex: String,
+}
+
+#[derive(Deserialize)]
+struct PairingInvalidUri {
+    name: String,
+    uri: String,
+}
+
+fn load() -> PairingVectors {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test-vectors")
+        .join("core")
+        .join("pairing.vectors.json");
+    let data = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("failed to read {}: {}", path.display(), e));
+    serde_json::from_str(&data).expect("pairing vectors failed to parse")
+}
+
+fn hex_to<const N: usize>(hex: &str) -> [u8; N] {
+    let bytes = from_hex(hex).expect("invalid hex");
+    bytes.try_into().expect("unexpected length")
+}
+
+#[test]
+fn pairing_golden_vectors_from_file() {
+    let vecs = load();
+    assert_eq!(vecs.version, 1);
+    assert!(vecs.cases.len() >= 3, "expected at least 3 pairing cases");
+
+    for case in &vecs.cases {
+        let identity: [u8; 32] = hex_to(&case.identity_public_hex);
+        let secret: [u8; 16] = hex_to(&case.secret_hex);
+
+        assert_eq!(
+            to_hex(&identity_fingerprint(&identity)),
+            case.expected_fingerprint_hex,
+            "fingerprint mismatch for case '{}'",
+            case.name
+        );
+
+        let parsed = PairingPayload::parse(&case.expected_uri)
+            .unwrap_or_else(|e| panic!("case '{}' URI must parse: {e}", case.name));
+        assert!(parsed.matches_identity(&identity), "case '{}'", case.name);
+        assert_eq!(
+            parsed.peer_code,
+            case.peer_code.to_uppercase().replace('-', ""),
+            "peer code mismatch for '{}'",
+            case.name
+        );
+        assert_eq!(
+            parsed.to_uri(),
+            case.expected_uri,
+            "URI mismatch for case '{}'",
+            case.name
+        );
+
+        let (ia, ib): ([u8; 32], [u8; 32]) =
+            (hex_to(&case.identity_a_hex), hex_to(&case.identity_b_hex));
+        let (ea, eb): ([u8; 32], [u8; 32]) =
+            (hex_to(&case.ephemeral_a_hex), hex_to(&case.ephemeral_b_hex));
+        let proof = compute_pairing_proof(&secret, &ia, &ib, &ea, &eb);
+        assert_eq!(
+            to_hex(&proof),
+            case.expected_proof_hex,
+            "proof mismatch for case '{}'",
+            case.name
+        );
+        assert!(verify_pairing_proof(&secret, &ib, &ia, &eb, &ea, &proof));
+        // The parsed payload carries the same secret.
+        assert_eq!(
+            parsed.prove(&ia, &ib, &ea, &eb),
+            proof,
+            "parsed secret mismatch for '{}'",
+            case.name
+        );
+    }
+}
+
+#[test]
+fn pairing_invalid_uris_rejected() {
+    let vecs = load();
+    assert!(!vecs.invalid_uris.is_empty());
+    for case in &vecs.invalid_uris {
+        assert!(
+            PairingPayload::parse(&case.uri).is_err(),
+            "invalid URI case '{}' must be rejected: {}",
+            case.name,
+            case.uri
+        );
+    }
+}
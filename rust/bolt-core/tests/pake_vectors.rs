@@ -0,0 +1,136 @@
+#![cfg(feature = "vectors")]
+//! PAKE golden vector tests.
+//!
+//! Loads pake.vectors.json from `test-vectors/core/` (Rust canonical)
+//! and verifies that `PakeSession` reproduces every share, confirmation,
+//! agreed key, and failure code.
+
+use bolt_core::encoding::{from_hex, to_hex};
+use bolt_core::pake::{PakeRole, PakeSession};
+use serde::Deserialize;
+use std::path::PathBuf;
+
+#[derive(Deserialize)]
+struct PakeVectors {
+    version: u32,
+    cases: Vec<PakeCase>,
+}
+
+#[derive(Deserialize)]
+struct PakeCase {
+    name: String,
+    code_initiator: String,
+    code_responder: String,
+    sid_hex: String,
+    identity_initiator_hex: String,
+    identity_responder_hex: String,
+    identity_responder_seen_hex: String,
+    seed_initiator_hex: String,
+    seed_responder_hex: String,
+    expected_share_initiator_hex: String,
+    expected_share_responder_hex: String,
+    expected_confirm_initiator_hex: String,
+    expected_confirm_responder_hex: String,
+    expected_key_hex: Option<String>,
+    expected_error: Option<String>,
+}
+
+fn vectors_dir() -> PathBuf {
+    let manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    manifest.join("test-vectors").join("core")
+}
+
+fn hex_to<const N: usize>(hex: &str) -> [u8; N] {
+    let bytes = from_hex(hex).expect("invalid hex");
+    bytes.try_into().expect("unexpected length")
+}
+
+#[test]
+fn pake_golden_vectors_from_file() {
+    let path = vectors_dir().join("pake.vectors.json");
+    let data = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("failed to read {}: {}", path.display(), e));
+    let vecs: PakeVectors = serde_json::from_str(&data).expect("pake vectors failed to parse");
+
+    assert_eq!(vecs.version, 1);
+    assert!(vecs.cases.len() >= 4, "expected at least 4 PAKE cases");
+
+    for case in &vecs.cases {
+        let sid = from_hex(&case.sid_hex).unwrap();
+        let id_i: [u8; 32] = hex_to(&case.identity_initiator_hex);
+        let id_r: [u8; 32] = hex_to(&case.identity_responder_hex);
+        let id_r_seen: [u8; 32] = hex_to(&case.identity_responder_seen_hex);
+        let seed_i: [u8; 64] = hex_to(&case.seed_initiator_hex);
+        let seed_r: [u8; 64] = hex_to(&case.seed_responder_hex);
+
+        let init = PakeSession::start_with_seed(
+            PakeRole::Initiator,
+            &case.code_initiator,
+            &sid,
+            &id_i,
+            &seed_i,
+        )
+        .unwrap();
+        let resp = PakeSession::start_with_seed(
+            PakeRole::Responder,
+            &case.code_responder,
+            &sid,
+            &id_r,
+            &seed_r,
+        )
+        .unwrap();
+
+        let (share_i, share_r) = (init.share(), resp.share());
+        assert_eq!(
+            to_hex(&share_i),
+            case.expected_share_initiator_hex,
+            "initiator share mismatch for case '{}'",
+            case.name
+        );
+        assert_eq!(
+            to_hex(&share_r),
+            case.expected_share_responder_hex,
+            "responder share mismatch for case '{}'",
+            case.name
+        );
+
+        let keys_i = init.finish(&share_r, &id_r_seen).unwrap();
+        let keys_r = resp.finish(&share_i, &id_i).unwrap();
+        let (conf_i, conf_r) = (keys_i.confirmation(), keys_r.confirmation());
+        assert_eq!(
+            to_hex(&conf_i),
+            case.expected_confirm_initiator_hex,
+            "initiator confirmation mismatch for case '{}'",
+            case.name
+        );
+        assert_eq!(
+            to_hex(&conf_r),
+            case.expected_confirm_responder_hex,
+            "responder confirmation mismatch for case '{}'",
+            case.name
+        );
+
+        match (&case.expected_key_hex, &case.expected_error) {
+            (Some(key_hex), None) => {
+                let out_i = keys_i.verify(&conf_r).unwrap();
+                let out_r = keys_r.verify(&conf_i).unwrap();
+                assert_eq!(
+                    &to_hex(&out_i.key),
+                    key_hex,
+                    "key mismatch for '{}'",
+                    case.name
+                );
+                assert_eq!(out_i.key, out_r.key);
+                assert_eq!(out_i.remote_identity_public_key, id_r);
+                assert_eq!(out_r.remote_identity_public_key, id_i);
+            }
+            (None, Some(code)) => {
+                let err_i = keys_i.verify(&conf_r).err().expect("initiator must reject");
+                let err_r = keys_r.verify(&conf_i).err().expect("responder must reject");
+                assert_eq!(err_i.code(), code, "error mismatch for '{}'", case.name);
+                assert_eq!(err_r.code(), code, "error mismatch for '{}'", case.name);
+            }
+            _ => panic!("case '{}' must set exactly one of key/error", case.name),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+#![cfg(feature = "vectors")]
+//! Identity rotation golden vector tests.
+//!
+//! Loads rotation.vectors.json from `test-vectors/core/` (Rust canonical)
+//! and verifies that signing reproduces each statement and that
+//! `verify_rotation` returns the expected key or error code.
+
+use bolt_core::crypto::KeyPair;
+use bolt_core::encoding::{from_hex, to_hex};
+use bolt_core::rotation::{verify_rotation, RotationStatement};
+use serde::Deserialize;
+use std::path::PathBuf;
+
+#[derive(Deserialize)]
+struct RotationVectors {
+    version: u32,
+    cases: Vec<RotationCase>,
+}
+
+#[derive(Deserialize)]
+struct RotationCase {
+    name: String,
+    old_secret_hex: String,
+    new_secret_hex: String,
+    old_nonce_hex: String,
+    new_nonce_hex: String,
+    issued_at_ms: u64,
+    validity_ms: u64,
+    signed_statement_hex: String,
+    statement_hex: String,
+    pinned_hex: String,
+    now_ms: u64,
+    expected_new_identity_hex: Option<String>,
+    expected_error: Option<String>,
+}
+
+fn vectors_dir() -> PathBuf {
+    let manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    manifest.join("test-vectors").join("core")
+}
+
+fn hex_to<const N: usize>(hex: &str) -> [u8; N] {
+    let bytes = from_hex(hex).expect("invalid hex");
+    bytes.try_into().expect("unexpected length")
+}
+
+fn keypair(secret_hex: &str) -> KeyPair {
+    let secret_key: [u8; 32] = hex_to(secret_hex);
+    let public_key = *crypto_box::SecretKey::from(secret_key)
+        .public_key()
+        .as_bytes();
+    KeyPair {
+        public_key,
+        secret_key,
+    }
+}
+
+#[test]
+fn rotation_golden_vectors_from_file() {
+    let path = vectors_dir().join("rotation.vectors.json");
+    let data = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("failed to read {}: {}", path.display(), e));
+    let vecs: RotationVectors =
+        serde_json::from_str(&data).expect("rotation vectors failed to parse");
+
+    assert_eq!(vecs.version, 1);
+    assert!(vecs.cases.len() >= 4, "expected at least 4 rotation cases");
+
+    for case in &vecs.cases {
+        let old = keypair(&case.old_secret_hex);
+        let new = keypair(&case.new_secret_hex);
+        let signed = RotationStatement::sign_with_nonces(
+            &old,
+            &new,
+            case.issued_at_ms,
+            case.validity_ms,
+            &hex_to(&case.old_nonce_hex),
+            &hex_to(&case.new_nonce_hex),
+        )
+        .unwrap();
+        assert_eq!(
+            to_hex(&signed.to_bytes()),
+            case.signed_statement_hex,
+            "signed statement mismatch for case '{}'",
+            case.name
+        );
+
+        let presented = from_hex(&case.statement_hex).unwrap();
+        let pinned: [u8; 32] = hex_to(&case.pinned_hex);
+        let result = RotationStatement::from_bytes(&presented)
+            .and_then(|stmt| verify_rotation(&stmt, &pinned, case.now_ms));
+
+        match (&case.expected_new_identity_hex, &case.expected_error) {
+            (Some(key_hex), None) => {
+                let key = result.unwrap_or_else(|e| panic!("case '{}': {e}", case.name));
+                assert_eq!(&to_hex(&key), key_hex, "key mismatch for '{}'", case.name);
+                assert_eq!(key, new.public_key);
+            }
+            (None, Some(code)) => {
+                let err = result.expect_err(&case.name);
+                assert_eq!(err.code(), code, "error mismatch for '{}'", case.name);
+            }
+            _ => panic!("case '{}' must set exactly one of key/error", case.name),
+        }
+    }
+}
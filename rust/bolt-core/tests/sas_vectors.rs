@@ -23,6 +23,10 @@ struct SasCase {
     ephemeral_a_hex: String,
     ephemeral_b_hex: String,
     expected_sas: String,
+    expected_sas_words: String,
+    expected_sas_emoji: String,
+    expected_sas_emoji_names: Vec<String>,
+    expected_sas_decimal: String,
 }
 
 fn vectors_dir() -> PathBuf {
@@ -72,6 +76,55 @@ fn sas_golden_vectors_from_file() {
     }
 }
 
+#[test]
+fn sas_encoded_golden_vectors_from_file() {
+    use bolt_core::sas::{compute_sas_emoji_names, compute_sas_encoded, SasEncoding};
+
+    let path = vectors_dir().join("sas.vectors.json");
+    let data = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("failed to read {}: {}", path.display(), e));
+    let vecs: SasVectors = serde_json::from_str(&data).expect("sas vectors failed to parse");
+
+    for case in &vecs.cases {
+        let id_a = hex_to_32(&case.identity_a_hex);
+        let id_b = hex_to_32(&case.identity_b_hex);
+        let eph_a = hex_to_32(&case.ephemeral_a_hex);
+        let eph_b = hex_to_32(&case.ephemeral_b_hex);
+
+        let expected = [
+            (SasEncoding::Hex, &case.expected_sas),
+            (SasEncoding::Words, &case.expected_sas_words),
+            (SasEncoding::Emoji, &case.expected_sas_emoji),
+            (SasEncoding::Decimal, &case.expected_sas_decimal),
+        ];
+        for (encoding, want) in expected {
+            let got = compute_sas_encoded(&id_a, &id_b, &eph_a, &eph_b, encoding);
+            assert_eq!(
+                &got,
+                want,
+                "{} SAS mismatch for case '{}'",
+                encoding.as_str(),
+                case.name
+            );
+            let got_rev = compute_sas_encoded(&id_b, &id_a, &eph_b, &eph_a, encoding);
+            assert_eq!(
+                &got_rev,
+                want,
+                "{} SAS symmetry failed for case '{}'",
+                encoding.as_str(),
+                case.name
+            );
+        }
+
+        let names = compute_sas_emoji_names(&id_a, &id_b, &eph_a, &eph_b);
+        assert_eq!(
+            names, case.expected_sas_emoji_names,
+            "emoji names mismatch for case '{}'",
+            case.name
+        );
+    }
+}
+
 #[test]
 fn sas_vector_file_exists_and_not_empty() {
     let path = vectors_dir().join("sas.vectors.json");
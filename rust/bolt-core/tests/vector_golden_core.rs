@@ -75,12 +75,33 @@ fn generate_envelope_open_vectors() {
     );
 }
 
+#[test]
+fn generate_pake_vectors() {
+    write_and_verify("pake.vectors.json", vectors::generate_pake_json);
+}
+
+#[test]
+fn generate_rotation_vectors() {
+    write_and_verify("rotation.vectors.json", vectors::generate_rotation_json);
+}
+
+#[test]
+fn generate_pairing_vectors() {
+    write_and_verify("pairing.vectors.json", vectors::generate_pairing_json);
+}
+
+/// A vector file name and the generator that writes it.
+type Generator = (&'static str, fn() -> String);
+
 /// Generates all vector files and then verifies they all exist.
 #[test]
 fn all_core_vector_files_present() {
     let dir = vector_dir();
-    let generators: Vec<(&str, fn() -> String)> = vec![
-        ("box-payload.vectors.json", vectors::generate_box_payload_json),
+    let generators: Vec<Generator> = vec![
+        (
+            "box-payload.vectors.json",
+            vectors::generate_box_payload_json,
+        ),
         ("framing.vectors.json", vectors::generate_framing_json),
         ("sas.vectors.json", vectors::generate_sas_json),
         (
@@ -91,6 +112,9 @@ fn all_core_vector_files_present() {
             "envelope-open.vectors.json",
             vectors::generate_envelope_open_json,
         ),
+        ("pake.vectors.json", vectors::generate_pake_json),
+        ("rotation.vectors.json", vectors::generate_rotation_json),
+        ("pairing.vectors.json", vectors::generate_pairing_json),
     ];
     for (filename, gen) in &generators {
         let path = dir.join(filename);
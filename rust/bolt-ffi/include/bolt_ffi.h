@@ -0,0 +1,407 @@
+/*
+ * bolt-ffi C ABI. Conventions (see src/c_api.rs):
+ *  - Fallible calls return BoltStatus; on BOLT_STATUS_ERROR read
+ *    bolt_last_error_code() (canonical wire code) on the same thread.
+ *  - Handles are released with the matching *_free; *_free(NULL) is a no-op.
+ *  - Keys are 32 bytes, BTR transfer IDs 16 bytes. (ptr, len) buffers may
+ *    pass NULL only when len == 0. Strings are NUL-terminated UTF-8.
+ *  - char* outputs: bolt_string_free. BoltBuffer outputs: bolt_buffer_free.
+ *  - Panics do not abort or unwind: the call fails (ERROR / NULL / false /
+ *    0) with last error INVALID_STATE. Free any handle it was given.
+ */
+
+#ifndef BOLT_FFI_H
+#define BOLT_FFI_H
+
+/* Generated by cbindgen from bolt-ffi/src/c_api.rs. Do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Result of a fallible call.
+typedef enum {
+  // Success.
+  BOLT_STATUS_OK = 0,
+  // Failure; see `bolt_last_error_code()`.
+  BOLT_STATUS_ERROR = 1,
+  // Iteration finished (`bolt_send_session_next_chunk` only).
+  BOLT_STATUS_DONE = 2,
+} BoltStatus;
+
+// Transfer state tag (PROTOCOL.md §9).
+typedef enum {
+  BOLT_TRANSFER_STATE_IDLE = 0,
+  BOLT_TRANSFER_STATE_OFFERED = 1,
+  BOLT_TRANSFER_STATE_ACCEPTED = 2,
+  BOLT_TRANSFER_STATE_TRANSFERRING = 3,
+  BOLT_TRANSFER_STATE_PAUSED = 4,
+  BOLT_TRANSFER_STATE_COMPLETED = 5,
+  BOLT_TRANSFER_STATE_CANCELLED = 6,
+  BOLT_TRANSFER_STATE_ERROR = 7,
+} BoltTransferState;
+
+// Opaque BtrEngine handle. Session root key is zeroized on free.
+typedef struct BoltBtrEngine BoltBtrEngine;
+
+// Opaque BtrTransferContext handle. Chain key is zeroized on free.
+typedef struct BoltBtrTransferCtx BoltBtrTransferCtx;
+
+// Opaque X25519 keypair. Secret key is zeroized on free.
+typedef struct BoltKeyPair BoltKeyPair;
+
+// Opaque receive-side §9 state machine. Verifies SHA-256 on finish
+// when the offer carried an expected hash.
+typedef struct BoltReceiveSession BoltReceiveSession;
+
+// Opaque send-side §9 state machine.
+typedef struct BoltSendSession BoltSendSession;
+
+// Rust-owned byte buffer. Release with `bolt_buffer_free`.
+typedef struct {
+  uint8_t *data;
+  size_t len;
+} BoltBuffer;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Wire error code of the last failing call on this thread, or NULL.
+const char *bolt_last_error_code(void);
+
+// Diagnostic message of the last failing call on this thread, or NULL.
+// For logging only — match on `bolt_last_error_code()`.
+const char *bolt_last_error_message(void);
+
+// Free a buffer returned by this library. Contents are zeroized first.
+//
+// # Safety
+//
+// `buffer` must have been returned by this library and not freed before.
+void bolt_buffer_free(BoltBuffer buffer);
+
+// Free a string returned by this library.
+//
+// # Safety
+//
+// `s` must be NULL or have been returned by this library and not freed before.
+void bolt_string_free(char *s);
+
+// Generate an ephemeral X25519 keypair for session use.
+BoltKeyPair *bolt_keypair_generate_ephemeral(void);
+
+// Generate a persistent identity X25519 keypair.
+BoltKeyPair *bolt_keypair_generate_identity(void);
+
+// Copy the 32-byte public key into `out`.
+//
+// # Safety
+//
+// `keypair` must be a live handle; `out` must point to 32 writable bytes.
+BoltStatus bolt_keypair_public_key(BoltKeyPair *keypair, uint8_t *out);
+
+// Copy the 32-byte secret key into `out`.
+//
+// # Safety
+//
+// `keypair` must be a live handle; `out` must point to 32 writable bytes.
+BoltStatus bolt_keypair_secret_key(BoltKeyPair *keypair, uint8_t *out);
+
+// Free a keypair (secret key zeroized).
+//
+// # Safety
+//
+// `keypair` must be NULL or a live handle; it is invalid afterwards.
+void bolt_keypair_free(BoltKeyPair *keypair);
+
+// Seal plaintext with NaCl box. `out` receives base64(nonce || ciphertext).
+//
+// # Safety
+//
+// See module conventions. Keys point to 32 bytes; `out` is writable.
+BoltStatus bolt_seal_box_payload(const uint8_t *plaintext,
+                                 size_t plaintext_len,
+                                 const uint8_t *remote_public_key,
+                                 const uint8_t *sender_secret_key,
+                                 char **out);
+
+// Open a base64(nonce || ciphertext) NaCl box payload into `out`.
+//
+// # Safety
+//
+// See module conventions. Keys point to 32 bytes; `out` is writable.
+BoltStatus bolt_open_box_payload(const char *sealed,
+                                 const uint8_t *sender_public_key,
+                                 const uint8_t *receiver_secret_key,
+                                 BoltBuffer *out);
+
+// Compute the SAS string. `encoding` is NULL (hex), `"hex"`, `"words"`,
+// `"emoji"`, or `"decimal"`.
+//
+// # Safety
+//
+// See module conventions. Keys point to 32 bytes; `out` is writable.
+BoltStatus bolt_compute_sas(const uint8_t *identity_a,
+                            const uint8_t *identity_b,
+                            const uint8_t *ephemeral_a,
+                            const uint8_t *ephemeral_b,
+                            const char *encoding,
+                            char **out);
+
+// Generate a 6-character secure peer code. Free with `bolt_string_free`.
+char *bolt_generate_peer_code(void);
+
+// Validate a peer code. NULL or non-UTF-8 input is invalid.
+//
+// # Safety
+//
+// `code` must be NULL or a NUL-terminated string.
+bool bolt_is_valid_peer_code(const char *code);
+
+// SHA-256 hex digest of `data`. Returns NULL if `data` is NULL with `len > 0`.
+//
+// # Safety
+//
+// `data` must point to `len` readable bytes.
+char *bolt_sha256_hex(const uint8_t *data, size_t len);
+
+// Create a BTR engine from the 32-byte ephemeral shared secret.
+// Returns NULL (with last error set) on invalid input.
+//
+// # Safety
+//
+// `shared_secret` must point to 32 readable bytes.
+BoltBtrEngine *bolt_btr_engine_new(const uint8_t *shared_secret);
+
+// Begin a send-side transfer. Writes the local ratchet public key
+// (32 bytes) to `out_local_ratchet_pub` and the context to `out_ctx`.
+//
+// # Safety
+//
+// See module conventions. `transfer_id` points to 16 bytes.
+BoltStatus bolt_btr_engine_begin_transfer_send(BoltBtrEngine *engine,
+                                               const uint8_t *transfer_id,
+                                               const uint8_t *remote_ratchet_pub,
+                                               uint8_t *out_local_ratchet_pub,
+                                               BoltBtrTransferCtx **out_ctx);
+
+// Begin a receive-side transfer using the local ephemeral secret key.
+//
+// # Safety
+//
+// See module conventions. `transfer_id` points to 16 bytes.
+BoltStatus bolt_btr_engine_begin_transfer_receive(BoltBtrEngine *engine,
+                                                  const uint8_t *transfer_id,
+                                                  const uint8_t *remote_ratchet_pub,
+                                                  const uint8_t *local_secret_key,
+                                                  BoltBtrTransferCtx **out_ctx);
+
+// Current ratchet generation (0 for a NULL handle).
+//
+// # Safety
+//
+// `engine` must be NULL or a live handle.
+uint32_t bolt_btr_engine_ratchet_generation(const BoltBtrEngine *engine);
+
+// End the current transfer's replay tracking.
+//
+// # Safety
+//
+// `engine` must be NULL or a live handle.
+void bolt_btr_engine_end_transfer(BoltBtrEngine *engine);
+
+// Free an engine. All BTR state is zeroized.
+//
+// # Safety
+//
+// `engine` must be NULL or a live handle; it is invalid afterwards.
+void bolt_btr_engine_free(BoltBtrEngine *engine);
+
+// Seal a chunk at the next chain position.
+//
+// # Safety
+//
+// See module conventions.
+BoltStatus bolt_btr_ctx_seal_chunk(BoltBtrTransferCtx *ctx,
+                                   const uint8_t *plaintext,
+                                   size_t plaintext_len,
+                                   uint32_t *out_chain_index,
+                                   BoltBuffer *out_sealed);
+
+// Open a chunk at the expected chain position.
+//
+// # Safety
+//
+// See module conventions.
+BoltStatus bolt_btr_ctx_open_chunk(BoltBtrTransferCtx *ctx,
+                                   uint32_t expected_chain_index,
+                                   const uint8_t *sealed,
+                                   size_t sealed_len,
+                                   BoltBuffer *out_plaintext);
+
+// Current chain index (0 for a NULL handle).
+//
+// # Safety
+//
+// `ctx` must be NULL or a live handle.
+uint32_t bolt_btr_ctx_chain_index(const BoltBtrTransferCtx *ctx);
+
+// Free a transfer context. Chain state is zeroized.
+//
+// # Safety
+//
+// `ctx` must be NULL or a live handle; it is invalid afterwards.
+void bolt_btr_ctx_free(BoltBtrTransferCtx *ctx);
+
+// Create a send session in Idle.
+BoltSendSession *bolt_send_session_new(void);
+
+// Begin an outbound transfer (Idle → Offered). `file_hash` may be NULL.
+// Writes the chunk count to `out_total_chunks`.
+//
+// # Safety
+//
+// See module conventions.
+BoltStatus bolt_send_session_begin(BoltSendSession *session,
+                                   const char *transfer_id,
+                                   const uint8_t *payload,
+                                   size_t payload_len,
+                                   const char *filename,
+                                   const char *file_hash,
+                                   uint32_t *out_total_chunks);
+
+// Receiver accepted (Offered → Transferring).
+//
+// # Safety
+//
+// See module conventions.
+BoltStatus bolt_send_session_on_accept(BoltSendSession *session, const char *transfer_id);
+
+// Cancel the transfer (→ Cancelled(BySender)).
+//
+// # Safety
+//
+// See module conventions.
+BoltStatus bolt_send_session_on_cancel(BoltSendSession *session, const char *transfer_id);
+
+// Yield the next chunk. Returns `BOLT_STATUS_DONE` once all chunks have
+// been yielded (outputs untouched).
+//
+// # Safety
+//
+// See module conventions.
+BoltStatus bolt_send_session_next_chunk(BoltSendSession *session,
+                                        uint32_t *out_chunk_index,
+                                        BoltBuffer *out_data);
+
+// Finalize (Transferring → Completed).
+//
+// # Safety
+//
+// `session` must be a live handle.
+BoltStatus bolt_send_session_finish(BoltSendSession *session);
+
+// Current state tag.
+//
+// # Safety
+//
+// See module conventions.
+BoltStatus bolt_send_session_state(BoltSendSession *session, BoltTransferState *out_state);
+
+// Free a send session.
+//
+// # Safety
+//
+// `session` must be NULL or a live handle; it is invalid afterwards.
+void bolt_send_session_free(BoltSendSession *session);
+
+// Create a receive session in Idle.
+BoltReceiveSession *bolt_receive_session_new(void);
+
+// Inbound offer (Idle → Offered). `expected_hash` may be NULL.
+//
+// # Safety
+//
+// See module conventions.
+BoltStatus bolt_receive_session_on_file_offer(BoltReceiveSession *session,
+                                              const char *transfer_id,
+                                              uint64_t size,
+                                              uint32_t total_chunks,
+                                              const char *expected_hash);
+
+// Accept the pending offer (Offered → Transferring).
+//
+// # Safety
+//
+// `session` must be a live handle.
+BoltStatus bolt_receive_session_accept(BoltReceiveSession *session);
+
+// Reject the pending offer (Offered → Cancelled(Rejected)).
+//
+// # Safety
+//
+// `session` must be a live handle.
+BoltStatus bolt_receive_session_reject(BoltReceiveSession *session);
+
+// Deliver an in-order chunk.
+//
+// # Safety
+//
+// See module conventions.
+BoltStatus bolt_receive_session_on_file_chunk(BoltReceiveSession *session,
+                                              const char *transfer_id,
+                                              uint32_t chunk_index,
+                                              const uint8_t *data,
+                                              size_t data_len);
+
+// Finalize (Transferring → Completed), verifying SHA-256 if expected.
+//
+// # Safety
+//
+// See module conventions.
+BoltStatus bolt_receive_session_finish(BoltReceiveSession *session, const char *transfer_id);
+
+// Cancel (Transferring → Cancelled(ByReceiver)).
+//
+// # Safety
+//
+// See module conventions.
+BoltStatus bolt_receive_session_cancel(BoltReceiveSession *session, const char *transfer_id);
+
+// Current state tag.
+//
+// # Safety
+//
+// See module conventions.
+BoltStatus bolt_receive_session_state(BoltReceiveSession *session, BoltTransferState *out_state);
+
+// True if Completed and the file hash was verified.
+//
+// # Safety
+//
+// `session` must be NULL or a live handle.
+bool bolt_receive_session_hash_verified(const BoltReceiveSession *session);
+
+// Copy the reassembled file into `out`. Fails with `INVALID_STATE`
+// unless the session is Completed.
+//
+// # Safety
+//
+// See module conventions.
+BoltStatus bolt_receive_session_completed_bytes(BoltReceiveSession *session, BoltBuffer *out);
+
+// Free a receive session.
+//
+// # Safety
+//
+// `session` must be NULL or a live handle; it is invalid afterwards.
+void bolt_receive_session_free(BoltReceiveSession *session);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* BOLT_FFI_H */
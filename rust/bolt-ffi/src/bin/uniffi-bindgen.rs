@@ -0,0 +1,8 @@
+//! UniFFI binding generator for bolt-ffi.
+//!
+//! cargo run -p bolt-ffi --features bindgen --bin uniffi-bindgen -- \
+//!     generate --library target/debug/libbolt_ffi.so --language kotlin --out-dir out
+
+fn main() {
+    uniffi::uniffi_bindgen_main()
+}
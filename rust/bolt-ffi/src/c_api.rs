@@ -0,0 +1,1039 @@
+//! Plain C ABI over the Bolt protocol authority.
+//!
+//! Header: `include/bolt_ffi.h` (cbindgen; see `cbindgen.toml`).
+//!
+//! ## Conventions
+//!
+//! - Fallible calls return [`BoltStatus`]. On `BOLT_STATUS_ERROR` the
+//!   canonical wire code and a diagnostic message are available from
+//!   [`bolt_last_error_code`] / [`bolt_last_error_message`]. Both are
+//!   thread-local and valid until the next failing call on that thread.
+//! - Handles are created by `*_new` / `*_generate_*` / `*_begin_*` and
+//!   released by the matching `*_free`. `*_free(NULL)` is a no-op.
+//!   Handles are not internally synchronised; callers serialise access.
+//! - Key arguments are `const uint8_t *` pointing at exactly 32 bytes;
+//!   BTR transfer IDs at exactly 16 bytes. Byte buffers are `(ptr, len)`
+//!   pairs; `ptr` may be NULL only when `len == 0`.
+//! - Strings in are NUL-terminated UTF-8. Strings out are released with
+//!   [`bolt_string_free`]; byte outputs ([`BoltBuffer`]) with
+//!   [`bolt_buffer_free`], which zeroizes before freeing.
+//! - Handles owning key material (`BoltKeyPair`, `BoltBtrEngine`,
+//!   `BoltBtrTransferCtx`) zeroize it on free.
+//! - Panics never unwind into the caller. Every entry point except the
+//!   last-error accessors catches them and fails as if the call had
+//!   returned an error: `BOLT_STATUS_ERROR`,
+//!   NULL, `false` or 0, with last error `INVALID_STATE`. A handle passed
+//!   to a call that panicked should be freed, not reused.
+
+use std::cell::RefCell;
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use zeroize::{Zeroize, Zeroizing};
+
+use bolt_transfer_core::Sha256Verifier;
+
+use crate::{fixed, sas_encoding, FfiError};
+
+// ── Status / errors ───────────────────────────────────────────────
+
+/// Result of a fallible call.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoltStatus {
+    /// Success.
+    Ok = 0,
+    /// Failure; see `bolt_last_error_code()`.
+    Error = 1,
+    /// Iteration finished (`bolt_send_session_next_chunk` only).
+    Done = 2,
+}
+
+struct LastError {
+    code: CString,
+    message: CString,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<LastError>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(e: FfiError) {
+    let code = CString::new(e.code).expect("wire codes contain no NUL");
+    let message = CString::new(e.message.replace('\0', " ")).expect("NUL replaced");
+    LAST_ERROR.with(|slot| *slot.borrow_mut() = Some(LastError { code, message }));
+}
+
+fn status(result: Result<(), FfiError>) -> BoltStatus {
+    match result {
+        Ok(()) => BoltStatus::Ok,
+        Err(e) => {
+            set_last_error(e);
+            BoltStatus::Error
+        }
+    }
+}
+
+/// Run an entry point body, turning a panic into an `INVALID_STATE` error.
+fn catch<R>(f: impl FnOnce() -> Result<R, FfiError>) -> Result<R, FfiError> {
+    catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let detail = payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+            .unwrap_or("unknown panic");
+        Err(FfiError {
+            code: "INVALID_STATE",
+            message: format!("panic: {detail}"),
+        })
+    })
+}
+
+/// Unwrap for entry points without a status return: on error, set the
+/// last error and return `fallback`.
+fn or_else<T>(result: Result<T, FfiError>, fallback: T) -> T {
+    result.unwrap_or_else(|e| {
+        set_last_error(e);
+        fallback
+    })
+}
+
+/// Wire error code of the last failing call on this thread, or NULL.
+#[no_mangle]
+pub extern "C" fn bolt_last_error_code() -> *const c_char {
+    LAST_ERROR.with(|slot| {
+        slot.borrow()
+            .as_ref()
+            .map_or(std::ptr::null(), |e| e.code.as_ptr())
+    })
+}
+
+/// Diagnostic message of the last failing call on this thread, or NULL.
+/// For logging only — match on `bolt_last_error_code()`.
+#[no_mangle]
+pub extern "C" fn bolt_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|slot| {
+        slot.borrow()
+            .as_ref()
+            .map_or(std::ptr::null(), |e| e.message.as_ptr())
+    })
+}
+
+// ── Memory ────────────────────────────────────────────────────────
+
+/// Rust-owned byte buffer. Release with `bolt_buffer_free`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct BoltBuffer {
+    pub data: *mut u8,
+    pub len: usize,
+}
+
+impl BoltBuffer {
+    fn from_vec(bytes: Vec<u8>) -> Self {
+        let boxed = bytes.into_boxed_slice();
+        let len = boxed.len();
+        let data = Box::into_raw(boxed) as *mut u8;
+        Self { data, len }
+    }
+}
+
+/// Free a buffer returned by this library. Contents are zeroized first.
+///
+/// # Safety
+///
+/// `buffer` must have been returned by this library and not freed before.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_buffer_free(buffer: BoltBuffer) {
+    if buffer.data.is_null() {
+        return;
+    }
+    or_else(
+        catch(|| {
+            let mut boxed =
+                Box::from_raw(std::ptr::slice_from_raw_parts_mut(buffer.data, buffer.len));
+            boxed.zeroize();
+            Ok(())
+        }),
+        (),
+    )
+}
+
+/// Free a string returned by this library.
+///
+/// # Safety
+///
+/// `s` must be NULL or have been returned by this library and not freed before.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        or_else(
+            catch(|| {
+                drop(CString::from_raw(s));
+                Ok(())
+            }),
+            (),
+        )
+    }
+}
+
+fn into_c_string(s: String) -> *mut c_char {
+    // Outputs are hex/base64/alphabet strings — never contain NUL.
+    CString::new(s).map_or(std::ptr::null_mut(), CString::into_raw)
+}
+
+// ── Argument helpers ──────────────────────────────────────────────
+
+fn null_arg(name: &str) -> FfiError {
+    FfiError::invalid_input(&format!("{name} must not be NULL"))
+}
+
+unsafe fn array<const N: usize>(ptr: *const u8, name: &str) -> Result<[u8; N], FfiError> {
+    if ptr.is_null() {
+        return Err(null_arg(name));
+    }
+    fixed(std::slice::from_raw_parts(ptr, N), name)
+}
+
+unsafe fn bytes<'a>(ptr: *const u8, len: usize, name: &str) -> Result<&'a [u8], FfiError> {
+    if len == 0 {
+        return Ok(&[]);
+    }
+    if ptr.is_null() {
+        return Err(null_arg(name));
+    }
+    Ok(std::slice::from_raw_parts(ptr, len))
+}
+
+unsafe fn text<'a>(ptr: *const c_char, name: &str) -> Result<&'a str, FfiError> {
+    if ptr.is_null() {
+        return Err(null_arg(name));
+    }
+    CStr::from_ptr(ptr)
+        .to_str()
+        .map_err(|_| FfiError::invalid_input(&format!("{name} must be UTF-8")))
+}
+
+unsafe fn opt_text<'a>(ptr: *const c_char, name: &str) -> Result<Option<&'a str>, FfiError> {
+    if ptr.is_null() {
+        Ok(None)
+    } else {
+        text(ptr, name).map(Some)
+    }
+}
+
+unsafe fn handle<'a, T>(ptr: *mut T, name: &str) -> Result<&'a mut T, FfiError> {
+    ptr.as_mut().ok_or_else(|| null_arg(name))
+}
+
+/// Write `value()` to `out`. The value is built only after the NULL check,
+/// so owned outputs (strings, buffers) are never leaked on a NULL `out`.
+unsafe fn write_out<T>(out: *mut T, name: &str, value: impl FnOnce() -> T) -> Result<(), FfiError> {
+    if out.is_null() {
+        return Err(null_arg(name));
+    }
+    out.write(value());
+    Ok(())
+}
+
+unsafe fn free_handle<T>(ptr: *mut T) {
+    if !ptr.is_null() {
+        or_else(
+            catch(|| {
+                drop(Box::from_raw(ptr));
+                Ok(())
+            }),
+            (),
+        )
+    }
+}
+
+fn new_handle<T>(make: impl FnOnce() -> T) -> *mut T {
+    or_else(
+        catch(|| Ok(Box::into_raw(Box::new(make())))),
+        std::ptr::null_mut(),
+    )
+}
+
+// ── Key Generation ────────────────────────────────────────────────
+
+/// Opaque X25519 keypair. Secret key is zeroized on free.
+pub struct BoltKeyPair {
+    inner: bolt_core::crypto::KeyPair,
+}
+
+/// Generate an ephemeral X25519 keypair for session use.
+#[no_mangle]
+pub extern "C" fn bolt_keypair_generate_ephemeral() -> *mut BoltKeyPair {
+    new_handle(|| BoltKeyPair {
+        inner: bolt_core::crypto::generate_ephemeral_keypair(),
+    })
+}
+
+/// Generate a persistent identity X25519 keypair.
+#[no_mangle]
+pub extern "C" fn bolt_keypair_generate_identity() -> *mut BoltKeyPair {
+    new_handle(|| BoltKeyPair {
+        inner: bolt_core::identity::generate_identity_keypair(),
+    })
+}
+
+/// Copy the 32-byte public key into `out`.
+///
+/// # Safety
+///
+/// `keypair` must be a live handle; `out` must point to 32 writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_keypair_public_key(
+    keypair: *mut BoltKeyPair,
+    out: *mut u8,
+) -> BoltStatus {
+    status(catch(|| {
+        let kp = handle(keypair, "keypair")?;
+        write_out(out.cast::<[u8; 32]>(), "out", || kp.inner.public_key)
+    }))
+}
+
+/// Copy the 32-byte secret key into `out`.
+///
+/// # Safety
+///
+/// `keypair` must be a live handle; `out` must point to 32 writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_keypair_secret_key(
+    keypair: *mut BoltKeyPair,
+    out: *mut u8,
+) -> BoltStatus {
+    status(catch(|| {
+        let kp = handle(keypair, "keypair")?;
+        write_out(out.cast::<[u8; 32]>(), "out", || kp.inner.secret_key)
+    }))
+}
+
+/// Free a keypair (secret key zeroized).
+///
+/// # Safety
+///
+/// `keypair` must be NULL or a live handle; it is invalid afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_keypair_free(keypair: *mut BoltKeyPair) {
+    free_handle(keypair);
+}
+
+// ── NaCl Box (XSalsa20-Poly1305) ─────────────────────────────────
+
+/// Seal plaintext with NaCl box. `out` receives base64(nonce || ciphertext).
+///
+/// # Safety
+///
+/// See module conventions. Keys point to 32 bytes; `out` is writable.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_seal_box_payload(
+    plaintext: *const u8,
+    plaintext_len: usize,
+    remote_public_key: *const u8,
+    sender_secret_key: *const u8,
+    out: *mut *mut c_char,
+) -> BoltStatus {
+    status(catch(|| {
+        let plaintext = bytes(plaintext, plaintext_len, "plaintext")?;
+        let rpk: [u8; 32] = array(remote_public_key, "remote_public_key")?;
+        let ssk: [u8; 32] = array(sender_secret_key, "sender_secret_key")?;
+        let sealed = bolt_core::crypto::seal_box_payload(plaintext, &rpk, &ssk)?;
+        write_out(out, "out", || into_c_string(sealed))
+    }))
+}
+
+/// Open a base64(nonce || ciphertext) NaCl box payload into `out`.
+///
+/// # Safety
+///
+/// See module conventions. Keys point to 32 bytes; `out` is writable.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_open_box_payload(
+    sealed: *const c_char,
+    sender_public_key: *const u8,
+    receiver_secret_key: *const u8,
+    out: *mut BoltBuffer,
+) -> BoltStatus {
+    status(catch(|| {
+        let sealed = text(sealed, "sealed")?;
+        let spk: [u8; 32] = array(sender_public_key, "sender_public_key")?;
+        let rsk: [u8; 32] = array(receiver_secret_key, "receiver_secret_key")?;
+        let opened = bolt_core::crypto::open_box_payload(sealed, &spk, &rsk)?;
+        write_out(out, "out", || BoltBuffer::from_vec(opened))
+    }))
+}
+
+// ── SAS / Peer Code / Hashing ─────────────────────────────────────
+
+/// Compute the SAS string. `encoding` is NULL (hex), `"hex"`, `"words"`,
+/// `"emoji"`, or `"decimal"`.
+///
+/// # Safety
+///
+/// See module conventions. Keys point to 32 bytes; `out` is writable.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_compute_sas(
+    identity_a: *const u8,
+    identity_b: *const u8,
+    ephemeral_a: *const u8,
+    ephemeral_b: *const u8,
+    encoding: *const c_char,
+    out: *mut *mut c_char,
+) -> BoltStatus {
+    status(catch(|| {
+        let id_a: [u8; 32] = array(identity_a, "identity_a")?;
+        let id_b: [u8; 32] = array(identity_b, "identity_b")?;
+        let eph_a: [u8; 32] = array(ephemeral_a, "ephemeral_a")?;
+        let eph_b: [u8; 32] = array(ephemeral_b, "ephemeral_b")?;
+        let encoding = sas_encoding(opt_text(encoding, "encoding")?)?;
+        let sas = bolt_core::sas::compute_sas_encoded(&id_a, &id_b, &eph_a, &eph_b, encoding);
+        write_out(out, "out", || into_c_string(sas))
+    }))
+}
+
+/// Generate a 6-character secure peer code. Free with `bolt_string_free`.
+#[no_mangle]
+pub extern "C" fn bolt_generate_peer_code() -> *mut c_char {
+    or_else(
+        catch(|| {
+            Ok(into_c_string(
+                bolt_core::peer_code::generate_secure_peer_code(),
+            ))
+        }),
+        std::ptr::null_mut(),
+    )
+}
+
+/// Validate a peer code. NULL or non-UTF-8 input is invalid.
+///
+/// # Safety
+///
+/// `code` must be NULL or a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_is_valid_peer_code(code: *const c_char) -> bool {
+    or_else(
+        catch(|| Ok(text(code, "code").is_ok_and(bolt_core::peer_code::is_valid_peer_code))),
+        false,
+    )
+}
+
+/// SHA-256 hex digest of `data`. Returns NULL if `data` is NULL with `len > 0`.
+///
+/// # Safety
+///
+/// `data` must point to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_sha256_hex(data: *const u8, len: usize) -> *mut c_char {
+    or_else(
+        catch(|| {
+            let data = bytes(data, len, "data")?;
+            Ok(into_c_string(bolt_core::hash::sha256_hex(data)))
+        }),
+        std::ptr::null_mut(),
+    )
+}
+
+// ── BTR Engine ────────────────────────────────────────────────────
+
+/// Opaque BtrEngine handle. Session root key is zeroized on free.
+pub struct BoltBtrEngine {
+    inner: bolt_btr::BtrEngine,
+}
+
+/// Create a BTR engine from the 32-byte ephemeral shared secret.
+/// Returns NULL (with last error set) on invalid input.
+///
+/// # Safety
+///
+/// `shared_secret` must point to 32 readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_btr_engine_new(shared_secret: *const u8) -> *mut BoltBtrEngine {
+    or_else(
+        catch(|| {
+            let ss = Zeroizing::new(array::<32>(shared_secret, "shared_secret")?);
+            let engine = bolt_btr::BtrEngine::new(&ss);
+            Ok(Box::into_raw(Box::new(BoltBtrEngine { inner: engine })))
+        }),
+        std::ptr::null_mut(),
+    )
+}
+
+/// Begin a send-side transfer. Writes the local ratchet public key
+/// (32 bytes) to `out_local_ratchet_pub` and the context to `out_ctx`.
+///
+/// # Safety
+///
+/// See module conventions. `transfer_id` points to 16 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_btr_engine_begin_transfer_send(
+    engine: *mut BoltBtrEngine,
+    transfer_id: *const u8,
+    remote_ratchet_pub: *const u8,
+    out_local_ratchet_pub: *mut u8,
+    out_ctx: *mut *mut BoltBtrTransferCtx,
+) -> BoltStatus {
+    status(catch(|| {
+        let engine = handle(engine, "engine")?;
+        let tid: [u8; 16] = array(transfer_id, "transfer_id")?;
+        let rpub: [u8; 32] = array(remote_ratchet_pub, "remote_ratchet_pub")?;
+        if out_local_ratchet_pub.is_null() {
+            return Err(null_arg("out_local_ratchet_pub"));
+        }
+        if out_ctx.is_null() {
+            return Err(null_arg("out_ctx"));
+        }
+        let (ctx, local_pub) = engine.inner.begin_transfer_send(&tid, &rpub)?;
+        out_local_ratchet_pub.cast::<[u8; 32]>().write(local_pub);
+        out_ctx.write(Box::into_raw(Box::new(BoltBtrTransferCtx { inner: ctx })));
+        Ok(())
+    }))
+}
+
+/// Begin a receive-side transfer using the local ephemeral secret key.
+///
+/// # Safety
+///
+/// See module conventions. `transfer_id` points to 16 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_btr_engine_begin_transfer_receive(
+    engine: *mut BoltBtrEngine,
+    transfer_id: *const u8,
+    remote_ratchet_pub: *const u8,
+    local_secret_key: *const u8,
+    out_ctx: *mut *mut BoltBtrTransferCtx,
+) -> BoltStatus {
+    status(catch(|| {
+        let engine = handle(engine, "engine")?;
+        let tid: [u8; 16] = array(transfer_id, "transfer_id")?;
+        let rpub: [u8; 32] = array(remote_ratchet_pub, "remote_ratchet_pub")?;
+        let lsk = Zeroizing::new(array::<32>(local_secret_key, "local_secret_key")?);
+        if out_ctx.is_null() {
+            return Err(null_arg("out_ctx"));
+        }
+        let ctx = engine
+            .inner
+            .begin_transfer_receive_with_key(&tid, &rpub, &lsk);
+        out_ctx.write(Box::into_raw(Box::new(BoltBtrTransferCtx { inner: ctx? })));
+        Ok(())
+    }))
+}
+
+/// Current ratchet generation (0 for a NULL handle).
+///
+/// # Safety
+///
+/// `engine` must be NULL or a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_btr_engine_ratchet_generation(engine: *const BoltBtrEngine) -> u32 {
+    or_else(
+        catch(|| Ok(engine.as_ref().map_or(0, |e| e.inner.ratchet_generation()))),
+        0,
+    )
+}
+
+/// End the current transfer's replay tracking.
+///
+/// # Safety
+///
+/// `engine` must be NULL or a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_btr_engine_end_transfer(engine: *mut BoltBtrEngine) {
+    if let Some(e) = engine.as_mut() {
+        or_else(
+            catch(|| {
+                e.inner.end_transfer();
+                Ok(())
+            }),
+            (),
+        )
+    }
+}
+
+/// Free an engine. All BTR state is zeroized.
+///
+/// # Safety
+///
+/// `engine` must be NULL or a live handle; it is invalid afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_btr_engine_free(engine: *mut BoltBtrEngine) {
+    if let Some(e) = engine.as_mut() {
+        or_else(
+            catch(|| {
+                e.inner.cleanup_disconnect();
+                Ok(())
+            }),
+            (),
+        )
+    }
+    free_handle(engine);
+}
+
+// ── BTR Transfer Context ──────────────────────────────────────────
+
+/// Opaque BtrTransferContext handle. Chain key is zeroized on free.
+pub struct BoltBtrTransferCtx {
+    inner: bolt_btr::BtrTransferContext,
+}
+
+/// Seal a chunk at the next chain position.
+///
+/// # Safety
+///
+/// See module conventions.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_btr_ctx_seal_chunk(
+    ctx: *mut BoltBtrTransferCtx,
+    plaintext: *const u8,
+    plaintext_len: usize,
+    out_chain_index: *mut u32,
+    out_sealed: *mut BoltBuffer,
+) -> BoltStatus {
+    status(catch(|| {
+        let ctx = handle(ctx, "ctx")?;
+        let plaintext = bytes(plaintext, plaintext_len, "plaintext")?;
+        if out_chain_index.is_null() {
+            return Err(null_arg("out_chain_index"));
+        }
+        if out_sealed.is_null() {
+            return Err(null_arg("out_sealed"));
+        }
+        let (chain_index, sealed) = ctx.inner.seal_chunk(plaintext)?;
+        out_chain_index.write(chain_index);
+        out_sealed.write(BoltBuffer::from_vec(sealed));
+        Ok(())
+    }))
+}
+
+/// Open a chunk at the expected chain position.
+///
+/// # Safety
+///
+/// See module conventions.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_btr_ctx_open_chunk(
+    ctx: *mut BoltBtrTransferCtx,
+    expected_chain_index: u32,
+    sealed: *const u8,
+    sealed_len: usize,
+    out_plaintext: *mut BoltBuffer,
+) -> BoltStatus {
+    status(catch(|| {
+        let ctx = handle(ctx, "ctx")?;
+        let sealed = bytes(sealed, sealed_len, "sealed")?;
+        if out_plaintext.is_null() {
+            return Err(null_arg("out_plaintext"));
+        }
+        let opened = ctx.inner.open_chunk(expected_chain_index, sealed)?;
+        out_plaintext.write(BoltBuffer::from_vec(opened));
+        Ok(())
+    }))
+}
+
+/// Current chain index (0 for a NULL handle).
+///
+/// # Safety
+///
+/// `ctx` must be NULL or a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_btr_ctx_chain_index(ctx: *const BoltBtrTransferCtx) -> u32 {
+    or_else(
+        catch(|| Ok(ctx.as_ref().map_or(0, |c| c.inner.chain_index()))),
+        0,
+    )
+}
+
+/// Free a transfer context. Chain state is zeroized.
+///
+/// # Safety
+///
+/// `ctx` must be NULL or a live handle; it is invalid afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_btr_ctx_free(ctx: *mut BoltBtrTransferCtx) {
+    if let Some(c) = ctx.as_mut() {
+        or_else(
+            catch(|| {
+                c.inner.cleanup_complete();
+                Ok(())
+            }),
+            (),
+        )
+    }
+    free_handle(ctx);
+}
+
+// ── Transfer State (§9) ───────────────────────────────────────────
+
+/// Transfer state tag (PROTOCOL.md §9).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoltTransferState {
+    Idle = 0,
+    Offered = 1,
+    Accepted = 2,
+    Transferring = 3,
+    Paused = 4,
+    Completed = 5,
+    Cancelled = 6,
+    Error = 7,
+}
+
+impl From<&bolt_transfer_core::TransferState> for BoltTransferState {
+    fn from(state: &bolt_transfer_core::TransferState) -> Self {
+        use bolt_transfer_core::TransferState;
+
+        match state {
+            TransferState::Idle => BoltTransferState::Idle,
+            TransferState::Offered { .. } => BoltTransferState::Offered,
+            TransferState::Accepted { .. } => BoltTransferState::Accepted,
+            TransferState::Transferring { .. } => BoltTransferState::Transferring,
+            TransferState::Paused { .. } => BoltTransferState::Paused,
+            TransferState::Completed { .. } => BoltTransferState::Completed,
+            TransferState::Cancelled { .. } => BoltTransferState::Cancelled,
+            TransferState::Error { .. } => BoltTransferState::Error,
+        }
+    }
+}
+
+// ── Send Session ──────────────────────────────────────────────────
+
+/// Opaque send-side §9 state machine.
+pub struct BoltSendSession {
+    inner: bolt_transfer_core::SendSession,
+}
+
+/// Create a send session in Idle.
+#[no_mangle]
+pub extern "C" fn bolt_send_session_new() -> *mut BoltSendSession {
+    new_handle(|| BoltSendSession {
+        inner: bolt_transfer_core::SendSession::new(),
+    })
+}
+
+/// Begin an outbound transfer (Idle → Offered). `file_hash` may be NULL.
+/// Writes the chunk count to `out_total_chunks`.
+///
+/// # Safety
+///
+/// See module conventions.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_send_session_begin(
+    session: *mut BoltSendSession,
+    transfer_id: *const c_char,
+    payload: *const u8,
+    payload_len: usize,
+    filename: *const c_char,
+    file_hash: *const c_char,
+    out_total_chunks: *mut u32,
+) -> BoltStatus {
+    status(catch(|| {
+        let session = handle(session, "session")?;
+        let transfer_id = text(transfer_id, "transfer_id")?;
+        let payload = bytes(payload, payload_len, "payload")?;
+        let filename = text(filename, "filename")?;
+        let file_hash = opt_text(file_hash, "file_hash")?.map(String::from);
+        if out_total_chunks.is_null() {
+            return Err(null_arg("out_total_chunks"));
+        }
+        let offer = session
+            .inner
+            .begin_send(transfer_id, payload.to_vec(), filename, file_hash)?;
+        out_total_chunks.write(offer.total_chunks);
+        Ok(())
+    }))
+}
+
+/// Receiver accepted (Offered → Transferring).
+///
+/// # Safety
+///
+/// See module conventions.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_send_session_on_accept(
+    session: *mut BoltSendSession,
+    transfer_id: *const c_char,
+) -> BoltStatus {
+    status(catch(|| {
+        let session = handle(session, "session")?;
+        Ok(session.inner.on_accept(text(transfer_id, "transfer_id")?)?)
+    }))
+}
+
+/// Cancel the transfer (→ Cancelled(BySender)).
+///
+/// # Safety
+///
+/// See module conventions.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_send_session_on_cancel(
+    session: *mut BoltSendSession,
+    transfer_id: *const c_char,
+) -> BoltStatus {
+    status(catch(|| {
+        let session = handle(session, "session")?;
+        Ok(session.inner.on_cancel(text(transfer_id, "transfer_id")?)?)
+    }))
+}
+
+/// Yield the next chunk. Returns `BOLT_STATUS_DONE` once all chunks have
+/// been yielded (outputs untouched).
+///
+/// # Safety
+///
+/// See module conventions.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_send_session_next_chunk(
+    session: *mut BoltSendSession,
+    out_chunk_index: *mut u32,
+    out_data: *mut BoltBuffer,
+) -> BoltStatus {
+    let result = catch(|| {
+        let session = handle(session, "session")?;
+        if out_chunk_index.is_null() {
+            return Err(null_arg("out_chunk_index"));
+        }
+        if out_data.is_null() {
+            return Err(null_arg("out_data"));
+        }
+        let Some(chunk) = session.inner.next_chunk()? else {
+            return Ok(false);
+        };
+        out_chunk_index.write(chunk.chunk_index);
+        out_data.write(BoltBuffer::from_vec(chunk.data));
+        Ok(true)
+    });
+    match result {
+        Ok(true) => BoltStatus::Ok,
+        Ok(false) => BoltStatus::Done,
+        Err(e) => status(Err(e)),
+    }
+}
+
+/// Finalize (Transferring → Completed).
+///
+/// # Safety
+///
+/// `session` must be a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_send_session_finish(session: *mut BoltSendSession) -> BoltStatus {
+    status(catch(|| {
+        handle(session, "session")?.inner.finish()?;
+        Ok(())
+    }))
+}
+
+/// Current state tag.
+///
+/// # Safety
+///
+/// See module conventions.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_send_session_state(
+    session: *mut BoltSendSession,
+    out_state: *mut BoltTransferState,
+) -> BoltStatus {
+    status(catch(|| {
+        let session = handle(session, "session")?;
+        write_out(out_state, "out_state", || session.inner.state().into())
+    }))
+}
+
+/// Free a send session.
+///
+/// # Safety
+///
+/// `session` must be NULL or a live handle; it is invalid afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_send_session_free(session: *mut BoltSendSession) {
+    free_handle(session);
+}
+
+// ── Receive Session ───────────────────────────────────────────────
+
+/// Opaque receive-side §9 state machine. Verifies SHA-256 on finish
+/// when the offer carried an expected hash.
+pub struct BoltReceiveSession {
+    inner: bolt_transfer_core::ReceiveSession,
+}
+
+/// Create a receive session in Idle.
+#[no_mangle]
+pub extern "C" fn bolt_receive_session_new() -> *mut BoltReceiveSession {
+    new_handle(|| BoltReceiveSession {
+        inner: bolt_transfer_core::ReceiveSession::new(),
+    })
+}
+
+/// Inbound offer (Idle → Offered). `expected_hash` may be NULL.
+///
+/// # Safety
+///
+/// See module conventions.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_receive_session_on_file_offer(
+    session: *mut BoltReceiveSession,
+    transfer_id: *const c_char,
+    size: u64,
+    total_chunks: u32,
+    expected_hash: *const c_char,
+) -> BoltStatus {
+    status(catch(|| {
+        let session = handle(session, "session")?;
+        let transfer_id = text(transfer_id, "transfer_id")?;
+        let expected_hash = opt_text(expected_hash, "expected_hash")?;
+        Ok(session
+            .inner
+            .on_file_offer(transfer_id, size, total_chunks, expected_hash)?)
+    }))
+}
+
+/// Accept the pending offer (Offered → Transferring).
+///
+/// # Safety
+///
+/// `session` must be a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_receive_session_accept(
+    session: *mut BoltReceiveSession,
+) -> BoltStatus {
+    status(catch(|| {
+        handle(session, "session")?.inner.accept_current_offer()?;
+        Ok(())
+    }))
+}
+
+/// Reject the pending offer (Offered → Cancelled(Rejected)).
+///
+/// # Safety
+///
+/// `session` must be a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_receive_session_reject(
+    session: *mut BoltReceiveSession,
+) -> BoltStatus {
+    status(catch(|| {
+        handle(session, "session")?.inner.reject_current_offer()?;
+        Ok(())
+    }))
+}
+
+/// Deliver an in-order chunk.
+///
+/// # Safety
+///
+/// See module conventions.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_receive_session_on_file_chunk(
+    session: *mut BoltReceiveSession,
+    transfer_id: *const c_char,
+    chunk_index: u32,
+    data: *const u8,
+    data_len: usize,
+) -> BoltStatus {
+    status(catch(|| {
+        let session = handle(session, "session")?;
+        let transfer_id = text(transfer_id, "transfer_id")?;
+        let data = bytes(data, data_len, "data")?;
+        Ok(session
+            .inner
+            .on_file_chunk(transfer_id, chunk_index, data)?)
+    }))
+}
+
+/// Finalize (Transferring → Completed), verifying SHA-256 if expected.
+///
+/// # Safety
+///
+/// See module conventions.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_receive_session_finish(
+    session: *mut BoltReceiveSession,
+    transfer_id: *const c_char,
+) -> BoltStatus {
+    status(catch(|| {
+        let session = handle(session, "session")?;
+        let transfer_id = text(transfer_id, "transfer_id")?;
+        Ok(session
+            .inner
+            .on_file_finish(transfer_id, Some(&Sha256Verifier))?)
+    }))
+}
+
+/// Cancel (Transferring → Cancelled(ByReceiver)).
+///
+/// # Safety
+///
+/// See module conventions.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_receive_session_cancel(
+    session: *mut BoltReceiveSession,
+    transfer_id: *const c_char,
+) -> BoltStatus {
+    status(catch(|| {
+        let session = handle(session, "session")?;
+        Ok(session.inner.cancel(text(transfer_id, "transfer_id")?)?)
+    }))
+}
+
+/// Current state tag.
+///
+/// # Safety
+///
+/// See module conventions.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_receive_session_state(
+    session: *mut BoltReceiveSession,
+    out_state: *mut BoltTransferState,
+) -> BoltStatus {
+    status(catch(|| {
+        let session = handle(session, "session")?;
+        write_out(out_state, "out_state", || session.inner.state().into())
+    }))
+}
+
+/// True if Completed and the file hash was verified.
+///
+/// # Safety
+///
+/// `session` must be NULL or a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_receive_session_hash_verified(
+    session: *const BoltReceiveSession,
+) -> bool {
+    or_else(
+        catch(|| Ok(session.as_ref().is_some_and(|s| s.inner.hash_verified()))),
+        false,
+    )
+}
+
+/// Copy the reassembled file into `out`. Fails with `INVALID_STATE`
+/// unless the session is Completed.
+///
+/// # Safety
+///
+/// See module conventions.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_receive_session_completed_bytes(
+    session: *mut BoltReceiveSession,
+    out: *mut BoltBuffer,
+) -> BoltStatus {
+    status(catch(|| {
+        let session = handle(session, "session")?;
+        let data = session.inner.completed_bytes().ok_or(FfiError {
+            code: "INVALID_STATE",
+            message: "transfer not completed".to_string(),
+        })?;
+        write_out(out, "out", || BoltBuffer::from_vec(data.to_vec()))
+    }))
+}
+
+/// Free a receive session.
+///
+/// # Safety
+///
+/// `session` must be NULL or a live handle; it is invalid afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn bolt_receive_session_free(session: *mut BoltReceiveSession) {
+    free_handle(session);
+}
@@ -0,0 +1,501 @@
+//! UniFFI interface (Kotlin / Swift).
+//!
+//! Same semantics as [`crate::c_api`], shaped for UniFFI: objects are
+//! `Arc`-shared with interior locking, byte strings are `Vec<u8>`, and
+//! every failure surfaces as [`BoltError::Wire`] carrying the canonical
+//! wire code (Kotlin `BoltException.Wire`, Swift `BoltError.wire`).
+
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use bolt_transfer_core::Sha256Verifier;
+
+use crate::{fixed, sas_encoding, FfiError};
+
+// ── Errors ────────────────────────────────────────────────────────
+
+/// Error surfaced to foreign callers.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Error)]
+pub enum BoltError {
+    /// `code` is a canonical wire error code; `detail` is diagnostic only.
+    Wire { code: String, detail: String },
+}
+
+impl std::fmt::Display for BoltError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BoltError::Wire { code, detail } => write!(f, "{code}: {detail}"),
+        }
+    }
+}
+
+impl std::error::Error for BoltError {}
+
+impl From<FfiError> for BoltError {
+    fn from(e: FfiError) -> Self {
+        BoltError::Wire {
+            code: e.code.to_string(),
+            detail: e.message,
+        }
+    }
+}
+
+macro_rules! wire_error_from {
+    ($($ty:ty),*) => {
+        $(impl From<$ty> for BoltError {
+            fn from(e: $ty) -> Self {
+                FfiError::from(e).into()
+            }
+        })*
+    };
+}
+
+wire_error_from!(
+    bolt_core::errors::BoltError,
+    bolt_btr::BtrError,
+    bolt_transfer_core::TransferError
+);
+
+fn lock<T>(m: &Mutex<T>) -> MutexGuard<'_, T> {
+    // State machines validate every transition, so a poisoned lock is safe to reuse.
+    m.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+// ── Key Generation ────────────────────────────────────────────────
+
+/// X25519 keypair. Secret key is zeroized when the object is released.
+#[derive(uniffi::Object)]
+pub struct KeyPair {
+    inner: bolt_core::crypto::KeyPair,
+}
+
+#[uniffi::export]
+impl KeyPair {
+    /// Ephemeral keypair for session use.
+    #[uniffi::constructor]
+    pub fn ephemeral() -> Arc<Self> {
+        Arc::new(Self {
+            inner: bolt_core::crypto::generate_ephemeral_keypair(),
+        })
+    }
+
+    /// Persistent identity keypair.
+    #[uniffi::constructor]
+    pub fn identity() -> Arc<Self> {
+        Arc::new(Self {
+            inner: bolt_core::identity::generate_identity_keypair(),
+        })
+    }
+
+    pub fn public_key(&self) -> Vec<u8> {
+        self.inner.public_key.to_vec()
+    }
+
+    pub fn secret_key(&self) -> Vec<u8> {
+        self.inner.secret_key.to_vec()
+    }
+}
+
+// ── NaCl Box / SAS / Peer Code / Hashing ──────────────────────────
+
+/// Seal plaintext with NaCl box. Returns base64(nonce || ciphertext).
+#[uniffi::export]
+pub fn seal_box_payload(
+    plaintext: Vec<u8>,
+    remote_public_key: Vec<u8>,
+    sender_secret_key: Vec<u8>,
+) -> Result<String, BoltError> {
+    let rpk: [u8; 32] = fixed(&remote_public_key, "remote_public_key")?;
+    let ssk: [u8; 32] = fixed(&sender_secret_key, "sender_secret_key")?;
+    Ok(bolt_core::crypto::seal_box_payload(&plaintext, &rpk, &ssk)?)
+}
+
+/// Open a base64(nonce || ciphertext) NaCl box payload.
+#[uniffi::export]
+pub fn open_box_payload(
+    sealed: String,
+    sender_public_key: Vec<u8>,
+    receiver_secret_key: Vec<u8>,
+) -> Result<Vec<u8>, BoltError> {
+    let spk: [u8; 32] = fixed(&sender_public_key, "sender_public_key")?;
+    let rsk: [u8; 32] = fixed(&receiver_secret_key, "receiver_secret_key")?;
+    Ok(bolt_core::crypto::open_box_payload(&sealed, &spk, &rsk)?)
+}
+
+/// Compute the SAS string. `encoding`: None/`"hex"`, `"words"`, `"emoji"`,
+/// or `"decimal"`.
+#[uniffi::export]
+pub fn compute_sas(
+    identity_a: Vec<u8>,
+    identity_b: Vec<u8>,
+    ephemeral_a: Vec<u8>,
+    ephemeral_b: Vec<u8>,
+    encoding: Option<String>,
+) -> Result<String, BoltError> {
+    let id_a: [u8; 32] = fixed(&identity_a, "identity_a")?;
+    let id_b: [u8; 32] = fixed(&identity_b, "identity_b")?;
+    let eph_a: [u8; 32] = fixed(&ephemeral_a, "ephemeral_a")?;
+    let eph_b: [u8; 32] = fixed(&ephemeral_b, "ephemeral_b")?;
+    let encoding = sas_encoding(encoding.as_deref())?;
+    Ok(bolt_core::sas::compute_sas_encoded(
+        &id_a, &id_b, &eph_a, &eph_b, encoding,
+    ))
+}
+
+/// Generate a 6-character secure peer code.
+#[uniffi::export]
+pub fn generate_peer_code() -> String {
+    bolt_core::peer_code::generate_secure_peer_code()
+}
+
+/// Validate a peer code.
+#[uniffi::export]
+pub fn is_valid_peer_code(code: String) -> bool {
+    bolt_core::peer_code::is_valid_peer_code(&code)
+}
+
+/// SHA-256 hex digest.
+#[uniffi::export]
+pub fn sha256_hex(data: Vec<u8>) -> String {
+    bolt_core::hash::sha256_hex(&data)
+}
+
+// ── BTR ───────────────────────────────────────────────────────────
+
+/// Session-level BTR ratchet state. Zeroized on release.
+#[derive(uniffi::Object)]
+pub struct BtrEngine {
+    inner: Mutex<bolt_btr::BtrEngine>,
+}
+
+/// Output of `BtrEngine.begin_transfer_send`.
+#[derive(uniffi::Record)]
+pub struct BtrSendStart {
+    pub context: Arc<BtrTransferContext>,
+    pub local_ratchet_pub: Vec<u8>,
+}
+
+#[uniffi::export]
+impl BtrEngine {
+    /// Create from the 32-byte ephemeral shared secret.
+    #[uniffi::constructor]
+    pub fn new(shared_secret: Vec<u8>) -> Result<Arc<Self>, BoltError> {
+        let ss: [u8; 32] = fixed(&shared_secret, "shared_secret")?;
+        Ok(Arc::new(Self {
+            inner: Mutex::new(bolt_btr::BtrEngine::new(&ss)),
+        }))
+    }
+
+    pub fn begin_transfer_send(
+        &self,
+        transfer_id: Vec<u8>,
+        remote_ratchet_pub: Vec<u8>,
+    ) -> Result<BtrSendStart, BoltError> {
+        let tid: [u8; 16] = fixed(&transfer_id, "transfer_id")?;
+        let rpub: [u8; 32] = fixed(&remote_ratchet_pub, "remote_ratchet_pub")?;
+        let (ctx, local_pub) = lock(&self.inner).begin_transfer_send(&tid, &rpub)?;
+        Ok(BtrSendStart {
+            context: Arc::new(BtrTransferContext {
+                inner: Mutex::new(ctx),
+            }),
+            local_ratchet_pub: local_pub.to_vec(),
+        })
+    }
+
+    pub fn begin_transfer_receive(
+        &self,
+        transfer_id: Vec<u8>,
+        remote_ratchet_pub: Vec<u8>,
+        local_secret_key: Vec<u8>,
+    ) -> Result<Arc<BtrTransferContext>, BoltError> {
+        let tid: [u8; 16] = fixed(&transfer_id, "transfer_id")?;
+        let rpub: [u8; 32] = fixed(&remote_ratchet_pub, "remote_ratchet_pub")?;
+        let lsk: [u8; 32] = fixed(&local_secret_key, "local_secret_key")?;
+        let ctx = lock(&self.inner).begin_transfer_receive_with_key(&tid, &rpub, &lsk)?;
+        Ok(Arc::new(BtrTransferContext {
+            inner: Mutex::new(ctx),
+        }))
+    }
+
+    pub fn ratchet_generation(&self) -> u32 {
+        lock(&self.inner).ratchet_generation()
+    }
+
+    pub fn end_transfer(&self) {
+        lock(&self.inner).end_transfer();
+    }
+
+    /// Zeroize all BTR state; the engine is unusable afterwards.
+    pub fn cleanup_disconnect(&self) {
+        lock(&self.inner).cleanup_disconnect();
+    }
+}
+
+/// Output of `BtrTransferContext.seal_chunk`.
+#[derive(uniffi::Record)]
+pub struct SealedChunk {
+    pub chain_index: u32,
+    pub sealed: Vec<u8>,
+}
+
+/// Per-transfer chain state. Chain key is zeroized on release.
+#[derive(uniffi::Object)]
+pub struct BtrTransferContext {
+    inner: Mutex<bolt_btr::BtrTransferContext>,
+}
+
+#[uniffi::export]
+impl BtrTransferContext {
+    pub fn seal_chunk(&self, plaintext: Vec<u8>) -> Result<SealedChunk, BoltError> {
+        let (chain_index, sealed) = lock(&self.inner).seal_chunk(&plaintext)?;
+        Ok(SealedChunk {
+            chain_index,
+            sealed,
+        })
+    }
+
+    pub fn open_chunk(
+        &self,
+        expected_chain_index: u32,
+        sealed: Vec<u8>,
+    ) -> Result<Vec<u8>, BoltError> {
+        Ok(lock(&self.inner).open_chunk(expected_chain_index, &sealed)?)
+    }
+
+    pub fn chain_index(&self) -> u32 {
+        lock(&self.inner).chain_index()
+    }
+
+    pub fn cleanup_complete(&self) {
+        lock(&self.inner).cleanup_complete();
+    }
+
+    pub fn cleanup_cancel(&self) {
+        lock(&self.inner).cleanup_cancel();
+    }
+}
+
+// ── Transfer State (§9) ───────────────────────────────────────────
+
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Enum)]
+pub enum CancelReason {
+    BySender,
+    ByReceiver,
+    Rejected,
+}
+
+/// Transfer state (PROTOCOL.md §9).
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Enum)]
+pub enum TransferState {
+    Idle,
+    Offered {
+        transfer_id: String,
+    },
+    Accepted {
+        transfer_id: String,
+    },
+    Transferring {
+        transfer_id: String,
+    },
+    Paused {
+        transfer_id: String,
+    },
+    Completed {
+        transfer_id: String,
+    },
+    Cancelled {
+        transfer_id: String,
+        reason: CancelReason,
+    },
+    Error {
+        detail: String,
+    },
+}
+
+impl From<&bolt_transfer_core::TransferState> for TransferState {
+    fn from(state: &bolt_transfer_core::TransferState) -> Self {
+        use bolt_transfer_core::{CancelReason as Reason, TransferState as S};
+
+        match state.clone() {
+            S::Idle => TransferState::Idle,
+            S::Offered { transfer_id } => TransferState::Offered { transfer_id },
+            S::Accepted { transfer_id } => TransferState::Accepted { transfer_id },
+            S::Transferring { transfer_id } => TransferState::Transferring { transfer_id },
+            S::Paused { transfer_id } => TransferState::Paused { transfer_id },
+            S::Completed { transfer_id } => TransferState::Completed { transfer_id },
+            S::Cancelled {
+                transfer_id,
+                reason,
+            } => TransferState::Cancelled {
+                transfer_id,
+                reason: match reason {
+                    Reason::BySender => CancelReason::BySender,
+                    Reason::ByReceiver => CancelReason::ByReceiver,
+                    Reason::Rejected => CancelReason::Rejected,
+                },
+            },
+            S::Error { detail } => TransferState::Error { detail },
+        }
+    }
+}
+
+// ── Send Session ──────────────────────────────────────────────────
+
+#[derive(uniffi::Record)]
+pub struct SendOffer {
+    pub transfer_id: String,
+    pub filename: String,
+    pub size: u64,
+    pub total_chunks: u32,
+    pub chunk_size: u32,
+    pub file_hash: Option<String>,
+}
+
+#[derive(uniffi::Record)]
+pub struct SendChunk {
+    pub transfer_id: String,
+    pub chunk_index: u32,
+    pub total_chunks: u32,
+    pub data: Vec<u8>,
+}
+
+/// Send-side §9 state machine.
+#[derive(uniffi::Object)]
+pub struct SendSession {
+    inner: Mutex<bolt_transfer_core::SendSession>,
+}
+
+#[uniffi::export]
+impl SendSession {
+    #[uniffi::constructor]
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self {
+            inner: Mutex::new(bolt_transfer_core::SendSession::new()),
+        })
+    }
+
+    pub fn begin_send(
+        &self,
+        transfer_id: String,
+        payload: Vec<u8>,
+        filename: String,
+        file_hash: Option<String>,
+    ) -> Result<SendOffer, BoltError> {
+        let offer = lock(&self.inner).begin_send(&transfer_id, payload, &filename, file_hash)?;
+        Ok(SendOffer {
+            transfer_id: offer.transfer_id,
+            filename: offer.filename,
+            size: offer.size,
+            total_chunks: offer.total_chunks,
+            chunk_size: offer.chunk_size,
+            file_hash: offer.file_hash,
+        })
+    }
+
+    pub fn on_accept(&self, transfer_id: String) -> Result<(), BoltError> {
+        Ok(lock(&self.inner).on_accept(&transfer_id)?)
+    }
+
+    pub fn on_cancel(&self, transfer_id: String) -> Result<(), BoltError> {
+        Ok(lock(&self.inner).on_cancel(&transfer_id)?)
+    }
+
+    pub fn on_pause(&self, transfer_id: String) -> Result<(), BoltError> {
+        Ok(lock(&self.inner).on_pause(&transfer_id)?)
+    }
+
+    pub fn on_resume(&self, transfer_id: String) -> Result<(), BoltError> {
+        Ok(lock(&self.inner).on_resume(&transfer_id)?)
+    }
+
+    /// Next chunk, or None when all chunks have been yielded.
+    pub fn next_chunk(&self) -> Result<Option<SendChunk>, BoltError> {
+        Ok(lock(&self.inner).next_chunk()?.map(|c| SendChunk {
+            transfer_id: c.transfer_id,
+            chunk_index: c.chunk_index,
+            total_chunks: c.total_chunks,
+            data: c.data,
+        }))
+    }
+
+    /// Finalize; returns the transfer ID.
+    pub fn finish(&self) -> Result<String, BoltError> {
+        Ok(lock(&self.inner).finish()?)
+    }
+
+    pub fn state(&self) -> TransferState {
+        lock(&self.inner).state().into()
+    }
+}
+
+// ── Receive Session ───────────────────────────────────────────────
+
+/// Receive-side §9 state machine with SHA-256 verification on finish.
+#[derive(uniffi::Object)]
+pub struct ReceiveSession {
+    inner: Mutex<bolt_transfer_core::ReceiveSession>,
+}
+
+#[uniffi::export]
+impl ReceiveSession {
+    #[uniffi::constructor]
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self {
+            inner: Mutex::new(bolt_transfer_core::ReceiveSession::new()),
+        })
+    }
+
+    pub fn on_file_offer(
+        &self,
+        transfer_id: String,
+        size: u64,
+        total_chunks: u32,
+        expected_hash: Option<String>,
+    ) -> Result<(), BoltError> {
+        Ok(lock(&self.inner).on_file_offer(
+            &transfer_id,
+            size,
+            total_chunks,
+            expected_hash.as_deref(),
+        )?)
+    }
+
+    /// Accept the pending offer; returns its transfer ID.
+    pub fn accept(&self) -> Result<String, BoltError> {
+        Ok(lock(&self.inner).accept_current_offer()?)
+    }
+
+    /// Reject the pending offer; returns its transfer ID.
+    pub fn reject(&self) -> Result<String, BoltError> {
+        Ok(lock(&self.inner).reject_current_offer()?)
+    }
+
+    pub fn on_file_chunk(
+        &self,
+        transfer_id: String,
+        chunk_index: u32,
+        data: Vec<u8>,
+    ) -> Result<(), BoltError> {
+        Ok(lock(&self.inner).on_file_chunk(&transfer_id, chunk_index, &data)?)
+    }
+
+    pub fn finish(&self, transfer_id: String) -> Result<(), BoltError> {
+        Ok(lock(&self.inner).on_file_finish(&transfer_id, Some(&Sha256Verifier))?)
+    }
+
+    pub fn cancel(&self, transfer_id: String) -> Result<(), BoltError> {
+        Ok(lock(&self.inner).cancel(&transfer_id)?)
+    }
+
+    pub fn state(&self) -> TransferState {
+        lock(&self.inner).state().into()
+    }
+
+    pub fn hash_verified(&self) -> bool {
+        lock(&self.inner).hash_verified()
+    }
+
+    /// Reassembled file once Completed.
+    pub fn completed_bytes(&self) -> Option<Vec<u8>> {
+        lock(&self.inner).completed_bytes().map(<[u8]>::to_vec)
+    }
+}
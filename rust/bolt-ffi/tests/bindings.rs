@@ -0,0 +1,97 @@
+//! Kotlin and Swift bindings generate from the built cdylib and expose
+//! the expected surface. Run with `cargo test -p bolt-ffi --features bindgen`.
+
+#![cfg(feature = "bindgen")]
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn cdylib() -> PathBuf {
+    // The bindgen binary and the cdylib share target/<profile>/.
+    let bin = Path::new(env!("CARGO_BIN_EXE_uniffi-bindgen"));
+    let name = format!(
+        "{}bolt_ffi{}",
+        std::env::consts::DLL_PREFIX,
+        std::env::consts::DLL_SUFFIX
+    );
+    let lib = bin.parent().unwrap().join(name);
+    assert!(lib.exists(), "cdylib missing at {}", lib.display());
+    lib
+}
+
+fn generate(language: &str) -> PathBuf {
+    let out = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(format!("bindings-{language}"));
+    let _ = std::fs::remove_dir_all(&out);
+    let status = Command::new(env!("CARGO_BIN_EXE_uniffi-bindgen"))
+        .args(["generate", "--no-format", "--library"])
+        .arg(cdylib())
+        .args(["--language", language, "--out-dir"])
+        .arg(&out)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .status()
+        .expect("spawn uniffi-bindgen");
+    assert!(status.success(), "uniffi-bindgen {language} failed");
+    out
+}
+
+fn read_all(dir: &Path, ext: &str) -> String {
+    let mut text = String::new();
+    for entry in walk(dir) {
+        if entry.extension().is_some_and(|e| e == ext) {
+            text.push_str(&std::fs::read_to_string(&entry).unwrap());
+        }
+    }
+    assert!(!text.is_empty(), "no .{ext} files under {}", dir.display());
+    text
+}
+
+fn walk(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            files.extend(walk(&path));
+        } else {
+            files.push(path);
+        }
+    }
+    files
+}
+
+#[test]
+fn kotlin_bindings_generate() {
+    let kt = read_all(&generate("kotlin"), "kt");
+    for needle in [
+        "class KeyPair",
+        "class BtrEngine",
+        "class BtrTransferContext",
+        "class SendSession",
+        "class ReceiveSession",
+        "sealed class BoltException",
+        "sealed class TransferState",
+        "fun `sealBoxPayload`",
+        "fun `computeSas`",
+    ] {
+        assert!(kt.contains(needle), "Kotlin bindings missing `{needle}`");
+    }
+}
+
+#[test]
+fn swift_bindings_generate() {
+    let out = generate("swift");
+    let swift = read_all(&out, "swift");
+    for needle in [
+        "class KeyPair",
+        "class BtrEngine",
+        "class SendSession",
+        "class ReceiveSession",
+        "enum BoltError",
+        "enum TransferState",
+        "func sealBoxPayload(",
+        "func computeSas(",
+    ] {
+        assert!(swift.contains(needle), "Swift bindings missing `{needle}`");
+    }
+    assert!(!read_all(&out, "h").is_empty());
+    assert!(!read_all(&out, "modulemap").is_empty());
+}
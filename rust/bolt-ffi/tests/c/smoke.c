@@ -0,0 +1,64 @@
+/* Plain-C consumer of include/bolt_ffi.h. Built and run by tests/c_header.rs. */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "bolt_ffi.h"
+
+#define CHECK(cond)                                                   \
+  do {                                                                \
+    if (!(cond)) {                                                    \
+      fprintf(stderr, "%s:%d: CHECK failed: %s (last error: %s)\n",   \
+              __FILE__, __LINE__, #cond,                              \
+              bolt_last_error_code() ? bolt_last_error_code() : "-"); \
+      return 1;                                                       \
+    }                                                                 \
+  } while (0)
+
+int main(void) {
+  uint8_t a_pk[32], a_sk[32], b_pk[32], b_sk[32];
+  BoltKeyPair *a = bolt_keypair_generate_ephemeral();
+  BoltKeyPair *b = bolt_keypair_generate_ephemeral();
+  CHECK(bolt_keypair_public_key(a, a_pk) == BOLT_STATUS_OK);
+  CHECK(bolt_keypair_secret_key(a, a_sk) == BOLT_STATUS_OK);
+  CHECK(bolt_keypair_public_key(b, b_pk) == BOLT_STATUS_OK);
+  CHECK(bolt_keypair_secret_key(b, b_sk) == BOLT_STATUS_OK);
+
+  const char *msg = "hello from C";
+  char *sealed = NULL;
+  CHECK(bolt_seal_box_payload((const uint8_t *)msg, strlen(msg), b_pk, a_sk,
+                              &sealed) == BOLT_STATUS_OK);
+  BoltBuffer opened = {0};
+  CHECK(bolt_open_box_payload(sealed, a_pk, b_sk, &opened) == BOLT_STATUS_OK);
+  CHECK(opened.len == strlen(msg) && memcmp(opened.data, msg, opened.len) == 0);
+  bolt_buffer_free(opened);
+  bolt_string_free(sealed);
+
+  CHECK(bolt_open_box_payload("not base64!", a_pk, b_sk, &opened) ==
+        BOLT_STATUS_ERROR);
+  CHECK(strcmp(bolt_last_error_code(), "INVALID_MESSAGE") == 0 ||
+        strcmp(bolt_last_error_code(), "ENCRYPTION_FAILED") == 0);
+
+  char *sas = NULL;
+  CHECK(bolt_compute_sas(a_pk, b_pk, a_pk, b_pk, NULL, &sas) == BOLT_STATUS_OK);
+  CHECK(strlen(sas) == 6);
+  bolt_string_free(sas);
+
+  char *code = bolt_generate_peer_code();
+  CHECK(bolt_is_valid_peer_code(code));
+  bolt_string_free(code);
+
+  BoltSendSession *s = bolt_send_session_new();
+  uint32_t total = 0;
+  CHECK(bolt_send_session_begin(s, "c-1", (const uint8_t *)msg, strlen(msg),
+                                "m.txt", NULL, &total) == BOLT_STATUS_OK);
+  CHECK(total == 1);
+  BoltTransferState state;
+  CHECK(bolt_send_session_state(s, &state) == BOLT_STATUS_OK);
+  CHECK(state == BOLT_TRANSFER_STATE_OFFERED);
+  bolt_send_session_free(s);
+
+  bolt_keypair_free(a);
+  bolt_keypair_free(b);
+  return 0;
+}
@@ -0,0 +1,372 @@
+//! C ABI behaviour, exercised through the exported `extern "C"` symbols.
+
+use std::ffi::{CStr, CString};
+use std::ptr;
+
+use bolt_ffi::c_api::*;
+
+fn last_code() -> String {
+    let code = bolt_last_error_code();
+    assert!(!code.is_null(), "expected last error to be set");
+    unsafe { CStr::from_ptr(code) }
+        .to_str()
+        .unwrap()
+        .to_string()
+}
+
+unsafe fn take_string(s: *mut std::ffi::c_char) -> String {
+    assert!(!s.is_null());
+    let out = CStr::from_ptr(s).to_str().unwrap().to_string();
+    bolt_string_free(s);
+    out
+}
+
+unsafe fn take_buffer(buf: BoltBuffer) -> Vec<u8> {
+    let out = std::slice::from_raw_parts(buf.data, buf.len).to_vec();
+    bolt_buffer_free(buf);
+    out
+}
+
+fn keys(kp: *mut BoltKeyPair) -> ([u8; 32], [u8; 32]) {
+    let (mut pk, mut sk) = ([0u8; 32], [0u8; 32]);
+    unsafe {
+        assert_eq!(bolt_keypair_public_key(kp, pk.as_mut_ptr()), BoltStatus::Ok);
+        assert_eq!(bolt_keypair_secret_key(kp, sk.as_mut_ptr()), BoltStatus::Ok);
+    }
+    (pk, sk)
+}
+
+// ── Crypto ──────────────────────────────────────────────────────────
+
+#[test]
+fn box_seal_open_roundtrip() {
+    let alice = bolt_keypair_generate_ephemeral();
+    let bob = bolt_keypair_generate_ephemeral();
+    let (alice_pk, alice_sk) = keys(alice);
+    let (bob_pk, bob_sk) = keys(bob);
+    let msg = b"hello over the C ABI";
+
+    unsafe {
+        let mut sealed = ptr::null_mut();
+        let st = bolt_seal_box_payload(
+            msg.as_ptr(),
+            msg.len(),
+            bob_pk.as_ptr(),
+            alice_sk.as_ptr(),
+            &mut sealed,
+        );
+        assert_eq!(st, BoltStatus::Ok);
+
+        let mut opened = BoltBuffer {
+            data: ptr::null_mut(),
+            len: 0,
+        };
+        let st = bolt_open_box_payload(sealed, alice_pk.as_ptr(), bob_sk.as_ptr(), &mut opened);
+        assert_eq!(st, BoltStatus::Ok);
+        assert_eq!(take_buffer(opened), msg);
+        bolt_string_free(sealed);
+
+        // Wrong key → ENCRYPTION_FAILED.
+        let mut sealed = ptr::null_mut();
+        bolt_seal_box_payload(
+            msg.as_ptr(),
+            msg.len(),
+            bob_pk.as_ptr(),
+            alice_sk.as_ptr(),
+            &mut sealed,
+        );
+        let mut opened = BoltBuffer {
+            data: ptr::null_mut(),
+            len: 0,
+        };
+        let st = bolt_open_box_payload(sealed, bob_pk.as_ptr(), bob_sk.as_ptr(), &mut opened);
+        assert_eq!(st, BoltStatus::Error);
+        assert_eq!(last_code(), "ENCRYPTION_FAILED");
+        bolt_string_free(sealed);
+
+        bolt_keypair_free(alice);
+        bolt_keypair_free(bob);
+    }
+}
+
+#[test]
+fn null_arguments_are_invalid_message() {
+    unsafe {
+        let mut out = ptr::null_mut();
+        let st = bolt_seal_box_payload(ptr::null(), 0, ptr::null(), ptr::null(), &mut out);
+        assert_eq!(st, BoltStatus::Error);
+        assert_eq!(last_code(), "INVALID_MESSAGE");
+        assert!(out.is_null());
+
+        assert_eq!(
+            bolt_keypair_public_key(ptr::null_mut(), [0u8; 32].as_mut_ptr()),
+            BoltStatus::Error
+        );
+        assert!(bolt_btr_engine_new(ptr::null()).is_null());
+        assert_eq!(last_code(), "INVALID_MESSAGE");
+
+        // NULL `out` after a successful seal: rejected, nothing allocated.
+        let (pk, sk) = ([9u8; 32], [7u8; 32]);
+        let st = bolt_seal_box_payload(b"x".as_ptr(), 1, pk.as_ptr(), sk.as_ptr(), ptr::null_mut());
+        assert_eq!(st, BoltStatus::Error);
+        assert_eq!(last_code(), "INVALID_MESSAGE");
+
+        // *_free(NULL) is a no-op.
+        bolt_keypair_free(ptr::null_mut());
+        bolt_btr_engine_free(ptr::null_mut());
+        bolt_btr_ctx_free(ptr::null_mut());
+        bolt_send_session_free(ptr::null_mut());
+        bolt_receive_session_free(ptr::null_mut());
+        bolt_string_free(ptr::null_mut());
+    }
+}
+
+#[test]
+fn sas_and_peer_code() {
+    let (a, b) = ([1u8; 32], [2u8; 32]);
+    unsafe {
+        let mut out = ptr::null_mut();
+        let st = bolt_compute_sas(
+            a.as_ptr(),
+            b.as_ptr(),
+            a.as_ptr(),
+            b.as_ptr(),
+            ptr::null(),
+            &mut out,
+        );
+        assert_eq!(st, BoltStatus::Ok);
+        assert_eq!(
+            take_string(out),
+            bolt_core::sas::compute_sas(&a, &b, &a, &b)
+        );
+
+        let words = CString::new("words").unwrap();
+        assert_eq!(
+            bolt_compute_sas(
+                a.as_ptr(),
+                b.as_ptr(),
+                a.as_ptr(),
+                b.as_ptr(),
+                words.as_ptr(),
+                &mut out
+            ),
+            BoltStatus::Ok
+        );
+        bolt_string_free(out);
+
+        let bogus = CString::new("klingon").unwrap();
+        let st = bolt_compute_sas(
+            a.as_ptr(),
+            b.as_ptr(),
+            a.as_ptr(),
+            b.as_ptr(),
+            bogus.as_ptr(),
+            &mut out,
+        );
+        assert_eq!(st, BoltStatus::Error);
+        assert_eq!(last_code(), "INVALID_MESSAGE");
+
+        let code = bolt_generate_peer_code();
+        assert!(bolt_is_valid_peer_code(code));
+        bolt_string_free(code);
+        assert!(!bolt_is_valid_peer_code(ptr::null()));
+
+        let hash = take_string(bolt_sha256_hex(b"hello".as_ptr(), 5));
+        assert!(hash.starts_with("2cf24dba"));
+    }
+}
+
+// ── BTR + transfer sessions ─────────────────────────────────────────
+
+#[test]
+fn send_btr_receive_end_to_end() {
+    let payload: Vec<u8> = (0..40_000u32).map(|i| (i % 251) as u8).collect();
+    let hash = CString::new(bolt_core::hash::sha256_hex(&payload)).unwrap();
+    let tid = CString::new("ffi-1").unwrap();
+    let name = CString::new("file.bin").unwrap();
+    let shared = [0x42u8; 32];
+    let btr_tid = [0x07u8; 16];
+
+    unsafe {
+        // BTR: sender seals against receiver's ephemeral key; receiver opens
+        // with its secret key and the sender's ratchet pub.
+        let receiver_kp = bolt_keypair_generate_ephemeral();
+        let (recv_pk, recv_sk) = keys(receiver_kp);
+        let send_engine = bolt_btr_engine_new(shared.as_ptr());
+        let recv_engine = bolt_btr_engine_new(shared.as_ptr());
+        let mut sender_ratchet_pub = [0u8; 32];
+        let mut send_ctx = ptr::null_mut();
+        assert_eq!(
+            bolt_btr_engine_begin_transfer_send(
+                send_engine,
+                btr_tid.as_ptr(),
+                recv_pk.as_ptr(),
+                sender_ratchet_pub.as_mut_ptr(),
+                &mut send_ctx,
+            ),
+            BoltStatus::Ok
+        );
+        let mut recv_ctx = ptr::null_mut();
+        assert_eq!(
+            bolt_btr_engine_begin_transfer_receive(
+                recv_engine,
+                btr_tid.as_ptr(),
+                sender_ratchet_pub.as_ptr(),
+                recv_sk.as_ptr(),
+                &mut recv_ctx,
+            ),
+            BoltStatus::Ok
+        );
+        assert_eq!(bolt_btr_engine_ratchet_generation(send_engine), 1);
+
+        // §9 sessions.
+        let sender = bolt_send_session_new();
+        let receiver = bolt_receive_session_new();
+        let mut total = 0u32;
+        assert_eq!(
+            bolt_send_session_begin(
+                sender,
+                tid.as_ptr(),
+                payload.as_ptr(),
+                payload.len(),
+                name.as_ptr(),
+                hash.as_ptr(),
+                &mut total,
+            ),
+            BoltStatus::Ok
+        );
+        assert_eq!(
+            bolt_receive_session_on_file_offer(
+                receiver,
+                tid.as_ptr(),
+                payload.len() as u64,
+                total,
+                hash.as_ptr(),
+            ),
+            BoltStatus::Ok
+        );
+        assert_eq!(bolt_receive_session_accept(receiver), BoltStatus::Ok);
+        assert_eq!(
+            bolt_send_session_on_accept(sender, tid.as_ptr()),
+            BoltStatus::Ok
+        );
+
+        let mut state = BoltTransferState::Idle;
+        bolt_send_session_state(sender, &mut state);
+        assert_eq!(state, BoltTransferState::Transferring);
+
+        loop {
+            let mut index = 0u32;
+            let mut chunk = BoltBuffer {
+                data: ptr::null_mut(),
+                len: 0,
+            };
+            match bolt_send_session_next_chunk(sender, &mut index, &mut chunk) {
+                BoltStatus::Done => break,
+                BoltStatus::Ok => {}
+                BoltStatus::Error => panic!("next_chunk failed: {}", last_code()),
+            }
+            let plain = take_buffer(chunk);
+
+            let mut chain_index = 0u32;
+            let mut sealed = BoltBuffer {
+                data: ptr::null_mut(),
+                len: 0,
+            };
+            assert_eq!(
+                bolt_btr_ctx_seal_chunk(
+                    send_ctx,
+                    plain.as_ptr(),
+                    plain.len(),
+                    &mut chain_index,
+                    &mut sealed
+                ),
+                BoltStatus::Ok
+            );
+            assert_eq!(chain_index, index);
+            let sealed = take_buffer(sealed);
+
+            let mut opened = BoltBuffer {
+                data: ptr::null_mut(),
+                len: 0,
+            };
+            assert_eq!(
+                bolt_btr_ctx_open_chunk(
+                    recv_ctx,
+                    chain_index,
+                    sealed.as_ptr(),
+                    sealed.len(),
+                    &mut opened
+                ),
+                BoltStatus::Ok
+            );
+            let opened = take_buffer(opened);
+            assert_eq!(
+                bolt_receive_session_on_file_chunk(
+                    receiver,
+                    tid.as_ptr(),
+                    index,
+                    opened.as_ptr(),
+                    opened.len(),
+                ),
+                BoltStatus::Ok
+            );
+        }
+        assert_eq!(bolt_btr_ctx_chain_index(send_ctx), total);
+
+        assert_eq!(bolt_send_session_finish(sender), BoltStatus::Ok);
+        assert_eq!(
+            bolt_receive_session_finish(receiver, tid.as_ptr()),
+            BoltStatus::Ok
+        );
+        assert!(bolt_receive_session_hash_verified(receiver));
+        let mut out = BoltBuffer {
+            data: ptr::null_mut(),
+            len: 0,
+        };
+        assert_eq!(
+            bolt_receive_session_completed_bytes(receiver, &mut out),
+            BoltStatus::Ok
+        );
+        assert_eq!(take_buffer(out), payload);
+
+        bolt_btr_ctx_free(send_ctx);
+        bolt_btr_ctx_free(recv_ctx);
+        bolt_btr_engine_free(send_engine);
+        bolt_btr_engine_free(recv_engine);
+        bolt_send_session_free(sender);
+        bolt_receive_session_free(receiver);
+        bolt_keypair_free(receiver_kp);
+    }
+}
+
+#[test]
+fn receive_errors_carry_wire_codes() {
+    let tid = CString::new("ffi-2").unwrap();
+    let wrong_hash = CString::new(bolt_core::hash::sha256_hex(b"abcd")).unwrap();
+    unsafe {
+        let rs = bolt_receive_session_new();
+        assert_eq!(bolt_receive_session_accept(rs), BoltStatus::Error);
+        assert_eq!(last_code(), "INVALID_STATE");
+
+        bolt_receive_session_on_file_offer(rs, tid.as_ptr(), 4, 1, wrong_hash.as_ptr());
+        bolt_receive_session_accept(rs);
+        bolt_receive_session_on_file_chunk(rs, tid.as_ptr(), 0, b"abce".as_ptr(), 4);
+        assert_eq!(
+            bolt_receive_session_finish(rs, tid.as_ptr()),
+            BoltStatus::Error
+        );
+        assert_eq!(last_code(), "INTEGRITY_FAILED");
+
+        let mut out = BoltBuffer {
+            data: ptr::null_mut(),
+            len: 0,
+        };
+        assert_eq!(
+            bolt_receive_session_completed_bytes(rs, &mut out),
+            BoltStatus::Error
+        );
+        assert_eq!(last_code(), "INVALID_STATE");
+        bolt_receive_session_free(rs);
+    }
+}
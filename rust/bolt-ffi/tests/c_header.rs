@@ -0,0 +1,83 @@
+//! `include/bolt_ffi.h` must match what cbindgen generates from the
+//! current source, and must compile and link from plain C.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn crate_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn generate_header() -> String {
+    let dir = crate_dir();
+    let config = cbindgen::Config::from_file(dir.join("cbindgen.toml")).expect("cbindgen.toml");
+    let bindings = cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(dir.join("src").join("c_api.rs"))
+        .generate()
+        .expect("cbindgen failed");
+    let mut out = Vec::new();
+    bindings.write(&mut out);
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn checked_in_header_is_current() {
+    let path = crate_dir().join("include").join("bolt_ffi.h");
+    let checked_in = std::fs::read_to_string(&path).expect("read bolt_ffi.h");
+    assert!(
+        checked_in == generate_header(),
+        "{} is stale; regenerate with `cbindgen --config cbindgen.toml --output include/bolt_ffi.h`",
+        path.display()
+    );
+}
+
+/// target/<profile>/ — parent of the `deps/` dir holding this test binary.
+fn profile_dir() -> PathBuf {
+    let exe = std::env::current_exe().unwrap();
+    exe.parent().and_then(Path::parent).unwrap().to_path_buf()
+}
+
+#[test]
+fn c_smoke_program_links_and_runs() {
+    let Ok(cc) = std::env::var("CC").or_else(|_| which("cc")) else {
+        eprintln!("skipping: no C compiler on PATH");
+        return;
+    };
+    let dir = crate_dir();
+    let lib = profile_dir().join("libbolt_ffi.a");
+    assert!(lib.exists(), "staticlib missing at {}", lib.display());
+    let out_dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR"));
+    let exe = out_dir.join("bolt_ffi_smoke");
+
+    let status = Command::new(&cc)
+        .arg("-std=c99")
+        .arg("-Wall")
+        .arg("-Werror")
+        .arg("-I")
+        .arg(dir.join("include"))
+        .arg(dir.join("tests").join("c").join("smoke.c"))
+        .arg(&lib)
+        .args(["-lpthread", "-ldl", "-lm"])
+        .arg("-o")
+        .arg(&exe)
+        .status()
+        .expect("spawn C compiler");
+    assert!(status.success(), "C compile/link failed");
+
+    let output = Command::new(&exe).output().expect("run smoke program");
+    assert!(
+        output.status.success(),
+        "smoke program failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+fn which(name: &str) -> Result<String, ()> {
+    let path = std::env::var_os("PATH").ok_or(())?;
+    std::env::split_paths(&path)
+        .map(|p| p.join(name))
+        .find(|p| p.is_file())
+        .map(|p| p.to_string_lossy().into_owned())
+        .ok_or(())
+}
@@ -0,0 +1,1422 @@
+//! Bolt Peer — one Bolt session over a message transport: HELLO, profile
+//! envelope, BTR and file transfer.
+//!
+//! Transport-agnostic — outbound frames go through [`Wire`] and inbound
+//! frames come in via [`PeerLink::on_frame`] — so the same state machine
+//! drives the browser's WS-direct socket (bolt-ui-wasm), the CLI's
+//! WebSocket (bolt-cli) and the in-memory pipe in tests.
+//!
+//! Ephemeral keys are exchanged in plaintext signaling, as on every Bolt
+//! transport: our [`PeerLink::local_ephemeral_key`] rides in the
+//! `publicKey` field of `connection_request` / `connection_accepted`, and
+//! the peer's goes to [`PeerLink::set_remote_ephemeral`] before the socket
+//! opens. Frame order on a fresh link:
+//! 1. `hello` — sealed to the peer's ephemeral key, exactly once
+//!    ([`HelloState`]), sent by [`PeerLink::start`].
+//! 2. Inner messages, wrapped in `profile-envelope` when envelope-v1 was
+//!    negotiated. An inbound `file-offer` is held as
+//!    [`LinkEvent::Offered`] until the app calls
+//!    [`PeerLink::accept_offer`]; the sender sends no chunks before the
+//!    peer's `file-accept`.
+//!
+//! Other inbound rules match `bolt-conformance`'s reference peer.
+//! With `bolt.transfer-ratchet-v1` negotiated on both sides each transfer
+//! ratchets the [`BtrEngine`]: the sender's ratchet public key and
+//! generation ride on the first chunk's envelope and every chunk carries
+//! `chain_index`. One transfer runs at a time, in either direction, so
+//! both engines ratchet in lockstep.
+//!
+//! With the `pq-hybrid` feature the link also advertises
+//! `bolt.pq-hybrid-mlkem768-v1`. When both sides negotiate it, the BTR
+//! session root comes from the X25519 + ML-KEM-768 hybrid instead.
+//! [`PqState`] tracks the `pqEncapsulationKey` / `pq-ciphertext` /
+//! `pq-confirm` exchange (`docs/PROTOCOL_CONTRACT.md` §10), and no offer
+//! goes either way until it completes.
+
+use serde_json::{json, Map, Value};
+
+use bolt_btr::constants::{PQ_HYBRID_CAPABILITY, TRANSFER_RATCHET_CAPABILITY};
+#[cfg(feature = "pq-hybrid")]
+use bolt_btr::hybrid::{is_kem_initiator, mlkem_encapsulate, MlKemKeypair};
+#[cfg(feature = "pq-hybrid")]
+use bolt_btr::{check_hello_transcript, hello_transcript};
+use bolt_btr::{negotiate_btr, BtrEngine, BtrError, BtrMode, BtrTransferContext};
+use bolt_core::constants::{FILE_HASH_CAPABILITY, PROFILE_ENVELOPE_V1_CAPABILITY};
+use bolt_core::crypto::{generate_ephemeral_keypair, open_box_payload, seal_box_payload, KeyPair};
+use bolt_core::encoding::{from_base64, from_hex, to_base64, to_hex};
+use bolt_core::hash::sha256_hex;
+use bolt_core::sas::compute_sas;
+use bolt_core::session::{negotiate_capabilities, HelloError, HelloState};
+use bolt_transfer_core::receive::MAX_TRANSFER_BYTES;
+use bolt_transfer_core::{
+    Backpressure, BackpressureConfig, BackpressureController, ReceiveSession, SendSession,
+    Sha256Verifier, TransferError, TransferState, TransportQuery,
+};
+#[cfg(feature = "pq-hybrid")]
+use zeroize::Zeroize;
+
+/// Capabilities a link advertises in HELLO by default.
+#[cfg(not(feature = "pq-hybrid"))]
+pub const CAPABILITIES: [&str; 3] = [
+    PROFILE_ENVELOPE_V1_CAPABILITY,
+    FILE_HASH_CAPABILITY,
+    TRANSFER_RATCHET_CAPABILITY,
+];
+
+/// Capabilities a link advertises in HELLO by default.
+#[cfg(feature = "pq-hybrid")]
+pub const CAPABILITIES: [&str; 4] = [
+    PROFILE_ENVELOPE_V1_CAPABILITY,
+    FILE_HASH_CAPABILITY,
+    TRANSFER_RATCHET_CAPABILITY,
+    PQ_HYBRID_CAPABILITY,
+];
+
+/// Send-buffer watermarks. A base64 chunk inside an envelope is ~30 KB, so
+/// the transfer-core default (64 KiB) would stall after two chunks.
+const HIGH_WATERMARK: usize = 1024 * 1024;
+const LOW_WATERMARK: usize = 256 * 1024;
+
+/// Outbound half of the link; implemented by each transport.
+pub trait Wire: TransportQuery {
+    fn send_text(&mut self, frame: &str);
+}
+
+/// Something the UI should react to, drained via [`PeerLink::poll_event`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum LinkEvent {
+    /// HELLO completed; `sas` is the code both users compare.
+    Established {
+        sas: String,
+        /// The peer's identity key from its HELLO.
+        remote_identity: [u8; 32],
+    },
+    /// The peer offered a file. Nothing is received until
+    /// [`PeerLink::accept_offer`].
+    Offered {
+        transfer_id: String,
+        file_name: String,
+        size: u64,
+    },
+    /// `bytes` of `total` plaintext bytes have gone out.
+    SendProgress {
+        transfer_id: String,
+        file_name: String,
+        bytes: u64,
+        total: u64,
+    },
+    /// `file-finish` went out. The receiver checks the hash; a failure
+    /// comes back as an `error` message.
+    Sent {
+        transfer_id: String,
+        file_name: String,
+    },
+    ReceiveProgress {
+        transfer_id: String,
+        file_name: String,
+        bytes: u64,
+        total: u64,
+    },
+    Received {
+        transfer_id: String,
+        file_name: String,
+        bytes: Vec<u8>,
+        /// The sender's `fileHash` was checked (`bolt.file-hash`).
+        hash_verified: bool,
+    },
+    /// Protocol error (ours or the peer's). The link is closed.
+    Failed { code: String, detail: String },
+}
+
+/// Wire code + detail for a rejected frame.
+type Rejection = (&'static str, String);
+
+fn reject(code: &'static str, detail: impl Into<String>) -> Rejection {
+    (code, detail.into())
+}
+
+fn transfer(e: TransferError) -> Rejection {
+    (e.code(), e.to_string())
+}
+
+fn ratchet(e: BtrError) -> Rejection {
+    (e.wire_code(), e.to_string())
+}
+
+fn key32(b64: &str) -> Option<[u8; 32]> {
+    from_base64(b64).ok().and_then(|k| k.try_into().ok())
+}
+
+struct Outgoing {
+    transfer_id: String,
+    file_name: String,
+    size: u64,
+    sent: u64,
+    btr: Option<BtrTransferContext>,
+    /// Our ratchet public key, sent on the first chunk's envelope only.
+    ratchet_public_key: Option<[u8; 32]>,
+}
+
+struct Incoming {
+    file_name: String,
+    transfer_id: String,
+    size: u64,
+    received: u64,
+    btr: Option<BtrTransferContext>,
+}
+
+/// PQ hybrid handshake progress (§16.8).
+#[cfg(feature = "pq-hybrid")]
+enum PqState {
+    /// Classical session, or nothing to do yet.
+    Idle,
+    /// We are the KEM initiator; our HELLO carries this keypair's
+    /// encapsulation key.
+    Offered(MlKemKeypair),
+    /// Initiator after HELLO, waiting for `pq-ciphertext`.
+    AwaitCiphertext {
+        keypair: MlKemKeypair,
+        transcript: [u8; 32],
+    },
+    /// Responder after sending `pq-ciphertext`, waiting for `pq-confirm`.
+    AwaitConfirm { transcript: [u8; 32] },
+}
+
+pub struct PeerLink {
+    identity_public_key: [u8; 32],
+    ephemeral: KeyPair,
+    capabilities: Vec<String>,
+    remote_ephemeral: Option<[u8; 32]>,
+    hello: HelloState,
+    negotiated: Vec<String>,
+    btr: Option<BtrEngine>,
+    send: SendSession,
+    outgoing: Option<Outgoing>,
+    backpressure: BackpressureController,
+    receive: ReceiveSession,
+    incoming: Option<Incoming>,
+    #[cfg(feature = "pq-hybrid")]
+    pq: PqState,
+    events: Vec<LinkEvent>,
+    closed: bool,
+}
+
+impl PeerLink {
+    /// A link with a fresh ephemeral keypair. `capabilities` are advertised
+    /// in HELLO (normally [`CAPABILITIES`]).
+    pub fn new(identity_public_key: [u8; 32], capabilities: &[&str]) -> Self {
+        Self {
+            identity_public_key,
+            ephemeral: generate_ephemeral_keypair(),
+            capabilities: capabilities.iter().map(|c| c.to_string()).collect(),
+            remote_ephemeral: None,
+            hello: HelloState::new(),
+            negotiated: Vec::new(),
+            btr: None,
+            send: SendSession::new(),
+            outgoing: None,
+            backpressure: BackpressureController::new(BackpressureConfig::new(
+                HIGH_WATERMARK,
+                LOW_WATERMARK,
+            )),
+            receive: ReceiveSession::new(),
+            incoming: None,
+            #[cfg(feature = "pq-hybrid")]
+            pq: PqState::Idle,
+            events: Vec::new(),
+            closed: false,
+        }
+    }
+
+    /// Our ephemeral public key, for the `publicKey` signal field.
+    pub fn local_ephemeral_key(&self) -> [u8; 32] {
+        self.ephemeral.public_key
+    }
+
+    /// The peer's ephemeral public key from its connection signal. Set it
+    /// before [`start`](Self::start).
+    pub fn set_remote_ephemeral(&mut self, remote_ephemeral: [u8; 32]) {
+        self.remote_ephemeral = Some(remote_ephemeral);
+    }
+
+    /// Send our sealed HELLO. Call once the socket is open.
+    pub fn start(&mut self, wire: &mut dyn Wire) {
+        if self.closed {
+            return;
+        }
+        if let Err((code, detail)) = self.send_hello(wire) {
+            self.fail(code, detail, wire);
+        }
+    }
+
+    pub fn is_established(&self) -> bool {
+        self.hello.is_completed() && !self.closed
+    }
+
+    /// Whether transfers may start: HELLO is done and, with `pq-hybrid`,
+    /// so is the ML-KEM exchange.
+    pub fn is_ready(&self) -> bool {
+        self.is_established() && !self.pq_pending()
+    }
+
+    /// Whether a transfer is in flight in either direction.
+    pub fn is_busy(&self) -> bool {
+        self.outgoing.is_some() || self.incoming.is_some()
+    }
+
+    /// Whether an inbound offer is waiting for [`accept_offer`](Self::accept_offer).
+    pub fn has_pending_offer(&self) -> bool {
+        !self.closed && matches!(self.receive.state(), TransferState::Offered { .. })
+    }
+
+    /// Accept the pending inbound offer and tell the peer to start sending.
+    /// Only call once the user has confirmed the SAS.
+    pub fn accept_offer(&mut self, wire: &mut dyn Wire) -> Result<(), String> {
+        if !self.has_pending_offer() {
+            return Err("No pending offer".to_string());
+        }
+        let tid = self
+            .receive
+            .accept_current_offer()
+            .map_err(|e| e.to_string())?;
+        let msg = json!({ "type": "file-accept", "transferId": tid });
+        if let Err((code, detail)) = self.send_message(&msg, Map::new(), wire) {
+            self.fail(code, detail.clone(), wire);
+            return Err(detail);
+        }
+        if let Some(incoming) = &self.incoming {
+            self.events.push(LinkEvent::ReceiveProgress {
+                transfer_id: incoming.transfer_id.clone(),
+                file_name: incoming.file_name.clone(),
+                bytes: 0,
+                total: incoming.size,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn poll_event(&mut self) -> Option<LinkEvent> {
+        if self.events.is_empty() {
+            None
+        } else {
+            Some(self.events.remove(0))
+        }
+    }
+
+    /// Handle one inbound text frame. Any protocol violation sends an
+    /// `error` frame, closes the link and surfaces [`LinkEvent::Failed`].
+    pub fn on_frame(&mut self, text: &str, wire: &mut dyn Wire) {
+        if self.closed {
+            return;
+        }
+        let result = serde_json::from_str::<Value>(text)
+            .map_err(|_| reject("INVALID_MESSAGE", "frame is not JSON"))
+            .and_then(|frame| self.dispatch(&frame, wire));
+        if let Err((code, detail)) = result {
+            self.fail(code, detail, wire);
+        }
+    }
+
+    /// Offer a file and return its transfer id; chunks go out from
+    /// [`pump`](Self::pump) once the peer sends `file-accept`.
+    pub fn send_file(
+        &mut self,
+        file_name: &str,
+        bytes: Vec<u8>,
+        wire: &mut dyn Wire,
+    ) -> Result<String, String> {
+        if !self.is_established() {
+            return Err("Not connected".to_string());
+        }
+        if self.pq_pending() {
+            return Err("Key exchange still in progress".to_string());
+        }
+        if self.is_busy() {
+            return Err("A transfer is already in progress".to_string());
+        }
+        if bytes.len() as u64 > MAX_TRANSFER_BYTES {
+            return Err(format!(
+                "File is larger than {} MiB",
+                MAX_TRANSFER_BYTES / (1024 * 1024)
+            ));
+        }
+
+        let mut transfer_id = [0u8; 16];
+        getrandom::getrandom(&mut transfer_id).map_err(|e| e.to_string())?;
+        let tid = to_hex(&transfer_id);
+        let file_hash = self.has(FILE_HASH_CAPABILITY).then(|| sha256_hex(&bytes));
+
+        self.send = SendSession::new();
+        let offer = self
+            .send
+            .begin_send(&tid, bytes, file_name, file_hash)
+            .map_err(|e| e.to_string())?;
+
+        let (btr, ratchet_public_key) = match (&mut self.btr, self.remote_ephemeral) {
+            (Some(engine), Some(remote)) => {
+                let (ctx, public_key) = engine
+                    .begin_transfer_send(&transfer_id, &remote)
+                    .map_err(|e| e.to_string())?;
+                (Some(ctx), Some(public_key))
+            }
+            _ => (None, None),
+        };
+
+        let mut msg = json!({
+            "type": "file-offer",
+            "transferId": tid,
+            "filename": offer.filename,
+            "size": offer.size,
+            "totalChunks": offer.total_chunks,
+        });
+        if let Some(hash) = &offer.file_hash {
+            msg["fileHash"] = json!(hash);
+        }
+        self.outgoing = Some(Outgoing {
+            transfer_id: tid.clone(),
+            file_name: file_name.to_string(),
+            size: offer.size,
+            sent: 0,
+            btr,
+            ratchet_public_key,
+        });
+        self.backpressure.reset();
+        if let Err((code, detail)) = self.send_message(&msg, Map::new(), wire) {
+            self.fail(code, detail.clone(), wire);
+            return Err(detail);
+        }
+        Ok(tid)
+    }
+
+    /// Send as many chunks of the outgoing transfer as the socket buffer
+    /// allows, then `file-finish`. Call every frame; a no-op until the
+    /// peer accepts the offer.
+    pub fn pump(&mut self, wire: &mut dyn Wire) {
+        if self.closed
+            || self.outgoing.is_none()
+            || matches!(self.send.state(), TransferState::Offered { .. })
+        {
+            return;
+        }
+        if let Err((code, detail)) = self.pump_chunks(wire) {
+            self.fail(code, detail, wire);
+        }
+    }
+
+    /// Send an `error` message and close the link, e.g. to refuse an offer
+    /// or a rejected SAS. Surfaces [`LinkEvent::Failed`] like a protocol
+    /// error.
+    pub fn abort(&mut self, code: &str, detail: impl Into<String>, wire: &mut dyn Wire) {
+        if !self.closed {
+            self.fail(code, detail.into(), wire);
+        }
+    }
+
+    /// Drop all session and ratchet state; the link is unusable afterwards.
+    pub fn close(&mut self) {
+        self.closed = true;
+        self.outgoing = None;
+        self.incoming = None;
+        if let Some(engine) = &mut self.btr {
+            engine.cleanup_disconnect();
+        }
+        self.btr = None;
+    }
+
+    fn has(&self, capability: &str) -> bool {
+        self.negotiated.iter().any(|c| c == capability)
+    }
+
+    /// BTR metadata rides on the envelope, so BTR needs envelope-v1 too.
+    fn btr_negotiated(&self) -> bool {
+        self.has(TRANSFER_RATCHET_CAPABILITY) && self.has(PROFILE_ENVELOPE_V1_CAPABILITY)
+    }
+
+    /// The BTR session root comes from the PQ hybrid (§16.8).
+    fn pq_negotiated(&self) -> bool {
+        self.btr_negotiated() && self.has(PQ_HYBRID_CAPABILITY)
+    }
+
+    fn x25519_shared(&self, remote: &[u8; 32]) -> x25519_dalek::SharedSecret {
+        x25519_dalek::StaticSecret::from(self.ephemeral.secret_key)
+            .diffie_hellman(&x25519_dalek::PublicKey::from(*remote))
+    }
+
+    fn fail(&mut self, code: &str, detail: String, wire: &mut dyn Wire) {
+        let msg = json!({ "type": "error", "code": code, "message": detail });
+        // Best effort: the link closes whether or not the peer hears about it.
+        let _ = self.send_message(&msg, Map::new(), wire);
+        self.close();
+        self.events.push(LinkEvent::Failed {
+            code: code.to_string(),
+            detail,
+        });
+    }
+
+    /// Send an inner message, enveloped once HELLO negotiated envelope-v1.
+    /// `btr_fields` are merged into the envelope header.
+    fn send_message(
+        &self,
+        msg: &Value,
+        btr_fields: Map<String, Value>,
+        wire: &mut dyn Wire,
+    ) -> Result<(), Rejection> {
+        let remote = match self.remote_ephemeral {
+            Some(remote)
+                if self.hello.is_completed() && self.has(PROFILE_ENVELOPE_V1_CAPABILITY) =>
+            {
+                remote
+            }
+            _ => {
+                wire.send_text(&msg.to_string());
+                return Ok(());
+            }
+        };
+        let payload = seal_box_payload(
+            msg.to_string().as_bytes(),
+            &remote,
+            &self.ephemeral.secret_key,
+        )
+        .map_err(|e| reject("ENCRYPTION_FAILED", e.to_string()))?;
+        let mut frame = json!({
+            "type": "profile-envelope",
+            "version": 1,
+            "encoding": "base64",
+            "payload": payload,
+        });
+        if let Value::Object(header) = &mut frame {
+            header.extend(btr_fields);
+        }
+        wire.send_text(&frame.to_string());
+        Ok(())
+    }
+
+    fn dispatch(&mut self, frame: &Value, wire: &mut dyn Wire) -> Result<(), Rejection> {
+        let hello_done = self.hello.is_completed();
+        match frame["type"].as_str() {
+            Some("hello") => {
+                let hello = self
+                    .on_hello(frame)
+                    .map_err(|e| (e.code(), e.to_string()))?;
+                self.begin_pq(&hello, wire)
+            }
+            Some("profile-envelope") => {
+                if hello_done && !self.has(PROFILE_ENVELOPE_V1_CAPABILITY) {
+                    return Err(reject(
+                        "ENVELOPE_UNNEGOTIATED",
+                        "profile-envelope-v1 not negotiated",
+                    ));
+                }
+                let inner = self.open_envelope(frame)?;
+                if !hello_done && inner["type"] != "error" {
+                    return Err(reject("INVALID_STATE", "message before HELLO"));
+                }
+                self.on_message(&inner, frame, wire)
+            }
+            Some("ping" | "pong") if !hello_done => self.on_message(frame, &Value::Null, wire),
+            Some(_) if !hello_done => Err(reject("INVALID_STATE", "message before HELLO")),
+            Some(_) if self.has(PROFILE_ENVELOPE_V1_CAPABILITY) => Err(reject(
+                "ENVELOPE_REQUIRED",
+                "plaintext message after envelope-v1 negotiation",
+            )),
+            Some(_) => self.on_message(frame, &Value::Null, wire),
+            None => Err(reject("INVALID_MESSAGE", "frame has no string `type`")),
+        }
+    }
+
+    fn send_hello(&mut self, wire: &mut dyn Wire) -> Result<(), Rejection> {
+        let remote = self
+            .remote_ephemeral
+            .ok_or_else(|| reject("INVALID_STATE", "no ephemeral key from the peer's signal"))?;
+        let inner = json!({
+            "type": "hello",
+            "version": 1,
+            "identityPublicKey": to_base64(&self.identity_public_key),
+            "capabilities": self.capabilities,
+        });
+        #[cfg(feature = "pq-hybrid")]
+        let inner = self.offer_pq_key(inner, &remote)?;
+        let payload = seal_box_payload(
+            inner.to_string().as_bytes(),
+            &remote,
+            &self.ephemeral.secret_key,
+        )
+        .map_err(|e| reject("ENCRYPTION_FAILED", e.to_string()))?;
+        wire.send_text(&json!({ "type": "hello", "payload": payload }).to_string());
+        Ok(())
+    }
+
+    /// Returns the peer's inner HELLO.
+    fn on_hello(&mut self, frame: &Value) -> Result<Value, HelloError> {
+        if self.hello.is_completed() {
+            return Err(HelloError::DuplicateHello);
+        }
+        let remote_ephemeral = self.remote_ephemeral.ok_or_else(|| {
+            HelloError::ParseError("HELLO before the peer's ephemeral key".into())
+        })?;
+        let payload = frame["payload"]
+            .as_str()
+            .ok_or_else(|| HelloError::ParseError("missing `payload`".into()))?;
+        let plain = open_box_payload(payload, &remote_ephemeral, &self.ephemeral.secret_key)
+            .map_err(|e| HelloError::DecryptFail(e.to_string()))?;
+        let schema = |detail: &str| HelloError::SchemaError(detail.to_string());
+        let inner: Value =
+            serde_json::from_slice(&plain).map_err(|_| schema("inner payload is not JSON"))?;
+        if inner["type"] != "hello" || inner["version"] != 1 {
+            return Err(schema("expected type `hello`, version 1"));
+        }
+        let identity = inner["identityPublicKey"]
+            .as_str()
+            .and_then(key32)
+            .ok_or_else(|| schema("identityPublicKey must be 32 bytes of base64"))?;
+        let remote: Vec<String> = inner["capabilities"]
+            .as_array()
+            .and_then(|caps| caps.iter().map(|c| c.as_str().map(String::from)).collect())
+            .ok_or_else(|| schema("`capabilities` must be an array of strings"))?;
+
+        self.hello
+            .mark_completed()
+            .map_err(|_| HelloError::DuplicateHello)?;
+        self.negotiated = negotiate_capabilities(&self.capabilities, &remote);
+
+        let local_btr = self
+            .capabilities
+            .iter()
+            .any(|c| c == TRANSFER_RATCHET_CAPABILITY);
+        let remote_btr = remote.iter().any(|c| c == TRANSFER_RATCHET_CAPABILITY);
+        if negotiate_btr(local_btr, remote_btr, true) == BtrMode::FullBtr
+            && self.btr_negotiated()
+            && !self.pq_negotiated()
+        {
+            let shared = self.x25519_shared(&remote_ephemeral);
+            self.btr = Some(BtrEngine::new(shared.as_bytes()));
+        }
+
+        let sas = compute_sas(
+            &self.identity_public_key,
+            &identity,
+            &self.ephemeral.public_key,
+            &remote_ephemeral,
+        );
+        self.events.push(LinkEvent::Established {
+            sas,
+            remote_identity: identity,
+        });
+        Ok(inner)
+    }
+
+    /// Whether the PQ hybrid handshake still has to finish before
+    /// transfers.
+    #[cfg(feature = "pq-hybrid")]
+    fn pq_pending(&self) -> bool {
+        !matches!(self.pq, PqState::Idle)
+    }
+
+    #[cfg(not(feature = "pq-hybrid"))]
+    fn pq_pending(&self) -> bool {
+        false
+    }
+
+    /// If we advertise PQ and are the KEM initiator, generate our ML-KEM
+    /// keypair and add its encapsulation key to our inner HELLO.
+    #[cfg(feature = "pq-hybrid")]
+    fn offer_pq_key(&mut self, mut hello: Value, remote: &[u8; 32]) -> Result<Value, Rejection> {
+        let advertised = self.capabilities.iter().any(|c| c == PQ_HYBRID_CAPABILITY);
+        if advertised && is_kem_initiator(&self.ephemeral.public_key, remote).map_err(ratchet)? {
+            let keypair = MlKemKeypair::generate();
+            hello["pqEncapsulationKey"] = json!(to_base64(keypair.encapsulation_key()));
+            self.pq = PqState::Offered(keypair);
+        }
+        Ok(hello)
+    }
+
+    /// Our side of the PQ handshake once HELLO completes. The initiator
+    /// waits for `pq-ciphertext`; the responder encapsulates to the key in
+    /// the peer's HELLO and sends it.
+    #[cfg(feature = "pq-hybrid")]
+    fn begin_pq(&mut self, hello: &Value, wire: &mut dyn Wire) -> Result<(), Rejection> {
+        let offered = std::mem::replace(&mut self.pq, PqState::Idle);
+        let Some(remote) = self.remote_ephemeral.filter(|_| self.pq_negotiated()) else {
+            return Ok(());
+        };
+        let remote_capabilities: Vec<String> = hello["capabilities"]
+            .as_array()
+            .map(|caps| {
+                caps.iter()
+                    .filter_map(|c| c.as_str().map(String::from))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        if let PqState::Offered(keypair) = offered {
+            let transcript = hello_transcript(
+                &self.capabilities,
+                &remote_capabilities,
+                keypair.encapsulation_key(),
+            );
+            self.pq = PqState::AwaitCiphertext {
+                keypair,
+                transcript,
+            };
+            return Ok(());
+        }
+
+        let key = hello["pqEncapsulationKey"]
+            .as_str()
+            .and_then(|k| from_base64(k).ok())
+            .ok_or_else(|| reject("PQ_HANDSHAKE_FAILED", "missing `pqEncapsulationKey`"))?;
+        let (ciphertext, mut mlkem) = mlkem_encapsulate(&key).map_err(ratchet)?;
+        let transcript = hello_transcript(&remote_capabilities, &self.capabilities, &key);
+        let shared = self.x25519_shared(&remote);
+        self.btr = Some(BtrEngine::new_hybrid(
+            shared.as_bytes(),
+            &mlkem,
+            &self.negotiated,
+        ));
+        mlkem.zeroize();
+        self.pq = PqState::AwaitConfirm { transcript };
+        let msg = json!({
+            "type": "pq-ciphertext",
+            "ciphertext": to_base64(&ciphertext),
+            "transcript": to_hex(&transcript),
+        });
+        self.send_message(&msg, Map::new(), wire)
+    }
+
+    #[cfg(not(feature = "pq-hybrid"))]
+    fn begin_pq(&mut self, _hello: &Value, _wire: &mut dyn Wire) -> Result<(), Rejection> {
+        Ok(())
+    }
+
+    /// Initiator: decapsulate, check the responder's transcript, start the
+    /// hybrid BTR engine and confirm.
+    #[cfg(feature = "pq-hybrid")]
+    fn on_pq_ciphertext(&mut self, msg: &Value, wire: &mut dyn Wire) -> Result<(), Rejection> {
+        let PqState::AwaitCiphertext {
+            keypair,
+            transcript,
+        } = std::mem::replace(&mut self.pq, PqState::Idle)
+        else {
+            return Err(reject("INVALID_STATE", "unexpected pq-ciphertext"));
+        };
+        let received = msg["transcript"]
+            .as_str()
+            .and_then(|t| from_hex(t).ok())
+            .unwrap_or_default();
+        check_hello_transcript(&transcript, &received).map_err(ratchet)?;
+        let ciphertext = msg["ciphertext"]
+            .as_str()
+            .and_then(|c| from_base64(c).ok())
+            .ok_or_else(|| reject("PQ_HANDSHAKE_FAILED", "missing `ciphertext`"))?;
+        let mut mlkem = keypair.decapsulate(&ciphertext).map_err(ratchet)?;
+        let remote = self.remote_ephemeral.ok_or_else(|| {
+            reject(
+                "INVALID_STATE",
+                "pq-ciphertext before the peer's ephemeral key",
+            )
+        })?;
+        let shared = self.x25519_shared(&remote);
+        self.btr = Some(BtrEngine::new_hybrid(
+            shared.as_bytes(),
+            &mlkem,
+            &self.negotiated,
+        ));
+        mlkem.zeroize();
+        let msg = json!({ "type": "pq-confirm", "transcript": to_hex(&transcript) });
+        self.send_message(&msg, Map::new(), wire)
+    }
+
+    /// Responder: the initiator saw the same HELLOs.
+    #[cfg(feature = "pq-hybrid")]
+    fn on_pq_confirm(&mut self, msg: &Value) -> Result<(), Rejection> {
+        let PqState::AwaitConfirm { transcript } = std::mem::replace(&mut self.pq, PqState::Idle)
+        else {
+            return Err(reject("INVALID_STATE", "unexpected pq-confirm"));
+        };
+        let received = msg["transcript"]
+            .as_str()
+            .and_then(|t| from_hex(t).ok())
+            .unwrap_or_default();
+        check_hello_transcript(&transcript, &received).map_err(ratchet)
+    }
+
+    fn open_envelope(&self, frame: &Value) -> Result<Value, Rejection> {
+        let payload = match (&frame["version"], &frame["encoding"], &frame["payload"]) {
+            (Value::Number(v), Value::String(enc), Value::String(p))
+                if v.as_u64() == Some(1) && enc == "base64" =>
+            {
+                p
+            }
+            _ => return Err(reject("ENVELOPE_INVALID", "bad envelope header")),
+        };
+        let remote = self
+            .remote_ephemeral
+            .ok_or_else(|| reject("INVALID_STATE", "envelope before the peer's ephemeral key"))?;
+        let plain = open_box_payload(payload, &remote, &self.ephemeral.secret_key)
+            .map_err(|e| reject("ENVELOPE_DECRYPT_FAIL", e.to_string()))?;
+        serde_json::from_slice(&plain)
+            .map_err(|_| reject("INVALID_MESSAGE", "inner payload is not JSON"))
+    }
+
+    /// `header` is the enclosing envelope (BTR fields), or `Null`.
+    fn on_message(
+        &mut self,
+        msg: &Value,
+        header: &Value,
+        wire: &mut dyn Wire,
+    ) -> Result<(), Rejection> {
+        let str_field = |name: &str| {
+            msg[name]
+                .as_str()
+                .ok_or_else(|| reject("INVALID_MESSAGE", format!("missing `{name}`")))
+        };
+        let u32_field = |name: &str| {
+            msg[name]
+                .as_u64()
+                .and_then(|n| u32::try_from(n).ok())
+                .ok_or_else(|| reject("INVALID_MESSAGE", format!("missing `{name}`")))
+        };
+
+        match msg["type"].as_str().unwrap_or_default() {
+            "ping" => {
+                let pong = json!({ "type": "pong", "ts_ms": msg["ts_ms"] });
+                self.send_message(&pong, Map::new(), wire)
+            }
+            "pong" => Ok(()),
+            "error" => {
+                // The peer is closing; don't answer its error with another.
+                self.close();
+                self.events.push(LinkEvent::Failed {
+                    code: msg["code"].as_str().unwrap_or("UNKNOWN").to_string(),
+                    detail: msg["message"].as_str().unwrap_or_default().to_string(),
+                });
+                Ok(())
+            }
+            "file-offer" => {
+                let tid = str_field("transferId")?;
+                let file_name = str_field("filename")?;
+                let size = msg["size"]
+                    .as_u64()
+                    .ok_or_else(|| reject("INVALID_MESSAGE", "missing `size`"))?;
+                let total_chunks = u32_field("totalChunks")?;
+                if size > MAX_TRANSFER_BYTES {
+                    return Err(reject(
+                        "LIMIT_EXCEEDED",
+                        format!("offer of {size} bytes exceeds {MAX_TRANSFER_BYTES}"),
+                    ));
+                }
+                if self.is_busy() {
+                    return Err(reject("INVALID_STATE", "transfer already active"));
+                }
+                if self.pq_pending() {
+                    return Err(reject("INVALID_STATE", "offer before PQ handshake"));
+                }
+                let expected_hash = if self.has(FILE_HASH_CAPABILITY) {
+                    msg["fileHash"].as_str()
+                } else {
+                    None
+                };
+                self.receive = ReceiveSession::new();
+                self.receive
+                    .on_file_offer(tid, size, total_chunks, expected_hash)
+                    .map_err(transfer)?;
+                self.incoming = Some(Incoming {
+                    file_name: file_name.to_string(),
+                    transfer_id: tid.to_string(),
+                    size,
+                    received: 0,
+                    btr: None,
+                });
+                self.events.push(LinkEvent::Offered {
+                    transfer_id: tid.to_string(),
+                    file_name: file_name.to_string(),
+                    size,
+                });
+                Ok(())
+            }
+            "file-accept" => {
+                let tid = str_field("transferId")?;
+                if self.outgoing.is_none() {
+                    return Err(reject("INVALID_STATE", "no outgoing offer"));
+                }
+                self.send.on_accept(tid).map_err(transfer)
+            }
+            "file-chunk" => {
+                let tid = str_field("transferId")?;
+                let index = u32_field("chunkIndex")?;
+                let data = from_base64(str_field("chunk")?)
+                    .map_err(|e| reject("INVALID_MESSAGE", e.to_string()))?;
+                let data = if self.btr.is_some() {
+                    self.open_ratcheted(tid, header, &data)?
+                } else {
+                    data
+                };
+                self.receive
+                    .on_file_chunk(tid, index, &data)
+                    .map_err(transfer)?;
+                if let Some(incoming) = &mut self.incoming {
+                    incoming.received += data.len() as u64;
+                    self.events.push(LinkEvent::ReceiveProgress {
+                        transfer_id: incoming.transfer_id.clone(),
+                        file_name: incoming.file_name.clone(),
+                        bytes: incoming.received,
+                        total: incoming.size,
+                    });
+                }
+                Ok(())
+            }
+            "file-finish" => {
+                let tid = str_field("transferId")?;
+                self.receive
+                    .on_file_finish(tid, Some(&Sha256Verifier))
+                    .map_err(transfer)?;
+                let bytes = self.receive.completed_bytes().unwrap_or_default().to_vec();
+                let hash_verified = self.receive.hash_verified();
+                self.receive = ReceiveSession::new();
+                if let Some(mut incoming) = self.incoming.take() {
+                    if let Some(ctx) = &mut incoming.btr {
+                        ctx.cleanup_complete();
+                    }
+                    if let Some(engine) = &mut self.btr {
+                        engine.end_transfer();
+                    }
+                    self.events.push(LinkEvent::Received {
+                        transfer_id: incoming.transfer_id,
+                        file_name: incoming.file_name,
+                        bytes,
+                        hash_verified,
+                    });
+                }
+                Ok(())
+            }
+            #[cfg(feature = "pq-hybrid")]
+            "pq-ciphertext" => self.on_pq_ciphertext(msg, wire),
+            #[cfg(feature = "pq-hybrid")]
+            "pq-confirm" => self.on_pq_confirm(msg),
+            other => Err(reject(
+                "UNKNOWN_MESSAGE_TYPE",
+                format!("unknown message type `{other}`"),
+            )),
+        }
+    }
+
+    fn open_ratcheted(
+        &mut self,
+        tid: &str,
+        header: &Value,
+        sealed: &[u8],
+    ) -> Result<Vec<u8>, Rejection> {
+        let (Some(engine), Some(incoming)) = (&mut self.btr, &mut self.incoming) else {
+            return Err(reject(
+                "RATCHET_STATE_ERROR",
+                "chunk without an active transfer",
+            ));
+        };
+        if incoming.transfer_id != tid {
+            return Err(reject("RATCHET_STATE_ERROR", "chunk for another transfer"));
+        }
+        let chain_index = header["chain_index"]
+            .as_u64()
+            .and_then(|i| u32::try_from(i).ok())
+            .ok_or_else(|| reject("RATCHET_STATE_ERROR", "missing `chain_index`"))?;
+        let transfer_id: [u8; 16] = from_hex(tid)
+            .ok()
+            .and_then(|id| id.try_into().ok())
+            .ok_or_else(|| reject("RATCHET_STATE_ERROR", "transferId is not 16 bytes"))?;
+
+        if incoming.btr.is_none() {
+            let ratchet_public_key = header["ratchet_public_key"]
+                .as_str()
+                .and_then(key32)
+                .ok_or_else(|| reject("RATCHET_STATE_ERROR", "missing `ratchet_public_key`"))?;
+            let ctx = engine
+                .begin_transfer_receive_with_key(
+                    &transfer_id,
+                    &ratchet_public_key,
+                    &self.ephemeral.secret_key,
+                )
+                .map_err(ratchet)?;
+            if header["ratchet_generation"].as_u64() != Some(u64::from(ctx.generation())) {
+                return Err(reject("RATCHET_STATE_ERROR", "ratchet generation mismatch"));
+            }
+            incoming.btr = Some(ctx);
+        }
+        let ctx = incoming.btr.as_mut().expect("ratchet context just set");
+        engine
+            .check_replay(&transfer_id, ctx.generation(), chain_index)
+            .map_err(ratchet)?;
+        ctx.open_chunk(chain_index, sealed).map_err(ratchet)
+    }
+
+    fn pump_chunks(&mut self, wire: &mut dyn Wire) -> Result<(), Rejection> {
+        loop {
+            // A transport with blocking sends never reports buffered bytes;
+            // stop once it has failed instead of sealing the rest into it.
+            if !wire.is_open() {
+                return Ok(());
+            }
+            if self.backpressure.evaluate(wire) == Backpressure::Pause
+                || self.backpressure.is_paused()
+            {
+                return Ok(());
+            }
+            let Some(chunk) = self.send.next_chunk().map_err(transfer)? else {
+                break;
+            };
+            let Some(outgoing) = &mut self.outgoing else {
+                return Ok(());
+            };
+
+            outgoing.sent += chunk.data.len() as u64;
+            let mut btr_fields = Map::new();
+            let data = match &mut outgoing.btr {
+                Some(ctx) => {
+                    let (chain_index, sealed) = ctx.seal_chunk(&chunk.data).map_err(ratchet)?;
+                    btr_fields.insert("chain_index".into(), json!(chain_index));
+                    if let Some(public_key) = outgoing.ratchet_public_key.take() {
+                        btr_fields
+                            .insert("ratchet_public_key".into(), json!(to_base64(&public_key)));
+                        btr_fields.insert("ratchet_generation".into(), json!(ctx.generation()));
+                    }
+                    sealed
+                }
+                None => chunk.data,
+            };
+            let progress = LinkEvent::SendProgress {
+                transfer_id: outgoing.transfer_id.clone(),
+                file_name: outgoing.file_name.clone(),
+                bytes: outgoing.sent,
+                total: outgoing.size,
+            };
+            let msg = json!({
+                "type": "file-chunk",
+                "transferId": chunk.transfer_id,
+                "chunkIndex": chunk.chunk_index,
+                "totalChunks": chunk.total_chunks,
+                "chunk": to_base64(&data),
+            });
+            self.send_message(&msg, btr_fields, wire)?;
+            self.events.push(progress);
+        }
+
+        let tid = self.send.finish().map_err(transfer)?;
+        self.send_message(
+            &json!({ "type": "file-finish", "transferId": tid }),
+            Map::new(),
+            wire,
+        )?;
+        if let Some(mut outgoing) = self.outgoing.take() {
+            if let Some(ctx) = &mut outgoing.btr {
+                ctx.cleanup_complete();
+            }
+            if let Some(engine) = &mut self.btr {
+                engine.end_transfer();
+            }
+            self.events.push(LinkEvent::Sent {
+                transfer_id: outgoing.transfer_id,
+                file_name: outgoing.file_name,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// In-memory socket end: frames queue up until the test delivers them.
+    #[derive(Default)]
+    struct Pipe {
+        frames: Vec<String>,
+    }
+
+    impl TransportQuery for Pipe {
+        fn is_open(&self) -> bool {
+            true
+        }
+        fn buffered_bytes(&self) -> usize {
+            self.frames.iter().map(String::len).sum()
+        }
+        fn max_message_size(&self) -> usize {
+            usize::MAX
+        }
+    }
+
+    impl Wire for Pipe {
+        fn send_text(&mut self, frame: &str) {
+            self.frames.push(frame.to_string());
+        }
+    }
+
+    struct Pair {
+        a: PeerLink,
+        b: PeerLink,
+        a_out: Pipe,
+        b_out: Pipe,
+    }
+
+    /// Two links that have swapped ephemeral keys, as over signaling.
+    fn linked(a_caps: &[&str], b_caps: &[&str]) -> (PeerLink, PeerLink) {
+        let mut a = PeerLink::new([1u8; 32], a_caps);
+        let mut b = PeerLink::new([2u8; 32], b_caps);
+        a.set_remote_ephemeral(b.local_ephemeral_key());
+        b.set_remote_ephemeral(a.local_ephemeral_key());
+        (a, b)
+    }
+
+    impl Pair {
+        fn new(a_caps: &[&str], b_caps: &[&str]) -> Self {
+            let (a, b) = linked(a_caps, b_caps);
+            let mut pair = Self {
+                a,
+                b,
+                a_out: Pipe::default(),
+                b_out: Pipe::default(),
+            };
+            pair.a.start(&mut pair.a_out);
+            pair.b.start(&mut pair.b_out);
+            pair.settle();
+            pair
+        }
+
+        /// Pump both senders and deliver frames until both pipes drain.
+        /// Pending offers are accepted, as after a confirmed SAS.
+        fn settle(&mut self) {
+            loop {
+                if self.a.has_pending_offer() {
+                    self.a.accept_offer(&mut self.a_out).unwrap();
+                }
+                if self.b.has_pending_offer() {
+                    self.b.accept_offer(&mut self.b_out).unwrap();
+                }
+                self.a.pump(&mut self.a_out);
+                self.b.pump(&mut self.b_out);
+                if self.a_out.frames.is_empty() && self.b_out.frames.is_empty() {
+                    return;
+                }
+                for frame in std::mem::take(&mut self.a_out.frames) {
+                    self.b.on_frame(&frame, &mut self.b_out);
+                }
+                for frame in std::mem::take(&mut self.b_out.frames) {
+                    self.a.on_frame(&frame, &mut self.a_out);
+                }
+            }
+        }
+    }
+
+    fn drain(link: &mut PeerLink) -> Vec<LinkEvent> {
+        std::iter::from_fn(|| link.poll_event()).collect()
+    }
+
+    fn sas(events: &[LinkEvent]) -> String {
+        events
+            .iter()
+            .find_map(|e| match e {
+                LinkEvent::Established { sas, .. } => Some(sas.clone()),
+                _ => None,
+            })
+            .expect("no Established event")
+    }
+
+    fn received(events: &[LinkEvent]) -> (String, Vec<u8>) {
+        events
+            .iter()
+            .find_map(|e| match e {
+                LinkEvent::Received {
+                    file_name,
+                    bytes,
+                    hash_verified: true,
+                    ..
+                } => Some((file_name.clone(), bytes.clone())),
+                _ => None,
+            })
+            .expect("no Received event")
+    }
+
+    fn payload(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i % 251) as u8).collect()
+    }
+
+    #[test]
+    fn hello_establishes_with_matching_sas() {
+        let mut pair = Pair::new(&CAPABILITIES, &CAPABILITIES);
+        assert!(pair.a.is_established() && pair.b.is_established());
+        assert_eq!(pair.a.negotiated.len(), CAPABILITIES.len());
+        assert!(pair.a.btr.is_some() && pair.b.btr.is_some());
+
+        let events = drain(&mut pair.a);
+        let sas_a = sas(&events);
+        assert_eq!(sas_a.len(), 6);
+        assert_eq!(sas_a, sas(&drain(&mut pair.b)));
+        assert!(matches!(
+            events[0],
+            LinkEvent::Established { remote_identity, .. } if remote_identity == [2u8; 32]
+        ));
+    }
+
+    #[test]
+    fn ratcheted_transfers_round_trip_both_ways() {
+        let mut pair = Pair::new(&CAPABILITIES, &CAPABILITIES);
+        drain(&mut pair.a);
+        drain(&mut pair.b);
+
+        // Several chunks, and more than the high watermark so pump() has
+        // to pause and resume across settle() rounds.
+        let data = payload(HIGH_WATERMARK + 40_000);
+        pair.a
+            .send_file("big.bin", data.clone(), &mut pair.a_out)
+            .unwrap();
+        pair.settle();
+        let events = drain(&mut pair.a);
+        assert!(matches!(
+            events.last(),
+            Some(LinkEvent::Sent { file_name, .. }) if file_name == "big.bin"
+        ));
+        let total = data.len() as u64;
+        assert!(events.iter().any(|e| matches!(
+            e,
+            LinkEvent::SendProgress { bytes, total: t, .. } if *bytes == total && *t == total
+        )));
+        assert_eq!(received(&drain(&mut pair.b)), ("big.bin".to_string(), data));
+
+        // The reverse direction ratchets the next generation on both ends.
+        let reply = b"thanks".to_vec();
+        pair.b
+            .send_file("reply.txt", reply.clone(), &mut pair.b_out)
+            .unwrap();
+        pair.settle();
+        assert_eq!(
+            received(&drain(&mut pair.a)),
+            ("reply.txt".to_string(), reply)
+        );
+        assert!(!pair.a.is_busy() && !pair.b.is_busy());
+    }
+
+    #[test]
+    fn transfer_without_ratchet_or_envelope() {
+        let caps = [FILE_HASH_CAPABILITY];
+        let mut pair = Pair::new(&CAPABILITIES, &caps);
+        assert!(pair.a.btr.is_none());
+        drain(&mut pair.b);
+
+        let data = payload(50_000);
+        pair.a
+            .send_file("plain.bin", data.clone(), &mut pair.a_out)
+            .unwrap();
+        assert!(pair.a_out.frames[0].contains("\"file-offer\""));
+        pair.settle();
+        assert_eq!(
+            received(&drain(&mut pair.b)),
+            ("plain.bin".to_string(), data)
+        );
+    }
+
+    #[test]
+    fn offer_is_held_until_accepted() {
+        let mut pair = Pair::new(&CAPABILITIES, &CAPABILITIES);
+        drain(&mut pair.b);
+        let data = payload(40_000);
+        pair.a
+            .send_file("held.bin", data.clone(), &mut pair.a_out)
+            .unwrap();
+        pair.a.pump(&mut pair.a_out);
+        assert_eq!(pair.a_out.frames.len(), 1, "no chunks before file-accept");
+
+        let offer = pair.a_out.frames.remove(0);
+        pair.b.on_frame(&offer, &mut pair.b_out);
+        assert!(pair.b.has_pending_offer());
+        assert!(pair.b_out.frames.is_empty());
+        assert!(matches!(
+            &drain(&mut pair.b)[..],
+            [LinkEvent::Offered { file_name, size, .. }]
+                if file_name == "held.bin" && *size == data.len() as u64
+        ));
+
+        pair.b.accept_offer(&mut pair.b_out).unwrap();
+        assert!(pair.b.accept_offer(&mut pair.b_out).is_err());
+        pair.settle();
+        assert_eq!(
+            received(&drain(&mut pair.b)),
+            ("held.bin".to_string(), data)
+        );
+    }
+
+    #[test]
+    fn chunk_before_accept_fails_link() {
+        let caps = [FILE_HASH_CAPABILITY];
+        let mut pair = Pair::new(&caps, &caps);
+        drain(&mut pair.b);
+        pair.a.send_file("f", vec![7; 10], &mut pair.a_out).unwrap();
+        pair.b
+            .on_frame(&pair.a_out.frames.remove(0), &mut pair.b_out);
+        let chunk = json!({
+            "type": "file-chunk", "transferId": pair.a.send.state().transfer_id(),
+            "chunkIndex": 0, "totalChunks": 1, "chunk": to_base64(&[7; 10]),
+        });
+        pair.b.on_frame(&chunk.to_string(), &mut pair.b_out);
+        assert!(matches!(
+            drain(&mut pair.b).last(),
+            Some(LinkEvent::Failed { code, .. }) if code == "INVALID_STATE"
+        ));
+    }
+
+    #[test]
+    fn send_requires_established_and_idle_link() {
+        let mut link = PeerLink::new([1u8; 32], &CAPABILITIES);
+        let mut out = Pipe::default();
+        assert!(link.send_file("x", vec![1], &mut out).is_err());
+
+        let mut pair = Pair::new(&CAPABILITIES, &CAPABILITIES);
+        pair.a
+            .send_file("one", vec![1; 10], &mut pair.a_out)
+            .unwrap();
+        assert!(pair
+            .a
+            .send_file("two", vec![2; 10], &mut pair.a_out)
+            .is_err());
+    }
+
+    #[test]
+    fn start_without_signaled_key_fails_link() {
+        let mut link = PeerLink::new([1u8; 32], &CAPABILITIES);
+        let mut out = Pipe::default();
+        link.start(&mut out);
+        assert!(link.closed);
+        assert!(matches!(
+            drain(&mut link).last(),
+            Some(LinkEvent::Failed { code, .. }) if code == "INVALID_STATE"
+        ));
+        assert!(!out.frames.iter().any(|f| f.contains("\"hello\"")));
+    }
+
+    #[test]
+    fn plaintext_after_envelope_negotiation_fails_link() {
+        let mut pair = Pair::new(&CAPABILITIES, &CAPABILITIES);
+        drain(&mut pair.b);
+        let offer = json!({
+            "type": "file-offer", "transferId": "00", "filename": "x",
+            "size": 1, "totalChunks": 1,
+        });
+        pair.b.on_frame(&offer.to_string(), &mut pair.b_out);
+        assert!(pair.b.closed);
+        assert_eq!(
+            drain(&mut pair.b),
+            vec![LinkEvent::Failed {
+                code: "ENVELOPE_REQUIRED".into(),
+                detail: "plaintext message after envelope-v1 negotiation".into(),
+            }]
+        );
+
+        // The enveloped error closes the other end too.
+        pair.settle();
+        assert!(pair.a.closed);
+        assert!(matches!(
+            drain(&mut pair.a).last(),
+            Some(LinkEvent::Failed { code, .. }) if code == "ENVELOPE_REQUIRED"
+        ));
+    }
+
+    #[test]
+    fn duplicate_hello_and_replayed_chunk_are_rejected() {
+        let (mut link, mut peer) = linked(&CAPABILITIES, &CAPABILITIES);
+        let (mut out, mut peer_out) = (Pipe::default(), Pipe::default());
+        link.start(&mut out);
+        peer.start(&mut peer_out);
+        let hello = peer_out.frames.remove(0);
+        link.on_frame(&hello, &mut out);
+        assert!(link.is_established());
+        link.on_frame(&hello, &mut out);
+        assert!(matches!(
+            drain(&mut link).last(),
+            Some(LinkEvent::Failed { code, .. }) if code == "DUPLICATE_HELLO"
+        ));
+
+        let mut pair = Pair::new(&CAPABILITIES, &CAPABILITIES);
+        drain(&mut pair.b);
+        pair.a
+            .send_file("f", payload(40_000), &mut pair.a_out)
+            .unwrap();
+        pair.b
+            .on_frame(&pair.a_out.frames.remove(0), &mut pair.b_out);
+        pair.b.accept_offer(&mut pair.b_out).unwrap();
+        pair.a
+            .on_frame(&pair.b_out.frames.remove(0), &mut pair.a_out);
+        pair.a.pump(&mut pair.a_out);
+        let chunk0 = pair.a_out.frames.remove(0);
+        pair.b.on_frame(&chunk0, &mut pair.b_out);
+        assert!(!pair.b.closed);
+        pair.b.on_frame(&chunk0, &mut pair.b_out);
+        assert!(matches!(
+            drain(&mut pair.b).last(),
+            Some(LinkEvent::Failed { code, .. }) if code == "RATCHET_CHAIN_ERROR"
+        ));
+    }
+
+    /// Overwrite the transcript a KEM initiator expects, as if it had seen
+    /// different HELLOs from its peer. Returns whether it was waiting.
+    #[cfg(feature = "pq-hybrid")]
+    fn corrupt_expected_transcript(link: &mut PeerLink) -> bool {
+        match &mut link.pq {
+            PqState::AwaitCiphertext { transcript, .. } => {
+                *transcript = [0u8; 32];
+                true
+            }
+            _ => false,
+        }
+    }
+
+    #[cfg(feature = "pq-hybrid")]
+    #[test]
+    fn pq_hybrid_handshake_completes_before_transfers() {
+        let mut pair = Pair::new(&CAPABILITIES, &CAPABILITIES);
+        assert!(pair.a.pq_negotiated() && pair.b.pq_negotiated());
+        assert!(!pair.a.pq_pending() && !pair.b.pq_pending());
+        drain(&mut pair.a);
+        drain(&mut pair.b);
+
+        let data = payload(1000);
+        pair.a
+            .send_file("pq.bin", data.clone(), &mut pair.a_out)
+            .unwrap();
+        pair.settle();
+        assert_eq!(received(&drain(&mut pair.b)), ("pq.bin".to_string(), data));
+    }
+
+    #[cfg(feature = "pq-hybrid")]
+    #[test]
+    fn pq_transcript_mismatch_fails_link() {
+        let (a, b) = linked(&CAPABILITIES, &CAPABILITIES);
+        let mut pair = Pair {
+            a,
+            b,
+            a_out: Pipe::default(),
+            b_out: Pipe::default(),
+        };
+        pair.a.start(&mut pair.a_out);
+        pair.b.start(&mut pair.b_out);
+        // One frame at a time, so the initiator is caught before the
+        // responder's pq-ciphertext reaches it.
+        loop {
+            if pair.a_out.frames.is_empty() {
+                let frame = pair.b_out.frames.remove(0);
+                pair.a.on_frame(&frame, &mut pair.a_out);
+            } else {
+                let frame = pair.a_out.frames.remove(0);
+                pair.b.on_frame(&frame, &mut pair.b_out);
+            }
+            if corrupt_expected_transcript(&mut pair.a) || corrupt_expected_transcript(&mut pair.b)
+            {
+                break;
+            }
+        }
+        pair.settle();
+        assert!(pair.a.closed && pair.b.closed);
+        for link in [&mut pair.a, &mut pair.b] {
+            assert!(matches!(
+                drain(link).last(),
+                Some(LinkEvent::Failed { code, .. }) if code == "PQ_DOWNGRADE_REJECTED"
+            ));
+        }
+    }
+}
@@ -6,7 +6,8 @@
 //! These bindings wrap the canonical Rust implementations. The browser
 //! path calls these instead of the TS tweetnacl/noble-hashes equivalents.
 //!
-//! Scope: crypto + session only. BTR + transfer SM deferred to RB4.
+//! Scope: crypto + session (RB3), BTR + send/receive transfer state
+//! machines, backpressure, and integrity verification (RB4).
 
 use wasm_bindgen::prelude::*;
 
@@ -19,36 +20,112 @@ pub fn init() {
     console_error_panic_hook::set_once();
 }
 
+// ── Typed Errors ──────────────────────────────────────────────────
+
+/// Error thrown across the WASM boundary.
+///
+/// `code` is always a canonical wire error code from bolt-core
+/// `WIRE_ERROR_CODES`, so TS callers switch on it instead of parsing
+/// messages. Argument validation failures use `INVALID_MESSAGE`.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct WasmBoltError {
+    code: &'static str,
+    message: String,
+}
+
+#[wasm_bindgen]
+impl WasmBoltError {
+    /// Canonical wire error code.
+    #[wasm_bindgen(getter)]
+    pub fn code(&self) -> String {
+        self.code.to_string()
+    }
+
+    /// Human-readable detail (diagnostics only — do not match on it).
+    #[wasm_bindgen(getter)]
+    pub fn message(&self) -> String {
+        self.message.clone()
+    }
+}
+
+impl WasmBoltError {
+    fn invalid_input(message: &str) -> Self {
+        Self {
+            code: "INVALID_MESSAGE",
+            message: message.to_string(),
+        }
+    }
+}
+
+impl From<bolt_core::errors::BoltError> for WasmBoltError {
+    fn from(e: bolt_core::errors::BoltError) -> Self {
+        Self {
+            code: e.code(),
+            message: e.to_string(),
+        }
+    }
+}
+
+impl From<bolt_btr::BtrError> for WasmBoltError {
+    fn from(e: bolt_btr::BtrError) -> Self {
+        Self {
+            code: e.wire_code(),
+            message: e.to_string(),
+        }
+    }
+}
+
+impl From<bolt_transfer_core::TransferError> for WasmBoltError {
+    fn from(e: bolt_transfer_core::TransferError) -> Self {
+        Self {
+            code: e.code(),
+            message: e.to_string(),
+        }
+    }
+}
+
 // ── Key Generation ────────────────────────────────────────────────
 
+/// X25519 keypair handle. Secret key is zeroized when the handle is freed.
+#[wasm_bindgen]
+pub struct WasmKeyPair {
+    inner: bolt_core::crypto::KeyPair,
+}
+
+#[wasm_bindgen]
+impl WasmKeyPair {
+    /// Public key (32 bytes).
+    #[wasm_bindgen(js_name = "publicKey", getter)]
+    pub fn public_key(&self) -> Vec<u8> {
+        self.inner.public_key.to_vec()
+    }
+
+    /// Secret key (32 bytes).
+    #[wasm_bindgen(js_name = "secretKey", getter)]
+    pub fn secret_key(&self) -> Vec<u8> {
+        self.inner.secret_key.to_vec()
+    }
+}
+
 /// Generate an ephemeral X25519 keypair for session use.
 ///
-/// Returns a JsValue containing { publicKey: Uint8Array, secretKey: Uint8Array }.
 /// Parity: TS `generateEphemeralKeyPair()` (tweetnacl `box.keyPair()`).
 #[wasm_bindgen(js_name = "generateEphemeralKeyPair")]
-pub fn generate_ephemeral_keypair() -> Result<JsValue, JsValue> {
-    let kp = bolt_core::crypto::generate_ephemeral_keypair();
-    let obj = js_sys::Object::new();
-    let pk = js_sys::Uint8Array::from(&kp.public_key[..]);
-    let sk = js_sys::Uint8Array::from(&kp.secret_key[..]);
-    js_sys::Reflect::set(&obj, &"publicKey".into(), &pk)?;
-    js_sys::Reflect::set(&obj, &"secretKey".into(), &sk)?;
-    Ok(obj.into())
+pub fn generate_ephemeral_keypair() -> WasmKeyPair {
+    WasmKeyPair {
+        inner: bolt_core::crypto::generate_ephemeral_keypair(),
+    }
 }
 
 /// Generate a persistent identity X25519 keypair.
 ///
-/// Returns a JsValue containing { publicKey: Uint8Array, secretKey: Uint8Array }.
 /// Parity: TS `generateIdentityKeyPair()`.
 #[wasm_bindgen(js_name = "generateIdentityKeyPair")]
-pub fn generate_identity_keypair() -> Result<JsValue, JsValue> {
-    let kp = bolt_core::identity::generate_identity_keypair();
-    let obj = js_sys::Object::new();
-    let pk = js_sys::Uint8Array::from(&kp.public_key[..]);
-    let sk = js_sys::Uint8Array::from(&kp.secret_key[..]);
-    js_sys::Reflect::set(&obj, &"publicKey".into(), &pk)?;
-    js_sys::Reflect::set(&obj, &"secretKey".into(), &sk)?;
-    Ok(obj.into())
+pub fn generate_identity_keypair() -> WasmKeyPair {
+    WasmKeyPair {
+        inner: bolt_core::identity::generate_identity_keypair(),
+    }
 }
 
 // ── NaCl Box (XSalsa20-Poly1305) ─────────────────────────────────
@@ -62,16 +139,15 @@ pub fn seal_box_payload(
     plaintext: &[u8],
     remote_public_key: &[u8],
     sender_secret_key: &[u8],
-) -> Result<String, JsValue> {
+) -> Result<String, WasmBoltError> {
     let rpk: [u8; 32] = remote_public_key
         .try_into()
-        .map_err(|_| JsValue::from_str("remote_public_key must be 32 bytes"))?;
+        .map_err(|_| WasmBoltError::invalid_input("remote_public_key must be 32 bytes"))?;
     let ssk: [u8; 32] = sender_secret_key
         .try_into()
-        .map_err(|_| JsValue::from_str("sender_secret_key must be 32 bytes"))?;
+        .map_err(|_| WasmBoltError::invalid_input("sender_secret_key must be 32 bytes"))?;
 
-    bolt_core::crypto::seal_box_payload(plaintext, &rpk, &ssk)
-        .map_err(|e| JsValue::from_str(&e.to_string()))
+    bolt_core::crypto::seal_box_payload(plaintext, &rpk, &ssk).map_err(WasmBoltError::from)
 }
 
 /// Open a sealed NaCl box payload. Expects base64(nonce || ciphertext).
@@ -82,46 +158,141 @@ pub fn open_box_payload(
     sealed: &str,
     sender_public_key: &[u8],
     receiver_secret_key: &[u8],
-) -> Result<Vec<u8>, JsValue> {
+) -> Result<Vec<u8>, WasmBoltError> {
     let spk: [u8; 32] = sender_public_key
         .try_into()
-        .map_err(|_| JsValue::from_str("sender_public_key must be 32 bytes"))?;
+        .map_err(|_| WasmBoltError::invalid_input("sender_public_key must be 32 bytes"))?;
     let rsk: [u8; 32] = receiver_secret_key
         .try_into()
-        .map_err(|_| JsValue::from_str("receiver_secret_key must be 32 bytes"))?;
+        .map_err(|_| WasmBoltError::invalid_input("receiver_secret_key must be 32 bytes"))?;
 
-    bolt_core::crypto::open_box_payload(sealed, &spk, &rsk)
-        .map_err(|e| JsValue::from_str(&e.to_string()))
+    bolt_core::crypto::open_box_payload(sealed, &spk, &rsk).map_err(WasmBoltError::from)
 }
 
 // ── SAS (Short Authentication String) ─────────────────────────────
 
-/// Compute 6-character SAS from identity + ephemeral public keys.
+/// SAS rendering (mirrors bolt-core `SasEncoding`).
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WasmSasEncoding {
+    /// 6 uppercase hex characters.
+    Hex = 0,
+    /// 4 PGP words.
+    Words = 1,
+    /// 7 space-separated emoji.
+    Emoji = 2,
+    /// 3 groups of 4 decimal digits.
+    Decimal = 3,
+}
+
+impl From<WasmSasEncoding> for bolt_core::sas::SasEncoding {
+    fn from(v: WasmSasEncoding) -> Self {
+        match v {
+            WasmSasEncoding::Hex => bolt_core::sas::SasEncoding::Hex,
+            WasmSasEncoding::Words => bolt_core::sas::SasEncoding::Words,
+            WasmSasEncoding::Emoji => bolt_core::sas::SasEncoding::Emoji,
+            WasmSasEncoding::Decimal => bolt_core::sas::SasEncoding::Decimal,
+        }
+    }
+}
+
+/// Compute SAS from identity + ephemeral public keys.
 ///
-/// Parity: TS `computeSas(identityA, identityB, ephemeralA, ephemeralB)`.
-/// Identical algorithm: SHA-256(sort32(id_a, id_b) || sort32(eph_a, eph_b)),
-/// first 6 hex chars, uppercase.
+/// Parity: TS `computeSas(identityA, identityB, ephemeralA, ephemeralB, encoding?)`.
+/// Identical algorithm: SHA-256(sort32(id_a, id_b) || sort32(eph_a, eph_b)).
+/// `encoding` defaults to `WasmSasEncoding.Hex` (first 6 hex chars uppercase).
 #[wasm_bindgen(js_name = "computeSas")]
 pub fn compute_sas(
     identity_a: &[u8],
     identity_b: &[u8],
     ephemeral_a: &[u8],
     ephemeral_b: &[u8],
-) -> Result<String, JsValue> {
+    encoding: Option<WasmSasEncoding>,
+) -> Result<String, WasmBoltError> {
+    let (id_a, id_b, eph_a, eph_b) = sas_keys(identity_a, identity_b, ephemeral_a, ephemeral_b)?;
+    Ok(bolt_core::sas::compute_sas_encoded(
+        &id_a,
+        &id_b,
+        &eph_a,
+        &eph_b,
+        encoding.unwrap_or(WasmSasEncoding::Hex).into(),
+    ))
+}
+
+/// One emoji of the emoji SAS with its English caption.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct WasmSasEmoji {
+    symbol: String,
+    name: String,
+}
+
+#[wasm_bindgen]
+impl WasmSasEmoji {
+    /// Emoji character(s).
+    #[wasm_bindgen(getter)]
+    pub fn symbol(&self) -> String {
+        self.symbol.clone()
+    }
+
+    /// English caption, for reading the SAS aloud.
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+/// Emoji SAS as symbol + caption pairs, in display order.
+///
+/// Returns 7 entries aligned with `computeSas(..., WasmSasEncoding.Emoji)`.
+#[wasm_bindgen(js_name = "computeSasEmoji")]
+pub fn compute_sas_emoji(
+    identity_a: &[u8],
+    identity_b: &[u8],
+    ephemeral_a: &[u8],
+    ephemeral_b: &[u8],
+) -> Result<Vec<WasmSasEmoji>, WasmBoltError> {
+    let (id_a, id_b, eph_a, eph_b) = sas_keys(identity_a, identity_b, ephemeral_a, ephemeral_b)?;
+    let rendered = bolt_core::sas::compute_sas_encoded(
+        &id_a,
+        &id_b,
+        &eph_a,
+        &eph_b,
+        bolt_core::sas::SasEncoding::Emoji,
+    );
+    Ok(rendered
+        .split(' ')
+        .zip(bolt_core::sas::compute_sas_emoji_names(
+            &id_a, &id_b, &eph_a, &eph_b,
+        ))
+        .map(|(symbol, name)| WasmSasEmoji {
+            symbol: symbol.to_string(),
+            name: name.to_string(),
+        })
+        .collect())
+}
+
+type SasKeys = ([u8; 32], [u8; 32], [u8; 32], [u8; 32]);
+
+fn sas_keys(
+    identity_a: &[u8],
+    identity_b: &[u8],
+    ephemeral_a: &[u8],
+    ephemeral_b: &[u8],
+) -> Result<SasKeys, WasmBoltError> {
     let id_a: [u8; 32] = identity_a
         .try_into()
-        .map_err(|_| JsValue::from_str("identity_a must be 32 bytes"))?;
+        .map_err(|_| WasmBoltError::invalid_input("identity_a must be 32 bytes"))?;
     let id_b: [u8; 32] = identity_b
         .try_into()
-        .map_err(|_| JsValue::from_str("identity_b must be 32 bytes"))?;
+        .map_err(|_| WasmBoltError::invalid_input("identity_b must be 32 bytes"))?;
     let eph_a: [u8; 32] = ephemeral_a
         .try_into()
-        .map_err(|_| JsValue::from_str("ephemeral_a must be 32 bytes"))?;
+        .map_err(|_| WasmBoltError::invalid_input("ephemeral_a must be 32 bytes"))?;
     let eph_b: [u8; 32] = ephemeral_b
         .try_into()
-        .map_err(|_| JsValue::from_str("ephemeral_b must be 32 bytes"))?;
-
-    Ok(bolt_core::sas::compute_sas(&id_a, &id_b, &eph_a, &eph_b))
+        .map_err(|_| WasmBoltError::invalid_input("ephemeral_b must be 32 bytes"))?;
+    Ok((id_a, id_b, eph_a, eph_b))
 }
 
 // ── Peer Code ─────────────────────────────────────────────────────
@@ -170,10 +341,10 @@ impl WasmBtrEngine {
     /// Create a new BTR engine from the ephemeral shared secret.
     /// Called after HELLO handshake when BTR is negotiated.
     #[wasm_bindgen(constructor)]
-    pub fn new(shared_secret: &[u8]) -> Result<WasmBtrEngine, JsValue> {
+    pub fn new(shared_secret: &[u8]) -> Result<WasmBtrEngine, WasmBoltError> {
         let ss: [u8; 32] = shared_secret
             .try_into()
-            .map_err(|_| JsValue::from_str("shared_secret must be 32 bytes"))?;
+            .map_err(|_| WasmBoltError::invalid_input("shared_secret must be 32 bytes"))?;
         Ok(WasmBtrEngine {
             inner: bolt_btr::BtrEngine::new(&ss),
         })
@@ -185,18 +356,18 @@ impl WasmBtrEngine {
         &mut self,
         transfer_id: &[u8],
         remote_ratchet_pub: &[u8],
-    ) -> Result<WasmBtrTransferCtx, JsValue> {
+    ) -> Result<WasmBtrTransferCtx, WasmBoltError> {
         let tid: [u8; 16] = transfer_id
             .try_into()
-            .map_err(|_| JsValue::from_str("transfer_id must be 16 bytes"))?;
+            .map_err(|_| WasmBoltError::invalid_input("transfer_id must be 16 bytes"))?;
         let rpub: [u8; 32] = remote_ratchet_pub
             .try_into()
-            .map_err(|_| JsValue::from_str("remote_ratchet_pub must be 32 bytes"))?;
+            .map_err(|_| WasmBoltError::invalid_input("remote_ratchet_pub must be 32 bytes"))?;
 
         let (ctx, local_pub) = self
             .inner
             .begin_transfer_send(&tid, &rpub)
-            .map_err(|e| JsValue::from_str(&format!("{:?}", e)))?;
+            .map_err(WasmBoltError::from)?;
 
         Ok(WasmBtrTransferCtx {
             inner: ctx,
@@ -213,21 +384,21 @@ impl WasmBtrEngine {
         transfer_id: &[u8],
         remote_ratchet_pub: &[u8],
         local_secret_key: &[u8],
-    ) -> Result<WasmBtrTransferCtx, JsValue> {
+    ) -> Result<WasmBtrTransferCtx, WasmBoltError> {
         let tid: [u8; 16] = transfer_id
             .try_into()
-            .map_err(|_| JsValue::from_str("transfer_id must be 16 bytes"))?;
+            .map_err(|_| WasmBoltError::invalid_input("transfer_id must be 16 bytes"))?;
         let rpub: [u8; 32] = remote_ratchet_pub
             .try_into()
-            .map_err(|_| JsValue::from_str("remote_ratchet_pub must be 32 bytes"))?;
+            .map_err(|_| WasmBoltError::invalid_input("remote_ratchet_pub must be 32 bytes"))?;
         let lsk: [u8; 32] = local_secret_key
             .try_into()
-            .map_err(|_| JsValue::from_str("local_secret_key must be 32 bytes"))?;
+            .map_err(|_| WasmBoltError::invalid_input("local_secret_key must be 32 bytes"))?;
 
         let ctx = self
             .inner
             .begin_transfer_receive_with_key(&tid, &rpub, &lsk)
-            .map_err(|e| JsValue::from_str(&format!("{:?}", e)))?;
+            .map_err(WasmBoltError::from)?;
 
         Ok(WasmBtrTransferCtx {
             inner: ctx,
@@ -265,23 +436,18 @@ pub struct WasmBtrTransferCtx {
 
 #[wasm_bindgen]
 impl WasmBtrTransferCtx {
-    /// Encrypt a chunk. Returns { chainIndex: number, sealed: Uint8Array }.
+    /// Encrypt a chunk at the next chain position.
     /// HOT PATH — called per 16 KiB chunk (~62 times per MiB).
     #[wasm_bindgen(js_name = "sealChunk")]
-    pub fn seal_chunk(&mut self, plaintext: &[u8]) -> Result<JsValue, JsValue> {
+    pub fn seal_chunk(&mut self, plaintext: &[u8]) -> Result<WasmSealedChunk, WasmBoltError> {
         let (chain_index, sealed) = self
             .inner
             .seal_chunk(plaintext)
-            .map_err(|e| JsValue::from_str(&format!("{:?}", e)))?;
-
-        let obj = js_sys::Object::new();
-        js_sys::Reflect::set(&obj, &"chainIndex".into(), &JsValue::from(chain_index))?;
-        js_sys::Reflect::set(
-            &obj,
-            &"sealed".into(),
-            &js_sys::Uint8Array::from(&sealed[..]),
-        )?;
-        Ok(obj.into())
+            .map_err(WasmBoltError::from)?;
+        Ok(WasmSealedChunk {
+            chain_index,
+            sealed,
+        })
     }
 
     /// Decrypt a chunk at expected chain position.
@@ -291,10 +457,10 @@ impl WasmBtrTransferCtx {
         &mut self,
         expected_chain_index: u32,
         sealed: &[u8],
-    ) -> Result<Vec<u8>, JsValue> {
+    ) -> Result<Vec<u8>, WasmBoltError> {
         self.inner
             .open_chunk(expected_chain_index, sealed)
-            .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
+            .map_err(WasmBoltError::from)
     }
 
     /// Current chain index.
@@ -333,6 +499,129 @@ impl WasmBtrTransferCtx {
     }
 }
 
+/// Output of `WasmBtrTransferCtx.sealChunk`.
+#[wasm_bindgen]
+pub struct WasmSealedChunk {
+    chain_index: u32,
+    sealed: Vec<u8>,
+}
+
+#[wasm_bindgen]
+impl WasmSealedChunk {
+    /// Chain index the chunk was sealed at.
+    #[wasm_bindgen(js_name = "chainIndex", getter)]
+    pub fn chain_index(&self) -> u32 {
+        self.chain_index
+    }
+
+    /// Sealed chunk bytes.
+    #[wasm_bindgen(getter)]
+    pub fn sealed(&self) -> Vec<u8> {
+        self.sealed.clone()
+    }
+}
+
+// ── Transfer State (§9, flattened for wasm-bindgen) ───────────────
+
+/// Transfer state tag (PROTOCOL.md §9).
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WasmTransferStateKind {
+    Idle = 0,
+    Offered = 1,
+    Accepted = 2,
+    Transferring = 3,
+    Paused = 4,
+    Completed = 5,
+    Cancelled = 6,
+    Error = 7,
+}
+
+/// Reason carried by the Cancelled state.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WasmCancelReason {
+    BySender = 0,
+    ByReceiver = 1,
+    Rejected = 2,
+}
+
+/// Snapshot of a `TransferState`.
+///
+/// Flattened DTO (wasm-bindgen does not support enums with data
+/// payloads): `transferId` is set for every kind except Idle and Error,
+/// `cancelReason` only for Cancelled, `detail` only for Error.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct WasmTransferState {
+    kind: WasmTransferStateKind,
+    transfer_id: Option<String>,
+    cancel_reason: Option<WasmCancelReason>,
+    detail: Option<String>,
+}
+
+#[wasm_bindgen]
+impl WasmTransferState {
+    /// State tag.
+    #[wasm_bindgen(getter)]
+    pub fn kind(&self) -> WasmTransferStateKind {
+        self.kind
+    }
+
+    /// Transfer ID, if the state carries one.
+    #[wasm_bindgen(js_name = "transferId", getter)]
+    pub fn transfer_id(&self) -> Option<String> {
+        self.transfer_id.clone()
+    }
+
+    /// Cancellation reason (Cancelled only).
+    #[wasm_bindgen(js_name = "cancelReason", getter)]
+    pub fn cancel_reason(&self) -> Option<WasmCancelReason> {
+        self.cancel_reason
+    }
+
+    /// Error detail (Error only).
+    #[wasm_bindgen(getter)]
+    pub fn detail(&self) -> Option<String> {
+        self.detail.clone()
+    }
+}
+
+impl From<&bolt_transfer_core::TransferState> for WasmTransferState {
+    fn from(state: &bolt_transfer_core::TransferState) -> Self {
+        use bolt_transfer_core::{CancelReason, TransferState};
+
+        let kind = match state {
+            TransferState::Idle => WasmTransferStateKind::Idle,
+            TransferState::Offered { .. } => WasmTransferStateKind::Offered,
+            TransferState::Accepted { .. } => WasmTransferStateKind::Accepted,
+            TransferState::Transferring { .. } => WasmTransferStateKind::Transferring,
+            TransferState::Paused { .. } => WasmTransferStateKind::Paused,
+            TransferState::Completed { .. } => WasmTransferStateKind::Completed,
+            TransferState::Cancelled { .. } => WasmTransferStateKind::Cancelled,
+            TransferState::Error { .. } => WasmTransferStateKind::Error,
+        };
+        let cancel_reason = match state {
+            TransferState::Cancelled { reason, .. } => Some(match reason {
+                CancelReason::BySender => WasmCancelReason::BySender,
+                CancelReason::ByReceiver => WasmCancelReason::ByReceiver,
+                CancelReason::Rejected => WasmCancelReason::Rejected,
+            }),
+            _ => None,
+        };
+        let detail = match state {
+            TransferState::Error { detail } => Some(detail.clone()),
+            _ => None,
+        };
+        Self {
+            kind,
+            transfer_id: state.transfer_id().map(String::from),
+            cancel_reason,
+            detail,
+        }
+    }
+}
+
 // ── Transfer State Machine (send-side §9 authority) ───────────────
 
 /// Opaque handle to SendSession. Rust owns transfer-state transitions.
@@ -353,7 +642,6 @@ impl WasmSendSession {
     }
 
     /// Begin an outbound transfer. Transitions Idle → Offered.
-    /// Returns { transferId, filename, size, totalChunks, chunkSize, fileHash? }.
     #[wasm_bindgen(js_name = "beginSend")]
     pub fn begin_send(
         &mut self,
@@ -361,107 +649,61 @@ impl WasmSendSession {
         payload: &[u8],
         filename: &str,
         file_hash: Option<String>,
-    ) -> Result<JsValue, JsValue> {
+    ) -> Result<WasmSendOffer, WasmBoltError> {
         let offer = self
             .inner
             .begin_send(transfer_id, payload.to_vec(), filename, file_hash)
-            .map_err(|e| JsValue::from_str(&e.to_string()))?;
-
-        let obj = js_sys::Object::new();
-        js_sys::Reflect::set(&obj, &"transferId".into(), &offer.transfer_id.into())?;
-        js_sys::Reflect::set(&obj, &"filename".into(), &offer.filename.into())?;
-        js_sys::Reflect::set(&obj, &"size".into(), &JsValue::from(offer.size as f64))?;
-        js_sys::Reflect::set(
-            &obj,
-            &"totalChunks".into(),
-            &JsValue::from(offer.total_chunks),
-        )?;
-        js_sys::Reflect::set(
-            &obj,
-            &"chunkSize".into(),
-            &JsValue::from(offer.chunk_size),
-        )?;
-        if let Some(h) = &offer.file_hash {
-            js_sys::Reflect::set(&obj, &"fileHash".into(), &h.into())?;
-        }
-        Ok(obj.into())
+            .map_err(WasmBoltError::from)?;
+        Ok(WasmSendOffer { inner: offer })
     }
 
     /// Receiver accepted. Transitions Offered → Transferring.
     #[wasm_bindgen(js_name = "onAccept")]
-    pub fn on_accept(&mut self, transfer_id: &str) -> Result<(), JsValue> {
+    pub fn on_accept(&mut self, transfer_id: &str) -> Result<(), WasmBoltError> {
         self.inner
             .on_accept(transfer_id)
-            .map_err(|e| JsValue::from_str(&e.to_string()))
+            .map_err(WasmBoltError::from)
     }
 
     /// Cancel. Transitions Offered/Transferring/Paused → Cancelled.
     #[wasm_bindgen(js_name = "onCancel")]
-    pub fn on_cancel(&mut self, transfer_id: &str) -> Result<(), JsValue> {
+    pub fn on_cancel(&mut self, transfer_id: &str) -> Result<(), WasmBoltError> {
         self.inner
             .on_cancel(transfer_id)
-            .map_err(|e| JsValue::from_str(&e.to_string()))
+            .map_err(WasmBoltError::from)
     }
 
     /// Pause sending. Transitions Transferring → Paused.
     #[wasm_bindgen(js_name = "onPause")]
-    pub fn on_pause(&mut self, transfer_id: &str) -> Result<(), JsValue> {
+    pub fn on_pause(&mut self, transfer_id: &str) -> Result<(), WasmBoltError> {
         self.inner
             .on_pause(transfer_id)
-            .map_err(|e| JsValue::from_str(&e.to_string()))
+            .map_err(WasmBoltError::from)
     }
 
     /// Resume sending. Transitions Paused → Transferring.
     #[wasm_bindgen(js_name = "onResume")]
-    pub fn on_resume(&mut self, transfer_id: &str) -> Result<(), JsValue> {
+    pub fn on_resume(&mut self, transfer_id: &str) -> Result<(), WasmBoltError> {
         self.inner
             .on_resume(transfer_id)
-            .map_err(|e| JsValue::from_str(&e.to_string()))
+            .map_err(WasmBoltError::from)
     }
 
-    /// Yield next chunk. Returns { transferId, chunkIndex, totalChunks, data } or null.
+    /// Yield next chunk, or undefined when all chunks have been yielded.
     #[wasm_bindgen(js_name = "nextChunk")]
-    pub fn next_chunk(&mut self) -> Result<JsValue, JsValue> {
-        let chunk = self
-            .inner
-            .next_chunk()
-            .map_err(|e| JsValue::from_str(&e.to_string()))?;
-
-        match chunk {
-            None => Ok(JsValue::NULL),
-            Some(c) => {
-                let obj = js_sys::Object::new();
-                js_sys::Reflect::set(&obj, &"transferId".into(), &c.transfer_id.into())?;
-                js_sys::Reflect::set(
-                    &obj,
-                    &"chunkIndex".into(),
-                    &JsValue::from(c.chunk_index),
-                )?;
-                js_sys::Reflect::set(
-                    &obj,
-                    &"totalChunks".into(),
-                    &JsValue::from(c.total_chunks),
-                )?;
-                js_sys::Reflect::set(
-                    &obj,
-                    &"data".into(),
-                    &js_sys::Uint8Array::from(&c.data[..]),
-                )?;
-                Ok(obj.into())
-            }
-        }
+    pub fn next_chunk(&mut self) -> Result<Option<WasmSendChunk>, WasmBoltError> {
+        let chunk = self.inner.next_chunk().map_err(WasmBoltError::from)?;
+        Ok(chunk.map(|inner| WasmSendChunk { inner }))
     }
 
     /// Finalize. Transitions Transferring → Completed. Returns transfer_id.
-    pub fn finish(&mut self) -> Result<String, JsValue> {
-        self.inner
-            .finish()
-            .map_err(|e| JsValue::from_str(&e.to_string()))
+    pub fn finish(&mut self) -> Result<String, WasmBoltError> {
+        self.inner.finish().map_err(WasmBoltError::from)
     }
 
-    /// Current state as string (for TS display/logging).
-    pub fn state(&self) -> String {
-        format!("{:?}", self.inner.state())
+    /// Current transfer state.
+    pub fn state(&self) -> WasmTransferState {
+        self.inner.state().into()
     }
 
     /// True if Transferring with chunks remaining.
@@ -471,19 +713,377 @@ impl WasmSendSession {
     }
 }
 
+impl Default for WasmSendSession {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Offer metadata returned by `WasmSendSession.beginSend`.
+#[wasm_bindgen]
+pub struct WasmSendOffer {
+    inner: bolt_transfer_core::SendOffer,
+}
+
+#[wasm_bindgen]
+impl WasmSendOffer {
+    #[wasm_bindgen(js_name = "transferId", getter)]
+    pub fn transfer_id(&self) -> String {
+        self.inner.transfer_id.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn filename(&self) -> String {
+        self.inner.filename.clone()
+    }
+
+    /// File size in bytes.
+    #[wasm_bindgen(getter)]
+    pub fn size(&self) -> f64 {
+        self.inner.size as f64
+    }
+
+    #[wasm_bindgen(js_name = "totalChunks", getter)]
+    pub fn total_chunks(&self) -> u32 {
+        self.inner.total_chunks
+    }
+
+    #[wasm_bindgen(js_name = "chunkSize", getter)]
+    pub fn chunk_size(&self) -> u32 {
+        self.inner.chunk_size
+    }
+
+    /// SHA-256 hex, present when bolt.file-hash is negotiated.
+    #[wasm_bindgen(js_name = "fileHash", getter)]
+    pub fn file_hash(&self) -> Option<String> {
+        self.inner.file_hash.clone()
+    }
+}
+
+/// One outbound chunk returned by `WasmSendSession.nextChunk`.
+#[wasm_bindgen]
+pub struct WasmSendChunk {
+    inner: bolt_transfer_core::SendChunk,
+}
+
+#[wasm_bindgen]
+impl WasmSendChunk {
+    #[wasm_bindgen(js_name = "transferId", getter)]
+    pub fn transfer_id(&self) -> String {
+        self.inner.transfer_id.clone()
+    }
+
+    #[wasm_bindgen(js_name = "chunkIndex", getter)]
+    pub fn chunk_index(&self) -> u32 {
+        self.inner.chunk_index
+    }
+
+    #[wasm_bindgen(js_name = "totalChunks", getter)]
+    pub fn total_chunks(&self) -> u32 {
+        self.inner.total_chunks
+    }
+
+    /// Chunk plaintext (encrypt before sending).
+    #[wasm_bindgen(getter)]
+    pub fn data(&self) -> Vec<u8> {
+        self.inner.data.clone()
+    }
+}
+
+// ── Transfer State Machine (receive-side §9 authority) ────────────
+
+/// Opaque handle to ReceiveSession. Rust owns receive-state transitions
+/// and reassembly; TS forwards wire events and renders the result.
+#[wasm_bindgen]
+pub struct WasmReceiveSession {
+    inner: bolt_transfer_core::ReceiveSession,
+}
+
+#[wasm_bindgen]
+impl WasmReceiveSession {
+    /// Create a new receive session in Idle state.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> WasmReceiveSession {
+        WasmReceiveSession {
+            inner: bolt_transfer_core::ReceiveSession::new(),
+        }
+    }
+
+    /// Inbound offer. Transitions Idle → Offered.
+    /// Pass `expectedHash` only when bolt.file-hash is negotiated.
+    #[wasm_bindgen(js_name = "onFileOffer")]
+    pub fn on_file_offer(
+        &mut self,
+        transfer_id: &str,
+        size: f64,
+        total_chunks: u32,
+        expected_hash: Option<String>,
+    ) -> Result<(), WasmBoltError> {
+        if !(size.is_finite() && size >= 0.0 && size.fract() == 0.0) {
+            return Err(WasmBoltError::invalid_input(
+                "size must be a non-negative integer",
+            ));
+        }
+        self.inner
+            .on_file_offer(
+                transfer_id,
+                size as u64,
+                total_chunks,
+                expected_hash.as_deref(),
+            )
+            .map_err(WasmBoltError::from)
+    }
+
+    /// Accept the pending offer. Transitions Offered → Transferring.
+    /// Returns the transfer_id for the accept message.
+    pub fn accept(&mut self) -> Result<String, WasmBoltError> {
+        self.inner
+            .accept_current_offer()
+            .map_err(WasmBoltError::from)
+    }
+
+    /// Reject the pending offer. Transitions Offered → Cancelled(Rejected).
+    /// Returns the transfer_id for the reject message.
+    pub fn reject(&mut self) -> Result<String, WasmBoltError> {
+        self.inner
+            .reject_current_offer()
+            .map_err(WasmBoltError::from)
+    }
+
+    /// Append a decrypted chunk. Chunks MUST arrive in index order.
+    /// HOT PATH — called per 16 KiB chunk.
+    #[wasm_bindgen(js_name = "onFileChunk")]
+    pub fn on_file_chunk(
+        &mut self,
+        transfer_id: &str,
+        chunk_index: u32,
+        data: &[u8],
+    ) -> Result<(), WasmBoltError> {
+        self.inner
+            .on_file_chunk(transfer_id, chunk_index, data)
+            .map_err(WasmBoltError::from)
+    }
+
+    /// Finalize. Transitions Transferring → Completed.
+    /// Verifies SHA-256 when the offer carried an expected hash.
+    pub fn finish(&mut self, transfer_id: &str) -> Result<(), WasmBoltError> {
+        self.inner
+            .on_file_finish(transfer_id, Some(&bolt_transfer_core::Sha256Verifier))
+            .map_err(WasmBoltError::from)
+    }
+
+    /// Cancel. Transitions Transferring → Cancelled(ByReceiver).
+    pub fn cancel(&mut self, transfer_id: &str) -> Result<(), WasmBoltError> {
+        self.inner.cancel(transfer_id).map_err(WasmBoltError::from)
+    }
+
+    /// Current transfer state.
+    pub fn state(&self) -> WasmTransferState {
+        self.inner.state().into()
+    }
+
+    /// True if Completed and the file hash was verified.
+    #[wasm_bindgen(js_name = "hashVerified")]
+    pub fn hash_verified(&self) -> bool {
+        self.inner.hash_verified()
+    }
+
+    /// Reassembled file bytes once Completed, otherwise undefined.
+    #[wasm_bindgen(js_name = "completedBytes")]
+    pub fn completed_bytes(&self) -> Option<Vec<u8>> {
+        self.inner.completed_bytes().map(<[u8]>::to_vec)
+    }
+}
+
+impl Default for WasmReceiveSession {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ── Integrity Verifier (SHA-256) ──────────────────────────────────
+
+/// Standalone SHA-256 verifier for files reassembled outside Rust.
+#[wasm_bindgen]
+pub struct WasmIntegrityVerifier;
+
+#[wasm_bindgen]
+impl WasmIntegrityVerifier {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> WasmIntegrityVerifier {
+        WasmIntegrityVerifier
+    }
+
+    /// True if SHA-256(`data`) matches `expectedHash` (hex).
+    pub fn verify(&self, data: &[u8], expected_hash: &str) -> bool {
+        use bolt_transfer_core::IntegrityVerifier;
+        bolt_transfer_core::Sha256Verifier.verify(data, expected_hash)
+    }
+}
+
+impl Default for WasmIntegrityVerifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ── Backpressure Controller ───────────────────────────────────────
+
+/// `TransportQuery` over a JS callback returning the channel's
+/// `bufferedAmount`. A throwing or non-numeric callback reads as
+/// `usize::MAX` so the controller fails toward Pause.
+struct JsBufferedAmount<'a> {
+    callback: &'a js_sys::Function,
+}
+
+impl bolt_transfer_core::TransportQuery for JsBufferedAmount<'_> {
+    fn is_open(&self) -> bool {
+        true
+    }
+
+    fn buffered_bytes(&self) -> usize {
+        match self.callback.call0(&JsValue::NULL).map(|v| v.as_f64()) {
+            Ok(Some(n)) if n.is_finite() && n >= 0.0 => n as usize,
+            _ => usize::MAX,
+        }
+    }
+
+    fn max_message_size(&self) -> usize {
+        bolt_core::constants::DEFAULT_CHUNK_SIZE
+    }
+}
+
+/// Backpressure signal.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WasmBackpressure {
+    Pause = 0,
+    Resume = 1,
+    NoChange = 2,
+}
+
+/// Transport pressure level.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WasmPressureState {
+    Clear = 0,
+    Elevated = 1,
+    Pressured = 2,
+}
+
+impl From<bolt_transfer_core::Backpressure> for WasmBackpressure {
+    fn from(v: bolt_transfer_core::Backpressure) -> Self {
+        match v {
+            bolt_transfer_core::Backpressure::Pause => WasmBackpressure::Pause,
+            bolt_transfer_core::Backpressure::Resume => WasmBackpressure::Resume,
+            bolt_transfer_core::Backpressure::NoChange => WasmBackpressure::NoChange,
+        }
+    }
+}
+
+impl From<bolt_transfer_core::PressureState> for WasmPressureState {
+    fn from(v: bolt_transfer_core::PressureState) -> Self {
+        match v {
+            bolt_transfer_core::PressureState::Clear => WasmPressureState::Clear,
+            bolt_transfer_core::PressureState::Elevated => WasmPressureState::Elevated,
+            bolt_transfer_core::PressureState::Pressured => WasmPressureState::Pressured,
+        }
+    }
+}
+
+/// Opaque handle to BackpressureController (high/low watermark).
+/// JS supplies `() => dataChannel.bufferedAmount`; Rust decides.
+#[wasm_bindgen]
+pub struct WasmBackpressureController {
+    inner: bolt_transfer_core::BackpressureController,
+    buffered_amount: js_sys::Function,
+}
+
+#[wasm_bindgen]
+impl WasmBackpressureController {
+    /// Create a controller. Watermarks default to 64 KiB high / 16 KiB low.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        buffered_amount: js_sys::Function,
+        high_watermark: Option<usize>,
+        low_watermark: Option<usize>,
+    ) -> Result<WasmBackpressureController, WasmBoltError> {
+        let default = bolt_transfer_core::BackpressureConfig::default();
+        let high = high_watermark.unwrap_or(default.high_watermark);
+        let low = low_watermark.unwrap_or(default.low_watermark);
+        if low >= high {
+            return Err(WasmBoltError::invalid_input(
+                "low_watermark must be less than high_watermark",
+            ));
+        }
+        Ok(WasmBackpressureController {
+            inner: bolt_transfer_core::BackpressureController::new(
+                bolt_transfer_core::BackpressureConfig::new(high, low),
+            ),
+            buffered_amount,
+        })
+    }
+
+    /// Sample the buffered amount and return the backpressure signal.
+    pub fn evaluate(&mut self) -> WasmBackpressure {
+        let transport = JsBufferedAmount {
+            callback: &self.buffered_amount,
+        };
+        self.inner.evaluate(&transport).into()
+    }
+
+    /// Current pressure for policy input.
+    #[wasm_bindgen(js_name = "pressureState")]
+    pub fn pressure_state(&self) -> WasmPressureState {
+        let transport = JsBufferedAmount {
+            callback: &self.buffered_amount,
+        };
+        self.inner.pressure_state(&transport).into()
+    }
+
+    /// True while paused (between a Pause and the next Resume).
+    #[wasm_bindgen(js_name = "isPaused")]
+    pub fn is_paused(&self) -> bool {
+        self.inner.is_paused()
+    }
+
+    /// Reset to the initial unpaused state.
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+}
+
 // ── BTR Negotiation (stateless) ───────────────────────────────────
 
+/// Negotiated BTR mode (§16).
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WasmBtrMode {
+    FullBtr = 0,
+    Downgrade = 1,
+    StaticEphemeral = 2,
+    Reject = 3,
+}
+
+impl From<bolt_btr::BtrMode> for WasmBtrMode {
+    fn from(v: bolt_btr::BtrMode) -> Self {
+        match v {
+            bolt_btr::BtrMode::FullBtr => WasmBtrMode::FullBtr,
+            bolt_btr::BtrMode::Downgrade => WasmBtrMode::Downgrade,
+            bolt_btr::BtrMode::StaticEphemeral => WasmBtrMode::StaticEphemeral,
+            bolt_btr::BtrMode::Reject => WasmBtrMode::Reject,
+        }
+    }
+}
+
 /// Negotiate BTR mode from capability flags.
 #[wasm_bindgen(js_name = "negotiateBtr")]
 pub fn negotiate_btr(
     local_supports: bool,
     remote_supports: bool,
     remote_well_formed: bool,
-) -> String {
-    format!(
-        "{:?}",
-        bolt_btr::negotiate_btr(local_supports, remote_supports, remote_well_formed)
-    )
+) -> WasmBtrMode {
+    bolt_btr::negotiate_btr(local_supports, remote_supports, remote_well_formed).into()
 }
 
 // ── Tests ─────────────────────────────────────────────────────────
@@ -520,6 +1120,27 @@ mod tests {
         assert_eq!(sas1.len(), 6);
     }
 
+    #[test]
+    fn sas_encoding_enum_and_emoji_dto() {
+        let a = [1u8; 32];
+        let b = [2u8; 32];
+        assert_eq!(
+            super::compute_sas(&a, &b, &a, &b, None).unwrap(),
+            bolt_core::sas::compute_sas(&a, &b, &a, &b)
+        );
+        let emoji =
+            super::compute_sas(&a, &b, &a, &b, Some(super::WasmSasEncoding::Emoji)).unwrap();
+        let pairs = super::compute_sas_emoji(&a, &b, &a, &b).unwrap();
+        assert_eq!(pairs.len(), 7);
+        let symbols: Vec<String> = pairs.iter().map(|e| e.symbol()).collect();
+        assert_eq!(symbols.join(" "), emoji);
+        let names: Vec<String> = pairs.iter().map(|e| e.name()).collect();
+        assert_eq!(
+            names,
+            bolt_core::sas::compute_sas_emoji_names(&a, &b, &a, &b)
+        );
+    }
+
     #[test]
     fn peer_code_valid() {
         let code = bolt_core::peer_code::generate_secure_peer_code();
@@ -618,6 +1239,41 @@ mod tests {
         ss.finish().unwrap();
     }
 
+    #[test]
+    fn receive_session_lifecycle_with_hash() {
+        use bolt_transfer_core::IntegrityVerifier;
+
+        let data = b"receive side via WASM";
+        let hash = bolt_core::hash::sha256_hex(data);
+        let mut rs = bolt_transfer_core::ReceiveSession::new();
+        rs.on_file_offer("rx-1", data.len() as u64, 2, Some(&hash.to_uppercase()))
+            .unwrap();
+        rs.accept_current_offer().unwrap();
+        rs.on_file_chunk("rx-1", 0, &data[..10]).unwrap();
+        rs.on_file_chunk("rx-1", 1, &data[10..]).unwrap();
+        rs.on_file_finish("rx-1", Some(&bolt_transfer_core::Sha256Verifier))
+            .unwrap();
+        assert!(rs.hash_verified());
+        assert_eq!(rs.completed_bytes(), Some(&data[..]));
+        assert!(!bolt_transfer_core::Sha256Verifier.verify(b"other", &hash));
+    }
+
+    #[test]
+    fn receive_session_hash_mismatch_fails() {
+        let mut rs = bolt_transfer_core::ReceiveSession::new();
+        rs.on_file_offer("rx-2", 4, 1, Some(&bolt_core::hash::sha256_hex(b"abcd")))
+            .unwrap();
+        rs.accept_current_offer().unwrap();
+        rs.on_file_chunk("rx-2", 0, b"abce").unwrap();
+        let err = rs
+            .on_file_finish("rx-2", Some(&bolt_transfer_core::Sha256Verifier))
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            bolt_transfer_core::TransferError::IntegrityFailed(_)
+        ));
+    }
+
     #[test]
     fn send_session_invalid_transition() {
         let mut ss = bolt_transfer_core::send::SendSession::new();
@@ -670,4 +1326,55 @@ mod tests {
         let result = bolt_core::crypto::open_box_payload(sealed, &sender_pk, &receiver_sk).unwrap();
         assert_eq!(result, b"Hello, Bolt!");
     }
+
+    // ── Typed results ──
+
+    #[test]
+    fn wasm_error_codes_are_wire_codes() {
+        use bolt_core::errors::WIRE_ERROR_CODES;
+
+        let errors = [
+            super::WasmBoltError::invalid_input("bad arg"),
+            bolt_core::errors::BoltError::Encryption("x".into()).into(),
+            bolt_btr::BtrError::RatchetDecryptFail("x".into()).into(),
+            bolt_transfer_core::TransferError::InvalidTransition("x".into()).into(),
+            bolt_transfer_core::TransferError::IntegrityFailed("x".into()).into(),
+        ];
+        for e in &errors {
+            assert!(WIRE_ERROR_CODES.contains(&e.code), "{}", e.code);
+        }
+    }
+
+    #[test]
+    fn transfer_state_flattens() {
+        use super::{WasmCancelReason, WasmTransferState, WasmTransferStateKind};
+        use bolt_transfer_core::{CancelReason, TransferState};
+
+        let s = WasmTransferState::from(&TransferState::Cancelled {
+            transfer_id: "t".into(),
+            reason: CancelReason::Rejected,
+        });
+        assert_eq!(s.kind, WasmTransferStateKind::Cancelled);
+        assert_eq!(s.transfer_id.as_deref(), Some("t"));
+        assert_eq!(s.cancel_reason, Some(WasmCancelReason::Rejected));
+
+        let s = WasmTransferState::from(&TransferState::Error {
+            detail: "boom".into(),
+        });
+        assert_eq!(s.kind, WasmTransferStateKind::Error);
+        assert_eq!(s.transfer_id, None);
+        assert_eq!(s.detail.as_deref(), Some("boom"));
+    }
+
+    #[test]
+    fn btr_mode_maps() {
+        assert_eq!(
+            super::WasmBtrMode::from(bolt_btr::negotiate_btr(true, true, true)),
+            super::WasmBtrMode::FullBtr
+        );
+        assert_eq!(
+            super::WasmBtrMode::from(bolt_btr::negotiate_btr(true, false, true)),
+            super::WasmBtrMode::Downgrade
+        );
+    }
 }
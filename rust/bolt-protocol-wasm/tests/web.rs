@@ -0,0 +1,169 @@
+//! wasm-bindgen-test coverage for the JS-facing receive, backpressure,
+//! and integrity bindings. Run with `wasm-pack test --node`.
+
+#![cfg(target_arch = "wasm32")]
+
+use bolt_protocol_wasm::{
+    WasmBackpressure, WasmBackpressureController, WasmCancelReason, WasmIntegrityVerifier,
+    WasmPressureState, WasmReceiveSession, WasmSendSession, WasmTransferStateKind,
+};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+
+// ── WasmReceiveSession ──────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn receive_offer_accept_chunk_finish() {
+    let data = b"hello from the receive side";
+    let hash = bolt_core::hash::sha256_hex(data);
+    let mut rs = WasmReceiveSession::new();
+    rs.on_file_offer("rx-1", data.len() as f64, 2, Some(hash))
+        .unwrap();
+    let state = rs.state();
+    assert_eq!(state.kind(), WasmTransferStateKind::Offered);
+    assert_eq!(state.transfer_id().as_deref(), Some("rx-1"));
+    assert_eq!(rs.accept().unwrap(), "rx-1");
+    rs.on_file_chunk("rx-1", 0, &data[..8]).unwrap();
+    rs.on_file_chunk("rx-1", 1, &data[8..]).unwrap();
+    assert!(rs.completed_bytes().is_none());
+    rs.finish("rx-1").unwrap();
+    assert!(rs.hash_verified());
+    assert_eq!(rs.completed_bytes().unwrap(), data.to_vec());
+}
+
+#[wasm_bindgen_test]
+fn receive_reject() {
+    let mut rs = WasmReceiveSession::new();
+    rs.on_file_offer("rx-2", 10.0, 1, None).unwrap();
+    assert_eq!(rs.reject().unwrap(), "rx-2");
+    assert_eq!(rs.state().cancel_reason(), Some(WasmCancelReason::Rejected));
+    assert_eq!(rs.accept().unwrap_err().code(), "INVALID_STATE");
+}
+
+#[wasm_bindgen_test]
+fn receive_cancel_mid_transfer() {
+    let mut rs = WasmReceiveSession::new();
+    rs.on_file_offer("rx-3", 10.0, 2, None).unwrap();
+    rs.accept().unwrap();
+    rs.on_file_chunk("rx-3", 0, b"01234").unwrap();
+    rs.cancel("rx-3").unwrap();
+    assert_eq!(rs.state().kind(), WasmTransferStateKind::Cancelled);
+    assert_eq!(
+        rs.state().cancel_reason(),
+        Some(WasmCancelReason::ByReceiver)
+    );
+    assert!(rs.on_file_chunk("rx-3", 1, b"56789").is_err());
+}
+
+#[wasm_bindgen_test]
+fn receive_rejects_out_of_order_chunk() {
+    let mut rs = WasmReceiveSession::new();
+    rs.on_file_offer("rx-4", 10.0, 2, None).unwrap();
+    rs.accept().unwrap();
+    assert!(rs.on_file_chunk("rx-4", 1, b"56789").is_err());
+}
+
+#[wasm_bindgen_test]
+fn receive_hash_mismatch_fails_finish() {
+    let mut rs = WasmReceiveSession::new();
+    rs.on_file_offer("rx-5", 4.0, 1, Some(bolt_core::hash::sha256_hex(b"abcd")))
+        .unwrap();
+    rs.accept().unwrap();
+    rs.on_file_chunk("rx-5", 0, b"abce").unwrap();
+    assert_eq!(rs.finish("rx-5").unwrap_err().code(), "INTEGRITY_FAILED");
+    assert!(rs.completed_bytes().is_none());
+}
+
+#[wasm_bindgen_test]
+fn receive_rejects_non_integer_size() {
+    let mut rs = WasmReceiveSession::new();
+    assert!(rs.on_file_offer("rx-6", 1.5, 1, None).is_err());
+    assert!(rs.on_file_offer("rx-6", -1.0, 1, None).is_err());
+    assert!(rs.on_file_offer("rx-6", f64::NAN, 1, None).is_err());
+}
+
+// ── WasmSendSession ─────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn send_session_typed_offer_and_chunks() {
+    let payload = b"typed send side";
+    let mut ss = WasmSendSession::new();
+    let offer = ss.begin_send("tx-1", payload, "a.txt", None).unwrap();
+    assert_eq!(offer.transfer_id(), "tx-1");
+    assert_eq!(offer.size(), payload.len() as f64);
+    assert_eq!(ss.state().kind(), WasmTransferStateKind::Offered);
+    ss.on_accept("tx-1").unwrap();
+
+    let mut data = Vec::new();
+    while let Some(chunk) = ss.next_chunk().unwrap() {
+        assert_eq!(chunk.transfer_id(), "tx-1");
+        data.extend_from_slice(&chunk.data());
+    }
+    assert_eq!(data, payload);
+    assert_eq!(ss.finish().unwrap(), "tx-1");
+    assert_eq!(ss.state().kind(), WasmTransferStateKind::Completed);
+}
+
+// ── WasmIntegrityVerifier ───────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn integrity_verifier_sha256() {
+    let v = WasmIntegrityVerifier::new();
+    let hash = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+    assert!(v.verify(b"hello", hash));
+    assert!(v.verify(b"hello", &hash.to_uppercase()));
+    assert!(!v.verify(b"hellp", hash));
+}
+
+// ── WasmBackpressureController ──────────────────────────────────────
+
+fn buffered(expr: &str) -> js_sys::Function {
+    js_sys::Function::new_no_args(&format!("return {expr};"))
+}
+
+#[wasm_bindgen_test]
+fn backpressure_pause_and_resume() {
+    // Callback reads a mutable global so the test can drive it.
+    let global = js_sys::global();
+    let set = |n: f64| {
+        js_sys::Reflect::set(&global, &"__boltBuffered".into(), &JsValue::from(n)).unwrap();
+    };
+    set(0.0);
+    let mut bp =
+        WasmBackpressureController::new(buffered("globalThis.__boltBuffered"), None, None).unwrap();
+
+    assert_eq!(bp.evaluate(), WasmBackpressure::NoChange);
+    assert_eq!(bp.pressure_state(), WasmPressureState::Clear);
+
+    set(70_000.0);
+    assert_eq!(bp.evaluate(), WasmBackpressure::Pause);
+    assert!(bp.is_paused());
+    assert_eq!(bp.pressure_state(), WasmPressureState::Pressured);
+
+    set(30_000.0);
+    assert_eq!(bp.evaluate(), WasmBackpressure::NoChange);
+
+    set(1_000.0);
+    assert_eq!(bp.evaluate(), WasmBackpressure::Resume);
+    assert!(!bp.is_paused());
+}
+
+#[wasm_bindgen_test]
+fn backpressure_custom_watermarks() {
+    let mut bp = WasmBackpressureController::new(buffered("200"), Some(100), Some(10)).unwrap();
+    assert_eq!(bp.evaluate(), WasmBackpressure::Pause);
+    bp.reset();
+    assert!(!bp.is_paused());
+}
+
+#[wasm_bindgen_test]
+fn backpressure_rejects_inverted_watermarks() {
+    assert!(WasmBackpressureController::new(buffered("0"), Some(10), Some(100)).is_err());
+}
+
+#[wasm_bindgen_test]
+fn backpressure_throwing_callback_pauses() {
+    let throwing = js_sys::Function::new_no_args("throw new Error('closed');");
+    let mut bp = WasmBackpressureController::new(throwing, None, None).unwrap();
+    assert_eq!(bp.evaluate(), WasmBackpressure::Pause);
+}
@@ -0,0 +1,1189 @@
+//! bolt-py — Python bindings for the Bolt protocol authority.
+//!
+//! Exposes the `bolt` extension module for QA scripting: drive handshakes
+//! and transfers from pytest with the same Rust code the daemon runs.
+//!
+//! ```python
+//! import bolt
+//! alice, bob = bolt.generate_ephemeral_keypair(), bolt.generate_ephemeral_keypair()
+//! sealed = bolt.seal_box_payload(b"hi", bob.public_key, alice.secret_key)
+//! assert bolt.open_box_payload(sealed, alice.public_key, bob.secret_key) == b"hi"
+//! ```
+//!
+//! Failures raise `bolt.BoltError`; `err.code` is the canonical wire error
+//! code (bolt-core `WIRE_ERROR_CODES`), `err.args[1]` a diagnostic message.
+//! State and mode names use the upper-case spec tokens (`"TRANSFERRING"`,
+//! `"FULL_BTR"`), matching the golden vector files.
+//!
+//! ## Parity
+//!
+//! Function set mirrors bolt-protocol-wasm (RB3 + RB4), plus the pairing
+//! payload, PAKE, identity rotation, and BTR primitives (`btr_*`, `ReplayGuard`) that
+//! the golden vector suite re-verifies. Seeded PAKE starts and
+//! fixed-nonce rotation signing need the `vectors` feature.
+//!
+//! ## Non-goals
+//!
+//! - No transport, signaling, or daemon IPC — tests bring their own.
+
+use std::borrow::Cow;
+
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+
+create_exception!(
+    bolt,
+    BoltError,
+    PyException,
+    "Bolt protocol error. `code` is the canonical wire error code."
+);
+
+// ── Errors ────────────────────────────────────────────────────────
+
+/// Wire-coded failure, raised in Python as `BoltError(code, message)`.
+struct Error {
+    code: &'static str,
+    message: String,
+}
+
+impl Error {
+    fn invalid_input(message: &str) -> Self {
+        Self {
+            code: "INVALID_MESSAGE",
+            message: message.to_string(),
+        }
+    }
+
+    fn invalid_state(message: &str) -> Self {
+        Self {
+            code: "INVALID_STATE",
+            message: message.to_string(),
+        }
+    }
+}
+
+impl From<Error> for PyErr {
+    fn from(e: Error) -> Self {
+        Python::with_gil(|py| {
+            let err = BoltError::new_err((e.code, e.message));
+            // Best effort: `code` attribute for ergonomic matching.
+            let _ = err.value_bound(py).setattr("code", e.code);
+            err
+        })
+    }
+}
+
+impl From<bolt_core::errors::BoltError> for Error {
+    fn from(e: bolt_core::errors::BoltError) -> Self {
+        Self {
+            code: e.code(),
+            message: e.to_string(),
+        }
+    }
+}
+
+impl From<bolt_core::pake::PakeError> for Error {
+    fn from(e: bolt_core::pake::PakeError) -> Self {
+        Self {
+            code: e.code(),
+            message: e.to_string(),
+        }
+    }
+}
+
+impl From<bolt_core::rotation::RotationError> for Error {
+    fn from(e: bolt_core::rotation::RotationError) -> Self {
+        Self {
+            code: e.code(),
+            message: e.to_string(),
+        }
+    }
+}
+
+impl From<bolt_btr::BtrError> for Error {
+    fn from(e: bolt_btr::BtrError) -> Self {
+        Self {
+            code: e.wire_code(),
+            message: e.to_string(),
+        }
+    }
+}
+
+impl From<bolt_transfer_core::TransferError> for Error {
+    fn from(e: bolt_transfer_core::TransferError) -> Self {
+        Self {
+            code: e.code(),
+            message: e.to_string(),
+        }
+    }
+}
+
+fn fixed<const N: usize>(bytes: &[u8], name: &str) -> Result<[u8; N], Error> {
+    bytes
+        .try_into()
+        .map_err(|_| Error::invalid_input(&format!("{name} must be {N} bytes")))
+}
+
+fn py_bytes(bytes: Vec<u8>) -> Cow<'static, [u8]> {
+    Cow::Owned(bytes)
+}
+
+type BytesPair = (Cow<'static, [u8]>, Cow<'static, [u8]>);
+
+// ── Key Generation ────────────────────────────────────────────────
+
+/// X25519 keypair. Secret key is zeroized when the object is collected.
+#[pyclass(module = "bolt", frozen)]
+struct KeyPair {
+    inner: bolt_core::crypto::KeyPair,
+}
+
+#[pymethods]
+impl KeyPair {
+    /// Rebuild a keypair from its 32-byte X25519 secret key.
+    #[staticmethod]
+    fn from_secret_key(secret_key: &[u8]) -> Result<Self, Error> {
+        let secret_key: [u8; 32] = fixed(secret_key, "secret_key")?;
+        let public_key =
+            *x25519_dalek::PublicKey::from(&x25519_dalek::StaticSecret::from(secret_key))
+                .as_bytes();
+        Ok(Self {
+            inner: bolt_core::crypto::KeyPair {
+                public_key,
+                secret_key,
+            },
+        })
+    }
+
+    #[getter]
+    fn public_key(&self) -> Cow<'static, [u8]> {
+        py_bytes(self.inner.public_key.to_vec())
+    }
+
+    #[getter]
+    fn secret_key(&self) -> Cow<'static, [u8]> {
+        py_bytes(self.inner.secret_key.to_vec())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "KeyPair(public_key={})",
+            bolt_core::encoding::to_hex(&self.inner.public_key)
+        )
+    }
+}
+
+/// Generate an ephemeral X25519 keypair for session use.
+#[pyfunction]
+fn generate_ephemeral_keypair() -> KeyPair {
+    KeyPair {
+        inner: bolt_core::crypto::generate_ephemeral_keypair(),
+    }
+}
+
+/// Generate a persistent identity X25519 keypair.
+#[pyfunction]
+fn generate_identity_keypair() -> KeyPair {
+    KeyPair {
+        inner: bolt_core::identity::generate_identity_keypair(),
+    }
+}
+
+/// Raw X25519 `scalar * point` (tweetnacl `scalarMult`).
+#[pyfunction]
+fn scalar_mult(secret_key: &[u8], public_key: &[u8]) -> Result<Cow<'static, [u8]>, Error> {
+    let sk: [u8; 32] = fixed(secret_key, "secret_key")?;
+    let pk: [u8; 32] = fixed(public_key, "public_key")?;
+    let shared =
+        x25519_dalek::StaticSecret::from(sk).diffie_hellman(&x25519_dalek::PublicKey::from(pk));
+    Ok(py_bytes(shared.as_bytes().to_vec()))
+}
+
+// ── NaCl Box (XSalsa20-Poly1305) ─────────────────────────────────
+
+/// Seal plaintext with NaCl box. Returns base64(nonce || ciphertext).
+#[pyfunction]
+fn seal_box_payload(
+    plaintext: &[u8],
+    remote_public_key: &[u8],
+    sender_secret_key: &[u8],
+) -> Result<String, Error> {
+    let rpk: [u8; 32] = fixed(remote_public_key, "remote_public_key")?;
+    let ssk: [u8; 32] = fixed(sender_secret_key, "sender_secret_key")?;
+    Ok(bolt_core::crypto::seal_box_payload(plaintext, &rpk, &ssk)?)
+}
+
+/// Open a base64(nonce || ciphertext) NaCl box payload.
+#[pyfunction]
+fn open_box_payload(
+    sealed: &str,
+    sender_public_key: &[u8],
+    receiver_secret_key: &[u8],
+) -> Result<Cow<'static, [u8]>, Error> {
+    let spk: [u8; 32] = fixed(sender_public_key, "sender_public_key")?;
+    let rsk: [u8; 32] = fixed(receiver_secret_key, "receiver_secret_key")?;
+    Ok(py_bytes(bolt_core::crypto::open_box_payload(
+        sealed, &spk, &rsk,
+    )?))
+}
+
+// ── SAS / Peer Code / Hashing ─────────────────────────────────────
+
+type SasKeys = ([u8; 32], [u8; 32], [u8; 32], [u8; 32]);
+
+fn sas_keys(
+    identity_a: &[u8],
+    identity_b: &[u8],
+    ephemeral_a: &[u8],
+    ephemeral_b: &[u8],
+) -> Result<SasKeys, Error> {
+    Ok((
+        fixed(identity_a, "identity_a")?,
+        fixed(identity_b, "identity_b")?,
+        fixed(ephemeral_a, "ephemeral_a")?,
+        fixed(ephemeral_b, "ephemeral_b")?,
+    ))
+}
+
+/// Compute the SAS. `encoding`: `"hex"`, `"words"`, `"emoji"`, `"decimal"`.
+#[pyfunction]
+#[pyo3(signature = (identity_a, identity_b, ephemeral_a, ephemeral_b, encoding = "hex"))]
+fn compute_sas(
+    identity_a: &[u8],
+    identity_b: &[u8],
+    ephemeral_a: &[u8],
+    ephemeral_b: &[u8],
+    encoding: &str,
+) -> Result<String, Error> {
+    let (id_a, id_b, eph_a, eph_b) = sas_keys(identity_a, identity_b, ephemeral_a, ephemeral_b)?;
+    let encoding = bolt_core::sas::SasEncoding::from_name(encoding)
+        .ok_or_else(|| Error::invalid_input(&format!("unknown SAS encoding: {encoding}")))?;
+    Ok(bolt_core::sas::compute_sas_encoded(
+        &id_a, &id_b, &eph_a, &eph_b, encoding,
+    ))
+}
+
+/// English captions for the emoji SAS, aligned with `compute_sas(..., "emoji")`.
+#[pyfunction]
+fn compute_sas_emoji_names(
+    identity_a: &[u8],
+    identity_b: &[u8],
+    ephemeral_a: &[u8],
+    ephemeral_b: &[u8],
+) -> Result<Vec<&'static str>, Error> {
+    let (id_a, id_b, eph_a, eph_b) = sas_keys(identity_a, identity_b, ephemeral_a, ephemeral_b)?;
+    Ok(bolt_core::sas::compute_sas_emoji_names(
+        &id_a, &id_b, &eph_a, &eph_b,
+    ))
+}
+
+/// Generate a 6-character secure peer code.
+#[pyfunction]
+fn generate_peer_code() -> String {
+    bolt_core::peer_code::generate_secure_peer_code()
+}
+
+/// Validate a peer code.
+#[pyfunction]
+fn is_valid_peer_code(code: &str) -> bool {
+    bolt_core::peer_code::is_valid_peer_code(code)
+}
+
+/// SHA-256 hex digest.
+#[pyfunction]
+fn sha256_hex(data: &[u8]) -> String {
+    bolt_core::hash::sha256_hex(data)
+}
+
+// ── Pairing Payload ───────────────────────────────────────────────
+
+/// A `bolt://pair` payload. The secret is never exposed: `prove` and
+/// `verify` use it once, after which both raise `INVALID_STATE`.
+#[pyclass(module = "bolt")]
+struct PairingPayload {
+    inner: Option<bolt_core::pairing::PairingPayload>,
+}
+
+impl PairingPayload {
+    fn payload(&self) -> Result<&bolt_core::pairing::PairingPayload, Error> {
+        self.inner
+            .as_ref()
+            .ok_or_else(|| Error::invalid_state("pairing secret already used"))
+    }
+
+    fn take(&mut self) -> Result<bolt_core::pairing::PairingPayload, Error> {
+        self.inner
+            .take()
+            .ok_or_else(|| Error::invalid_state("pairing secret already used"))
+    }
+}
+
+#[pymethods]
+impl PairingPayload {
+    /// Payload for the local peer with a fresh one-time secret.
+    #[staticmethod]
+    fn generate(peer_code: &str, identity_public_key: &[u8]) -> Result<Self, Error> {
+        let id: [u8; 32] = fixed(identity_public_key, "identity_public_key")?;
+        Ok(Self {
+            inner: Some(bolt_core::pairing::PairingPayload::generate(
+                peer_code, &id,
+            )?),
+        })
+    }
+
+    /// Parse and validate a `bolt://pair` URI.
+    #[staticmethod]
+    fn parse(uri: &str) -> Result<Self, Error> {
+        Ok(Self {
+            inner: Some(bolt_core::pairing::PairingPayload::parse(uri)?),
+        })
+    }
+
+    /// Normalized peer code.
+    #[getter]
+    fn peer_code(&self) -> Result<String, Error> {
+        Ok(self.payload()?.peer_code.clone())
+    }
+
+    /// Identity fingerprint (16 bytes).
+    #[getter]
+    fn identity_fingerprint(&self) -> Result<Cow<'static, [u8]>, Error> {
+        Ok(py_bytes(self.payload()?.identity_fingerprint.to_vec()))
+    }
+
+    /// `bolt://pair` URI for a QR code.
+    fn to_uri(&self) -> Result<String, Error> {
+        Ok(self.payload()?.to_uri())
+    }
+
+    /// Whether `identity_public_key` (from HELLO) matches the fingerprint.
+    fn matches_identity(&self, identity_public_key: &[u8]) -> Result<bool, Error> {
+        let id: [u8; 32] = fixed(identity_public_key, "identity_public_key")?;
+        Ok(self.payload()?.matches_identity(&id))
+    }
+
+    /// Scanning peer: proof of the secret (32 bytes). Uses the secret up.
+    fn prove(
+        &mut self,
+        identity_a: &[u8],
+        identity_b: &[u8],
+        ephemeral_a: &[u8],
+        ephemeral_b: &[u8],
+    ) -> Result<Cow<'static, [u8]>, Error> {
+        let (ia, ib): ([u8; 32], [u8; 32]) = (
+            fixed(identity_a, "identity_a")?,
+            fixed(identity_b, "identity_b")?,
+        );
+        let (ea, eb): ([u8; 32], [u8; 32]) = (
+            fixed(ephemeral_a, "ephemeral_a")?,
+            fixed(ephemeral_b, "ephemeral_b")?,
+        );
+        Ok(py_bytes(self.take()?.prove(&ia, &ib, &ea, &eb).to_vec()))
+    }
+
+    /// Displaying peer: check a proof. Uses the secret up either way.
+    fn verify(
+        &mut self,
+        identity_a: &[u8],
+        identity_b: &[u8],
+        ephemeral_a: &[u8],
+        ephemeral_b: &[u8],
+        proof: &[u8],
+    ) -> Result<bool, Error> {
+        let (ia, ib): ([u8; 32], [u8; 32]) = (
+            fixed(identity_a, "identity_a")?,
+            fixed(identity_b, "identity_b")?,
+        );
+        let (ea, eb): ([u8; 32], [u8; 32]) = (
+            fixed(ephemeral_a, "ephemeral_a")?,
+            fixed(ephemeral_b, "ephemeral_b")?,
+        );
+        Ok(self.take()?.verify(&ia, &ib, &ea, &eb, proof))
+    }
+}
+
+/// Pairing fingerprint of an identity key (16 bytes).
+#[pyfunction]
+fn pairing_fingerprint(identity_public_key: &[u8]) -> Result<Cow<'static, [u8]>, Error> {
+    let id: [u8; 32] = fixed(identity_public_key, "identity_public_key")?;
+    Ok(py_bytes(
+        bolt_core::pairing::identity_fingerprint(&id).to_vec(),
+    ))
+}
+
+// ── PAKE / Identity Rotation ──────────────────────────────────────
+
+fn pake_role(role: &str) -> Result<bolt_core::pake::PakeRole, Error> {
+    use bolt_core::pake::PakeRole;
+
+    match role {
+        "INITIATOR" => Ok(PakeRole::Initiator),
+        "RESPONDER" => Ok(PakeRole::Responder),
+        _ => Err(Error::invalid_input(&format!("unknown PAKE role: {role}"))),
+    }
+}
+
+/// One side of a PAKE run. `role`: `"INITIATOR"` or `"RESPONDER"`.
+#[pyclass(module = "bolt")]
+struct PakeSession {
+    inner: Option<bolt_core::pake::PakeSession>,
+}
+
+#[pymethods]
+impl PakeSession {
+    #[new]
+    fn new(
+        role: &str,
+        code: &str,
+        sid: &[u8],
+        local_identity_public_key: &[u8],
+    ) -> Result<Self, Error> {
+        let id: [u8; 32] = fixed(local_identity_public_key, "local_identity_public_key")?;
+        Ok(Self {
+            inner: Some(bolt_core::pake::PakeSession::start(
+                pake_role(role)?,
+                code,
+                sid,
+                &id,
+            )?),
+        })
+    }
+
+    /// Deterministic start for golden vectors (`vectors` feature only).
+    #[cfg(feature = "vectors")]
+    #[staticmethod]
+    fn start_with_seed(
+        role: &str,
+        code: &str,
+        sid: &[u8],
+        local_identity_public_key: &[u8],
+        seed: &[u8],
+    ) -> Result<Self, Error> {
+        let id: [u8; 32] = fixed(local_identity_public_key, "local_identity_public_key")?;
+        let seed: [u8; 64] = fixed(seed, "seed")?;
+        Ok(Self {
+            inner: Some(bolt_core::pake::PakeSession::start_with_seed(
+                pake_role(role)?,
+                code,
+                sid,
+                &id,
+                &seed,
+            )?),
+        })
+    }
+
+    /// Local share (32 bytes) to send to the peer.
+    #[getter]
+    fn share(&self) -> Result<Cow<'static, [u8]>, Error> {
+        let session = self
+            .inner
+            .as_ref()
+            .ok_or_else(|| Error::invalid_state("PAKE session already finished"))?;
+        Ok(py_bytes(session.share().to_vec()))
+    }
+
+    /// Complete the exchange. The session is consumed.
+    fn finish(
+        &mut self,
+        remote_share: &[u8],
+        remote_identity_public_key: &[u8],
+    ) -> Result<PakeKeys, Error> {
+        let share: [u8; 32] = fixed(remote_share, "remote_share")?;
+        let id: [u8; 32] = fixed(remote_identity_public_key, "remote_identity_public_key")?;
+        let session = self
+            .inner
+            .take()
+            .ok_or_else(|| Error::invalid_state("PAKE session already finished"))?;
+        Ok(PakeKeys {
+            inner: Some(session.finish(&share, &id)?),
+        })
+    }
+}
+
+/// Keys from a completed PAKE exchange, pending key confirmation.
+#[pyclass(module = "bolt")]
+struct PakeKeys {
+    inner: Option<bolt_core::pake::PakeKeys>,
+}
+
+#[pymethods]
+impl PakeKeys {
+    /// Local confirmation MAC (32 bytes) to send to the peer.
+    #[getter]
+    fn confirmation(&self) -> Result<Cow<'static, [u8]>, Error> {
+        let keys = self
+            .inner
+            .as_ref()
+            .ok_or_else(|| Error::invalid_state("PAKE keys already verified"))?;
+        Ok(py_bytes(keys.confirmation().to_vec()))
+    }
+
+    /// Verify the peer's confirmation. Returns `(key, remote_identity_public_key)`.
+    fn verify(&mut self, remote_confirmation: &[u8]) -> Result<BytesPair, Error> {
+        let keys = self
+            .inner
+            .take()
+            .ok_or_else(|| Error::invalid_state("PAKE keys already verified"))?;
+        let outcome = keys.verify(remote_confirmation)?;
+        Ok((
+            py_bytes(outcome.key.to_vec()),
+            py_bytes(outcome.remote_identity_public_key.to_vec()),
+        ))
+    }
+}
+
+/// Sign an identity rotation from `old` to `new`. Returns the wire statement.
+#[pyfunction]
+fn sign_rotation(
+    old: PyRef<'_, KeyPair>,
+    new: PyRef<'_, KeyPair>,
+    issued_at_ms: u64,
+    validity_ms: u64,
+) -> Result<Cow<'static, [u8]>, Error> {
+    let statement = bolt_core::rotation::RotationStatement::sign(
+        &old.inner,
+        &new.inner,
+        issued_at_ms,
+        validity_ms,
+    )?;
+    Ok(py_bytes(statement.to_bytes().to_vec()))
+}
+
+/// Deterministic `sign_rotation` for golden vectors (`vectors` feature only).
+#[cfg(feature = "vectors")]
+#[pyfunction]
+fn sign_rotation_with_nonces(
+    old: PyRef<'_, KeyPair>,
+    new: PyRef<'_, KeyPair>,
+    issued_at_ms: u64,
+    validity_ms: u64,
+    old_nonce: &[u8],
+    new_nonce: &[u8],
+) -> Result<Cow<'static, [u8]>, Error> {
+    let statement = bolt_core::rotation::RotationStatement::sign_with_nonces(
+        &old.inner,
+        &new.inner,
+        issued_at_ms,
+        validity_ms,
+        &fixed(old_nonce, "old_nonce")?,
+        &fixed(new_nonce, "new_nonce")?,
+    )?;
+    Ok(py_bytes(statement.to_bytes().to_vec()))
+}
+
+/// Verify a wire rotation statement against the pinned key. Returns the new key to pin.
+#[pyfunction]
+fn verify_rotation(
+    statement: &[u8],
+    pinned: &[u8],
+    now_ms: u64,
+) -> Result<Cow<'static, [u8]>, Error> {
+    let pinned: [u8; 32] = fixed(pinned, "pinned")?;
+    let statement = bolt_core::rotation::RotationStatement::from_bytes(statement)?;
+    Ok(py_bytes(
+        bolt_core::rotation::verify_rotation(&statement, &pinned, now_ms)?.to_vec(),
+    ))
+}
+
+// ── BTR ───────────────────────────────────────────────────────────
+
+fn btr_mode_name(mode: bolt_btr::BtrMode) -> &'static str {
+    use bolt_btr::BtrMode;
+
+    match mode {
+        BtrMode::FullBtr => "FULL_BTR",
+        BtrMode::Downgrade => "DOWNGRADE",
+        BtrMode::StaticEphemeral => "STATIC_EPHEMERAL",
+        BtrMode::Reject => "REJECT",
+    }
+}
+
+fn btr_mode_from_name(name: &str) -> Result<bolt_btr::BtrMode, Error> {
+    use bolt_btr::BtrMode;
+
+    [
+        BtrMode::FullBtr,
+        BtrMode::Downgrade,
+        BtrMode::StaticEphemeral,
+        BtrMode::Reject,
+    ]
+    .into_iter()
+    .find(|m| btr_mode_name(*m) == name)
+    .ok_or_else(|| Error::invalid_input(&format!("unknown BTR mode: {name}")))
+}
+
+/// Negotiate BTR mode. Returns `"FULL_BTR"`, `"DOWNGRADE"`,
+/// `"STATIC_EPHEMERAL"`, or `"REJECT"`.
+#[pyfunction]
+fn negotiate_btr(
+    local_supports: bool,
+    remote_supports: bool,
+    remote_well_formed: bool,
+) -> &'static str {
+    btr_mode_name(bolt_btr::negotiate_btr(
+        local_supports,
+        remote_supports,
+        remote_well_formed,
+    ))
+}
+
+/// Log token for a negotiated mode (e.g. `"[BTR_DOWNGRADE]"`), or None.
+#[pyfunction]
+fn btr_log_token(mode: &str) -> Result<Option<&'static str>, Error> {
+    Ok(bolt_btr::negotiate::btr_log_token(btr_mode_from_name(
+        mode,
+    )?))
+}
+
+/// Session-level BTR ratchet state.
+#[pyclass(module = "bolt")]
+struct BtrEngine {
+    inner: bolt_btr::BtrEngine,
+}
+
+#[pymethods]
+impl BtrEngine {
+    /// Create from the 32-byte ephemeral shared secret.
+    #[new]
+    fn new(shared_secret: &[u8]) -> Result<Self, Error> {
+        let ss: [u8; 32] = fixed(shared_secret, "shared_secret")?;
+        Ok(Self {
+            inner: bolt_btr::BtrEngine::new(&ss),
+        })
+    }
+
+    /// Begin a send-side transfer. Returns `(context, local_ratchet_pub)`.
+    fn begin_transfer_send(
+        &mut self,
+        transfer_id: &[u8],
+        remote_ratchet_pub: &[u8],
+    ) -> Result<(BtrTransferContext, Cow<'static, [u8]>), Error> {
+        let tid: [u8; 16] = fixed(transfer_id, "transfer_id")?;
+        let rpub: [u8; 32] = fixed(remote_ratchet_pub, "remote_ratchet_pub")?;
+        let (ctx, local_pub) = self.inner.begin_transfer_send(&tid, &rpub)?;
+        Ok((
+            BtrTransferContext { inner: ctx },
+            py_bytes(local_pub.to_vec()),
+        ))
+    }
+
+    /// Begin a receive-side transfer using the local ephemeral secret key.
+    fn begin_transfer_receive(
+        &mut self,
+        transfer_id: &[u8],
+        remote_ratchet_pub: &[u8],
+        local_secret_key: &[u8],
+    ) -> Result<BtrTransferContext, Error> {
+        let tid: [u8; 16] = fixed(transfer_id, "transfer_id")?;
+        let rpub: [u8; 32] = fixed(remote_ratchet_pub, "remote_ratchet_pub")?;
+        let lsk: [u8; 32] = fixed(local_secret_key, "local_secret_key")?;
+        let ctx = self
+            .inner
+            .begin_transfer_receive_with_key(&tid, &rpub, &lsk)?;
+        Ok(BtrTransferContext { inner: ctx })
+    }
+
+    #[getter]
+    fn ratchet_generation(&self) -> u32 {
+        self.inner.ratchet_generation()
+    }
+
+    fn end_transfer(&mut self) {
+        self.inner.end_transfer();
+    }
+
+    /// Zeroize all BTR state; the engine is unusable afterwards.
+    fn cleanup_disconnect(&mut self) {
+        self.inner.cleanup_disconnect();
+    }
+}
+
+/// Per-transfer chain state.
+#[pyclass(module = "bolt")]
+struct BtrTransferContext {
+    inner: bolt_btr::BtrTransferContext,
+}
+
+#[pymethods]
+impl BtrTransferContext {
+    /// Seal at the next chain position. Returns `(chain_index, sealed)`.
+    fn seal_chunk(&mut self, plaintext: &[u8]) -> Result<(u32, Cow<'static, [u8]>), Error> {
+        let (chain_index, sealed) = self.inner.seal_chunk(plaintext)?;
+        Ok((chain_index, py_bytes(sealed)))
+    }
+
+    fn open_chunk(
+        &mut self,
+        expected_chain_index: u32,
+        sealed: &[u8],
+    ) -> Result<Cow<'static, [u8]>, Error> {
+        Ok(py_bytes(
+            self.inner.open_chunk(expected_chain_index, sealed)?,
+        ))
+    }
+
+    #[getter]
+    fn chain_index(&self) -> u32 {
+        self.inner.chain_index()
+    }
+
+    #[getter]
+    fn generation(&self) -> u32 {
+        self.inner.generation()
+    }
+
+    #[getter]
+    fn transfer_id(&self) -> Cow<'static, [u8]> {
+        py_bytes(self.inner.transfer_id().to_vec())
+    }
+
+    fn cleanup_complete(&mut self) {
+        self.inner.cleanup_complete();
+    }
+
+    fn cleanup_cancel(&mut self) {
+        self.inner.cleanup_cancel();
+    }
+}
+
+// ── BTR Primitives (§16.3, §16.4, §16.8) ─────────────────────────
+
+/// `session_root_key` from the ephemeral shared secret.
+#[pyfunction]
+fn btr_derive_session_root(ephemeral_shared_secret: &[u8]) -> Result<Cow<'static, [u8]>, Error> {
+    let ess: [u8; 32] = fixed(ephemeral_shared_secret, "ephemeral_shared_secret")?;
+    Ok(py_bytes(
+        bolt_btr::key_schedule::derive_session_root(&ess).to_vec(),
+    ))
+}
+
+/// `transfer_root_key` for one transfer.
+#[pyfunction]
+fn btr_derive_transfer_root(
+    session_root_key: &[u8],
+    transfer_id: &[u8],
+) -> Result<Cow<'static, [u8]>, Error> {
+    let srk: [u8; 32] = fixed(session_root_key, "session_root_key")?;
+    let tid: [u8; 16] = fixed(transfer_id, "transfer_id")?;
+    Ok(py_bytes(
+        bolt_btr::key_schedule::derive_transfer_root(&srk, &tid).to_vec(),
+    ))
+}
+
+/// One symmetric chain step. Returns `(message_key, next_chain_key)`.
+#[pyfunction]
+fn btr_chain_advance(chain_key: &[u8]) -> Result<BytesPair, Error> {
+    let ck: [u8; 32] = fixed(chain_key, "chain_key")?;
+    let out = bolt_btr::key_schedule::chain_advance(&ck);
+    Ok((
+        py_bytes(out.message_key.to_vec()),
+        py_bytes(out.next_chain_key.to_vec()),
+    ))
+}
+
+/// New `session_root_key` after a DH ratchet step.
+#[pyfunction]
+fn btr_derive_ratcheted_session_root(
+    session_root_key: &[u8],
+    dh_output: &[u8],
+) -> Result<Cow<'static, [u8]>, Error> {
+    let srk: [u8; 32] = fixed(session_root_key, "session_root_key")?;
+    let dh: [u8; 32] = fixed(dh_output, "dh_output")?;
+    Ok(py_bytes(
+        bolt_btr::ratchet::derive_ratcheted_session_root(&srk, &dh).to_vec(),
+    ))
+}
+
+/// Seal with a message key. Returns `nonce || ciphertext` (random nonce).
+#[pyfunction]
+fn btr_seal(message_key: &[u8], plaintext: &[u8]) -> Result<Cow<'static, [u8]>, Error> {
+    let mk: [u8; 32] = fixed(message_key, "message_key")?;
+    Ok(py_bytes(bolt_btr::encrypt::btr_seal(&mk, plaintext)?))
+}
+
+/// Open `nonce || ciphertext` with a message key.
+#[pyfunction]
+fn btr_open(message_key: &[u8], sealed: &[u8]) -> Result<Cow<'static, [u8]>, Error> {
+    let mk: [u8; 32] = fixed(message_key, "message_key")?;
+    Ok(py_bytes(bolt_btr::encrypt::btr_open(&mk, sealed)?))
+}
+
+/// Canonical hash of the negotiated capability list.
+#[pyfunction]
+fn btr_capability_transcript(negotiated_capabilities: Vec<String>) -> Cow<'static, [u8]> {
+    py_bytes(bolt_btr::hybrid::capability_transcript(&negotiated_capabilities).to_vec())
+}
+
+/// X25519 + ML-KEM shared secrets combined into one secret.
+#[pyfunction]
+fn btr_combine_hybrid_secret(
+    x25519_shared_secret: &[u8],
+    mlkem_shared_secret: &[u8],
+    negotiated_capabilities: Vec<String>,
+) -> Result<Cow<'static, [u8]>, Error> {
+    let x: [u8; 32] = fixed(x25519_shared_secret, "x25519_shared_secret")?;
+    let k: [u8; 32] = fixed(mlkem_shared_secret, "mlkem_shared_secret")?;
+    Ok(py_bytes(
+        bolt_btr::hybrid::combine_hybrid_secret(&x, &k, &negotiated_capabilities).to_vec(),
+    ))
+}
+
+/// `session_root_key` from a hybrid handshake.
+#[pyfunction]
+fn btr_derive_hybrid_session_root(
+    x25519_shared_secret: &[u8],
+    mlkem_shared_secret: &[u8],
+    negotiated_capabilities: Vec<String>,
+) -> Result<Cow<'static, [u8]>, Error> {
+    let x: [u8; 32] = fixed(x25519_shared_secret, "x25519_shared_secret")?;
+    let k: [u8; 32] = fixed(mlkem_shared_secret, "mlkem_shared_secret")?;
+    Ok(py_bytes(
+        bolt_btr::hybrid::derive_hybrid_session_root(&x, &k, &negotiated_capabilities).to_vec(),
+    ))
+}
+
+/// (transfer_id, generation, chain_index) replay and ordering guard (§11).
+#[pyclass(module = "bolt")]
+#[derive(Default)]
+struct ReplayGuard {
+    inner: bolt_btr::replay::ReplayGuard,
+}
+
+#[pymethods]
+impl ReplayGuard {
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn begin_transfer(&mut self, transfer_id: &[u8], generation: u32) -> Result<(), Error> {
+        let tid: [u8; 16] = fixed(transfer_id, "transfer_id")?;
+        self.inner.begin_transfer(tid, generation);
+        Ok(())
+    }
+
+    /// Accept or reject the next chunk position.
+    fn check(
+        &mut self,
+        transfer_id: &[u8],
+        generation: u32,
+        chain_index: u32,
+    ) -> Result<(), Error> {
+        let tid: [u8; 16] = fixed(transfer_id, "transfer_id")?;
+        Ok(self.inner.check(&tid, generation, chain_index)?)
+    }
+
+    fn end_transfer(&mut self) {
+        self.inner.end_transfer();
+    }
+
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+}
+
+// ── Transfer State (§9) ───────────────────────────────────────────
+
+/// Snapshot of a §9 transfer state.
+#[pyclass(module = "bolt", frozen, get_all)]
+struct TransferState {
+    /// `"IDLE"`, `"OFFERED"`, `"ACCEPTED"`, `"TRANSFERRING"`, `"PAUSED"`,
+    /// `"COMPLETED"`, `"CANCELLED"`, or `"ERROR"`.
+    kind: &'static str,
+    transfer_id: Option<String>,
+    /// `"BY_SENDER"`, `"BY_RECEIVER"`, or `"REJECTED"` (CANCELLED only).
+    cancel_reason: Option<&'static str>,
+    /// Error detail (ERROR only).
+    detail: Option<String>,
+}
+
+#[pymethods]
+impl TransferState {
+    fn __repr__(&self) -> String {
+        match (&self.transfer_id, self.cancel_reason, &self.detail) {
+            (Some(tid), Some(reason), _) => {
+                format!("TransferState({}, {tid}, {reason})", self.kind)
+            }
+            (Some(tid), None, _) => format!("TransferState({}, {tid})", self.kind),
+            (None, _, Some(detail)) => format!("TransferState({}, {detail:?})", self.kind),
+            _ => format!("TransferState({})", self.kind),
+        }
+    }
+}
+
+impl From<&bolt_transfer_core::TransferState> for TransferState {
+    fn from(state: &bolt_transfer_core::TransferState) -> Self {
+        use bolt_transfer_core::{CancelReason, TransferState as S};
+
+        let kind = match state {
+            S::Idle => "IDLE",
+            S::Offered { .. } => "OFFERED",
+            S::Accepted { .. } => "ACCEPTED",
+            S::Transferring { .. } => "TRANSFERRING",
+            S::Paused { .. } => "PAUSED",
+            S::Completed { .. } => "COMPLETED",
+            S::Cancelled { .. } => "CANCELLED",
+            S::Error { .. } => "ERROR",
+        };
+        let cancel_reason = match state {
+            S::Cancelled { reason, .. } => Some(match reason {
+                CancelReason::BySender => "BY_SENDER",
+                CancelReason::ByReceiver => "BY_RECEIVER",
+                CancelReason::Rejected => "REJECTED",
+            }),
+            _ => None,
+        };
+        let detail = match state {
+            S::Error { detail } => Some(detail.clone()),
+            _ => None,
+        };
+        Self {
+            kind,
+            transfer_id: state.transfer_id().map(String::from),
+            cancel_reason,
+            detail,
+        }
+    }
+}
+
+// ── Send Session ──────────────────────────────────────────────────
+
+/// Offer metadata returned by `SendSession.begin_send`.
+#[pyclass(module = "bolt", frozen, get_all)]
+struct SendOffer {
+    transfer_id: String,
+    filename: String,
+    size: u64,
+    total_chunks: u32,
+    chunk_size: u32,
+    file_hash: Option<String>,
+}
+
+/// One outbound chunk returned by `SendSession.next_chunk`.
+#[pyclass(module = "bolt", frozen)]
+struct SendChunk {
+    #[pyo3(get)]
+    transfer_id: String,
+    #[pyo3(get)]
+    chunk_index: u32,
+    #[pyo3(get)]
+    total_chunks: u32,
+    data: Vec<u8>,
+}
+
+#[pymethods]
+impl SendChunk {
+    #[getter]
+    fn data(&self) -> Cow<'static, [u8]> {
+        py_bytes(self.data.clone())
+    }
+}
+
+/// Send-side §9 state machine.
+#[pyclass(module = "bolt")]
+struct SendSession {
+    inner: bolt_transfer_core::SendSession,
+}
+
+#[pymethods]
+impl SendSession {
+    #[new]
+    fn new() -> Self {
+        Self {
+            inner: bolt_transfer_core::SendSession::new(),
+        }
+    }
+
+    /// Idle → Offered.
+    #[pyo3(signature = (transfer_id, payload, filename, file_hash = None))]
+    fn begin_send(
+        &mut self,
+        transfer_id: &str,
+        payload: &[u8],
+        filename: &str,
+        file_hash: Option<String>,
+    ) -> Result<SendOffer, Error> {
+        let offer = self
+            .inner
+            .begin_send(transfer_id, payload.to_vec(), filename, file_hash)?;
+        Ok(SendOffer {
+            transfer_id: offer.transfer_id,
+            filename: offer.filename,
+            size: offer.size,
+            total_chunks: offer.total_chunks,
+            chunk_size: offer.chunk_size,
+            file_hash: offer.file_hash,
+        })
+    }
+
+    fn on_accept(&mut self, transfer_id: &str) -> Result<(), Error> {
+        Ok(self.inner.on_accept(transfer_id)?)
+    }
+
+    fn on_cancel(&mut self, transfer_id: &str) -> Result<(), Error> {
+        Ok(self.inner.on_cancel(transfer_id)?)
+    }
+
+    fn on_pause(&mut self, transfer_id: &str) -> Result<(), Error> {
+        Ok(self.inner.on_pause(transfer_id)?)
+    }
+
+    fn on_resume(&mut self, transfer_id: &str) -> Result<(), Error> {
+        Ok(self.inner.on_resume(transfer_id)?)
+    }
+
+    /// Next chunk, or None when all chunks have been yielded.
+    fn next_chunk(&mut self) -> Result<Option<SendChunk>, Error> {
+        Ok(self.inner.next_chunk()?.map(|c| SendChunk {
+            transfer_id: c.transfer_id,
+            chunk_index: c.chunk_index,
+            total_chunks: c.total_chunks,
+            data: c.data,
+        }))
+    }
+
+    /// Transferring → Completed. Returns the transfer ID.
+    fn finish(&mut self) -> Result<String, Error> {
+        Ok(self.inner.finish()?)
+    }
+
+    #[getter]
+    fn state(&self) -> TransferState {
+        self.inner.state().into()
+    }
+}
+
+// ── Receive Session ───────────────────────────────────────────────
+
+/// Receive-side §9 state machine with SHA-256 verification on finish.
+#[pyclass(module = "bolt")]
+struct ReceiveSession {
+    inner: bolt_transfer_core::ReceiveSession,
+}
+
+#[pymethods]
+impl ReceiveSession {
+    #[new]
+    fn new() -> Self {
+        Self {
+            inner: bolt_transfer_core::ReceiveSession::new(),
+        }
+    }
+
+    /// Idle → Offered.
+    #[pyo3(signature = (transfer_id, size, total_chunks, expected_hash = None))]
+    fn on_file_offer(
+        &mut self,
+        transfer_id: &str,
+        size: u64,
+        total_chunks: u32,
+        expected_hash: Option<&str>,
+    ) -> Result<(), Error> {
+        Ok(self
+            .inner
+            .on_file_offer(transfer_id, size, total_chunks, expected_hash)?)
+    }
+
+    /// Offered → Transferring. Returns the transfer ID.
+    fn accept(&mut self) -> Result<String, Error> {
+        Ok(self.inner.accept_current_offer()?)
+    }
+
+    /// Offered → Cancelled(REJECTED). Returns the transfer ID.
+    fn reject(&mut self) -> Result<String, Error> {
+        Ok(self.inner.reject_current_offer()?)
+    }
+
+    fn on_file_chunk(
+        &mut self,
+        transfer_id: &str,
+        chunk_index: u32,
+        data: &[u8],
+    ) -> Result<(), Error> {
+        Ok(self.inner.on_file_chunk(transfer_id, chunk_index, data)?)
+    }
+
+    /// Transferring → Completed, verifying SHA-256 if the offer carried one.
+    fn finish(&mut self, transfer_id: &str) -> Result<(), Error> {
+        Ok(self
+            .inner
+            .on_file_finish(transfer_id, Some(&bolt_transfer_core::Sha256Verifier))?)
+    }
+
+    fn cancel(&mut self, transfer_id: &str) -> Result<(), Error> {
+        Ok(self.inner.cancel(transfer_id)?)
+    }
+
+    #[getter]
+    fn state(&self) -> TransferState {
+        self.inner.state().into()
+    }
+
+    #[getter]
+    fn hash_verified(&self) -> bool {
+        self.inner.hash_verified()
+    }
+
+    /// Reassembled file once COMPLETED, else None.
+    #[getter]
+    fn completed_bytes(&self) -> Option<Cow<'static, [u8]>> {
+        self.inner.completed_bytes().map(|b| py_bytes(b.to_vec()))
+    }
+}
+
+// ── Module ────────────────────────────────────────────────────────
+
+#[pymodule]
+fn bolt(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("BoltError", m.py().get_type_bound::<BoltError>())?;
+    m.add(
+        "WIRE_ERROR_CODES",
+        bolt_core::errors::WIRE_ERROR_CODES.to_vec(),
+    )?;
+
+    m.add_class::<KeyPair>()?;
+    m.add_function(wrap_pyfunction!(generate_ephemeral_keypair, m)?)?;
+    m.add_function(wrap_pyfunction!(generate_identity_keypair, m)?)?;
+    m.add_function(wrap_pyfunction!(seal_box_payload, m)?)?;
+    m.add_function(wrap_pyfunction!(open_box_payload, m)?)?;
+    m.add_function(wrap_pyfunction!(compute_sas, m)?)?;
+    m.add_function(wrap_pyfunction!(compute_sas_emoji_names, m)?)?;
+    m.add_function(wrap_pyfunction!(generate_peer_code, m)?)?;
+    m.add_function(wrap_pyfunction!(is_valid_peer_code, m)?)?;
+    m.add_function(wrap_pyfunction!(sha256_hex, m)?)?;
+    m.add_function(wrap_pyfunction!(scalar_mult, m)?)?;
+    m.add("NONCE_LENGTH", bolt_core::constants::NONCE_LENGTH)?;
+    m.add("BOX_OVERHEAD", bolt_core::constants::BOX_OVERHEAD)?;
+
+    m.add_class::<PairingPayload>()?;
+    m.add_function(wrap_pyfunction!(pairing_fingerprint, m)?)?;
+    m.add_class::<PakeSession>()?;
+    m.add_class::<PakeKeys>()?;
+    m.add_function(wrap_pyfunction!(sign_rotation, m)?)?;
+    #[cfg(feature = "vectors")]
+    m.add_function(wrap_pyfunction!(sign_rotation_with_nonces, m)?)?;
+    m.add_function(wrap_pyfunction!(verify_rotation, m)?)?;
+
+    m.add_function(wrap_pyfunction!(negotiate_btr, m)?)?;
+    m.add_function(wrap_pyfunction!(btr_log_token, m)?)?;
+    m.add_class::<BtrEngine>()?;
+    m.add_class::<BtrTransferContext>()?;
+    m.add_function(wrap_pyfunction!(btr_derive_session_root, m)?)?;
+    m.add_function(wrap_pyfunction!(btr_derive_transfer_root, m)?)?;
+    m.add_function(wrap_pyfunction!(btr_chain_advance, m)?)?;
+    m.add_function(wrap_pyfunction!(btr_derive_ratcheted_session_root, m)?)?;
+    m.add_function(wrap_pyfunction!(btr_seal, m)?)?;
+    m.add_function(wrap_pyfunction!(btr_open, m)?)?;
+    m.add_function(wrap_pyfunction!(btr_capability_transcript, m)?)?;
+    m.add_function(wrap_pyfunction!(btr_combine_hybrid_secret, m)?)?;
+    m.add_function(wrap_pyfunction!(btr_derive_hybrid_session_root, m)?)?;
+    m.add_class::<ReplayGuard>()?;
+
+    m.add_class::<TransferState>()?;
+    m.add_class::<SendOffer>()?;
+    m.add_class::<SendChunk>()?;
+    m.add_class::<SendSession>()?;
+    m.add_class::<ReceiveSession>()?;
+    Ok(())
+}
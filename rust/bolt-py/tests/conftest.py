@@ -0,0 +1,18 @@
+"""Shared fixtures: golden vectors live in bolt-core/test-vectors."""
+
+import json
+from pathlib import Path
+
+import pytest
+
+VECTORS_DIR = Path(__file__).resolve().parents[2] / "bolt-core" / "test-vectors"
+
+
+def load_vectors(relpath):
+    with open(VECTORS_DIR / relpath, encoding="utf-8") as f:
+        return json.load(f)
+
+
+@pytest.fixture(scope="session")
+def vectors():
+    return load_vectors
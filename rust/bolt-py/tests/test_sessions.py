@@ -0,0 +1,82 @@
+"""§9 send/receive sessions and BTR driven end-to-end from Python."""
+
+import pytest
+
+import bolt
+
+
+def test_keypairs_and_peer_codes():
+    kp = bolt.generate_ephemeral_keypair()
+    assert len(kp.public_key) == 32 and len(kp.secret_key) == 32
+    assert len(bolt.generate_identity_keypair().public_key) == 32
+
+    code = bolt.generate_peer_code()
+    assert bolt.is_valid_peer_code(code)
+    assert not bolt.is_valid_peer_code("")
+    assert bolt.sha256_hex(b"hello").startswith("2cf24dba")
+
+
+def test_send_btr_receive_end_to_end():
+    payload = bytes(i % 251 for i in range(40_000))
+    digest = bolt.sha256_hex(payload)
+    shared = bytes([0x42]) * 32
+    btr_tid = bytes([0x07]) * 16
+
+    receiver_kp = bolt.generate_ephemeral_keypair()
+    send_engine, recv_engine = bolt.BtrEngine(shared), bolt.BtrEngine(shared)
+    send_ctx, sender_ratchet_pub = send_engine.begin_transfer_send(
+        btr_tid, receiver_kp.public_key
+    )
+    recv_ctx = recv_engine.begin_transfer_receive(
+        btr_tid, sender_ratchet_pub, receiver_kp.secret_key
+    )
+
+    sender, receiver = bolt.SendSession(), bolt.ReceiveSession()
+    offer = sender.begin_send("py-1", payload, "file.bin", digest)
+    assert sender.state.kind == "OFFERED"
+    receiver.on_file_offer(offer.transfer_id, offer.size, offer.total_chunks, offer.file_hash)
+    assert receiver.accept() == "py-1"
+    sender.on_accept("py-1")
+    assert sender.state.kind == "TRANSFERRING"
+
+    while (chunk := sender.next_chunk()) is not None:
+        chain_index, sealed = send_ctx.seal_chunk(chunk.data)
+        assert chain_index == chunk.chunk_index
+        opened = recv_ctx.open_chunk(chain_index, sealed)
+        receiver.on_file_chunk(chunk.transfer_id, chunk.chunk_index, opened)
+    assert send_ctx.chain_index == offer.total_chunks
+
+    assert sender.finish() == "py-1"
+    receiver.finish("py-1")
+    assert receiver.hash_verified
+    assert receiver.completed_bytes == payload
+    assert receiver.state.kind == "COMPLETED"
+    assert receiver.state.transfer_id == "py-1"
+
+
+def test_errors_carry_wire_codes():
+    rs = bolt.ReceiveSession()
+    with pytest.raises(bolt.BoltError) as e:
+        rs.accept()
+    assert e.value.code == "INVALID_STATE"
+
+    rs.on_file_offer("py-2", 4, 1, bolt.sha256_hex(b"abcd"))
+    rs.accept()
+    rs.on_file_chunk("py-2", 0, b"abce")
+    with pytest.raises(bolt.BoltError) as e:
+        rs.finish("py-2")
+    assert e.value.code == "INTEGRITY_FAILED"
+    assert rs.completed_bytes is None
+
+
+def test_reject_and_cancel_states():
+    rs = bolt.ReceiveSession()
+    rs.on_file_offer("py-3", 10, 1)
+    assert rs.reject() == "py-3"
+    assert rs.state.kind == "CANCELLED"
+    assert rs.state.cancel_reason == "REJECTED"
+
+    ss = bolt.SendSession()
+    ss.begin_send("py-4", b"x" * 10, "x.bin")
+    ss.on_cancel("py-4")
+    assert ss.state.cancel_reason == "BY_RECEIVER"
@@ -0,0 +1,353 @@
+"""Golden vector re-verification through the Python bindings.
+
+Same files the Rust and TypeScript suites consume; a mismatch here means
+the bindings drifted from the Rust authority.
+"""
+
+import base64
+import json
+
+import pytest
+
+import bolt
+
+
+def h(s):
+    return bytes.fromhex(s)
+
+
+# ── SAS ───────────────────────────────────────────────────────────
+
+
+def test_sas_vectors(vectors):
+    data = vectors("core/sas.vectors.json")
+    assert data["cases"]
+    for case in data["cases"]:
+        keys = (
+            h(case["identity_a_hex"]),
+            h(case["identity_b_hex"]),
+            h(case["ephemeral_a_hex"]),
+            h(case["ephemeral_b_hex"]),
+        )
+        assert bolt.compute_sas(*keys) == case["expected_sas"], case["name"]
+        assert bolt.compute_sas(*keys, "words") == case["expected_sas_words"]
+        assert bolt.compute_sas(*keys, "emoji") == case["expected_sas_emoji"]
+        assert bolt.compute_sas(*keys, "decimal") == case["expected_sas_decimal"]
+        assert bolt.compute_sas_emoji_names(*keys) == case["expected_sas_emoji_names"]
+
+
+def test_sas_rejects_bad_input():
+    k = bytes(32)
+    with pytest.raises(bolt.BoltError) as e:
+        bolt.compute_sas(k, k, k, bytes(31))
+    assert e.value.code == "INVALID_MESSAGE"
+    with pytest.raises(bolt.BoltError):
+        bolt.compute_sas(k, k, k, k, "klingon")
+
+
+# ── NaCl box payload ──────────────────────────────────────────────
+
+
+def test_box_payload_vectors(vectors):
+    data = vectors("core/box-payload.vectors.json")
+    sender_pk = base64.b64decode(data["sender"]["publicKey_base64"])
+    receiver_sk = base64.b64decode(data["receiver"]["secretKey_base64"])
+    eve_pk = base64.b64decode(data["eve"]["publicKey_base64"])
+
+    for v in data["vectors"]:
+        opened = bolt.open_box_payload(v["sealed_base64"], sender_pk, receiver_sk)
+        assert opened == h(v["plaintext_hex"]), v["id"]
+
+    for v in data["corrupt_vectors"]:
+        spk = eve_pk if v.get("use_eve_as_sender") else sender_pk
+        with pytest.raises(bolt.BoltError) as e:
+            bolt.open_box_payload(v["sealed_base64"], spk, receiver_sk)
+        assert e.value.code == "ENCRYPTION_FAILED", v["id"]
+        assert v["expected_error"] in e.value.args[1]
+
+
+def test_box_payload_roundtrip_with_vector_keys(vectors):
+    data = vectors("core/box-payload.vectors.json")
+    sender_sk = base64.b64decode(data["sender"]["secretKey_base64"])
+    sender_pk = base64.b64decode(data["sender"]["publicKey_base64"])
+    receiver_pk = base64.b64decode(data["receiver"]["publicKey_base64"])
+    receiver_sk = base64.b64decode(data["receiver"]["secretKey_base64"])
+
+    sealed = bolt.seal_box_payload(b"from python", receiver_pk, sender_sk)
+    assert bolt.open_box_payload(sealed, sender_pk, receiver_sk) == b"from python"
+
+
+# ── HELLO / envelope open (H3) ────────────────────────────────────
+
+
+def test_web_hello_open_vectors(vectors):
+    data = vectors("core/web-hello-open.vectors.json")
+    assert data["cases"]
+    for case in data["cases"]:
+        opened = bolt.open_box_payload(
+            case["sealed_payload_base64"],
+            h(case["sender_public_hex"]),
+            h(case["receiver_secret_hex"]),
+        )
+        assert json.loads(opened) == case["expected_inner"], case["name"]
+        with pytest.raises(bolt.BoltError):
+            bolt.open_box_payload(
+                case["sealed_payload_base64"], bytes([0xFF] * 32), h(case["receiver_secret_hex"])
+            )
+
+
+def test_envelope_open_vectors(vectors):
+    data = vectors("core/envelope-open.vectors.json")
+    assert data["cases"]
+    for case in data["cases"]:
+        envelope = case["envelope_json"]
+        assert envelope["type"] == "profile-envelope", case["name"]
+        assert envelope["version"] == 1
+        assert envelope["encoding"] == "base64"
+        opened = bolt.open_box_payload(
+            envelope["payload"], h(case["sender_public_hex"]), h(case["receiver_secret_hex"])
+        )
+        assert json.loads(opened) == case["expected_inner"], case["name"]
+
+
+def test_framing_vectors(vectors):
+    data = vectors("core/framing.vectors.json")
+    assert data["constants"]["nonce_length"] == bolt.NONCE_LENGTH
+    assert data["constants"]["box_overhead"] == bolt.BOX_OVERHEAD
+    for v in data["vectors"]:
+        raw = base64.b64decode(v["sealed_base64"])
+        assert len(raw) == v["expected_decoded_length"], v["id"]
+        assert raw[: bolt.NONCE_LENGTH] == h(v["expected_nonce_hex"]), v["id"]
+        ciphertext = raw[bolt.NONCE_LENGTH :]
+        assert len(ciphertext) == v["expected_ciphertext_length"], v["id"]
+        assert len(ciphertext) == v["plaintext_length"] + bolt.BOX_OVERHEAD, v["id"]
+
+
+# ── Pairing / PAKE / identity rotation ────────────────────────────
+
+
+def _b64url(s):
+    return base64.urlsafe_b64decode(s + "=" * (-len(s) % 4))
+
+
+def test_pairing_vectors(vectors):
+    data = vectors("core/pairing.vectors.json")
+    assert data["cases"]
+    for case in data["cases"]:
+        identity = h(case["identity_public_hex"])
+        fingerprint = h(case["expected_fingerprint_hex"])
+        assert bolt.pairing_fingerprint(identity) == fingerprint, case["name"]
+
+        payload = bolt.PairingPayload.parse(case["expected_uri"])
+        assert payload.peer_code == case["peer_code"].upper().replace("-", ""), case["name"]
+        assert payload.identity_fingerprint == fingerprint, case["name"]
+        assert payload.matches_identity(identity), case["name"]
+        assert payload.to_uri() == case["expected_uri"], case["name"]
+        secret = case["expected_uri"].rsplit("&s=", 1)[1]
+        assert _b64url(secret) == h(case["secret_hex"]), case["name"]
+
+        ids = (h(case["identity_a_hex"]), h(case["identity_b_hex"]))
+        ephs = (h(case["ephemeral_a_hex"]), h(case["ephemeral_b_hex"]))
+        proof = payload.prove(*ids, *ephs)
+        assert proof == h(case["expected_proof_hex"]), case["name"]
+        with pytest.raises(bolt.BoltError) as e:
+            payload.prove(*ids, *ephs)
+        assert e.value.code == "INVALID_STATE"
+
+        # The displaying peer sees the keys in the other order.
+        shown = bolt.PairingPayload.parse(case["expected_uri"])
+        assert shown.verify(ids[1], ids[0], ephs[1], ephs[0], proof), case["name"]
+
+    assert data["invalid_uris"]
+    for case in data["invalid_uris"]:
+        with pytest.raises(bolt.BoltError):
+            bolt.PairingPayload.parse(case["uri"])
+
+
+
+def test_pake_vectors(vectors):
+    data = vectors("core/pake.vectors.json")
+    assert data["cases"]
+    for case in data["cases"]:
+        sid = h(case["sid_hex"])
+        id_i, id_r = h(case["identity_initiator_hex"]), h(case["identity_responder_hex"])
+        init = bolt.PakeSession.start_with_seed(
+            "INITIATOR", case["code_initiator"], sid, id_i, h(case["seed_initiator_hex"])
+        )
+        resp = bolt.PakeSession.start_with_seed(
+            "RESPONDER", case["code_responder"], sid, id_r, h(case["seed_responder_hex"])
+        )
+        share_i, share_r = init.share, resp.share
+        assert share_i == h(case["expected_share_initiator_hex"]), case["name"]
+        assert share_r == h(case["expected_share_responder_hex"]), case["name"]
+
+        keys_i = init.finish(share_r, h(case["identity_responder_seen_hex"]))
+        keys_r = resp.finish(share_i, id_i)
+        conf_i, conf_r = keys_i.confirmation, keys_r.confirmation
+        assert conf_i == h(case["expected_confirm_initiator_hex"]), case["name"]
+        assert conf_r == h(case["expected_confirm_responder_hex"]), case["name"]
+
+        if case["expected_error"] is None:
+            key_i, remote_i = keys_i.verify(conf_r)
+            key_r, remote_r = keys_r.verify(conf_i)
+            assert key_i == key_r == h(case["expected_key_hex"]), case["name"]
+            assert (remote_i, remote_r) == (id_r, id_i)
+        else:
+            for keys, conf in ((keys_i, conf_r), (keys_r, conf_i)):
+                with pytest.raises(bolt.BoltError) as e:
+                    keys.verify(conf)
+                assert e.value.code == case["expected_error"], case["name"]
+
+
+def test_rotation_vectors(vectors):
+    data = vectors("core/rotation.vectors.json")
+    assert data["cases"]
+    for case in data["cases"]:
+        old = bolt.KeyPair.from_secret_key(h(case["old_secret_hex"]))
+        new = bolt.KeyPair.from_secret_key(h(case["new_secret_hex"]))
+        signed = bolt.sign_rotation_with_nonces(
+            old,
+            new,
+            case["issued_at_ms"],
+            case["validity_ms"],
+            h(case["old_nonce_hex"]),
+            h(case["new_nonce_hex"]),
+        )
+        assert signed == h(case["signed_statement_hex"]), case["name"]
+
+        args = (h(case["statement_hex"]), h(case["pinned_hex"]), case["now_ms"])
+        if case["expected_error"] is None:
+            key = bolt.verify_rotation(*args)
+            assert key == h(case["expected_new_identity_hex"]) == new.public_key, case["name"]
+        else:
+            with pytest.raises(bolt.BoltError) as e:
+                bolt.verify_rotation(*args)
+            assert e.value.code == case["expected_error"], case["name"]
+
+
+# ── BTR ───────────────────────────────────────────────────────────
+
+
+def test_btr_negotiation_matrix(vectors):
+    data = vectors("btr/btr-downgrade-negotiate.vectors.json")
+    for v in data["vectors"]:
+        mode = bolt.negotiate_btr(
+            v["local_supports_btr"], v["remote_supports_btr"], v["remote_well_formed"]
+        )
+        assert mode == v["expected_mode"], v["id"]
+        assert bolt.btr_log_token(mode) == v["expected_log_token"], v["id"]
+
+
+def test_btr_lifecycle_vectors(vectors):
+    data = vectors("btr/btr-lifecycle.vectors.json")
+    engine = bolt.BtrEngine(h(data["ephemeral_shared_secret_hex"]))
+
+    for t in data["transfers"]:
+        ctx = engine.begin_transfer_receive(
+            h(t["transfer_id_hex"]), h(t["sender_public_hex"]), h(t["receiver_scalar_hex"])
+        )
+        assert engine.ratchet_generation == t["ratchet_generation_after"], t["id"]
+        for c in t["chunks"]:
+            opened = ctx.open_chunk(c["chain_index"], h(c["sealed_hex"]))
+            assert opened == h(c["plaintext_hex"]), (t["id"], c["chain_index"])
+        ctx.cleanup_complete()
+        engine.end_transfer()
+
+
+def test_btr_key_schedule_vectors(vectors):
+    for v in vectors("btr/btr-key-schedule.vectors.json")["vectors"]:
+        srk = bolt.btr_derive_session_root(h(v["ephemeral_shared_secret_hex"]))
+        assert srk == h(v["expected_session_root_key_hex"]), v["id"]
+
+
+def test_btr_transfer_ratchet_vectors(vectors):
+    for v in vectors("btr/btr-transfer-ratchet.vectors.json")["vectors"]:
+        trk = bolt.btr_derive_transfer_root(h(v["session_root_key_hex"]), h(v["transfer_id_hex"]))
+        assert trk == h(v["expected_transfer_root_key_hex"]), v["id"]
+
+
+def test_btr_chain_advance_vectors(vectors):
+    for v in vectors("btr/btr-chain-advance.vectors.json")["vectors"]:
+        message_key, next_chain_key = bolt.btr_chain_advance(h(v["chain_key_hex"]))
+        assert message_key == h(v["expected_message_key_hex"]), v["id"]
+        assert next_chain_key == h(v["expected_next_chain_key_hex"]), v["id"]
+
+
+def test_btr_dh_ratchet_vectors(vectors):
+    for v in vectors("btr/btr-dh-ratchet.vectors.json")["vectors"]:
+        srk = bolt.btr_derive_ratcheted_session_root(
+            h(v["current_session_root_key_hex"]), h(v["dh_output_hex"])
+        )
+        assert srk == h(v["expected_new_session_root_key_hex"]), v["id"]
+
+
+def test_btr_dh_sanity_vectors(vectors):
+    for v in vectors("btr/btr-dh-sanity.vectors.json")["vectors"]:
+        shared = bolt.scalar_mult(h(v["secret_scalar_hex"]), h(v["remote_public_hex"]))
+        assert shared == h(v["expected_shared_secret_hex"]), v["id"]
+
+
+def test_btr_encrypt_decrypt_vectors(vectors):
+    for v in vectors("btr/btr-encrypt-decrypt.vectors.json")["vectors"]:
+        key, sealed = h(v["message_key_hex"]), h(v["expected_ciphertext_hex"])
+        if v.get("expect_error"):
+            with pytest.raises(bolt.BoltError) as e:
+                bolt.btr_open(key, sealed)
+            assert e.value.code == v["expect_error"], v["id"]
+        else:
+            assert sealed[: bolt.NONCE_LENGTH] == h(v["nonce_hex"]), v["id"]
+            assert bolt.btr_open(key, sealed) == h(v["plaintext_hex"]), v["id"]
+            # btr_seal draws a fresh nonce, so only the round trip is comparable.
+            resealed = bolt.btr_seal(key, h(v["plaintext_hex"]))
+            assert bolt.btr_open(key, resealed) == h(v["plaintext_hex"]), v["id"]
+
+
+def test_btr_replay_reject_vectors(vectors):
+    for v in vectors("btr/btr-replay-reject.vectors.json")["vectors"]:
+        guard = bolt.ReplayGuard()
+        first = (v["prior_accepted"] or [v])[0]
+        guard.begin_transfer(h(first["transfer_id_hex"]), first["ratchet_generation"])
+        for p in v["prior_accepted"]:
+            guard.check(h(p["transfer_id_hex"]), p["ratchet_generation"], p["chain_index"])
+
+        args = (h(v["transfer_id_hex"]), v["ratchet_generation"], v["chain_index"])
+        if v["expected_reject"]:
+            with pytest.raises(bolt.BoltError) as e:
+                guard.check(*args)
+            assert e.value.code == v["expected_error_code"], v["id"]
+        else:
+            guard.check(*args)
+
+
+def test_btr_adversarial_vectors(vectors):
+    data = vectors("btr/btr-adversarial.vectors.json")
+    assert {v["type"] for v in data["vectors"]} == {"wrong_key_decrypt", "chain_index_desync"}
+    for v in data["vectors"]:
+        if v["type"] == "wrong_key_decrypt":
+            sealed = h(v["sealed_hex"])
+            assert bolt.btr_open(h(v["correct_key_hex"]), sealed) == h(v["plaintext_hex"])
+            with pytest.raises(bolt.BoltError) as e:
+                bolt.btr_open(h(v["wrong_key_hex"]), sealed)
+            assert e.value.code == v["expected_error"], v["id"]
+        else:
+            tid = h(v["transfer_id_hex"])
+            trk = bolt.btr_derive_transfer_root(h(v["session_root_key_hex"]), tid)
+            assert trk == h(v["transfer_root_key_hex"]), v["id"]
+            guard = bolt.ReplayGuard()
+            guard.begin_transfer(tid, 0)
+            with pytest.raises(bolt.BoltError) as e:
+                guard.check(tid, 0, v["receiver_open_at_index"])
+            assert e.value.code == v["expected_error"], v["id"]
+
+
+def test_btr_hybrid_combine_vectors(vectors):
+    for v in vectors("btr/btr-hybrid-combine.vectors.json")["vectors"]:
+        caps = v["negotiated_capabilities"]
+        x, k = h(v["x25519_shared_secret_hex"]), h(v["mlkem_shared_secret_hex"])
+        transcript = bolt.btr_capability_transcript(caps)
+        assert transcript == h(v["expected_capability_transcript_hex"]), v["id"]
+        combined = bolt.btr_combine_hybrid_secret(x, k, caps)
+        assert combined == h(v["expected_combined_secret_hex"]), v["id"]
+        srk = bolt.btr_derive_hybrid_session_root(x, k, caps)
+        assert srk == h(v["expected_session_root_key_hex"]), v["id"]
+        assert srk == bolt.btr_derive_session_root(combined), v["id"]
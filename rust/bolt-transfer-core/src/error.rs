@@ -9,6 +9,16 @@ pub enum TransferError {
     IntegrityFailed(String),
 }
 
+impl TransferError {
+    /// Canonical wire error code (bolt-core `WIRE_ERROR_CODES`).
+    pub fn code(&self) -> &'static str {
+        match self {
+            TransferError::InvalidTransition(_) => "INVALID_STATE",
+            TransferError::IntegrityFailed(_) => "INTEGRITY_FAILED",
+        }
+    }
+}
+
 impl std::fmt::Display for TransferError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -8,8 +8,9 @@
 //!
 //! - **Transport-agnostic**: No network I/O, no WebRTC, no IPC.
 //!   Transport observation is via the [`TransportQuery`] trait.
-//! - **Crypto-free**: No hash or encryption dependencies.
-//!   Integrity verification is optional via [`IntegrityVerifier`].
+//! - **Crypto-free**: No hash or encryption dependencies by default.
+//!   Integrity verification is optional via [`IntegrityVerifier`]; the
+//!   `sha256` feature adds the shared `Sha256Verifier`.
 //! - **Pure state machines**: Deterministic transitions, no async,
 //!   no side effects.
 //! - **WASM-compatible**: Compiles to `wasm32-unknown-unknown`.
@@ -57,4 +58,6 @@ pub use policy::{
 pub use receive::ReceiveSession;
 pub use send::{SendChunk, SendOffer, SendSession};
 pub use state::{CancelReason, TransferState};
+#[cfg(feature = "sha256")]
+pub use transport::Sha256Verifier;
 pub use transport::{IntegrityVerifier, TransportQuery};
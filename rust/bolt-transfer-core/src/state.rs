@@ -63,6 +63,31 @@ impl TransferState {
     }
 }
 
+/// Whether `from → to` is an edge of the PROTOCOL.md §9 diagram
+/// (self-loops included). Terminal states have no outgoing edges, and
+/// the transfer_id is fixed once a transfer is offered.
+///
+/// Single §9 edge table for the conformance models and fuzz targets.
+/// Gated behind the `test-support` feature.
+#[cfg(feature = "test-support")]
+pub fn is_section9_transition(from: &TransferState, to: &TransferState) -> bool {
+    use TransferState::*;
+    if from == to {
+        return true;
+    }
+    let same_id = from.transfer_id() == to.transfer_id();
+    match (from, to) {
+        (Idle, Offered { .. }) => true,
+        (Completed { .. } | Cancelled { .. } | Error { .. }, _) => false,
+        (_, Error { .. }) => true,
+        (Offered { .. }, Accepted { .. } | Transferring { .. } | Cancelled { .. }) => same_id,
+        (Accepted { .. }, Transferring { .. } | Cancelled { .. }) => same_id,
+        (Transferring { .. }, Paused { .. } | Completed { .. } | Cancelled { .. }) => same_id,
+        (Paused { .. }, Transferring { .. } | Cancelled { .. }) => same_id,
+        _ => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -122,4 +147,34 @@ mod tests {
         ];
         assert_eq!(reasons.len(), 3);
     }
+
+    #[cfg(feature = "test-support")]
+    #[test]
+    fn section9_edges_include_accepted_and_error() {
+        let offered = TransferState::Offered {
+            transfer_id: "t".into(),
+        };
+        let accepted = TransferState::Accepted {
+            transfer_id: "t".into(),
+        };
+        let transferring = TransferState::Transferring {
+            transfer_id: "t".into(),
+        };
+        let completed = TransferState::Completed {
+            transfer_id: "t".into(),
+        };
+        let error = TransferState::Error { detail: "x".into() };
+
+        assert!(is_section9_transition(&offered, &accepted));
+        assert!(is_section9_transition(&accepted, &transferring));
+        assert!(is_section9_transition(&transferring, &error));
+        assert!(!is_section9_transition(&error, &TransferState::Idle));
+        assert!(!is_section9_transition(&completed, &error));
+        assert!(!is_section9_transition(
+            &offered,
+            &TransferState::Accepted {
+                transfer_id: "other".into()
+            }
+        ));
+    }
 }
@@ -27,3 +27,36 @@ pub trait IntegrityVerifier {
     /// Returns `true` if the hash matches.
     fn verify(&self, data: &[u8], expected_hash: &str) -> bool;
 }
+
+/// SHA-256 verifier for `bolt.file-hash`. Hex comparison is
+/// case-insensitive (TS emits lowercase; older peers may not).
+#[cfg(feature = "sha256")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Sha256Verifier;
+
+#[cfg(feature = "sha256")]
+impl IntegrityVerifier for Sha256Verifier {
+    fn verify(&self, data: &[u8], expected_hash: &str) -> bool {
+        use sha2::{Digest, Sha256};
+        let computed: String = Sha256::digest(data)
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect();
+        computed.eq_ignore_ascii_case(expected_hash)
+    }
+}
+
+#[cfg(all(test, feature = "sha256"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sha256_verifier_matches_bolt_core_hash() {
+        let data = b"bolt file-hash";
+        let hex = bolt_core::hash::sha256_hex(data);
+        assert!(Sha256Verifier.verify(data, &hex));
+        assert!(Sha256Verifier.verify(data, &hex.to_uppercase()));
+        assert!(!Sha256Verifier.verify(b"other", &hex));
+        assert!(!Sha256Verifier.verify(data, &hex[..32]));
+    }
+}
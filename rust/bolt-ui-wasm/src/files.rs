@@ -0,0 +1,81 @@
+//! Browser file I/O. No filesystem in WASM: outgoing files come from an
+//! `<input type=file>` picker (or egui drag-and-drop), received files
+//! leave as a Blob download.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use eframe::egui;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Blob, HtmlAnchorElement, HtmlInputElement, Url};
+
+pub struct PickedFile {
+    pub name: String,
+    pub bytes: Vec<u8>,
+}
+
+/// Filled asynchronously once the picked file has been read.
+pub type FileSlot = Rc<RefCell<Option<Result<PickedFile, String>>>>;
+
+fn js_error(e: JsValue) -> String {
+    e.as_string().unwrap_or_else(|| format!("{e:?}"))
+}
+
+fn document() -> Result<web_sys::Document, String> {
+    web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or_else(|| "no document".to_string())
+}
+
+/// Open the browser file picker. The chosen file lands in `slot`; nothing
+/// happens if the user cancels.
+pub fn pick_file(slot: &FileSlot, ctx: &egui::Context) -> Result<(), String> {
+    let input: HtmlInputElement = document()?
+        .create_element("input")
+        .map_err(js_error)?
+        .dyn_into()
+        .map_err(|_| "not an input element".to_string())?;
+    input.set_type("file");
+
+    let on_change = {
+        let (slot, ctx, input) = (Rc::clone(slot), ctx.clone(), input.clone());
+        Closure::once_into_js(move || {
+            let Some(file) = input.files().and_then(|files| files.get(0)) else {
+                return;
+            };
+            wasm_bindgen_futures::spawn_local(async move {
+                let read = JsFuture::from(file.array_buffer())
+                    .await
+                    .map(|buffer| js_sys::Uint8Array::new(&buffer).to_vec())
+                    .map_err(js_error);
+                *slot.borrow_mut() = Some(read.map(|bytes| PickedFile {
+                    name: file.name(),
+                    bytes,
+                }));
+                ctx.request_repaint();
+            });
+        })
+    };
+    input.set_onchange(Some(on_change.unchecked_ref()));
+    input.click();
+    Ok(())
+}
+
+/// Hand `bytes` to the browser as a download named `name`.
+pub fn save_file(name: &str, bytes: &[u8]) -> Result<(), String> {
+    let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(bytes));
+    let blob = Blob::new_with_u8_array_sequence(&parts).map_err(js_error)?;
+    let url = Url::create_object_url_with_blob(&blob).map_err(js_error)?;
+
+    let anchor: HtmlAnchorElement = document()?
+        .create_element("a")
+        .map_err(js_error)?
+        .dyn_into()
+        .map_err(|_| "not an anchor element".to_string())?;
+    anchor.set_href(&url);
+    anchor.set_download(name);
+    anchor.click();
+    Url::revoke_object_url(&url).map_err(js_error)
+}
@@ -1,93 +1,479 @@
-// EW2 measurement PoC — browser egui shell via WASM.
+// Browser egui shell for Bolt, connecting to a desktop daemon's ws-endpoint.
 //
-// EW-G9: measurement-only, no consumer app integration.
-// No daemon, no IPC, no transport, no signaling.
-// Reuses theme, state, and screen rendering from bolt-ui where possible.
+// Signaling runs over the browser WebSocket API (signaling.rs). The Bolt
+// session — HELLO, envelope, BTR and send/receive — is bolt-peer's
+// PeerLink, running over a WS-direct socket (socket.rs). Ephemeral keys travel in the `publicKey`
+// field of connection_request / connection_accepted, so a desktop peer is
+// only reachable through signaling. No daemon, IPC or filesystem: files
+// are picked and downloaded through the browser (files.rs).
+// Theme and screens are adapted from bolt-ui.
 
+// Native builds only compile the unit tests; the app itself is wasm32-only.
+#![cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+
+mod files;
 mod screens;
+mod signaling;
+mod socket;
 mod state;
 mod theme;
 
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use bolt_core::encoding::{from_base64, to_base64};
 use eframe::egui;
+use serde_json::json;
+use web_time::Instant;
+
+use bolt_peer::{LinkEvent, PeerLink, CAPABILITIES};
+use files::FileSlot;
 use screens::connect::{ConnectAction, ConnectState};
+use screens::transfer::TransferAction;
 use screens::verify::VerifyAction;
 use screens::Screen;
+use signaling::{PeerInfo, SignalEvent, Signaling};
+use socket::{Socket, SocketEvent};
 use state::*;
 
-/// Browser-specific app struct. No daemon/IPC/filesystem fields.
-/// Compare with bolt-ui's BoltApp which has daemon_proc, ipc_client,
-/// data_dir, socket_path — all excluded here.
+const DEVICE_NAME: &str = "Web Browser";
+
+/// Browser-specific app struct. bolt-ui's BoltApp supervises a daemon per
+/// session over IPC; here the browser runs the one Bolt session itself.
 struct BoltWebApp {
     current_screen: Screen,
-    mode: ConnectMode,
-    host_info: Option<HostInfo>,
-    join_room: String,
-    join_session: String,
-    join_peer_code: String,
     local_peer_code: String,
+    /// Per page load — there is no storage for a long-lived identity yet,
+    /// so desktop peers see a new identity after every reload.
+    identity_public_key: [u8; 32],
+    signaling: Signaling,
+    peers: Vec<PeerInfo>,
+    incoming: Option<IncomingRequest>,
+    /// Peer code and display name.
+    remote: Option<(String, String)>,
+    socket: Option<Socket>,
+    link: Option<PeerLink>,
     connection: ConnectionState,
     transfer: TransferState,
     verify: VerifyState,
+    picked: FileSlot,
 }
 
 impl BoltWebApp {
-    fn new(cc: &eframe::CreationContext<'_>) -> Self {
+    fn new(cc: &eframe::CreationContext<'_>, signal_url: &str) -> Self {
         theme::apply_theme(&cc.egui_ctx);
 
-        // bolt-core peer code generation — already WASM-proven
         let local_peer_code = bolt_core::peer_code::generate_secure_peer_code();
+        let identity_public_key = bolt_core::identity::generate_identity_keypair().public_key;
 
         Self {
             current_screen: Screen::Connect,
-            mode: ConnectMode::Host,
-            host_info: None,
-            join_room: String::new(),
-            join_session: String::new(),
-            join_peer_code: String::new(),
+            signaling: Signaling::new(signal_url, &local_peer_code, DEVICE_NAME),
             local_peer_code,
+            identity_public_key,
+            peers: Vec::new(),
+            incoming: None,
+            remote: None,
+            socket: None,
+            link: None,
             connection: ConnectionState::Idle,
             transfer: TransferState::Idle,
             verify: VerifyState::NotStarted,
+            picked: Rc::new(RefCell::new(None)),
         }
     }
 
-    fn handle_connect_action(&mut self, action: ConnectAction) {
+    fn handle_connect_action(&mut self, action: ConnectAction, ctx: &egui::Context) {
         match action {
             ConnectAction::None => {}
-            ConnectAction::SwitchMode(mode) => {
-                self.mode = mode;
-            }
-            ConnectAction::CreateSession => {
-                // In desktop bolt-ui this spawns a daemon.
-                // In browser PoC we just generate mock host info.
-                let peer_code = bolt_core::peer_code::generate_secure_peer_code();
-                self.host_info = Some(HostInfo {
-                    peer_code,
-                    room: format!("r{}", &self.local_peer_code[..4].to_lowercase()),
-                    session: format!("s{:08x}", web_time::Instant::now().elapsed().as_millis() as u32),
-                });
-            }
-            ConnectAction::StartHostWithJoiner(_code) => {
-                // No daemon to start — simulate connecting state
+            ConnectAction::Request(peer_code) => self.request_peer(peer_code),
+            ConnectAction::Accept => self.accept_incoming(ctx),
+            ConnectAction::Decline => self.decline_incoming(),
+            ConnectAction::Cancel | ConnectAction::Disconnect => {
+                self.end_session(ConnectionState::Idle);
+            }
+        }
+    }
+
+    fn request_peer(&mut self, peer_code: String) {
+        let name = self
+            .peers
+            .iter()
+            .find(|p| p.peer_code == peer_code)
+            .map_or_else(|| peer_code.clone(), |p| p.device_name.clone());
+        let link = PeerLink::new(self.identity_public_key, &CAPABILITIES);
+        self.signaling.send_signal(
+            &peer_code,
+            "connection_request",
+            json!({
+                "deviceName": DEVICE_NAME,
+                "deviceType": signaling::DEVICE_TYPE,
+                "publicKey": to_base64(&link.local_ephemeral_key()),
+            }),
+        );
+        self.link = Some(link);
+        self.remote = Some((peer_code.clone(), name));
+        self.connection = ConnectionState::Requesting {
+            peer_code,
+            started_at: Instant::now(),
+        };
+    }
+
+    fn accept_incoming(&mut self, ctx: &egui::Context) {
+        let Some(req) = self.incoming.take() else {
+            return;
+        };
+        let mut link = PeerLink::new(self.identity_public_key, &CAPABILITIES);
+        link.set_remote_ephemeral(req.public_key);
+        self.signaling.send_signal(
+            &req.peer_code,
+            "connection_accepted",
+            json!({
+                "deviceName": DEVICE_NAME,
+                "deviceType": signaling::DEVICE_TYPE,
+                "publicKey": to_base64(&link.local_ephemeral_key()),
+            }),
+        );
+        self.link = Some(link);
+        self.remote = Some((req.peer_code, req.device_name));
+        self.open_link(&req.ws_url, ctx);
+    }
+
+    fn decline_incoming(&mut self) {
+        if let Some(req) = self.incoming.take() {
+            self.signaling.send_signal(
+                &req.peer_code,
+                "connection_declined",
+                json!({ "reason": "user_declined" }),
+            );
+        }
+    }
+
+    /// Open the WS-direct socket for `self.link`, which already holds the
+    /// peer's ephemeral key.
+    fn open_link(&mut self, ws_url: &str, ctx: &egui::Context) {
+        match Socket::connect(ws_url, ctx) {
+            Ok(socket) => {
+                self.socket = Some(socket);
                 self.connection = ConnectionState::Connecting {
-                    started_at: web_time::Instant::now(),
+                    started_at: Instant::now(),
                 };
+                self.verify = VerifyState::NotStarted;
+                self.transfer = TransferState::Idle;
             }
-            ConnectAction::StartJoin { .. } => {
-                self.connection = ConnectionState::Connecting {
-                    started_at: web_time::Instant::now(),
+            Err(e) => self.end_session(ConnectionState::Error(e)),
+        }
+    }
+
+    /// Tear down the link and socket, leaving `connection` as the outcome.
+    fn end_session(&mut self, connection: ConnectionState) {
+        if let ConnectionState::Requesting { peer_code, .. } = &self.connection {
+            self.signaling.send_signal(
+                peer_code,
+                "connection_declined",
+                json!({ "reason": "cancelled" }),
+            );
+        }
+        if let Some(link) = &mut self.link {
+            link.close();
+        }
+        self.link = None;
+        self.socket = None;
+        self.remote = None;
+        self.connection = connection;
+        self.verify = VerifyState::NotStarted;
+        self.transfer = match std::mem::replace(&mut self.transfer, TransferState::Idle) {
+            TransferState::Sending { file_name, .. }
+            | TransferState::Receiving { file_name, .. } => TransferState::Failed {
+                file_name,
+                reason: "Connection closed".to_string(),
+            },
+            TransferState::Ready => TransferState::Idle,
+            other => other,
+        };
+    }
+
+    fn poll_signaling(&mut self, ctx: &egui::Context) {
+        for event in self.signaling.poll(ctx) {
+            match event {
+                SignalEvent::Connected | SignalEvent::Error(_) => {}
+                SignalEvent::Disconnected(_) => self.peers.clear(),
+                SignalEvent::PeerList(peers) => {
+                    self.peers = peers
+                        .into_iter()
+                        .filter(|p| p.peer_code != self.local_peer_code)
+                        .collect();
+                }
+                SignalEvent::PeerJoined(peer) => {
+                    if peer.peer_code != self.local_peer_code
+                        && !self.peers.iter().any(|p| p.peer_code == peer.peer_code)
+                    {
+                        self.peers.push(peer);
+                    }
+                }
+                SignalEvent::PeerLeft(peer_code) => {
+                    self.peers.retain(|p| p.peer_code != peer_code);
+                    if self
+                        .incoming
+                        .as_ref()
+                        .is_some_and(|r| r.peer_code == peer_code)
+                    {
+                        self.incoming = None;
+                    }
+                    if matches!(&self.connection, ConnectionState::Requesting { peer_code: p, .. } if *p == peer_code)
+                    {
+                        self.end_session(ConnectionState::Error("Peer went offline".to_string()));
+                    }
+                }
+                SignalEvent::Signal {
+                    from,
+                    signal_type,
+                    data,
+                } => self.on_signal(&from, &signal_type, &data, ctx),
+            }
+        }
+    }
+
+    fn on_signal(
+        &mut self,
+        from: &str,
+        signal_type: &str,
+        data: &serde_json::Value,
+        ctx: &egui::Context,
+    ) {
+        let requesting_from = matches!(
+            &self.connection,
+            ConnectionState::Requesting { peer_code, .. } if peer_code == from
+        );
+        match signal_type {
+            "connection_request" => {
+                let duplicate = self.remote.as_ref().is_some_and(|(code, _)| code == from)
+                    || self.incoming.as_ref().is_some_and(|r| r.peer_code == from);
+                if duplicate {
+                    return;
+                }
+                let reason = if !self.connection.can_retry() || self.incoming.is_some() {
+                    "busy"
+                } else if let (Some(ws_url), Some(public_key)) =
+                    (data["wsUrl"].as_str(), public_key(data))
+                {
+                    self.incoming = Some(IncomingRequest {
+                        peer_code: from.to_string(),
+                        device_name: data["deviceName"].as_str().unwrap_or("Unknown").to_string(),
+                        ws_url: ws_url.to_string(),
+                        public_key,
+                    });
+                    self.current_screen = Screen::Connect;
+                    return;
+                } else {
+                    // No endpoint to dial (another browser), or no key to
+                    // seal HELLO to.
+                    "unsupported"
                 };
+                self.signaling.send_signal(
+                    from,
+                    "connection_declined",
+                    json!({ "reason": reason }),
+                );
+            }
+            "connection_accepted" if requesting_from => {
+                match (data["wsUrl"].as_str(), public_key(data), &mut self.link) {
+                    (Some(ws_url), Some(key), Some(link)) => {
+                        link.set_remote_ephemeral(key);
+                        let ws_url = ws_url.to_string();
+                        self.open_link(&ws_url, ctx);
+                    }
+                    _ => self.end_session(ConnectionState::Error(
+                        "Peer accepted without a wsUrl and publicKey".to_string(),
+                    )),
+                }
             }
-            ConnectAction::Cancel => {
-                self.connection = ConnectionState::Idle;
-                self.host_info = None;
+            "connection_declined" => {
+                if self.incoming.as_ref().is_some_and(|r| r.peer_code == from) {
+                    self.incoming = None;
+                }
+                if requesting_from {
+                    self.end_session(ConnectionState::Error("Connection declined".to_string()));
+                }
             }
+            _ => {}
         }
     }
+
+    fn poll_link(&mut self, ctx: &egui::Context) {
+        let (Some(socket), Some(link)) = (&mut self.socket, &mut self.link) else {
+            return;
+        };
+        let mut closed = None;
+        for event in socket.poll() {
+            match event {
+                SocketEvent::Open => link.start(socket),
+                SocketEvent::Text(text) => link.on_frame(&text, socket),
+                SocketEvent::Closed(reason) => {
+                    closed = Some(reason);
+                    break;
+                }
+            }
+        }
+        if closed.is_none() {
+            link.pump(socket);
+            if link.is_busy() {
+                // bufferedAmount drains without an event; keep pumping.
+                ctx.request_repaint_after(Duration::from_millis(16));
+            }
+        }
+        let events: Vec<LinkEvent> = std::iter::from_fn(|| link.poll_event()).collect();
+
+        for event in events {
+            self.on_link_event(event);
+        }
+        if let (Some(reason), true) = (closed, self.socket.is_some()) {
+            self.end_session(ConnectionState::Error(reason));
+        }
+    }
+
+    fn on_link_event(&mut self, event: LinkEvent) {
+        match event {
+            LinkEvent::Established { sas, .. } => {
+                self.connection = ConnectionState::Connected;
+                self.verify = VerifyState::Pending { sas_code: sas };
+                self.current_screen = Screen::Verify;
+            }
+            // Held until the SAS is confirmed; see `confirm_verification`.
+            LinkEvent::Offered { .. } => self.accept_offer(),
+            LinkEvent::SendProgress {
+                file_name,
+                bytes,
+                total,
+                ..
+            } => {
+                self.transfer = TransferState::Sending {
+                    file_name,
+                    progress: fraction(bytes, total),
+                }
+            }
+            LinkEvent::Sent { file_name, .. } => {
+                self.transfer = TransferState::Complete { file_name }
+            }
+            LinkEvent::ReceiveProgress {
+                file_name,
+                bytes,
+                total,
+                ..
+            } => {
+                self.transfer = TransferState::Receiving {
+                    file_name,
+                    progress: fraction(bytes, total),
+                }
+            }
+            LinkEvent::Received {
+                file_name, bytes, ..
+            } => {
+                self.transfer = match files::save_file(&file_name, &bytes) {
+                    Ok(()) => TransferState::Complete { file_name },
+                    Err(reason) => TransferState::Failed { file_name, reason },
+                };
+            }
+            LinkEvent::Failed { code, detail } => {
+                self.end_session(ConnectionState::Error(format!("{code}: {detail}")));
+            }
+        }
+    }
+
+    /// The user confirmed the SAS: transfers open up, including an offer
+    /// the peer sent while verification was pending.
+    fn confirm_verification(&mut self) {
+        self.verify = VerifyState::Confirmed;
+        self.transfer = TransferState::Ready;
+        self.accept_offer();
+    }
+
+    /// Accept the link's pending offer, if the SAS has been confirmed.
+    fn accept_offer(&mut self) {
+        let (Some(socket), Some(link)) = (&mut self.socket, &mut self.link) else {
+            return;
+        };
+        if self.verify != VerifyState::Confirmed || !link.has_pending_offer() {
+            return;
+        }
+        if let Err(reason) = link.accept_offer(socket) {
+            self.transfer = TransferState::Failed {
+                file_name: "file".to_string(),
+                reason,
+            };
+        }
+    }
+
+    /// Why Send File is unavailable, or `None` if it is.
+    fn send_blocked(&self) -> Option<&'static str> {
+        match (&self.connection, &self.verify) {
+            (ConnectionState::Connected, VerifyState::Confirmed) => self
+                .link
+                .as_ref()
+                .is_some_and(PeerLink::is_busy)
+                .then_some("Transfer in progress"),
+            (ConnectionState::Connected, _) => Some("Verify the connection first"),
+            _ => Some("Connect to a peer first"),
+        }
+    }
+
+    fn send_file(&mut self, file_name: String, bytes: Vec<u8>) {
+        if self.send_blocked().is_some() {
+            return;
+        }
+        let (Some(socket), Some(link)) = (&mut self.socket, &mut self.link) else {
+            return;
+        };
+        if let Err(reason) = link.send_file(&file_name, bytes, socket) {
+            self.transfer = TransferState::Failed { file_name, reason };
+        }
+    }
+
+    /// Files from the picker (async) or dropped onto the canvas.
+    fn take_outgoing_file(&mut self, ctx: &egui::Context) {
+        let picked = self.picked.borrow_mut().take();
+        match picked {
+            Some(Ok(file)) => self.send_file(file.name, file.bytes),
+            Some(Err(reason)) => {
+                self.transfer = TransferState::Failed {
+                    file_name: "file".to_string(),
+                    reason,
+                };
+            }
+            None => {}
+        }
+
+        let dropped = ctx.input(|i| {
+            i.raw
+                .dropped_files
+                .iter()
+                .find_map(|f| f.bytes.as_ref().map(|b| (f.name.clone(), b.to_vec())))
+        });
+        if let Some((name, bytes)) = dropped {
+            self.send_file(name, bytes);
+        }
+    }
+}
+
+fn fraction(bytes: u64, total: u64) -> f32 {
+    bytes as f32 / total.max(1) as f32
+}
+
+/// The peer's ephemeral key from a connection signal's `publicKey`.
+fn public_key(data: &serde_json::Value) -> Option<[u8; 32]> {
+    data["publicKey"]
+        .as_str()
+        .and_then(|k| from_base64(k).ok())
+        .and_then(|k| k.try_into().ok())
 }
 
 impl eframe::App for BoltWebApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        self.poll_signaling(ctx);
+        self.poll_link(ctx);
+        self.take_outgoing_file(ctx);
+
         // Navigation bar
         egui::TopBottomPanel::top("nav").show(ctx, |ui| {
             ui.horizontal(|ui| {
@@ -97,7 +483,7 @@ impl eframe::App for BoltWebApp {
                         .color(theme::ACCENT),
                 );
                 ui.label(
-                    egui::RichText::new("EW2 PoC")
+                    egui::RichText::new("Web")
                         .size(theme::FONT_SIZE_SMALL)
                         .color(theme::TEXT_MUTED),
                 );
@@ -128,32 +514,41 @@ impl eframe::App for BoltWebApp {
         });
 
         // Main content
-        egui::CentralPanel::default().show(ctx, |ui| {
-            match self.current_screen {
-                Screen::Connect => {
-                    let mut connect_state = ConnectState {
-                        mode: self.mode,
-                        host_info: self.host_info.as_ref(),
-                        local_peer_code: &self.local_peer_code,
-                        join_room: &mut self.join_room,
-                        join_session: &mut self.join_session,
-                        join_peer_code: &mut self.join_peer_code,
-                        connection: &self.connection,
-                        prereq_error: None,
-                    };
-                    let action = screens::connect::show(ui, &mut connect_state);
-                    self.handle_connect_action(action);
-                }
-                Screen::Transfer => {
-                    screens::transfer::show(ui, &self.transfer);
+        egui::CentralPanel::default().show(ctx, |ui| match self.current_screen {
+            Screen::Connect => {
+                let mut connect_state = ConnectState {
+                    local_peer_code: &self.local_peer_code,
+                    signaling_url: self.signaling.url(),
+                    signaling_connected: self.signaling.is_connected(),
+                    peers: &self.peers,
+                    incoming: self.incoming.as_ref(),
+                    peer_name: self.remote.as_ref().map(|(_, name)| name.as_str()),
+                    connection: &self.connection,
+                };
+                let action = screens::connect::show(ui, &mut connect_state);
+                self.handle_connect_action(action, ctx);
+            }
+            Screen::Transfer => {
+                let blocked = self.send_blocked();
+                if screens::transfer::show(ui, &self.transfer, blocked) == TransferAction::PickFile
+                {
+                    if let Err(reason) = files::pick_file(&self.picked, ctx) {
+                        self.transfer = TransferState::Failed {
+                            file_name: "file".to_string(),
+                            reason,
+                        };
+                    }
                 }
-                Screen::Verify => {
-                    let mut action = VerifyAction::None;
-                    screens::verify::show(ui, &self.verify, &mut action);
-                    match action {
-                        VerifyAction::None => {}
-                        VerifyAction::Confirm => self.verify = VerifyState::Confirmed,
-                        VerifyAction::Reject => self.verify = VerifyState::Rejected,
+            }
+            Screen::Verify => {
+                let mut action = VerifyAction::None;
+                screens::verify::show(ui, &self.verify, &mut action);
+                match action {
+                    VerifyAction::None => {}
+                    VerifyAction::Confirm => self.confirm_verification(),
+                    VerifyAction::Reject => {
+                        self.end_session(ConnectionState::Idle);
+                        self.verify = VerifyState::Rejected;
                     }
                 }
             }
@@ -161,28 +556,40 @@ impl eframe::App for BoltWebApp {
 
         // Check timeout
         if self.connection.is_timed_out() {
-            self.connection = ConnectionState::TimedOut;
+            self.end_session(ConnectionState::TimedOut);
+        } else if self.connection.can_cancel() {
+            ctx.request_repaint_after(Duration::from_secs(1));
         }
     }
 }
 
 // WASM entry point
+#[cfg(target_arch = "wasm32")]
 use wasm_bindgen::prelude::*;
 
+/// `signal_url` overrides the rendezvous server; by default it is the
+/// page's host on [`signaling::DEFAULT_PORT`].
+#[cfg(target_arch = "wasm32")]
 #[wasm_bindgen]
-pub async fn start(canvas_id: &str) -> Result<(), JsValue> {
+pub async fn start(canvas_id: &str, signal_url: Option<String>) -> Result<(), JsValue> {
     console_error_panic_hook::set_once();
 
-    let document = web_sys::window()
-        .expect("no window")
-        .document()
-        .expect("no document");
+    let window = web_sys::window().expect("no window");
+    let document = window.document().expect("no document");
     let canvas = document
         .get_element_by_id(canvas_id)
         .expect("canvas not found");
-    let canvas: web_sys::HtmlCanvasElement = canvas
-        .dyn_into()
-        .expect("element is not a canvas");
+    let canvas: web_sys::HtmlCanvasElement = canvas.dyn_into().expect("element is not a canvas");
+
+    let signal_url = signal_url.unwrap_or_else(|| {
+        let host = window
+            .location()
+            .hostname()
+            .ok()
+            .filter(|h| !h.is_empty())
+            .unwrap_or_else(|| "127.0.0.1".to_string());
+        format!("ws://{host}:{}", signaling::DEFAULT_PORT)
+    });
 
     let web_options = eframe::WebOptions::default();
 
@@ -190,7 +597,7 @@ pub async fn start(canvas_id: &str) -> Result<(), JsValue> {
         .start(
             canvas,
             web_options,
-            Box::new(|cc| Ok(Box::new(BoltWebApp::new(cc)))),
+            Box::new(move |cc| Ok(Box::new(BoltWebApp::new(cc, &signal_url)))),
         )
         .await
 }
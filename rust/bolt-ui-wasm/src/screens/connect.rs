@@ -1,36 +1,35 @@
-// EW2 REUSE: Adapted from bolt-ui/src/screens/connect.rs.
+// Adapted from bolt-ui/src/screens/connect.rs, decoupled from BoltApp:
+// takes state refs and returns a ConnectAction for the caller to handle.
 //
-// DECOUPLING REQUIRED: bolt-ui's connect.rs takes &mut BoltApp (desktop struct
-// with daemon/IPC fields). This version takes state refs instead.
-// All egui rendering code is preserved; only the function signatures changed.
-// Daemon stderr display (lines 77-88 in original) removed — no daemon in WASM.
-// app.start_host(), app.start_join(), app.cancel_connect() replaced with
-// ConnectAction enum for the caller to handle.
+// The browser has no daemon, so there is no Host mode. Peers come from
+// signaling discovery; a desktop peer serves the WS-direct endpoint and
+// the browser connects to it. There is no direct connect by URL: the
+// ephemeral key HELLO is sealed to only arrives with the peer's signal.
 
 use eframe::egui;
 
+use crate::signaling::PeerInfo;
 use crate::state::*;
 use crate::theme;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ConnectAction {
     None,
-    SwitchMode(ConnectMode),
-    CreateSession,
-    StartHostWithJoiner(String),
-    StartJoin { room: String, session: String, peer_code: String },
+    Request(String),
+    Accept,
+    Decline,
     Cancel,
+    Disconnect,
 }
 
 pub struct ConnectState<'a> {
-    pub mode: ConnectMode,
-    pub host_info: Option<&'a HostInfo>,
     pub local_peer_code: &'a str,
-    pub join_room: &'a mut String,
-    pub join_session: &'a mut String,
-    pub join_peer_code: &'a mut String,
+    pub signaling_url: &'a str,
+    pub signaling_connected: bool,
+    pub peers: &'a [PeerInfo],
+    pub incoming: Option<&'a IncomingRequest>,
+    pub peer_name: Option<&'a str>,
     pub connection: &'a ConnectionState,
-    pub prereq_error: Option<&'a str>,
 }
 
 pub fn show(ui: &mut egui::Ui, state: &mut ConnectState<'_>) -> ConnectAction {
@@ -38,65 +37,91 @@ pub fn show(ui: &mut egui::Ui, state: &mut ConnectState<'_>) -> ConnectAction {
 
     ui.add_space(theme::SPACING_XL);
 
-    // Mode selector (Host / Join tabs)
-    ui.horizontal(|ui| {
-        let host_active = state.mode == ConnectMode::Host;
-        let join_active = state.mode == ConnectMode::Join;
-        let can_switch = state.connection.can_retry();
-
-        if ui
-            .selectable_label(
-                host_active,
-                egui::RichText::new("Host")
-                    .size(theme::FONT_SIZE_HEADING)
-                    .color(if host_active { theme::ACCENT } else { theme::TEXT_SECONDARY }),
+    ui.vertical_centered(|ui| {
+        ui.label(
+            egui::RichText::new("Your Peer Code")
+                .size(theme::FONT_SIZE_SMALL)
+                .color(theme::TEXT_SECONDARY),
+        );
+        ui.label(
+            egui::RichText::new(state.local_peer_code)
+                .size(theme::FONT_SIZE_TITLE)
+                .color(theme::ACCENT)
+                .monospace(),
+        );
+        let (color, text) = if state.signaling_connected {
+            (
+                theme::SUCCESS,
+                format!("Signaling: {}", state.signaling_url),
             )
-            .clicked()
-            && can_switch
-        {
-            action = ConnectAction::SwitchMode(ConnectMode::Host);
-        }
-
-        ui.add_space(theme::SPACING_LG);
-
-        if ui
-            .selectable_label(
-                join_active,
-                egui::RichText::new("Join")
-                    .size(theme::FONT_SIZE_HEADING)
-                    .color(if join_active { theme::ACCENT } else { theme::TEXT_SECONDARY }),
+        } else {
+            (
+                theme::WARNING,
+                format!("Signaling offline ({}) — retrying", state.signaling_url),
             )
-            .clicked()
-            && can_switch
-        {
-            action = ConnectAction::SwitchMode(ConnectMode::Join);
-        }
+        };
+        ui.label(
+            egui::RichText::new(text)
+                .size(theme::FONT_SIZE_SMALL)
+                .color(color),
+        );
     });
 
     ui.add_space(theme::SPACING_LG);
     ui.separator();
     ui.add_space(theme::SPACING_LG);
 
-    match state.mode {
-        ConnectMode::Host => {
-            let host_action = show_host(ui, state);
-            if host_action != ConnectAction::None {
-                action = host_action;
+    ui.vertical_centered(|ui| {
+        if let Some(req) = state.incoming {
+            let incoming_action = show_incoming(ui, req);
+            if incoming_action != ConnectAction::None {
+                action = incoming_action;
             }
+            ui.add_space(theme::SPACING_LG);
         }
-        ConnectMode::Join => {
-            let join_action = show_join(ui, state);
-            if join_action != ConnectAction::None {
-                action = join_action;
+
+        match state.connection {
+            ConnectionState::Connected => {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "Connected to {}",
+                        state.peer_name.unwrap_or("peer")
+                    ))
+                    .size(theme::FONT_SIZE_HEADING)
+                    .color(theme::SUCCESS),
+                );
+                ui.add_space(theme::SPACING_MD);
+                if ui.add(danger_button("Disconnect")).clicked() {
+                    action = ConnectAction::Disconnect;
+                }
+            }
+            c if c.can_cancel() => {
+                ui.label(
+                    egui::RichText::new(state.peer_name.unwrap_or("peer"))
+                        .size(theme::FONT_SIZE_HEADING)
+                        .color(theme::TEXT_PRIMARY),
+                );
+                ui.add_space(theme::SPACING_MD);
+                ui.spinner();
+                ui.add_space(theme::SPACING_MD);
+                if ui.add(danger_button("Cancel")).clicked() {
+                    action = ConnectAction::Cancel;
+                }
+            }
+            _ => {
+                let peers_action = show_peers(ui, state);
+                if peers_action != ConnectAction::None {
+                    action = peers_action;
+                }
             }
         }
-    }
+    });
 
     // Status bar
     ui.add_space(theme::SPACING_XL);
     let status_color = match state.connection {
         ConnectionState::Idle => theme::TEXT_MUTED,
-        ConnectionState::Connecting { .. } => theme::WARNING,
+        ConnectionState::Requesting { .. } | ConnectionState::Connecting { .. } => theme::WARNING,
         ConnectionState::Connected => theme::SUCCESS,
         ConnectionState::TimedOut => theme::ERROR,
         ConnectionState::Error(_) => theme::ERROR,
@@ -110,212 +135,111 @@ pub fn show(ui: &mut egui::Ui, state: &mut ConnectState<'_>) -> ConnectAction {
     action
 }
 
-fn show_host(ui: &mut egui::Ui, state: &mut ConnectState<'_>) -> ConnectAction {
+fn show_incoming(ui: &mut egui::Ui, req: &IncomingRequest) -> ConnectAction {
     let mut action = ConnectAction::None;
 
-    ui.vertical_centered(|ui| {
-        if let Some(err) = state.prereq_error {
-            ui.colored_label(theme::ERROR, format!("Prerequisite: {err}"));
-            ui.add_space(theme::SPACING_MD);
-        }
-
-        if state.host_info.is_none() && *state.connection == ConnectionState::Idle {
-            let btn = egui::Button::new(
-                egui::RichText::new("Create Session")
-                    .size(theme::FONT_SIZE_BODY)
-                    .color(theme::ACCENT_FG),
-            )
-            .fill(theme::ACCENT)
-            .corner_radius(theme::ROUNDING);
-
-            if ui.add_enabled(state.prereq_error.is_none(), btn).clicked() {
-                action = ConnectAction::CreateSession;
-            }
-        }
-
-        if let Some(info) = state.host_info {
+    egui::Frame::NONE
+        .fill(theme::PANEL_BG)
+        .corner_radius(theme::ROUNDING)
+        .inner_margin(theme::SPACING_LG)
+        .stroke(egui::Stroke::new(1.0, theme::ACCENT))
+        .show(ui, |ui| {
+            ui.set_min_width(280.0);
             ui.label(
-                egui::RichText::new("Share these with your peer:")
+                egui::RichText::new(format!("{} wants to connect", req.device_name))
                     .size(theme::FONT_SIZE_BODY)
-                    .color(theme::TEXT_SECONDARY),
+                    .color(theme::TEXT_PRIMARY),
+            );
+            ui.label(
+                egui::RichText::new(&req.peer_code)
+                    .size(theme::FONT_SIZE_SMALL)
+                    .color(theme::TEXT_MUTED)
+                    .monospace(),
             );
             ui.add_space(theme::SPACING_MD);
-
-            egui::Frame::NONE
-                .fill(theme::PANEL_BG)
-                .corner_radius(theme::ROUNDING)
-                .inner_margin(theme::SPACING_LG)
-                .stroke(egui::Stroke::new(1.0, theme::ACCENT))
-                .show(ui, |ui| {
-                    ui.set_min_width(280.0);
-                    show_field(ui, "Room", &info.room);
-                    show_field(ui, "Session", &info.session);
-                    show_field(ui, "Code", &info.peer_code);
-                });
-
-            if matches!(state.connection, ConnectionState::Idle) {
-                ui.add_space(theme::SPACING_LG);
-                ui.label(
-                    egui::RichText::new("Enter joiner's peer code to start:")
-                        .size(theme::FONT_SIZE_BODY)
-                        .color(theme::TEXT_SECONDARY),
-                );
-                ui.add_space(theme::SPACING_SM);
-
-                let input = egui::TextEdit::singleline(state.join_peer_code)
-                    .hint_text("Joiner's code")
-                    .font(egui::TextStyle::Monospace)
-                    .desired_width(150.0)
-                    .char_limit(bolt_core::constants::PEER_CODE_LENGTH);
-                ui.add(input);
-
-                ui.add_space(theme::SPACING_MD);
-
-                let start_btn = egui::Button::new(
-                    egui::RichText::new("Start Listening")
-                        .size(theme::FONT_SIZE_BODY)
-                        .color(theme::ACCENT_FG),
-                )
-                .fill(theme::ACCENT)
-                .corner_radius(theme::ROUNDING);
-
-                let ready = state.join_peer_code.len() == bolt_core::constants::PEER_CODE_LENGTH;
-                if ui.add_enabled(ready, start_btn).clicked() {
-                    action = ConnectAction::StartHostWithJoiner(state.join_peer_code.clone());
+            ui.horizontal(|ui| {
+                if ui.add(primary_button("Accept")).clicked() {
+                    action = ConnectAction::Accept;
                 }
-            }
-        }
-
-        if state.connection.can_cancel() {
-            ui.add_space(theme::SPACING_MD);
-            let cancel = egui::Button::new(
-                egui::RichText::new("Cancel").size(theme::FONT_SIZE_BODY).color(theme::TEXT_PRIMARY),
-            )
-            .fill(theme::ERROR)
-            .corner_radius(theme::ROUNDING);
-            if ui.add(cancel).clicked() {
-                action = ConnectAction::Cancel;
-            }
-        }
-    });
+                if ui.add(danger_button("Decline")).clicked() {
+                    action = ConnectAction::Decline;
+                }
+            });
+        });
 
     action
 }
 
-fn show_join(ui: &mut egui::Ui, state: &mut ConnectState<'_>) -> ConnectAction {
+fn show_peers(ui: &mut egui::Ui, state: &ConnectState<'_>) -> ConnectAction {
     let mut action = ConnectAction::None;
 
-    ui.vertical_centered(|ui| {
-        ui.label(
-            egui::RichText::new("Your Peer Code")
-                .size(theme::FONT_SIZE_SMALL)
-                .color(theme::TEXT_SECONDARY),
-        );
-        ui.label(
-            egui::RichText::new(state.local_peer_code)
-                .size(theme::FONT_SIZE_TITLE)
-                .color(theme::ACCENT)
-                .monospace(),
-        );
-
-        ui.add_space(theme::SPACING_XL);
+    ui.label(
+        egui::RichText::new("Nearby devices")
+            .size(theme::FONT_SIZE_HEADING)
+            .color(theme::TEXT_PRIMARY),
+    );
+    ui.add_space(theme::SPACING_MD);
 
+    if state.peers.is_empty() {
         ui.label(
-            egui::RichText::new("Enter host's connection details:")
+            egui::RichText::new("No devices found — open LocalBolt on a computer on this network")
                 .size(theme::FONT_SIZE_BODY)
-                .color(theme::TEXT_SECONDARY),
+                .color(theme::TEXT_MUTED),
         );
-        ui.add_space(theme::SPACING_MD);
-
-        let is_connecting = matches!(state.connection, ConnectionState::Connecting { .. });
-
-        ui.horizontal(|ui| {
-            ui.label(egui::RichText::new("Room:").color(theme::TEXT_SECONDARY));
-            let r = egui::TextEdit::singleline(state.join_room)
-                .hint_text("e.g. r1a2b3")
-                .font(egui::TextStyle::Monospace)
-                .desired_width(150.0)
-                .interactive(!is_connecting);
-            ui.add(r);
-        });
-
-        ui.add_space(theme::SPACING_SM);
-
-        ui.horizontal(|ui| {
-            ui.label(egui::RichText::new("Session:").color(theme::TEXT_SECONDARY));
-            let s = egui::TextEdit::singleline(state.join_session)
-                .hint_text("e.g. s4d5e6f7")
-                .font(egui::TextStyle::Monospace)
-                .desired_width(150.0)
-                .interactive(!is_connecting);
-            ui.add(s);
-        });
-
-        ui.add_space(theme::SPACING_SM);
-
-        ui.horizontal(|ui| {
-            ui.label(egui::RichText::new("Code:").color(theme::TEXT_SECONDARY));
-            let c = egui::TextEdit::singleline(state.join_peer_code)
-                .hint_text("Host's code")
-                .font(egui::TextStyle::Monospace)
-                .desired_width(150.0)
-                .interactive(!is_connecting);
-            ui.add(c);
-        });
-
-        ui.add_space(theme::SPACING_LG);
-
-        if is_connecting {
-            let cancel = egui::Button::new(
-                egui::RichText::new("Cancel").size(theme::FONT_SIZE_BODY).color(theme::TEXT_PRIMARY),
-            )
-            .fill(theme::ERROR)
-            .corner_radius(theme::ROUNDING);
-            if ui.add(cancel).clicked() {
-                action = ConnectAction::Cancel;
-            }
-        } else {
-            let label = if state.connection.can_retry() && !matches!(state.connection, ConnectionState::Idle) {
-                "Retry"
-            } else {
-                "Join"
-            };
-            let join_btn = egui::Button::new(
-                egui::RichText::new(label).size(theme::FONT_SIZE_BODY).color(theme::ACCENT_FG),
-            )
-            .fill(theme::ACCENT)
-            .corner_radius(theme::ROUNDING);
-
-            let ready = !state.join_room.is_empty()
-                && !state.join_session.is_empty()
-                && !state.join_peer_code.is_empty()
-                && state.prereq_error.is_none();
+    }
 
-            if ui.add_enabled(ready, join_btn).clicked() {
-                action = ConnectAction::StartJoin {
-                    room: state.join_room.clone(),
-                    session: state.join_session.clone(),
-                    peer_code: state.join_peer_code.clone(),
-                };
-            }
-        }
-    });
+    for peer in state.peers {
+        egui::Frame::NONE
+            .fill(theme::PANEL_BG)
+            .corner_radius(theme::ROUNDING)
+            .inner_margin(theme::SPACING_MD)
+            .stroke(egui::Stroke::new(1.0, theme::BORDER))
+            .show(ui, |ui| {
+                ui.set_min_width(300.0);
+                ui.horizontal(|ui| {
+                    ui.label(
+                        egui::RichText::new(&peer.device_name)
+                            .size(theme::FONT_SIZE_BODY)
+                            .color(theme::TEXT_PRIMARY),
+                    );
+                    ui.label(
+                        egui::RichText::new(&peer.device_type)
+                            .size(theme::FONT_SIZE_SMALL)
+                            .color(theme::TEXT_MUTED),
+                    );
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        // browser↔browser is WebRTC only; we can only reach daemons.
+                        let reachable = state.signaling_connected && peer.device_type != "browser";
+                        if ui
+                            .add_enabled(reachable, primary_button("Connect"))
+                            .clicked()
+                        {
+                            action = ConnectAction::Request(peer.peer_code.clone());
+                        }
+                    });
+                });
+            });
+    }
 
     action
 }
 
-fn show_field(ui: &mut egui::Ui, label: &str, value: &str) {
-    ui.horizontal(|ui| {
-        ui.label(
-            egui::RichText::new(format!("{label}:"))
-                .size(theme::FONT_SIZE_BODY)
-                .color(theme::TEXT_SECONDARY),
-        );
-        ui.label(
-            egui::RichText::new(value)
-                .size(theme::FONT_SIZE_BODY)
-                .color(theme::ACCENT)
-                .monospace(),
-        );
-    });
+fn primary_button(label: &str) -> egui::Button<'static> {
+    egui::Button::new(
+        egui::RichText::new(label)
+            .size(theme::FONT_SIZE_BODY)
+            .color(theme::ACCENT_FG),
+    )
+    .fill(theme::ACCENT)
+    .corner_radius(theme::ROUNDING)
+}
+
+fn danger_button(label: &str) -> egui::Button<'static> {
+    egui::Button::new(
+        egui::RichText::new(label)
+            .size(theme::FONT_SIZE_BODY)
+            .color(theme::TEXT_PRIMARY),
+    )
+    .fill(theme::ERROR)
+    .corner_radius(theme::ROUNDING)
 }
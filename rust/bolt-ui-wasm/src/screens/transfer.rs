@@ -1,13 +1,21 @@
-// EW2 REUSE: Copied from bolt-ui/src/screens/transfer.rs.
-// Change: crate path only (crate::state, crate::theme).
-// Zero logic changes — this screen was already state-ref based.
+// Adapted from bolt-ui/src/screens/transfer.rs (crate paths).
+// Send File returns a TransferAction instead of calling into the app;
+// `blocked` says why sending is unavailable, if it is.
 
 use eframe::egui;
 
 use crate::state::TransferState;
 use crate::theme;
 
-pub fn show(ui: &mut egui::Ui, transfer: &TransferState) {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferAction {
+    None,
+    PickFile,
+}
+
+pub fn show(ui: &mut egui::Ui, transfer: &TransferState, blocked: Option<&str>) -> TransferAction {
+    let mut action = TransferAction::None;
+
     ui.add_space(theme::SPACING_XL);
 
     ui.vertical_centered(|ui| {
@@ -73,7 +81,10 @@ pub fn show(ui: &mut egui::Ui, transfer: &TransferState) {
                                 .color(theme::SUCCESS),
                         );
                     }
-                    TransferState::Sending { file_name, progress } => {
+                    TransferState::Sending {
+                        file_name,
+                        progress,
+                    } => {
                         ui.label(
                             egui::RichText::new(format!("\u{2191} {}", file_name))
                                 .size(theme::FONT_SIZE_BODY)
@@ -85,7 +96,10 @@ pub fn show(ui: &mut egui::Ui, transfer: &TransferState) {
                                 .color(theme::TEXT_SECONDARY),
                         );
                     }
-                    TransferState::Receiving { file_name, progress } => {
+                    TransferState::Receiving {
+                        file_name,
+                        progress,
+                    } => {
                         ui.label(
                             egui::RichText::new(format!("\u{2193} {}", file_name))
                                 .size(theme::FONT_SIZE_BODY)
@@ -129,15 +143,17 @@ pub fn show(ui: &mut egui::Ui, transfer: &TransferState) {
         .fill(theme::ACCENT)
         .corner_radius(theme::ROUNDING);
 
-        if ui.add_enabled(false, send_btn).clicked() {
-            // No transport in EW2 PoC
+        if ui.add_enabled(blocked.is_none(), send_btn).clicked() {
+            action = TransferAction::PickFile;
         }
 
         ui.add_space(theme::SPACING_SM);
         ui.label(
-            egui::RichText::new("Connect to a peer first")
+            egui::RichText::new(blocked.unwrap_or("or drop a file onto this window"))
                 .size(theme::FONT_SIZE_SMALL)
                 .color(theme::TEXT_MUTED),
         );
     });
+
+    action
 }
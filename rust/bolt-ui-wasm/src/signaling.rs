@@ -0,0 +1,245 @@
+//! Rendezvous signaling over the browser WebSocket API.
+//!
+//! Same wire as bolt-app-core's `signaling_client`, which needs threads
+//! and tungstenite and so can't run here: `register` on open, discovery
+//! events, relayed `signal`s and a keepalive ping. A dropped connection
+//! is retried after [`RECONNECT_DELAY`].
+
+use std::time::Duration;
+
+use eframe::egui;
+use serde_json::{json, Value};
+use web_time::Instant;
+
+use crate::socket::{Socket, SocketEvent};
+
+pub const DEVICE_TYPE: &str = "browser";
+/// Rendezvous port, as in bolt-app-core's `DEFAULT_RENDEZVOUS_ADDR`.
+pub const DEFAULT_PORT: u16 = 3001;
+pub const PING_INTERVAL: Duration = Duration::from_secs(30);
+pub const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// Peer info from the signaling server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PeerInfo {
+    pub peer_code: String,
+    pub device_name: String,
+    pub device_type: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SignalEvent {
+    Connected,
+    Disconnected(String),
+    PeerList(Vec<PeerInfo>),
+    PeerJoined(PeerInfo),
+    PeerLeft(String),
+    /// A relayed signal from another peer.
+    Signal {
+        from: String,
+        signal_type: String,
+        data: Value,
+    },
+    Error(String),
+}
+
+pub fn register_frame(peer_code: &str, device_name: &str) -> String {
+    json!({
+        "type": "register",
+        "peer_code": peer_code,
+        "device_name": device_name,
+        "device_type": DEVICE_TYPE,
+    })
+    .to_string()
+}
+
+pub fn signal_frame(to: &str, signal_type: &str, data: Value, from: &str) -> String {
+    json!({
+        "type": "signal",
+        "to": to,
+        "payload": { "type": signal_type, "data": data, "from": from, "to": to },
+    })
+    .to_string()
+}
+
+fn peer_info(v: &Value) -> Option<PeerInfo> {
+    Some(PeerInfo {
+        peer_code: v["peer_code"].as_str()?.to_string(),
+        device_name: v["device_name"].as_str()?.to_string(),
+        device_type: v["device_type"].as_str()?.to_string(),
+    })
+}
+
+/// Decode one server message; unknown or malformed messages are `None`.
+pub fn parse_server_message(text: &str) -> Option<SignalEvent> {
+    let msg: Value = serde_json::from_str(text).ok()?;
+    match msg["type"].as_str()? {
+        "peers" => Some(SignalEvent::PeerList(
+            msg["peers"]
+                .as_array()?
+                .iter()
+                .filter_map(peer_info)
+                .collect(),
+        )),
+        "peer_joined" => peer_info(&msg["peer"]).map(SignalEvent::PeerJoined),
+        "peer_left" => Some(SignalEvent::PeerLeft(
+            msg["peer_code"].as_str()?.to_string(),
+        )),
+        "signal" => {
+            let payload = &msg["payload"];
+            Some(SignalEvent::Signal {
+                from: msg["from"].as_str()?.to_string(),
+                signal_type: payload["type"].as_str().unwrap_or("unknown").to_string(),
+                data: payload["data"].clone(),
+            })
+        }
+        "error" => Some(SignalEvent::Error(
+            msg["message"]
+                .as_str()
+                .unwrap_or("unknown error")
+                .to_string(),
+        )),
+        _ => None,
+    }
+}
+
+pub struct Signaling {
+    url: String,
+    peer_code: String,
+    device_name: String,
+    socket: Option<Socket>,
+    connected: bool,
+    last_ping: Instant,
+    retry_at: Option<Instant>,
+}
+
+impl Signaling {
+    /// Not connected yet; the first [`poll`](Self::poll) connects.
+    pub fn new(url: &str, peer_code: &str, device_name: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            peer_code: peer_code.to_string(),
+            device_name: device_name.to_string(),
+            socket: None,
+            connected: false,
+            last_ping: Instant::now(),
+            retry_at: None,
+        }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.connected
+    }
+
+    /// Connect or reconnect as due, then drain socket events. Call every frame.
+    pub fn poll(&mut self, ctx: &egui::Context) -> Vec<SignalEvent> {
+        let mut events = Vec::new();
+
+        if self.socket.is_none() {
+            match self.retry_at {
+                Some(at) if at > Instant::now() => {
+                    ctx.request_repaint_after(at - Instant::now());
+                    return events;
+                }
+                _ => match Socket::connect(&self.url, ctx) {
+                    Ok(socket) => self.socket = Some(socket),
+                    Err(e) => {
+                        self.retry_at = Some(Instant::now() + RECONNECT_DELAY);
+                        events.push(SignalEvent::Disconnected(format!("connect: {e}")));
+                        return events;
+                    }
+                },
+            }
+        }
+
+        let Some(socket) = &mut self.socket else {
+            return events;
+        };
+        for event in socket.poll() {
+            match event {
+                SocketEvent::Open => {
+                    socket.send(&register_frame(&self.peer_code, &self.device_name));
+                    self.connected = true;
+                    self.last_ping = Instant::now();
+                    events.push(SignalEvent::Connected);
+                }
+                SocketEvent::Text(text) => events.extend(parse_server_message(&text)),
+                SocketEvent::Closed(reason) => {
+                    self.socket = None;
+                    self.connected = false;
+                    self.retry_at = Some(Instant::now() + RECONNECT_DELAY);
+                    events.push(SignalEvent::Disconnected(reason));
+                    return events;
+                }
+            }
+        }
+
+        if self.connected && self.last_ping.elapsed() >= PING_INTERVAL {
+            socket.send(&json!({ "type": "ping" }).to_string());
+            self.last_ping = Instant::now();
+        }
+        events
+    }
+
+    /// Relay a signal to `to`. Dropped if not connected; callers time out.
+    pub fn send_signal(&self, to: &str, signal_type: &str, data: Value) {
+        if let (true, Some(socket)) = (self.connected, &self.socket) {
+            socket.send(&signal_frame(to, signal_type, data, &self.peer_code));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signal_frame_matches_app_core_wire() {
+        let frame: Value = serde_json::from_str(&signal_frame(
+            "BBBBBB",
+            "connection_accepted",
+            json!({}),
+            "AAAAAA",
+        ))
+        .unwrap();
+        assert_eq!(frame["type"], "signal");
+        assert_eq!(frame["to"], "BBBBBB");
+        assert_eq!(frame["payload"]["type"], "connection_accepted");
+        assert_eq!(frame["payload"]["from"], "AAAAAA");
+    }
+
+    #[test]
+    fn parses_discovery_and_signals() {
+        let peers = r#"{"type":"peers","peers":[
+            {"peer_code":"ABCDEF","device_name":"Mac","device_type":"desktop"},
+            {"peer_code":"broken"}]}"#;
+        assert_eq!(
+            parse_server_message(peers),
+            Some(SignalEvent::PeerList(vec![PeerInfo {
+                peer_code: "ABCDEF".into(),
+                device_name: "Mac".into(),
+                device_type: "desktop".into(),
+            }]))
+        );
+        let signal = r#"{"type":"signal","from":"ABCDEF",
+            "payload":{"type":"connection_accepted","data":{"wsUrl":"ws://10.0.0.2:9100"}}}"#;
+        match parse_server_message(signal) {
+            Some(SignalEvent::Signal {
+                from,
+                signal_type,
+                data,
+            }) => {
+                assert_eq!(from, "ABCDEF");
+                assert_eq!(signal_type, "connection_accepted");
+                assert_eq!(data["wsUrl"], "ws://10.0.0.2:9100");
+            }
+            other => panic!("unexpected {other:?}"),
+        }
+        assert_eq!(parse_server_message(r#"{"type":"pong"}"#), None);
+        assert_eq!(parse_server_message("not json"), None);
+    }
+}
@@ -0,0 +1,131 @@
+//! Browser WebSocket wrapper shared by signaling and the WS-direct link.
+//!
+//! `web_sys::WebSocket` callbacks push into a queue that the egui frame
+//! loop drains with [`Socket::poll`], and request a repaint so events are
+//! handled without waiting for input. The closures live as long as the
+//! socket.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use eframe::egui;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{CloseEvent, Event, MessageEvent, WebSocket};
+
+use bolt_peer::Wire;
+use bolt_transfer_core::TransportQuery;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SocketEvent {
+    Open,
+    Text(String),
+    Closed(String),
+}
+
+pub struct Socket {
+    ws: WebSocket,
+    inbox: Rc<RefCell<Vec<SocketEvent>>>,
+    _on_open: Closure<dyn FnMut()>,
+    _on_message: Closure<dyn FnMut(MessageEvent)>,
+    _on_close: Closure<dyn FnMut(CloseEvent)>,
+    _on_error: Closure<dyn FnMut(Event)>,
+}
+
+impl Socket {
+    pub fn connect(url: &str, ctx: &egui::Context) -> Result<Self, String> {
+        let ws = WebSocket::new(url).map_err(|e| format!("{url}: {e:?}"))?;
+        let inbox = Rc::new(RefCell::new(Vec::new()));
+
+        let on_open = {
+            let (inbox, ctx) = (Rc::clone(&inbox), ctx.clone());
+            Closure::<dyn FnMut()>::new(move || {
+                inbox.borrow_mut().push(SocketEvent::Open);
+                ctx.request_repaint();
+            })
+        };
+        let on_message = {
+            let (inbox, ctx) = (Rc::clone(&inbox), ctx.clone());
+            Closure::<dyn FnMut(MessageEvent)>::new(move |e: MessageEvent| {
+                // Bolt frames are JSON text; binary frames are not part of
+                // the wire format and are dropped.
+                if let Some(text) = e.data().as_string() {
+                    inbox.borrow_mut().push(SocketEvent::Text(text));
+                    ctx.request_repaint();
+                }
+            })
+        };
+        let on_close = {
+            let (inbox, ctx) = (Rc::clone(&inbox), ctx.clone());
+            Closure::<dyn FnMut(CloseEvent)>::new(move |e: CloseEvent| {
+                let reason = if e.reason().is_empty() {
+                    format!("closed (code {})", e.code())
+                } else {
+                    e.reason()
+                };
+                inbox.borrow_mut().push(SocketEvent::Closed(reason));
+                ctx.request_repaint();
+            })
+        };
+        // An error is always followed by close, which carries the outcome.
+        let on_error = {
+            let ctx = ctx.clone();
+            Closure::<dyn FnMut(Event)>::new(move |_: Event| ctx.request_repaint())
+        };
+
+        ws.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+        ws.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+        ws.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+        ws.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+
+        Ok(Self {
+            ws,
+            inbox,
+            _on_open: on_open,
+            _on_message: on_message,
+            _on_close: on_close,
+            _on_error: on_error,
+        })
+    }
+
+    /// Take every event queued since the last poll.
+    pub fn poll(&mut self) -> Vec<SocketEvent> {
+        std::mem::take(&mut *self.inbox.borrow_mut())
+    }
+
+    pub fn send(&self, text: &str) {
+        // A failed send surfaces as a close event.
+        let _ = self.ws.send_with_str(text);
+    }
+}
+
+impl Drop for Socket {
+    fn drop(&mut self) {
+        // Detach handlers before the closures are freed.
+        self.ws.set_onopen(None);
+        self.ws.set_onmessage(None);
+        self.ws.set_onclose(None);
+        self.ws.set_onerror(None);
+        let _ = self.ws.close();
+    }
+}
+
+impl TransportQuery for Socket {
+    fn is_open(&self) -> bool {
+        self.ws.ready_state() == WebSocket::OPEN
+    }
+
+    fn buffered_bytes(&self) -> usize {
+        self.ws.buffered_amount() as usize
+    }
+
+    fn max_message_size(&self) -> usize {
+        usize::MAX
+    }
+}
+
+impl Wire for Socket {
+    fn send_text(&mut self, frame: &str) {
+        self.send(frame);
+    }
+}
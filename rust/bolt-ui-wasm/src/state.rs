@@ -1,35 +1,35 @@
-// EW2 REUSE: Adapted from bolt-ui/src/state.rs.
-// Change: std::time::Instant → web_time::Instant (WASM-compatible).
-// All enums, methods, and constants otherwise identical.
+// Adapted from bolt-ui/src/state.rs with std::time::Instant → web_time::Instant.
+// Connection states follow the browser flow instead of the daemon's:
+// a signaling request, then the WS-direct socket and HELLO.
 
 use std::time::Duration;
 use web_time::Instant;
 
 pub const CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum ConnectMode {
-    Host,
-    Join,
-}
-
+/// A `connection_request` from a desktop peer, waiting for Accept/Decline.
 #[derive(Debug, Clone)]
-pub struct HostInfo {
+pub struct IncomingRequest {
     pub peer_code: String,
-    pub room: String,
-    pub session: String,
-}
-
-impl HostInfo {
-    pub fn share_string(&self) -> String {
-        format!("{} / {} / {}", self.room, self.session, self.peer_code)
-    }
+    pub device_name: String,
+    /// The peer's ws-endpoint daemon; we connect to it on accept.
+    pub ws_url: String,
+    /// The peer's ephemeral key (`publicKey`); HELLO is sealed to it.
+    pub public_key: [u8; 32],
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ConnectionState {
     Idle,
-    Connecting { started_at: Instant },
+    /// `connection_request` sent; waiting for the peer's answer.
+    Requesting {
+        peer_code: String,
+        started_at: Instant,
+    },
+    /// WS-direct socket opening and HELLO.
+    Connecting {
+        started_at: Instant,
+    },
     Connected,
     TimedOut,
     Error(String),
@@ -39,6 +39,7 @@ impl ConnectionState {
     pub fn status_text(&self) -> &str {
         match self {
             Self::Idle => "Ready",
+            Self::Requesting { .. } => "Waiting for peer to accept\u{2026}",
             Self::Connecting { .. } => "Connecting\u{2026}",
             Self::Connected => "Connected",
             Self::TimedOut => "Connection timed out",
@@ -48,7 +49,9 @@ impl ConnectionState {
 
     pub fn is_timed_out(&self) -> bool {
         match self {
-            Self::Connecting { started_at } => started_at.elapsed() >= CONNECT_TIMEOUT,
+            Self::Requesting { started_at, .. } | Self::Connecting { started_at } => {
+                started_at.elapsed() >= CONNECT_TIMEOUT
+            }
             _ => false,
         }
     }
@@ -58,7 +61,7 @@ impl ConnectionState {
     }
 
     pub fn can_cancel(&self) -> bool {
-        matches!(self, Self::Connecting { .. })
+        matches!(self, Self::Requesting { .. } | Self::Connecting { .. })
     }
 }
 
@@ -77,10 +80,16 @@ impl TransferState {
         match self {
             Self::Idle => "No active transfer".to_string(),
             Self::Ready => "Connected — ready to transfer".to_string(),
-            Self::Sending { file_name, progress } => {
+            Self::Sending {
+                file_name,
+                progress,
+            } => {
                 format!("Sending {} ({:.0}%)", file_name, progress * 100.0)
             }
-            Self::Receiving { file_name, progress } => {
+            Self::Receiving {
+                file_name,
+                progress,
+            } => {
                 format!("Receiving {} ({:.0}%)", file_name, progress * 100.0)
             }
             Self::Complete { file_name } => format!("{} — complete", file_name),
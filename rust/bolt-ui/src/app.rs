@@ -1,23 +1,63 @@
 use std::sync::mpsc;
-use std::time::Instant;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use eframe::egui;
 
+use bolt_app_core::contracts::session_contract::SessionPhase;
+use bolt_app_core::daemon_events::DaemonEvent;
+use bolt_app_core::ipc_transport::IpcStream;
+use bolt_app_core::ipc_types::{
+    Decision, Endpoint, HelloMode, PairingDecisionPayload, TransferCommand,
+    TransferCompletePayload, TransferControlPayload, TransferDirection, TransferSendPayload,
+};
+use bolt_app_core::peer_sessions::{PeerSessions, SessionId, DEFAULT_MAX_SESSIONS};
+use bolt_app_core::platform;
+use bolt_app_core::settings::{Settings, SettingsChange, SettingsError, SettingsStore};
 use bolt_app_core::signaling_client::{self, DiscoveryEvent, Plane, SignalingConfig};
+use bolt_app_core::transfer_history::{TransferHistory, TransferResult};
+use bolt_app_core::transfer_queue::{ActiveTransfer, Batch, TransferQueue};
+use bolt_app_core::trusted_devices::PinStore;
 
 use crate::daemon::{self, DaemonProcess};
 use crate::ipc::IpcClient;
 use crate::screens;
+use crate::session::{self, DaemonTransport, PendingAction, PendingWsSignal};
 use crate::state::*;
 use crate::theme;
 
-/// Rendezvous server address. Override with BOLT_RENDEZVOUS_URL env var.
-/// Default: 127.0.0.1:3001 (embedded signal server started by app).
-fn rendezvous_addr() -> String {
-    if let Ok(url) = std::env::var("BOLT_RENDEZVOUS_URL") {
-        return url;
-    }
-    "127.0.0.1:3001".to_string()
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Host name, used as the device name unless one is set in settings.
+fn host_device_name() -> String {
+    hostname::get()
+        .map(|h| h.to_string_lossy().to_string())
+        .unwrap_or_else(|_| "Desktop".to_string())
+}
+
+/// Start signaling (local + optional cloud) with the current settings.
+fn spawn_signaling(
+    settings: &Settings,
+    peer_code: &str,
+) -> (mpsc::Receiver<DiscoveryEvent>, signaling_client::SignalingHandle) {
+    let (tx, rx) = mpsc::channel();
+    let handle = signaling_client::spawn_signaling_client(
+        SignalingConfig {
+            server_url: settings.effective_rendezvous_addr(),
+            cloud_url: settings.effective_cloud_signal_url(),
+            peer_code: peer_code.to_string(),
+            device_name: settings.device_name_or(host_device_name),
+            device_type: "desktop".to_string(),
+        },
+        Box::new(move |event| {
+            let _ = tx.send(event);
+        }),
+    );
+    (rx, handle)
 }
 
 /// Get the local LAN IP address for the browser to reach us.
@@ -33,11 +73,11 @@ fn local_ip() -> String {
     "127.0.0.1".to_string()
 }
 
-/// Signal pending until daemon WS endpoint is ready.
-struct PendingWsSignal {
-    peer_code: String,
-    signal_type: String, // "connection_request" or "connection_accepted"
-}
+/// Minimum spacing between IPC connection attempts while the daemon starts.
+const IPC_RETRY_INTERVAL: Duration = Duration::from_millis(250);
+
+/// How long a transfer action may wait for the daemon's ack.
+const ACK_TIMEOUT: Duration = Duration::from_secs(10);
 
 fn parse_device_type(s: &str) -> DeviceType {
     match s {
@@ -53,13 +93,38 @@ pub struct BoltApp {
     // ── Discovery state (primary) ────────────────────────────
     pub discovery: DiscoveryStatus,
     pub discovered_peers: Vec<DiscoveredPeer>,
-    pub connected_peer: Option<ConnectedPeer>,
 
-    // ── Connection lifecycle ─────────────────────────────────
-    pub connection: ConnectionState,
+    // ── Sessions ─────────────────────────────────────────────
+    /// One session (and daemon) per connected or connecting peer.
+    pub sessions: PeerSessions<DaemonTransport>,
+    /// Files queued while no session is selected; the next session
+    /// opened takes them.
+    pub staged: TransferQueue,
+    /// Why the last session could not be opened.
+    pub session_notice: Option<String>,
     pub incoming_request: Option<IncomingRequest>,
-    pub transfer: TransferState,
-    pub verify: VerifyState,
+    /// Finished transfers, persisted across restarts.
+    pub history: TransferHistory,
+    /// Last failure to load or write the history file.
+    pub history_error: Option<String>,
+    pub show_transfers: bool,
+    /// Persisted settings (see `bolt_app_core::settings`).
+    pub settings: SettingsStore,
+    /// Settings file that could not be loaded; defaults are in use and
+    /// the store is read-only.
+    pub settings_error: Option<String>,
+    settings_rx: mpsc::Receiver<SettingsChange>,
+    pub show_settings: bool,
+    /// Identity pins of peers seen in identity sessions (app-local; the
+    /// daemon keeps its own TOFU store).
+    pub trust: PinStore,
+    /// Last failure to load or write the pin store.
+    pub trust_error: Option<String>,
+    pub show_devices: bool,
+    /// Trusted device being renamed: (identity key, name being typed).
+    pub device_rename: Option<(String, String)>,
+    /// Settings form state while the panel is open.
+    pub settings_draft: Option<screens::settings::Draft>,
 
     // ── Manual pairing fallback (secondary) ──────────────────
     pub show_manual_pair: bool,
@@ -71,26 +136,12 @@ pub struct BoltApp {
 
     // ── Runtime ──────────────────────────────────────────────
     pub local_peer_code: String,
-    pub daemon_proc: Option<DaemonProcess>,
-    pub ipc_client: Option<IpcClient>,
     pub prereq_error: Option<String>,
     pub signal_healthy: bool,
     /// Track which signaling planes are currently connected.
     local_plane_connected: bool,
     cloud_plane_connected: bool,
     daemon_bin: Option<std::path::PathBuf>,
-    data_dir: String,
-    socket_path: String,
-    #[allow(dead_code)]
-    cloud_signal_url: Option<String>,
-    /// Port the daemon WS endpoint listens on (for direct browser connections).
-    daemon_ws_port: u16,
-    /// WT endpoint cert hash (captured from daemon stderr, SECURE-DIRECT-1).
-    wt_cert_hash: Option<String>,
-    /// Whether the WT endpoint has confirmed it is listening.
-    wt_endpoint_ready: bool,
-    /// Signal waiting to be sent once daemon WS endpoint is ready.
-    pending_ws_signal: Option<PendingWsSignal>,
 
     // ── Signaling ────────────────────────────────────────────
     signaling_rx: mpsc::Receiver<DiscoveryEvent>,
@@ -103,10 +154,11 @@ impl BoltApp {
 
         let local_peer_code = bolt_core::peer_code::generate_secure_peer_code();
         let pid = std::process::id();
-        let data_dir = format!("/tmp/bolt-ui-{pid}");
-        let socket_path = format!("/tmp/bolt-ui-{pid}.sock");
-        // Per-instance WS port for direct browser connections (9100 + pid hash to avoid collisions)
-        let daemon_ws_port = 9100 + (pid % 900) as u16;
+        // Per-session sockets and data dirs live under the runtime dir.
+        let runtime_dir = format!("/tmp/bolt-ui-{pid}");
+        // Per-instance WS port base for direct browser connections (9100 + pid
+        // hash to avoid collisions); each session takes the next WS/WT pair.
+        let base_port = 9100 + (pid % 900) as u16;
 
         let (daemon_bin, prereq_error) = match daemon::find_daemon_binary() {
             Ok(path) => (Some(path), None),
@@ -115,30 +167,36 @@ impl BoltApp {
 
         let signal_healthy = bolt_app_core::signal_monitor::probe_signal_health();
 
+        let history_path = platform::transfer_history_path();
+        let (history, history_error) = match TransferHistory::load(&history_path) {
+            Ok(history) => (history, None),
+            Err(e) => {
+                tracing::warn!("[UI] transfer history unreadable: {e}");
+                (TransferHistory::empty(history_path), Some(e.to_string()))
+            }
+        };
+
+        let settings_path = platform::settings_path();
+        let (mut settings, settings_error) = match SettingsStore::load(&settings_path) {
+            Ok(store) => (store, None),
+            Err(e) => {
+                tracing::warn!("[UI] settings unreadable, using defaults read-only: {e}");
+                (SettingsStore::read_only(settings_path, e.clone()), Some(e))
+            }
+        };
+        let settings_rx = settings.subscribe();
+
+        let trust_path = platform::trusted_devices_path();
+        let (trust, trust_error) = match PinStore::load(&trust_path) {
+            Ok(store) => (store, None),
+            Err(e) => {
+                tracing::warn!("[UI] pin store unreadable, not saving pins: {e}");
+                (PinStore::read_only(trust_path, e.clone()), Some(e))
+            }
+        };
+
         // Spawn real signaling client for peer discovery.
-        let (tx, rx) = mpsc::channel();
-        let device_name = hostname::get()
-            .map(|h| h.to_string_lossy().to_string())
-            .unwrap_or_else(|_| "Desktop".to_string());
-        // Cloud signaling URL — matches website's VITE_SIGNAL_URL.
-        // Override with BOLT_CLOUD_SIGNAL_URL env var.
-        let cloud_url = std::env::var("BOLT_CLOUD_SIGNAL_URL")
-            .ok()
-            .or_else(|| Some("wss://bolt-rendezvous.fly.dev".to_string()));
-
-        let cloud_url_for_storage = cloud_url.clone();
-        let signaling_handle = signaling_client::spawn_signaling_client(
-            SignalingConfig {
-                server_url: rendezvous_addr(),
-                cloud_url,
-                peer_code: local_peer_code.clone(),
-                device_name,
-                device_type: "desktop".to_string(),
-            },
-            Box::new(move |event| {
-                let _ = tx.send(event);
-            }),
-        );
+        let (rx, signaling_handle) = spawn_signaling(settings.current(), &local_peer_code);
 
         Self {
             discovery: if signal_healthy {
@@ -147,11 +205,22 @@ impl BoltApp {
                 DiscoveryStatus::Offline
             },
             discovered_peers: Vec::new(),
-            connected_peer: None,
-            connection: ConnectionState::Idle,
+            sessions: PeerSessions::new(runtime_dir, base_port, DEFAULT_MAX_SESSIONS),
+            staged: TransferQueue::new(),
+            session_notice: None,
             incoming_request: None,
-            transfer: TransferState::Idle,
-            verify: VerifyState::NotStarted,
+            history,
+            history_error,
+            show_transfers: false,
+            settings,
+            settings_error,
+            settings_rx,
+            show_settings: false,
+            trust,
+            trust_error,
+            show_devices: false,
+            device_rename: None,
+            settings_draft: None,
             show_manual_pair: false,
             mode: ConnectMode::Host,
             host_info: None,
@@ -159,111 +228,569 @@ impl BoltApp {
             join_session: String::new(),
             join_peer_code: String::new(),
             local_peer_code,
-            daemon_proc: None,
-            ipc_client: None,
             prereq_error,
             signal_healthy,
             local_plane_connected: false,
             cloud_plane_connected: false,
             daemon_bin,
-            data_dir,
-            socket_path,
-            cloud_signal_url: cloud_url_for_storage,
-            daemon_ws_port,
-            wt_cert_hash: None,
-            wt_endpoint_ready: false,
-            pending_ws_signal: None,
             signaling_rx: rx,
             signaling_handle,
         }
     }
 
-    /// Get the daemon's data directory path (used for signal files).
-    pub fn data_dir(&self) -> &str {
-        &self.data_dir
+    // ── Transfer actions ─────────────────────────────────────
+
+    /// Queue of the selected session, or the staging queue when none is.
+    pub fn queue(&self) -> &TransferQueue {
+        match self.sessions.selected() {
+            Some(session) => &session.queue,
+            None => &self.staged,
+        }
+    }
+
+    pub fn queue_mut(&mut self) -> &mut TransferQueue {
+        match self.sessions.selected_mut() {
+            Some(session) => &mut session.queue,
+            None => &mut self.staged,
+        }
     }
 
-    // ── Discovery actions ────────────────────────────────────
+    /// Queue `path` for sending; it starts once nothing else is in flight.
+    pub fn enqueue_file(&mut self, path: &std::path::Path) {
+        let id = self.queue_mut().enqueue(path);
+        tracing::info!("[UI] queued {} (item {id})", path.display());
+    }
 
-    /// Send a connection request to a discovered peer.
-    /// Spawns daemon WS server first, includes wsUrl in the request.
-    pub fn connect_to_peer(&mut self, peer: &DiscoveredPeer) {
-        if self.connection != ConnectionState::Idle {
-            return;
+    /// Queue a picker selection or drop (files and folders) as one batch.
+    pub fn enqueue_paths(&mut self, paths: &[std::path::PathBuf]) {
+        let queue = self.queue_mut();
+        match queue.enqueue_batch(paths) {
+            Some(id) => {
+                let batch = queue.batch(id).expect("just enqueued");
+                tracing::info!(
+                    "[UI] queued batch {id}: {} ({} files)",
+                    batch.label,
+                    batch.total_files
+                );
+            }
+            None => tracing::warn!("[UI] selection holds no files: {paths:?}"),
         }
+    }
 
-        // Spawn daemon WS server (non-blocking)
-        self.spawn_daemon_ws_server();
+    /// The selected session's multi-file batch in flight and its combined
+    /// progress.
+    pub fn batch_progress(&self) -> Option<(&Batch, f32)> {
+        let session = self.sessions.selected()?;
+        let batch = session.queue.current_batch().filter(|b| b.total_files > 1)?;
+        let file_progress = match &session.transport.transfer {
+            TransferState::Sending { progress, .. } | TransferState::Receiving { progress, .. } => {
+                *progress as f64
+            }
+            _ => 0.0,
+        };
+        let active = session.queue.active().map(|a| (a, file_progress));
+        Some((batch, batch.progress(active) as f32))
+    }
 
-        // Store target peer info — signal will be sent from poll_daemon once WS is ready
-        self.connection = ConnectionState::Requesting {
-            peer_code: peer.peer_code.clone(),
-            peer_name: peer.device_name.clone(),
-            started_at: Instant::now(),
+    /// Start the next queued send in every session that allows a transfer
+    /// and has nothing else in flight.
+    fn pump_queues(&mut self) {
+        for id in self.sessions.ids() {
+            self.pump_queue(id);
+        }
+    }
+
+    fn pump_queue(&mut self, id: SessionId) {
+        let Some(session) = self.sessions.get_mut(id) else {
+            return;
         };
-        self.pending_ws_signal = Some(PendingWsSignal {
-            peer_code: peer.peer_code.clone(),
-            signal_type: "connection_request".into(),
+        if !session.transfer_allowed() || session.transport.pending_action.is_some() {
+            return;
+        }
+        let Some(active) = session.queue.start_next_send() else {
+            return;
+        };
+        let file_name = active.file_name.clone();
+        let path = active.path.as_ref().map(|p| p.display().to_string());
+        let command = TransferCommand::Send(TransferSendPayload {
+            path: path.unwrap_or_default(),
+            file_name: Some(file_name.clone()),
         });
+        session.transport.transfer = TransferState::Sending {
+            file_name: file_name.clone(),
+            progress: 0.0,
+        };
+        session.transport.active_transfer_id = None;
+        session.transport.paused = false;
+        self.dispatch_action(id, TransferAction::Send { file_name }, command);
+    }
 
-        tracing::info!("[UI] daemon spawning, will send connection_request to {} when WS ready", peer.peer_code);
+    /// End the session's active transfer (if any) and record it in the
+    /// history.
+    fn finish_active(
+        &mut self,
+        id: SessionId,
+        result: TransferResult,
+        error: Option<String>,
+        complete: Option<&TransferCompletePayload>,
+    ) {
+        let Some(session) = self.sessions.get_mut(id) else {
+            return;
+        };
+        if let Some(active) = session.queue.finish(result) {
+            let peer = session.peer_name.clone();
+            self.record_history(&peer, active, result, error, complete);
+        }
     }
 
-    /// Wait for the daemon WS endpoint to be listening (max 5s).
-    /// Blocking — only used in manual pair fallback, not the primary path.
-    #[allow(dead_code)]
-    fn wait_for_daemon_ws_ready(&self) -> bool {
-        let deadline = Instant::now() + std::time::Duration::from_secs(5);
-        while Instant::now() < deadline {
-            if let Some(ref proc) = self.daemon_proc {
-                if proc.recent_stderr(30).iter().any(|l| l.contains("[WS_ENDPOINT] listening")) {
-                    return true;
+    fn record_history(
+        &mut self,
+        peer: &str,
+        active: ActiveTransfer,
+        result: TransferResult,
+        error: Option<String>,
+        complete: Option<&TransferCompletePayload>,
+    ) {
+        let mut entry = active.into_history(peer, result, error);
+        if let Some(c) = complete {
+            entry.file_hash_hex = c.file_hash_hex.clone();
+            entry.size_bytes = c.file_size_bytes.or(entry.size_bytes);
+            if c.save_path.is_some() {
+                entry.path = c.save_path.clone();
+            }
+        }
+        match self.history.record(entry) {
+            Ok(()) => self.history_error = None,
+            Err(e) => {
+                tracing::warn!("[UI] transfer history write failed: {e}");
+                self.history_error = Some(e.to_string());
+            }
+        }
+    }
+
+    /// Delete the persisted transfer history.
+    pub fn clear_history(&mut self) {
+        self.history_error = self.history.clear().err().map(|e| e.to_string());
+    }
+
+    // ── Trusted devices ──────────────────────────────────────
+
+    /// Pin the session peer's identity key (or refresh its pin) and set the
+    /// session's verification from it. Returns whether the pin is already
+    /// SAS-verified.
+    fn observe_identity(&mut self, id: SessionId, identity_pk_b64: Option<String>) -> bool {
+        let Some(session) = self.sessions.get(id) else {
+            return false;
+        };
+        let key = identity_pk_b64.or_else(|| session.identity_pk_b64().map(String::from));
+        let verified = match &key {
+            Some(key) => match self.trust.observe(key, &session.peer_name, now_ms()) {
+                Ok(device) => device.is_verified(),
+                Err(e) => {
+                    tracing::warn!("[UI] pin store write failed: {e}");
+                    self.trust_error = Some(e);
+                    false
                 }
+            },
+            None => false,
+        };
+        if let Some(session) = self.sessions.get_mut(id) {
+            session.on_identity(key, verified);
+        }
+        verified
+    }
+
+    /// The user confirmed the SAS: allow transfers and remember the peer.
+    pub fn mark_session_verified(&mut self, id: SessionId) {
+        let Some(session) = self.sessions.get_mut(id) else {
+            return;
+        };
+        tracing::info!("[UI] user marked {} as verified", session.peer_code);
+        session.mark_verified();
+        session.transport.transfer = TransferState::Ready;
+        if let Some(key) = session.identity_pk_b64().map(String::from) {
+            self.record_trust(|trust| trust.mark_verified(&key, now_ms()));
+        }
+    }
+
+    /// Answer the daemon's `pairing.request` (`--pairing-policy ask`).
+    pub fn answer_pairing(&mut self, id: SessionId, allow: bool) {
+        let Some(session) = self.sessions.get_mut(id) else {
+            return;
+        };
+        let Some(request) = session.transport.pairing_request.take() else {
+            return;
+        };
+        let decision = PairingDecisionPayload {
+            request_id: request.request_id,
+            decision: if allow { Decision::AllowOnce } else { Decision::DenyOnce },
+            note: None,
+        };
+        tracing::info!("[UI] pairing {:?} for {}", decision.decision, request.remote_device_name);
+        let sent = match &session.transport.ipc_client {
+            Some(client) => client.send_command(
+                "pairing.decision",
+                serde_json::to_value(decision).unwrap_or_default(),
+            ),
+            None => Err("daemon IPC not connected".to_string()),
+        };
+        if let Err(e) = sent {
+            self.fail_session(id, format!("pairing answer not sent: {e}"));
+        }
+    }
+
+    /// The user rejected the SAS: a rejected peer must not stay connected.
+    pub fn reject_verification(&mut self, id: SessionId) {
+        tracing::info!("[UI] user rejected peer verification");
+        self.close_session(id, "verification rejected");
+    }
+
+    /// Apply a pin store edit, keeping the last failure for display.
+    pub fn record_trust(&mut self, edit: impl FnOnce(&mut PinStore) -> Result<(), String>) {
+        match edit(&mut self.trust) {
+            Ok(()) => self.trust_error = None,
+            Err(e) => {
+                tracing::warn!("[UI] pin store update failed: {e}");
+                self.trust_error = Some(e);
             }
-            std::thread::sleep(std::time::Duration::from_millis(100));
         }
-        false
     }
 
-    /// Kill the current daemon and reset WT cert state so the next daemon's
-    /// cert hash will be captured fresh (SECURE-DIRECT-1 cert lifecycle fix).
-    fn kill_daemon(&mut self) {
-        if let Some(mut proc) = self.daemon_proc.take() {
-            proc.kill();
+    /// Whether a connected session's peer presented `identity_pk_b64`.
+    pub fn is_identity_connected(&self, identity_pk_b64: &str) -> bool {
+        self.sessions.iter().any(|s| {
+            s.phase() == SessionPhase::Connected && s.identity_pk_b64() == Some(identity_pk_b64)
+        })
+    }
+
+    // ── Settings ─────────────────────────────────────────────
+
+    /// Validate and persist `settings`; changes are applied on the next frame.
+    pub fn save_settings(&mut self, settings: Settings) -> Result<(), SettingsError> {
+        self.settings.update(settings)
+    }
+
+    fn rendezvous_addr(&self) -> String {
+        self.settings.current().effective_rendezvous_addr()
+    }
+
+    fn device_name(&self) -> String {
+        self.settings.current().device_name_or(host_device_name)
+    }
+
+    /// Apply saved settings. Signaling re-registers at once; daemon flags
+    /// take effect when the next daemon is spawned.
+    fn apply_settings_changes(&mut self) {
+        while let Ok(change) = self.settings_rx.try_recv() {
+            if change.signaling_changed() {
+                tracing::info!("[UI] signaling settings changed — reconnecting");
+                self.signaling_handle.shutdown();
+                let (rx, handle) = spawn_signaling(&change.current, &self.local_peer_code);
+                self.signaling_rx = rx;
+                self.signaling_handle = handle;
+                self.local_plane_connected = false;
+                self.cloud_plane_connected = false;
+                self.discovered_peers.clear();
+                self.discovery = DiscoveryStatus::Searching;
+            }
+            if change.daemon_args_changed() {
+                tracing::info!(
+                    "[UI] daemon settings changed — applies to the next connection ({:?})",
+                    change.current.daemon_args()
+                );
+            }
         }
-        self.wt_cert_hash = None;
-        self.wt_endpoint_ready = false;
     }
 
-    /// Spawn daemon as a direct WS endpoint server (for browser connections).
-    fn spawn_daemon_ws_server(&mut self) {
-        // Kill any existing daemon first
-        self.kill_daemon();
+    /// Ask the session's daemon to cancel its active transfer.
+    pub fn request_cancel(&mut self, id: SessionId) {
+        let Some(transfer_id) = self
+            .sessions
+            .get(id)
+            .and_then(|s| s.transport.active_transfer_id.clone())
+        else {
+            return;
+        };
+        let command = TransferCommand::Cancel(TransferControlPayload { transfer_id });
+        self.dispatch_action(id, TransferAction::Cancel, command);
+    }
 
-        let daemon_bin = match &self.daemon_bin {
-            Some(b) => b.clone(),
-            None => {
-                self.connection = ConnectionState::Error("Daemon not found".into());
-                return;
+    /// Ask the session's daemon to pause its active transfer, or to resume
+    /// it when paused.
+    pub fn request_pause_toggle(&mut self, id: SessionId) {
+        let Some(session) = self.sessions.get(id) else {
+            return;
+        };
+        let Some(transfer_id) = session.transport.active_transfer_id.clone() else {
+            return;
+        };
+        let control = TransferControlPayload { transfer_id };
+        let (action, command) = if session.transport.paused {
+            (TransferAction::Resume, TransferCommand::Resume(control))
+        } else {
+            (TransferAction::Pause, TransferCommand::Pause(control))
+        };
+        self.dispatch_action(id, action, command);
+    }
+
+    /// The session's active transfer is paused.
+    pub fn is_transfer_paused(&self, id: SessionId) -> bool {
+        self.sessions.get(id).is_some_and(|s| s.transport.paused)
+    }
+
+    /// A cancel, pause or resume can be sent: the daemon named the
+    /// transfer and no other action is in flight.
+    pub fn can_cancel_transfer(&self, id: SessionId) -> bool {
+        self.sessions.get(id).is_some_and(|s| {
+            s.transport.active_transfer_id.is_some() && s.transport.pending_action.is_none()
+        })
+    }
+
+    /// Clear a finished or failed transfer so the session can send again.
+    pub fn reset_transfer(&mut self, id: SessionId) {
+        if let Some(session) = self.sessions.get_mut(id) {
+            session.transport.transfer = TransferState::Ready;
+        }
+    }
+
+    fn dispatch_action(&mut self, id: SessionId, action: TransferAction, command: TransferCommand) {
+        let Some(session) = self.sessions.get_mut(id) else {
+            return;
+        };
+        let transport = &mut session.transport;
+        transport.transfer_notice = None;
+        let sent = match &transport.ipc_client {
+            Some(client) => client.send_transfer_command(&command),
+            None => Err("daemon IPC not connected".to_string()),
+        };
+        match sent {
+            Ok(handle) => {
+                tracing::info!("[UI] {} sent ({:?})", command.msg_type(), handle.id());
+                transport.pending_action = Some(PendingAction {
+                    action,
+                    handle,
+                    sent_at: Instant::now(),
+                });
+            }
+            Err(e) => self.on_action_result(id, action, Err(e)),
+        }
+    }
+
+    /// Resolve in-flight actions from their acks. An action left unanswered
+    /// past [`ACK_TIMEOUT`] is dropped as unconfirmed: the daemon may still
+    /// have carried it out, so it is not treated as failed.
+    fn poll_pending_actions(&mut self) {
+        for id in self.sessions.ids() {
+            let Some(session) = self.sessions.get_mut(id) else {
+                continue;
+            };
+            let Some(pending) = &session.transport.pending_action else {
+                continue;
+            };
+            let result = pending.handle.try_result();
+            if result.is_none() && pending.sent_at.elapsed() < ACK_TIMEOUT {
+                continue;
+            }
+            let pending = session.transport.pending_action.take().expect("checked above");
+            match result {
+                Some(result) => {
+                    self.on_action_result(id, pending.action, result.map(|ack| ack.transfer_id))
+                }
+                None => {
+                    if let Some(client) = &session.transport.ipc_client {
+                        client.forget_command(pending.handle.id());
+                    }
+                    tracing::warn!("[UI] {} unconfirmed: no ack", pending.action.label());
+                    session.transport.transfer_notice = Some(format!(
+                        "{} unconfirmed — the daemon did not acknowledge it",
+                        pending.action.label()
+                    ));
+                }
+            }
+        }
+    }
+
+    fn on_action_result(
+        &mut self,
+        id: SessionId,
+        action: TransferAction,
+        result: Result<Option<String>, String>,
+    ) {
+        match (action, result) {
+            (TransferAction::Send { .. }, Ok(transfer_id)) => {
+                tracing::info!("[UI] send accepted (transfer {transfer_id:?})");
+                if let Some(session) = self.sessions.get_mut(id) {
+                    if let Some(active) = session.queue.active_mut() {
+                        active.transfer_id.clone_from(&transfer_id);
+                    }
+                    session.transport.active_transfer_id = transfer_id;
+                }
+            }
+            (TransferAction::Send { file_name }, Err(reason)) => {
+                tracing::error!("[UI] send rejected: {reason}");
+                self.finish_active(id, TransferResult::Failed, Some(reason.clone()), None);
+                // The session may have ended meanwhile; don't resurrect it.
+                if let Some(session) = self.sessions.get_mut(id) {
+                    if matches!(session.transport.transfer, TransferState::Sending { .. }) {
+                        session.transport.transfer = TransferState::Failed { file_name, reason };
+                    }
+                }
+            }
+            (TransferAction::Cancel, Ok(_)) => {
+                tracing::info!("[UI] transfer cancelled");
+                self.finish_active(id, TransferResult::Cancelled, None, None);
+                if let Some(session) = self.sessions.get_mut(id) {
+                    session.transport.active_transfer_id = None;
+                    session.transport.paused = false;
+                    session.transport.transfer = TransferState::Ready;
+                }
             }
+            (action @ (TransferAction::Pause | TransferAction::Resume), Ok(_)) => {
+                tracing::info!("[UI] transfer {}d", action.label().to_lowercase());
+                if let Some(session) = self.sessions.get_mut(id) {
+                    session.transport.paused = action == TransferAction::Pause;
+                }
+            }
+            (action, Err(reason)) => {
+                tracing::warn!("[UI] {} rejected: {reason}", action.label().to_lowercase());
+                if let Some(session) = self.sessions.get_mut(id) {
+                    session.transport.transfer_notice =
+                        Some(format!("{} failed: {reason}", action.label()));
+                }
+            }
+        }
+    }
+
+    // ── Sessions ─────────────────────────────────────────────
+
+    /// Open a session with a peer, hand it any staged files and show it.
+    /// On failure the reason is kept in `session_notice`.
+    fn open_session(
+        &mut self,
+        peer_code: &str,
+        peer_name: &str,
+        device_type: DeviceType,
+        phase: SessionPhase,
+        connection: ConnectionState,
+    ) -> Option<SessionId> {
+        let transport = DaemonTransport::new(device_type, connection);
+        let id = match self.sessions.open(peer_code, peer_name, phase, transport) {
+            Ok(id) => id,
+            Err(e) => {
+                tracing::warn!("[UI] cannot open session: {e}");
+                self.session_notice = Some(e);
+                return None;
+            }
+        };
+        self.session_notice = None;
+        if let Some(session) = self.sessions.get_mut(id) {
+            session.queue = std::mem::take(&mut self.staged);
+        }
+        self.sessions.select(Some(id));
+        Some(id)
+    }
+
+    /// Mark a session failed and stop its daemon. The session stays listed
+    /// with its error until the user closes it.
+    fn fail_session(&mut self, id: SessionId, reason: String) {
+        self.finish_active(id, TransferResult::Failed, Some(reason.clone()), None);
+        if let Some(session) = self.sessions.get_mut(id) {
+            tracing::warn!("[UI] session with {} failed: {reason}", session.peer_code);
+            session.transport.kill_daemon();
+            session.transport.transfer = TransferState::Idle;
+            session.transport.active_transfer_id = None;
+            session.transport.paused = false;
+            session.transport.pending_ws_signal = None;
+            session::set_connection(session, ConnectionState::Error(reason));
+        }
+    }
+
+    /// End a session: fail its active transfer, stop its daemon and drop it.
+    pub fn close_session(&mut self, id: SessionId, reason: &str) {
+        self.finish_active(id, TransferResult::Failed, Some(reason.to_string()), None);
+        if let Some(mut session) = self.sessions.close(id) {
+            session.transport.kill_daemon();
+            let _ = std::fs::remove_file(&session.resources.socket_path);
+        }
+    }
+
+    /// Kill a session's daemon (see [`DaemonTransport::kill_daemon`]).
+    pub fn kill_daemon(&mut self, id: SessionId) {
+        if let Some(session) = self.sessions.get_mut(id) {
+            session.transport.kill_daemon();
+        }
+    }
+
+    // ── Discovery actions ────────────────────────────────────
+
+    /// Send a connection request to a discovered peer, or show the
+    /// existing session with it. Spawns the session's daemon WS server
+    /// first; the request goes out with its wsUrl once endpoints are ready.
+    pub fn connect_to_peer(&mut self, peer: &DiscoveredPeer) {
+        if let Some(id) = self.sessions.find_peer(&peer.peer_code) {
+            self.sessions.select(Some(id));
+            return;
+        }
+        let connection = ConnectionState::Requesting {
+            peer_code: peer.peer_code.clone(),
+            peer_name: peer.device_name.clone(),
+            started_at: Instant::now(),
+        };
+        let Some(id) = self.open_session(
+            &peer.peer_code,
+            &peer.device_name,
+            peer.device_type,
+            SessionPhase::Requesting,
+            connection,
+        ) else {
+            return;
+        };
+
+        // Spawn daemon WS server (non-blocking)
+        self.spawn_daemon_ws_server(id);
+
+        // Signal will be sent from poll_daemon once endpoints are ready
+        if let Some(session) = self.sessions.get_mut(id) {
+            session.transport.pending_ws_signal = Some(PendingWsSignal {
+                signal_type: "connection_request".into(),
+            });
+        }
+
+        tracing::info!("[UI] daemon spawning, will send connection_request to {} when WS ready", peer.peer_code);
+    }
+
+    /// Spawn a session's daemon as a direct WS endpoint server (for browser
+    /// connections).
+    fn spawn_daemon_ws_server(&mut self, id: SessionId) {
+        let Some(daemon_bin) = self.daemon_bin.clone() else {
+            self.fail_session(id, "Daemon not found".into());
+            return;
+        };
+        let settings_args = self.settings.current().daemon_args();
+        let Some(session) = self.sessions.get_mut(id) else {
+            return;
         };
+        // Kill any existing daemon first
+        session.transport.kill_daemon();
 
-        let ws_listen = format!("0.0.0.0:{}", self.daemon_ws_port);
-        let _ = std::fs::create_dir_all(&self.data_dir);
+        let resources = &session.resources;
+        let ws_listen = format!("0.0.0.0:{}", resources.ws_port);
+        let _ = std::fs::create_dir_all(&resources.data_dir);
 
         match DaemonProcess::spawn_ws_server(
             &daemon_bin,
             &ws_listen,
-            &self.socket_path,
-            &self.data_dir,
+            &resources.socket_path.to_string_lossy(),
+            &resources.data_dir.to_string_lossy(),
+            &settings_args,
         ) {
             Ok(proc) => {
-                self.daemon_proc = Some(proc);
+                session.transport.daemon_proc = Some(proc);
                 tracing::info!("[UI] daemon WS server started on {ws_listen}");
             }
             Err(e) => {
-                self.connection = ConnectionState::Error(format!("Daemon spawn failed: {e}"));
                 tracing::error!("[UI] daemon WS spawn failed: {e}");
+                self.fail_session(id, format!("Daemon spawn failed: {e}"));
             }
         }
     }
@@ -279,38 +806,48 @@ impl BoltApp {
         match peer_plane {
             Some(SignalingPlane::Cloud) => {
                 // Peer discovered via cloud → daemon must use cloud signaling
-                self.cloud_signal_url
-                    .clone()
-                    .unwrap_or_else(|| format!("ws://{}", rendezvous_addr()))
+                self.settings
+                    .current()
+                    .effective_cloud_signal_url()
+                    .unwrap_or_else(|| format!("ws://{}", self.rendezvous_addr()))
             }
             _ => {
                 // Peer discovered locally → use local embedded server
-                format!("ws://{}", rendezvous_addr())
+                format!("ws://{}", self.rendezvous_addr())
             }
         }
     }
 
-    /// Spawn daemon using peer's discovered signaling plane (rendezvous fallback).
+    /// Spawn a session's daemon using its peer's discovered signaling plane
+    /// (rendezvous fallback).
     #[allow(dead_code)]
-    fn spawn_daemon_for_peer(&mut self, peer_code: &str, peer_name: &str, is_offerer: bool) {
-        let signal_url = self.resolve_signal_url_for_peer(peer_code);
-        self.spawn_daemon_with_url(peer_code, peer_name, is_offerer, &signal_url);
+    fn spawn_daemon_for_peer(&mut self, id: SessionId, is_offerer: bool) {
+        let Some(peer_code) = self.sessions.get(id).map(|s| s.peer_code.clone()) else {
+            return;
+        };
+        let signal_url = self.resolve_signal_url_for_peer(&peer_code);
+        self.spawn_daemon_with_url(id, is_offerer, &signal_url);
     }
 
-    /// Spawn daemon with an explicit signaling URL (rendezvous fallback).
+    /// Spawn a session's daemon with an explicit signaling URL (rendezvous
+    /// fallback).
     #[allow(dead_code)]
-    fn spawn_daemon_with_url(&mut self, peer_code: &str, peer_name: &str, is_offerer: bool, signal_url: &str) {
-        let daemon_bin = match &self.daemon_bin {
-            Some(b) => b.clone(),
-            None => {
-                self.connection = ConnectionState::Error("Daemon not found".into());
-                return;
-            }
+    fn spawn_daemon_with_url(&mut self, id: SessionId, is_offerer: bool, signal_url: &str) {
+        let Some(daemon_bin) = self.daemon_bin.clone() else {
+            self.fail_session(id, "Daemon not found".into());
+            return;
         };
 
         let room = daemon::generate_room_id();
-        let session = daemon::generate_session_id();
-        let _ = std::fs::create_dir_all(&self.data_dir);
+        let session_id = daemon::generate_session_id();
+        let settings_args = self.settings.current().daemon_args();
+        let Some(session) = self.sessions.get_mut(id) else {
+            return;
+        };
+        let peer_code = session.peer_code.clone();
+        let socket_path = session.resources.socket_path.to_string_lossy().to_string();
+        let data_dir = session.resources.data_dir.to_string_lossy().to_string();
+        let _ = std::fs::create_dir_all(&data_dir);
 
         tracing::info!(
             "[UI] spawning daemon: signal_url={signal_url}, role={}, peer={peer_code}",
@@ -321,34 +858,37 @@ impl BoltApp {
             DaemonProcess::spawn_join(
                 &daemon_bin,
                 &self.local_peer_code,
-                peer_code,
+                &peer_code,
                 &room,
-                &session,
-                &self.socket_path,
-                &self.data_dir,
-                &signal_url,
+                &session_id,
+                &socket_path,
+                &data_dir,
+                signal_url,
+                &settings_args,
             )
         } else {
             DaemonProcess::spawn_host(
                 &daemon_bin,
                 &self.local_peer_code,
-                peer_code,
+                &peer_code,
                 &room,
-                &session,
-                &self.socket_path,
-                &self.data_dir,
-                &signal_url,
+                &session_id,
+                &socket_path,
+                &data_dir,
+                signal_url,
+                &settings_args,
             )
         };
 
         match result {
             Ok(proc) => {
-                self.daemon_proc = Some(proc);
-                self.connection = ConnectionState::Establishing {
-                    peer_code: peer_code.to_string(),
-                    peer_name: peer_name.to_string(),
+                session.transport.daemon_proc = Some(proc);
+                let connection = ConnectionState::Establishing {
+                    peer_code: peer_code.clone(),
+                    peer_name: session.peer_name.clone(),
                     started_at: Instant::now(),
                 };
+                session::set_connection(session, connection);
                 tracing::info!(
                     "[UI] daemon spawned as {} for peer {}",
                     if is_offerer { "offerer" } else { "answerer" },
@@ -356,39 +896,47 @@ impl BoltApp {
                 );
             }
             Err(e) => {
-                self.connection = ConnectionState::Error(format!("Daemon spawn failed: {e}"));
                 tracing::error!("[UI] daemon spawn failed: {e}");
+                self.fail_session(id, format!("Daemon spawn failed: {e}"));
             }
         }
     }
 
-    /// Accept an incoming connection request — spawn daemon WS server.
+    /// Accept an incoming connection request — open a session and spawn its
+    /// daemon WS server.
     pub fn accept_incoming(&mut self) {
         let req = match self.incoming_request.take() {
             Some(r) => r,
             None => return,
         };
 
-        // Spawn daemon WS server (non-blocking)
-        self.spawn_daemon_ws_server();
-
-        // Store target — signal will be sent from poll_daemon once WS is ready
-        self.pending_ws_signal = Some(PendingWsSignal {
-            peer_code: req.peer_code.clone(),
-            signal_type: "connection_accepted".into(),
-        });
-
-        self.connection = ConnectionState::Establishing {
+        let connection = ConnectionState::Establishing {
             peer_code: req.peer_code.clone(),
             peer_name: req.device_name.clone(),
             started_at: Instant::now(),
         };
+        let Some(id) = self.open_session(
+            &req.peer_code,
+            &req.device_name,
+            req.device_type,
+            SessionPhase::IncomingRequest,
+            ConnectionState::Idle,
+        ) else {
+            return;
+        };
+        if let Some(session) = self.sessions.get_mut(id) {
+            session::set_connection(session, connection);
+        }
 
-        self.connected_peer = Some(ConnectedPeer {
-            peer_code: req.peer_code,
-            device_name: req.device_name,
-            device_type: req.device_type,
-        });
+        // Spawn daemon WS server (non-blocking)
+        self.spawn_daemon_ws_server(id);
+
+        // Signal will be sent from poll_daemon once endpoints are ready
+        if let Some(session) = self.sessions.get_mut(id) {
+            session.transport.pending_ws_signal = Some(PendingWsSignal {
+                signal_type: "connection_accepted".into(),
+            });
+        }
 
         tracing::info!("[UI] accepted — daemon spawning, will send connection_accepted when WS ready");
     }
@@ -408,34 +956,31 @@ impl BoltApp {
     }
 
     /// Cancel an outgoing connection request.
-    pub fn cancel_request(&mut self) {
-        if let ConnectionState::Requesting { ref peer_code, .. } = self.connection {
-            self.signaling_handle.send_signal(
-                peer_code,
-                "connection_declined",
-                serde_json::json!({"reason": "cancelled"}),
-                &self.local_peer_code,
-            );
+    pub fn cancel_request(&mut self, id: SessionId) {
+        if let Some(session) = self.sessions.get(id) {
+            if let ConnectionState::Requesting { ref peer_code, .. } = session.transport.connection {
+                self.signaling_handle.send_signal(
+                    peer_code,
+                    "connection_declined",
+                    serde_json::json!({"reason": "cancelled"}),
+                    &self.local_peer_code,
+                );
+            }
         }
-        self.kill_daemon();
-        self.connection = ConnectionState::Idle;
+        self.close_session(id, "cancelled");
     }
 
     // ── Manual pairing actions (fallback) ────────────────────
 
     pub fn start_host(&mut self) {
-        let _daemon_bin = match &self.daemon_bin {
-            Some(b) => b.clone(),
-            None => {
-                self.connection = ConnectionState::Error("Daemon not found".into());
-                return;
-            }
-        };
+        if self.daemon_bin.is_none() {
+            self.session_notice = Some("Daemon not found".into());
+            return;
+        }
 
-        if !daemon::probe_rendezvous(&rendezvous_addr()) {
-            self.connection = ConnectionState::Error(
-                format!("Signal server unreachable at {}", rendezvous_addr()),
-            );
+        let rendezvous = self.rendezvous_addr();
+        if !daemon::probe_rendezvous(&rendezvous) {
+            self.session_notice = Some(format!("Signal server unreachable at {rendezvous}"));
             return;
         }
 
@@ -443,34 +988,49 @@ impl BoltApp {
         let session = daemon::generate_session_id();
         let peer_code = self.local_peer_code.clone();
 
-        let _ = std::fs::create_dir_all(&self.data_dir);
-
+        self.session_notice = None;
         self.host_info = Some(HostInfo {
             peer_code,
             room,
             session,
         });
-        self.connection = ConnectionState::Idle;
     }
 
     pub fn start_host_with_joiner(&mut self, joiner_code: &str) {
-        let daemon_bin = match &self.daemon_bin {
-            Some(b) => b.clone(),
-            None => {
-                self.connection = ConnectionState::Error("Daemon not found".into());
-                return;
-            }
+        let Some(daemon_bin) = self.daemon_bin.clone() else {
+            self.session_notice = Some("Daemon not found".into());
+            return;
         };
 
         let info = match &self.host_info {
             Some(i) => i.clone(),
             None => {
-                self.connection = ConnectionState::Error("No host info".into());
+                self.session_notice = Some("No host info".into());
                 return;
             }
         };
 
-        let _ = std::fs::create_dir_all(&self.data_dir);
+        let connection = ConnectionState::Establishing {
+            peer_code: joiner_code.to_string(),
+            peer_name: joiner_code.to_string(),
+            started_at: Instant::now(),
+        };
+        let Some(id) = self.open_session(
+            joiner_code,
+            joiner_code,
+            DeviceType::Unknown,
+            SessionPhase::IncomingRequest,
+            ConnectionState::Idle,
+        ) else {
+            return;
+        };
+        let rendezvous = self.rendezvous_addr();
+        let settings_args = self.settings.current().daemon_args();
+        let Some(session) = self.sessions.get_mut(id) else {
+            return;
+        };
+        let data_dir = session.resources.data_dir.to_string_lossy().to_string();
+        let _ = std::fs::create_dir_all(&data_dir);
 
         match DaemonProcess::spawn_host(
             &daemon_bin,
@@ -478,427 +1038,332 @@ impl BoltApp {
             joiner_code,
             &info.room,
             &info.session,
-            &self.socket_path,
-            &self.data_dir,
-            &rendezvous_addr(),
+            &session.resources.socket_path.to_string_lossy(),
+            &data_dir,
+            &rendezvous,
+            &settings_args,
         ) {
             Ok(proc) => {
-                self.daemon_proc = Some(proc);
-                self.connection = ConnectionState::Establishing {
-                    peer_code: joiner_code.to_string(),
-                    peer_name: joiner_code.to_string(),
-                    started_at: Instant::now(),
-                };
+                session.transport.daemon_proc = Some(proc);
+                session::set_connection(session, connection);
             }
             Err(e) => {
-                self.connection = ConnectionState::Error(format!("Spawn failed: {e}"));
+                self.fail_session(id, format!("Spawn failed: {e}"));
             }
         }
     }
 
     pub fn start_join(&mut self) {
-        let daemon_bin = match &self.daemon_bin {
-            Some(b) => b.clone(),
-            None => {
-                self.connection = ConnectionState::Error("Daemon not found".into());
-                return;
-            }
+        let Some(daemon_bin) = self.daemon_bin.clone() else {
+            self.session_notice = Some("Daemon not found".into());
+            return;
         };
 
-        if !daemon::probe_rendezvous(&rendezvous_addr()) {
-            self.connection = ConnectionState::Error(
-                format!("Signal server unreachable at {}", rendezvous_addr()),
-            );
+        let rendezvous = self.rendezvous_addr();
+        if !daemon::probe_rendezvous(&rendezvous) {
+            self.session_notice = Some(format!("Signal server unreachable at {rendezvous}"));
             return;
         }
 
-        let _ = std::fs::create_dir_all(&self.data_dir);
+        let peer_code = self.join_peer_code.clone();
+        let connection = ConnectionState::Requesting {
+            peer_code: peer_code.clone(),
+            peer_name: peer_code.clone(),
+            started_at: Instant::now(),
+        };
+        let Some(id) = self.open_session(
+            &peer_code,
+            &peer_code,
+            DeviceType::Unknown,
+            SessionPhase::Requesting,
+            connection,
+        ) else {
+            return;
+        };
+        let settings_args = self.settings.current().daemon_args();
+        let Some(session) = self.sessions.get_mut(id) else {
+            return;
+        };
+        let data_dir = session.resources.data_dir.to_string_lossy().to_string();
+        let _ = std::fs::create_dir_all(&data_dir);
 
         match DaemonProcess::spawn_join(
             &daemon_bin,
             &self.local_peer_code,
-            &self.join_peer_code,
+            &peer_code,
             &self.join_room,
             &self.join_session,
-            &self.socket_path,
-            &self.data_dir,
-            &rendezvous_addr(),
+            &session.resources.socket_path.to_string_lossy(),
+            &data_dir,
+            &rendezvous,
+            &settings_args,
         ) {
             Ok(proc) => {
-                self.daemon_proc = Some(proc);
-                self.connection = ConnectionState::Requesting {
-                    peer_code: self.join_peer_code.clone(),
-                    peer_name: self.join_peer_code.clone(),
-                    started_at: Instant::now(),
-                };
+                session.transport.daemon_proc = Some(proc);
             }
             Err(e) => {
-                self.connection = ConnectionState::Error(format!("Spawn failed: {e}"));
+                self.fail_session(id, format!("Spawn failed: {e}"));
             }
         }
     }
 
-    pub fn cancel_connect(&mut self) {
-        self.kill_daemon();
-        self.ipc_client = None;
-        self.connection = ConnectionState::Idle;
-        self.transfer = TransferState::Idle;
-        self.verify = VerifyState::NotStarted;
-        self.connected_peer = None;
+    pub fn cancel_connect(&mut self, id: SessionId) {
+        self.close_session(id, "disconnected");
     }
 
-    pub fn disconnect(&mut self) {
-        self.cancel_connect();
+    pub fn disconnect(&mut self, id: SessionId) {
+        self.cancel_connect(id);
     }
 
-    pub fn poll_daemon(&mut self) {
-        if self.connection.is_timed_out() {
-            let error_detail = self
+    fn poll_sessions(&mut self) {
+        for id in self.sessions.ids() {
+            self.poll_daemon(id);
+        }
+    }
+
+    pub fn poll_daemon(&mut self, id: SessionId) {
+        let Some(session) = self.sessions.get_mut(id) else {
+            return;
+        };
+        let transport = &mut session.transport;
+
+        if transport.connection.is_timed_out() {
+            let error_detail = transport
                 .daemon_proc
                 .as_ref()
                 .and_then(|p| p.last_error())
                 .unwrap_or_else(|| "Connection timed out".into());
-            self.kill_daemon();
-            self.ipc_client = None;
-            self.connection = ConnectionState::Error(error_detail);
+            self.fail_session(id, error_detail);
             return;
         }
 
-        if let Some(proc) = &mut self.daemon_proc {
+        if let Some(proc) = &mut transport.daemon_proc {
             if !proc.is_running() {
                 let error = proc
                     .last_error()
                     .unwrap_or_else(|| "Daemon exited unexpectedly".into());
-                self.connection = ConnectionState::Error(error);
-                self.daemon_proc = None;
-                self.ipc_client = None;
-                self.wt_cert_hash = None;
-                self.wt_endpoint_ready = false;
+                self.fail_session(id, error);
                 return;
             }
+        }
 
-            let recent = proc.recent_stderr(30);
-
-            // Capture WT cert hash from daemon stderr (SECURE-DIRECT-1 SD1).
-            // Format: "[WT_CERT] hash=<64 hex chars>"
-            if self.wt_cert_hash.is_none() {
-                if let Some(hash) = recent.iter().find_map(|l| {
-                    if let Some(idx) = l.find("[WT_CERT] hash=") {
-                        Some(l[idx + 15..].trim().to_string())
-                    } else {
-                        None
+        // Connect IPC once the daemon socket accepts. The daemon replays
+        // current state after the handshake, so connecting late loses nothing.
+        if transport.ipc_client.is_none() && transport.daemon_proc.is_some() {
+            let due = transport
+                .last_ipc_attempt
+                .is_none_or(|t| t.elapsed() >= IPC_RETRY_INTERVAL);
+            if due {
+                transport.last_ipc_attempt = Some(Instant::now());
+                let socket_path = session.resources.socket_path.to_string_lossy();
+                if IpcStream::probe(std::path::Path::new(socket_path.as_ref())) {
+                    match IpcClient::connect(&socket_path) {
+                        Ok(client) => transport.ipc_client = Some(client),
+                        Err(e) => tracing::debug!("[UI] IPC connect failed: {e}"),
                     }
-                }) {
-                    tracing::info!("[UI] captured WT cert hash: {hash}");
-                    self.wt_cert_hash = Some(hash);
                 }
             }
+        }
 
-            // Detect WT endpoint ready (must see this before advertising wtUrl)
-            if !self.wt_endpoint_ready && recent.iter().any(|l| l.contains("[WT_ENDPOINT] listening")) {
-                self.wt_endpoint_ready = true;
-                tracing::info!("[UI] WT endpoint ready");
-            }
+        let events = match &transport.ipc_client {
+            Some(client) => client.drain_events(),
+            None => Vec::new(),
+        };
+        for event in events {
+            self.apply_daemon_event(id, event);
+        }
 
-            // Check if daemon endpoints are ready — send pending signal.
-            // Wait for WS to be listening. If WT cert hash was captured,
-            // also wait for WT endpoint to be ready before sending signaling
-            // (prevents browser from getting wtUrl before WT is listening).
-            if let Some(ref pending) = self.pending_ws_signal {
-                let ws_ready = recent.iter().any(|l| l.contains("[WS_ENDPOINT] listening"));
-                let wt_needed = self.wt_cert_hash.is_some();
-                let wt_ready = !wt_needed || self.wt_endpoint_ready;
-                if ws_ready && wt_ready {
-                    let ws_url = format!("ws://{}:{}", local_ip(), self.daemon_ws_port);
-                    let device_name = hostname::get()
-                        .map(|h| h.to_string_lossy().to_string())
-                        .unwrap_or_else(|_| "Desktop".to_string());
-
-                    // Build signaling payload with WS + optional WT endpoints
-                    let wt_port = self.daemon_ws_port + 1;
-                    let mut payload = serde_json::json!({
-                        "deviceName": device_name,
-                        "deviceType": "desktop",
-                        "wsUrl": ws_url,
-                    });
-                    // Only advertise WT endpoint if it's confirmed listening
-                    if self.wt_endpoint_ready {
-                        if let Some(ref cert_hash) = self.wt_cert_hash {
-                            let wt_url = format!("https://{}:{}", local_ip(), wt_port);
-                            payload["wtUrl"] = serde_json::Value::String(wt_url);
-                            payload["certHash"] = serde_json::Value::String(cert_hash.clone());
-                        }
-                    }
+        self.send_pending_signal(id);
+    }
 
-                    match pending.signal_type.as_str() {
-                        "connection_request" => {
-                            self.signaling_handle.send_signal(
-                                &pending.peer_code,
-                                "connection_request",
-                                payload,
-                                &self.local_peer_code,
-                            );
-                            tracing::info!("[UI] daemon WS ready — sent connection_request with wsUrl={ws_url}");
+    /// Fold one daemon event into a session's state. Endpoint, session,
+    /// verification and transfer state change only here (and on local user
+    /// actions).
+    fn apply_daemon_event(&mut self, id: SessionId, event: DaemonEvent) {
+        let Some(session) = self.sessions.get_mut(id) else {
+            return;
+        };
+        match event {
+            DaemonEvent::EndpointListening(p) => {
+                tracing::info!("[UI] {:?} endpoint listening (port {:?})", p.endpoint, p.port);
+                match p.endpoint {
+                    Endpoint::Ws => session.transport.endpoints.ws_listening = true,
+                    Endpoint::Wt => session.transport.endpoints.wt_listening = true,
+                }
+            }
+            DaemonEvent::WtCert(p) => {
+                tracing::info!("[UI] WT cert hash: {}", p.cert_hash_hex);
+                session.transport.endpoints.wt_cert_hash = Some(p.cert_hash_hex);
+            }
+            DaemonEvent::SessionConnected(p) => {
+                tracing::info!("[UI] daemon session established ({:?} HELLO)", p.hello_mode);
+                session::set_connection(session, ConnectionState::Connected);
+                match p.hello_mode {
+                    // Legacy HELLO (no identity) → transfer allowed immediately.
+                    HelloMode::Legacy => {
+                        session.on_legacy();
+                        session.transport.transfer = TransferState::Ready;
+                    }
+                    HelloMode::Identity => {
+                        // Unverified peers wait for the user to compare the
+                        // SAS (`session.sas` may already have arrived).
+                        let trusted = self.observe_identity(id, p.remote_identity_pk_b64);
+                        if trusted {
+                            // SAS verified in an earlier session → trusted again.
+                            tracing::info!("[UI] peer identity previously verified");
                         }
-                        "connection_accepted" => {
-                            // For accepted, strip deviceName/deviceType
-                            let accept_payload = if self.wt_endpoint_ready {
-                                if let Some(ref cert_hash) = self.wt_cert_hash {
-                                    let wt_url = format!("https://{}:{}", local_ip(), wt_port);
-                                    serde_json::json!({
-                                        "wsUrl": ws_url,
-                                        "wtUrl": wt_url,
-                                        "certHash": cert_hash,
-                                    })
-                                } else {
-                                    serde_json::json!({ "wsUrl": ws_url })
-                                }
+                        if let Some(session) = self.sessions.get_mut(id) {
+                            session.transport.transfer = if trusted {
+                                TransferState::Ready
                             } else {
-                                serde_json::json!({ "wsUrl": ws_url })
+                                TransferState::Idle
                             };
-                            self.signaling_handle.send_signal(
-                                &pending.peer_code,
-                                "connection_accepted",
-                                accept_payload,
-                                &self.local_peer_code,
-                            );
-                            tracing::info!("[UI] daemon WS ready — sent connection_accepted with wsUrl={ws_url}");
                         }
-                        _ => {}
                     }
-                    self.pending_ws_signal = None;
                 }
             }
-
-            // Detect WS session establishment
-            if self.connection.is_connecting() {
-                if recent.iter().any(|l| (l.contains("[WS_SESSION]") || l.contains("[WT_SESSION]")) && l.contains("session established")) {
-                    tracing::info!("[UI] daemon session established — browser connected");
-                    self.connection = ConnectionState::Connected;
-                    // Determine verification mode from daemon HELLO logs.
-                    // Legacy HELLO (no identity) → transfer allowed immediately.
-                    // Identity HELLO → wait for user verification before transfer.
-                    let is_legacy = recent.iter().any(|l| l.contains("legacy HELLO"));
-                    if is_legacy {
-                        self.verify = VerifyState::Legacy;
-                        self.transfer = TransferState::Ready;
-                    } else {
-                        // Identity mode: extract SAS from daemon if available via IPC,
-                        // otherwise set pending with placeholder until IPC delivers it.
-                        // For now, detect SAS from HELLO logs.
-                        let sas = recent.iter().find_map(|l| {
-                            if let Some(idx) = l.find("[SAS]") {
-                                Some(l[idx + 5..].trim().to_string())
-                            } else {
-                                None
-                            }
-                        });
-                        self.verify = VerifyState::Pending {
-                            sas_code: sas.unwrap_or_else(|| "------".into()),
-                        };
-                        // Transfer stays Idle until user verifies
-                        self.transfer = TransferState::Idle;
-                    }
-                }
+            DaemonEvent::SessionSas(p) => {
+                session.on_sas(p.sas, p.remote_identity_pk_b64);
             }
-
-            // Detect session end (peer disconnected) from daemon stderr.
-            // Daemon logs this when the WT/WS session ends and ACTIVE_SESSION is cleared.
-            if matches!(self.connection, ConnectionState::Connected) {
-                let session_ended = recent.iter().any(|l| {
-                    l.contains("ACTIVE_SESSION cleared") || l.contains("active session handle cleared")
-                });
-                if session_ended {
-                    tracing::info!("[UI] daemon session ended — peer disconnected");
-                    self.connection = ConnectionState::Idle;
-                    self.transfer = TransferState::Idle;
-                    self.verify = VerifyState::NotStarted;
-                    self.connected_peer = None;
-                    // Don't kill daemon — it can accept new connections
-                    return;
+            DaemonEvent::SessionEnded(p) => {
+                tracing::info!("[UI] daemon session ended ({:?})", p.reason);
+                let reason = p.reason.unwrap_or_else(|| "session ended".into());
+                self.close_session(id, &reason);
+            }
+            DaemonEvent::SessionError(p) => {
+                self.fail_session(id, p.reason);
+            }
+            DaemonEvent::TransferStarted(p) => {
+                let displaced = session.queue.on_started(
+                    p.direction,
+                    &p.file_name,
+                    p.file_size_bytes,
+                    p.transfer_id.clone(),
+                );
+                let transport = &mut session.transport;
+                if p.transfer_id.is_some() {
+                    transport.active_transfer_id = p.transfer_id;
+                }
+                transport.paused = false;
+                transport.transfer = match p.direction {
+                    TransferDirection::Send => TransferState::Sending {
+                        file_name: p.file_name,
+                        progress: 0.0,
+                    },
+                    TransferDirection::Receive => TransferState::Receiving {
+                        file_name: p.file_name,
+                        progress: 0.0,
+                    },
+                };
+                if let Some(displaced) = displaced {
+                    let peer = session.peer_name.clone();
+                    let reason = "superseded by another transfer".to_string();
+                    self.record_history(&peer, displaced, TransferResult::Failed, Some(reason), None);
                 }
             }
-
-            // Detect transfer activity from daemon stderr (WS or WT path)
-            if matches!(self.connection, ConnectionState::Connected) {
-                let is_transfer = |l: &str| l.contains("[WS_TRANSFER]") || l.contains("[WT_TRANSFER]");
-                for line in &recent {
-                    // Detect incoming file transfer start
-                    if is_transfer(line) && line.contains("receiving:") {
-                        // Extract filename from log: "[WS/WT_TRANSFER] ... receiving: filename (size bytes, ...)"
-                        let fname = line.split("receiving:").nth(1)
-                            .and_then(|s| s.trim().split('(').next())
-                            .map(|s| s.trim().to_string())
-                            .unwrap_or_else(|| "incoming file".into());
-                        if matches!(self.transfer, TransferState::Ready | TransferState::Idle) {
-                            self.transfer = TransferState::Receiving {
-                                file_name: fname,
-                                progress: 0.0,
-                            };
-                        }
-                    }
-                    // Update transfer progress
-                    // Daemon format: "[WS_TRANSFER] ... progress: {done}/{total} chunks ({name})"
-                    if is_transfer(line) && line.contains("progress:") {
-                        if let Some(frac) = line.split("progress:").nth(1) {
-                            let parts: Vec<&str> = frac.trim().splitn(2, '/').collect();
-                            if parts.len() == 2 {
-                                let done: f32 = parts[0].trim().parse().unwrap_or(0.0);
-                                let total: f32 = parts[1].trim().split_whitespace().next()
-                                    .and_then(|s| s.parse().ok()).unwrap_or(1.0);
-                                let pct = if total > 0.0 { done / total } else { 0.0 };
-                                match &mut self.transfer {
-                                    TransferState::Sending { progress, .. } => *progress = pct,
-                                    TransferState::Receiving { progress, .. } => *progress = pct,
-                                    _ => {}
-                                }
-                            }
-                        }
-                    }
-                    // Detect file saved (receive complete)
-                    // Daemon format: "[WS_TRANSFER] {peer} saved: {name} ({bytes} bytes) → {path}"
-                    if is_transfer(line) && line.contains("saved:") {
-                        let after_saved = line.split("saved:").nth(1).unwrap_or("");
-                        let fname = after_saved.trim().split('(').next()
-                            .map(|s| s.trim().to_string())
-                            .unwrap_or_else(|| "file".into());
-                        let save_path = after_saved.split('\u{2192}').nth(1) // → arrow
-                            .map(|s| s.trim().to_string());
-                        // Reveal in Finder on macOS
-                        if let Some(ref path) = save_path {
-                            #[cfg(target_os = "macos")]
-                            {
-                                let _ = std::process::Command::new("open")
-                                    .arg("-R")
-                                    .arg(path)
-                                    .spawn();
-                            }
-                        }
-                        self.transfer = TransferState::Complete { file_name: fname, save_path };
-                    }
-                    // Detect send complete
-                    // Daemon format: "[WS_TRANSFER] all {N} chunks queued for {name}"
-                    if is_transfer(line) && line.contains("chunks queued") {
-                        if let TransferState::Sending { ref file_name, .. } = self.transfer {
-                            let name = file_name.clone();
-                            self.transfer = TransferState::Complete { file_name: name, save_path: None };
-                        }
-                    }
+            DaemonEvent::TransferProgress(p) => match &mut session.transport.transfer {
+                TransferState::Sending { progress, .. }
+                | TransferState::Receiving { progress, .. } => {
+                    *progress = p.progress as f32;
+                }
+                _ => {}
+            },
+            DaemonEvent::TransferComplete(p) => {
+                // Reveal in Finder on macOS
+                #[cfg(target_os = "macos")]
+                if let Some(ref path) = p.save_path {
+                    let _ = std::process::Command::new("open").arg("-R").arg(path).spawn();
+                }
+                self.finish_active(id, TransferResult::Completed, None, Some(&p));
+                if let Some(session) = self.sessions.get_mut(id) {
+                    session.transport.transfer = TransferState::Complete {
+                        file_name: p.file_name,
+                        save_path: p.save_path,
+                    };
+                    session.transport.active_transfer_id = None;
+                    session.transport.paused = false;
                 }
             }
-
-            // Try IPC connection for event forwarding
-            if self.ipc_client.is_none() {
-                if proc
-                    .recent_stderr(20)
-                    .iter()
-                    .any(|l| l.contains("[IPC] listening"))
-                {
-                    if let Ok(client) = IpcClient::connect(&self.socket_path) {
-                        self.ipc_client = Some(client);
-                    }
+            DaemonEvent::TransferError(p) => {
+                self.finish_active(id, TransferResult::Failed, Some(p.reason.clone()), None);
+                if let Some(session) = self.sessions.get_mut(id) {
+                    session.transport.transfer = TransferState::Failed {
+                        file_name: p.file_name.unwrap_or_else(|| "unknown".into()),
+                        reason: p.reason,
+                    };
+                    session.transport.active_transfer_id = None;
+                    session.transport.paused = false;
                 }
             }
+            DaemonEvent::PairingRequest(p) => {
+                tracing::info!("[UI] pairing request from {}", p.remote_device_name);
+                session.transport.pairing_request = Some(p);
+            }
+            DaemonEvent::Status(_)
+            | DaemonEvent::TransferIncomingRequest(_)
+            | DaemonEvent::DecisionAck(_) => {}
         }
+    }
 
-        if let Some(client) = &self.ipc_client {
-            for event in client.drain_events() {
-                match event.msg_type.as_str() {
-                    "daemon.status" => {}
-                    "session.connected" => {
-                        let caps = event
-                            .payload
-                            .get("negotiated_capabilities")
-                            .and_then(|v| v.as_array())
-                            .map(|a| a.len())
-                            .unwrap_or(0);
-                        if caps > 0 {
-                            self.connection = ConnectionState::Connected;
-                            self.transfer = TransferState::Ready;
-                        }
-                    }
-                    "session.sas" => {
-                        if let Some(sas) = event.payload.get("sas").and_then(|v| v.as_str()) {
-                            self.verify = VerifyState::Pending {
-                                sas_code: sas.to_string(),
-                            };
-                        }
-                    }
-                    "session.error" => {
-                        let reason = event
-                            .payload
-                            .get("reason")
-                            .and_then(|v| v.as_str())
-                            .unwrap_or("unknown error")
-                            .to_string();
-                        self.connection = ConnectionState::Error(reason);
-                    }
-                    "session.ended" => {
-                        self.connection = ConnectionState::Idle;
-                        self.transfer = TransferState::Idle;
-                        self.verify = VerifyState::NotStarted;
-                        self.connected_peer = None;
-                    }
-                    "transfer.started" => {
-                        let file_name = event
-                            .payload
-                            .get("file_name")
-                            .and_then(|v| v.as_str())
-                            .unwrap_or("unknown")
-                            .to_string();
-                        let direction = event
-                            .payload
-                            .get("direction")
-                            .and_then(|v| v.as_str())
-                            .unwrap_or("receive");
-                        if direction == "send" {
-                            self.transfer = TransferState::Sending {
-                                file_name,
-                                progress: 0.0,
-                            };
-                        } else {
-                            self.transfer = TransferState::Receiving {
-                                file_name,
-                                progress: 0.0,
-                            };
-                        }
-                    }
-                    "transfer.progress" => {
-                        let progress = event
-                            .payload
-                            .get("progress")
-                            .and_then(|v| v.as_f64())
-                            .unwrap_or(0.0) as f32;
-                        match &mut self.transfer {
-                            TransferState::Sending { progress: p, .. }
-                            | TransferState::Receiving { progress: p, .. } => {
-                                *p = progress;
-                            }
-                            _ => {}
-                        }
-                    }
-                    "transfer.complete" => {
-                        let file_name = event
-                            .payload
-                            .get("file_name")
-                            .and_then(|v| v.as_str())
-                            .unwrap_or("unknown")
-                            .to_string();
-                        self.transfer = TransferState::Complete { file_name, save_path: None };
-                    }
-                    _ => {}
-                }
-            }
+    /// Send a session's pending connection signal once its daemon endpoints
+    /// are ready (see [`EndpointState::ready_to_signal`]).
+    fn send_pending_signal(&mut self, id: SessionId) {
+        let device_name = self.device_name();
+        let Some(session) = self.sessions.get_mut(id) else {
+            return;
+        };
+        if !session.transport.endpoints.ready_to_signal() {
+            return;
+        }
+        let Some(pending) = session.transport.pending_ws_signal.take() else {
+            return;
+        };
+
+        let ws_url = format!("ws://{}:{}", local_ip(), session.resources.ws_port);
+        let mut payload = serde_json::json!({ "wsUrl": ws_url });
+        // Only advertise WT endpoint if it's confirmed listening
+        if let Some(cert_hash) = session.transport.endpoints.advertised_wt_cert() {
+            let wt_url = format!("https://{}:{}", local_ip(), session.resources.wt_port());
+            payload["wtUrl"] = serde_json::Value::String(wt_url);
+            payload["certHash"] = serde_json::Value::String(cert_hash.to_string());
         }
+        // connection_accepted carries the endpoints only
+        if pending.signal_type == "connection_request" {
+            payload["deviceName"] = serde_json::Value::String(device_name);
+            payload["deviceType"] = serde_json::Value::String("desktop".into());
+        }
+
+        self.signaling_handle.send_signal(
+            &session.peer_code,
+            &pending.signal_type,
+            payload,
+            &self.local_peer_code,
+        );
+        tracing::info!(
+            "[UI] daemon endpoints ready — sent {} to {} with wsUrl={ws_url}",
+            pending.signal_type,
+            session.peer_code
+        );
     }
 }
 
 impl eframe::App for BoltApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        if self.daemon_proc.is_some() {
-            self.poll_daemon();
+        if !self.sessions.is_empty() {
+            self.poll_sessions();
             ctx.request_repaint();
         }
+        self.poll_pending_actions();
+        self.apply_settings_changes();
+
+        // ── Dropped files / folders → one batch ───────────────
+        let dropped: Vec<std::path::PathBuf> = ctx.input(|i| {
+            i.raw.dropped_files.iter().filter_map(|f| f.path.clone()).collect()
+        });
+        if !dropped.is_empty() {
+            self.enqueue_paths(&dropped);
+        }
+        self.pump_queues();
 
         // ── Process signaling events ──────────────────────────
         while let Ok(event) = self.signaling_rx.try_recv() {
@@ -993,20 +1458,21 @@ impl eframe::App for BoltApp {
                     };
                     match sig.signal_type.as_str() {
                         "connection_request" => {
-                            if self.connection != ConnectionState::Idle {
-                                // Duplicate from same peer via other plane — ignore
-                                let dominated = self.incoming_request
+                            // Duplicate from same peer via other plane, or a
+                            // peer we already have a session with — ignore
+                            let duplicate = self.sessions.find_peer(&sig.from).is_some()
+                                || self.incoming_request
                                     .as_ref()
-                                    .map(|r| r.peer_code == sig.from)
-                                    .unwrap_or(false);
-                                if !dominated {
-                                    self.signaling_handle.send_signal(
-                                        &sig.from,
-                                        "connection_declined",
-                                        serde_json::json!({"reason": "busy"}),
-                                        &self.local_peer_code,
-                                    );
-                                }
+                                    .is_some_and(|r| r.peer_code == sig.from);
+                            if duplicate {
+                                tracing::debug!("[UI] duplicate connection_request from {}", sig.from);
+                            } else if self.incoming_request.is_some() || self.sessions.is_full() {
+                                self.signaling_handle.send_signal(
+                                    &sig.from,
+                                    "connection_declined",
+                                    serde_json::json!({"reason": "busy"}),
+                                    &self.local_peer_code,
+                                );
                             } else {
                                 let device_name = sig.data.get("deviceName")
                                     .and_then(|v| v.as_str())
@@ -1025,23 +1491,31 @@ impl eframe::App for BoltApp {
                             }
                         }
                         "connection_accepted" => {
-                            if let ConnectionState::Requesting { ref peer_code, ref peer_name, .. } = self.connection {
-                                tracing::info!("[UI] connection accepted by {} — daemon WS server already running", sig.from);
-                                // Daemon WS server was already spawned in connect_to_peer().
-                                // Browser will now connect to it directly. Just update state.
-                                let code = peer_code.clone();
-                                let name = peer_name.clone();
-                                self.connection = ConnectionState::Establishing {
-                                    peer_code: code,
-                                    peer_name: name,
-                                    started_at: Instant::now(),
-                                };
+                            let session = self.sessions.find_peer(&sig.from)
+                                .and_then(|id| self.sessions.get_mut(id));
+                            if let Some(session) = session {
+                                if let ConnectionState::Requesting { ref peer_code, ref peer_name, .. } = session.transport.connection {
+                                    tracing::info!("[UI] connection accepted by {} — daemon WS server already running", sig.from);
+                                    // Daemon WS server was already spawned in connect_to_peer().
+                                    // Browser will now connect to it directly. Just update state.
+                                    let connection = ConnectionState::Establishing {
+                                        peer_code: peer_code.clone(),
+                                        peer_name: peer_name.clone(),
+                                        started_at: Instant::now(),
+                                    };
+                                    session::set_connection(session, connection);
+                                }
                             }
                         }
                         "connection_declined" => {
-                            if let ConnectionState::Requesting { .. } = self.connection {
+                            let requesting = self.sessions.find_peer(&sig.from).filter(|&id| {
+                                self.sessions.get(id).is_some_and(|s| {
+                                    matches!(s.transport.connection, ConnectionState::Requesting { .. })
+                                })
+                            });
+                            if let Some(id) = requesting {
                                 tracing::info!("[UI] connection declined by {}", sig.from);
-                                self.connection = ConnectionState::Error("Connection declined".into());
+                                self.fail_session(id, "Connection declined".into());
                             }
                             if let Some(ref req) = self.incoming_request {
                                 if req.peer_code == sig.from {
@@ -1105,15 +1579,41 @@ impl eframe::App for BoltApp {
             .show(ctx, |ui| {
                 screens::main_card::show(ui, self);
             });
+
+        if ctx.input(|i| !i.raw.hovered_files.is_empty()) {
+            show_drop_overlay(ctx);
+        }
     }
 }
 
+/// Full-window hint while files are dragged over the window.
+fn show_drop_overlay(ctx: &egui::Context) {
+    let painter = ctx.layer_painter(egui::LayerId::new(
+        egui::Order::Foreground,
+        egui::Id::new("drop_overlay"),
+    ));
+    let rect = ctx.content_rect();
+    painter.rect_filled(rect, 0.0, theme::WINDOW_BG.gamma_multiply(0.85));
+    painter.rect_stroke(
+        rect.shrink(theme::SPACING_LG),
+        0.0,
+        egui::Stroke::new(2.0, theme::ACCENT),
+        egui::StrokeKind::Inside,
+    );
+    painter.text(
+        rect.center(),
+        egui::Align2::CENTER_CENTER,
+        "Drop files or folders to send",
+        egui::FontId::proportional(theme::FONT_SIZE_HEADING),
+        theme::ACCENT,
+    );
+}
+
 impl Drop for BoltApp {
     fn drop(&mut self) {
-        if let Some(mut proc) = self.daemon_proc.take() {
-            proc.kill();
+        for session in self.sessions.iter_mut() {
+            session.transport.kill_daemon();
         }
-        let _ = std::fs::remove_file(&self.socket_path);
-        let _ = std::fs::remove_dir_all(&self.data_dir);
+        let _ = std::fs::remove_dir_all(self.sessions.runtime_dir());
     }
 }
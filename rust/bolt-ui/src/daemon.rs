@@ -13,7 +13,8 @@ use std::thread;
 // bolt_app_core re-exports available for future use:
 // platform paths, IPC types, watchdog, signal monitor, etc.
 
-/// Daemon process handle with stderr capture.
+/// Daemon process handle. Stderr is captured for diagnostics
+/// ([`DaemonProcess::last_error`]); it is never parsed for state.
 pub struct DaemonProcess {
     child: Child,
     pid: u32,
@@ -97,6 +98,7 @@ impl DaemonProcess {
         socket_path: &str,
         data_dir: &str,
         rendezvous_url: &str,
+        settings_args: &[String],
     ) -> Result<Self, String> {
         // Respect the URL as given — may be ws:// or wss://
         let ws_url = if rendezvous_url.starts_with("ws://") || rendezvous_url.starts_with("wss://") {
@@ -116,9 +118,9 @@ impl DaemonProcess {
                 "--expect-peer", expect_peer,
                 "--socket-path", socket_path,
                 "--data-dir", data_dir,
-                "--pairing-policy", "allow",
                 "--phase-timeout-secs", "3600",
             ],
+            settings_args,
         )
     }
 
@@ -132,6 +134,7 @@ impl DaemonProcess {
         socket_path: &str,
         data_dir: &str,
         rendezvous_url: &str,
+        settings_args: &[String],
     ) -> Result<Self, String> {
         // Respect the URL as given — may be ws:// or wss://
         let ws_url = if rendezvous_url.starts_with("ws://") || rendezvous_url.starts_with("wss://") {
@@ -151,19 +154,22 @@ impl DaemonProcess {
                 "--to", to_peer,
                 "--socket-path", socket_path,
                 "--data-dir", data_dir,
-                "--pairing-policy", "allow",
                 "--phase-timeout-secs", "3600",
             ],
+            settings_args,
         )
     }
 
     /// Spawn daemon as a direct WS endpoint server for browser connections.
     /// Uses ws-endpoint mode — no WebRTC/file-signal path, just WS serving.
+    /// Browser peers pair here too, so `settings_args` carries the
+    /// `--pairing-policy` this mode always passed.
     pub fn spawn_ws_server(
         daemon_bin: &PathBuf,
         ws_listen: &str,
         socket_path: &str,
         data_dir: &str,
+        settings_args: &[String],
     ) -> Result<Self, String> {
         Self::spawn(
             daemon_bin,
@@ -172,12 +178,13 @@ impl DaemonProcess {
                 "--ws-listen", ws_listen,
                 "--socket-path", socket_path,
                 "--data-dir", data_dir,
-                "--pairing-policy", "allow",
             ],
+            settings_args,
         )
     }
 
-    fn spawn(daemon_bin: &PathBuf, args: &[&str]) -> Result<Self, String> {
+    /// `settings_args` come from [`bolt_app_core::settings::Settings::daemon_args`].
+    fn spawn(daemon_bin: &PathBuf, args: &[&str], settings_args: &[String]) -> Result<Self, String> {
         // Ensure data-dir exists with secure permissions
         if let Some(idx) = args.iter().position(|a| *a == "--data-dir") {
             if let Some(dir) = args.get(idx + 1) {
@@ -192,6 +199,7 @@ impl DaemonProcess {
 
         let mut child = Command::new(daemon_bin)
             .args(args)
+            .args(settings_args)
             .stdout(Stdio::null())
             .stderr(Stdio::piped())
             .spawn()
@@ -227,51 +235,8 @@ impl DaemonProcess {
         self.child.try_wait().ok().flatten().is_none()
     }
 
-    pub fn recent_stderr(&self, last_n: usize) -> Vec<String> {
-        self.stderr_lines
-            .lock()
-            .map(|buf| {
-                let start = buf.len().saturating_sub(last_n);
-                buf[start..].to_vec()
-            })
-            .unwrap_or_default()
-    }
-
-    pub fn has_connected(&self) -> bool {
-        self.stderr_lines
-            .lock()
-            .map(|buf| {
-                buf.iter().any(|l| {
-                    l.contains("hello/ack complete")
-                        || l.contains("connection state: Connected")
-                        || l.contains("[DC_OPEN]")
-                })
-            })
-            .unwrap_or(false)
-    }
-
-    pub fn sas_code(&self) -> Option<String> {
-        self.stderr_lines
-            .lock()
-            .ok()
-            .and_then(|buf| {
-                buf.iter().find_map(|l| {
-                    if l.contains("[SAS]") {
-                        l.split("[SAS]").nth(1).map(|s| s.trim().to_string())
-                    } else {
-                        None
-                    }
-                })
-            })
-    }
-
-    pub fn is_transfer_ready(&self) -> bool {
-        self.stderr_lines
-            .lock()
-            .map(|buf| buf.iter().any(|l| l.contains("[DC_OPEN]")))
-            .unwrap_or(false)
-    }
-
+    /// Most recent error-looking stderr line, for diagnostics only —
+    /// session state comes from the IPC event stream.
     pub fn last_error(&self) -> Option<String> {
         self.stderr_lines
             .lock()
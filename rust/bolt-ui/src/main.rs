@@ -12,6 +12,7 @@ pub mod app;
 pub mod daemon;
 pub mod ipc;
 mod screens;
+pub mod session;
 pub mod state;
 mod theme;
 
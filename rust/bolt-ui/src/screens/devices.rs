@@ -0,0 +1,186 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use eframe::egui;
+
+use bolt_app_core::trusted_devices::{VerificationMethod, MAX_ALIAS_CHARS};
+
+use crate::app::BoltApp;
+use crate::theme;
+
+/// Pin store edit requested by a row button; applied after the list is drawn.
+enum DeviceEdit {
+    StartRename(String),
+    SaveRename,
+    CancelRename,
+    Reverify(String),
+    Revoke(String),
+}
+
+/// Trusted devices panel (below the main card).
+pub fn show(ui: &mut egui::Ui, app: &mut BoltApp) {
+    theme::inset_frame().show(ui, |ui| {
+        ui.set_min_width(340.0);
+        theme::section_label(ui, "trusted devices");
+        ui.label(
+            egui::RichText::new(
+                "Kept by this app. Revoking here does not change what the daemon trusts.",
+            )
+            .size(theme::FONT_SIZE_SMALL)
+            .color(theme::TEXT_MUTED),
+        );
+        ui.add_space(theme::SPACING_SM);
+
+        if let Some(err) = &app.trust_error {
+            ui.label(
+                egui::RichText::new(format!("! {err}"))
+                    .size(theme::FONT_SIZE_SMALL)
+                    .color(theme::ERROR),
+            );
+        }
+
+        if app.trust.list().is_empty() {
+            ui.label(
+                egui::RichText::new(
+                    "No devices yet — peers appear here after their first secure session",
+                )
+                .size(theme::FONT_SIZE_SMALL)
+                .color(theme::TEXT_MUTED),
+            );
+            return;
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+        let mut edit = None;
+        egui::ScrollArea::vertical()
+            .max_height(260.0)
+            .show(ui, |ui| {
+                for device in app.trust.list() {
+                    let key = &device.identity_pk_b64;
+                    let connected = app.is_identity_connected(key);
+                    let (mark, color) = match device.method {
+                        VerificationMethod::Sas => ("\u{2713}", theme::SUCCESS),
+                        VerificationMethod::Tofu => ("\u{25CB}", theme::WARNING),
+                    };
+
+                    ui.horizontal(|ui| match &mut app.device_rename {
+                        Some((renaming, text)) if renaming == key => {
+                            ui.add(
+                                egui::TextEdit::singleline(text)
+                                    .hint_text(&device.device_name)
+                                    .desired_width(160.0)
+                                    .char_limit(MAX_ALIAS_CHARS),
+                            );
+                            if ui.small_button("Save").clicked() {
+                                edit = Some(DeviceEdit::SaveRename);
+                            }
+                            if ui.small_button("Cancel").clicked() {
+                                edit = Some(DeviceEdit::CancelRename);
+                            }
+                        }
+                        _ => {
+                            ui.label(
+                                egui::RichText::new(format!("{mark} {}", device.display_name()))
+                                    .size(theme::FONT_SIZE_BODY)
+                                    .color(color),
+                            );
+                            if connected {
+                                theme::status_dot(ui, theme::ACCENT, false, "Connected");
+                            }
+                            ui.with_layout(
+                                egui::Layout::right_to_left(egui::Align::Center),
+                                |ui| {
+                                    if ui
+                                        .small_button("Revoke")
+                                        .on_hover_text("Forget this device in this app")
+                                        .clicked()
+                                    {
+                                        edit = Some(DeviceEdit::Revoke(key.clone()));
+                                    }
+                                    if device.is_verified()
+                                        && ui
+                                            .small_button("Re-verify")
+                                            .on_hover_text(
+                                                "Compare the code again on the next connection",
+                                            )
+                                            .clicked()
+                                    {
+                                        edit = Some(DeviceEdit::Reverify(key.clone()));
+                                    }
+                                    if ui.small_button("Rename").clicked() {
+                                        edit = Some(DeviceEdit::StartRename(key.clone()));
+                                    }
+                                },
+                            );
+                        }
+                    });
+
+                    ui.label(theme::mono_value(
+                        &device.fingerprint(),
+                        theme::FONT_SIZE_SMALL,
+                    ));
+                    let method = match device.verified_at_ms {
+                        Some(at) if device.is_verified() => {
+                            format!("SAS verified {}", format_ago(now, at))
+                        }
+                        _ => "Not verified (trusted on first use)".to_string(),
+                    };
+                    let mut details = vec![method];
+                    if device.alias.is_some() {
+                        details.push(format!("announces \"{}\"", device.device_name));
+                    }
+                    details.push(format!(
+                        "first seen {}",
+                        format_ago(now, device.first_seen_ms)
+                    ));
+                    details.push(format!(
+                        "last seen {}",
+                        format_ago(now, device.last_seen_ms)
+                    ));
+                    ui.label(
+                        egui::RichText::new(details.join(" · "))
+                            .size(theme::FONT_SIZE_SMALL)
+                            .color(theme::TEXT_SECONDARY),
+                    );
+                    ui.separator();
+                }
+            });
+
+        match edit {
+            Some(DeviceEdit::StartRename(key)) => {
+                let current = app
+                    .trust
+                    .get(&key)
+                    .and_then(|d| d.alias.clone())
+                    .unwrap_or_default();
+                app.device_rename = Some((key, current));
+            }
+            Some(DeviceEdit::SaveRename) => {
+                if let Some((key, text)) = app.device_rename.take() {
+                    app.record_trust(|trust| trust.rename(&key, Some(&text)));
+                }
+            }
+            Some(DeviceEdit::CancelRename) => app.device_rename = None,
+            Some(DeviceEdit::Reverify(key)) => {
+                app.record_trust(|trust| trust.require_reverification(&key));
+            }
+            Some(DeviceEdit::Revoke(key)) => {
+                app.record_trust(|trust| trust.revoke(&key));
+            }
+            None => {}
+        }
+    });
+}
+
+/// "just now", "5m ago", "3h ago", "12d ago".
+fn format_ago(now_ms: u64, then_ms: u64) -> String {
+    let secs = now_ms.saturating_sub(then_ms) / 1000;
+    match secs {
+        0..60 => "just now".to_string(),
+        60..3_600 => format!("{}m ago", secs / 60),
+        3_600..86_400 => format!("{}h ago", secs / 3_600),
+        _ => format!("{}d ago", secs / 86_400),
+    }
+}
@@ -1,11 +1,16 @@
 use eframe::egui;
 
+use bolt_app_core::contracts::session_contract::SessionPhase;
+use bolt_app_core::peer_sessions::SessionId;
+
 use crate::app::BoltApp;
+use crate::session;
 use crate::state::*;
 use crate::theme;
 
 /// Single-card progressive flow — matches website's one-card architecture.
-/// State drives what's shown. No tabs.
+/// Each peer session gets a tab above the card; the selected session's
+/// state drives what's shown, and "+" shows the device list.
 pub fn show(ui: &mut egui::Ui, app: &mut BoltApp) {
     ui.vertical_centered(|ui| {
         // ── Encryption badge (always visible, matches website) ──
@@ -31,42 +36,98 @@ pub fn show(ui: &mut egui::Ui, app: &mut BoltApp) {
                 );
                 ui.add_space(theme::SPACING_MD);
             }
+            if let Some(notice) = &app.session_notice {
+                show_error(ui, notice);
+                ui.add_space(theme::SPACING_MD);
+            }
+
+            // Incoming request takes the card until answered
+            if app.incoming_request.is_some() {
+                show_incoming_request(ui, app);
+                return;
+            }
+
+            if !app.sessions.is_empty() {
+                show_session_tabs(ui, app);
+                ui.add_space(theme::SPACING_MD);
+            }
 
             // ── State-driven card content ─────────────────────
-            match &app.connection {
-                ConnectionState::Connected => {
-                    show_connected(ui, app);
-                }
-                ConnectionState::Requesting { .. } => {
-                    show_requesting(ui, app);
-                }
-                ConnectionState::Establishing { .. } => {
-                    show_establishing(ui, app);
-                }
-                ConnectionState::Error(msg) => {
-                    let msg = msg.clone();
-                    show_error(ui, &msg);
-                    ui.add_space(theme::SPACING_LG);
-                    show_device_list(ui, app);
-                }
-                ConnectionState::TimedOut => {
-                    show_error(ui, "Connection timed out");
-                    ui.add_space(theme::SPACING_LG);
-                    show_device_list(ui, app);
-                }
-                ConnectionState::Idle => {
-                    // Check for incoming request first
-                    if app.incoming_request.is_some() {
-                        show_incoming_request(ui, app);
-                    } else {
-                        show_device_list(ui, app);
-                    }
-                }
+            match app.sessions.selected_id() {
+                Some(id) => show_session(ui, app, id),
+                None => show_device_list(ui, app),
             }
         });
 
-        // ── Manual pair fallback (below main card) ───────────
+        // ── Transfer queue / history (below main card) ────────
         ui.add_space(theme::SPACING_LG);
+        let queued = app.queue().pending().len();
+        let label = match (app.show_transfers, queued) {
+            (true, _) => "\u{25BC} Transfers".to_string(),
+            (false, 0) => "\u{25B6} Transfers".to_string(),
+            (false, n) => format!("\u{25B6} Transfers ({n} queued)"),
+        };
+        if ui
+            .small_button(
+                egui::RichText::new(label)
+                    .size(theme::FONT_SIZE_SMALL)
+                    .color(theme::TEXT_MUTED),
+            )
+            .clicked()
+        {
+            app.show_transfers = !app.show_transfers;
+        }
+        if app.show_transfers {
+            ui.add_space(theme::SPACING_SM);
+            super::transfers::show(ui, app);
+        }
+
+        // ── Trusted devices (below main card) ────────────────
+        ui.add_space(theme::SPACING_SM);
+        if ui
+            .small_button(
+                egui::RichText::new(if app.show_devices {
+                    "\u{25BC} Trusted Devices"
+                } else {
+                    "\u{25B6} Trusted Devices"
+                })
+                .size(theme::FONT_SIZE_SMALL)
+                .color(theme::TEXT_MUTED),
+            )
+            .clicked()
+        {
+            app.show_devices = !app.show_devices;
+            app.device_rename = None;
+        }
+        if app.show_devices {
+            ui.add_space(theme::SPACING_SM);
+            super::devices::show(ui, app);
+        }
+
+        // ── Settings (below main card) ───────────────────────
+        ui.add_space(theme::SPACING_SM);
+        if ui
+            .small_button(
+                egui::RichText::new(if app.show_settings {
+                    "\u{25BC} Settings"
+                } else {
+                    "\u{25B6} Settings"
+                })
+                .size(theme::FONT_SIZE_SMALL)
+                .color(theme::TEXT_MUTED),
+            )
+            .clicked()
+        {
+            app.show_settings = !app.show_settings;
+            app.settings_draft = None;
+        }
+        if app.show_settings {
+            ui.add_space(theme::SPACING_SM);
+            super::settings::show(ui, app);
+        }
+
+        // ── Manual pair fallback (below main card) ───────────
+        ui.add_space(theme::SPACING_SM);
         if ui
             .small_button(
                 egui::RichText::new(if app.show_manual_pair {
@@ -89,6 +150,113 @@ pub fn show(ui: &mut egui::Ui, app: &mut BoltApp) {
     });
 }
 
+// ── Session tabs ─────────────────────────────────────────────
+
+/// One tab per peer session with its status dot; "+" opens the device list.
+fn show_session_tabs(ui: &mut egui::Ui, app: &mut BoltApp) {
+    let selected = app.sessions.selected_id();
+    let mut select = None;
+    ui.horizontal_wrapped(|ui| {
+        for session in app.sessions.iter() {
+            let status = session::status_text(session);
+            theme::status_dot(ui, status_color(session), is_busy(session), &status);
+            let active = selected == Some(session.id);
+            let color = if active { theme::ACCENT } else { theme::TEXT_SECONDARY };
+            if ui
+                .selectable_label(
+                    active,
+                    egui::RichText::new(&session.peer_name)
+                        .size(theme::FONT_SIZE_SMALL)
+                        .color(color),
+                )
+                .on_hover_text(status)
+                .clicked()
+            {
+                select = Some(Some(session.id));
+            }
+        }
+        if ui
+            .add_enabled(
+                !app.sessions.is_full(),
+                egui::Button::new(
+                    egui::RichText::new("+")
+                        .size(theme::FONT_SIZE_SMALL)
+                        .color(theme::ACCENT_DIM),
+                ),
+            )
+            .on_hover_text("Connect another device")
+            .clicked()
+        {
+            select = Some(None);
+        }
+    });
+    if let Some(id) = select {
+        app.sessions.select(id);
+    }
+}
+
+fn status_color(session: &session::Session) -> egui::Color32 {
+    match &session.transport.connection {
+        ConnectionState::Connected => match session::verify_state(session) {
+            VerifyState::Pending { .. } => theme::WARNING,
+            _ => theme::SUCCESS,
+        },
+        ConnectionState::Requesting { .. } => theme::WARNING,
+        ConnectionState::Establishing { .. } => theme::ACCENT,
+        ConnectionState::Idle => theme::TEXT_MUTED,
+        ConnectionState::TimedOut | ConnectionState::Error(_) => theme::ERROR,
+    }
+}
+
+fn is_busy(session: &session::Session) -> bool {
+    session.transport.connection.is_connecting()
+        || matches!(
+            session.transport.transfer,
+            TransferState::Sending { .. } | TransferState::Receiving { .. }
+        )
+}
+
+/// Card content for one peer session.
+fn show_session(ui: &mut egui::Ui, app: &mut BoltApp, id: SessionId) {
+    let Some(connection) = app.sessions.get(id).map(|s| s.transport.connection.clone()) else {
+        return;
+    };
+    if app.sessions.get(id).is_some_and(|s| s.transport.pairing_request.is_some()) {
+        show_pairing_request(ui, app, id);
+        return;
+    }
+    match &connection {
+        ConnectionState::Connected => {
+            show_connected(ui, app, id);
+        }
+        ConnectionState::Requesting { .. } => {
+            show_requesting(ui, app, id, &connection);
+        }
+        ConnectionState::Establishing { .. } => {
+            show_establishing(ui, app, id, &connection);
+        }
+        ConnectionState::Error(msg) => {
+            show_error(ui, msg);
+            show_ended(ui, app, id);
+        }
+        ConnectionState::TimedOut => {
+            show_error(ui, "Connection timed out");
+            show_ended(ui, app, id);
+        }
+        ConnectionState::Idle => {
+            show_ended(ui, app, id);
+        }
+    }
+}
+
+/// A failed or ended session stays as a tab until closed.
+fn show_ended(ui: &mut egui::Ui, app: &mut BoltApp, id: SessionId) {
+    ui.add_space(theme::SPACING_LG);
+    if ui.add(theme::danger_button("CLOSE")).clicked() {
+        app.disconnect(id);
+    }
+}
+
 // ── Device list (discovery) ──────────────────────────────────
 
 fn show_device_list(ui: &mut egui::Ui, app: &mut BoltApp) {
@@ -107,6 +275,11 @@ fn show_device_list(ui: &mut egui::Ui, app: &mut BoltApp) {
     } else {
         let mut selected_peer = None;
         for peer in &app.discovered_peers {
+            // Peers with a session show its status and open its tab
+            let session = app
+                .sessions
+                .find_peer(&peer.peer_code)
+                .and_then(|id| app.sessions.get(id));
             let resp = ui.horizontal(|ui| {
                 ui.label(
                     egui::RichText::new(peer.device_type.icon())
@@ -124,6 +297,13 @@ fn show_device_list(ui: &mut egui::Ui, app: &mut BoltApp) {
                             .size(theme::FONT_SIZE_SMALL)
                             .color(theme::ACCENT_DIM),
                     );
+                    if let Some(session) = session {
+                        ui.label(
+                            egui::RichText::new(session::status_text(session))
+                                .size(theme::FONT_SIZE_SMALL)
+                                .color(status_color(session)),
+                        );
+                    }
                 });
             });
             if resp.response.interact(egui::Sense::click()).clicked() {
@@ -139,8 +319,8 @@ fn show_device_list(ui: &mut egui::Ui, app: &mut BoltApp) {
 
 // ── Requesting (waiting for acceptance) ──────────────────────
 
-fn show_requesting(ui: &mut egui::Ui, app: &mut BoltApp) {
-    let text = app.connection.status_text();
+fn show_requesting(ui: &mut egui::Ui, app: &mut BoltApp, id: SessionId, connection: &ConnectionState) {
+    let text = connection.status_text();
     ui.label(
         egui::RichText::new(&text)
             .size(theme::FONT_SIZE_BODY)
@@ -150,14 +330,14 @@ fn show_requesting(ui: &mut egui::Ui, app: &mut BoltApp) {
     theme::status_dot(ui, theme::WARNING, true, "Waiting...");
     ui.add_space(theme::SPACING_LG);
     if ui.add(theme::danger_button("CANCEL")).clicked() {
-        app.cancel_request();
+        app.cancel_request(id);
     }
 }
 
 // ── Establishing (daemon spawning / WebRTC handshake) ────────
 
-fn show_establishing(ui: &mut egui::Ui, app: &mut BoltApp) {
-    let text = app.connection.status_text();
+fn show_establishing(ui: &mut egui::Ui, app: &mut BoltApp, id: SessionId, connection: &ConnectionState) {
+    let text = connection.status_text();
     ui.label(
         egui::RichText::new(&text)
             .size(theme::FONT_SIZE_BODY)
@@ -167,7 +347,7 @@ fn show_establishing(ui: &mut egui::Ui, app: &mut BoltApp) {
     theme::status_dot(ui, theme::ACCENT, true, "Establishing...");
     ui.add_space(theme::SPACING_LG);
     if ui.add(theme::danger_button("CANCEL")).clicked() {
-        app.cancel_connect();
+        app.cancel_connect(id);
     }
 }
 
@@ -207,16 +387,47 @@ fn show_incoming_request(ui: &mut egui::Ui, app: &mut BoltApp) {
     });
 }
 
+// ── Pairing request (--pairing-policy ask) ───────────────────
+
+fn show_pairing_request(ui: &mut egui::Ui, app: &mut BoltApp, id: SessionId) {
+    let Some(req) = app.sessions.get(id).and_then(|s| s.transport.pairing_request.as_ref()) else {
+        return;
+    };
+    ui.label(
+        egui::RichText::new(format!("{} wants to pair", req.remote_device_name))
+            .size(theme::FONT_SIZE_HEADING)
+            .color(theme::TEXT_PRIMARY),
+    );
+    ui.label(
+        egui::RichText::new("This device has not connected before")
+            .size(theme::FONT_SIZE_SMALL)
+            .color(theme::TEXT_SECONDARY),
+    );
+    ui.add_space(theme::SPACING_SM);
+    theme::field_row(ui, "Code", &req.sas);
+    ui.add_space(theme::SPACING_LG);
+    ui.horizontal(|ui| {
+        if ui.add(theme::danger_button("DENY")).clicked() {
+            app.answer_pairing(id, false);
+        }
+        ui.add_space(theme::SPACING_MD);
+        if ui.add(theme::primary_button("ALLOW")).clicked() {
+            app.answer_pairing(id, true);
+        }
+    });
+}
+
 // ── Connected ────────────────────────────────────────────────
 
-fn show_connected(ui: &mut egui::Ui, app: &mut BoltApp) {
-    let (name, icon) = {
-        let cp = app.connected_peer.as_ref();
-        (
-            cp.map(|p| p.device_name.clone()).unwrap_or_else(|| "Peer".into()),
-            cp.map(|p| p.device_type.icon().to_string()).unwrap_or_else(|| "\u{25CB}".into()),
-        )
+fn show_connected(ui: &mut egui::Ui, app: &mut BoltApp, id: SessionId) {
+    let Some(session) = app.sessions.get(id) else {
+        return;
     };
+    let name = session.peer_name.clone();
+    let icon = session.transport.device_type.icon().to_string();
+    let verify = session::verify_state(session);
+    let transfer_allowed = session.phase() == SessionPhase::Connected && session.transfer_allowed();
+    let transfer = session.transport.transfer.clone();
 
     // Connected peer row
     ui.horizontal(|ui| {
@@ -232,7 +443,7 @@ fn show_connected(ui: &mut egui::Ui, app: &mut BoltApp) {
         );
         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
             if ui.add(theme::danger_button("DISCONNECT")).clicked() {
-                app.disconnect();
+                app.disconnect(id);
             }
         });
     });
@@ -240,7 +451,7 @@ fn show_connected(ui: &mut egui::Ui, app: &mut BoltApp) {
     ui.add_space(theme::SPACING_LG);
 
     // Verification state (inline, matches website verification-gated flow)
-    match &app.verify {
+    match &verify {
         VerifyState::Pending { sas_code } => {
             let formatted = sas_code
                 .chars()
@@ -261,9 +472,7 @@ fn show_connected(ui: &mut egui::Ui, app: &mut BoltApp) {
             ui.add_space(theme::SPACING_SM);
             ui.horizontal(|ui| {
                 if ui.add(theme::primary_button("Mark Verified")).clicked() {
-                    tracing::info!("[UI] user marked peer as verified");
-                    app.verify = VerifyState::Confirmed;
-                    app.transfer = TransferState::Ready;
+                    app.mark_session_verified(id);
                 }
                 ui.add_space(theme::SPACING_SM);
                 if ui
@@ -274,15 +483,7 @@ fn show_connected(ui: &mut egui::Ui, app: &mut BoltApp) {
                     ))
                     .clicked()
                 {
-                    tracing::info!("[UI] user rejected peer verification");
-                    app.verify = VerifyState::Rejected;
-                    // Disconnect — rejected peer should not remain connected
-                    if let Some(mut proc) = app.daemon_proc.take() {
-                        proc.kill();
-                    }
-                    app.ipc_client = None;
-                    app.connection = ConnectionState::Idle;
-                    app.transfer = TransferState::Idle;
+                    app.reject_verification(id);
                 }
             });
         }
@@ -312,39 +513,31 @@ fn show_connected(ui: &mut egui::Ui, app: &mut BoltApp) {
     ui.add_space(theme::SPACING_LG);
 
     // Transfer state — only shown when verification allows it (matches web policy)
-    if app.verify.is_transfer_allowed() {
-        match &app.transfer {
+    if transfer_allowed {
+        match &transfer {
             TransferState::Ready => {
                 theme::section_label(ui, "transfer");
                 ui.add_space(theme::SPACING_SM);
-                if ui.add(theme::primary_button("SEND FILE")).clicked() {
-                    if let Some(path) = rfd::FileDialog::new().pick_file() {
-                        let file_name = path.file_name()
-                            .map(|n| n.to_string_lossy().to_string())
-                            .unwrap_or_else(|| "file".into());
-                        let path_str = path.display().to_string();
-                        tracing::info!("[UI] file selected: {} ({})", file_name, path_str);
-
-                        // Signal daemon to send file via signal file
-                        let signal_path = format!("{}/send_file.signal", app.data_dir());
-                        match std::fs::write(&signal_path, &path_str) {
-                            Ok(()) => {
-                                tracing::info!("[UI] wrote send signal: {signal_path}");
-                                app.transfer = TransferState::Sending {
-                                    file_name,
-                                    progress: 0.0,
-                                };
-                            }
-                            Err(e) => {
-                                tracing::error!("[UI] failed to write send signal: {e}");
-                                app.transfer = TransferState::Failed {
-                                    file_name,
-                                    reason: format!("Signal error: {e}"),
-                                };
-                            }
+                ui.horizontal(|ui| {
+                    if ui.add(theme::primary_button("SEND FILES")).clicked() {
+                        if let Some(paths) = rfd::FileDialog::new().pick_files() {
+                            tracing::info!("[UI] {} file(s) selected", paths.len());
+                            app.enqueue_paths(&paths);
                         }
                     }
-                }
+                    ui.add_space(theme::SPACING_SM);
+                    if ui.add(theme::primary_button("SEND FOLDER")).clicked() {
+                        if let Some(path) = rfd::FileDialog::new().pick_folder() {
+                            tracing::info!("[UI] folder selected: {}", path.display());
+                            app.enqueue_paths(&[path]);
+                        }
+                    }
+                });
+                ui.label(
+                    egui::RichText::new("or drop files and folders onto the window")
+                        .size(theme::FONT_SIZE_SMALL)
+                        .color(theme::TEXT_MUTED),
+                );
             }
             TransferState::Sending { file_name, progress } => {
                 theme::section_label(ui, "sending");
@@ -354,6 +547,8 @@ fn show_connected(ui: &mut egui::Ui, app: &mut BoltApp) {
                         .text(format!("{:.0}%", progress * 100.0))
                         .desired_width(320.0),
                 );
+                show_batch_progress(ui, app, id);
+                show_controls(ui, app, id);
             }
             TransferState::Receiving { file_name, progress } => {
                 theme::section_label(ui, "receiving");
@@ -363,6 +558,7 @@ fn show_connected(ui: &mut egui::Ui, app: &mut BoltApp) {
                         .text(format!("{:.0}%", progress * 100.0))
                         .desired_width(320.0),
                 );
+                show_controls(ui, app, id);
             }
             TransferState::Complete { file_name, save_path } => {
                 ui.label(
@@ -390,7 +586,7 @@ fn show_connected(ui: &mut egui::Ui, app: &mut BoltApp) {
                 }
                 ui.add_space(theme::SPACING_SM);
                 if ui.add(theme::primary_button("SEND ANOTHER")).clicked() {
-                    app.transfer = TransferState::Ready;
+                    app.reset_transfer(id);
                 }
             }
             TransferState::Failed { file_name, reason } => {
@@ -401,7 +597,7 @@ fn show_connected(ui: &mut egui::Ui, app: &mut BoltApp) {
                 );
                 ui.add_space(theme::SPACING_SM);
                 if ui.add(theme::primary_button("TRY AGAIN")).clicked() {
-                    app.transfer = TransferState::Ready;
+                    app.reset_transfer(id);
                 }
             }
             TransferState::Idle => {}
@@ -409,6 +605,55 @@ fn show_connected(ui: &mut egui::Ui, app: &mut BoltApp) {
     }
 }
 
+/// Combined progress of a multi-file batch, or the queue length otherwise.
+fn show_batch_progress(ui: &mut egui::Ui, app: &BoltApp, id: SessionId) {
+    if let Some((batch, progress)) = app.batch_progress() {
+        ui.add_space(theme::SPACING_SM);
+        theme::field_row(
+            ui,
+            "Batch",
+            &format!("{} — {}/{} files", batch.label, batch.finished_files(), batch.total_files),
+        );
+        ui.add(
+            egui::ProgressBar::new(progress)
+                .text(format!("{:.0}%", progress * 100.0))
+                .desired_width(320.0),
+        );
+        if batch.failed_files > 0 {
+            show_error(ui, &format!("{} failed", batch.failed_files));
+        }
+        return;
+    }
+    let queued = app.sessions.get(id).map_or(0, |s| s.queue.pending().len());
+    if queued > 0 {
+        ui.label(
+            egui::RichText::new(format!("{queued} more queued"))
+                .size(theme::FONT_SIZE_SMALL)
+                .color(theme::TEXT_SECONDARY),
+        );
+    }
+}
+
+/// Pause/resume and cancel buttons for the active transfer, plus any
+/// rejected or unconfirmed action notice.
+fn show_controls(ui: &mut egui::Ui, app: &mut BoltApp, id: SessionId) {
+    ui.add_space(theme::SPACING_SM);
+    if let Some(notice) = app.sessions.get(id).and_then(|s| s.transport.transfer_notice.as_ref()) {
+        show_error(ui, notice);
+    }
+    let enabled = app.can_cancel_transfer(id);
+    let pause_label = if app.is_transfer_paused(id) { "RESUME" } else { "PAUSE" };
+    ui.horizontal(|ui| {
+        if ui.add_enabled(enabled, theme::primary_button(pause_label)).clicked() {
+            app.request_pause_toggle(id);
+        }
+        ui.add_space(theme::SPACING_SM);
+        if ui.add_enabled(enabled, theme::danger_button("CANCEL")).clicked() {
+            app.request_cancel(id);
+        }
+    });
+}
+
 // ── Error state ──────────────────────────────────────────────
 
 fn show_error(ui: &mut egui::Ui, msg: &str) {
@@ -446,7 +691,7 @@ fn show_manual_pair(ui: &mut egui::Ui, app: &mut BoltApp) {
 
         match app.mode {
             ConnectMode::Host => {
-                if app.host_info.is_none() && app.connection == ConnectionState::Idle {
+                if app.host_info.is_none() {
                     if ui
                         .add_enabled(app.prereq_error.is_none(), theme::primary_button("CREATE SESSION"))
                         .clicked()
@@ -459,7 +704,7 @@ fn show_manual_pair(ui: &mut egui::Ui, app: &mut BoltApp) {
                     theme::field_row(ui, "Session", &info.session);
                     theme::field_row(ui, "Code", &info.peer_code);
 
-                    if matches!(app.connection, ConnectionState::Idle) {
+                    if !app.sessions.is_full() {
                         ui.add_space(theme::SPACING_SM);
                         let mut joiner = app.join_peer_code.clone();
                         ui.add(
@@ -1,3 +1,8 @@
 // Single-card progressive flow — matches website's one-card model.
-// No tabs/screens. State progression drives what's shown.
+// Peer sessions show as tabs above the card; the selected session's state
+// progression drives what's shown. The transfer queue/history, trusted
+// devices and settings panels fold out below the card like manual pairing.
+pub mod devices;
 pub mod main_card;
+pub mod settings;
+pub mod transfers;
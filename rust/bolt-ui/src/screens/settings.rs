@@ -0,0 +1,216 @@
+use std::path::PathBuf;
+
+use eframe::egui;
+
+use bolt_app_core::settings::{
+    FieldError, PairingPolicy, Settings, SettingsError, SettingsField, CLOUD_SIGNAL_ENV,
+    MAX_DEVICE_NAME_CHARS, RENDEZVOUS_ENV,
+};
+
+use crate::app::BoltApp;
+use crate::theme;
+
+/// Settings form being edited. Text fields stay as typed until saved;
+/// empty optional fields mean "use the default".
+pub struct Draft {
+    device_name: String,
+    rendezvous_addr: String,
+    cloud_signaling: bool,
+    cloud_signal_url: String,
+    /// Not editable yet (the daemon has no download folder flag).
+    download_dir: Option<PathBuf>,
+    pairing_policy: PairingPolicy,
+    errors: Vec<FieldError>,
+    status: Option<Result<(), String>>,
+}
+
+impl Draft {
+    fn from_settings(s: &Settings) -> Self {
+        Self {
+            device_name: s.device_name.clone().unwrap_or_default(),
+            rendezvous_addr: s.rendezvous_addr.clone(),
+            cloud_signaling: s.cloud_signaling,
+            cloud_signal_url: s.cloud_signal_url.clone(),
+            download_dir: s.download_dir.clone(),
+            pairing_policy: s.pairing_policy,
+            errors: Vec::new(),
+            status: None,
+        }
+    }
+
+    fn to_settings(&self) -> Settings {
+        let optional = |v: &str| Some(v.trim()).filter(|v| !v.is_empty()).map(String::from);
+        Settings {
+            device_name: optional(&self.device_name),
+            rendezvous_addr: self.rendezvous_addr.trim().to_string(),
+            cloud_signaling: self.cloud_signaling,
+            cloud_signal_url: self.cloud_signal_url.trim().to_string(),
+            download_dir: self.download_dir.clone(),
+            pairing_policy: self.pairing_policy,
+            ..Settings::default()
+        }
+    }
+
+    fn error(&self, field: SettingsField) -> Option<&str> {
+        self.errors
+            .iter()
+            .find(|e| e.field == field)
+            .map(|e| e.message.as_str())
+    }
+}
+
+/// Settings panel (below the main card).
+pub fn show(ui: &mut egui::Ui, app: &mut BoltApp) {
+    let mut draft = app
+        .settings_draft
+        .take()
+        .unwrap_or_else(|| Draft::from_settings(app.settings.current()));
+
+    theme::inset_frame().show(ui, |ui| {
+        ui.set_min_width(340.0);
+        theme::section_label(ui, "settings");
+        ui.add_space(theme::SPACING_SM);
+
+        if let Some(err) = &app.settings_error {
+            note(
+                ui,
+                &format!("! settings file not loaded; changes cannot be saved: {err}"),
+                theme::ERROR,
+            );
+        }
+
+        theme::section_label(ui, "device");
+        text_field(
+            ui,
+            "Name",
+            &mut draft.device_name,
+            "this computer's name",
+            MAX_DEVICE_NAME_CHARS,
+        );
+        field_error(ui, &draft, SettingsField::DeviceName);
+
+        ui.add_space(theme::SPACING_MD);
+        theme::section_label(ui, "signaling");
+        text_field(ui, "Local", &mut draft.rendezvous_addr, "127.0.0.1:3001", 0);
+        field_error(ui, &draft, SettingsField::RendezvousAddr);
+        env_override_note(ui, RENDEZVOUS_ENV);
+        ui.checkbox(
+            &mut draft.cloud_signaling,
+            egui::RichText::new("Cloud signaling (find peers beyond this network)")
+                .size(theme::FONT_SIZE_SMALL)
+                .color(theme::TEXT_SECONDARY),
+        );
+        if draft.cloud_signaling {
+            text_field(ui, "Cloud", &mut draft.cloud_signal_url, "wss://…", 0);
+            field_error(ui, &draft, SettingsField::CloudSignalUrl);
+            env_override_note(ui, CLOUD_SIGNAL_ENV);
+        }
+
+        ui.add_space(theme::SPACING_MD);
+        theme::section_label(ui, "pairing");
+        ui.horizontal(|ui| {
+            label(ui, "New peers");
+            for policy in PairingPolicy::ALL {
+                let active = draft.pairing_policy == policy;
+                let color = if active {
+                    theme::ACCENT
+                } else {
+                    theme::TEXT_MUTED
+                };
+                if ui
+                    .selectable_label(
+                        active,
+                        egui::RichText::new(policy.as_arg().to_uppercase())
+                            .size(theme::FONT_SIZE_SMALL)
+                            .color(color),
+                    )
+                    .clicked()
+                {
+                    draft.pairing_policy = policy;
+                }
+            }
+        });
+        note(
+            ui,
+            "Pairing policy changes apply to the next connection.",
+            theme::TEXT_MUTED,
+        );
+
+        ui.add_space(theme::SPACING_MD);
+        ui.horizontal(|ui| {
+            let writable = app.settings.read_only_reason().is_none();
+            if ui.add_enabled(writable, theme::primary_button("SAVE")).clicked() {
+                match app.save_settings(draft.to_settings()) {
+                    Ok(()) => {
+                        draft = Draft::from_settings(app.settings.current());
+                        draft.status = Some(Ok(()));
+                    }
+                    Err(SettingsError::Invalid(errors)) => {
+                        draft.errors = errors;
+                        draft.status = None;
+                    }
+                    Err(e) => draft.status = Some(Err(e.to_string())),
+                }
+            }
+            if ui.small_button("Revert").clicked() {
+                draft = Draft::from_settings(app.settings.current());
+            }
+            if ui.small_button("Defaults").clicked() {
+                draft = Draft::from_settings(&Settings::default());
+            }
+        });
+        match &draft.status {
+            Some(Ok(())) => note(ui, "Saved", theme::SUCCESS),
+            Some(Err(e)) => note(ui, &format!("! {e}"), theme::ERROR),
+            None => {}
+        }
+    });
+
+    app.settings_draft = Some(draft);
+}
+
+fn label(ui: &mut egui::Ui, text: &str) {
+    ui.label(
+        egui::RichText::new(format!("{text}:"))
+            .size(theme::FONT_SIZE_SMALL)
+            .color(theme::TEXT_SECONDARY),
+    );
+}
+
+/// Labelled single-line input. `char_limit` 0 means unlimited.
+fn text_field(ui: &mut egui::Ui, name: &str, value: &mut String, hint: &str, char_limit: usize) {
+    ui.horizontal(|ui| {
+        label(ui, name);
+        let mut edit = egui::TextEdit::singleline(value)
+            .hint_text(hint)
+            .desired_width(220.0);
+        if char_limit > 0 {
+            edit = edit.char_limit(char_limit);
+        }
+        ui.add(edit);
+    });
+}
+
+fn field_error(ui: &mut egui::Ui, draft: &Draft, field: SettingsField) {
+    if let Some(message) = draft.error(field) {
+        note(ui, &format!("! {message}"), theme::ERROR);
+    }
+}
+
+fn env_override_note(ui: &mut egui::Ui, var: &str) {
+    if std::env::var_os(var).is_some() {
+        note(
+            ui,
+            &format!("Overridden by {var} for this run"),
+            theme::WARNING,
+        );
+    }
+}
+
+fn note(ui: &mut egui::Ui, text: &str, color: egui::Color32) {
+    ui.label(
+        egui::RichText::new(text)
+            .size(theme::FONT_SIZE_SMALL)
+            .color(color),
+    );
+}
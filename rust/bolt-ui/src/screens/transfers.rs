@@ -0,0 +1,230 @@
+use eframe::egui;
+
+use bolt_app_core::ipc_types::TransferDirection;
+use bolt_app_core::transfer_history::TransferResult;
+use bolt_app_core::transfer_queue::QueueItemId;
+
+use crate::app::BoltApp;
+use crate::theme;
+
+/// Queue edit requested by a row button; applied after the list is drawn.
+enum QueueEdit {
+    Up(QueueItemId),
+    Down(QueueItemId),
+    Remove(QueueItemId),
+}
+
+/// Transfer queue and history panel (below the main card).
+pub fn show(ui: &mut egui::Ui, app: &mut BoltApp) {
+    theme::inset_frame().show(ui, |ui| {
+        ui.set_min_width(340.0);
+        show_queue(ui, app);
+        ui.add_space(theme::SPACING_LG);
+        show_history(ui, app);
+    });
+}
+
+// ── Queue ────────────────────────────────────────────────────
+
+fn show_queue(ui: &mut egui::Ui, app: &mut BoltApp) {
+    theme::section_label(ui, "queue");
+    ui.add_space(theme::SPACING_SM);
+
+    if let Some(active) = app.queue().active() {
+        let arrow = direction_arrow(active.direction);
+        ui.label(
+            egui::RichText::new(format!("{arrow} {} — in progress", active.file_name))
+                .size(theme::FONT_SIZE_BODY)
+                .color(theme::ACCENT),
+        );
+    }
+
+    let mut edit = None;
+    let last = app.queue().pending().len().saturating_sub(1);
+    for (i, item) in app.queue().pending().iter().enumerate() {
+        ui.horizontal(|ui| {
+            ui.label(
+                egui::RichText::new(format!("{}. {}", i + 1, item.file_name))
+                    .size(theme::FONT_SIZE_BODY)
+                    .color(theme::TEXT_PRIMARY),
+            );
+            if let Some(size) = item.size_bytes {
+                ui.label(
+                    egui::RichText::new(format_size(size))
+                        .size(theme::FONT_SIZE_SMALL)
+                        .color(theme::TEXT_SECONDARY),
+                );
+            }
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui
+                    .small_button("\u{2715}")
+                    .on_hover_text("Remove")
+                    .clicked()
+                {
+                    edit = Some(QueueEdit::Remove(item.id));
+                }
+                if ui
+                    .add_enabled(i < last, egui::Button::new("\u{25BC}").small())
+                    .clicked()
+                {
+                    edit = Some(QueueEdit::Down(item.id));
+                }
+                if ui
+                    .add_enabled(i > 0, egui::Button::new("\u{25B2}").small())
+                    .clicked()
+                {
+                    edit = Some(QueueEdit::Up(item.id));
+                }
+            });
+        });
+    }
+    match edit {
+        Some(QueueEdit::Up(id)) => {
+            app.queue_mut().move_up(id);
+        }
+        Some(QueueEdit::Down(id)) => {
+            app.queue_mut().move_down(id);
+        }
+        Some(QueueEdit::Remove(id)) => {
+            app.queue_mut().remove(id);
+        }
+        None => {}
+    }
+
+    if app.queue().is_empty() {
+        ui.label(
+            egui::RichText::new("Nothing queued")
+                .size(theme::FONT_SIZE_SMALL)
+                .color(theme::TEXT_MUTED),
+        );
+    }
+
+    ui.add_space(theme::SPACING_SM);
+    ui.horizontal(|ui| {
+        if ui.add(theme::primary_button("ADD FILES")).clicked() {
+            if let Some(paths) = rfd::FileDialog::new().pick_files() {
+                app.enqueue_paths(&paths);
+            }
+        }
+        if !app.queue().pending().is_empty() && ui.small_button("Clear queue").clicked() {
+            app.queue_mut().clear_pending();
+        }
+    });
+}
+
+// ── History ──────────────────────────────────────────────────
+
+fn show_history(ui: &mut egui::Ui, app: &mut BoltApp) {
+    theme::section_label(ui, "history");
+    ui.add_space(theme::SPACING_SM);
+
+    if let Some(err) = &app.history_error {
+        ui.label(
+            egui::RichText::new(format!("! history not saved: {err}"))
+                .size(theme::FONT_SIZE_SMALL)
+                .color(theme::ERROR),
+        );
+    }
+
+    if app.history.entries().is_empty() {
+        ui.label(
+            egui::RichText::new("No transfers yet")
+                .size(theme::FONT_SIZE_SMALL)
+                .color(theme::TEXT_MUTED),
+        );
+        return;
+    }
+
+    let mut retry = None;
+    egui::ScrollArea::vertical()
+        .max_height(220.0)
+        .show(ui, |ui| {
+            for entry in app.history.entries().iter().rev() {
+                let (mark, color) = match entry.result {
+                    TransferResult::Completed => ("\u{2713}", theme::SUCCESS),
+                    TransferResult::Failed => ("\u{2717}", theme::ERROR),
+                    TransferResult::Cancelled => ("\u{2298}", theme::WARNING),
+                };
+                ui.horizontal(|ui| {
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "{mark} {} {}",
+                            direction_arrow(entry.direction),
+                            entry.file_name
+                        ))
+                        .size(theme::FONT_SIZE_BODY)
+                        .color(color),
+                    );
+                    if let Some(path) = entry.retry_path() {
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            if ui.small_button("Retry").clicked() {
+                                retry = Some(path);
+                            }
+                        });
+                    }
+                });
+
+                let mut details = vec![entry.peer.clone()];
+                if let Some(size) = entry.size_bytes {
+                    details.push(format_size(size));
+                }
+                details.push(format_duration(entry.duration_ms));
+                if let Some(hash) = &entry.file_hash_hex {
+                    let short: String = hash.chars().take(12).collect();
+                    details.push(format!("sha256 {short}…"));
+                }
+                ui.label(
+                    egui::RichText::new(details.join(" · "))
+                        .size(theme::FONT_SIZE_SMALL)
+                        .color(theme::TEXT_SECONDARY),
+                );
+                if let Some(error) = &entry.error {
+                    ui.label(
+                        egui::RichText::new(error)
+                            .size(theme::FONT_SIZE_SMALL)
+                            .color(theme::TEXT_MUTED),
+                    );
+                }
+                ui.separator();
+            }
+        });
+    if let Some(path) = retry {
+        app.enqueue_file(&path);
+    }
+
+    ui.add_space(theme::SPACING_SM);
+    if ui.add(theme::danger_button("CLEAR HISTORY")).clicked() {
+        app.clear_history();
+    }
+}
+
+fn direction_arrow(direction: TransferDirection) -> &'static str {
+    match direction {
+        TransferDirection::Send => "\u{2191}",
+        TransferDirection::Receive => "\u{2193}",
+    }
+}
+
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+fn format_duration(ms: u64) -> String {
+    let secs = ms / 1000;
+    if secs < 60 {
+        format!("{}.{}s", secs, (ms % 1000) / 100)
+    } else {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    }
+}
@@ -0,0 +1,186 @@
+//! Per-peer session transport for bolt-ui.
+//!
+//! Each [`Session`] is a [`PeerSession`] from bolt-app-core (phase,
+//! verification, queue, daemon resources) whose transport is a
+//! [`DaemonTransport`]: the daemon spawned for that peer, its IPC client and
+//! the UI-facing connection/transfer state derived from its events.
+
+use std::time::Instant;
+
+use bolt_app_core::contracts::session_contract::{SessionPhase, VerificationState};
+use bolt_app_core::ipc_ack::AckHandle;
+use bolt_app_core::ipc_types::PairingRequestPayload;
+use bolt_app_core::peer_sessions::PeerSession;
+
+use crate::daemon::DaemonProcess;
+use crate::ipc::IpcClient;
+use crate::state::*;
+
+pub type Session = PeerSession<DaemonTransport>;
+
+/// Signal pending until daemon endpoints are ready.
+pub struct PendingWsSignal {
+    pub signal_type: String, // "connection_request" or "connection_accepted"
+}
+
+/// Transfer action sent over IPC, awaiting `decision.ack`.
+pub struct PendingAction {
+    pub action: TransferAction,
+    pub handle: AckHandle,
+    pub sent_at: Instant,
+}
+
+/// One peer's daemon and the UI state derived from it.
+pub struct DaemonTransport {
+    pub device_type: DeviceType,
+    pub connection: ConnectionState,
+    pub transfer: TransferState,
+    /// Daemon transfer id of the active transfer (from ack or `transfer.started`).
+    pub active_transfer_id: Option<String>,
+    /// The active transfer is paused (set from pause/resume acks).
+    pub paused: bool,
+    /// Last rejected transfer action that did not end the transfer.
+    pub transfer_notice: Option<String>,
+    pub pending_action: Option<PendingAction>,
+    /// Daemon `pairing.request` waiting for the user (`--pairing-policy ask`).
+    pub pairing_request: Option<PairingRequestPayload>,
+    pub daemon_proc: Option<DaemonProcess>,
+    pub ipc_client: Option<IpcClient>,
+    pub last_ipc_attempt: Option<Instant>,
+    /// Daemon WS/WT endpoint readiness and WT cert hash (SECURE-DIRECT-1),
+    /// from `endpoint.*` IPC events.
+    pub endpoints: EndpointState,
+    /// Signal waiting to be sent once daemon endpoints are ready.
+    pub pending_ws_signal: Option<PendingWsSignal>,
+}
+
+impl DaemonTransport {
+    pub fn new(device_type: DeviceType, connection: ConnectionState) -> Self {
+        Self {
+            device_type,
+            connection,
+            transfer: TransferState::Idle,
+            active_transfer_id: None,
+            paused: false,
+            transfer_notice: None,
+            pending_action: None,
+            pairing_request: None,
+            daemon_proc: None,
+            ipc_client: None,
+            last_ipc_attempt: None,
+            endpoints: EndpointState::default(),
+            pending_ws_signal: None,
+        }
+    }
+
+    /// Kill the daemon and reset endpoint state so the next daemon's cert
+    /// hash is captured fresh (SECURE-DIRECT-1 cert lifecycle fix).
+    pub fn kill_daemon(&mut self) {
+        if let Some(mut proc) = self.daemon_proc.take() {
+            proc.kill();
+        }
+        self.ipc_client = None;
+        self.pairing_request = None;
+        self.last_ipc_attempt = None;
+        self.endpoints = EndpointState::default();
+    }
+}
+
+/// Set the UI connection state and move the contract phase with it.
+pub fn set_connection(session: &mut Session, connection: ConnectionState) {
+    let phase = match &connection {
+        ConnectionState::Requesting { .. } => SessionPhase::Requesting,
+        ConnectionState::Establishing { .. } => SessionPhase::Connecting,
+        ConnectionState::Connected => SessionPhase::Connected,
+        ConnectionState::Idle | ConnectionState::TimedOut | ConnectionState::Error(_) => {
+            SessionPhase::Idle
+        }
+    };
+    // A browser peer may connect before its acceptance signal arrives.
+    if phase == SessionPhase::Connected && session.phase() == SessionPhase::Requesting {
+        let _ = session.set_phase(SessionPhase::Connecting);
+    }
+    if let Err(e) = session.set_phase(phase) {
+        tracing::warn!("[UI] {e}");
+    }
+    session.transport.connection = connection;
+}
+
+/// Verification view for the connected-card UI.
+pub fn verify_state(session: &Session) -> VerifyState {
+    if session.phase() != SessionPhase::Connected {
+        return VerifyState::NotStarted;
+    }
+    match session.verification() {
+        VerificationState::Legacy => VerifyState::Legacy,
+        VerificationState::Verified => VerifyState::Confirmed,
+        VerificationState::Unverified => VerifyState::Pending {
+            sas_code: session.sas().unwrap_or("------").to_string(),
+        },
+    }
+}
+
+/// Short per-peer status for the session tabs and device list.
+pub fn status_text(session: &Session) -> String {
+    let t = &session.transport;
+    match (&t.connection, &t.transfer) {
+        (ConnectionState::Connected, TransferState::Sending { progress, .. }) => {
+            format!("Sending {:.0}%", progress * 100.0)
+        }
+        (ConnectionState::Connected, TransferState::Receiving { progress, .. }) => {
+            format!("Receiving {:.0}%", progress * 100.0)
+        }
+        (ConnectionState::Connected, _) => match session.verification() {
+            VerificationState::Unverified => "Verify code".to_string(),
+            VerificationState::Verified => "Verified".to_string(),
+            VerificationState::Legacy => "Legacy Peer".to_string(),
+        },
+        (ConnectionState::Requesting { .. }, _) => "Waiting to accept".to_string(),
+        (ConnectionState::Establishing { .. }, _) => "Connecting".to_string(),
+        (ConnectionState::Idle, _) => "Ended".to_string(),
+        (ConnectionState::TimedOut, _) => "Timed out".to_string(),
+        (ConnectionState::Error(_), _) => "Failed".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bolt_app_core::peer_sessions::PeerSessions;
+
+    #[test]
+    fn connection_drives_phase_and_verify_view() {
+        let mut sessions = PeerSessions::new("/tmp/bolt-ui-test", 9100, 4);
+        let requesting = ConnectionState::Requesting {
+            peer_code: "AB3DEF".into(),
+            peer_name: "Laptop".into(),
+            started_at: Instant::now(),
+        };
+        let id = sessions
+            .open(
+                "AB3DEF",
+                "Laptop",
+                SessionPhase::Requesting,
+                DaemonTransport::new(DeviceType::Laptop, requesting),
+            )
+            .unwrap();
+        let session = sessions.get_mut(id).unwrap();
+        assert_eq!(verify_state(session), VerifyState::NotStarted);
+
+        set_connection(session, ConnectionState::Connected);
+        assert_eq!(session.phase(), SessionPhase::Connected);
+        session.on_identity(Some("key".into()), false);
+        session.on_sas("A1B2C3".into(), None);
+        assert_eq!(
+            verify_state(session),
+            VerifyState::Pending {
+                sas_code: "A1B2C3".into()
+            }
+        );
+        assert_eq!(status_text(session), "Verify code");
+
+        set_connection(session, ConnectionState::Error("boom".into()));
+        assert_eq!(session.phase(), SessionPhase::Idle);
+        assert_eq!(status_text(session), "Failed");
+    }
+}
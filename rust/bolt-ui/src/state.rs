@@ -127,16 +127,6 @@ impl ConnectionState {
     }
 }
 
-// ── Connected Peer ───────────────────────────────────────────
-
-/// Info about the currently connected peer.
-#[derive(Debug, Clone)]
-pub struct ConnectedPeer {
-    pub peer_code: String,
-    pub device_name: String,
-    pub device_type: DeviceType,
-}
-
 // ── Incoming Connection Request ───────────────────────────────
 
 /// An inbound connection request from another peer.
@@ -171,6 +161,35 @@ impl HostInfo {
     }
 }
 
+// ── Daemon Endpoints ─────────────────────────────────────────
+
+/// Daemon transport endpoints, from `endpoint.*` IPC events.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EndpointState {
+    pub ws_listening: bool,
+    pub wt_listening: bool,
+    /// WebTransport certificate hash (SECURE-DIRECT-1).
+    pub wt_cert_hash: Option<String>,
+}
+
+impl EndpointState {
+    /// Signaling may go out: WS is listening and, when the daemon announced
+    /// a WT certificate, WT is listening too — the browser must not get a
+    /// wtUrl before WT accepts connections.
+    pub fn ready_to_signal(&self) -> bool {
+        self.ws_listening && (self.wt_cert_hash.is_none() || self.wt_listening)
+    }
+
+    /// Certificate hash to advertise with a wtUrl, once WT is listening.
+    pub fn advertised_wt_cert(&self) -> Option<&str> {
+        if self.wt_listening {
+            self.wt_cert_hash.as_deref()
+        } else {
+            None
+        }
+    }
+}
+
 // ── Transfer State ───────────────────────────────────────────
 
 #[derive(Debug, Clone, PartialEq)]
@@ -218,6 +237,26 @@ impl TransferState {
     }
 }
 
+/// A user transfer action sent to the daemon, awaiting its `decision.ack`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransferAction {
+    Send { file_name: String },
+    Cancel,
+    Pause,
+    Resume,
+}
+
+impl TransferAction {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Send { .. } => "Send",
+            Self::Cancel => "Cancel",
+            Self::Pause => "Pause",
+            Self::Resume => "Resume",
+        }
+    }
+}
+
 // ── Verification State ───────────────────────────────────────
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -227,7 +266,6 @@ pub enum VerifyState {
     Legacy,
     Pending { sas_code: String },
     Confirmed,
-    Rejected,
 }
 
 impl VerifyState {
@@ -237,7 +275,6 @@ impl VerifyState {
             Self::Legacy => "Legacy Peer",
             Self::Pending { .. } => "Compare this code with your peer",
             Self::Confirmed => "Verified",
-            Self::Rejected => "Rejected",
         }
     }
 
@@ -292,6 +329,20 @@ mod tests {
         assert!(!DeviceType::Browser.icon().is_empty());
     }
 
+    #[test]
+    fn endpoints_wait_for_wt_once_cert_is_known() {
+        let mut e = EndpointState::default();
+        assert!(!e.ready_to_signal());
+        e.ws_listening = true;
+        assert!(e.ready_to_signal());
+        e.wt_cert_hash = Some("ab".repeat(32));
+        assert!(!e.ready_to_signal());
+        assert!(e.advertised_wt_cert().is_none());
+        e.wt_listening = true;
+        assert!(e.ready_to_signal());
+        assert!(e.advertised_wt_cert().is_some());
+    }
+
     #[test]
     fn transfer_ready_state() {
         assert!(TransferState::Ready.status_text().contains("Ready"));
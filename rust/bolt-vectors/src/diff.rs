@@ -0,0 +1,131 @@
+//! Structural JSON diff.
+//!
+//! Paths use `$` for the root, `.key` for object members and `[i]` for
+//! array elements: `$.cases[0].expected_sas`.
+
+use serde::Serialize;
+use serde_json::Value;
+
+/// How a value differs between the two sides.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Changed,
+    /// Present only on the new / actual side.
+    Added,
+    /// Present only on the old / expected side.
+    Removed,
+}
+
+/// One differing value.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Difference {
+    pub path: String,
+    pub kind: ChangeKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new: Option<Value>,
+}
+
+/// All differences from `old` to `new`, in document order.
+pub fn json_diff(old: &Value, new: &Value) -> Vec<Difference> {
+    let mut out = Vec::new();
+    walk("$".to_string(), old, new, &mut out);
+    out
+}
+
+fn walk(path: String, old: &Value, new: &Value, out: &mut Vec<Difference>) {
+    match (old, new) {
+        (Value::Object(a), Value::Object(b)) => {
+            for (key, va) in a {
+                let child = format!("{path}.{key}");
+                match b.get(key) {
+                    Some(vb) => walk(child, va, vb, out),
+                    None => out.push(removed(child, va)),
+                }
+            }
+            for (key, vb) in b {
+                if !a.contains_key(key) {
+                    out.push(added(format!("{path}.{key}"), vb));
+                }
+            }
+        }
+        (Value::Array(a), Value::Array(b)) => {
+            for (i, va) in a.iter().enumerate() {
+                let child = format!("{path}[{i}]");
+                match b.get(i) {
+                    Some(vb) => walk(child, va, vb, out),
+                    None => out.push(removed(child, va)),
+                }
+            }
+            for (i, vb) in b.iter().enumerate().skip(a.len()) {
+                out.push(added(format!("{path}[{i}]"), vb));
+            }
+        }
+        _ if old == new => {}
+        _ => out.push(Difference {
+            path,
+            kind: ChangeKind::Changed,
+            old: Some(old.clone()),
+            new: Some(new.clone()),
+        }),
+    }
+}
+
+fn added(path: String, value: &Value) -> Difference {
+    Difference {
+        path,
+        kind: ChangeKind::Added,
+        old: None,
+        new: Some(value.clone()),
+    }
+}
+
+fn removed(path: String, value: &Value) -> Difference {
+    Difference {
+        path,
+        kind: ChangeKind::Removed,
+        old: Some(value.clone()),
+        new: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn identical_documents_have_no_differences() {
+        let a = json!({"b": [1, 2], "a": {"x": "y"}});
+        let b = json!({"a": {"x": "y"}, "b": [1, 2]});
+        assert!(json_diff(&a, &b).is_empty());
+    }
+
+    #[test]
+    fn reports_changed_added_and_removed_paths() {
+        let old = json!({"cases": [{"sas": "65434F"}, {"sas": "AAAAAA"}], "gone": 1});
+        let new = json!({"cases": [{"sas": "65434E"}], "extra": true});
+        let diffs = json_diff(&old, &new);
+        let summary: Vec<_> = diffs.iter().map(|d| (d.path.as_str(), d.kind)).collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("$.cases[0].sas", ChangeKind::Changed),
+                ("$.cases[1]", ChangeKind::Removed),
+                ("$.gone", ChangeKind::Removed),
+                ("$.extra", ChangeKind::Added),
+            ]
+        );
+        assert_eq!(diffs[0].old, Some(json!("65434F")));
+        assert_eq!(diffs[0].new, Some(json!("65434E")));
+    }
+
+    #[test]
+    fn type_change_is_a_single_difference() {
+        let diffs = json_diff(&json!({"a": [1]}), &json!({"a": {"0": 1}}));
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].path, "$.a");
+    }
+}
@@ -0,0 +1,155 @@
+//! bolt-vectors — golden vector CLI library.
+//!
+//! Registry of every vector family the Rust authority generates
+//! ([`FAMILIES`]), plus the three operations behind the `bolt-vectors`
+//! binary:
+//!
+//! - [`report::generate`] — write canonical files into a directory
+//! - [`report::verify`] — compare a directory against the canonical output
+//! - [`report::diff_dirs`] — compare two vector directories
+//! - [`report::write_manifest`] / [`report::check_manifest`] — the
+//!   [`manifest::Manifest`] of protocol constants and wire error codes
+//!
+//! Comparison is structural ([`diff::json_diff`]): key order and
+//! whitespace are ignored, every differing value is reported with its
+//! JSON path.
+//!
+//! ## Layout
+//!
+//! A vector directory mirrors `rust/bolt-core/test-vectors/`: `core/` and
+//! `btr/` subdirectories holding `*.vectors.json` files.
+
+pub mod diff;
+pub mod manifest;
+pub mod report;
+
+use bolt_btr::vectors as btr;
+use bolt_core::vectors as core;
+
+/// One vector file and its canonical generator.
+#[derive(Clone, Copy)]
+pub struct Family {
+    /// Subdirectory: `"core"` or `"btr"`.
+    pub set: &'static str,
+    /// File name within `set`.
+    pub file: &'static str,
+    pub generate: fn() -> String,
+}
+
+impl Family {
+    /// Stable name, e.g. `core/sas` or `btr/btr-lifecycle`.
+    pub fn name(&self) -> String {
+        format!(
+            "{}/{}",
+            self.set,
+            self.file.trim_end_matches(".vectors.json")
+        )
+    }
+
+    /// Path relative to the vector directory, e.g. `core/sas.vectors.json`.
+    pub fn relative_path(&self) -> String {
+        format!("{}/{}", self.set, self.file)
+    }
+}
+
+const fn family(set: &'static str, file: &'static str, generate: fn() -> String) -> Family {
+    Family {
+        set,
+        file,
+        generate,
+    }
+}
+
+/// Every vector family in `test-vectors/core` and `test-vectors/btr`.
+pub const FAMILIES: &[Family] = &[
+    family(
+        "core",
+        "box-payload.vectors.json",
+        core::generate_box_payload_json,
+    ),
+    family("core", "framing.vectors.json", core::generate_framing_json),
+    family("core", "sas.vectors.json", core::generate_sas_json),
+    family(
+        "core",
+        "web-hello-open.vectors.json",
+        core::generate_hello_open_json,
+    ),
+    family(
+        "core",
+        "envelope-open.vectors.json",
+        core::generate_envelope_open_json,
+    ),
+    family("core", "pake.vectors.json", core::generate_pake_json),
+    family(
+        "core",
+        "rotation.vectors.json",
+        core::generate_rotation_json,
+    ),
+    family("core", "pairing.vectors.json", core::generate_pairing_json),
+    family(
+        "btr",
+        "btr-key-schedule.vectors.json",
+        btr::generate_key_schedule_json,
+    ),
+    family(
+        "btr",
+        "btr-transfer-ratchet.vectors.json",
+        btr::generate_transfer_ratchet_json,
+    ),
+    family(
+        "btr",
+        "btr-chain-advance.vectors.json",
+        btr::generate_chain_advance_json,
+    ),
+    family(
+        "btr",
+        "btr-replay-reject.vectors.json",
+        btr::generate_replay_reject_json,
+    ),
+    family(
+        "btr",
+        "btr-downgrade-negotiate.vectors.json",
+        btr::generate_downgrade_negotiate_json,
+    ),
+    family(
+        "btr",
+        "btr-dh-ratchet.vectors.json",
+        btr::generate_dh_ratchet_json,
+    ),
+    family(
+        "btr",
+        "btr-encrypt-decrypt.vectors.json",
+        btr::generate_encrypt_decrypt_json,
+    ),
+    family(
+        "btr",
+        "btr-dh-sanity.vectors.json",
+        btr::generate_dh_sanity_json,
+    ),
+    family(
+        "btr",
+        "btr-lifecycle.vectors.json",
+        btr::generate_lifecycle_json,
+    ),
+    family(
+        "btr",
+        "btr-adversarial.vectors.json",
+        btr::generate_adversarial_json,
+    ),
+    family(
+        "btr",
+        "btr-hybrid-combine.vectors.json",
+        btr::generate_hybrid_combine_json,
+    ),
+];
+
+/// Families whose name starts with any of `prefixes` (all when empty).
+pub fn select(prefixes: &[String]) -> Vec<Family> {
+    FAMILIES
+        .iter()
+        .filter(|f| {
+            prefixes.is_empty() || prefixes.iter().any(|p| f.name().starts_with(p.as_str()))
+        })
+        .copied()
+        .collect()
+}
@@ -0,0 +1,135 @@
+//! `bolt-vectors` — generate, verify and diff golden vector sets.
+//!
+//! ```text
+//! bolt-vectors generate --out rust/bolt-core/test-vectors
+//! bolt-vectors --json verify ./my-impl-vectors --only core/sas --only btr/
+//! bolt-vectors diff old-vectors/ new-vectors/
+//! bolt-vectors manifest --format toml
+//! bolt-vectors manifest --check rust/bolt-core/test-vectors/protocol-manifest.json
+//! ```
+//!
+//! Exit status is 0 when every family passes, 1 when any family is
+//! mismatched, missing or invalid, 2 on usage or I/O errors.
+
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand, ValueEnum};
+
+use bolt_vectors::manifest::{Format, Manifest};
+use bolt_vectors::report::{self, Report};
+
+#[derive(Parser)]
+#[command(
+    name = "bolt-vectors",
+    version,
+    about = "Generate, verify and diff Bolt golden vector sets"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Print the report as a single JSON document on stdout.
+    #[arg(long, global = true)]
+    json: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Write the canonical vector files (DIR/core, DIR/btr).
+    Generate {
+        #[arg(long, value_name = "DIR", default_value = "test-vectors")]
+        out: PathBuf,
+        /// Only families whose name starts with PREFIX (repeatable).
+        #[arg(long, value_name = "PREFIX")]
+        only: Vec<String>,
+    },
+    /// Check a vector directory against the canonical output.
+    Verify {
+        dir: PathBuf,
+        /// Only families whose name starts with PREFIX (repeatable).
+        #[arg(long, value_name = "PREFIX")]
+        only: Vec<String>,
+    },
+    /// Compare two vector directories.
+    Diff { old: PathBuf, new: PathBuf },
+    /// Print the protocol manifest (constants, capabilities, wire error
+    /// codes), write it with --out, or check a copy with --check.
+    /// The file format follows the extension (`.toml`, otherwise JSON).
+    Manifest {
+        /// Format printed on stdout.
+        #[arg(long, value_enum, default_value = "json")]
+        format: ManifestFormat,
+        #[arg(long, value_name = "FILE", conflicts_with = "check")]
+        out: Option<PathBuf>,
+        #[arg(long, value_name = "FILE")]
+        check: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ManifestFormat {
+    Json,
+    Toml,
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Generate { out, only } => report::generate(&out, &families(&only)),
+        Command::Verify { dir, only } => {
+            if dir.is_dir() {
+                Ok(report::verify(&dir, &families(&only)))
+            } else {
+                Err(std::io::Error::new(
+                    std::io::ErrorKind::NotFound,
+                    format!("not a directory: {}", dir.display()),
+                ))
+            }
+        }
+        Command::Diff { old, new } => report::diff_dirs(&old, &new),
+        Command::Manifest { out: Some(out), .. } => report::write_manifest(&out),
+        Command::Manifest {
+            check: Some(check), ..
+        } => Ok(report::check_manifest(&check)),
+        Command::Manifest { format, .. } => {
+            let format = match format {
+                ManifestFormat::Json => Format::Json,
+                ManifestFormat::Toml => Format::Toml,
+            };
+            print!("{}", format.render(&Manifest::current()));
+            return;
+        }
+    };
+    match result {
+        Ok(report) => {
+            print(&report, cli.json);
+            if !report.ok {
+                std::process::exit(1);
+            }
+        }
+        Err(e) => {
+            eprintln!("bolt-vectors: {e}");
+            std::process::exit(2);
+        }
+    }
+}
+
+fn families(only: &[String]) -> Vec<bolt_vectors::Family> {
+    let selected = bolt_vectors::select(only);
+    if selected.is_empty() {
+        eprintln!("bolt-vectors: no family matches {only:?}");
+        std::process::exit(2);
+    }
+    selected
+}
+
+fn print(report: &Report, json: bool) {
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(report).expect("report serializes")
+        );
+    } else {
+        print!("{}", report.to_text());
+    }
+}
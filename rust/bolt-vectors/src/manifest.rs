@@ -0,0 +1,267 @@
+//! Protocol manifest: every constant SDKs must mirror, in one document.
+//!
+//! [`Manifest::current`] collects the scalar constants of
+//! `bolt_core::constants`, `bolt_btr::constants` and the transfer limits,
+//! the BTR HKDF info strings, every capability string, and the wire error
+//! registry with each code's class and required action
+//! (`bolt_core::errors::WIRE_ERROR_REGISTRY`). Other SDKs code-generate
+//! from the JSON or TOML rendering instead of grepping Rust source.
+//!
+//! `manifest_version` is bumped when the document *shape* changes; value
+//! changes are tracked by `protocol_version` and the checked-in copies in
+//! `rust/bolt-core/test-vectors/protocol-manifest.{json,toml}`.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use bolt_btr::constants as btr;
+use bolt_core::constants as core;
+use bolt_core::errors::WIRE_ERROR_REGISTRY;
+use bolt_transfer_core::receive::MAX_TRANSFER_BYTES;
+
+/// Shape version of the manifest document.
+pub const MANIFEST_VERSION: u32 = 1;
+
+/// Checked-in JSON rendering, relative to `rust/bolt-core/test-vectors`.
+pub const MANIFEST_JSON_FILE: &str = "protocol-manifest.json";
+
+/// Checked-in TOML rendering, relative to `rust/bolt-core/test-vectors`.
+pub const MANIFEST_TOML_FILE: &str = "protocol-manifest.toml";
+
+const CORE: &str = "bolt_core::constants";
+const BTR: &str = "bolt_btr::constants";
+const TRANSFER: &str = "bolt_transfer_core::receive";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    pub manifest_version: u32,
+    pub protocol_version: u64,
+    pub generator: String,
+    pub constants: Vec<Constant>,
+    pub hkdf_info: Vec<NamedString>,
+    pub capabilities: Vec<NamedString>,
+    pub wire_error_codes: Vec<WireErrorCode>,
+}
+
+/// A scalar constant. `type` is `integer` or `string`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Constant {
+    pub name: String,
+    pub module: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub value: Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Value {
+    Integer(u64),
+    String(String),
+}
+
+/// A named string: an HKDF info label (UTF-8 of the byte string) or a
+/// capability.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NamedString {
+    pub name: String,
+    pub module: String,
+    pub value: String,
+}
+
+/// One wire error code with its class (`PROTOCOL`, `ENFORCEMENT`, `BTR`,
+/// `PAKE`, `ROTATION`, `PQ`) and required receiver action (`disconnect`,
+/// `cancel_transfer`, `reject_message`, `notify`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WireErrorCode {
+    pub code: String,
+    pub class: String,
+    pub action: String,
+}
+
+fn int(module: &str, name: &str, value: u64) -> Constant {
+    Constant {
+        name: name.into(),
+        module: module.into(),
+        kind: "integer".into(),
+        value: Value::Integer(value),
+    }
+}
+
+fn string(module: &str, name: &str, value: &str) -> Constant {
+    Constant {
+        name: name.into(),
+        module: module.into(),
+        kind: "string".into(),
+        value: Value::String(value.into()),
+    }
+}
+
+fn named(module: &str, name: &str, value: &[u8]) -> NamedString {
+    NamedString {
+        name: name.into(),
+        module: module.into(),
+        value: String::from_utf8(value.to_vec()).expect("info strings are ASCII"),
+    }
+}
+
+impl Manifest {
+    /// The manifest for the constants compiled into this binary.
+    pub fn current() -> Self {
+        let constants = vec![
+            int(CORE, "NONCE_LENGTH", core::NONCE_LENGTH as u64),
+            int(CORE, "PUBLIC_KEY_LENGTH", core::PUBLIC_KEY_LENGTH as u64),
+            int(CORE, "SECRET_KEY_LENGTH", core::SECRET_KEY_LENGTH as u64),
+            int(CORE, "DEFAULT_CHUNK_SIZE", core::DEFAULT_CHUNK_SIZE as u64),
+            int(CORE, "PEER_CODE_LENGTH", core::PEER_CODE_LENGTH as u64),
+            string(CORE, "PEER_CODE_ALPHABET", core::PEER_CODE_ALPHABET),
+            int(CORE, "SAS_LENGTH", core::SAS_LENGTH as u64),
+            int(CORE, "SAS_WORD_COUNT", core::SAS_WORD_COUNT as u64),
+            int(CORE, "SAS_EMOJI_COUNT", core::SAS_EMOJI_COUNT as u64),
+            int(CORE, "SAS_DECIMAL_GROUPS", core::SAS_DECIMAL_GROUPS as u64),
+            int(CORE, "BOX_OVERHEAD", core::BOX_OVERHEAD as u64),
+            int(CORE, "TRANSFER_ID_LENGTH", core::TRANSFER_ID_LENGTH as u64),
+            int(CORE, "SAS_ENTROPY", core::SAS_ENTROPY as u64),
+            string(CORE, "FILE_HASH_ALGORITHM", core::FILE_HASH_ALGORITHM),
+            int(CORE, "FILE_HASH_LENGTH", core::FILE_HASH_LENGTH as u64),
+            int(CORE, "BOLT_VERSION", core::BOLT_VERSION as u64),
+            string(CORE, "CAPABILITY_NAMESPACE", core::CAPABILITY_NAMESPACE),
+            int(CORE, "PAIRING_VERSION", core::PAIRING_VERSION as u64),
+            string(CORE, "PAIRING_URI_PREFIX", core::PAIRING_URI_PREFIX),
+            int(
+                CORE,
+                "PAIRING_FINGERPRINT_LENGTH",
+                core::PAIRING_FINGERPRINT_LENGTH as u64,
+            ),
+            int(
+                CORE,
+                "PAIRING_SECRET_LENGTH",
+                core::PAIRING_SECRET_LENGTH as u64,
+            ),
+            int(CORE, "PAKE_CODE_LENGTH", core::PAKE_CODE_LENGTH as u64),
+            int(CORE, "ROTATION_VERSION", core::ROTATION_VERSION as u64),
+            int(
+                CORE,
+                "ROTATION_STATEMENT_LENGTH",
+                core::ROTATION_STATEMENT_LENGTH as u64,
+            ),
+            int(
+                CORE,
+                "ROTATION_MAX_VALIDITY_MS",
+                core::ROTATION_MAX_VALIDITY_MS,
+            ),
+            int(
+                CORE,
+                "ROTATION_MAX_CLOCK_SKEW_MS",
+                core::ROTATION_MAX_CLOCK_SKEW_MS,
+            ),
+            int(BTR, "BTR_KEY_LENGTH", btr::BTR_KEY_LENGTH as u64),
+            int(
+                BTR,
+                "MLKEM768_ENCAPSULATION_KEY_LENGTH",
+                btr::MLKEM768_ENCAPSULATION_KEY_LENGTH as u64,
+            ),
+            int(
+                BTR,
+                "MLKEM768_CIPHERTEXT_LENGTH",
+                btr::MLKEM768_CIPHERTEXT_LENGTH as u64,
+            ),
+            int(
+                BTR,
+                "MLKEM_SHARED_SECRET_LENGTH",
+                btr::MLKEM_SHARED_SECRET_LENGTH as u64,
+            ),
+            string(
+                BTR,
+                "PQ_HELLO_TRANSCRIPT_LABEL",
+                std::str::from_utf8(btr::PQ_HELLO_TRANSCRIPT_LABEL).expect("label is ASCII"),
+            ),
+            int(TRANSFER, "MAX_TRANSFER_BYTES", MAX_TRANSFER_BYTES),
+        ];
+        let hkdf_info = vec![
+            named(BTR, "BTR_SESSION_ROOT_INFO", btr::BTR_SESSION_ROOT_INFO),
+            named(BTR, "BTR_TRANSFER_ROOT_INFO", btr::BTR_TRANSFER_ROOT_INFO),
+            named(BTR, "BTR_MESSAGE_KEY_INFO", btr::BTR_MESSAGE_KEY_INFO),
+            named(BTR, "BTR_CHAIN_ADVANCE_INFO", btr::BTR_CHAIN_ADVANCE_INFO),
+            named(BTR, "BTR_DH_RATCHET_INFO", btr::BTR_DH_RATCHET_INFO),
+            named(BTR, "BTR_HYBRID_COMBINE_INFO", btr::BTR_HYBRID_COMBINE_INFO),
+        ];
+        let capabilities = [
+            (CORE, "FILE_HASH_CAPABILITY", core::FILE_HASH_CAPABILITY),
+            (
+                CORE,
+                "PROFILE_ENVELOPE_V1_CAPABILITY",
+                core::PROFILE_ENVELOPE_V1_CAPABILITY,
+            ),
+            (CORE, "PAKE_CAPABILITY", core::PAKE_CAPABILITY),
+            (
+                CORE,
+                "IDENTITY_ROTATION_CAPABILITY",
+                core::IDENTITY_ROTATION_CAPABILITY,
+            ),
+            (
+                BTR,
+                "TRANSFER_RATCHET_CAPABILITY",
+                btr::TRANSFER_RATCHET_CAPABILITY,
+            ),
+            (BTR, "PQ_HYBRID_CAPABILITY", btr::PQ_HYBRID_CAPABILITY),
+        ]
+        .into_iter()
+        .map(|(module, name, value)| named(module, name, value.as_bytes()))
+        .collect();
+        let wire_error_codes = WIRE_ERROR_REGISTRY
+            .iter()
+            .map(|spec| WireErrorCode {
+                code: spec.code.into(),
+                class: spec.class.as_str().into(),
+                action: spec.action.as_str().into(),
+            })
+            .collect();
+
+        Self {
+            manifest_version: MANIFEST_VERSION,
+            protocol_version: core::BOLT_VERSION as u64,
+            generator: "bolt-vectors manifest".into(),
+            constants,
+            hkdf_info,
+            capabilities,
+            wire_error_codes,
+        }
+    }
+
+    /// Pretty JSON with a trailing newline, as checked in.
+    pub fn to_json(&self) -> String {
+        let mut out = serde_json::to_string_pretty(self).expect("manifest serializes");
+        out.push('\n');
+        out
+    }
+
+    pub fn to_toml(&self) -> String {
+        toml::to_string(self).expect("manifest serializes")
+    }
+}
+
+/// Output format for `bolt-vectors manifest`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Toml,
+}
+
+impl Format {
+    pub fn render(self, manifest: &Manifest) -> String {
+        match self {
+            Format::Json => manifest.to_json(),
+            Format::Toml => manifest.to_toml(),
+        }
+    }
+
+    /// `Toml` for a `.toml` path, `Json` otherwise.
+    pub fn for_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("toml") => Format::Toml,
+            _ => Format::Json,
+        }
+    }
+}
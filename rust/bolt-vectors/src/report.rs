@@ -0,0 +1,334 @@
+//! Generate / verify / diff operations and their machine-readable report.
+//!
+//! Every operation returns a [`Report`]; the binary prints it as text or,
+//! with `--json`, as a single JSON document. `ok` is false when any family
+//! fails, and the binary exits 1 in that case.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::diff::{json_diff, Difference};
+use crate::manifest::{Format, Manifest};
+use crate::Family;
+
+/// Differences listed per family; the rest are only counted.
+pub const MAX_LISTED_DIFFERENCES: usize = 100;
+
+/// Outcome for one vector family.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    /// verify: file matches the canonical output.
+    Pass,
+    /// verify: file differs from the canonical output.
+    Mismatch,
+    /// verify: file absent from the directory under test.
+    Missing,
+    /// File is not valid JSON (or could not be read).
+    Invalid,
+    /// generate: file written or rewritten.
+    Written,
+    /// generate / diff: file content identical.
+    Unchanged,
+    /// diff: file present on both sides with differences.
+    Changed,
+    /// diff: file only in the new directory.
+    Added,
+    /// diff: file only in the old directory.
+    Removed,
+}
+
+impl Status {
+    fn is_failure(self) -> bool {
+        matches!(self, Status::Mismatch | Status::Missing | Status::Invalid)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FamilyReport {
+    /// Family name (`core/sas`) or, for diff, the relative file path.
+    pub family: String,
+    pub file: String,
+    pub status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub differences: Vec<Difference>,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub differences_total: usize,
+}
+
+fn is_zero(n: &usize) -> bool {
+    *n == 0
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    /// `generate`, `verify`, `diff` or `manifest`.
+    pub command: &'static str,
+    pub ok: bool,
+    pub families: Vec<FamilyReport>,
+}
+
+impl Report {
+    fn new(command: &'static str, families: Vec<FamilyReport>) -> Self {
+        let ok = families.iter().all(|f| !f.status.is_failure());
+        Self {
+            command,
+            ok,
+            families,
+        }
+    }
+
+    /// Number of families with `status`.
+    pub fn count(&self, status: Status) -> usize {
+        self.families.iter().filter(|f| f.status == status).count()
+    }
+
+    /// Human-readable rendering: one line per family, differences indented.
+    pub fn to_text(&self) -> String {
+        let mut out = String::new();
+        for f in &self.families {
+            let status = serde_json::to_value(f.status).unwrap_or(Value::Null);
+            out.push_str(&format!(
+                "{:<10} {}",
+                status.as_str().unwrap_or("?"),
+                f.family
+            ));
+            if let Some(detail) = &f.detail {
+                out.push_str(&format!(": {detail}"));
+            }
+            out.push('\n');
+            for d in &f.differences {
+                out.push_str(&format!("    {:?} {}", d.kind, d.path));
+                if let Some(old) = &d.old {
+                    out.push_str(&format!("  old={old}"));
+                }
+                if let Some(new) = &d.new {
+                    out.push_str(&format!("  new={new}"));
+                }
+                out.push('\n');
+            }
+            if f.differences_total > f.differences.len() {
+                out.push_str(&format!(
+                    "    ... {} more\n",
+                    f.differences_total - f.differences.len()
+                ));
+            }
+        }
+        let failed = self
+            .families
+            .iter()
+            .filter(|f| f.status.is_failure())
+            .count();
+        out.push_str(&format!(
+            "{}: {} families, {} failed\n",
+            self.command,
+            self.families.len(),
+            failed
+        ));
+        out
+    }
+}
+
+fn entry(family: String, file: String, status: Status) -> FamilyReport {
+    FamilyReport {
+        family,
+        file,
+        status,
+        detail: None,
+        differences: Vec::new(),
+        differences_total: 0,
+    }
+}
+
+fn with_differences(mut report: FamilyReport, mut diffs: Vec<Difference>) -> FamilyReport {
+    report.differences_total = diffs.len();
+    diffs.truncate(MAX_LISTED_DIFFERENCES);
+    report.differences = diffs;
+    report
+}
+
+fn read_json(path: &Path) -> Result<Value, String> {
+    let text = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&text).map_err(|e| e.to_string())
+}
+
+fn canonical(family: &Family) -> Value {
+    serde_json::from_str(&(family.generate)())
+        .unwrap_or_else(|e| panic!("{} generator produced invalid JSON: {e}", family.name()))
+}
+
+/// Write each family's canonical output to `out/<set>/<file>`. Files whose
+/// content is already identical are left untouched.
+pub fn generate(out: &Path, families: &[Family]) -> std::io::Result<Report> {
+    let mut entries = Vec::new();
+    for family in families {
+        let path = out.join(family.set).join(family.file);
+        let content = (family.generate)();
+        let status = if fs::read_to_string(&path).is_ok_and(|existing| existing == content) {
+            Status::Unchanged
+        } else {
+            fs::create_dir_all(path.parent().expect("family path has a parent"))?;
+            fs::write(&path, &content)?;
+            Status::Written
+        };
+        entries.push(entry(family.name(), family.relative_path(), status));
+    }
+    Ok(Report::new("generate", entries))
+}
+
+/// Compare each family file under `dir` with the canonical output.
+/// Differences are reported from canonical (`old`) to actual (`new`).
+pub fn verify(dir: &Path, families: &[Family]) -> Report {
+    let entries = families
+        .iter()
+        .map(|family| {
+            let path = dir.join(family.set).join(family.file);
+            let base = |status| entry(family.name(), family.relative_path(), status);
+            if !path.exists() {
+                return base(Status::Missing);
+            }
+            match read_json(&path) {
+                Err(e) => FamilyReport {
+                    detail: Some(e),
+                    ..base(Status::Invalid)
+                },
+                Ok(actual) => {
+                    let diffs = json_diff(&canonical(family), &actual);
+                    if diffs.is_empty() {
+                        base(Status::Pass)
+                    } else {
+                        with_differences(base(Status::Mismatch), diffs)
+                    }
+                }
+            }
+        })
+        .collect();
+    Report::new("verify", entries)
+}
+
+/// Report family name for the protocol manifest.
+pub const MANIFEST_FAMILY: &str = "protocol-manifest";
+
+/// Write [`Manifest::current`] to `path`, as TOML for a `.toml` path and
+/// JSON otherwise. An identical file is left untouched.
+pub fn write_manifest(path: &Path) -> std::io::Result<Report> {
+    let content = Format::for_path(path).render(&Manifest::current());
+    let status = if fs::read_to_string(path).is_ok_and(|existing| existing == content) {
+        Status::Unchanged
+    } else {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, &content)?;
+        Status::Written
+    };
+    let file = path.display().to_string();
+    Ok(Report::new(
+        "manifest",
+        vec![entry(MANIFEST_FAMILY.into(), file, status)],
+    ))
+}
+
+/// Compare a manifest file (JSON, or TOML for a `.toml` path) with
+/// [`Manifest::current`]. Differences run from canonical to actual.
+pub fn check_manifest(path: &Path) -> Report {
+    let base = |status| entry(MANIFEST_FAMILY.into(), path.display().to_string(), status);
+    let entry = if !path.exists() {
+        base(Status::Missing)
+    } else {
+        let actual = match Format::for_path(path) {
+            Format::Json => read_json(path),
+            Format::Toml => read_toml(path),
+        };
+        match actual {
+            Err(e) => FamilyReport {
+                detail: Some(e),
+                ..base(Status::Invalid)
+            },
+            Ok(actual) => {
+                let canonical =
+                    serde_json::to_value(Manifest::current()).expect("manifest serializes");
+                let diffs = json_diff(&canonical, &actual);
+                if diffs.is_empty() {
+                    base(Status::Pass)
+                } else {
+                    with_differences(base(Status::Mismatch), diffs)
+                }
+            }
+        }
+    };
+    Report::new("manifest", vec![entry])
+}
+
+fn read_toml(path: &Path) -> Result<Value, String> {
+    let text = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let value: toml::Value = toml::from_str(&text).map_err(|e| e.to_string())?;
+    serde_json::to_value(value).map_err(|e| e.to_string())
+}
+
+/// Compare every `*.vectors.json` under `old` and `new` (recursively).
+pub fn diff_dirs(old: &Path, new: &Path) -> std::io::Result<Report> {
+    let mut files = BTreeSet::new();
+    for root in [old, new] {
+        collect_vector_files(root, root, &mut files)?;
+    }
+    let entries = files
+        .into_iter()
+        .map(|rel| {
+            let name = rel.trim_end_matches(".vectors.json").to_string();
+            let base = |status| entry(name.clone(), rel.clone(), status);
+            let (a, b) = (old.join(&rel), new.join(&rel));
+            match (a.exists(), b.exists()) {
+                (true, false) => base(Status::Removed),
+                (false, true) => base(Status::Added),
+                _ => match (read_json(&a), read_json(&b)) {
+                    (Ok(va), Ok(vb)) => {
+                        let diffs = json_diff(&va, &vb);
+                        if diffs.is_empty() {
+                            base(Status::Unchanged)
+                        } else {
+                            with_differences(base(Status::Changed), diffs)
+                        }
+                    }
+                    (Err(e), _) | (_, Err(e)) => FamilyReport {
+                        detail: Some(e),
+                        ..base(Status::Invalid)
+                    },
+                },
+            }
+        })
+        .collect();
+    Ok(Report::new("diff", entries))
+}
+
+/// Relative paths (with `/` separators) of all `*.vectors.json` under `dir`.
+pub fn collect_vector_files(
+    root: &Path,
+    dir: &Path,
+    out: &mut BTreeSet<String>,
+) -> std::io::Result<()> {
+    let mut entries: Vec<PathBuf> = fs::read_dir(dir)?
+        .map(|e| e.map(|e| e.path()))
+        .collect::<Result<_, _>>()?;
+    entries.sort();
+    for path in entries {
+        if path.is_dir() {
+            collect_vector_files(root, &path, out)?;
+        } else if path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|n| n.ends_with(".vectors.json"))
+        {
+            let rel = path.strip_prefix(root).expect("path under root");
+            let rel: Vec<_> = rel.iter().map(|c| c.to_string_lossy()).collect();
+            out.insert(rel.join("/"));
+        }
+    }
+    Ok(())
+}
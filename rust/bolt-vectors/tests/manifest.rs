@@ -0,0 +1,148 @@
+//! Protocol manifest: checked-in copies stay current, both renderings
+//! carry the same data, and the constants modules and the registry are
+//! covered completely.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use bolt_btr::constants::{BTR_WIRE_ERROR_CODES, PQ_WIRE_ERROR_CODES};
+use bolt_core::errors::WIRE_ERROR_CODES;
+use bolt_vectors::manifest::{Manifest, Value, MANIFEST_JSON_FILE, MANIFEST_TOML_FILE};
+use bolt_vectors::report::{self, Status};
+
+fn checked_in(file: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../bolt-core/test-vectors")
+        .join(file)
+}
+
+#[test]
+fn checked_in_manifests_are_current() {
+    for file in [MANIFEST_JSON_FILE, MANIFEST_TOML_FILE] {
+        let report = report::check_manifest(&checked_in(file));
+        assert!(report.ok, "{file}:\n{}", report.to_text());
+    }
+}
+
+#[test]
+fn json_and_toml_renderings_agree() {
+    let manifest = Manifest::current();
+    let from_json: Manifest = serde_json::from_str(&manifest.to_json()).unwrap();
+    let from_toml: Manifest = toml::from_str(&manifest.to_toml()).unwrap();
+    assert_eq!(from_json, manifest);
+    assert_eq!(from_toml, manifest);
+}
+
+#[test]
+fn manifest_covers_every_wire_error_code_once() {
+    let manifest = Manifest::current();
+    let codes: Vec<&str> = manifest
+        .wire_error_codes
+        .iter()
+        .map(|c| c.code.as_str())
+        .collect();
+    assert_eq!(codes, WIRE_ERROR_CODES);
+    for code in &manifest.wire_error_codes {
+        assert!(
+            !code.class.is_empty() && !code.action.is_empty(),
+            "{code:?}"
+        );
+    }
+}
+
+/// Names of the top-level `pub const` items in a Rust source file.
+fn pub_consts(source: &str) -> Vec<&str> {
+    source
+        .lines()
+        .filter_map(|line| line.strip_prefix("pub const "))
+        .filter_map(|rest| rest.split(':').next())
+        .map(str::trim)
+        .collect()
+}
+
+/// A `pub const` added to a constants module without a manifest entry
+/// fails here, before SDKs silently miss it.
+#[test]
+fn manifest_lists_every_pub_const() {
+    let manifest = Manifest::current();
+    let listed: BTreeSet<&str> = manifest
+        .constants
+        .iter()
+        .map(|c| c.name.as_str())
+        .chain(
+            manifest
+                .hkdf_info
+                .iter()
+                .chain(&manifest.capabilities)
+                .map(|s| s.name.as_str()),
+        )
+        .collect();
+    for source in [
+        "../bolt-core/src/constants.rs",
+        "../bolt-btr/src/constants.rs",
+    ] {
+        let text = fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join(source)).unwrap();
+        let names = pub_consts(&text);
+        assert!(!names.is_empty(), "{source}: no pub const found");
+        for name in names {
+            // Wire error code lists are the `wire_error_codes` section.
+            if name.ends_with("_WIRE_ERROR_CODES") {
+                continue;
+            }
+            assert!(
+                listed.contains(name),
+                "{source}: {name} is missing from Manifest::current()"
+            );
+        }
+    }
+    for code in BTR_WIRE_ERROR_CODES.iter().chain(&PQ_WIRE_ERROR_CODES) {
+        assert!(
+            manifest.wire_error_codes.iter().any(|c| c.code == *code),
+            "{code} is missing from wire_error_codes"
+        );
+    }
+}
+
+#[test]
+fn names_are_unique_and_types_match_values() {
+    let manifest = Manifest::current();
+    let mut names = BTreeSet::new();
+    for c in &manifest.constants {
+        assert!(names.insert(c.name.as_str()), "duplicate {}", c.name);
+        match (&c.value, c.kind.as_str()) {
+            (Value::Integer(_), "integer") | (Value::String(_), "string") => {}
+            _ => panic!("{} declares type {} for {:?}", c.name, c.kind, c.value),
+        }
+    }
+    for s in manifest.hkdf_info.iter().chain(&manifest.capabilities) {
+        assert!(names.insert(s.name.as_str()), "duplicate {}", s.name);
+    }
+    for cap in &manifest.capabilities {
+        assert!(cap.value.starts_with("bolt."), "{}", cap.value);
+    }
+}
+
+#[test]
+fn check_reports_drift_with_paths() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("m.json");
+    let stale = Manifest::current()
+        .to_json()
+        .replace("\"value\": 24", "\"value\": 12");
+    std::fs::write(&path, stale).unwrap();
+
+    let report = report::check_manifest(&path);
+    assert!(!report.ok);
+    assert_eq!(report.count(Status::Mismatch), 1);
+    assert_eq!(
+        report.families[0].differences[0].path,
+        "$.constants[0].value"
+    );
+
+    let written = report::write_manifest(&path).unwrap();
+    assert_eq!(written.count(Status::Written), 1);
+    assert!(report::check_manifest(&path).ok);
+    let missing = report::check_manifest(&dir.path().join("absent.toml"));
+    assert_eq!(missing.count(Status::Missing), 1);
+}
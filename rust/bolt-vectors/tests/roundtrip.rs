@@ -0,0 +1,115 @@
+//! generate → verify → mutate → verify/diff round trip, plus registry
+//! coverage of the checked-in vector directory.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use bolt_vectors::diff::ChangeKind;
+use bolt_vectors::report::{self, Status};
+use bolt_vectors::FAMILIES;
+
+fn checked_in() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../bolt-core/test-vectors")
+}
+
+#[test]
+fn every_checked_in_vector_file_has_a_family() {
+    let mut files = BTreeSet::new();
+    for set in ["core", "btr"] {
+        let dir = checked_in().join(set);
+        report::collect_vector_files(&checked_in(), &dir, &mut files).unwrap();
+    }
+    let registered: BTreeSet<String> = FAMILIES.iter().map(|f| f.relative_path()).collect();
+    let unregistered: Vec<_> = files.difference(&registered).collect();
+    assert!(unregistered.is_empty(), "no family for {unregistered:?}");
+}
+
+#[test]
+fn checked_in_vectors_verify() {
+    let report = report::verify(&checked_in(), FAMILIES);
+    assert!(report.ok, "{}", report.to_text());
+    assert_eq!(report.count(Status::Pass), FAMILIES.len());
+}
+
+#[test]
+fn generate_verify_and_diff_round_trip() {
+    let out = tempfile::tempdir().unwrap();
+    let generated = report::generate(out.path(), FAMILIES).unwrap();
+    assert_eq!(generated.count(Status::Written), FAMILIES.len());
+    let again = report::generate(out.path(), FAMILIES).unwrap();
+    assert_eq!(again.count(Status::Unchanged), FAMILIES.len());
+    assert!(report::verify(out.path(), FAMILIES).ok);
+
+    let pristine = tempfile::tempdir().unwrap();
+    report::generate(pristine.path(), FAMILIES).unwrap();
+
+    // Mutate one expected value and drop one file.
+    let sas = out.path().join("core/sas.vectors.json");
+    let mut doc: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&sas).unwrap()).unwrap();
+    let (path, field) = first_string_leaf(&doc).expect("sas vectors have string fields");
+    *doc.pointer_mut(&field).unwrap() = serde_json::json!("tampered");
+    fs::write(&sas, serde_json::to_string_pretty(&doc).unwrap()).unwrap();
+    fs::remove_file(out.path().join("btr/btr-lifecycle.vectors.json")).unwrap();
+
+    let verified = report::verify(out.path(), FAMILIES);
+    assert!(!verified.ok);
+    let sas_entry = verified
+        .families
+        .iter()
+        .find(|f| f.family == "core/sas")
+        .unwrap();
+    assert_eq!(sas_entry.status, Status::Mismatch);
+    assert_eq!(sas_entry.differences_total, 1);
+    assert_eq!(sas_entry.differences[0].path, path);
+    assert_eq!(sas_entry.differences[0].kind, ChangeKind::Changed);
+    assert_eq!(
+        sas_entry.differences[0].new,
+        Some(serde_json::json!("tampered"))
+    );
+    let lifecycle = verified
+        .families
+        .iter()
+        .find(|f| f.family == "btr/btr-lifecycle")
+        .unwrap();
+    assert_eq!(lifecycle.status, Status::Missing);
+    assert_eq!(verified.count(Status::Pass), FAMILIES.len() - 2);
+
+    let diff = report::diff_dirs(pristine.path(), out.path()).unwrap();
+    assert!(diff.ok);
+    assert_eq!(diff.count(Status::Changed), 1);
+    assert_eq!(diff.count(Status::Removed), 1);
+    assert_eq!(diff.count(Status::Unchanged), FAMILIES.len() - 2);
+
+    let json = serde_json::to_value(&verified).unwrap();
+    assert_eq!(json["command"], "verify");
+    assert_eq!(json["ok"], false);
+}
+
+#[test]
+fn invalid_json_is_reported() {
+    let out = tempfile::tempdir().unwrap();
+    let only = bolt_vectors::select(&["core/framing".to_string()]);
+    assert_eq!(only.len(), 1);
+    fs::create_dir_all(out.path().join("core")).unwrap();
+    fs::write(out.path().join("core/framing.vectors.json"), "{not json").unwrap();
+    let report = report::verify(out.path(), &only);
+    assert!(!report.ok);
+    assert_eq!(report.families[0].status, Status::Invalid);
+    assert!(report.families[0].detail.is_some());
+}
+
+/// JSON path (`$.a[0].b`) and pointer (`/a/0/b`) of the first string leaf.
+fn first_string_leaf(value: &serde_json::Value) -> Option<(String, String)> {
+    match value {
+        serde_json::Value::String(_) => Some(("$".into(), String::new())),
+        serde_json::Value::Array(items) => items.iter().enumerate().find_map(|(i, v)| {
+            first_string_leaf(v).map(|(p, q)| (format!("$[{i}]{}", &p[1..]), format!("/{i}{q}")))
+        }),
+        serde_json::Value::Object(map) => map.iter().find_map(|(k, v)| {
+            first_string_leaf(v).map(|(p, q)| (format!("$.{k}{}", &p[1..]), format!("/{k}{q}")))
+        }),
+        _ => None,
+    }
+}
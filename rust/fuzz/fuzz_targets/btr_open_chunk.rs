@@ -0,0 +1,64 @@
+//! `BtrTransferContext::open_chunk` on attacker-controlled ciphertext.
+//!
+//! Two modes, selected by the first byte:
+//! - raw: open fuzzer bytes at a fuzzer-chosen chain index;
+//! - round trip: seal a fuzzer plaintext with a twin context, optionally
+//!   flip one ciphertext bit, then open.
+//!
+//! Invariants: no panic; the chain advances iff open succeeds; an
+//! untampered chunk opens to its plaintext, a tampered one never opens;
+//! errors map to a registered wire code.
+
+#![no_main]
+
+use bolt_btr::BtrTransferContext;
+use bolt_fuzz::{assert_wire_code, Input};
+use libfuzzer_sys::fuzz_target;
+
+const TRANSFER_ID: [u8; 16] = [0x42; 16];
+
+fuzz_target!(|data: &[u8]| {
+    let mut input = Input::new(data);
+    let round_trip = input.bool();
+    let chain_key = input.array32();
+    let chain_index = u32::from(input.u8());
+    let mut receiver = BtrTransferContext::new_for_test(TRANSFER_ID, 1, chain_key, chain_index);
+
+    if round_trip {
+        let mut sender = BtrTransferContext::new_for_test(TRANSFER_ID, 1, chain_key, chain_index);
+        let plaintext = input.bytes(255);
+        let (index, mut sealed) = sender.seal_chunk(plaintext).expect("seal");
+        let flip = input.u32() as usize;
+        let tampered = input.bool();
+        if tampered {
+            let at = flip % sealed.len();
+            sealed[at] ^= 1 << (flip % 8);
+        }
+        match receiver.open_chunk(index, &sealed) {
+            Ok(opened) => {
+                assert!(!tampered, "tampered chunk opened");
+                assert_eq!(opened, plaintext);
+                assert_eq!(receiver.chain_index(), sender.chain_index());
+            }
+            Err(e) => {
+                assert!(tampered, "untampered chunk rejected: {e}");
+                assert_wire_code(e.wire_code());
+            }
+        }
+        return;
+    }
+
+    let expected_index = if input.bool() {
+        chain_index
+    } else {
+        u32::from(input.u8())
+    };
+    let sealed = input.rest();
+    match receiver.open_chunk(expected_index, sealed) {
+        Ok(_) => assert_eq!(receiver.chain_index(), chain_index + 1),
+        Err(e) => {
+            assert_wire_code(e.wire_code());
+            assert_eq!(receiver.chain_index(), chain_index);
+        }
+    }
+});
@@ -0,0 +1,28 @@
+//! `from_base64` / `from_hex` on arbitrary strings.
+//!
+//! Invariants: no panic; accepted input round-trips through the matching
+//! encoder; errors map to a registered wire code.
+
+#![no_main]
+
+use bolt_core::encoding::{from_base64, from_hex, to_base64, to_hex};
+use bolt_fuzz::assert_wire_code;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let text = String::from_utf8_lossy(data);
+
+    match from_base64(&text) {
+        Ok(bytes) => assert_eq!(from_base64(&to_base64(&bytes)).unwrap(), bytes),
+        Err(e) => assert_wire_code(e.code()),
+    }
+
+    match from_hex(&text) {
+        Ok(bytes) => assert_eq!(to_hex(&bytes), text.to_ascii_lowercase()),
+        Err(e) => assert_wire_code(e.code()),
+    }
+
+    // Every byte string encodes and decodes losslessly.
+    assert_eq!(from_base64(&to_base64(data)).unwrap(), data);
+    assert_eq!(from_hex(&to_hex(data)).unwrap(), data);
+});
@@ -0,0 +1,27 @@
+//! `open_box_payload` on attacker-controlled sealed strings and keys.
+//!
+//! Invariants: no panic; payloads shorter than nonce + MAC never open;
+//! errors map to a registered wire code.
+
+#![no_main]
+
+use bolt_core::constants::{BOX_OVERHEAD, NONCE_LENGTH};
+use bolt_core::crypto::open_box_payload;
+use bolt_core::encoding::from_base64;
+use bolt_fuzz::{assert_wire_code, Input};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let mut input = Input::new(data);
+    let sender_public = input.array32();
+    let receiver_secret = input.array32();
+    let sealed = String::from_utf8_lossy(input.rest());
+
+    match open_box_payload(&sealed, &sender_public, &receiver_secret) {
+        Ok(plaintext) => {
+            let raw = from_base64(&sealed).expect("opened payload decodes");
+            assert_eq!(raw.len(), NONCE_LENGTH + BOX_OVERHEAD + plaintext.len());
+        }
+        Err(e) => assert_wire_code(e.code()),
+    }
+});
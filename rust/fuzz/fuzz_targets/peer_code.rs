@@ -0,0 +1,23 @@
+//! `normalize_peer_code` / `is_valid_peer_code` on arbitrary strings.
+//!
+//! Invariants: no panic; a valid code normalizes to a dash-free code of
+//! 6 or 8 alphabet characters that is itself valid and a fixed point.
+
+#![no_main]
+
+use bolt_core::constants::PEER_CODE_ALPHABET;
+use bolt_core::peer_code::{is_valid_peer_code, normalize_peer_code};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let code = String::from_utf8_lossy(data);
+    let normalized = normalize_peer_code(&code);
+    assert!(!normalized.contains('-'));
+
+    if is_valid_peer_code(&code) {
+        assert!(normalized.len() == 6 || normalized.len() == 8);
+        assert!(normalized.chars().all(|c| PEER_CODE_ALPHABET.contains(c)));
+        assert!(is_valid_peer_code(&normalized));
+        assert_eq!(normalize_peer_code(&normalized), normalized);
+    }
+});
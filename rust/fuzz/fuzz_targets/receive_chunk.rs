@@ -0,0 +1,66 @@
+//! `ReceiveSession::on_file_chunk` against a reference model.
+//!
+//! An offer with fuzzer-chosen size and chunk count is accepted, then a
+//! sequence of chunks with fuzzer-chosen transfer_id, index and payload is
+//! delivered. Invariants: no panic; a chunk is accepted iff its id matches
+//! and its index is the next in-range index; rejected chunks leave the
+//! state untouched; the completed buffer is exactly the accepted chunks.
+
+#![no_main]
+
+use bolt_fuzz::{assert_wire_code, Input};
+use bolt_transfer_core::receive::MAX_TRANSFER_BYTES;
+use bolt_transfer_core::{ReceiveSession, TransferState};
+use libfuzzer_sys::fuzz_target;
+
+const TRANSFER_ID: &str = "fuzz-0";
+
+fuzz_target!(|data: &[u8]| {
+    let mut input = Input::new(data);
+    let size = u64::from(input.u32()).clamp(1, MAX_TRANSFER_BYTES);
+    let total_chunks = u32::from(input.u8()).max(1);
+
+    let mut session = ReceiveSession::new();
+    session
+        .on_file_offer(TRANSFER_ID, size, total_chunks, None)
+        .expect("offer within limits");
+    session.accept_current_offer().expect("offer pending");
+
+    let mut next_index = 0u32;
+    let mut expected = Vec::new();
+    while !input.is_empty() {
+        let transfer_id = if input.u8().is_multiple_of(8) {
+            "fuzz-1"
+        } else {
+            TRANSFER_ID
+        };
+        let chunk_index = match input.u8() % 4 {
+            0 => input.u32(),
+            1 => next_index.wrapping_add(1),
+            _ => next_index,
+        };
+        let chunk = input.bytes(64);
+
+        let before = session.state().clone();
+        let accept =
+            transfer_id == TRANSFER_ID && chunk_index == next_index && chunk_index < total_chunks;
+        match session.on_file_chunk(transfer_id, chunk_index, chunk) {
+            Ok(()) => {
+                assert!(accept, "accepted chunk {chunk_index} for {transfer_id}");
+                next_index += 1;
+                expected.extend_from_slice(chunk);
+            }
+            Err(e) => {
+                assert!(!accept, "rejected chunk {chunk_index}: {e}");
+                assert_wire_code(e.code());
+                assert_eq!(session.state(), &before);
+            }
+        }
+    }
+
+    session
+        .on_file_finish(TRANSFER_ID, None)
+        .expect("finish while transferring");
+    assert!(matches!(session.state(), TransferState::Completed { .. }));
+    assert_eq!(session.completed_bytes(), Some(expected.as_slice()));
+});
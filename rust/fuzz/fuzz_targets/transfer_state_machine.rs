@@ -0,0 +1,195 @@
+//! Random event sequences against a `SendSession` / `ReceiveSession` pair.
+//!
+//! Each input byte selects one event (begin, accept, pause, chunk, finish,
+//! cancel, ... on either side) with fuzzer-chosen arguments; events may
+//! mirror the peer faithfully or be arbitrary. Invariants after every
+//! event:
+//! - no panic;
+//! - an error carries a registered wire code and leaves the state as it was;
+//! - every state change is an edge of the PROTOCOL.md §9 diagram;
+//! - when the receiver completes from a faithful offer and faithful chunks,
+//!   its bytes are a prefix of the sender's payload, and `hash_verified`
+//!   reflects whether a hash was offered.
+
+#![no_main]
+
+use bolt_core::hash::sha256_hex;
+use bolt_fuzz::{assert_wire_code, Input};
+use bolt_transfer_core::state::is_section9_transition;
+use bolt_transfer_core::{
+    IntegrityVerifier, ReceiveSession, SendChunk, SendSession, Sha256Verifier, TransferError,
+    TransferState,
+};
+use libfuzzer_sys::fuzz_target;
+
+const TRANSFER_IDS: [&str; 2] = ["t0", "t1"];
+
+struct Harness {
+    send: SendSession,
+    recv: ReceiveSession,
+    payload: Vec<u8>,
+    offer: Option<(String, u64, u32, String)>,
+    in_flight: Vec<SendChunk>,
+    /// Receiver offer and every accepted chunk came from the sender.
+    faithful: bool,
+    hash_offered: bool,
+}
+
+fn checked<T>(
+    result: Result<T, TransferError>,
+    before: &TransferState,
+    after: &TransferState,
+) -> Option<T> {
+    match result {
+        Ok(value) => {
+            assert!(
+                is_section9_transition(before, after),
+                "illegal transition {before:?} -> {after:?}"
+            );
+            Some(value)
+        }
+        Err(e) => {
+            assert_wire_code(e.code());
+            assert_eq!(before, after, "failed event changed state: {e}");
+            None
+        }
+    }
+}
+
+impl Harness {
+    fn step(&mut self, input: &mut Input) {
+        let tid = TRANSFER_IDS[usize::from(input.u8() & 1)];
+        let send_before = self.send.state().clone();
+        let recv_before = self.recv.state().clone();
+
+        match input.u8() % 13 {
+            0 => {
+                let payload = input.bytes(200).to_vec();
+                let hash = input.bool().then(|| sha256_hex(&payload));
+                let result = self.send.begin_send(tid, payload.clone(), "f.bin", hash);
+                if let Some(offer) = checked(result, &send_before, self.send.state()) {
+                    self.payload = payload;
+                    let hash = offer.file_hash.unwrap_or_default();
+                    self.offer = Some((offer.transfer_id, offer.size, offer.total_chunks, hash));
+                }
+            }
+            1 => {
+                checked(self.send.on_accept(tid), &send_before, self.send.state());
+            }
+            2 => {
+                checked(self.send.on_cancel(tid), &send_before, self.send.state());
+            }
+            3 => {
+                checked(self.send.on_pause(tid), &send_before, self.send.state());
+            }
+            4 => {
+                checked(self.send.on_resume(tid), &send_before, self.send.state());
+            }
+            5 => {
+                if let Some(Some(chunk)) =
+                    checked(self.send.next_chunk(), &send_before, self.send.state())
+                {
+                    self.in_flight.push(chunk);
+                }
+            }
+            6 => {
+                checked(self.send.finish(), &send_before, self.send.state());
+            }
+            7 => {
+                let mirror = input.bool();
+                let with_hash = input.bool();
+                let (id, size, chunks, hash) = match (&self.offer, mirror) {
+                    (Some((id, size, chunks, hash)), true) => {
+                        (id.clone(), *size, *chunks, hash.clone())
+                    }
+                    _ => (
+                        tid.to_string(),
+                        u64::from(input.u32()),
+                        u32::from(input.u8()),
+                        String::new(),
+                    ),
+                };
+                let hash = (with_hash && !hash.is_empty()).then_some(hash);
+                let result = self.recv.on_file_offer(&id, size, chunks, hash.as_deref());
+                if checked(result, &recv_before, self.recv.state()).is_some() {
+                    self.faithful = mirror && self.offer.is_some();
+                    self.hash_offered = hash.is_some();
+                }
+            }
+            8 => {
+                checked(
+                    self.recv.accept_current_offer(),
+                    &recv_before,
+                    self.recv.state(),
+                );
+            }
+            9 => {
+                checked(
+                    self.recv.reject_current_offer(),
+                    &recv_before,
+                    self.recv.state(),
+                );
+            }
+            10 => {
+                let deliver_real = input.bool() && !self.in_flight.is_empty();
+                let (id, index, data, real) = if deliver_real {
+                    let chunk = self.in_flight.remove(0);
+                    (chunk.transfer_id, chunk.chunk_index, chunk.data, true)
+                } else {
+                    (
+                        tid.to_string(),
+                        u32::from(input.u8() % 8),
+                        input.bytes(32).to_vec(),
+                        false,
+                    )
+                };
+                let result = self.recv.on_file_chunk(&id, index, &data);
+                if checked(result, &recv_before, self.recv.state()).is_some() && !real {
+                    self.faithful = false;
+                }
+            }
+            11 => {
+                let verifier = input
+                    .bool()
+                    .then_some(&Sha256Verifier as &dyn IntegrityVerifier);
+                let result = self.recv.on_file_finish(tid, verifier);
+                checked(result, &recv_before, self.recv.state());
+            }
+            _ => {
+                checked(self.recv.cancel(tid), &recv_before, self.recv.state());
+            }
+        }
+
+        // Receiver never lingers in Accepted and never pauses (v1).
+        assert!(!matches!(
+            self.recv.state(),
+            TransferState::Accepted { .. } | TransferState::Paused { .. }
+        ));
+        assert_eq!(
+            self.recv.completed_bytes().is_some(),
+            matches!(self.recv.state(), TransferState::Completed { .. })
+        );
+        if let (true, Some(bytes)) = (self.faithful, self.recv.completed_bytes()) {
+            // FILE_FINISH may arrive early; what was received is a prefix.
+            assert!(self.payload.starts_with(bytes));
+            assert_eq!(self.recv.hash_verified(), self.hash_offered);
+        }
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    let mut input = Input::new(data);
+    let chunk_size = usize::from(input.u8() % 64) + 1;
+    let mut harness = Harness {
+        send: SendSession::with_chunk_size(chunk_size),
+        recv: ReceiveSession::new(),
+        payload: Vec::new(),
+        offer: None,
+        in_flight: Vec::new(),
+        faithful: false,
+        hash_offered: false,
+    };
+    while !input.is_empty() {
+        harness.step(&mut input);
+    }
+});
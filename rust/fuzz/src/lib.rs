@@ -0,0 +1,84 @@
+//! Shared helpers for the `bolt-fuzz` targets.
+//!
+//! Targets decode their own structure from the raw libFuzzer input with
+//! [`Input`] rather than `arbitrary`, so a crash artifact is a plain byte
+//! string that can be replayed without the fuzz toolchain.
+//!
+//! ## Targets
+//!
+//! | Target | Surface |
+//! |--------|---------|
+//! | `open_box_payload` | `bolt_core::crypto::open_box_payload` |
+//! | `encoding` | `bolt_core::encoding::{from_base64, from_hex}` |
+//! | `peer_code` | `bolt_core::peer_code::{normalize_peer_code, is_valid_peer_code}` |
+//! | `receive_chunk` | `ReceiveSession::on_file_chunk` |
+//! | `btr_open_chunk` | `BtrTransferContext::open_chunk` |
+//! | `transfer_state_machine` | random `SendSession` / `ReceiveSession` event sequences |
+//!
+//! Every target asserts that errors carry a registered wire code
+//! ([`assert_wire_code`]).
+
+use bolt_core::errors::is_valid_wire_error_code;
+
+/// Panics unless `code` is in the PROTOCOL.md §10 registry.
+pub fn assert_wire_code(code: &str) {
+    assert!(
+        is_valid_wire_error_code(code),
+        "unregistered wire code: {code}"
+    );
+}
+
+/// Cursor over fuzzer bytes. Reads past the end yield zeros, so every
+/// input decodes to some event sequence.
+pub struct Input<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Input<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn u8(&mut self) -> u8 {
+        match self.data.split_first() {
+            Some((b, rest)) => {
+                self.data = rest;
+                *b
+            }
+            None => 0,
+        }
+    }
+
+    pub fn bool(&mut self) -> bool {
+        self.u8() & 1 == 1
+    }
+
+    pub fn u32(&mut self) -> u32 {
+        u32::from_le_bytes([self.u8(), self.u8(), self.u8(), self.u8()])
+    }
+
+    /// Up to `max` bytes, length taken from the next input byte.
+    pub fn bytes(&mut self, max: usize) -> &'a [u8] {
+        let len = (self.u8() as usize).min(max).min(self.data.len());
+        let (head, rest) = self.data.split_at(len);
+        self.data = rest;
+        head
+    }
+
+    pub fn array32(&mut self) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        for b in &mut out {
+            *b = self.u8();
+        }
+        out
+    }
+
+    /// Everything not consumed yet.
+    pub fn rest(&mut self) -> &'a [u8] {
+        std::mem::take(&mut self.data)
+    }
+}
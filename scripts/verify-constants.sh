@@ -6,6 +6,10 @@ set -euo pipefail
 # the Rust and TypeScript sources and asserts they match.
 #
 # TS values are canonical today. Rust MUST match.
+#
+# The full set of constants, capabilities and wire error codes is exported
+# as rust/bolt-core/test-vectors/protocol-manifest.json (see
+# VECTOR_AUTHORITY.md); new cross-language checks should read that file.
 
 SCRIPT_DIR="$(cd "$(dirname "$0")" && pwd)"
 ROOT="$(cd "$SCRIPT_DIR/.." && pwd)"